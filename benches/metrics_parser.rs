@@ -0,0 +1,153 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Remote-mode scrape parsing: the streaming line parser against the
+//! previous regex-per-line tokenizer, on a ~2MB payload shaped like a
+//! `/metrics` response from a busy 8-GPU node.
+//!
+//! Run with `cargo bench --bench metrics_parser`.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hint::black_box;
+
+use all_smi::network::metrics_parser::MetricsParser;
+use all_smi::parsing::prometheus::{parse_line, LabelMap};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use regex::Regex;
+
+const TARGET_PAYLOAD_BYTES: usize = 2 * 1024 * 1024;
+
+fn build_payload() -> String {
+    let mut out = String::with_capacity(TARGET_PAYLOAD_BYTES + 64 * 1024);
+    let gpu_metrics = [
+        ("gpu_utilization", "37.5"),
+        ("gpu_memory_used_bytes", "61203283968"),
+        ("gpu_memory_total_bytes", "85899345920"),
+        ("gpu_temperature_celsius", "64"),
+        ("gpu_power_consumption_watts", "412.73"),
+        ("gpu_frequency_mhz", "1980"),
+    ];
+    let mut round = 0;
+    while out.len() < TARGET_PAYLOAD_BYTES {
+        for (metric, value) in gpu_metrics {
+            let _ = writeln!(out, "# HELP all_smi_{metric} {metric}");
+            let _ = writeln!(out, "# TYPE all_smi_{metric} gauge");
+            for index in 0..8 {
+                let _ = writeln!(
+                    out,
+                    r#"all_smi_{metric}{{gpu="NVIDIA H100 80GB HBM3", instance="node-{round:04}", uuid="GPU-{round:04}-{index}", index="{index}"}} {value}"#
+                );
+            }
+        }
+        for core in 0..128 {
+            let _ = writeln!(
+                out,
+                r#"all_smi_cpu_core_utilization{{cpu_model="AMD EPYC 9654", instance="node-{round:04}", hostname="node-{round:04}", index="0", core_id="{core}", core_type="S"}} 12.5"#
+            );
+        }
+        for mount in ["/", "/home", "/data", "/scratch"] {
+            let _ = writeln!(
+                out,
+                r#"all_smi_disk_total_bytes{{instance="node-{round:04}", mount_point="{mount}", index="0"}} 4398046511104"#
+            );
+        }
+        let _ = writeln!(
+            out,
+            r#"all_smi_memory_used_bytes{{instance="node-{round:04}", hostname="node-{round:04}", index="0"}} 68719476736"#
+        );
+        round += 1;
+    }
+    out
+}
+
+/// Label sanitizing of the legacy tokenizer: trim whitespace and
+/// surrounding quotes, and cap the length.
+fn sanitize_label_value(s: &str) -> String {
+    const MAX_LABEL_VALUE_LENGTH: usize = 1024;
+
+    let cleaned = s.trim().trim_matches('"');
+    if cleaned.len() > MAX_LABEL_VALUE_LENGTH {
+        cleaned[..MAX_LABEL_VALUE_LENGTH].to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// The tokenizer used before the streaming parser: a regex match per line
+/// followed by splitting the label block into owned strings.
+fn legacy_tokenize(text: &str, re: &Regex) -> usize {
+    let mut seen = 0;
+    for line in text.lines() {
+        let Some(cap) = re.captures(line.trim()) else {
+            continue;
+        };
+        let name = cap[1].to_string();
+        let labels_str = cap[2].to_string();
+        let value = cap[3].parse::<f64>().unwrap_or(0.0);
+
+        let mut labels: HashMap<String, String> = HashMap::with_capacity(16);
+        for label in labels_str.split(',') {
+            if let Some((key, val)) = label.split_once('=') {
+                labels.insert(sanitize_label_value(key), sanitize_label_value(val));
+            }
+        }
+        black_box((&name, &labels, value));
+        seen += 1;
+    }
+    seen
+}
+
+fn streaming_tokenize(text: &str) -> usize {
+    let mut labels = LabelMap::with_capacity(16);
+    let mut seen = 0;
+    for line in text.lines() {
+        if let Some(sample) = parse_line(line, &mut labels) {
+            black_box((sample, &labels));
+            seen += 1;
+        }
+    }
+    seen
+}
+
+fn bench_tokenize(c: &mut Criterion) {
+    let payload = build_payload();
+    let re = Regex::new(r"^all_smi_([^\{]+)\{([^}]+)\} ([\d\.]+)$").unwrap();
+    assert_eq!(legacy_tokenize(&payload, &re), streaming_tokenize(&payload));
+
+    let mut group = c.benchmark_group("tokenize_2mb");
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+    group.bench_function("regex", |b| {
+        b.iter(|| legacy_tokenize(black_box(&payload), &re))
+    });
+    group.bench_function("streaming", |b| {
+        b.iter(|| streaming_tokenize(black_box(&payload)))
+    });
+    group.finish();
+}
+
+fn bench_parse_metrics(c: &mut Criterion) {
+    let payload = build_payload();
+    let parser = MetricsParser::new();
+
+    let mut group = c.benchmark_group("parse_metrics_2mb");
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+    group.bench_function("streaming", |b| {
+        b.iter(|| parser.parse_metrics(black_box(&payload), "10.0.0.1:9090"))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_tokenize, bench_parse_metrics);
+criterion_main!(benches);
@@ -0,0 +1,138 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TUI frame rendering: building an 8-GPU frame into a fresh buffer against
+//! the reused frame buffer, and the bytes the differential renderer writes
+//! for a full redraw, one changed device and an unchanged screen.
+//!
+//! Run with `cargo bench --bench render`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use all_smi::device::GpuInfo;
+use all_smi::ui::buffer::{BufferWriter, DifferentialRenderer};
+use all_smi::ui::renderers::{print_gpu_info, GpuRowMarks};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const WIDTH: usize = 160;
+const HEIGHT: usize = 50;
+
+fn gpu(index: usize, utilization: f64) -> GpuInfo {
+    let detail = [
+        ("Driver Version", "570.86.15"),
+        ("CUDA Version", "12.8"),
+        ("power_limit_max", "700"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect::<HashMap<_, _>>();
+    GpuInfo {
+        uuid: format!("GPU-{index}"),
+        name: "NVIDIA H100".to_string(),
+        device_type: "GPU".to_string(),
+        host_id: "node-0001".to_string(),
+        hostname: "node-0001".to_string(),
+        instance: "node-0001".to_string(),
+        utilization,
+        temperature: 64,
+        used_memory: 57 << 30,
+        total_memory: 80 << 30,
+        frequency: 1980,
+        power_consumption: 412.7,
+        detail,
+        ..Default::default()
+    }
+}
+
+fn draw_frame(buffer: &mut BufferWriter, gpus: &[GpuInfo]) {
+    for info in gpus {
+        print_gpu_info(buffer, GpuRowMarks::default(), info, None, WIDTH, 0, 0);
+    }
+}
+
+fn frame(gpus: &[GpuInfo]) -> String {
+    let mut buffer = BufferWriter::new();
+    draw_frame(&mut buffer, gpus);
+    buffer.get_buffer().to_string()
+}
+
+/// Bytes `renderer` writes to go from its current screen to `content`
+fn redraw(renderer: &mut DifferentialRenderer, out: &mut Vec<u8>, content: &str) -> usize {
+    out.clear();
+    renderer.render_to(out, content, WIDTH, HEIGHT).unwrap();
+    out.len()
+}
+
+fn bench_build_frame(c: &mut Criterion) {
+    let gpus: Vec<GpuInfo> = (0..8).map(|i| gpu(i, 37.5)).collect();
+
+    let mut group = c.benchmark_group("build_frame_8_gpus");
+    group.bench_function("fresh_buffer", |b| {
+        b.iter(|| {
+            let mut buffer = BufferWriter::new();
+            draw_frame(&mut buffer, black_box(&gpus));
+            black_box(buffer.get_buffer().len())
+        })
+    });
+    let mut buffer = BufferWriter::new();
+    group.bench_function("reused_buffer", |b| {
+        b.iter(|| {
+            buffer.clear();
+            draw_frame(&mut buffer, black_box(&gpus));
+            black_box(buffer.get_buffer().len())
+        })
+    });
+    group.finish();
+}
+
+fn bench_redraw(c: &mut Criterion) {
+    let gpus: Vec<GpuInfo> = (0..8).map(|i| gpu(i, 37.5)).collect();
+    let mut changed = gpus.clone();
+    changed[3].utilization = 81.0;
+    let (base, changed) = (frame(&gpus), frame(&changed));
+    let mut out = Vec::with_capacity(64 * 1024);
+
+    // Bytes written per frame for each case, for comparison with the timings
+    let mut renderer = DifferentialRenderer::new().unwrap();
+    let full = redraw(&mut renderer, &mut out, &base);
+    let one_changed = redraw(&mut renderer, &mut out, &changed);
+    let unchanged = redraw(&mut renderer, &mut out, &changed);
+    println!("bytes written: full={full} one_gpu_changed={one_changed} unchanged={unchanged}");
+    assert_eq!(unchanged, 0);
+
+    let mut group = c.benchmark_group("redraw_8_gpus");
+    group.bench_function("full", |b| {
+        b.iter(|| {
+            let mut renderer = DifferentialRenderer::new().unwrap();
+            redraw(&mut renderer, &mut out, black_box(&base))
+        })
+    });
+    let mut renderer = DifferentialRenderer::new().unwrap();
+    let mut flip = false;
+    group.bench_function("one_gpu_changed", |b| {
+        b.iter(|| {
+            flip = !flip;
+            let content = if flip { &changed } else { &base };
+            redraw(&mut renderer, &mut out, black_box(content))
+        })
+    });
+    group.bench_function("unchanged", |b| {
+        b.iter(|| redraw(&mut renderer, &mut out, black_box(&base)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_build_frame, bench_redraw);
+criterion_main!(benches);
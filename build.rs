@@ -13,6 +13,26 @@
 // limitations under the License.
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Commit the binary is built from, for `--version --json` and
+    // all_smi_build_info. Packagers building outside a checkout can set
+    // ALL_SMI_GIT_SHA themselves.
+    let git_sha = std::env::var("ALL_SMI_GIT_SHA").ok().or_else(|| {
+        std::process::Command::new("git")
+            .args(["rev-parse", "--short=12", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|sha| sha.trim().to_string())
+    });
+    println!(
+        "cargo:rustc-env=ALL_SMI_GIT_SHA={}",
+        git_sha.as_deref().unwrap_or("unknown")
+    );
+    println!("cargo:rerun-if-env-changed=ALL_SMI_GIT_SHA");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+
     // Only compile proto files on Linux (TPU is Linux-only)
     #[cfg(target_os = "linux")]
     {
@@ -27,6 +47,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .type_attribute(".", "#[allow(clippy::enum_variant_names)]")
                 .compile_protos(&[proto_file], &["proto/"])?;
         }
+
+        // Kubelet PodResources client for pod labels; the server is only
+        // used by the tests' mock kubelet
+        tonic_prost_build::configure()
+            .build_client(true)
+            .build_server(true)
+            .compile_protos(&["proto/pod_resources.proto"], &["proto/"])?;
+    }
+
+    // Snapshot push service for API mode
+    if std::env::var_os("CARGO_FEATURE_GRPC").is_some() {
+        tonic_prost_build::configure()
+            .build_client(true)
+            .build_server(true)
+            .protoc_arg("--experimental_allow_proto3_optional")
+            .compile_protos(&["proto/all_smi_metrics.proto"], &["proto/"])?;
+    }
+
+    // Prometheus remote-write messages for API mode push
+    if std::env::var_os("CARGO_FEATURE_REMOTE_WRITE").is_some() {
+        tonic_prost_build::configure()
+            .build_client(false)
+            .build_server(false)
+            .compile_protos(&["proto/remote_write.proto"], &["proto/"])?;
     }
 
     Ok(())
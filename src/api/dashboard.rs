@@ -0,0 +1,646 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Grafana dashboard for the metrics exported in API mode.
+//!
+//! The catalogue below lists the numeric series worth graphing, grouped by
+//! exporter. Sections whose readers are not compiled into this build are left
+//! out, so the dashboard only references metrics the binary can emit.
+
+use std::io;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Gauge,
+    /// Graphed as a per-second rate
+    Counter,
+}
+
+#[derive(Debug)]
+pub struct DashboardMetric {
+    pub name: &'static str,
+    /// Same text as the exporter's `# HELP` line; used as the panel title
+    pub help: &'static str,
+    pub kind: MetricKind,
+    /// Grafana unit id
+    pub unit: &'static str,
+    pub legend: &'static str,
+}
+
+#[derive(Debug)]
+pub struct DashboardSection {
+    pub title: &'static str,
+    pub metrics: &'static [DashboardMetric],
+}
+
+const fn gauge(
+    name: &'static str,
+    help: &'static str,
+    unit: &'static str,
+    legend: &'static str,
+) -> DashboardMetric {
+    DashboardMetric {
+        name,
+        help,
+        kind: MetricKind::Gauge,
+        unit,
+        legend,
+    }
+}
+
+const fn counter(
+    name: &'static str,
+    help: &'static str,
+    unit: &'static str,
+    legend: &'static str,
+) -> DashboardMetric {
+    DashboardMetric {
+        name,
+        help,
+        kind: MetricKind::Counter,
+        unit,
+        legend,
+    }
+}
+
+const DEVICE_LEGEND: &str = "{{instance}} #{{index}}";
+const HOST_LEGEND: &str = "{{instance}}";
+
+const GPU: DashboardSection = DashboardSection {
+    title: "GPU / NPU",
+    metrics: &[
+        gauge(
+            "all_smi_gpu_utilization",
+            "GPU utilization percentage",
+            "percent",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_gpu_memory_used_bytes",
+            "GPU memory used in bytes",
+            "bytes",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_gpu_temperature_celsius",
+            "GPU temperature in celsius",
+            "celsius",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_gpu_power_consumption_watts",
+            "GPU power consumption in watts",
+            "watt",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_gpu_frequency_mhz",
+            "GPU frequency in MHz",
+            "megahertz",
+            DEVICE_LEGEND,
+        ),
+    ],
+};
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+const APPLE_SILICON: DashboardSection = DashboardSection {
+    title: "Apple Silicon",
+    metrics: &[
+        gauge(
+            "all_smi_cpu_p_core_utilization",
+            "Apple Silicon P-core utilization percentage",
+            "percent",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_cpu_e_core_utilization",
+            "Apple Silicon E-core utilization percentage",
+            "percent",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_ane_power_watts",
+            "ANE power consumption in watts",
+            "watt",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_combined_power_watts",
+            "Combined power consumption (CPU + GPU + ANE) in watts",
+            "watt",
+            HOST_LEGEND,
+        ),
+    ],
+};
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const GAUDI: DashboardSection = DashboardSection {
+    title: "Intel Gaudi",
+    metrics: &[
+        gauge(
+            "all_smi_gaudi_aip_utilization_percent",
+            "Gaudi AIP (AI Processor) utilization percentage",
+            "percent",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_gaudi_memory_used_bytes",
+            "Gaudi HBM memory used in bytes",
+            "bytes",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_gaudi_power_draw_watts",
+            "Gaudi current power consumption in watts",
+            "watt",
+            DEVICE_LEGEND,
+        ),
+    ],
+};
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const GOOGLE_TPU: DashboardSection = DashboardSection {
+    title: "Google TPU",
+    metrics: &[
+        gauge(
+            "all_smi_tpu_utilization_percent",
+            "TPU utilization percentage",
+            "percent",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_tpu_memory_used_bytes",
+            "TPU HBM memory used in bytes",
+            "bytes",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_tpu_hlo_queue_size",
+            "Number of pending HLO programs in the queue",
+            "short",
+            DEVICE_LEGEND,
+        ),
+    ],
+};
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const TENSTORRENT: DashboardSection = DashboardSection {
+    title: "Tenstorrent",
+    metrics: &[
+        gauge(
+            "all_smi_tenstorrent_asic_temperature_celsius",
+            "ASIC temperature in celsius",
+            "celsius",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_tenstorrent_aiclk_mhz",
+            "AI clock frequency in MHz",
+            "megahertz",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_tenstorrent_power_raw_watts",
+            "Raw power consumption in watts",
+            "watt",
+            DEVICE_LEGEND,
+        ),
+    ],
+};
+
+const FURIOSA: DashboardSection = DashboardSection {
+    title: "Furiosa",
+    metrics: &[
+        gauge(
+            "all_smi_furiosa_utilization_percent",
+            "NPU utilization percentage",
+            "percent",
+            DEVICE_LEGEND,
+        ),
+        gauge(
+            "all_smi_furiosa_memory_used_bytes",
+            "NPU memory used in bytes",
+            "bytes",
+            DEVICE_LEGEND,
+        ),
+    ],
+};
+
+const CPU: DashboardSection = DashboardSection {
+    title: "CPU",
+    metrics: &[
+        gauge(
+            "all_smi_cpu_utilization",
+            "CPU utilization percentage",
+            "percent",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_cpu_frequency_mhz",
+            "CPU frequency in MHz",
+            "megahertz",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_cpu_temperature_celsius",
+            "CPU temperature in celsius",
+            "celsius",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_cpu_power_consumption_watts",
+            "CPU power consumption in watts",
+            "watt",
+            HOST_LEGEND,
+        ),
+    ],
+};
+
+const MEMORY: DashboardSection = DashboardSection {
+    title: "Memory",
+    metrics: &[
+        gauge(
+            "all_smi_memory_utilization",
+            "Memory utilization percentage",
+            "percent",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_memory_used_bytes",
+            "Used system memory in bytes",
+            "bytes",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_memory_available_bytes",
+            "Available system memory in bytes",
+            "bytes",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_swap_used_bytes",
+            "Used swap space in bytes",
+            "bytes",
+            HOST_LEGEND,
+        ),
+    ],
+};
+
+const STORAGE: DashboardSection = DashboardSection {
+    title: "Storage",
+    metrics: &[
+        gauge(
+            "all_smi_disk_available_bytes",
+            "Available disk space in bytes",
+            "bytes",
+            "{{instance}} {{mount_point}}",
+        ),
+        gauge(
+            "all_smi_disk_inodes_free",
+            "Inodes available to unprivileged users",
+            "short",
+            "{{instance}} {{mount_point}}",
+        ),
+    ],
+};
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const NETWORK: DashboardSection = DashboardSection {
+    title: "Network",
+    metrics: &[
+        gauge(
+            "all_smi_net_rx_bytes_per_sec",
+            "Bytes received per second",
+            "Bps",
+            "{{instance}} {{iface}}",
+        ),
+        gauge(
+            "all_smi_net_tx_bytes_per_sec",
+            "Bytes transmitted per second",
+            "Bps",
+            "{{instance}} {{iface}}",
+        ),
+        counter(
+            "all_smi_net_rx_errors_total",
+            "Receive errors since the interface came up",
+            "short",
+            "{{instance}} {{iface}}",
+        ),
+        counter(
+            "all_smi_net_tx_errors_total",
+            "Transmit errors since the interface came up",
+            "short",
+            "{{instance}} {{iface}}",
+        ),
+    ],
+};
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const INFINIBAND: DashboardSection = DashboardSection {
+    title: "InfiniBand",
+    metrics: &[
+        counter(
+            "all_smi_ib_port_xmit_bytes_total",
+            "Bytes transmitted on the InfiniBand port",
+            "Bps",
+            "{{instance}} {{device}}/{{port}}",
+        ),
+        counter(
+            "all_smi_ib_port_rcv_bytes_total",
+            "Bytes received on the InfiniBand port",
+            "Bps",
+            "{{instance}} {{device}}/{{port}}",
+        ),
+        counter(
+            "all_smi_ib_port_xmit_wait_total",
+            "Ticks the port had data to send but no flow-control credits",
+            "short",
+            "{{instance}} {{device}}/{{port}}",
+        ),
+        gauge(
+            "all_smi_ib_port_link_up",
+            "Whether the port is ACTIVE (1) or not (0)",
+            "bool_on_off",
+            "{{instance}} {{device}}/{{port}}",
+        ),
+    ],
+};
+
+const CHASSIS: DashboardSection = DashboardSection {
+    title: "Chassis",
+    metrics: &[
+        gauge(
+            "all_smi_chassis_power_watts",
+            "Total chassis power consumption in watts (CPU+GPU+ANE)",
+            "watt",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_chassis_inlet_temperature_celsius",
+            "Chassis inlet temperature in Celsius",
+            "celsius",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_chassis_outlet_temperature_celsius",
+            "Chassis outlet temperature in Celsius",
+            "celsius",
+            HOST_LEGEND,
+        ),
+        gauge(
+            "all_smi_chassis_fan_speed_rpm",
+            "Fan speed in RPM",
+            "rotrpm",
+            "{{instance}} {{fan_name}}",
+        ),
+    ],
+};
+
+/// Sections for the exporters compiled into this build, in dashboard order.
+pub fn dashboard_sections() -> Vec<&'static DashboardSection> {
+    let mut sections = vec![&GPU];
+    if cfg!(target_os = "macos") {
+        sections.push(&APPLE_SILICON);
+    }
+    if cfg!(target_os = "linux") {
+        sections.extend([&GAUDI, &GOOGLE_TPU, &TENSTORRENT]);
+    }
+    sections.extend([&FURIOSA, &CPU, &MEMORY, &STORAGE]);
+    if cfg!(target_os = "linux") {
+        sections.extend([&NETWORK, &INFINIBAND]);
+    }
+    sections.push(&CHASSIS);
+    sections
+}
+
+const PANEL_WIDTH: u32 = 12;
+const PANEL_HEIGHT: u32 = 8;
+const GRID_WIDTH: u32 = 24;
+
+fn query(metric: &DashboardMetric) -> String {
+    let selector = format!("{}{{instance=~\"$instance\"}}", metric.name);
+    match metric.kind {
+        MetricKind::Gauge => selector,
+        MetricKind::Counter => format!("rate({selector}[$__rate_interval])"),
+    }
+}
+
+fn datasource() -> Value {
+    json!({ "type": "prometheus", "uid": "${datasource}" })
+}
+
+/// Build the dashboard model: one collapsible row per section, two
+/// time-series panels side by side.
+pub fn generate_dashboard(sections: &[&DashboardSection]) -> Value {
+    let mut panels = Vec::new();
+    let mut id = 1;
+    let mut y = 0;
+
+    for section in sections {
+        panels.push(json!({
+            "id": id,
+            "type": "row",
+            "title": section.title,
+            "collapsed": false,
+            "gridPos": { "h": 1, "w": GRID_WIDTH, "x": 0, "y": y },
+            "panels": [],
+        }));
+        id += 1;
+        y += 1;
+
+        for (i, metric) in section.metrics.iter().enumerate() {
+            let x = (i as u32 % 2) * PANEL_WIDTH;
+            panels.push(json!({
+                "id": id,
+                "type": "timeseries",
+                "title": metric.help,
+                "description": metric.name,
+                "datasource": datasource(),
+                "gridPos": { "h": PANEL_HEIGHT, "w": PANEL_WIDTH, "x": x, "y": y },
+                "fieldConfig": { "defaults": { "unit": metric.unit }, "overrides": [] },
+                "options": { "legend": { "displayMode": "list", "placement": "bottom" } },
+                "targets": [{
+                    "refId": "A",
+                    "datasource": datasource(),
+                    "expr": query(metric),
+                    "legendFormat": metric.legend,
+                }],
+            }));
+            id += 1;
+            if x > 0 {
+                y += PANEL_HEIGHT;
+            }
+        }
+        if section.metrics.len() % 2 == 1 {
+            y += PANEL_HEIGHT;
+        }
+    }
+
+    json!({
+        "title": "all-smi",
+        "uid": "all-smi",
+        "tags": ["all-smi"],
+        "editable": true,
+        "schemaVersion": 39,
+        "refresh": "10s",
+        "time": { "from": "now-1h", "to": "now" },
+        "templating": {
+            "list": [
+                {
+                    "name": "datasource",
+                    "label": "Data source",
+                    "type": "datasource",
+                    "query": "prometheus",
+                },
+                {
+                    "name": "instance",
+                    "label": "Instance",
+                    "type": "query",
+                    "datasource": datasource(),
+                    "query": "label_values({__name__=~\"all_smi_.+\"}, instance)",
+                    "refresh": 2,
+                    "multi": true,
+                    "includeAll": true,
+                    "current": { "text": "All", "value": "$__all" },
+                },
+            ],
+        },
+        "panels": panels,
+    })
+}
+
+/// Write the dashboard for this build to `output`, or stdout when `None`.
+pub fn write_dashboard(output: Option<&Path>) -> io::Result<()> {
+    let dashboard = generate_dashboard(&dashboard_sections());
+    let mut text = serde_json::to_string_pretty(&dashboard)?;
+    text.push('\n');
+    match output {
+        Some(path) => std::fs::write(path, text),
+        None => {
+            use std::io::Write;
+            io::stdout().write_all(text.as_bytes())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metrics::{
+        infiniband::InfinibandMetricExporter, network::NetworkMetricExporter, MetricExporter,
+    };
+    use crate::network::{IbPortInfo, NetworkInfo};
+    use std::collections::HashSet;
+
+    const ALL_SECTIONS: [&DashboardSection; 12] = [
+        &GPU,
+        &APPLE_SILICON,
+        &GAUDI,
+        &GOOGLE_TPU,
+        &TENSTORRENT,
+        &FURIOSA,
+        &CPU,
+        &MEMORY,
+        &STORAGE,
+        &NETWORK,
+        &INFINIBAND,
+        &CHASSIS,
+    ];
+
+    #[test]
+    fn test_catalogue_is_consistent() {
+        let mut names = HashSet::new();
+        for section in ALL_SECTIONS {
+            for metric in section.metrics {
+                assert!(metric.name.starts_with("all_smi_"), "{}", metric.name);
+                assert!(names.insert(metric.name), "duplicate {}", metric.name);
+                assert_eq!(
+                    metric.kind == MetricKind::Counter,
+                    metric.name.ends_with("_total"),
+                    "{}",
+                    metric.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_help_matches_exporters() {
+        let network = vec![NetworkInfo {
+            interface: "eth0".to_string(),
+            rx_bytes_per_sec: 0.0,
+            tx_bytes_per_sec: 0.0,
+            rx_errors: 0,
+            tx_errors: 0,
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+        }];
+        let ports = vec![IbPortInfo {
+            device: "mlx5_0".to_string(),
+            port: 1,
+            state: "ACTIVE".to_string(),
+            rate_gbps: None,
+            xmit_bytes: 0,
+            rcv_bytes: 0,
+            xmit_wait: Some(0),
+            xmit_discards: None,
+            rcv_errors: None,
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+        }];
+        let output = NetworkMetricExporter::new(&network).export_metrics()
+            + &InfinibandMetricExporter::new(&ports).export_metrics();
+
+        for metric in NETWORK.metrics.iter().chain(INFINIBAND.metrics) {
+            let help = format!("# HELP {} {}\n", metric.name, metric.help);
+            assert!(output.contains(&help), "{help}");
+        }
+    }
+
+    #[test]
+    fn test_generate_dashboard_layout() {
+        let dashboard = generate_dashboard(&[&CPU, &CHASSIS]);
+        let panels = dashboard["panels"].as_array().unwrap();
+        assert_eq!(panels.len(), 2 + CPU.metrics.len() + CHASSIS.metrics.len());
+
+        assert_eq!(panels[0]["type"], "row");
+        assert_eq!(panels[0]["title"], "CPU");
+        assert_eq!(
+            panels[1]["targets"][0]["expr"],
+            "all_smi_cpu_utilization{instance=~\"$instance\"}"
+        );
+        // Second panel sits to the right of the first
+        assert_eq!(panels[2]["gridPos"]["x"], PANEL_WIDTH);
+        assert_eq!(panels[2]["gridPos"]["y"], panels[1]["gridPos"]["y"]);
+        // Next row starts below the last panel pair
+        let chassis_row = &panels[1 + CPU.metrics.len()];
+        assert_eq!(chassis_row["title"], "Chassis");
+        assert_eq!(chassis_row["gridPos"]["y"], 1 + 2 * PANEL_HEIGHT);
+
+        let ids: HashSet<u64> = panels.iter().map(|p| p["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids.len(), panels.len());
+    }
+
+    #[test]
+    fn test_counters_are_rated() {
+        let dashboard = generate_dashboard(&[&INFINIBAND]);
+        assert_eq!(
+            dashboard["panels"][1]["targets"][0]["expr"],
+            "rate(all_smi_ib_port_xmit_bytes_total{instance=~\"$instance\"}[$__rate_interval])"
+        );
+    }
+}
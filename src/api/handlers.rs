@@ -12,28 +12,166 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use axum::extract::State;
+use axum::body::{Body, Bytes};
+use axum::extract::{FromRef, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+use all_smi::traits::MetricMetadata;
+
 use crate::app_state::AppState;
+use crate::cli::StalenessPolicy;
+use crate::device::container_utils::process_container_id;
+use crate::device::privileges::unavailable_features;
+use crate::device::ProcessInfo;
+use crate::utils::build_info::BuildInfo;
+use crate::utils::logging::{log_buffer, LogRecord};
 
 use super::metrics::{
-    chassis::ChassisMetricExporter, cpu::CpuMetricExporter, disk::DiskMetricExporter,
-    gpu::GpuMetricExporter, memory::MemoryMetricExporter, npu::NpuMetricExporter,
-    process::ProcessMetricExporter, runtime::RuntimeMetricExporter, MetricExporter,
+    build_info::BuildInfoMetricExporter,
+    chassis::ChassisMetricExporter,
+    collection::CollectionMetricExporter,
+    cpu::CpuMetricExporter,
+    disk::DiskMetricExporter,
+    gpu::GpuMetricExporter,
+    infiniband::InfinibandMetricExporter,
+    memory::MemoryMetricExporter,
+    metadata::registered_metadata,
+    network::NetworkMetricExporter,
+    npu::NpuMetricExporter,
+    privileges::UnavailableFeatureExporter,
+    process::{ProcessMetricExporter, UserMetricExporter},
+    push::PushMetricExporter,
+    runtime::RuntimeMetricExporter,
+    scrape::ScrapeMetricExporter,
+    MetricExporter,
 };
+use super::scrape::{retry_after_secs, MetricsCache, RateLimiter};
+use super::staleness::{staleness, Staleness};
 
 pub type SharedState = Arc<RwLock<AppState>>;
 
-pub async fn metrics_handler(State(state): State<SharedState>) -> String {
-    let state = state.read().await;
+/// State of the API routes
+#[derive(Clone)]
+pub struct ApiState {
+    pub state: SharedState,
+    pub metrics: Arc<MetricsCache>,
+    pub limiter: Option<Arc<RateLimiter>>,
+    /// Snapshots pushed to `/ws`
+    #[cfg(feature = "web")]
+    pub live: super::web::LiveSubscriber,
+}
+
+impl ApiState {
+    /// `metrics_max_age` bounds how long a rendered `/metrics` text is
+    /// served; `max_requests_per_second` enables the rate limit.
+    pub fn new(
+        state: SharedState,
+        metrics_max_age: Duration,
+        max_requests_per_second: Option<u32>,
+    ) -> Self {
+        Self {
+            state,
+            metrics: Arc::new(MetricsCache::new(metrics_max_age)),
+            limiter: max_requests_per_second
+                .map(|limit| Arc::new(RateLimiter::new(limit, Instant::now()))),
+            // Without a publisher, `/ws` clients are closed right away
+            #[cfg(feature = "web")]
+            live: tokio::sync::watch::channel(None).1,
+        }
+    }
+
+    /// Push the snapshots of `live` to `/ws` clients
+    #[cfg(feature = "web")]
+    pub fn with_live(self, live: super::web::LiveSubscriber) -> Self {
+        Self { live, ..self }
+    }
+}
+
+impl FromRef<ApiState> for SharedState {
+    fn from_ref(api: &ApiState) -> Self {
+        api.state.clone()
+    }
+}
+
+/// Cached exposition text, handed to the response body without a copy
+struct Exposition(Arc<str>);
+
+impl AsRef<[u8]> for Exposition {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+pub async fn metrics_handler(State(api): State<ApiState>) -> Response {
+    let stats = api.metrics.stats();
+    stats.record_request();
+    if let Some(limiter) = &api.limiter {
+        if let Err(wait) = limiter.acquire(Instant::now()) {
+            stats.record_rate_limited();
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after_secs(wait).to_string())],
+                "Too many requests\n",
+            )
+                .into_response();
+        }
+    }
+
+    let text = api.metrics.get(&*api.state.read().await);
+    let self_metrics = ScrapeMetricExporter::new(stats).export_metrics();
+    let length = text.len() + self_metrics.len();
+    let chunks = [
+        Bytes::from_owner(Exposition(text)),
+        Bytes::from(self_metrics),
+    ];
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/plain; charset=utf-8".to_string(),
+            ),
+            (header::CONTENT_LENGTH, length.to_string()),
+        ],
+        Body::from_stream(futures_util::stream::iter(chunks.map(Ok::<_, Infallible>))),
+    )
+        .into_response()
+}
+
+/// Metric names, HELP text, TYPE and labels, as JSON. The current state is
+/// exported first so every metric this host can report is registered.
+pub async fn metadata_handler(State(state): State<SharedState>) -> Json<Vec<MetricMetadata>> {
+    export_all_metrics(&*state.read().await);
+    Json(registered_metadata())
+}
+
+/// Every metric for `state` in Prometheus text format, as served on
+/// `/metrics` and pushed with `--push-url`.
+pub fn export_all_metrics(state: &AppState) -> String {
+    export_metrics_with(state, staleness())
+}
+
+/// [`export_all_metrics`] with explicit staleness settings.
+fn export_metrics_with(state: &AppState, staleness: Staleness) -> String {
     let mut all_metrics = String::new();
+    let stale = |collector| state.is_stale(collector, staleness.window(collector));
+    // Series of a stale collector are dropped under the omit policy
+    let export = |collector| staleness.policy == StalenessPolicy::Flag || !stale(collector);
 
     // Export GPU/NPU metrics
-    if !state.gpu_info.is_empty() {
+    if !state.gpu_info.is_empty() && export("gpu") {
         // Export GPU/NPU metrics together since the exporters handle filtering
-        let gpu_exporter = GpuMetricExporter::new(&state.gpu_info);
+        let mut gpu_exporter = GpuMetricExporter::new(&state.gpu_info);
+        if staleness.policy == StalenessPolicy::Flag {
+            gpu_exporter = gpu_exporter.with_stale(stale("gpu"));
+        }
         all_metrics.push_str(&gpu_exporter.export_metrics());
 
         let npu_exporter = NpuMetricExporter::new(&state.gpu_info);
@@ -41,30 +179,54 @@ pub async fn metrics_handler(State(state): State<SharedState>) -> String {
     }
 
     // Export process metrics
-    if !state.process_info.is_empty() {
+    if !state.process_info.is_empty() && export("gpu") {
         let process_exporter = ProcessMetricExporter::new(&state.process_info);
         all_metrics.push_str(&process_exporter.export_metrics());
     }
+    if !state.user_summaries.is_empty() && export("gpu") {
+        let user_exporter = UserMetricExporter::new(&state.user_summaries);
+        all_metrics.push_str(&user_exporter.export_metrics());
+    }
 
     // Export CPU metrics
-    if !state.cpu_info.is_empty() {
+    if !state.cpu_info.is_empty() && export("cpu") {
         let cpu_exporter = CpuMetricExporter::new(&state.cpu_info);
         all_metrics.push_str(&cpu_exporter.export_metrics());
     }
 
     // Export memory metrics
-    if !state.memory_info.is_empty() {
+    if !state.memory_info.is_empty() && export("memory") {
         let memory_exporter = MemoryMetricExporter::new(&state.memory_info);
         all_metrics.push_str(&memory_exporter.export_metrics());
     }
 
     // Export disk metrics from cached storage_info
     // This uses pre-collected data from the background task instead of collecting on each request
-    if !state.storage_info.is_empty() {
+    if !state.storage_info.is_empty() && export("disk") {
         let disk_exporter = DiskMetricExporter::new(&state.storage_info);
         all_metrics.push_str(&disk_exporter.export_metrics());
     }
 
+    // Export network interface metrics
+    if !state.network_info.is_empty() && export("network") {
+        let network_exporter = NetworkMetricExporter::new(&state.network_info);
+        all_metrics.push_str(&network_exporter.export_metrics());
+    }
+
+    // Export InfiniBand port metrics
+    if !state.infiniband_info.is_empty() && export("network") {
+        let infiniband_exporter = InfinibandMetricExporter::new(&state.infiniband_info);
+        all_metrics.push_str(&infiniband_exporter.export_metrics());
+    }
+
+    // Export when each collector last finished, stale or not
+    let collection_exporter = CollectionMetricExporter::new(&state.collection_stamps);
+    all_metrics.push_str(&collection_exporter.export_metrics());
+
+    // Export the version and features of this binary
+    let build_info_exporter = BuildInfoMetricExporter::new(BuildInfo::current());
+    all_metrics.push_str(&build_info_exporter.export_metrics());
+
     // Export runtime environment metrics
     let runtime_exporter = RuntimeMetricExporter::new(&state.runtime_environment);
     all_metrics.push_str(&runtime_exporter.export_metrics());
@@ -75,5 +237,277 @@ pub async fn metrics_handler(State(state): State<SharedState>) -> String {
         all_metrics.push_str(&chassis_exporter.export_metrics());
     }
 
+    // Export what the missing privileges leave out
+    let privileges_exporter = UnavailableFeatureExporter::new(unavailable_features());
+    all_metrics.push_str(&privileges_exporter.export_metrics());
+
+    // Export push counters when --push-url is set
+    if let Some(stats) = super::push::push_stats() {
+        let push_exporter = PushMetricExporter::new(stats);
+        all_metrics.push_str(&push_exporter.export_metrics());
+    }
+
     all_metrics
 }
+
+/// Order of the `/processes` list (`?sort=`)
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessSort {
+    /// GPU memory, largest first
+    #[default]
+    Memory,
+    /// GPU utilization, highest first
+    Gpu,
+    /// CPU usage, highest first
+    Cpu,
+    /// Process ID, lowest first
+    Pid,
+}
+
+/// Query parameters of `/processes`
+#[derive(Deserialize, Debug, Default)]
+pub struct ProcessQuery {
+    #[serde(default)]
+    pub sort: ProcessSort,
+    /// Return at most this many processes
+    pub limit: Option<usize>,
+}
+
+/// A GPU process as served on `/processes`
+#[derive(Serialize)]
+pub struct ProcessEntry {
+    #[serde(flatten)]
+    pub process: ProcessInfo,
+    /// Name of the device the process runs on
+    pub device_name: Option<String>,
+    /// Short ID of the container the process runs in
+    pub container_id: Option<String>,
+}
+
+/// GPU processes from the last collection as JSON, e.g.
+/// `/processes?sort=memory&limit=20` for the top GPU memory consumers.
+/// Empty unless API mode runs with `--processes`.
+pub async fn processes_handler(
+    State(state): State<SharedState>,
+    Query(query): Query<ProcessQuery>,
+) -> Json<Vec<ProcessEntry>> {
+    let entries = process_entries(&*state.read().await, &query, |_| None);
+    // Containers are looked up after releasing the lock
+    let entries = entries
+        .into_iter()
+        .map(|entry| ProcessEntry {
+            container_id: process_container_id(entry.process.pid),
+            ..entry
+        })
+        .collect();
+    Json(entries)
+}
+
+/// Processes using a GPU in `query` order, each with its device name and
+/// the container `container_id` finds for its PID.
+fn process_entries(
+    state: &AppState,
+    query: &ProcessQuery,
+    container_id: impl Fn(u32) -> Option<String>,
+) -> Vec<ProcessEntry> {
+    let mut processes: Vec<&ProcessInfo> =
+        state.process_info.iter().filter(|p| p.uses_gpu).collect();
+    match query.sort {
+        ProcessSort::Memory => processes.sort_by_key(|p| (Reverse(p.used_memory), p.pid)),
+        ProcessSort::Gpu => processes.sort_by(|a, b| {
+            b.gpu_utilization
+                .total_cmp(&a.gpu_utilization)
+                .then(a.pid.cmp(&b.pid))
+        }),
+        ProcessSort::Cpu => processes.sort_by(|a, b| {
+            b.cpu_percent
+                .total_cmp(&a.cpu_percent)
+                .then(a.pid.cmp(&b.pid))
+        }),
+        ProcessSort::Pid => processes.sort_by_key(|p| p.pid),
+    }
+    if let Some(limit) = query.limit {
+        processes.truncate(limit);
+    }
+
+    processes
+        .into_iter()
+        .map(|process| ProcessEntry {
+            device_name: state
+                .gpu_info
+                .iter()
+                .find(|gpu| gpu.uuid == process.device_uuid)
+                .map(|gpu| gpu.name.clone()),
+            container_id: container_id(process.pid),
+            process: process.clone(),
+        })
+        .collect()
+}
+
+/// Recent log records, oldest first, as JSON.
+pub async fn debug_logs_handler() -> Json<Vec<LogRecord>> {
+    Json(log_buffer().records())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_state::CollectionStamp;
+    use crate::device::{GpuInfo, SubsystemIntervals};
+    use std::time::{Duration, Instant};
+
+    /// State whose GPU collector finished `gpu_age` ago and whose memory
+    /// collector just finished
+    fn state(gpu_age: Duration) -> AppState {
+        let mut state = AppState::new();
+        state.gpu_info = vec![GpuInfo {
+            name: "Test GPU".to_string(),
+            utilization: 50.0,
+            temperature: 60,
+            used_memory: 1 << 30,
+            total_memory: 8 << 30,
+            frequency: 1500,
+            power_consumption: 200.0,
+            ..GpuInfo::fixture("GPU-0", "node1")
+        }];
+        let mut stamp = CollectionStamp::now();
+        stamp.at = Instant::now().checked_sub(gpu_age).unwrap();
+        state.collection_stamps.insert("gpu", stamp);
+        state
+            .collection_stamps
+            .insert("memory", CollectionStamp::now());
+        state
+    }
+
+    fn staleness(policy: StalenessPolicy) -> Staleness {
+        Staleness {
+            window: Some(Duration::from_secs(9)),
+            intervals: SubsystemIntervals::new(3),
+            policy,
+        }
+    }
+
+    #[test]
+    fn test_fresh_collector_is_exported() {
+        let fresh = state(Duration::ZERO);
+        let metrics = export_metrics_with(&fresh, staleness(StalenessPolicy::Omit));
+        assert!(metrics.contains("all_smi_gpu_utilization{"));
+        assert!(!metrics.contains("all_smi_gpu_stale"));
+
+        let metrics = export_metrics_with(&fresh, staleness(StalenessPolicy::Flag));
+        assert!(metrics.contains("all_smi_gpu_stale{gpu=\"Test GPU\""));
+        assert!(metrics.contains("index=\"0\"} 0\n"));
+    }
+
+    #[test]
+    fn test_stopped_collector_is_omitted() {
+        let stopped = state(Duration::from_secs(30));
+        let metrics = export_metrics_with(&stopped, staleness(StalenessPolicy::Omit));
+        assert!(!metrics.contains("all_smi_gpu_"));
+        // When it last ran is still reported
+        assert!(metrics.contains("all_smi_last_collection_timestamp_seconds{"));
+        assert!(metrics.contains("collector=\"gpu\""));
+        assert!(metrics.contains("collector=\"memory\""));
+    }
+
+    #[test]
+    fn test_stopped_collector_is_flagged() {
+        let stopped = state(Duration::from_secs(30));
+        let metrics = export_metrics_with(&stopped, staleness(StalenessPolicy::Flag));
+        assert!(metrics.contains("all_smi_gpu_utilization{"));
+        let stale_line = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_stale{"))
+            .unwrap();
+        assert!(stale_line.ends_with(" 1"));
+    }
+
+    fn process(pid: u32, used_memory: u64, gpu_utilization: f64) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: "GPU-0".to_string(),
+            pid,
+            process_name: format!("proc{pid}"),
+            used_memory,
+            used_memory_estimated: false,
+            cpu_percent: 0.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: "user".to_string(),
+            state: "S".to_string(),
+            start_time: 0,
+            cpu_time: 0,
+            command: String::new(),
+            ppid: 1,
+            threads: 1,
+            uses_gpu: used_memory > 0,
+            priority: 20,
+            nice_value: 0,
+            gpu_utilization,
+            k8s_pod: None,
+            host_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_process_entries_sorted_and_limited() {
+        let mut state = state(Duration::ZERO);
+        state.process_info = vec![
+            process(10, 1 << 20, 90.0),
+            process(20, 4 << 20, 10.0),
+            process(30, 0, 0.0),
+            process(40, 2 << 20, 50.0),
+        ];
+        let container = |pid| (pid == 20).then(|| "4f2a9c0e7b1d".to_string());
+
+        let query = ProcessQuery {
+            sort: ProcessSort::Memory,
+            limit: Some(2),
+        };
+        let entries = process_entries(&state, &query, container);
+        let pids: Vec<u32> = entries.iter().map(|e| e.process.pid).collect();
+        assert_eq!(pids, vec![20, 40]);
+        assert_eq!(entries[0].device_name.as_deref(), Some("Test GPU"));
+        assert_eq!(entries[0].container_id.as_deref(), Some("4f2a9c0e7b1d"));
+        assert_eq!(entries[1].container_id, None);
+
+        // Processes without GPU use are left out
+        let query = ProcessQuery {
+            sort: ProcessSort::Gpu,
+            limit: None,
+        };
+        let pids: Vec<u32> = process_entries(&state, &query, |_| None)
+            .iter()
+            .map(|e| e.process.pid)
+            .collect();
+        assert_eq!(pids, vec![10, 40, 20]);
+
+        let json = serde_json::to_value(process_entries(&state, &query, container)).unwrap();
+        assert_eq!(json[2]["pid"], 20);
+        assert_eq!(json[2]["device_name"], "Test GPU");
+        assert_eq!(json[2]["container_id"], "4f2a9c0e7b1d");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_over_the_limit_get_429() {
+        let state = SharedState::new(RwLock::new(AppState::new()));
+        let api = ApiState::new(state, Duration::from_secs(60), Some(1));
+
+        let response = metrics_handler(State(api.clone())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("all_smi_metrics_requests_total{"));
+        assert!(body.contains("all_smi_metrics_renders_total{"));
+
+        let response = metrics_handler(State(api.clone())).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+        assert_eq!(api.metrics.stats().requests(), 2);
+        assert_eq!(api.metrics.stats().rate_limited(), 1);
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MetricBuilder, MetricExporter};
+use crate::utils::build_info::BuildInfo;
+
+/// `all_smi_build_info`, the version and features of the running binary
+pub struct BuildInfoMetricExporter {
+    info: BuildInfo,
+    hostname: String,
+}
+
+impl BuildInfoMetricExporter {
+    pub fn new(info: BuildInfo) -> Self {
+        Self {
+            info,
+            hostname: crate::utils::get_hostname(),
+        }
+    }
+}
+
+impl MetricExporter for BuildInfoMetricExporter {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+        let features = self.info.features.join(",");
+        let labels = [
+            ("hostname", self.hostname.as_str()),
+            ("version", self.info.version),
+            ("git_sha", self.info.git_sha),
+            ("features", features.as_str()),
+            ("target", self.info.target.as_str()),
+        ];
+        builder
+            .help(
+                "all_smi_build_info",
+                "Version, commit and cargo features of the all-smi binary",
+            )
+            .type_("all_smi_build_info", "gauge")
+            .metric("all_smi_build_info", &labels, 1);
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_series() {
+        let info = BuildInfo {
+            version: "0.17.0",
+            git_sha: "3d95bdb0c1a2",
+            features: vec!["grpc", "web"],
+            platforms: vec!["nvidia"],
+            target: "linux-x86_64".to_string(),
+        };
+        let text = BuildInfoMetricExporter::new(info).export_metrics();
+        assert!(text.contains("# TYPE all_smi_build_info gauge\n"));
+        assert!(text.contains(
+            "version=\"0.17.0\", git_sha=\"3d95bdb0c1a2\", features=\"grpc,web\", target=\"linux-x86_64\"} 1\n"
+        ));
+
+        let current = BuildInfo::current();
+        assert_eq!(current.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(current.features.contains(&"web"), cfg!(feature = "web"));
+    }
+}
@@ -16,10 +16,15 @@
 //!
 //! Exports node-level metrics including:
 //! - Total power consumption (CPU+GPU+ANE)
-//! - Thermal pressure (Apple Silicon)
+//! - Thermal pressure and time spent at each level (Apple Silicon)
 //! - Individual power components (CPU, GPU, ANE)
+//! - Load average, uptime and kernel/OS identity of the node
 
 use super::{MetricBuilder, MetricExporter};
+use crate::device::host_info::LOAD_WINDOWS;
+use crate::device::thermal_pressure::{
+    thermal_level_value, thermal_time_key, THERMAL_LEVELS, THERMAL_TRANSITIONS_KEY,
+};
 use crate::device::ChassisInfo;
 
 /// Exporter for chassis-level metrics
@@ -37,12 +42,14 @@ impl<'a> ChassisMetricExporter<'a> {
 struct MetricPresenceFlags {
     has_power: bool,
     has_thermal_pressure: bool,
+    has_thermal_history: bool,
     has_cpu_power: bool,
     has_gpu_power: bool,
     has_ane_power: bool,
     has_inlet_temp: bool,
     has_outlet_temp: bool,
     has_fan_speeds: bool,
+    has_system: bool,
 }
 
 impl MetricPresenceFlags {
@@ -51,23 +58,27 @@ impl MetricPresenceFlags {
         let mut flags = Self {
             has_power: false,
             has_thermal_pressure: false,
+            has_thermal_history: false,
             has_cpu_power: false,
             has_gpu_power: false,
             has_ane_power: false,
             has_inlet_temp: false,
             has_outlet_temp: false,
             has_fan_speeds: false,
+            has_system: false,
         };
 
         for chassis in chassis_info {
             flags.has_power |= chassis.total_power_watts.is_some();
             flags.has_thermal_pressure |= chassis.thermal_pressure.is_some();
+            flags.has_thermal_history |= chassis.detail.contains_key(THERMAL_TRANSITIONS_KEY);
             flags.has_cpu_power |= chassis.detail.contains_key("cpu_power_watts");
             flags.has_gpu_power |= chassis.detail.contains_key("gpu_power_watts");
             flags.has_ane_power |= chassis.detail.contains_key("ane_power_watts");
             flags.has_inlet_temp |= chassis.inlet_temperature.is_some();
             flags.has_outlet_temp |= chassis.outlet_temperature.is_some();
             flags.has_fan_speeds |= !chassis.fan_speeds.is_empty();
+            flags.has_system |= chassis.system.is_some();
 
             // Early exit if all flags are set
             if flags.all_present() {
@@ -81,12 +92,14 @@ impl MetricPresenceFlags {
     fn all_present(&self) -> bool {
         self.has_power
             && self.has_thermal_pressure
+            && self.has_thermal_history
             && self.has_cpu_power
             && self.has_gpu_power
             && self.has_ane_power
             && self.has_inlet_temp
             && self.has_outlet_temp
             && self.has_fan_speeds
+            && self.has_system
     }
 }
 
@@ -146,6 +159,57 @@ impl<'a> MetricExporter for ChassisMetricExporter<'a> {
                     );
                 }
             }
+
+            builder
+                .help(
+                    "all_smi_thermal_pressure_level",
+                    "Thermal pressure level (0=Nominal, 1=Fair, 2=Serious, 3=Critical)",
+                )
+                .type_("all_smi_thermal_pressure_level", "gauge");
+            for chassis in self.chassis_info {
+                if let Some(level) = chassis
+                    .thermal_pressure
+                    .as_deref()
+                    .and_then(thermal_level_value)
+                {
+                    builder.metric(
+                        "all_smi_thermal_pressure_level",
+                        &[
+                            ("hostname", &chassis.hostname),
+                            ("instance", &chassis.instance),
+                        ],
+                        level,
+                    );
+                }
+            }
+        }
+
+        if flags.has_thermal_history {
+            builder
+                .help(
+                    "all_smi_thermal_pressure_seconds_total",
+                    "Seconds spent at each thermal pressure level since start",
+                )
+                .type_("all_smi_thermal_pressure_seconds_total", "counter");
+            for chassis in self.chassis_info {
+                for level in THERMAL_LEVELS {
+                    if let Some(secs) = chassis
+                        .detail
+                        .get(&thermal_time_key(level))
+                        .and_then(|secs| secs.parse::<f64>().ok())
+                    {
+                        builder.metric(
+                            "all_smi_thermal_pressure_seconds_total",
+                            &[
+                                ("hostname", &chassis.hostname),
+                                ("instance", &chassis.instance),
+                                ("level", level),
+                            ],
+                            secs,
+                        );
+                    }
+                }
+            }
         }
 
         // Export individual power components if available
@@ -288,13 +352,80 @@ impl<'a> MetricExporter for ChassisMetricExporter<'a> {
             }
         }
 
+        if flags.has_system {
+            export_system_metrics(&mut builder, self.chassis_info);
+        }
+
         builder.build()
     }
 }
 
+/// Load average, uptime and the `all_smi_node_info` identity metric
+fn export_system_metrics(builder: &mut MetricBuilder, chassis_info: &[ChassisInfo]) {
+    builder
+        .help(
+            "all_smi_load_average",
+            "System load average over the window",
+        )
+        .type_("all_smi_load_average", "gauge");
+    for chassis in chassis_info {
+        if let Some(system) = &chassis.system {
+            for (window, load) in LOAD_WINDOWS.iter().zip(system.load_average) {
+                builder.metric(
+                    "all_smi_load_average",
+                    &[
+                        ("hostname", &chassis.hostname),
+                        ("instance", &chassis.instance),
+                        ("window", window),
+                    ],
+                    format!("{load:.2}"),
+                );
+            }
+        }
+    }
+
+    builder
+        .help("all_smi_uptime_seconds", "Seconds since the host booted")
+        .type_("all_smi_uptime_seconds", "gauge");
+    for chassis in chassis_info {
+        if let Some(system) = &chassis.system {
+            builder.metric(
+                "all_smi_uptime_seconds",
+                &[
+                    ("hostname", &chassis.hostname),
+                    ("instance", &chassis.instance),
+                ],
+                system.uptime_seconds,
+            );
+        }
+    }
+
+    builder
+        .help(
+            "all_smi_node_info",
+            "Kernel version and OS name of the host",
+        )
+        .type_("all_smi_node_info", "gauge");
+    for chassis in chassis_info {
+        if let Some(system) = &chassis.system {
+            builder.metric(
+                "all_smi_node_info",
+                &[
+                    ("hostname", &chassis.hostname),
+                    ("instance", &chassis.instance),
+                    ("kernel", &system.kernel_version),
+                    ("os", &system.os_name),
+                ],
+                "1",
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::device::HostSystemInfo;
 
     #[test]
     fn test_empty_chassis_info() {
@@ -336,5 +467,62 @@ mod tests {
 
         assert!(metrics.contains("all_smi_chassis_thermal_pressure_info"));
         assert!(metrics.contains("level=\"Nominal\""));
+        assert!(metrics.contains(
+            "all_smi_thermal_pressure_level{hostname=\"mac-host\", instance=\"mac-instance\"} 0\n"
+        ));
+        assert!(!metrics.contains("all_smi_thermal_pressure_seconds_total"));
+    }
+
+    #[test]
+    fn test_thermal_pressure_seconds() {
+        let mut detail = std::collections::HashMap::new();
+        detail.insert("thermal_time_nominal_s".to_string(), "120".to_string());
+        detail.insert("thermal_time_serious_s".to_string(), "4".to_string());
+        detail.insert("thermal_transitions".to_string(), "2".to_string());
+        let chassis = vec![ChassisInfo {
+            hostname: "mac-host".to_string(),
+            instance: "mac-host".to_string(),
+            thermal_pressure: Some("Serious".to_string()),
+            detail,
+            ..Default::default()
+        }];
+        let metrics = ChassisMetricExporter::new(&chassis).export_metrics();
+
+        assert!(metrics.contains("# TYPE all_smi_thermal_pressure_seconds_total counter"));
+        assert!(metrics.contains(
+            "all_smi_thermal_pressure_seconds_total{hostname=\"mac-host\", instance=\"mac-host\", level=\"Serious\"} 4\n"
+        ));
+        assert!(metrics.contains(
+            "all_smi_thermal_pressure_level{hostname=\"mac-host\", instance=\"mac-host\"} 2\n"
+        ));
+    }
+
+    #[test]
+    fn test_system_metrics() {
+        let chassis = vec![ChassisInfo {
+            hostname: "node-1".to_string(),
+            instance: "node-1".to_string(),
+            system: Some(HostSystemInfo {
+                load_average: [3.5, 2.25, 1.0],
+                uptime_seconds: 1_137_600,
+                kernel_version: "6.8.0-45-generic".to_string(),
+                os_name: "Ubuntu 24.04.1 LTS".to_string(),
+            }),
+            ..Default::default()
+        }];
+        let metrics = ChassisMetricExporter::new(&chassis).export_metrics();
+
+        assert!(metrics.contains(
+            "all_smi_load_average{hostname=\"node-1\", instance=\"node-1\", window=\"1m\"} 3.50\n"
+        ));
+        assert!(metrics.contains(
+            "all_smi_load_average{hostname=\"node-1\", instance=\"node-1\", window=\"15m\"} 1.00\n"
+        ));
+        assert!(metrics.contains(
+            "all_smi_uptime_seconds{hostname=\"node-1\", instance=\"node-1\"} 1137600\n"
+        ));
+        assert!(metrics.contains(
+            "all_smi_node_info{hostname=\"node-1\", instance=\"node-1\", kernel=\"6.8.0-45-generic\", os=\"Ubuntu 24.04.1 LTS\"} 1\n"
+        ));
     }
 }
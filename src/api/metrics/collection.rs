@@ -0,0 +1,67 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::UNIX_EPOCH;
+
+use super::{MetricBuilder, MetricExporter};
+use crate::app_state::CollectionStamp;
+
+/// When each collector last finished a pass
+pub struct CollectionMetricExporter<'a> {
+    stamps: &'a BTreeMap<&'static str, CollectionStamp>,
+    hostname: String,
+}
+
+impl<'a> CollectionMetricExporter<'a> {
+    pub fn new(stamps: &'a BTreeMap<&'static str, CollectionStamp>) -> Self {
+        Self {
+            stamps,
+            hostname: crate::utils::get_hostname(),
+        }
+    }
+}
+
+impl<'a> MetricExporter for CollectionMetricExporter<'a> {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+        if self.stamps.is_empty() {
+            return builder.build();
+        }
+
+        builder
+            .help(
+                "all_smi_last_collection_timestamp_seconds",
+                "Unix time at which the collector last finished a pass",
+            )
+            .type_("all_smi_last_collection_timestamp_seconds", "gauge");
+        for (collector, stamp) in self.stamps {
+            let timestamp = stamp
+                .wall
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs_f64())
+                .unwrap_or(0.0);
+            builder.metric(
+                "all_smi_last_collection_timestamp_seconds",
+                &[
+                    ("hostname", self.hostname.as_str()),
+                    ("collector", collector),
+                ],
+                timestamp,
+            );
+        }
+
+        builder.build()
+    }
+}
@@ -51,6 +51,74 @@ impl<'a> DiskMetricExporter<'a> {
                 &labels,
                 info.available_bytes,
             );
+
+        // Inode counts, only for filesystems that report them
+        if let Some(total_inodes) = info.total_inodes {
+            builder
+                .help(
+                    "all_smi_disk_inodes_total",
+                    "Total inodes on the filesystem",
+                )
+                .type_("all_smi_disk_inodes_total", "gauge")
+                .metric("all_smi_disk_inodes_total", &labels, total_inodes);
+        }
+        if let Some(free_inodes) = info.free_inodes {
+            builder
+                .help(
+                    "all_smi_disk_inodes_free",
+                    "Inodes available to unprivileged users",
+                )
+                .type_("all_smi_disk_inodes_free", "gauge")
+                .metric("all_smi_disk_inodes_free", &labels, free_inodes);
+        }
+
+        // Drive health, only where sysfs or smartctl reported it
+        let health = &info.health;
+        if let Some(temperature) = health.temperature_celsius {
+            builder
+                .help(
+                    "all_smi_disk_temperature_celsius",
+                    "Temperature of the hottest drive backing the mount",
+                )
+                .type_("all_smi_disk_temperature_celsius", "gauge")
+                .metric("all_smi_disk_temperature_celsius", &labels, temperature);
+        }
+        if let Some(percentage_used) = health.percentage_used {
+            builder
+                .help(
+                    "all_smi_disk_percentage_used",
+                    "NVMe endurance used in percent, may exceed 100",
+                )
+                .type_("all_smi_disk_percentage_used", "gauge")
+                .metric("all_smi_disk_percentage_used", &labels, percentage_used);
+        }
+        if let Some(spare) = health.available_spare_percent {
+            builder
+                .help(
+                    "all_smi_disk_available_spare_percent",
+                    "NVMe spare capacity remaining in percent",
+                )
+                .type_("all_smi_disk_available_spare_percent", "gauge")
+                .metric("all_smi_disk_available_spare_percent", &labels, spare);
+        }
+        if let Some(reallocated) = health.reallocated_sectors {
+            builder
+                .help(
+                    "all_smi_disk_reallocated_sectors",
+                    "SATA reallocated sector count (SMART attribute 5)",
+                )
+                .type_("all_smi_disk_reallocated_sectors", "gauge")
+                .metric("all_smi_disk_reallocated_sectors", &labels, reallocated);
+        }
+        if let Some(ok) = health.health_ok {
+            builder
+                .help(
+                    "all_smi_disk_health_ok",
+                    "SMART overall self-assessment (1 = passed, 0 = failed)",
+                )
+                .type_("all_smi_disk_health_ok", "gauge")
+                .metric("all_smi_disk_health_ok", &labels, u8::from(ok));
+        }
     }
 }
 
@@ -13,25 +13,102 @@
 // limitations under the License.
 
 use super::{MetricBuilder, MetricExporter};
+use crate::device::device_selection::{device_index, DEVICE_INDEX_KEY};
+use crate::device::gpu_fan::{fan_speeds, fan_targets, is_fan_key};
+use crate::device::gpu_memory::{
+    bar1_memory, reserved_memory, BAR1_TOTAL_KEY, BAR1_USED_KEY, RESERVED_MEMORY_KEY,
+};
+use crate::device::gpu_presence::{is_lost, GPU_LOST_KEY};
+use crate::device::gpu_temperature::{
+    hotspot_temperature, memory_temperature, HOTSPOT_TEMPERATURE_KEY, MEMORY_TEMPERATURE_KEY,
+};
+use crate::device::k8s_allocation::{K8S_ALLOCATED_KEY, K8S_NAMESPACE_KEY, K8S_POD_KEY};
+use crate::device::memory_headroom::{memory_headroom, oom_thresholds, OomRisk};
+use crate::device::power_headroom::{power_headroom, POWER_LIMIT_ENFORCED_KEY};
+use crate::device::readers::nvidia_jetson::{
+    dla_utilization, is_jetson_dynamic_key, rail_power_watts, EMC_FREQUENCY_KEY,
+};
 use crate::device::GpuInfo;
 use crate::parsing::common::sanitize_label_name;
 
+/// Detail keys that change every cycle and are exported as their own series,
+/// kept out of the `all_smi_gpu_info` labels
+const DYNAMIC_DETAIL_KEYS: [&str; 13] = [
+    "fan_speed_rpm",
+    MEMORY_TEMPERATURE_KEY,
+    HOTSPOT_TEMPERATURE_KEY,
+    BAR1_USED_KEY,
+    BAR1_TOTAL_KEY,
+    RESERVED_MEMORY_KEY,
+    "clock_sm_current",
+    "clock_graphics_current",
+    "clock_memory_current",
+    "clock_video_current",
+    "throttle_status",
+    "throttle_reasons",
+    POWER_LIMIT_ENFORCED_KEY,
+];
+
+/// Clock domains exported as `all_smi_gpu_clock_<domain>_mhz`
+const CLOCK_DOMAINS: [(&str, &str); 4] = [
+    ("sm", "Current SM clock in MHz"),
+    ("graphics", "Current graphics clock in MHz"),
+    ("memory", "Current memory clock in MHz"),
+    ("video", "Current video encoder/decoder clock in MHz"),
+];
+
 pub struct GpuMetricExporter<'a> {
     pub gpu_info: &'a [GpuInfo],
+    /// Exported as `all_smi_gpu_stale` on every device when set
+    pub stale: Option<bool>,
+    /// Add `pod`/`namespace` to each allocated device's series
+    pod_labels: bool,
 }
 
 impl<'a> GpuMetricExporter<'a> {
     pub fn new(gpu_info: &'a [GpuInfo]) -> Self {
-        Self { gpu_info }
+        Self {
+            gpu_info,
+            stale: None,
+            pod_labels: super::pod_labels(),
+        }
     }
 
-    fn export_basic_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
-        let base_labels = [
+    /// Add `all_smi_gpu_stale` to each device (`--staleness-policy flag`).
+    pub fn with_stale(mut self, stale: bool) -> Self {
+        self.stale = Some(stale);
+        self
+    }
+
+    /// Add `pod`/`namespace` labels from the kubelet (`--kubernetes`).
+    #[cfg(test)]
+    pub fn with_pod_labels(mut self, pod_labels: bool) -> Self {
+        self.pod_labels = pod_labels;
+        self
+    }
+
+    /// Labels identifying a device's series, plus the pod it is allocated
+    /// to when pod labels are on
+    fn base_labels<'b>(&self, info: &'b GpuInfo, index: &'b str) -> Vec<(&'static str, &'b str)> {
+        let mut labels = vec![
             ("gpu", info.name.as_str()),
             ("instance", info.instance.as_str()),
             ("uuid", info.uuid.as_str()),
-            ("index", &index.to_string()),
+            ("index", index),
         ];
+        if self.pod_labels {
+            for (label, key) in [("pod", K8S_POD_KEY), ("namespace", K8S_NAMESPACE_KEY)] {
+                if let Some(value) = info.detail.get(key) {
+                    labels.push((label, value.as_str()));
+                }
+            }
+        }
+        labels
+    }
+
+    fn export_basic_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
 
         // GPU utilization
         builder
@@ -61,6 +138,57 @@ impl<'a> GpuMetricExporter<'a> {
                 info.total_memory,
             );
 
+        // Driver-reserved memory and the BAR1 aperture, where the driver
+        // reports them
+        if let Some(reserved) = reserved_memory(info) {
+            builder
+                .help(
+                    "all_smi_gpu_memory_reserved_bytes",
+                    "GPU memory reserved by the driver and firmware (including ECC) in bytes",
+                )
+                .type_("all_smi_gpu_memory_reserved_bytes", "gauge")
+                .metric("all_smi_gpu_memory_reserved_bytes", &base_labels, reserved);
+        }
+        if let Some((used, total)) = bar1_memory(info) {
+            builder
+                .help(
+                    "all_smi_gpu_bar1_memory_used_bytes",
+                    "GPU BAR1 memory used in bytes",
+                )
+                .type_("all_smi_gpu_bar1_memory_used_bytes", "gauge")
+                .metric("all_smi_gpu_bar1_memory_used_bytes", &base_labels, used)
+                .help(
+                    "all_smi_gpu_bar1_memory_total_bytes",
+                    "GPU BAR1 memory total in bytes",
+                )
+                .type_("all_smi_gpu_bar1_memory_total_bytes", "gauge")
+                .metric("all_smi_gpu_bar1_memory_total_bytes", &base_labels, total);
+        }
+
+        // Headroom and OOM risk, skipped for devices without a memory total
+        if let Some(headroom) = memory_headroom(info, &[], oom_thresholds()) {
+            builder
+                .help(
+                    "all_smi_gpu_memory_headroom_bytes",
+                    "GPU memory not in use (total minus used) in bytes",
+                )
+                .type_("all_smi_gpu_memory_headroom_bytes", "gauge")
+                .metric(
+                    "all_smi_gpu_memory_headroom_bytes",
+                    &base_labels,
+                    headroom.headroom_bytes,
+                );
+
+            let at_risk = headroom.risk != OomRisk::None;
+            builder
+                .help(
+                    "all_smi_gpu_oom_risk",
+                    "1 if GPU memory headroom is below --oom-warn-pct, 0 otherwise",
+                )
+                .type_("all_smi_gpu_oom_risk", "gauge")
+                .metric("all_smi_gpu_oom_risk", &base_labels, u8::from(at_risk));
+        }
+
         // Temperature
         builder
             .help(
@@ -74,6 +202,34 @@ impl<'a> GpuMetricExporter<'a> {
                 info.temperature,
             );
 
+        // Memory and hotspot sensors, on devices that have them
+        if let Some(celsius) = memory_temperature(info) {
+            builder
+                .help(
+                    "all_smi_gpu_memory_temperature_celsius",
+                    "GPU memory (HBM/GDDR) temperature in celsius",
+                )
+                .type_("all_smi_gpu_memory_temperature_celsius", "gauge")
+                .metric(
+                    "all_smi_gpu_memory_temperature_celsius",
+                    &base_labels,
+                    celsius,
+                );
+        }
+        if let Some(celsius) = hotspot_temperature(info) {
+            builder
+                .help(
+                    "all_smi_gpu_hotspot_temperature_celsius",
+                    "GPU hotspot (junction) temperature in celsius",
+                )
+                .type_("all_smi_gpu_hotspot_temperature_celsius", "gauge")
+                .metric(
+                    "all_smi_gpu_hotspot_temperature_celsius",
+                    &base_labels,
+                    celsius,
+                );
+        }
+
         // Power consumption
         builder
             .help(
@@ -103,6 +259,16 @@ impl<'a> GpuMetricExporter<'a> {
                 info.ane_utilization,
             );
 
+        if let Some(stale) = self.stale {
+            builder
+                .help(
+                    "all_smi_gpu_stale",
+                    "1 if the values are older than --staleness-window, 0 otherwise",
+                )
+                .type_("all_smi_gpu_stale", "gauge")
+                .metric("all_smi_gpu_stale", &base_labels, u8::from(stale));
+        }
+
         // DLA utilization (if available)
         if let Some(dla_util) = info.dla_utilization {
             builder
@@ -110,6 +276,53 @@ impl<'a> GpuMetricExporter<'a> {
                 .type_("all_smi_dla_utilization", "gauge")
                 .metric("all_smi_dla_utilization", &base_labels, dla_util);
         }
+
+        // Video engine utilization; cards without NVENC/NVDEC have no series
+        if let Some(encoder) = info.encoder_utilization {
+            builder
+                .help(
+                    "all_smi_gpu_encoder_utilization",
+                    "Video encoder (NVENC) utilization percentage",
+                )
+                .type_("all_smi_gpu_encoder_utilization", "gauge")
+                .metric("all_smi_gpu_encoder_utilization", &base_labels, encoder);
+        }
+        if let Some(decoder) = info.decoder_utilization {
+            builder
+                .help(
+                    "all_smi_gpu_decoder_utilization",
+                    "Video decoder (NVDEC) utilization percentage",
+                )
+                .type_("all_smi_gpu_decoder_utilization", "gauge")
+                .metric("all_smi_gpu_decoder_utilization", &base_labels, decoder);
+        }
+
+        // NUMA node the device is attached to (multi-node systems only)
+        if let Some(numa_node) = info
+            .detail
+            .get("numa_node")
+            .and_then(|node| node.parse::<u32>().ok())
+        {
+            builder
+                .help("all_smi_gpu_numa_node", "NUMA node the GPU is attached to")
+                .type_("all_smi_gpu_numa_node", "gauge")
+                .metric("all_smi_gpu_numa_node", &base_labels, numa_node);
+        }
+
+        // Kubernetes allocation (--k8s only)
+        if let Some(allocated) = info
+            .detail
+            .get(K8S_ALLOCATED_KEY)
+            .and_then(|allocated| allocated.parse::<bool>().ok())
+        {
+            builder
+                .help(
+                    "all_smi_gpu_allocated",
+                    "1 if the GPU is allocated to a Kubernetes pod, 0 if it is free",
+                )
+                .type_("all_smi_gpu_allocated", "gauge")
+                .metric("all_smi_gpu_allocated", &base_labels, u8::from(allocated));
+        }
     }
 
     fn export_apple_silicon_metrics(
@@ -122,12 +335,8 @@ impl<'a> GpuMetricExporter<'a> {
             return;
         }
 
-        let base_labels = [
-            ("gpu", info.name.as_str()),
-            ("instance", info.instance.as_str()),
-            ("uuid", info.uuid.as_str()),
-            ("index", &index.to_string()),
-        ];
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
 
         // ANE power in watts
         builder
@@ -189,6 +398,13 @@ impl<'a> GpuMetricExporter<'a> {
         let detail_labels: Vec<(String, String)> = info
             .detail
             .iter()
+            .filter(|(k, _)| {
+                !DYNAMIC_DETAIL_KEYS.contains(&k.as_str())
+                    && k.as_str() != GPU_LOST_KEY
+                    && !is_jetson_dynamic_key(k)
+                    && !is_fan_key(k)
+                    && k.as_str() != DEVICE_INDEX_KEY
+            })
             .map(|(k, v)| (sanitize_label_name(k), v.clone()))
             .collect();
 
@@ -213,13 +429,21 @@ impl<'a> GpuMetricExporter<'a> {
         builder.metric("all_smi_gpu_info", &all_labels, 1);
     }
 
+    fn export_up(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
+        builder
+            .help(
+                "all_smi_gpu_up",
+                "1 if the GPU is reported by its driver, 0 if it was seen earlier and is gone",
+            )
+            .type_("all_smi_gpu_up", "gauge")
+            .metric("all_smi_gpu_up", &base_labels, u8::from(!is_lost(info)));
+    }
+
     fn export_cuda_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
-        let base_labels = [
-            ("gpu", info.name.as_str()),
-            ("instance", info.instance.as_str()),
-            ("uuid", info.uuid.as_str()),
-            ("index", &index.to_string()),
-        ];
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
 
         // PCIe metrics
         if let Some(pcie_gen) = info.detail.get("pcie_gen_current") {
@@ -290,6 +514,37 @@ impl<'a> GpuMetricExporter<'a> {
             }
         }
 
+        if let Some(power_limit) = info.detail.get(POWER_LIMIT_ENFORCED_KEY) {
+            if let Ok(power) = power_limit.parse::<f64>() {
+                builder
+                    .help(
+                        "all_smi_gpu_power_limit_enforced_watts",
+                        "Power limit the driver enforces in watts",
+                    )
+                    .type_("all_smi_gpu_power_limit_enforced_watts", "gauge")
+                    .metric(
+                        "all_smi_gpu_power_limit_enforced_watts",
+                        &base_labels,
+                        power,
+                    );
+            }
+        }
+
+        // Negative while the GPU draws more than its limit and is being capped
+        if let Some(headroom) = power_headroom(info) {
+            builder
+                .help(
+                    "all_smi_gpu_power_headroom_watts",
+                    "Power limit minus power draw in watts; negative when capped",
+                )
+                .type_("all_smi_gpu_power_headroom_watts", "gauge")
+                .metric(
+                    "all_smi_gpu_power_headroom_watts",
+                    &base_labels,
+                    format!("{:.2}", headroom.headroom_watts),
+                );
+        }
+
         // Performance state
         if let Some(pstate) = info.detail.get("performance_state") {
             if let Some(state_str) = pstate.strip_prefix('P') {
@@ -307,20 +562,549 @@ impl<'a> GpuMetricExporter<'a> {
     }
 }
 
+impl<'a> GpuMetricExporter<'a> {
+    /// Current clock of each domain the reader breaks out, from the
+    /// `clock_<domain>_current` details. `all_smi_gpu_frequency_mhz` keeps
+    /// reporting the graphics clock.
+    fn export_clock_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
+
+        for (domain, help) in CLOCK_DOMAINS {
+            let Some(clock) = info
+                .detail
+                .get(&format!("clock_{domain}_current"))
+                .and_then(|clock| clock.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            let name = format!("all_smi_gpu_clock_{domain}_mhz");
+            builder
+                .help(&name, help)
+                .type_(&name, "gauge")
+                .metric(&name, &base_labels, clock);
+        }
+    }
+
+    /// Fan and throttle status, which only the AMD reader reports so far.
+    fn export_jetson_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
+
+        if let Some(hz) = info
+            .detail
+            .get(EMC_FREQUENCY_KEY)
+            .and_then(|hz| hz.parse::<u64>().ok())
+        {
+            builder
+                .help(
+                    "all_smi_jetson_emc_frequency_hz",
+                    "Jetson external memory controller clock in Hz",
+                )
+                .type_("all_smi_jetson_emc_frequency_hz", "gauge")
+                .metric("all_smi_jetson_emc_frequency_hz", &base_labels, hz);
+        }
+
+        let rails = rail_power_watts(&info.detail);
+        if !rails.is_empty() {
+            builder
+                .help(
+                    "all_smi_jetson_rail_power_watts",
+                    "Jetson power draw per INA3221 rail in watts",
+                )
+                .type_("all_smi_jetson_rail_power_watts", "gauge");
+        }
+        for (rail, watts) in rails {
+            let mut labels = base_labels.clone();
+            labels.push(("rail", rail));
+            builder.metric("all_smi_jetson_rail_power_watts", &labels, watts);
+        }
+
+        let dlas = dla_utilization(&info.detail);
+        if !dlas.is_empty() {
+            builder
+                .help(
+                    "all_smi_jetson_dla_utilization",
+                    "Jetson utilization per DLA core in percent",
+                )
+                .type_("all_smi_jetson_dla_utilization", "gauge");
+        }
+        for (dla, utilization) in dlas {
+            let dla = dla.to_string();
+            let mut labels = base_labels.clone();
+            labels.push(("dla", dla.as_str()));
+            builder.metric("all_smi_jetson_dla_utilization", &labels, utilization);
+        }
+    }
+
+    /// Per-fan speed and fan curve target in percent; cards without fans
+    /// export neither
+    fn export_fan_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
+
+        for (metric, help, fans) in [
+            (
+                "all_smi_gpu_fan_speed_percent",
+                "GPU fan speed in percent of its maximum",
+                fan_speeds(&info.detail),
+            ),
+            (
+                "all_smi_gpu_fan_target_percent",
+                "GPU fan speed the fan curve asks for, in percent of its maximum",
+                fan_targets(&info.detail),
+            ),
+        ] {
+            if !fans.is_empty() {
+                builder.help(metric, help).type_(metric, "gauge");
+            }
+            for (fan, percent) in fans {
+                let fan = fan.to_string();
+                let mut labels = base_labels.clone();
+                labels.push(("fan", fan.as_str()));
+                builder.metric(metric, &labels, percent);
+            }
+        }
+    }
+
+    fn export_amd_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index_str = index.to_string();
+        let base_labels = self.base_labels(info, &index_str);
+
+        if let Some(rpm) = info
+            .detail
+            .get("fan_speed_rpm")
+            .and_then(|rpm| rpm.parse::<u32>().ok())
+        {
+            builder
+                .help("all_smi_gpu_fan_speed_rpm", "GPU fan speed in RPM")
+                .type_("all_smi_gpu_fan_speed_rpm", "gauge")
+                .metric("all_smi_gpu_fan_speed_rpm", &base_labels, rpm);
+        }
+
+        if let Some(status) = info.detail.get("throttle_status") {
+            let reasons = info
+                .detail
+                .get("throttle_reasons")
+                .map_or("unknown", String::as_str);
+            let throttle_labels = [
+                ("gpu", info.name.as_str()),
+                ("instance", info.instance.as_str()),
+                ("uuid", info.uuid.as_str()),
+                ("index", index_str.as_str()),
+                ("status", status.as_str()),
+                ("reasons", reasons),
+            ];
+            builder
+                .help(
+                    "all_smi_gpu_throttle_status_info",
+                    "GPU throttler state: raw ASIC bits and the power, current, thermal or other limits in effect",
+                )
+                .type_("all_smi_gpu_throttle_status_info", "gauge")
+                .metric("all_smi_gpu_throttle_status_info", &throttle_labels, 1);
+        }
+    }
+}
+
 impl<'a> MetricExporter for GpuMetricExporter<'a> {
     fn export_metrics(&self) -> String {
         let mut builder = MetricBuilder::new();
 
-        for (i, info) in self.gpu_info.iter().enumerate() {
+        for (position, info) in self.gpu_info.iter().enumerate() {
+            let i = device_index(info, position);
             // Export metrics for GPU, NPU, and TPU devices
             if info.device_type == "GPU" || info.device_type == "NPU" || info.device_type == "TPU" {
+                self.export_up(&mut builder, info, i);
+                // A lost device has no readings, only its identity
+                if is_lost(info) {
+                    self.export_device_info(&mut builder, info, i);
+                    continue;
+                }
                 self.export_basic_metrics(&mut builder, info, i);
                 self.export_apple_silicon_metrics(&mut builder, info, i);
                 self.export_device_info(&mut builder, info, i);
                 self.export_cuda_metrics(&mut builder, info, i);
+                self.export_clock_metrics(&mut builder, info, i);
+                self.export_fan_metrics(&mut builder, info, i);
+                self.export_amd_metrics(&mut builder, info, i);
+                self.export_jetson_metrics(&mut builder, info, i);
             }
         }
 
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn amd_gpu(detail: HashMap<String, String>) -> GpuInfo {
+        GpuInfo {
+            uuid: "GPU-0000:03:00.0".to_string(),
+            name: "AMD Instinct MI210".to_string(),
+            device_type: "GPU".to_string(),
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+            instance: "node1".to_string(),
+            utilization: 80.0,
+            temperature: 60,
+            used_memory: 1 << 30,
+            total_memory: 64 << 30,
+            frequency: 1700,
+            power_consumption: 300.0,
+            detail,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_amd_metrics() {
+        let detail = [
+            ("fan_speed_rpm", "1150"),
+            ("clock_memory_current", "1600"),
+            ("throttle_status", "0x00000001"),
+            ("throttle_reasons", "power"),
+            ("vram_vendor", "samsung"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        assert!(metrics.contains(&format!("all_smi_gpu_fan_speed_rpm{{{labels}}} 1150\n")));
+        assert!(metrics.contains(&format!("all_smi_gpu_clock_memory_mhz{{{labels}}} 1600\n")));
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_throttle_status_info{{{labels}, status=\"0x00000001\", reasons=\"power\"}} 1\n"
+        )));
+
+        // Static details stay on the info series, per-cycle ones do not
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(info.contains("vram_vendor=\"samsung\""));
+        assert!(!info.contains("fan_speed_rpm"));
+        assert!(!info.contains("throttle_status"));
+    }
+
+    #[test]
+    fn test_fan_metrics_per_fan() {
+        let detail = [
+            ("fan_speed_percent_0", "43"),
+            ("fan_speed_percent_1", "45"),
+            ("fan_target_percent_0", "50"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        for (fan, speed) in [("0", 43), ("1", 45)] {
+            assert!(metrics.contains(&format!(
+                "all_smi_gpu_fan_speed_percent{{{labels}, fan=\"{fan}\"}} {speed}\n"
+            )));
+        }
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_fan_target_percent{{{labels}, fan=\"0\"}} 50\n"
+        )));
+        assert_eq!(
+            metrics
+                .matches("# TYPE all_smi_gpu_fan_speed_percent gauge")
+                .count(),
+            1
+        );
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(!info.contains("fan_"));
+
+        // Passively cooled cards export no fan series
+        let gpus = vec![amd_gpu(HashMap::new())];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+        assert!(!metrics.contains("all_smi_gpu_fan_speed_percent"));
+    }
+
+    #[test]
+    fn test_power_headroom() {
+        let detail = [
+            ("power_limit_current", "350.00"),
+            ("power_limit_enforced", "280.00"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        // Draws 300 W against the 280 W it is held to
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_power_limit_enforced_watts{{{labels}}} 280\n"
+        )));
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_power_headroom_watts{{{labels}}} -20.00\n"
+        )));
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(!info.contains("power_limit_enforced"));
+    }
+
+    #[test]
+    fn test_memory_and_hotspot_temperatures() {
+        let detail = [("temperature_memory", "71"), ("temperature_hotspot", "88")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_memory_temperature_celsius{{{labels}}} 71\n"
+        )));
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_hotspot_temperature_celsius{{{labels}}} 88\n"
+        )));
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(!info.contains("temperature_"));
+    }
+
+    #[test]
+    fn test_bar1_and_reserved_memory() {
+        let detail = [
+            ("bar1_memory_used", "4194304"),
+            ("bar1_memory_total", "268435456"),
+            ("memory_reserved", "536870912"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        for expected in [
+            format!("all_smi_gpu_bar1_memory_used_bytes{{{labels}}} 4194304\n"),
+            format!("all_smi_gpu_bar1_memory_total_bytes{{{labels}}} 268435456\n"),
+            format!("all_smi_gpu_memory_reserved_bytes{{{labels}}} 536870912\n"),
+        ] {
+            assert!(metrics.contains(&expected), "missing {expected}");
+        }
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(!info.contains("bar1_") && !info.contains("memory_reserved"));
+    }
+
+    #[test]
+    fn test_jetson_metrics() {
+        let detail = [
+            ("emc_frequency_hz", "2133000000"),
+            ("rail_power_mw_VDD_GPU_SOC", "1597"),
+            ("rail_power_mw_VDD_CPU_CV", "400"),
+            ("dla_utilization_0", "12.5"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        for expected in [
+            format!("all_smi_jetson_emc_frequency_hz{{{labels}}} 2133000000\n"),
+            format!("all_smi_jetson_rail_power_watts{{{labels}, rail=\"VDD_CPU_CV\"}} 0.4\n"),
+            format!("all_smi_jetson_rail_power_watts{{{labels}, rail=\"VDD_GPU_SOC\"}} 1.597\n"),
+            format!("all_smi_jetson_dla_utilization{{{labels}, dla=\"0\"}} 12.5\n"),
+        ] {
+            assert!(metrics.contains(&expected), "missing {expected}");
+        }
+        assert_eq!(
+            metrics
+                .matches("# HELP all_smi_jetson_rail_power_watts")
+                .count(),
+            1
+        );
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(!info.contains("emc_") && !info.contains("rail_") && !info.contains("dla_"));
+    }
+
+    #[test]
+    fn test_selected_devices_keep_their_index() {
+        let detail = [("index", "5")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        assert!(metrics.contains(
+            r#"all_smi_gpu_utilization{gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="5"}"#
+        ));
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert_eq!(info.matches("index=").count(), 1);
+    }
+
+    #[test]
+    fn test_amd_metrics_absent_without_details() {
+        let gpus = vec![amd_gpu(HashMap::new())];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+        assert!(!metrics.contains("all_smi_gpu_memory_temperature_celsius"));
+        assert!(!metrics.contains("all_smi_gpu_hotspot_temperature_celsius"));
+        assert!(!metrics.contains("all_smi_gpu_bar1_memory"));
+        assert!(!metrics.contains("all_smi_gpu_memory_reserved_bytes"));
+        assert!(!metrics.contains("all_smi_gpu_fan_speed_rpm"));
+        assert!(!metrics.contains("all_smi_gpu_clock_memory_mhz"));
+        assert!(!metrics.contains("all_smi_gpu_throttle_status_info"));
+        assert!(!metrics.contains("all_smi_jetson_"));
+    }
+
+    #[test]
+    fn test_clock_domain_metrics() {
+        let detail = [
+            ("clock_sm_current", "1980"),
+            ("clock_graphics_current", "1980"),
+            ("clock_memory_current", "2619"),
+            ("clock_video_current", "1755"),
+            ("clock_graphics_max", "1980"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let mut gpu = amd_gpu(detail);
+        gpu.name = "NVIDIA H100".to_string();
+        let metrics = GpuMetricExporter::new(&[gpu]).export_metrics();
+
+        let labels = r#"gpu="NVIDIA H100", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+        for (domain, clock) in [
+            ("sm", 1980),
+            ("graphics", 1980),
+            ("memory", 2619),
+            ("video", 1755),
+        ] {
+            assert!(metrics.contains(&format!(
+                "all_smi_gpu_clock_{domain}_mhz{{{labels}}} {clock}\n"
+            )));
+        }
+        assert!(metrics.contains("# TYPE all_smi_gpu_clock_video_mhz gauge\n"));
+        assert!(metrics.contains(&format!("all_smi_gpu_frequency_mhz{{{labels}}} 1700\n")));
+
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(!info.contains("clock_sm_current"));
+        assert!(!info.contains("clock_video_current"));
+    }
+
+    #[test]
+    fn test_pod_labels_only_with_flag() {
+        let detail = [(K8S_POD_KEY, "train-0"), (K8S_NAMESPACE_KEY, "ml")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let gpus = vec![amd_gpu(detail)];
+        let labels =
+            r#"gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0""#;
+
+        let metrics = GpuMetricExporter::new(&gpus)
+            .with_pod_labels(true)
+            .export_metrics();
+        assert!(metrics.contains(&format!(
+            "all_smi_gpu_utilization{{{labels}, pod=\"train-0\", namespace=\"ml\"}} 80\n"
+        )));
+
+        // Without the flag the series keep their identity and the pod is
+        // only on all_smi_gpu_info
+        let metrics = GpuMetricExporter::new(&gpus)
+            .with_pod_labels(false)
+            .export_metrics();
+        assert!(metrics.contains(&format!("all_smi_gpu_utilization{{{labels}}} 80\n")));
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{"))
+            .unwrap();
+        assert!(info.contains(r#"k8s_pod="train-0""#));
+        assert!(info.contains(r#"k8s_namespace="ml""#));
+    }
+
+    #[test]
+    fn test_eight_gpus_describe_each_metric_once() {
+        let gpus: Vec<GpuInfo> = (0..8)
+            .map(|i| {
+                let detail = [
+                    ("fan_speed_rpm", "1150"),
+                    ("clock_memory_current", "1600"),
+                    ("throttle_status", "0x00000001"),
+                ]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+                GpuInfo {
+                    uuid: format!("GPU-{i}"),
+                    ..amd_gpu(detail)
+                }
+            })
+            .collect();
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        let mut help_lines = std::collections::HashSet::new();
+        for line in metrics.lines().filter(|line| line.starts_with("# HELP ")) {
+            assert!(help_lines.insert(line), "{line} written twice");
+        }
+        let type_lines = metrics.lines().filter(|line| line.starts_with("# TYPE "));
+        assert_eq!(type_lines.count(), help_lines.len());
+
+        // Every device still has its samples
+        assert_eq!(metrics.matches("all_smi_gpu_utilization{").count(), 8);
+        let samples = metrics.lines().filter(|line| !line.starts_with('#'));
+        assert!(samples.count() >= 8 * help_lines.len());
+    }
+
+    #[test]
+    fn test_lost_gpu_exports_only_identity_and_up() {
+        let mut lost = GpuInfo {
+            uuid: "GPU-1".to_string(),
+            ..amd_gpu(HashMap::new())
+        };
+        lost.detail
+            .insert(GPU_LOST_KEY.to_string(), "true".to_string());
+        let gpus = vec![amd_gpu(HashMap::new()), lost];
+        let metrics = GpuMetricExporter::new(&gpus).export_metrics();
+
+        assert!(metrics.contains(r#"all_smi_gpu_up{gpu="AMD Instinct MI210", instance="node1", uuid="GPU-0000:03:00.0", index="0"} 1"#));
+        assert!(metrics.contains(r#"all_smi_gpu_up{gpu="AMD Instinct MI210", instance="node1", uuid="GPU-1", index="1"} 0"#));
+        assert_eq!(metrics.matches("all_smi_gpu_utilization{").count(), 1);
+        assert!(!metrics.contains(r#"uuid="GPU-1", index="1"} 80"#));
+        let info = metrics
+            .lines()
+            .find(|line| line.starts_with("all_smi_gpu_info{") && line.contains("GPU-1"))
+            .unwrap();
+        assert!(!info.contains("lost="));
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MetricBuilder, MetricExporter};
+use crate::network::IbPortInfo;
+
+/// InfiniBand port link state and traffic counters from cached IbPortInfo
+pub struct InfinibandMetricExporter<'a> {
+    ports: &'a [IbPortInfo],
+}
+
+impl<'a> InfinibandMetricExporter<'a> {
+    pub fn new(ports: &'a [IbPortInfo]) -> Self {
+        Self { ports }
+    }
+
+    /// One HELP/TYPE header followed by a sample for every port that
+    /// reports a value.
+    fn export_series(
+        &self,
+        builder: &mut MetricBuilder,
+        name: &str,
+        help: &str,
+        metric_type: &str,
+        value: impl Fn(&IbPortInfo) -> Option<String>,
+    ) {
+        let samples: Vec<(&IbPortInfo, String)> = self
+            .ports
+            .iter()
+            .filter_map(|port| value(port).map(|v| (port, v)))
+            .collect();
+        if samples.is_empty() {
+            return;
+        }
+
+        builder.help(name, help).type_(name, metric_type);
+        for (port, value) in samples {
+            let port_number = port.port.to_string();
+            let labels = [
+                ("instance", port.hostname.as_str()),
+                ("device", port.device.as_str()),
+                ("port", port_number.as_str()),
+            ];
+            builder.metric(name, &labels, value);
+        }
+    }
+}
+
+impl<'a> MetricExporter for InfinibandMetricExporter<'a> {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_xmit_bytes_total",
+            "Bytes transmitted on the InfiniBand port",
+            "counter",
+            |port| Some(port.xmit_bytes.to_string()),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_rcv_bytes_total",
+            "Bytes received on the InfiniBand port",
+            "counter",
+            |port| Some(port.rcv_bytes.to_string()),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_xmit_wait_total",
+            "Ticks the port had data to send but no flow-control credits",
+            "counter",
+            |port| port.xmit_wait.map(|v| v.to_string()),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_xmit_discards_total",
+            "Outbound packets discarded by the port",
+            "counter",
+            |port| port.xmit_discards.map(|v| v.to_string()),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_rcv_errors_total",
+            "Inbound packets with errors",
+            "counter",
+            |port| port.rcv_errors.map(|v| v.to_string()),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_link_up",
+            "Whether the port is ACTIVE (1) or not (0)",
+            "gauge",
+            |port| Some(u8::from(port.is_active()).to_string()),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_ib_port_rate_gbps",
+            "Link signalling rate in Gb/s",
+            "gauge",
+            |port| port.rate_gbps.map(|v| v.to_string()),
+        );
+
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn port(device: &str, state: &str, xmit_wait: Option<u64>) -> IbPortInfo {
+        IbPortInfo {
+            device: device.to_string(),
+            port: 1,
+            state: state.to_string(),
+            rate_gbps: Some(200.0),
+            xmit_bytes: 4096,
+            rcv_bytes: 1024,
+            xmit_wait,
+            xmit_discards: None,
+            rcv_errors: None,
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_infiniband_metrics_export() {
+        let ports = vec![
+            port("mlx5_0", "ACTIVE", Some(7)),
+            port("mlx5_1", "DOWN", None),
+        ];
+        let output = InfinibandMetricExporter::new(&ports).export_metrics();
+
+        assert!(output.contains(
+            "all_smi_ib_port_xmit_bytes_total{instance=\"node1\", device=\"mlx5_0\", port=\"1\"} 4096\n"
+        ));
+        assert!(output.contains(
+            "all_smi_ib_port_link_up{instance=\"node1\", device=\"mlx5_1\", port=\"1\"} 0\n"
+        ));
+        assert_eq!(
+            output.matches("all_smi_ib_port_xmit_wait_total{").count(),
+            1
+        );
+        // Counters no port reports are left out entirely
+        assert!(!output.contains("all_smi_ib_port_xmit_discards_total"));
+        assert_eq!(
+            output
+                .matches("# HELP all_smi_ib_port_rcv_bytes_total")
+                .count(),
+            1
+        );
+    }
+}
@@ -114,6 +114,110 @@ impl<'a> MemoryMetricExporter<'a> {
             );
     }
 
+    fn export_pressure_metrics(
+        &self,
+        builder: &mut MetricBuilder,
+        info: &MemoryInfo,
+        index: usize,
+    ) {
+        let index = index.to_string();
+        let base_labels = [
+            ("instance", info.instance.as_str()),
+            ("hostname", info.hostname.as_str()),
+            ("index", index.as_str()),
+        ];
+
+        // PSI (Linux): share of time tasks stalled on memory
+        if let Some(psi) = &info.psi {
+            let lines = [("some", Some(&psi.some)), ("full", psi.full.as_ref())];
+            for (kind, line) in lines {
+                let Some(line) = line else {
+                    continue;
+                };
+                for (window, avg) in line.windows() {
+                    let labels = [
+                        base_labels[0],
+                        base_labels[1],
+                        base_labels[2],
+                        ("kind", kind),
+                        ("window", window),
+                    ];
+                    builder
+                        .help(
+                            "all_smi_memory_pressure_ratio",
+                            "Share of time tasks stalled on memory over the window (PSI)",
+                        )
+                        .type_("all_smi_memory_pressure_ratio", "gauge")
+                        .metric(
+                            "all_smi_memory_pressure_ratio",
+                            &labels,
+                            // PSI has two decimals of percent
+                            format!("{:.4}", avg / 100.0),
+                        );
+                }
+            }
+            for (kind, line) in lines {
+                let Some(line) = line else {
+                    continue;
+                };
+                let labels = [
+                    base_labels[0],
+                    base_labels[1],
+                    base_labels[2],
+                    ("kind", kind),
+                ];
+                builder
+                    .help(
+                        "all_smi_memory_stall_seconds_total",
+                        "Total time tasks stalled on memory (PSI)",
+                    )
+                    .type_("all_smi_memory_stall_seconds_total", "counter")
+                    .metric(
+                        "all_smi_memory_stall_seconds_total",
+                        &labels,
+                        line.total_us as f64 / 1e6,
+                    );
+            }
+        }
+
+        // Pressure level and compressor (macOS)
+        if let Some(level) = info.pressure_level {
+            builder
+                .help(
+                    "all_smi_memory_pressure_level",
+                    "Memory pressure level (0=normal, 1=warning, 2=critical)",
+                )
+                .type_("all_smi_memory_pressure_level", "gauge")
+                .metric("all_smi_memory_pressure_level", &base_labels, level.value());
+        }
+        if let Some(compressed) = info.compressed_bytes {
+            builder
+                .help(
+                    "all_smi_memory_compressed_bytes",
+                    "Memory held by the compressor in bytes",
+                )
+                .type_("all_smi_memory_compressed_bytes", "gauge")
+                .metric("all_smi_memory_compressed_bytes", &base_labels, compressed);
+        }
+
+        // Swap activity
+        if let Some(swap_in) = info.swap_in_bytes_total {
+            builder
+                .help("all_smi_swap_in_bytes_total", "Bytes swapped in since boot")
+                .type_("all_smi_swap_in_bytes_total", "counter")
+                .metric("all_smi_swap_in_bytes_total", &base_labels, swap_in);
+        }
+        if let Some(swap_out) = info.swap_out_bytes_total {
+            builder
+                .help(
+                    "all_smi_swap_out_bytes_total",
+                    "Bytes swapped out since boot",
+                )
+                .type_("all_smi_swap_out_bytes_total", "counter")
+                .metric("all_smi_swap_out_bytes_total", &base_labels, swap_out);
+        }
+    }
+
     fn export_linux_specific_metrics(
         &self,
         builder: &mut MetricBuilder,
@@ -165,6 +269,7 @@ impl<'a> MetricExporter for MemoryMetricExporter<'a> {
         for (i, info) in self.memory_info.iter().enumerate() {
             self.export_basic_metrics(&mut builder, info, i);
             self.export_swap_metrics(&mut builder, info, i);
+            self.export_pressure_metrics(&mut builder, info, i);
             self.export_linux_specific_metrics(&mut builder, info, i);
         }
 
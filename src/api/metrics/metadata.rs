@@ -0,0 +1,164 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Central registry of metric metadata.
+//!
+//! Every exporter writes through [`MetricBuilder`](super::MetricBuilder),
+//! which records the HELP text, TYPE and label names of each metric and
+//! hands them to this registry when the builder is finished. `/metadata`
+//! serves the result.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+use all_smi::traits::{MetricMetadata, MetricType};
+
+static REGISTRY: OnceLock<Mutex<BTreeMap<String, MetricMetadata>>> = OnceLock::new();
+
+/// Name suffixes that state the unit, following the Prometheus naming
+/// convention (`_total` is stripped first).
+const UNIT_SUFFIXES: &[&str] = &[
+    "bytes", "celsius", "watts", "seconds", "mhz", "gbps", "rpm", "percent", "ratio",
+];
+
+/// Unit named by the metric's suffix, e.g. `bytes` for
+/// `all_smi_disk_read_bytes_total`.
+pub fn unit_from_name(name: &str) -> Option<String> {
+    let base = name.strip_suffix("_total").unwrap_or(name);
+    let suffix = base.rsplit('_').next()?;
+    UNIT_SUFFIXES.contains(&suffix).then(|| suffix.to_string())
+}
+
+/// Empty metadata entry for `name`, to be filled in by the builder.
+pub(super) fn new_entry(name: &str) -> MetricMetadata {
+    MetricMetadata {
+        name: name.to_string(),
+        help: None,
+        unit: unit_from_name(name),
+        metric_type: MetricType::Untyped,
+        labels: Default::default(),
+    }
+}
+
+/// Merge metadata recorded by one builder into the registry. Later HELP and
+/// TYPE lines win; label names accumulate.
+pub(super) fn register(entries: BTreeMap<String, MetricMetadata>) {
+    if entries.is_empty() {
+        return;
+    }
+    let registry = REGISTRY.get_or_init(|| Mutex::new(BTreeMap::new()));
+    let Ok(mut registry) = registry.lock() else {
+        return;
+    };
+    for (name, entry) in entries {
+        match registry.get_mut(&name) {
+            Some(existing) => {
+                if entry.help.is_some() {
+                    existing.help = entry.help;
+                }
+                if entry.metric_type != MetricType::Untyped {
+                    existing.metric_type = entry.metric_type;
+                }
+                for (label, example) in entry.labels {
+                    existing.labels.entry(label).or_insert(example);
+                }
+            }
+            None => {
+                registry.insert(name, entry);
+            }
+        }
+    }
+}
+
+/// Everything registered so far, sorted by metric name.
+pub fn registered_metadata() -> Vec<MetricMetadata> {
+    REGISTRY
+        .get()
+        .and_then(|registry| registry.lock().ok().map(|r| r.values().cloned().collect()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metrics::MetricBuilder;
+
+    #[test]
+    fn test_unit_from_name() {
+        assert_eq!(
+            unit_from_name("all_smi_disk_read_bytes_total").as_deref(),
+            Some("bytes")
+        );
+        assert_eq!(
+            unit_from_name("all_smi_gpu_temperature_celsius").as_deref(),
+            Some("celsius")
+        );
+        assert_eq!(unit_from_name("all_smi_gpu_utilization"), None);
+    }
+
+    #[test]
+    fn test_builder_registers_metadata() {
+        let mut builder = MetricBuilder::with_label_allowlist(None);
+        builder
+            .help("all_smi_test_registry_watts", "Test power draw")
+            .type_("all_smi_test_registry_watts", "gauge")
+            .metric(
+                "all_smi_test_registry_watts",
+                &[("instance", "node1"), ("index", "0")],
+                12.5,
+            )
+            .metric("all_smi_test_registry_untyped", &[], 1);
+        builder.build();
+
+        let metadata = registered_metadata();
+        let power = metadata
+            .iter()
+            .find(|m| m.name == "all_smi_test_registry_watts")
+            .unwrap();
+        assert_eq!(power.help.as_deref(), Some("Test power draw"));
+        assert_eq!(power.metric_type, MetricType::Gauge);
+        assert_eq!(power.unit.as_deref(), Some("watts"));
+        assert_eq!(
+            power.labels.get("instance").map(String::as_str),
+            Some("node1")
+        );
+        assert!(power.labels.contains_key("index"));
+        let json = serde_json::to_value(power).unwrap();
+        assert_eq!(json["type"], "gauge");
+        assert_eq!(json["labels"]["index"], "0");
+
+        let untyped = metadata
+            .iter()
+            .find(|m| m.name == "all_smi_test_registry_untyped")
+            .unwrap();
+        assert_eq!(untyped.metric_type, MetricType::Untyped);
+        assert!(untyped.labels.is_empty());
+    }
+
+    #[test]
+    fn test_register_merges_labels() {
+        let name = "all_smi_test_registry_merge";
+        for label in ["gpu", "uuid"] {
+            let mut builder = MetricBuilder::with_label_allowlist(None);
+            builder.metric(name, &[(label, "x")], 1);
+            builder.build();
+        }
+
+        let metadata = registered_metadata();
+        let merged = metadata.iter().find(|m| m.name == name).unwrap();
+        let mut labels: Vec<&str> = merged.labels.keys().map(String::as_str).collect();
+        labels.sort();
+        assert_eq!(labels, vec!["gpu", "uuid"]);
+    }
+}
@@ -12,14 +12,111 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod build_info;
 pub mod chassis;
+pub mod collection;
 pub mod cpu;
 pub mod disk;
 pub mod gpu;
+pub mod infiniband;
 pub mod memory;
+pub mod metadata;
+pub mod network;
 pub mod npu;
+pub mod privileges;
 pub mod process;
+pub mod push;
 pub mod runtime;
+pub mod scrape;
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+use all_smi::traits::{MetricMetadata, MetricType};
+
+use crate::common::config::METRIC_IDENTITY_LABELS;
+
+/// Identity labels kept on exported series (`api.labels`); unset keeps all.
+static LABEL_ALLOWLIST: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Whether GPU and process series carry `pod`/`namespace` (`--kubernetes`).
+static POD_LABELS: OnceLock<bool> = OnceLock::new();
+
+/// Labels added to every series, such as the Backend.AI session (`session_id`,
+/// `kernel_id`, `cluster_role`).
+static SESSION_LABELS: OnceLock<Vec<(&'static str, String)>> = OnceLock::new();
+
+/// Metrics already reported as colliding, so each is logged only once.
+static WARNED_COLLISIONS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// Restrict the identity labels on every exported series. An empty list
+/// keeps all labels. Only the first call takes effect.
+pub fn set_label_allowlist(labels: Vec<String>) {
+    if !labels.is_empty() {
+        let _ = LABEL_ALLOWLIST.set(labels);
+    }
+}
+
+/// Add `pod` and `namespace` labels to the series of devices allocated to a
+/// pod. Off by default so existing series keep their identity. Only the
+/// first call takes effect.
+pub fn set_pod_labels(enabled: bool) {
+    let _ = POD_LABELS.set(enabled);
+}
+
+fn pod_labels() -> bool {
+    POD_LABELS.get().copied().unwrap_or(false)
+}
+
+/// Add `labels` to every series exported from now on. Only the first call
+/// takes effect.
+pub fn set_session_labels(labels: Vec<(&'static str, String)>) {
+    let _ = SESSION_LABELS.set(labels);
+}
+
+/// Escape a label value for the exposition format: backslash, double quote,
+/// line feed and tab. A raw line feed would end the sample mid-label and
+/// fail the whole scrape.
+pub fn escape_label_value(value: &str) -> Cow<'_, str> {
+    if !value.contains(['\\', '"', '\n', '\t']) {
+        return Cow::Borrowed(value);
+    }
+    let mut escaped = String::with_capacity(value.len() + 8);
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(ch),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Make `name` a valid metric name (`[a-zA-Z_:][a-zA-Z0-9_:]*`) by replacing
+/// every invalid character with `_`.
+pub fn sanitize_metric_name(name: &str) -> Cow<'_, str> {
+    let valid = |index: usize, ch: char| {
+        ch.is_ascii_alphabetic() || ch == '_' || ch == ':' || (index > 0 && ch.is_ascii_digit())
+    };
+    if !name.is_empty() && name.chars().enumerate().all(|(i, ch)| valid(i, ch)) {
+        return Cow::Borrowed(name);
+    }
+    let sanitized: String = name
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| if valid(i, ch) { ch } else { '_' })
+        .collect();
+    let sanitized = if sanitized.is_empty() {
+        "_".to_string()
+    } else {
+        sanitized
+    };
+    tracing::debug!("Invalid metric name {name:?} exported as {sanitized}");
+    Cow::Owned(sanitized)
+}
 
 /// Trait for exporting metrics in Prometheus format
 pub trait MetricExporter {
@@ -30,12 +127,62 @@ pub trait MetricExporter {
 /// Helper struct to build Prometheus metrics
 pub struct MetricBuilder {
     metrics: String,
+    allowlist: Option<Vec<String>>,
+    /// Series written so far, tracked only while labels are filtered
+    seen: HashSet<String>,
+    /// Metrics whose HELP or TYPE line was already written. Exporters
+    /// describe a metric once per device; only the first line is kept.
+    helped: HashSet<String>,
+    typed: HashSet<String>,
+    /// HELP/TYPE/labels of the metrics written, handed to the metadata
+    /// registry on `build`
+    metadata: BTreeMap<String, MetricMetadata>,
+    /// Appended to every series that does not set them itself
+    session_labels: &'static [(&'static str, String)],
 }
 
 impl MetricBuilder {
     pub fn new() -> Self {
+        Self::with_label_allowlist(LABEL_ALLOWLIST.get().cloned())
+            .with_session_labels(SESSION_LABELS.get().map_or(&[], Vec::as_slice))
+    }
+
+    /// Builder that drops identity labels not in `allowlist`.
+    pub fn with_label_allowlist(allowlist: Option<Vec<String>>) -> Self {
         Self {
             metrics: String::new(),
+            allowlist: allowlist.filter(|labels| !labels.is_empty()),
+            seen: HashSet::new(),
+            helped: HashSet::new(),
+            typed: HashSet::new(),
+            metadata: BTreeMap::new(),
+            session_labels: &[],
+        }
+    }
+
+    /// Builder that appends `labels` to every series.
+    pub fn with_session_labels(mut self, labels: &'static [(&'static str, String)]) -> Self {
+        self.session_labels = labels;
+        self
+    }
+
+    fn metadata_entry(&mut self, name: &str) -> &mut MetricMetadata {
+        // Called for every sample, so avoid allocating the key once known
+        if !self.metadata.contains_key(name) {
+            self.metadata
+                .insert(name.to_string(), metadata::new_entry(name));
+        }
+        self.metadata
+            .get_mut(name)
+            .expect("metadata entry was just inserted")
+    }
+
+    fn keeps_label(&self, key: &str) -> bool {
+        match &self.allowlist {
+            Some(allowlist) => {
+                !METRIC_IDENTITY_LABELS.contains(&key) || allowlist.iter().any(|l| l == key)
+            }
+            None => true,
         }
     }
 
@@ -50,15 +197,29 @@ impl MetricBuilder {
 
     /// Add a HELP line
     pub fn help(&mut self, name: &str, description: &str) -> &mut Self {
+        let name = sanitize_metric_name(name);
+        if self.helped.contains(name.as_ref()) {
+            return self;
+        }
+        self.metadata_entry(&name).help = Some(description.to_string());
+        // HELP text escapes only backslash and line feed
+        let description = description.replace('\\', "\\\\").replace('\n', "\\n");
         self.metrics
             .push_str(&format!("# HELP {name} {description}\n"));
+        self.helped.insert(name.into_owned());
         self
     }
 
     /// Add a TYPE line
     pub fn type_(&mut self, name: &str, metric_type: &str) -> &mut Self {
+        let name = sanitize_metric_name(name);
+        if self.typed.contains(name.as_ref()) {
+            return self;
+        }
+        self.metadata_entry(&name).metric_type = MetricType::from_prometheus(metric_type);
         self.metrics
             .push_str(&format!("# TYPE {name} {metric_type}\n"));
+        self.typed.insert(name.into_owned());
         self
     }
 
@@ -69,21 +230,48 @@ impl MetricBuilder {
         labels: &[(&str, &str)],
         value: impl ToString,
     ) -> &mut Self {
+        let name = sanitize_metric_name(name);
+        let name = name.as_ref();
+        let line_start = self.metrics.len();
         self.metrics.push_str(name);
+        let mut kept_labels = Vec::with_capacity(labels.len());
 
-        if !labels.is_empty() {
-            self.metrics.push('{');
-            for (i, (key, value)) in labels.iter().enumerate() {
-                if i > 0 {
-                    self.metrics.push_str(", ");
-                }
-                // Escape quotes in values for Prometheus format
-                let escaped_value = value.replace('"', "\\\"");
-                self.metrics.push_str(&format!("{key}=\"{escaped_value}\""));
+        let session_labels: &'static [(&'static str, String)] = self.session_labels;
+        let session_labels = session_labels
+            .iter()
+            .filter(|(key, _)| !labels.iter().any(|(label, _)| label == key))
+            .map(|(key, value)| (*key, value.as_str()));
+        let mut written = 0;
+        for (key, value) in labels.iter().copied().chain(session_labels) {
+            if !self.keeps_label(key) {
+                continue;
             }
+            self.metrics.push_str(if written == 0 { "{" } else { ", " });
+            let escaped_value = escape_label_value(value);
+            self.metrics.push_str(&format!("{key}=\"{escaped_value}\""));
+            kept_labels.push((key, value));
+            written += 1;
+        }
+        if written > 0 {
             self.metrics.push('}');
         }
 
+        let entry = self.metadata_entry(name);
+        for (key, value) in kept_labels {
+            if !entry.labels.contains_key(key) {
+                entry.labels.insert(key.to_string(), value.to_string());
+            }
+        }
+
+        // With identity labels dropped, two devices can map to the same
+        // series. Prometheus rejects the whole scrape on duplicates, so keep
+        // only the first.
+        if self.allowlist.is_some() && !self.seen.insert(self.metrics[line_start..].to_string()) {
+            self.metrics.truncate(line_start);
+            warn_collision(name);
+            return self;
+        }
+
         self.metrics.push(' ');
         self.metrics.push_str(&value.to_string());
         self.metrics.push('\n');
@@ -92,6 +280,7 @@ impl MetricBuilder {
 
     /// Build the final metric string
     pub fn build(self) -> String {
+        metadata::register(self.metadata);
         self.metrics
     }
 }
@@ -101,3 +290,192 @@ impl Default for MetricBuilder {
         Self::new()
     }
 }
+
+fn warn_collision(name: &str) {
+    let warned = WARNED_COLLISIONS.get_or_init(|| Mutex::new(HashSet::new()));
+    if let Ok(mut warned) = warned.lock() {
+        if warned.insert(name.to_string()) {
+            tracing::warn!(
+                "Dropping duplicate {name} series: the configured api.labels cannot tell \
+                 devices apart; keep 'uuid' or 'index' to export all of them"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gpu_labels<'a>(uuid: &'a str, index: &'a str) -> [(&'a str, &'a str); 4] {
+        [
+            ("gpu", "NVIDIA H100"),
+            ("instance", "node1"),
+            ("uuid", uuid),
+            ("index", index),
+        ]
+    }
+
+    #[test]
+    fn test_default_keeps_all_labels() {
+        let mut builder = MetricBuilder::with_label_allowlist(None);
+        builder.metric("all_smi_gpu_utilization", &gpu_labels("GPU-0", "0"), 50);
+        assert_eq!(
+            builder.build(),
+            "all_smi_gpu_utilization{gpu=\"NVIDIA H100\", instance=\"node1\", uuid=\"GPU-0\", index=\"0\"} 50\n"
+        );
+    }
+
+    #[test]
+    fn test_session_labels_on_every_series() {
+        let session: &'static [(&'static str, String)] = Box::leak(Box::new([
+            ("session_id", "3f2a".to_string()),
+            ("kernel_id", "9c1d".to_string()),
+        ]));
+        let mut builder = MetricBuilder::with_label_allowlist(None).with_session_labels(session);
+        builder
+            .metric("all_smi_gpu_utilization", &gpu_labels("GPU-0", "0"), 50)
+            .metric("all_smi_gpu_count", &[], 1)
+            // A label the series sets itself is not repeated
+            .metric("all_smi_process_count", &[("kernel_id", "own")], 2);
+        assert_eq!(
+            builder.build(),
+            "all_smi_gpu_utilization{gpu=\"NVIDIA H100\", instance=\"node1\", uuid=\"GPU-0\", index=\"0\", session_id=\"3f2a\", kernel_id=\"9c1d\"} 50\n\
+             all_smi_gpu_count{session_id=\"3f2a\", kernel_id=\"9c1d\"} 1\n\
+             all_smi_process_count{kernel_id=\"own\", session_id=\"3f2a\"} 2\n"
+        );
+    }
+
+    #[test]
+    fn test_allowlist_drops_identity_labels() {
+        let allowlist = vec!["instance".to_string(), "index".to_string()];
+        let mut builder = MetricBuilder::with_label_allowlist(Some(allowlist));
+        builder
+            .metric("all_smi_gpu_utilization", &gpu_labels("GPU-0", "0"), 50)
+            .metric(
+                "all_smi_cpu_core_utilization",
+                &[("hostname", "node1"), ("core_id", "3")],
+                10,
+            )
+            .metric("all_smi_gpu_count", &[("uuid", "GPU-0")], 1);
+        assert_eq!(
+            builder.build(),
+            "all_smi_gpu_utilization{instance=\"node1\", index=\"0\"} 50\n\
+             all_smi_cpu_core_utilization{core_id=\"3\"} 10\n\
+             all_smi_gpu_count 1\n"
+        );
+    }
+
+    #[test]
+    fn test_colliding_series_are_dropped() {
+        let allowlist = vec!["gpu".to_string(), "instance".to_string()];
+        let mut builder = MetricBuilder::with_label_allowlist(Some(allowlist));
+        builder
+            .help("all_smi_gpu_utilization", "GPU utilization percentage")
+            .metric("all_smi_gpu_utilization", &gpu_labels("GPU-0", "0"), 50)
+            .metric("all_smi_gpu_utilization", &gpu_labels("GPU-1", "1"), 70)
+            .metric(
+                "all_smi_gpu_temperature_celsius",
+                &gpu_labels("GPU-1", "1"),
+                60,
+            );
+        assert_eq!(
+            builder.build(),
+            "# HELP all_smi_gpu_utilization GPU utilization percentage\n\
+             all_smi_gpu_utilization{gpu=\"NVIDIA H100\", instance=\"node1\"} 50\n\
+             all_smi_gpu_temperature_celsius{gpu=\"NVIDIA H100\", instance=\"node1\"} 60\n"
+        );
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        let mut builder = MetricBuilder::with_label_allowlist(None);
+        builder.metric(
+            "all_smi_gpu_info",
+            &[("gpu", "Vendor \"X\"\\GPU\n"), ("serial", "a\tb")],
+            1,
+        );
+        assert_eq!(
+            builder.build(),
+            "all_smi_gpu_info{gpu=\"Vendor \\\"X\\\"\\\\GPU\\n\", serial=\"a\\tb\"} 1\n"
+        );
+    }
+
+    #[test]
+    fn test_escaping_round_trips_through_parser() {
+        use crate::parsing::prometheus::{parse_line, LabelMap};
+
+        // Every string of up to four characters over an alphabet of the
+        // characters that need escaping, plus plain and multibyte ones
+        let alphabet = ['\\', '"', '\n', '\t', 'n', 't', 'a', ' ', '}', ',', 'é'];
+        let mut values = vec![String::new()];
+        let mut frontier = vec![String::new()];
+        for _ in 0..4 {
+            frontier = frontier
+                .iter()
+                .flat_map(|prefix| {
+                    alphabet.iter().map(move |ch| {
+                        let mut value = prefix.clone();
+                        value.push(*ch);
+                        value
+                    })
+                })
+                .collect();
+            values.extend(frontier.iter().cloned());
+        }
+
+        for value in &values {
+            let mut builder = MetricBuilder::with_label_allowlist(None);
+            builder.metric("all_smi_test", &[("value", value), ("after", "x")], 1);
+            let output = builder.build();
+            assert_eq!(output.lines().count(), 1, "{value:?} split the sample");
+
+            let mut labels = LabelMap::new();
+            let sample = parse_line(output.trim_end_matches('\n'), &mut labels)
+                .unwrap_or_else(|| panic!("{value:?} produced an unparsable line"));
+            assert_eq!(sample.value, 1.0);
+            assert_eq!(labels["value"], value.as_str());
+            assert_eq!(labels["after"], "x");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_metric_name() {
+        assert_eq!(sanitize_metric_name("all_smi_gpu:util"), "all_smi_gpu:util");
+        assert!(matches!(
+            sanitize_metric_name("all_smi_gpu_utilization"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(sanitize_metric_name("all-smi gpu.temp"), "all_smi_gpu_temp");
+        assert_eq!(sanitize_metric_name("0_leading_digit"), "__leading_digit");
+        assert_eq!(sanitize_metric_name("gpu2"), "gpu2");
+        assert_eq!(sanitize_metric_name(""), "_");
+
+        let mut builder = MetricBuilder::with_label_allowlist(None);
+        builder
+            .help("all_smi_npu-power", "NPU power")
+            .type_("all_smi_npu-power", "gauge")
+            .metric("all_smi_npu-power", &[], 5);
+        assert_eq!(
+            builder.build(),
+            "# HELP all_smi_npu_power NPU power\n\
+             # TYPE all_smi_npu_power gauge\n\
+             all_smi_npu_power 5\n"
+        );
+    }
+
+    #[test]
+    fn test_help_and_type_written_once_per_metric() {
+        let mut builder = MetricBuilder::with_label_allowlist(None);
+        for (uuid, index) in [("GPU-0", "0"), ("GPU-1", "1")] {
+            builder
+                .help("all_smi_gpu_utilization", "GPU utilization percentage")
+                .type_("all_smi_gpu_utilization", "gauge")
+                .metric("all_smi_gpu_utilization", &gpu_labels(uuid, index), 50);
+        }
+        let output = builder.build();
+        assert_eq!(output.matches("# HELP").count(), 1);
+        assert_eq!(output.matches("# TYPE").count(), 1);
+        assert_eq!(output.matches("all_smi_gpu_utilization{").count(), 2);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MetricBuilder, MetricExporter};
+use crate::network::NetworkInfo;
+
+/// Per-interface throughput and error counters from cached NetworkInfo
+pub struct NetworkMetricExporter<'a> {
+    network_info: &'a [NetworkInfo],
+}
+
+impl<'a> NetworkMetricExporter<'a> {
+    pub fn new(network_info: &'a [NetworkInfo]) -> Self {
+        Self { network_info }
+    }
+
+    fn export_series(
+        &self,
+        builder: &mut MetricBuilder,
+        name: &str,
+        help: &str,
+        metric_type: &str,
+        value: impl Fn(&NetworkInfo) -> String,
+    ) {
+        builder.help(name, help).type_(name, metric_type);
+        for info in self.network_info {
+            let labels = [
+                ("instance", info.hostname.as_str()),
+                ("iface", info.interface.as_str()),
+            ];
+            builder.metric(name, &labels, value(info));
+        }
+    }
+}
+
+impl<'a> MetricExporter for NetworkMetricExporter<'a> {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+
+        self.export_series(
+            &mut builder,
+            "all_smi_net_rx_bytes_per_sec",
+            "Bytes received per second",
+            "gauge",
+            |info| format!("{:.2}", info.rx_bytes_per_sec),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_net_tx_bytes_per_sec",
+            "Bytes transmitted per second",
+            "gauge",
+            |info| format!("{:.2}", info.tx_bytes_per_sec),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_net_rx_errors_total",
+            "Receive errors since the interface came up",
+            "counter",
+            |info| info.rx_errors.to_string(),
+        );
+        self.export_series(
+            &mut builder,
+            "all_smi_net_tx_errors_total",
+            "Transmit errors since the interface came up",
+            "counter",
+            |info| info.tx_errors.to_string(),
+        );
+
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_metrics_export() {
+        let info = vec![NetworkInfo {
+            interface: "eth0".to_string(),
+            rx_bytes_per_sec: 1250.0,
+            tx_bytes_per_sec: 0.5,
+            rx_errors: 3,
+            tx_errors: 0,
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+        }];
+        let output = NetworkMetricExporter::new(&info).export_metrics();
+
+        assert!(output.contains(
+            "all_smi_net_rx_bytes_per_sec{instance=\"node1\", iface=\"eth0\"} 1250.00\n"
+        ));
+        assert!(output
+            .contains("all_smi_net_tx_bytes_per_sec{instance=\"node1\", iface=\"eth0\"} 0.50\n"));
+        assert!(output.contains("# TYPE all_smi_net_rx_errors_total counter\n"));
+        assert!(
+            output.contains("all_smi_net_rx_errors_total{instance=\"node1\", iface=\"eth0\"} 3\n")
+        );
+        assert_eq!(
+            output.matches("# HELP all_smi_net_tx_errors_total").count(),
+            1
+        );
+    }
+}
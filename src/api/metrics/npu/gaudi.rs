@@ -15,6 +15,7 @@
 use super::common::CommonNpuExporter;
 use super::exporter_trait::{CommonNpuMetrics, NpuExporter};
 use crate::api::metrics::MetricBuilder;
+use crate::device::readers::gaudi::hbm_usage;
 use crate::device::GpuInfo;
 
 /// Intel Gaudi NPU-specific metric exporter
@@ -166,6 +167,58 @@ impl GaudiExporter {
             );
     }
 
+    /// Usage and temperature per HBM stack, when hl-smi reports them
+    fn export_hbm_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index = index.to_string();
+        let stacks = hbm_usage(&info.detail);
+        let base_labels = [
+            ("npu", info.name.as_str()),
+            ("instance", info.instance.as_str()),
+            ("uuid", info.uuid.as_str()),
+            ("index", index.as_str()),
+        ];
+
+        if stacks.iter().any(|s| s.used_bytes.is_some()) {
+            builder
+                .help(
+                    "all_smi_gaudi_hbm_used_bytes",
+                    "Gaudi memory used per HBM stack in bytes",
+                )
+                .type_("all_smi_gaudi_hbm_used_bytes", "gauge");
+            for stack in &stacks {
+                let Some(used) = stack.used_bytes else {
+                    continue;
+                };
+                let stack_id = stack.stack.to_string();
+                let mut labels = base_labels.to_vec();
+                labels.push(("stack", stack_id.as_str()));
+                builder.metric("all_smi_gaudi_hbm_used_bytes", &labels, used);
+            }
+        }
+
+        if stacks.iter().any(|s| s.temperature.is_some()) {
+            builder
+                .help(
+                    "all_smi_gaudi_hbm_temperature_celsius",
+                    "Gaudi HBM stack temperature in Celsius",
+                )
+                .type_("all_smi_gaudi_hbm_temperature_celsius", "gauge");
+            for stack in &stacks {
+                let Some(temperature) = stack.temperature else {
+                    continue;
+                };
+                let stack_id = stack.stack.to_string();
+                let mut labels = base_labels.to_vec();
+                labels.push(("stack", stack_id.as_str()));
+                builder.metric(
+                    "all_smi_gaudi_hbm_temperature_celsius",
+                    &labels,
+                    temperature,
+                );
+            }
+        }
+    }
+
     fn export_power_metrics(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
         let index_str = index.to_string();
         let base_labels = [
@@ -276,6 +329,7 @@ impl NpuExporter for GaudiExporter {
         self.export_driver_info(builder, info, index);
         self.export_aip_metrics(builder, info, index);
         self.export_memory_metrics(builder, info, index);
+        self.export_hbm_metrics(builder, info, index);
         self.export_power_metrics(builder, info, index);
         self.export_temperature_metrics(builder, info, index);
     }
@@ -328,3 +382,56 @@ impl CommonNpuMetrics for GaudiExporter {
         self.export_power_metrics(builder, info, index);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn gaudi_npu(detail: &[(&str, &str)]) -> GpuInfo {
+        GpuInfo {
+            uuid: "01P4-HL3090A0".to_string(),
+            name: "Intel Gaudi 3 PCIe LP".to_string(),
+            device_type: "NPU".to_string(),
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+            instance: "node1".to_string(),
+            utilization: 87.0,
+            temperature: 36,
+            power_consumption: 226.0,
+            detail: detail
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hbm_stack_export() {
+        let info = gaudi_npu(&[
+            ("hbm0_used_bytes", "12973178880"),
+            ("hbm0_temperature", "44"),
+            ("hbm1_used_bytes", "1048576"),
+        ]);
+        let mut builder = MetricBuilder::new();
+        GaudiExporter::new().export_vendor_metrics(&mut builder, &info, 0, "0");
+        let output = builder.build();
+
+        assert!(output.contains(
+            "all_smi_gaudi_hbm_used_bytes{npu=\"Intel Gaudi 3 PCIe LP\", instance=\"node1\", uuid=\"01P4-HL3090A0\", index=\"0\", stack=\"0\"} 12973178880\n"
+        ));
+        assert!(output.contains("stack=\"1\"} 1048576\n"));
+        assert_eq!(
+            output
+                .matches("all_smi_gaudi_hbm_temperature_celsius{")
+                .count(),
+            1
+        );
+
+        // Without per-stack detail nothing is exported
+        let mut builder = MetricBuilder::new();
+        GaudiExporter::new().export_vendor_metrics(&mut builder, &gaudi_npu(&[]), 0, "0");
+        assert!(!builder.build().contains("all_smi_gaudi_hbm_"));
+    }
+}
@@ -22,6 +22,7 @@ pub mod rebellions;
 pub mod tenstorrent;
 
 use crate::api::metrics::{MetricBuilder, MetricExporter};
+use crate::device::device_selection::device_index;
 use crate::device::GpuInfo;
 use exporter_trait::{CommonNpuMetrics, NpuExporter};
 use std::sync::OnceLock;
@@ -153,10 +154,10 @@ impl<'a> MetricExporter for NpuMetricExporter<'a> {
         let mut builder = MetricBuilder::new();
 
         // Filter NPU devices and export metrics
-        for (i, info) in self.npu_info.iter().enumerate() {
+        for (position, info) in self.npu_info.iter().enumerate() {
             // Only process NPU or TPU devices
             if info.device_type == "NPU" || info.device_type == "TPU" {
-                self.export_device_metrics(&mut builder, info, i);
+                self.export_device_metrics(&mut builder, info, device_index(info, position));
             }
         }
 
@@ -15,6 +15,7 @@
 use super::common::CommonNpuExporter;
 use super::exporter_trait::{CommonNpuMetrics, NpuExporter};
 use crate::api::metrics::MetricBuilder;
+use crate::device::readers::rebellions::{core_usage, neural_engine_utilization};
 use crate::device::GpuInfo;
 
 /// Rebellions NPU-specific metric exporter
@@ -111,6 +112,107 @@ impl RebellionsExporter {
         }
     }
 
+    /// Numeric P-state (P0 = 0 … P14 = 14) and per-neural-engine utilization,
+    /// both only present when the driver reports them.
+    fn export_power_state_and_engines(
+        &self,
+        builder: &mut MetricBuilder,
+        info: &GpuInfo,
+        index: usize,
+    ) {
+        let index = index.to_string();
+        let base_labels = [
+            ("npu", info.name.as_str()),
+            ("instance", info.instance.as_str()),
+            ("uuid", info.uuid.as_str()),
+            ("index", index.as_str()),
+        ];
+
+        if let Some(pstate) = info.detail.get("pstate").and_then(|p| parse_pstate(p)) {
+            builder
+                .help(
+                    "all_smi_rebellions_pstate",
+                    "Current performance state (P0=0, P1=1, ...)",
+                )
+                .type_("all_smi_rebellions_pstate", "gauge")
+                .metric("all_smi_rebellions_pstate", &base_labels, pstate);
+        }
+
+        let engines = neural_engine_utilization(&info.detail);
+        if engines.is_empty() {
+            return;
+        }
+        builder
+            .help(
+                "all_smi_rebellions_ne_utilization",
+                "Neural engine utilization percentage",
+            )
+            .type_("all_smi_rebellions_ne_utilization", "gauge");
+        for (ne, util) in engines {
+            let ne = ne.to_string();
+            let mut labels = base_labels.to_vec();
+            labels.push(("ne", ne.as_str()));
+            builder.metric("all_smi_rebellions_ne_utilization", &labels, util);
+        }
+    }
+
+    /// Utilization and memory per core. Devices whose driver reports no
+    /// neural engine breakdown are exported as a single core 0.
+    fn export_core_usage(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let index = index.to_string();
+        let cores = core_usage(info);
+
+        builder
+            .help(
+                "all_smi_rebellions_core_utilization",
+                "NPU core utilization percentage",
+            )
+            .type_("all_smi_rebellions_core_utilization", "gauge");
+        for core in &cores {
+            let core_id = core.core.to_string();
+            let labels = [
+                ("npu", info.name.as_str()),
+                ("instance", info.instance.as_str()),
+                ("uuid", info.uuid.as_str()),
+                ("index", index.as_str()),
+                ("core", core_id.as_str()),
+            ];
+            builder.metric(
+                "all_smi_rebellions_core_utilization",
+                &labels,
+                core.utilization,
+            );
+        }
+
+        if cores.iter().all(|core| core.memory_used.is_none()) {
+            return;
+        }
+        builder
+            .help(
+                "all_smi_rebellions_core_memory_used_bytes",
+                "Device memory used by an NPU core in bytes",
+            )
+            .type_("all_smi_rebellions_core_memory_used_bytes", "gauge");
+        for core in &cores {
+            let Some(memory_used) = core.memory_used else {
+                continue;
+            };
+            let core_id = core.core.to_string();
+            let labels = [
+                ("npu", info.name.as_str()),
+                ("instance", info.instance.as_str()),
+                ("uuid", info.uuid.as_str()),
+                ("index", index.as_str()),
+                ("core", core_id.as_str()),
+            ];
+            builder.metric(
+                "all_smi_rebellions_core_memory_used_bytes",
+                &labels,
+                memory_used,
+            );
+        }
+    }
+
     fn export_device_status(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
         use super::common::status_values;
 
@@ -126,6 +228,11 @@ impl RebellionsExporter {
     }
 }
 
+/// `"P14"` -> `14`.
+fn parse_pstate(pstate: &str) -> Option<u32> {
+    pstate.trim().strip_prefix(['P', 'p'])?.parse().ok()
+}
+
 impl Default for RebellionsExporter {
     fn default() -> Self {
         Self::new()
@@ -152,6 +259,8 @@ impl NpuExporter for RebellionsExporter {
         self.export_firmware_info(builder, info, index);
         self.export_device_info(builder, info, index);
         self.export_performance_state(builder, info, index);
+        self.export_power_state_and_engines(builder, info, index);
+        self.export_core_usage(builder, info, index);
         self.export_device_status(builder, info, index);
     }
 
@@ -203,3 +312,97 @@ impl CommonNpuMetrics for RebellionsExporter {
         self.common.export_power_metrics(builder, info, index);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn rebellions_npu(detail: &[(&str, &str)]) -> GpuInfo {
+        GpuInfo {
+            uuid: "rbln-0".to_string(),
+            name: "Rebellions RBLN-CA25".to_string(),
+            device_type: "NPU".to_string(),
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+            instance: "node1".to_string(),
+            utilization: 47.5,
+            temperature: 51,
+            power_consumption: 85.0,
+            detail: detail
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_pstate() {
+        assert_eq!(parse_pstate("P0"), Some(0));
+        assert_eq!(parse_pstate(" P14 "), Some(14));
+        assert_eq!(parse_pstate("unknown"), None);
+    }
+
+    #[test]
+    fn test_pstate_and_neural_engine_export() {
+        let info = rebellions_npu(&[
+            ("pstate", "P3"),
+            ("ne_10_utilization", "12.0"),
+            ("ne_0_utilization", "45.0"),
+            ("ne_1_utilization", "50.0"),
+        ]);
+        let mut builder = MetricBuilder::new();
+        RebellionsExporter::new().export_vendor_metrics(&mut builder, &info, 0, "0");
+        let output = builder.build();
+
+        assert!(output.contains(
+            "all_smi_rebellions_pstate{npu=\"Rebellions RBLN-CA25\", instance=\"node1\", uuid=\"rbln-0\", index=\"0\"} 3\n"
+        ));
+        assert!(output.contains("index=\"0\", ne=\"1\"} 50\n"));
+        let ne0 = output.find("ne=\"0\"").unwrap();
+        let ne10 = output.find("ne=\"10\"").unwrap();
+        assert!(ne0 < ne10);
+    }
+
+    #[test]
+    fn test_old_driver_detail_exports_no_engine_metrics() {
+        let info = rebellions_npu(&[("pstate", "P14")]);
+        let mut builder = MetricBuilder::new();
+        RebellionsExporter::new().export_vendor_metrics(&mut builder, &info, 0, "0");
+        let output = builder.build();
+
+        assert!(output.contains("all_smi_rebellions_pstate{"));
+        assert!(!output.contains("all_smi_rebellions_ne_utilization"));
+    }
+
+    #[test]
+    fn test_core_usage_export() {
+        let single = rebellions_npu(&[("pstate", "P14")]);
+        let mut builder = MetricBuilder::new();
+        RebellionsExporter::new().export_vendor_metrics(&mut builder, &single, 0, "0");
+        let output = builder.build();
+        assert!(output.contains("index=\"0\", core=\"0\"} 47.5\n"));
+        assert!(output.contains("all_smi_rebellions_core_memory_used_bytes{"));
+        assert!(!output.contains("core=\"1\""));
+
+        let multi = rebellions_npu(&[
+            ("ne_0_utilization", "45.0"),
+            ("ne_0_memory_used", "536870912"),
+            ("ne_1_utilization", "50.0"),
+        ]);
+        let mut builder = MetricBuilder::new();
+        RebellionsExporter::new().export_vendor_metrics(&mut builder, &multi, 0, "0");
+        let output = builder.build();
+        assert!(output.contains(
+            "all_smi_rebellions_core_utilization{npu=\"Rebellions RBLN-CA25\", instance=\"node1\", uuid=\"rbln-0\", index=\"0\", core=\"1\"} 50\n"
+        ));
+        assert!(output.contains("core=\"0\"} 536870912\n"));
+        assert_eq!(
+            output
+                .matches("all_smi_rebellions_core_memory_used_bytes{")
+                .count(),
+            1
+        );
+    }
+}
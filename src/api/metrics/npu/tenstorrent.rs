@@ -468,6 +468,21 @@ impl TenstorrentExporter {
             }
         }
 
+        // Heartbeat-based health; degraded when the counter stopped advancing
+        if let Some(health) = info.detail.get("health") {
+            builder
+                .help(
+                    "all_smi_tenstorrent_health_degraded",
+                    "1 if the telemetry heartbeat did not advance since the previous sample",
+                )
+                .type_("all_smi_tenstorrent_health_degraded", "gauge")
+                .metric(
+                    "all_smi_tenstorrent_health_degraded",
+                    &base_labels,
+                    u8::from(health == "Degraded"),
+                );
+        }
+
         // ARC health counters
         if let Some(arc0_health) = info.detail.get("arc0_health") {
             if let Some(health) = CommonNpuExporter::parse_numeric_value(arc0_health) {
@@ -533,6 +548,36 @@ impl TenstorrentExporter {
         }
     }
 
+    fn export_eth_links(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
+        let links = eth_links(info);
+        if links.is_empty() {
+            return;
+        }
+
+        let index = index.to_string();
+        builder
+            .help(
+                "all_smi_tenstorrent_eth_link_up",
+                "1 if the chip-to-chip Ethernet link is up, 0 if it is down or not cabled",
+            )
+            .type_("all_smi_tenstorrent_eth_link_up", "gauge");
+        for (port, up) in links {
+            let port = port.to_string();
+            let link_labels = [
+                ("npu", info.name.as_str()),
+                ("instance", info.instance.as_str()),
+                ("uuid", info.uuid.as_str()),
+                ("index", index.as_str()),
+                ("link", port.as_str()),
+            ];
+            builder.metric(
+                "all_smi_tenstorrent_eth_link_up",
+                &link_labels,
+                u8::from(up),
+            );
+        }
+    }
+
     fn export_board_info(&self, builder: &mut MetricBuilder, info: &GpuInfo, index: usize) {
         // Board type and architecture
         if let Some(board_type) = info.detail.get("board_type") {
@@ -684,6 +729,20 @@ impl TenstorrentExporter {
     }
 }
 
+/// `(port, link up)` from the `eth_<n>_link_up` detail entries, in port order
+fn eth_links(info: &GpuInfo) -> Vec<(u32, bool)> {
+    let mut links: Vec<(u32, bool)> = info
+        .detail
+        .iter()
+        .filter_map(|(key, value)| {
+            let port = key.strip_prefix("eth_")?.strip_suffix("_link_up")?;
+            Some((port.parse().ok()?, value == "1"))
+        })
+        .collect();
+    links.sort_by_key(|(port, _)| *port);
+    links
+}
+
 impl Default for TenstorrentExporter {
     fn default() -> Self {
         Self::new()
@@ -713,6 +772,7 @@ impl NpuExporter for TenstorrentExporter {
         self.export_clocks(builder, info, index);
         self.export_power(builder, info, index);
         self.export_status_health(builder, info, index);
+        self.export_eth_links(builder, info, index);
         self.export_board_info(builder, info, index);
         self.export_pcie_dram(builder, info, index);
     }
@@ -0,0 +1,78 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MetricBuilder, MetricExporter};
+use crate::device::privileges::MissingFeature;
+
+/// One `all_smi_feature_unavailable` series per feature left out for lack
+/// of privileges
+pub struct UnavailableFeatureExporter<'a> {
+    features: &'a [MissingFeature],
+    hostname: String,
+}
+
+impl<'a> UnavailableFeatureExporter<'a> {
+    pub fn new(features: &'a [MissingFeature]) -> Self {
+        Self {
+            features,
+            hostname: crate::utils::get_hostname(),
+        }
+    }
+}
+
+impl<'a> MetricExporter for UnavailableFeatureExporter<'a> {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+        if self.features.is_empty() {
+            return builder.build();
+        }
+
+        builder
+            .help(
+                "all_smi_feature_unavailable",
+                "Feature not collected because all-smi lacks the privileges for it",
+            )
+            .type_("all_smi_feature_unavailable", "gauge");
+        for feature in self.features {
+            let labels = [
+                ("hostname", self.hostname.as_str()),
+                ("feature", feature.id),
+                ("reason", feature.reason),
+            ];
+            builder.metric("all_smi_feature_unavailable", &labels, 1);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_series_per_feature() {
+        assert_eq!(UnavailableFeatureExporter::new(&[]).export_metrics(), "");
+
+        let features = [MissingFeature {
+            id: "smart_health",
+            name: "drive SMART health",
+            reason: "smartctl needs root to open the drives",
+        }];
+        let text = UnavailableFeatureExporter::new(&features).export_metrics();
+        assert!(text.contains("# TYPE all_smi_feature_unavailable gauge\n"));
+        assert!(text.contains(
+            "feature=\"smart_health\", reason=\"smartctl needs root to open the drives\"} 1\n"
+        ));
+    }
+}
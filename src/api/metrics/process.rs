@@ -12,41 +12,132 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
 use super::{MetricBuilder, MetricExporter};
+use crate::device::container_utils::{process_slurm_job, SlurmJob};
+use crate::device::process_list::{retain_top_processes, UserProcessSummary};
 use crate::device::ProcessInfo;
 
+const PROCESS_MEMORY_HELP: &str = "Process GPU memory used in bytes. Series are keyed by pid and \
+start_time so recycled PIDs stay distinct. With --process-top N only the N processes using the \
+most GPU memory are exported, ties broken by processes on a device first, then lowest pid";
+
+static PROCESS_TOP: OnceLock<usize> = OnceLock::new();
+
+/// Export per-process series for only the `limit` processes using the most
+/// GPU memory. Only the first call takes effect.
+pub fn set_process_top(limit: Option<usize>) {
+    if let Some(limit) = limit {
+        let _ = PROCESS_TOP.set(limit);
+    }
+}
+
 pub struct ProcessMetricExporter<'a> {
     pub process_info: &'a [ProcessInfo],
+    /// Per-process series limit from --process-top. Per-device totals
+    /// still cover every process.
+    top: Option<usize>,
+    /// Add the pod owning each process's GPU (`--kubernetes`)
+    pod_labels: bool,
+    /// SLURM job of a PID, for the `slurm_job_id`/`slurm_step_id` labels
+    slurm_job: fn(u32) -> Option<SlurmJob>,
 }
 
 impl<'a> ProcessMetricExporter<'a> {
     pub fn new(process_info: &'a [ProcessInfo]) -> Self {
-        Self { process_info }
+        Self {
+            process_info,
+            top: PROCESS_TOP.get().copied(),
+            pod_labels: super::pod_labels(),
+            slurm_job: process_slurm_job,
+        }
+    }
+
+    /// Look up the SLURM job of a PID with `slurm_job` instead of /proc.
+    #[cfg(test)]
+    pub fn with_slurm_job(mut self, slurm_job: fn(u32) -> Option<SlurmJob>) -> Self {
+        self.slurm_job = slurm_job;
+        self
+    }
+
+    /// Add `pod`/`namespace`/`container` labels from the kubelet.
+    #[cfg(test)]
+    pub fn with_pod_labels(mut self, pod_labels: bool) -> Self {
+        self.pod_labels = pod_labels;
+        self
+    }
+
+    /// Process count and summed process memory per device, by device UUID
+    fn device_totals(&self) -> BTreeMap<&'a str, (usize, u64)> {
+        let mut totals: BTreeMap<&str, (usize, u64)> = BTreeMap::new();
+        for process in self.process_info {
+            let entry = totals.entry(process.device_uuid.as_str()).or_default();
+            entry.0 += 1;
+            entry.1 += process.used_memory;
+        }
+        totals
+    }
+
+    fn export_device_totals(&self, builder: &mut MetricBuilder) {
+        let totals = self.device_totals();
+
+        builder
+            .help(
+                "all_smi_gpu_process_count",
+                "Number of processes using the GPU",
+            )
+            .type_("all_smi_gpu_process_count", "gauge");
+        for (uuid, (count, _)) in &totals {
+            builder.metric("all_smi_gpu_process_count", &[("uuid", *uuid)], count);
+        }
+
+        builder
+            .help(
+                "all_smi_gpu_process_memory_total_bytes",
+                "GPU memory used by all processes on the GPU in bytes",
+            )
+            .type_("all_smi_gpu_process_memory_total_bytes", "gauge");
+        for (uuid, (_, memory)) in &totals {
+            builder.metric(
+                "all_smi_gpu_process_memory_total_bytes",
+                &[("uuid", *uuid)],
+                memory,
+            );
+        }
     }
 
     fn export_process_metrics(&self, builder: &mut MetricBuilder, process: &ProcessInfo) {
         let pid_str = process.pid.to_string();
+        let start_time_str = process.start_time.to_string();
         let device_id_str = process.device_id.to_string();
 
-        let labels = [
+        let mut labels = vec![
             ("pid", pid_str.as_str()),
+            ("start_time", start_time_str.as_str()),
             ("name", process.process_name.as_str()),
             ("device_id", device_id_str.as_str()),
             ("device_uuid", process.device_uuid.as_str()),
         ];
+        if let Some(pod) = process.k8s_pod.as_ref().filter(|_| self.pod_labels) {
+            labels.push(("pod", pod.name.as_str()));
+            labels.push(("namespace", pod.namespace.as_str()));
+            labels.push(("container", pod.container.as_str()));
+        }
+        let slurm_job = (self.slurm_job)(process.pid);
+        if let Some(job) = &slurm_job {
+            labels.push(("slurm_job_id", job.job_id.as_str()));
+            if let Some(step_id) = &job.step_id {
+                labels.push(("slurm_step_id", step_id.as_str()));
+            }
+        }
 
-        // Process memory usage
-        builder
-            .help(
-                "all_smi_process_memory_used_bytes",
-                "Process memory used in bytes",
-            )
-            .type_("all_smi_process_memory_used_bytes", "gauge")
-            .metric(
-                "all_smi_process_memory_used_bytes",
-                &labels,
-                process.used_memory,
-            );
+        builder.metric(
+            "all_smi_process_memory_used_bytes",
+            &labels,
+            process.used_memory,
+        );
     }
 }
 
@@ -57,11 +148,255 @@ impl<'a> MetricExporter for ProcessMetricExporter<'a> {
         }
 
         let mut builder = MetricBuilder::new();
+        builder
+            .help("all_smi_process_memory_used_bytes", PROCESS_MEMORY_HELP)
+            .type_("all_smi_process_memory_used_bytes", "gauge");
 
-        for process in self.process_info {
-            self.export_process_metrics(&mut builder, process);
+        match self.top {
+            Some(limit) => {
+                let mut top = self.process_info.to_vec();
+                retain_top_processes(&mut top, limit);
+                for process in &top {
+                    self.export_process_metrics(&mut builder, process);
+                }
+            }
+            None => {
+                for process in self.process_info {
+                    self.export_process_metrics(&mut builder, process);
+                }
+            }
+        }
+        self.export_device_totals(&mut builder);
+
+        builder.build()
+    }
+}
+
+/// GPU memory summed per process owner
+pub struct UserMetricExporter<'a> {
+    summaries: &'a [UserProcessSummary],
+}
+
+impl<'a> UserMetricExporter<'a> {
+    pub fn new(summaries: &'a [UserProcessSummary]) -> Self {
+        Self { summaries }
+    }
+}
+
+impl<'a> MetricExporter for UserMetricExporter<'a> {
+    fn export_metrics(&self) -> String {
+        if self.summaries.is_empty() {
+            return String::new();
+        }
+
+        let mut builder = MetricBuilder::new();
+        builder
+            .help(
+                "all_smi_user_gpu_memory_bytes",
+                "GPU memory used by all processes of a user in bytes",
+            )
+            .type_("all_smi_user_gpu_memory_bytes", "gauge");
+        for summary in self.summaries {
+            builder.metric(
+                "all_smi_user_gpu_memory_bytes",
+                &[("user", summary.user.as_str())],
+                summary.gpu_memory,
+            );
         }
 
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::process_list::summarize_by_user;
+    use crate::device::K8sPod;
+
+    fn process(pid: u32, start_time: u64, used_memory: u64) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: "GPU-0".to_string(),
+            pid,
+            process_name: "python".to_string(),
+            used_memory,
+            used_memory_estimated: false,
+            cpu_percent: 0.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: "user".to_string(),
+            state: "S".to_string(),
+            start_time,
+            cpu_time: 0,
+            command: "python train.py".to_string(),
+            ppid: 1,
+            threads: 1,
+            uses_gpu: used_memory > 0,
+            priority: 20,
+            nice_value: 0,
+            gpu_utilization: 0.0,
+            k8s_pod: None,
+            host_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_retain_top_processes() {
+        let mut processes = vec![
+            process(30, 100, 512),
+            process(10, 100, 2048),
+            process(20, 100, 2048),
+            process(5, 100, 0),
+            process(40, 100, 1024),
+        ];
+        retain_top_processes(&mut processes, 3);
+        let pids: Vec<u32> = processes.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![10, 20, 40]);
+
+        retain_top_processes(&mut processes, 10);
+        assert_eq!(processes.len(), 3);
+
+        // An NPU process without a memory figure beats an idle process
+        let mut processes = vec![
+            process(5, 100, 0),
+            ProcessInfo {
+                device_uuid: "NPU-0".to_string(),
+                uses_gpu: true,
+                ..process(50, 100, 0)
+            },
+            process(40, 100, 1024),
+        ];
+        retain_top_processes(&mut processes, 2);
+        let pids: Vec<u32> = processes.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![40, 50]);
+    }
+
+    #[test]
+    fn test_user_gpu_memory_export() {
+        let mut processes = vec![process(1, 100, 1024), process(2, 100, 2048)];
+        processes.push(ProcessInfo {
+            user: "1001".to_string(),
+            ..process(3, 100, 512)
+        });
+        let output = UserMetricExporter::new(&summarize_by_user(&processes)).export_metrics();
+
+        assert!(output.contains("all_smi_user_gpu_memory_bytes{user=\"user\"} 3072\n"));
+        assert!(output.contains("all_smi_user_gpu_memory_bytes{user=\"1001\"} 512\n"));
+        assert!(UserMetricExporter::new(&[]).export_metrics().is_empty());
+    }
+
+    #[test]
+    fn test_device_process_totals() {
+        let mut processes = vec![process(1, 100, 1024), process(2, 100, 2048)];
+        processes.push(ProcessInfo {
+            device_uuid: "GPU-1".to_string(),
+            ..process(3, 100, 512)
+        });
+        let output = ProcessMetricExporter::new(&processes).export_metrics();
+
+        assert!(output.contains("all_smi_gpu_process_count{uuid=\"GPU-0\"} 2\n"));
+        assert!(output.contains("all_smi_gpu_process_count{uuid=\"GPU-1\"} 1\n"));
+        assert!(output.contains("all_smi_gpu_process_memory_total_bytes{uuid=\"GPU-0\"} 3072\n"));
+        assert!(output.contains("all_smi_gpu_process_memory_total_bytes{uuid=\"GPU-1\"} 512\n"));
+        assert!(ProcessMetricExporter::new(&[]).export_metrics().is_empty());
+
+        // Totals include processes left out by --process-top
+        let output = ProcessMetricExporter {
+            process_info: &processes,
+            top: Some(1),
+            pod_labels: false,
+            slurm_job: |_| None,
+        }
+        .export_metrics();
+        assert_eq!(
+            output.matches("all_smi_process_memory_used_bytes{").count(),
+            1
+        );
+        assert!(output.contains("all_smi_gpu_process_count{uuid=\"GPU-0\"} 2\n"));
+        assert!(output.contains("all_smi_gpu_process_memory_total_bytes{uuid=\"GPU-1\"} 512\n"));
+    }
+
+    #[test]
+    fn test_recycled_pid_gets_distinct_series() {
+        let processes = vec![
+            process(4242, 1_700_000_000, 1024),
+            process(4242, 1_700_000_600, 2048),
+        ];
+        let output = ProcessMetricExporter::new(&processes).export_metrics();
+
+        assert_eq!(
+            output
+                .matches("# HELP all_smi_process_memory_used_bytes")
+                .count(),
+            1
+        );
+        assert!(output.contains(
+            "all_smi_process_memory_used_bytes{pid=\"4242\", start_time=\"1700000000\", name=\"python\", device_id=\"0\", device_uuid=\"GPU-0\"} 1024\n"
+        ));
+        assert!(output.contains(
+            "all_smi_process_memory_used_bytes{pid=\"4242\", start_time=\"1700000600\", name=\"python\", device_id=\"0\", device_uuid=\"GPU-0\"} 2048\n"
+        ));
+    }
+
+    #[test]
+    fn test_slurm_job_labels() {
+        fn slurm_job(pid: u32) -> Option<SlurmJob> {
+            match pid {
+                4242 => Some(SlurmJob {
+                    job_id: "48213".to_string(),
+                    step_id: Some("0".to_string()),
+                }),
+                4343 => Some(SlurmJob {
+                    job_id: "48214".to_string(),
+                    step_id: None,
+                }),
+                _ => None,
+            }
+        }
+        let processes = vec![
+            process(4242, 1_700_000_000, 1024),
+            process(4343, 1_700_000_000, 2048),
+            process(4444, 1_700_000_000, 512),
+        ];
+        let output = ProcessMetricExporter::new(&processes)
+            .with_slurm_job(slurm_job)
+            .export_metrics();
+
+        assert!(output.contains(
+            "all_smi_process_memory_used_bytes{pid=\"4242\", start_time=\"1700000000\", name=\"python\", device_id=\"0\", device_uuid=\"GPU-0\", slurm_job_id=\"48213\", slurm_step_id=\"0\"} 1024\n"
+        ));
+        assert!(output.contains(
+            "all_smi_process_memory_used_bytes{pid=\"4343\", start_time=\"1700000000\", name=\"python\", device_id=\"0\", device_uuid=\"GPU-0\", slurm_job_id=\"48214\"} 2048\n"
+        ));
+        // Outside SLURM the series keeps its usual labels
+        assert!(output.contains(
+            "all_smi_process_memory_used_bytes{pid=\"4444\", start_time=\"1700000000\", name=\"python\", device_id=\"0\", device_uuid=\"GPU-0\"} 512\n"
+        ));
+    }
+
+    #[test]
+    fn test_pod_labels_only_with_flag() {
+        let processes = vec![ProcessInfo {
+            k8s_pod: Some(K8sPod {
+                namespace: "ml".to_string(),
+                name: "train-0".to_string(),
+                container: "trainer".to_string(),
+            }),
+            ..process(4242, 1_700_000_000, 1024)
+        }];
+
+        let output = ProcessMetricExporter::new(&processes)
+            .with_pod_labels(true)
+            .export_metrics();
+        assert!(output.contains(
+            "all_smi_process_memory_used_bytes{pid=\"4242\", start_time=\"1700000000\", name=\"python\", device_id=\"0\", device_uuid=\"GPU-0\", pod=\"train-0\", namespace=\"ml\", container=\"trainer\"} 1024\n"
+        ));
+
+        let output = ProcessMetricExporter::new(&processes)
+            .with_pod_labels(false)
+            .export_metrics();
+        assert!(!output.contains("pod="));
+    }
+}
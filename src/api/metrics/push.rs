@@ -0,0 +1,80 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MetricBuilder, MetricExporter};
+use crate::api::push::PushStats;
+
+pub struct PushMetricExporter<'a> {
+    stats: &'a PushStats,
+    hostname: String,
+}
+
+impl<'a> PushMetricExporter<'a> {
+    pub fn new(stats: &'a PushStats) -> Self {
+        Self {
+            stats,
+            hostname: crate::utils::get_hostname(),
+        }
+    }
+}
+
+impl<'a> MetricExporter for PushMetricExporter<'a> {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+        let labels = [
+            ("hostname", self.hostname.as_str()),
+            ("format", self.stats.format()),
+        ];
+
+        builder
+            .help(
+                "all_smi_push_attempts_total",
+                "Pushes attempted to --push-url, including retries",
+            )
+            .type_("all_smi_push_attempts_total", "counter")
+            .metric(
+                "all_smi_push_attempts_total",
+                &labels,
+                self.stats.attempts(),
+            );
+
+        builder
+            .help(
+                "all_smi_push_failures_total",
+                "Pushes to --push-url that failed",
+            )
+            .type_("all_smi_push_failures_total", "counter")
+            .metric(
+                "all_smi_push_failures_total",
+                &labels,
+                self.stats.failures(),
+            );
+
+        if let Some(timestamp) = self.stats.last_success() {
+            builder
+                .help(
+                    "all_smi_push_last_success_timestamp_seconds",
+                    "Unix time of the last successful push",
+                )
+                .type_("all_smi_push_last_success_timestamp_seconds", "gauge")
+                .metric(
+                    "all_smi_push_last_success_timestamp_seconds",
+                    &labels,
+                    timestamp,
+                );
+        }
+
+        builder.build()
+    }
+}
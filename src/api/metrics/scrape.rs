@@ -0,0 +1,100 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{MetricBuilder, MetricExporter};
+use crate::api::scrape::ScrapeStats;
+
+/// How `/metrics` itself is being scraped
+pub struct ScrapeMetricExporter<'a> {
+    stats: &'a ScrapeStats,
+    hostname: String,
+}
+
+impl<'a> ScrapeMetricExporter<'a> {
+    pub fn new(stats: &'a ScrapeStats) -> Self {
+        Self {
+            stats,
+            hostname: crate::utils::get_hostname(),
+        }
+    }
+}
+
+impl<'a> MetricExporter for ScrapeMetricExporter<'a> {
+    fn export_metrics(&self) -> String {
+        let mut builder = MetricBuilder::new();
+        let labels = [("hostname", self.hostname.as_str())];
+
+        builder
+            .help(
+                "all_smi_metrics_requests_total",
+                "Requests to /metrics, including rate-limited ones",
+            )
+            .type_("all_smi_metrics_requests_total", "counter")
+            .metric(
+                "all_smi_metrics_requests_total",
+                &labels,
+                self.stats.requests(),
+            );
+
+        builder
+            .help(
+                "all_smi_metrics_cache_hits_total",
+                "Requests to /metrics served from the text rendered at the last update",
+            )
+            .type_("all_smi_metrics_cache_hits_total", "counter")
+            .metric(
+                "all_smi_metrics_cache_hits_total",
+                &labels,
+                self.stats.cache_hits(),
+            );
+
+        builder
+            .help(
+                "all_smi_metrics_rate_limited_total",
+                "Requests to /metrics refused over --max-requests-per-second",
+            )
+            .type_("all_smi_metrics_rate_limited_total", "counter")
+            .metric(
+                "all_smi_metrics_rate_limited_total",
+                &labels,
+                self.stats.rate_limited(),
+            );
+
+        builder
+            .help(
+                "all_smi_metrics_renders_total",
+                "Times the metrics text was rendered",
+            )
+            .type_("all_smi_metrics_renders_total", "counter")
+            .metric(
+                "all_smi_metrics_renders_total",
+                &labels,
+                self.stats.renders(),
+            );
+
+        builder
+            .help(
+                "all_smi_metrics_render_duration_seconds",
+                "Time the last rendering of the metrics text took",
+            )
+            .type_("all_smi_metrics_render_duration_seconds", "gauge")
+            .metric(
+                "all_smi_metrics_render_duration_seconds",
+                &labels,
+                self.stats.last_render_seconds(),
+            );
+
+        builder.build()
+    }
+}
@@ -12,8 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod dashboard;
 pub mod handlers;
 pub mod metrics;
+pub mod proxy;
+pub mod push;
+#[cfg(feature = "remote-write")]
+pub mod remote_write;
+pub mod scrape;
 pub mod server;
+pub mod staleness;
+#[cfg(all(unix, feature = "systemd"))]
+pub mod systemd;
+pub mod textfile;
+#[cfg(feature = "web")]
+pub mod web;
 
 pub use server::*;
@@ -0,0 +1,404 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `all-smi proxy`: scrape every host `view` would show and re-export their
+//! metrics on one `/metrics` endpoint, so Prometheus needs a single target
+//! per cluster.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::{routing::get, Router};
+use tokio::sync::{watch, Semaphore};
+
+use crate::cli::ProxyArgs;
+use crate::common::config::EnvConfig;
+use crate::network::NetworkClient;
+use crate::parsing::prometheus::{parse_line, LabelMap};
+use crate::utils::build_info::BuildInfo;
+use crate::view::data_collection::hostfile::load_hosts;
+
+use super::metrics::build_info::BuildInfoMetricExporter;
+use super::metrics::{escape_label_value, MetricBuilder, MetricExporter};
+use super::server::{run_tcp_listener, shutdown_requested};
+
+/// Label added to every re-exported sample
+const VIA_LABEL: &str = r#"via="all-smi-proxy""#;
+
+/// Metrics about a node's own exporter rather than its devices. Every node
+/// reports the same names, so they would collide once merged.
+fn is_self_metric(name: &str) -> bool {
+    name == "all_smi_build_info"
+        || name.starts_with("all_smi_metrics_")
+        || name.starts_with("all_smi_push_")
+        || name.starts_with("all_smi_proxy_")
+}
+
+#[derive(Default)]
+struct Family {
+    help: Option<String>,
+    type_: Option<String>,
+    /// Relabeled sample lines, each ending in a line feed
+    samples: String,
+}
+
+/// Merged exposition of many hosts. Each payload is folded into its metric
+/// families as it arrives, so only the merged text is held, never the
+/// payloads themselves.
+#[derive(Default)]
+pub struct Federation {
+    families: BTreeMap<String, Family>,
+    /// Scrape outcome per host, in the order reported
+    up: Vec<(String, bool)>,
+}
+
+impl Federation {
+    /// Fold the payload scraped from `host` into the merged families. The
+    /// first HELP and TYPE seen for a family win; samples that do not parse
+    /// are dropped.
+    pub fn add(&mut self, host: &str, payload: &str) {
+        let mut labels = LabelMap::new();
+        // Family named by the HELP or TYPE line above the current sample
+        let mut current: Option<String> = None;
+        for line in payload.lines() {
+            let line = line.trim();
+            if let Some(comment) = line.strip_prefix('#') {
+                let mut words = comment.trim_start().splitn(3, ' ');
+                let (Some(keyword @ ("HELP" | "TYPE")), Some(name)) = (words.next(), words.next())
+                else {
+                    continue;
+                };
+                if is_self_metric(name) {
+                    current = None;
+                    continue;
+                }
+                let text = words.next().unwrap_or_default().to_string();
+                let family = self.families.entry(name.to_string()).or_default();
+                let slot = match keyword {
+                    "HELP" => &mut family.help,
+                    _ => &mut family.type_,
+                };
+                slot.get_or_insert(text);
+                current = Some(name.to_string());
+                continue;
+            }
+
+            let Some(sample) = parse_line(line, &mut labels) else {
+                continue;
+            };
+            if is_self_metric(sample.name) {
+                continue;
+            }
+            // Histogram and summary samples carry a suffix on the family name
+            let family = match &current {
+                Some(family)
+                    if sample.name == family
+                        || sample
+                            .name
+                            .strip_prefix(family.as_str())
+                            .is_some_and(|suffix| suffix.starts_with('_')) =>
+                {
+                    family.clone()
+                }
+                _ => sample.name.to_string(),
+            };
+            let samples = &mut self.families.entry(family).or_default().samples;
+            relabel(samples, line, sample.name, host, &labels);
+        }
+        self.up.push((host.to_string(), true));
+    }
+
+    /// Record that `host` could not be scraped.
+    pub fn add_down(&mut self, host: &str) {
+        self.up.push((host.to_string(), false));
+    }
+
+    /// Whether `host` has been added, up or down
+    pub fn has_host(&self, host: &str) -> bool {
+        self.up.iter().any(|(h, _)| h == host)
+    }
+
+    /// The merged exposition, one HELP and TYPE per family, followed by
+    /// `all_smi_proxy_up` for every host.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (name, family) in &self.families {
+            if family.samples.is_empty() {
+                continue;
+            }
+            if let Some(help) = &family.help {
+                let _ = writeln!(out, "# HELP {name} {help}");
+            }
+            if let Some(type_) = &family.type_ {
+                let _ = writeln!(out, "# TYPE {name} {type_}");
+            }
+            out.push_str(&family.samples);
+        }
+
+        let mut up: Vec<_> = self.up.iter().collect();
+        up.sort();
+        let mut builder = MetricBuilder::new();
+        builder
+            .help(
+                "all_smi_proxy_up",
+                "Whether the last scrape of the host succeeded",
+            )
+            .type_("all_smi_proxy_up", "gauge");
+        for (host, is_up) in up {
+            builder.metric(
+                "all_smi_proxy_up",
+                &[("instance", host.as_str())],
+                u8::from(*is_up),
+            );
+        }
+        out.push_str(&builder.build());
+        out
+    }
+}
+
+/// Append `line`, a sample of metric `name`, to `out`. `via="all-smi-proxy"`
+/// goes first, then `instance="<host>"` unless the node already set it. A
+/// `via` label of the node's own is kept as `upstream_via`.
+fn relabel(out: &mut String, line: &str, name: &str, host: &str, present: &LabelMap<'_>) {
+    let rest = line[name.len()..].trim_start();
+    out.push_str(name);
+    out.push('{');
+    out.push_str(VIA_LABEL);
+    if !present.contains_key("instance") {
+        let _ = write!(out, r#",instance="{}""#, escape_label_value(host));
+    }
+    match rest.strip_prefix('{') {
+        Some(labels) if labels.trim_start().starts_with('}') => out.push_str(labels.trim_start()),
+        Some(labels) => {
+            out.push(',');
+            match present.get_key_value("via") {
+                Some((key, _)) => {
+                    // Parsed label names borrow from the line
+                    let at = key.as_ptr() as usize - labels.as_ptr() as usize;
+                    out.push_str(&labels[..at]);
+                    out.push_str("upstream_via");
+                    out.push_str(&labels[at + key.len()..]);
+                }
+                None => out.push_str(labels),
+            }
+        }
+        None => {
+            out.push_str("} ");
+            out.push_str(rest);
+        }
+    }
+    out.push('\n');
+}
+
+/// Scrape every host once and merge what they answered.
+async fn scrape(client: &NetworkClient, hosts: &[String], semaphore: &Arc<Semaphore>) -> String {
+    let mut federation = Federation::default();
+    client
+        .fetch_payloads(hosts, semaphore, |host, payload| match payload {
+            Ok(text) => federation.add(&host, &text),
+            Err(e) => {
+                tracing::debug!("Failed to scrape {host}: {e}");
+                federation.add_down(&host);
+            }
+        })
+        .await;
+    // Hosts still pending at the overall timeout
+    for host in hosts {
+        if !federation.has_host(host) {
+            federation.add_down(host);
+        }
+    }
+
+    let mut text = federation.render();
+    text.push_str(&BuildInfoMetricExporter::new(BuildInfo::current()).export_metrics());
+    text
+}
+
+async fn metrics_handler(State(merged): State<watch::Receiver<Arc<str>>>) -> impl IntoResponse {
+    let body = merged.borrow().clone();
+    (
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        body.to_string(),
+    )
+}
+
+/// Run `all-smi proxy` until shutdown is requested. Returns the exit code.
+pub async fn run_proxy_mode(args: &ProxyArgs, shutdown: watch::Receiver<bool>) -> i32 {
+    let hosts = args.hosts.clone().unwrap_or_default();
+    let hostfiles = args.hostfile.clone().unwrap_or_default();
+    let hosts = match load_hosts(&hosts, &hostfiles) {
+        Ok(list) if !list.hosts.is_empty() => list.hosts,
+        Ok(_) => {
+            eprintln!("Error: proxy requires --hosts or --hostfile");
+            return 1;
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return 1;
+        }
+    };
+
+    let (merged_tx, merged_rx) = watch::channel(Arc::<str>::from(""));
+    let interval = Duration::from_secs(args.interval);
+    let scrape_shutdown = shutdown.clone();
+    tokio::spawn(async move {
+        let client = NetworkClient::new();
+        let semaphore = Arc::new(Semaphore::new(EnvConfig::max_concurrent_connections(
+            hosts.len(),
+        )));
+        loop {
+            let text = scrape(&client, &hosts, &semaphore).await;
+            merged_tx.send_replace(Arc::from(text));
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = shutdown_requested(scrape_shutdown.clone()) => break,
+            }
+        }
+    });
+
+    let app = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .with_state(merged_rx);
+    run_tcp_listener(app, args.port, shutdown).await;
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const NODE_A: &str = include_str!("fixtures/node-a.prom");
+    const NODE_B: &str = include_str!("fixtures/node-b.prom");
+
+    fn merged() -> String {
+        let mut federation = Federation::default();
+        federation.add("10.0.0.1:9090", NODE_A);
+        federation.add("10.0.0.2:9090", NODE_B);
+        federation.add_down("10.0.0.3:9090");
+        federation.render()
+    }
+
+    #[test]
+    fn test_merged_payloads_parse_with_one_help_and_type_per_family() {
+        let text = merged();
+
+        let mut labels = LabelMap::new();
+        for line in text.lines() {
+            assert!(
+                line.starts_with('#') || parse_line(line, &mut labels).is_some(),
+                "unparsable line: {line}"
+            );
+        }
+        for family in [
+            "all_smi_gpu_utilization",
+            "all_smi_gpu_memory_used_bytes",
+            "all_smi_cpu_utilization",
+            "all_smi_proxy_up",
+        ] {
+            assert_eq!(text.matches(&format!("# HELP {family} ")).count(), 1);
+            assert_eq!(text.matches(&format!("# TYPE {family} ")).count(), 1);
+        }
+        // Each family's samples follow its own HELP and TYPE
+        let gpu = text.find("# TYPE all_smi_gpu_utilization").unwrap();
+        let cpu = text.find("# TYPE all_smi_cpu_utilization").unwrap();
+        assert!(text.find("all_smi_gpu_utilization{").unwrap() > gpu);
+        assert!(text.find("all_smi_cpu_utilization{").unwrap() > cpu);
+
+        assert!(!text.contains("all_smi_build_info"));
+        assert!(!text.contains("all_smi_metrics_"));
+    }
+
+    #[test]
+    fn test_merged_payloads_keep_each_instance_once_per_series() {
+        let text = merged();
+
+        let mut series: HashMap<(String, String, String), usize> = HashMap::new();
+        let mut labels = LabelMap::new();
+        for line in text.lines() {
+            let Some(sample) = parse_line(line, &mut labels) else {
+                continue;
+            };
+            if sample.name == "all_smi_proxy_up" {
+                continue;
+            }
+            assert_eq!(labels.get("via").map(|v| v.as_ref()), Some("all-smi-proxy"));
+            let key = (
+                sample.name.to_string(),
+                labels["instance"].to_string(),
+                labels
+                    .get("uuid")
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+            );
+            *series.entry(key).or_default() += 1;
+        }
+        assert!(series.values().all(|&count| count == 1), "{series:?}");
+        let instances = |name: &str| {
+            let mut found: Vec<_> = series
+                .keys()
+                .filter(|(n, _, _)| n == name)
+                .map(|(_, instance, _)| instance.as_str())
+                .collect();
+            found.sort();
+            found.dedup();
+            found
+        };
+        assert_eq!(instances("all_smi_gpu_utilization"), ["node-a", "node-b"]);
+        assert_eq!(instances("all_smi_cpu_utilization"), ["node-a", "node-b"]);
+        assert_eq!(series.len(), 8);
+
+        assert!(text.contains(r#"all_smi_proxy_up{instance="10.0.0.1:9090"} 1"#));
+        assert!(text.contains(r#"all_smi_proxy_up{instance="10.0.0.2:9090"} 1"#));
+        assert!(text.contains(r#"all_smi_proxy_up{instance="10.0.0.3:9090"} 0"#));
+    }
+
+    #[test]
+    fn test_sample_without_instance_gets_the_host() {
+        let mut federation = Federation::default();
+        federation.add("10.0.0.1:9090", "plain_metric 1\nlabeled{a=\"b\"} 2\n");
+        let text = federation.render();
+        assert!(text.contains(r#"plain_metric{via="all-smi-proxy",instance="10.0.0.1:9090"} 1"#));
+        assert!(text.contains(r#"labeled{via="all-smi-proxy",instance="10.0.0.1:9090",a="b"} 2"#));
+    }
+
+    #[test]
+    fn test_labels_the_node_set_are_kept_once() {
+        let mut federation = Federation::default();
+        federation.add(
+            "10.0.0.1:9090",
+            concat!(
+                "spaced {a=\"b\"} 1\n",
+                "spaced_plain   2\n",
+                "own_via{via=\"relay\",a=\"b\"} 3\n",
+                "own_both{instance=\"node-a\",via=\"relay\"} 4\n",
+            ),
+        );
+        let text = federation.render();
+        assert!(text.contains(r#"spaced{via="all-smi-proxy",instance="10.0.0.1:9090",a="b"} 1"#));
+        assert!(text.contains(r#"spaced_plain{via="all-smi-proxy",instance="10.0.0.1:9090"} 2"#));
+        assert!(text.contains(
+            r#"own_via{via="all-smi-proxy",instance="10.0.0.1:9090",upstream_via="relay",a="b"} 3"#
+        ));
+        assert!(text
+            .contains(r#"own_both{via="all-smi-proxy",instance="node-a",upstream_via="relay"} 4"#));
+        assert_eq!(text.matches(r#"via="all-smi-proxy""#).count(), 4);
+        assert_eq!(text.matches("upstream_via=").count(), 2);
+    }
+}
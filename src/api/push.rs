@@ -0,0 +1,477 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Push of API mode metrics for hosts that cannot be scraped (`--push-url`).
+//!
+//! Every push interval the current metrics are sent to a Prometheus
+//! Pushgateway, or with the `remote-write` feature to a remote-write
+//! endpoint. Failed pushes are retried with a capped exponential backoff and
+//! counted in `all_smi_push_failures_total`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::CONTENT_TYPE;
+use tokio::sync::watch;
+use url::Url;
+
+use crate::api::handlers::{export_all_metrics, SharedState};
+use crate::api::server::shutdown_requested;
+use crate::cli::{PushArgs, PushFormat};
+
+/// `job` label of pushed series, and the Pushgateway grouping key job
+const PUSH_JOB: &str = "all_smi";
+
+/// Timeout of a single push request
+const PUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay before the first retry of a failed push
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Retries back off up to this delay while the endpoint stays unreachable
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// How often to check whether the first collection has finished
+const FIRST_COLLECTION_POLL: Duration = Duration::from_millis(200);
+
+/// Longest response body quoted in a push error
+const MAX_ERROR_BODY: usize = 256;
+
+static PUSH_STATS: OnceLock<PushStats> = OnceLock::new();
+
+/// Counters exported as the `all_smi_push_*` metrics.
+pub struct PushStats {
+    format: PushFormat,
+    attempts: AtomicU64,
+    failures: AtomicU64,
+    /// Unix time of the last successful push, 0 before the first
+    last_success: AtomicU64,
+}
+
+impl PushStats {
+    fn new(format: PushFormat) -> Self {
+        Self {
+            format,
+            attempts: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            last_success: AtomicU64::new(0),
+        }
+    }
+
+    pub fn format(&self) -> &'static str {
+        match self.format {
+            PushFormat::Pushgateway => "pushgateway",
+            #[cfg(feature = "remote-write")]
+            PushFormat::RemoteWrite => "remote-write",
+        }
+    }
+
+    pub fn attempts(&self) -> u64 {
+        self.attempts.load(Ordering::Relaxed)
+    }
+
+    pub fn failures(&self) -> u64 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    pub fn last_success(&self) -> Option<u64> {
+        Some(self.last_success.load(Ordering::Relaxed)).filter(|&t| t > 0)
+    }
+}
+
+/// Push counters, once pushing has started.
+pub fn push_stats() -> Option<&'static PushStats> {
+    PUSH_STATS.get()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PushError {
+    #[error("cannot read password file {path}: {source}")]
+    PasswordFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("endpoint returned {status}: {body}")]
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[cfg(feature = "remote-write")]
+    #[error("snappy compression failed: {0}")]
+    Compress(#[from] snap::Error),
+}
+
+/// Delay before the next retry: doubles from [`MIN_RETRY_DELAY`] on every
+/// failure, up to [`MAX_RETRY_DELAY`].
+#[derive(Debug)]
+struct Backoff {
+    next: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            next: MIN_RETRY_DELAY,
+        }
+    }
+}
+
+impl Backoff {
+    fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(MAX_RETRY_DELAY);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.next = MIN_RETRY_DELAY;
+    }
+}
+
+/// Sends rendered metrics to the push endpoint.
+pub struct Pusher {
+    client: reqwest::Client,
+    url: Url,
+    format: PushFormat,
+    auth: Option<(String, String)>,
+    #[cfg_attr(not(feature = "remote-write"), allow(dead_code))]
+    host: String,
+}
+
+impl Pusher {
+    pub fn new(args: &PushArgs, url: &Url, host: &str) -> Result<Self, PushError> {
+        let auth = match &args.push_username {
+            Some(username) => {
+                let password = match &args.push_password_file {
+                    Some(path) => std::fs::read_to_string(path)
+                        .map_err(|source| PushError::PasswordFile {
+                            path: path.clone(),
+                            source,
+                        })?
+                        .trim()
+                        .to_string(),
+                    None => String::new(),
+                };
+                Some((username.clone(), password))
+            }
+            None => None,
+        };
+        let url = match args.push_format {
+            PushFormat::Pushgateway => pushgateway_url(url, host),
+            #[cfg(feature = "remote-write")]
+            PushFormat::RemoteWrite => url.clone(),
+        };
+        let client = reqwest::Client::builder()
+            .timeout(PUSH_TIMEOUT)
+            .user_agent(concat!("all-smi/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Self {
+            client,
+            url,
+            format: args.push_format,
+            auth,
+            host: host.to_string(),
+        })
+    }
+
+    /// Send one set of metrics in Prometheus text format.
+    async fn push(&self, metrics: &str) -> Result<(), PushError> {
+        let request = match self.format {
+            PushFormat::Pushgateway => self
+                .client
+                .post(self.url.clone())
+                .header(CONTENT_TYPE, "text/plain; version=0.0.4")
+                .body(group_families(metrics)),
+            #[cfg(feature = "remote-write")]
+            PushFormat::RemoteWrite => {
+                let timestamp_ms = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as i64;
+                let request = super::remote_write::write_request(
+                    metrics,
+                    &[("job", PUSH_JOB), ("instance", &self.host)],
+                    timestamp_ms,
+                );
+                self.client
+                    .post(self.url.clone())
+                    .header(CONTENT_TYPE, "application/x-protobuf")
+                    .header(reqwest::header::CONTENT_ENCODING, "snappy")
+                    .header("X-Prometheus-Remote-Write-Version", "0.1.0")
+                    .body(super::remote_write::encode(&request)?)
+            }
+        };
+        let request = match &self.auth {
+            Some((username, password)) => request.basic_auth(username, Some(password)),
+            None => request,
+        };
+
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let mut body = response.text().await.unwrap_or_default();
+        if body.len() > MAX_ERROR_BODY {
+            let mut end = MAX_ERROR_BODY;
+            while !body.is_char_boundary(end) {
+                end -= 1;
+            }
+            body.truncate(end);
+        }
+        Err(PushError::Status {
+            status,
+            body: body.trim().to_string(),
+        })
+    }
+}
+
+/// Push the metrics in `state` every `interval` until shutdown is requested.
+/// Failed pushes are retried after a backoff instead of the interval.
+pub async fn run_push(
+    pusher: Pusher,
+    interval: Duration,
+    state: SharedState,
+    shutdown: watch::Receiver<bool>,
+) {
+    let stats = PUSH_STATS.get_or_init(|| PushStats::new(pusher.format));
+    let mut backoff = Backoff::default();
+    tracing::info!(
+        "Pushing metrics to {} every {}s",
+        pusher.url,
+        interval.as_secs()
+    );
+
+    loop {
+        let metrics = {
+            let state = state.read().await;
+            (!state.loading).then(|| export_all_metrics(&state))
+        };
+        let delay = match metrics {
+            // Nothing to push before the first collection
+            None => FIRST_COLLECTION_POLL,
+            Some(metrics) => {
+                stats.attempts.fetch_add(1, Ordering::Relaxed);
+                match pusher.push(&metrics).await {
+                    Ok(()) => {
+                        let now = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap_or_default()
+                            .as_secs();
+                        stats.last_success.store(now, Ordering::Relaxed);
+                        backoff.reset();
+                        interval
+                    }
+                    Err(e) => {
+                        stats.failures.fetch_add(1, Ordering::Relaxed);
+                        let delay = backoff.next_delay();
+                        tracing::warn!(
+                            "Push to {} failed, retrying in {}s: {e}",
+                            pusher.url,
+                            delay.as_secs()
+                        );
+                        delay
+                    }
+                }
+            }
+        };
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = shutdown_requested(shutdown.clone()) => break,
+        }
+    }
+}
+
+/// Pushgateway URL for this host's group,
+/// `<base>/metrics/job/all_smi/instance/<host>`. A URL that already names a
+/// group is used as given.
+fn pushgateway_url(base: &Url, host: &str) -> Url {
+    if base.path().contains("/metrics/job/") {
+        return base.clone();
+    }
+    let mut url = base.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments
+            .pop_if_empty()
+            .extend(["metrics", "job", PUSH_JOB, "instance", host]);
+    }
+    url
+}
+
+/// Regroup exposition text so each metric family appears once, its HELP and
+/// TYPE lines followed by all of its samples. Scrapers accept a family
+/// repeated per device, but the Pushgateway and the node_exporter textfile
+/// collector reject a second HELP or TYPE.
+pub(crate) fn group_families(text: &str) -> String {
+    #[derive(Default)]
+    struct Family<'a> {
+        help: Option<&'a str>,
+        type_: Option<&'a str>,
+        samples: Vec<&'a str>,
+    }
+
+    let mut order = Vec::new();
+    let mut families: HashMap<&str, Family> = HashMap::new();
+    for line in text.lines().map(str::trim) {
+        let (name, kind) = if let Some(rest) = line.strip_prefix("# HELP ") {
+            (rest.split_whitespace().next(), "HELP")
+        } else if let Some(rest) = line.strip_prefix("# TYPE ") {
+            (rest.split_whitespace().next(), "TYPE")
+        } else if line.is_empty() || line.starts_with('#') {
+            continue;
+        } else {
+            (line.split(['{', ' ', '\t']).next(), "sample")
+        };
+        let Some(name) = name.filter(|name| !name.is_empty()) else {
+            continue;
+        };
+
+        let family = families.entry(name).or_insert_with(|| {
+            order.push(name);
+            Family::default()
+        });
+        match kind {
+            "HELP" => {
+                family.help.get_or_insert(line);
+            }
+            "TYPE" => {
+                family.type_.get_or_insert(line);
+            }
+            _ => family.samples.push(line),
+        }
+    }
+
+    let mut output = String::with_capacity(text.len());
+    for name in order {
+        let family = &families[name];
+        for line in family
+            .help
+            .iter()
+            .chain(&family.type_)
+            .chain(&family.samples)
+        {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::{Request, State};
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use axum::Router;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_pushgateway_url() {
+        let url = |s: &str| Url::parse(s).unwrap();
+        assert_eq!(
+            pushgateway_url(&url("http://gw:9091"), "node1").as_str(),
+            "http://gw:9091/metrics/job/all_smi/instance/node1"
+        );
+        assert_eq!(
+            pushgateway_url(&url("https://gw.example/push/"), "edge 1").as_str(),
+            "https://gw.example/push/metrics/job/all_smi/instance/edge%201"
+        );
+        let grouped = url("http://gw:9091/metrics/job/edge/site/a");
+        assert_eq!(pushgateway_url(&grouped, "node1"), grouped);
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let mut backoff = Backoff::default();
+        let delays: Vec<u64> = (0..8).map(|_| backoff.next_delay().as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60]);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), MIN_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_group_families() {
+        let text = "# HELP a_bytes Bytes\n# TYPE a_bytes gauge\na_bytes{index=\"0\"} 1\n\
+                    # HELP b Other\n# TYPE b gauge\nb 2\n\
+                    # HELP a_bytes Bytes\n# TYPE a_bytes gauge\na_bytes{index=\"1\"} 3\n";
+        assert_eq!(
+            group_families(text),
+            "# HELP a_bytes Bytes\n# TYPE a_bytes gauge\na_bytes{index=\"0\"} 1\n\
+             a_bytes{index=\"1\"} 3\n# HELP b Other\n# TYPE b gauge\nb 2\n"
+        );
+    }
+
+    type Received = Arc<Mutex<Vec<(String, Option<String>, String)>>>;
+
+    async fn record(State(received): State<Received>, request: Request) -> StatusCode {
+        let path = request.uri().path().to_string();
+        let auth = request
+            .headers()
+            .get("authorization")
+            .map(|v| v.to_str().unwrap().to_string());
+        let body = axum::body::to_bytes(request.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let status = if body.contains("fail") {
+            StatusCode::BAD_REQUEST
+        } else {
+            StatusCode::OK
+        };
+        received.lock().unwrap().push((path, auth, body));
+        status
+    }
+
+    #[tokio::test]
+    async fn test_push_to_pushgateway() {
+        let received = Received::default();
+        let app = Router::new()
+            .route("/metrics/job/{job}/instance/{instance}", post(record))
+            .with_state(received.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let password_file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(password_file.path(), "secret\n").unwrap();
+        let args = PushArgs {
+            push_username: Some("edge".to_string()),
+            push_password_file: Some(password_file.path().to_path_buf()),
+            ..Default::default()
+        };
+        let url = Url::parse(&format!("http://{addr}")).unwrap();
+        let pusher = Pusher::new(&args, &url, "node1").unwrap();
+
+        pusher.push("# HELP up Up\nup 1\n").await.unwrap();
+        let err = pusher.push("fail 1\n").await.unwrap_err();
+        assert!(matches!(err, PushError::Status { status, .. } if status == 400));
+
+        let received = received.lock().unwrap();
+        let (path, auth, body) = &received[0];
+        assert_eq!(path, "/metrics/job/all_smi/instance/node1");
+        // base64("edge:secret")
+        assert_eq!(auth.as_deref(), Some("Basic ZWRnZTpzZWNyZXQ="));
+        assert_eq!(body, "# HELP up Up\nup 1\n");
+    }
+}
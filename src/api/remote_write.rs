@@ -0,0 +1,175 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus remote-write encoding of exported metrics (`remote-write`
+//! feature).
+//!
+//! Samples are parsed back out of the text exposition served on `/metrics`
+//! so both push formats carry exactly the same series.
+
+use std::collections::BTreeMap;
+
+use prost::Message;
+
+use crate::parsing::prometheus::{parse_line, LabelMap};
+
+pub mod proto {
+    tonic::include_proto!("prometheus");
+}
+
+use proto::{Label, Sample, TimeSeries, WriteRequest};
+
+/// One time series per distinct label set in `exposition`, each with a
+/// single sample stamped `timestamp_ms` (milliseconds since the epoch).
+/// `default_labels` are added to series that do not carry them already.
+/// If a series appears twice, the last value wins.
+pub fn write_request(
+    exposition: &str,
+    default_labels: &[(&str, &str)],
+    timestamp_ms: i64,
+) -> WriteRequest {
+    // Sorted label pairs, __name__ included, so series are ordered and unique
+    let mut series: BTreeMap<Vec<(String, String)>, f64> = BTreeMap::new();
+    let mut labels = LabelMap::new();
+    for line in exposition.lines() {
+        let Some(sample) = parse_line(line, &mut labels) else {
+            continue;
+        };
+        let mut pairs: Vec<(String, String)> = labels
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        pairs.push(("__name__".to_string(), sample.name.to_string()));
+        for (name, value) in default_labels {
+            if !labels.contains_key(name) {
+                pairs.push((name.to_string(), value.to_string()));
+            }
+        }
+        pairs.sort();
+        series.insert(pairs, sample.value);
+    }
+
+    WriteRequest {
+        timeseries: series
+            .into_iter()
+            .map(|(labels, value)| TimeSeries {
+                labels: labels
+                    .into_iter()
+                    .map(|(name, value)| Label { name, value })
+                    .collect(),
+                samples: vec![Sample {
+                    value,
+                    timestamp: timestamp_ms,
+                }],
+            })
+            .collect(),
+    }
+}
+
+/// Request body: the protobuf encoding, snappy block compressed.
+pub fn encode(request: &WriteRequest) -> Result<Vec<u8>, snap::Error> {
+    snap::raw::Encoder::new().compress_vec(&request.encode_to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protobuf_encoding() {
+        let request = write_request("a 1\n", &[], 1000);
+        let mut expected = vec![0x0a, 29, 0x0a, 13];
+        // Label { name: "__name__", value: "a" }
+        expected.extend([0x0a, 8]);
+        expected.extend(b"__name__");
+        expected.extend([0x12, 1, b'a']);
+        // Sample { value: 1.0, timestamp: 1000 }
+        expected.extend([0x12, 12, 0x09]);
+        expected.extend(1.0f64.to_le_bytes());
+        expected.extend([0x10, 0xe8, 0x07]);
+        assert_eq!(request.encode_to_vec(), expected);
+
+        let body = encode(&request).unwrap();
+        let decoded = snap::raw::Decoder::new().decompress_vec(&body).unwrap();
+        assert_eq!(decoded, expected);
+        assert_eq!(WriteRequest::decode(decoded.as_slice()).unwrap(), request);
+    }
+
+    #[test]
+    fn test_series_labels_and_timestamps() {
+        let exposition = "\
+# HELP all_smi_gpu_utilization GPU utilization percentage
+# TYPE all_smi_gpu_utilization gauge
+all_smi_gpu_utilization{gpu=\"H100\", instance=\"node1\", index=\"1\"} 40
+all_smi_gpu_utilization{gpu=\"H100\", instance=\"node1\", index=\"0\"} 12.5
+all_smi_container_runtime_info{hostname=\"node1\",runtime=\"docker\"} 1
+all_smi_gpu_utilization{gpu=\"H100\", instance=\"node1\", index=\"0\"} 13
+";
+        let request = write_request(
+            exposition,
+            &[("job", "all_smi"), ("instance", "edge-1")],
+            1_700_000_000_123,
+        );
+
+        let series: Vec<(Vec<(&str, &str)>, f64)> = request
+            .timeseries
+            .iter()
+            .map(|ts| {
+                assert_eq!(ts.samples.len(), 1);
+                assert_eq!(ts.samples[0].timestamp, 1_700_000_000_123);
+                let labels = ts
+                    .labels
+                    .iter()
+                    .map(|l| (l.name.as_str(), l.value.as_str()))
+                    .collect();
+                (labels, ts.samples[0].value)
+            })
+            .collect();
+        assert_eq!(
+            series,
+            vec![
+                (
+                    vec![
+                        ("__name__", "all_smi_container_runtime_info"),
+                        ("hostname", "node1"),
+                        ("instance", "edge-1"),
+                        ("job", "all_smi"),
+                        ("runtime", "docker"),
+                    ],
+                    1.0
+                ),
+                (
+                    vec![
+                        ("__name__", "all_smi_gpu_utilization"),
+                        ("gpu", "H100"),
+                        ("index", "0"),
+                        ("instance", "node1"),
+                        ("job", "all_smi"),
+                    ],
+                    13.0
+                ),
+                (
+                    vec![
+                        ("__name__", "all_smi_gpu_utilization"),
+                        ("gpu", "H100"),
+                        ("index", "1"),
+                        ("instance", "node1"),
+                        ("job", "all_smi"),
+                    ],
+                    40.0
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serving `/metrics` to eager scrapers.
+//!
+//! The collection task renders the exposition text once per update, under
+//! the same write lock that applies the new data, so a scrape never sees
+//! text older than the state. Scrapes in between share that text. Should the
+//! collection task stop updating, a scrape re-renders once the text is older
+//! than the fastest collection interval, so staleness still shows up.
+//!
+//! `--max-requests-per-second` caps scrapes with a token bucket holding one
+//! second's worth of requests; scrapes over the cap get 429.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::app_state::AppState;
+
+use super::handlers::export_all_metrics;
+
+/// Counters exported as the `all_smi_metrics_*` self-metrics.
+#[derive(Default)]
+pub struct ScrapeStats {
+    requests: AtomicU64,
+    cache_hits: AtomicU64,
+    rate_limited: AtomicU64,
+    renders: AtomicU64,
+    /// Duration of the last render in microseconds
+    last_render_us: AtomicU64,
+}
+
+impl ScrapeStats {
+    pub fn requests(&self) -> u64 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    pub fn cache_hits(&self) -> u64 {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    pub fn rate_limited(&self) -> u64 {
+        self.rate_limited.load(Ordering::Relaxed)
+    }
+
+    pub fn renders(&self) -> u64 {
+        self.renders.load(Ordering::Relaxed)
+    }
+
+    pub fn last_render_seconds(&self) -> f64 {
+        self.last_render_us.load(Ordering::Relaxed) as f64 / 1e6
+    }
+
+    pub fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_rate_limited(&self) {
+        self.rate_limited.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+struct Rendered {
+    text: Arc<str>,
+    at: Instant,
+}
+
+/// The last rendered exposition text
+pub struct MetricsCache {
+    rendered: RwLock<Option<Rendered>>,
+    max_age: Duration,
+    stats: ScrapeStats,
+}
+
+impl MetricsCache {
+    /// Text older than `max_age` is rendered again on the next scrape
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            rendered: RwLock::new(None),
+            max_age,
+            stats: ScrapeStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> &ScrapeStats {
+        &self.stats
+    }
+
+    /// Render `state` for the scrapes until the next update
+    pub fn refresh(&self, state: &AppState) -> Arc<str> {
+        let started = Instant::now();
+        let text: Arc<str> = export_all_metrics(state).into();
+        let elapsed = started.elapsed();
+        self.stats.renders.fetch_add(1, Ordering::Relaxed);
+        self.stats
+            .last_render_us
+            .store(elapsed.as_micros() as u64, Ordering::Relaxed);
+        *self.rendered.write().unwrap_or_else(|e| e.into_inner()) = Some(Rendered {
+            text: text.clone(),
+            at: started,
+        });
+        text
+    }
+
+    /// Text for a scrape of `state`: the cached one while it is fresh
+    pub fn get(&self, state: &AppState) -> Arc<str> {
+        let cached = self
+            .rendered
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|rendered| rendered.at.elapsed() < self.max_age)
+            .map(|rendered| rendered.text.clone());
+        match cached {
+            Some(text) => {
+                self.stats.cache_hits.fetch_add(1, Ordering::Relaxed);
+                text
+            }
+            None => self.refresh(state),
+        }
+    }
+}
+
+/// Token bucket admitting `per_second` requests a second, in bursts of up
+/// to that many
+pub struct RateLimiter {
+    per_second: f64,
+    /// Tokens left and when they were counted
+    bucket: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    pub fn new(per_second: u32, now: Instant) -> Self {
+        let per_second = f64::from(per_second.max(1));
+        Self {
+            per_second,
+            bucket: Mutex::new((per_second, now)),
+        }
+    }
+
+    /// Take a token, or return how long until one is available
+    pub fn acquire(&self, now: Instant) -> Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let (tokens, counted_at) = *bucket;
+        let refilled = now.saturating_duration_since(counted_at).as_secs_f64() * self.per_second;
+        let tokens = (tokens + refilled).min(self.per_second);
+        if tokens >= 1.0 {
+            *bucket = (tokens - 1.0, now);
+            Ok(())
+        } else {
+            *bucket = (tokens, now);
+            Err(Duration::from_secs_f64((1.0 - tokens) / self.per_second))
+        }
+    }
+}
+
+/// `Retry-After` value for a wait: whole seconds, at least one
+pub fn retry_after_secs(wait: Duration) -> u64 {
+    wait.as_secs_f64().ceil().max(1.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::GpuInfo;
+
+    fn state(utilization: f64) -> AppState {
+        let mut state = AppState::new();
+        state.gpu_info = vec![GpuInfo {
+            uuid: "GPU-0".to_string(),
+            name: "Test GPU".to_string(),
+            device_type: "GPU".to_string(),
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+            instance: "node1".to_string(),
+            utilization,
+            temperature: 60,
+            used_memory: 1 << 30,
+            total_memory: 8 << 30,
+            frequency: 1500,
+            power_consumption: 200.0,
+            ..Default::default()
+        }];
+        state
+    }
+
+    #[test]
+    fn test_cache_follows_state_updates() {
+        let cache = MetricsCache::new(Duration::from_secs(60));
+        let mut state = state(25.0);
+        let rendered = cache.refresh(&state);
+
+        // Scrapes between updates share the rendered text
+        let first = cache.get(&state);
+        let second = cache.get(&state);
+        assert!(Arc::ptr_eq(&first, &rendered));
+        assert!(Arc::ptr_eq(&second, &rendered));
+        assert_eq!(cache.stats().cache_hits(), 2);
+        assert_eq!(cache.stats().renders(), 1);
+
+        // The next update replaces it for every later scrape
+        state.gpu_info[0].utilization = 75.0;
+        cache.refresh(&state);
+        let text = cache.get(&state);
+        assert!(text.contains("all_smi_gpu_utilization{") && text.contains("} 75\n"));
+        assert!(!text.contains("} 25\n"));
+        assert_eq!(cache.stats().renders(), 2);
+    }
+
+    #[test]
+    fn test_outdated_text_is_rendered_again() {
+        let cache = MetricsCache::new(Duration::ZERO);
+        let state = state(25.0);
+        let rendered = cache.refresh(&state);
+        assert!(!Arc::ptr_eq(&cache.get(&state), &rendered));
+        assert_eq!(cache.stats().cache_hits(), 0);
+        assert_eq!(cache.stats().renders(), 2);
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let start = Instant::now();
+        let limiter = RateLimiter::new(2, start);
+        assert_eq!(limiter.acquire(start), Ok(()));
+        assert_eq!(limiter.acquire(start), Ok(()));
+        let wait = limiter.acquire(start).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+        assert_eq!(retry_after_secs(wait), 1);
+
+        // A token is back half a second later, and idle time does not bank
+        // more than a second's worth
+        assert_eq!(limiter.acquire(start + Duration::from_millis(500)), Ok(()));
+        let later = start + Duration::from_secs(10);
+        assert_eq!(limiter.acquire(later), Ok(()));
+        assert_eq!(limiter.acquire(later), Ok(()));
+        assert!(limiter.acquire(later).is_err());
+    }
+}
@@ -13,24 +13,42 @@
 // limitations under the License.
 
 use axum::{routing::get, Router};
-use std::time::Duration;
+use std::future::IntoFuture;
+use std::time::{Duration, Instant};
 use sysinfo::Disks;
 use tokio::net::TcpListener;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[cfg(unix)]
 use std::path::PathBuf;
 #[cfg(unix)]
 use tokio::net::UnixListener;
 
-use crate::api::handlers::{metrics_handler, SharedState};
-use crate::app_state::AppState;
+use crate::api::handlers::{
+    debug_logs_handler, metadata_handler, metrics_handler, processes_handler, ApiState, SharedState,
+};
+use crate::api::staleness::Staleness;
+use crate::api::textfile::TextfileWriter;
+use crate::app_state::{AppState, CollectionStamp};
 use crate::cli::ApiArgs;
-use crate::device::{get_cpu_readers, get_gpu_readers, get_memory_readers};
+use crate::device::device_order::stamp_indices;
+use crate::device::gpu_presence::GpuPresence;
+#[cfg(target_os = "linux")]
+use crate::device::pod_resources::PodResourcesWatcher;
+use crate::device::process_list::{summarize_by_user, UserProcessSummary};
+use crate::device::reader_supervisor::ReaderSupervisor;
+use crate::device::{
+    create_chassis_reader, get_memory_readers, ChassisInfo, ChassisReader, CollectionFilter,
+    CpuInfo, CpuReader, GpuInfo, GpuReader, MemoryInfo, MemoryReader, ProcessInfo, Subsystem,
+    SubsystemIntervals,
+};
+use crate::network::infiniband::get_ib_port_info;
+use crate::network::{IbPortInfo, NetworkInfo, NetworkReader};
+use crate::storage::health::disk_health;
 use crate::storage::info::StorageInfo;
+use crate::storage::inodes::read_inode_counts;
 use crate::utils::{filter_docker_aware_disks, get_hostname};
 
 /// Get the default Unix domain socket path for the current platform.
@@ -85,84 +103,510 @@ fn remove_stale_socket(path: &std::path::Path) -> std::io::Result<()> {
     }
 }
 
-/// Set restrictive permissions (0o600) on the socket file.
-/// This ensures only the owner can connect to the socket.
+/// Set the `--socket-mode` permissions (0o600 by default) on the socket file.
+/// The default ensures only the owner can connect to the socket.
 #[cfg(unix)]
-fn set_socket_permissions(path: &std::path::Path) -> std::io::Result<()> {
+fn set_socket_permissions(path: &std::path::Path, mode: u32) -> std::io::Result<()> {
     use std::os::unix::fs::PermissionsExt;
-    let permissions = std::fs::Permissions::from_mode(0o600);
+    let permissions = std::fs::Permissions::from_mode(mode);
     std::fs::set_permissions(path, permissions)
 }
 
-/// Run the API server with TCP and optionally Unix Domain Socket listeners.
-pub async fn run_api_mode(args: &ApiArgs) {
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "all_smi=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+/// How long in-flight requests may take to finish once shutdown is requested
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Resolves once `true` is sent on `shutdown` or its sender is dropped.
+pub async fn shutdown_requested(mut shutdown: watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|requested| *requested).await;
+}
+
+/// Drive a server set up with graceful shutdown. Once shutdown is requested,
+/// in-flight requests get [`SHUTDOWN_DRAIN_TIMEOUT`] to finish before the
+/// server is dropped.
+async fn drain_on_shutdown<F>(server: F, shutdown: watch::Receiver<bool>) -> std::io::Result<()>
+where
+    F: IntoFuture<Output = std::io::Result<()>>,
+{
+    let server = server.into_future();
+    tokio::pin!(server);
+    tokio::select! {
+        result = &mut server => return result,
+        _ = shutdown_requested(shutdown) => {}
+    }
+    match tokio::time::timeout(SHUTDOWN_DRAIN_TIMEOUT, server).await {
+        Ok(result) => result,
+        Err(_) => {
+            tracing::warn!(
+                "In-flight requests did not finish within {}s, closing them",
+                SHUTDOWN_DRAIN_TIMEOUT.as_secs()
+            );
+            Ok(())
+        }
+    }
+}
 
+/// Run the API server with TCP and optionally Unix Domain Socket listeners
+/// until `true` is sent on `shutdown`.
+pub async fn run_api_mode(args: &ApiArgs, shutdown: watch::Receiver<bool>) {
     println!("Starting API mode...");
+    crate::api::metrics::set_label_allowlist(args.labels.clone());
+    crate::api::metrics::process::set_process_top(args.process_top);
+    crate::api::metrics::set_pod_labels(args.kubernetes);
+    crate::api::metrics::set_session_labels(
+        crate::utils::runtime_environment::BackendAiSession::from_env().labels(),
+    );
+    let intervals = args.subsystem_intervals();
+    crate::api::staleness::set_staleness(Staleness::new(
+        args.staleness_window,
+        intervals,
+        args.staleness_policy,
+    ));
     let state = SharedState::new(RwLock::new(AppState::new()));
     let state_clone = state.clone();
+    // Rendered text outlives an update only when the collector stalls
+    let fastest = Subsystem::ALL
+        .iter()
+        .map(|subsystem| intervals.get(*subsystem))
+        .min()
+        .unwrap_or(args.interval);
+    let api = ApiState::new(
+        state.clone(),
+        Duration::from_secs(fastest),
+        args.max_requests_per_second,
+    );
+    #[cfg(feature = "web")]
+    let web = args.web;
+    #[cfg(not(feature = "web"))]
+    let web = false;
+    // Snapshots for `/ws` are only serialized when the dashboard is served
+    #[cfg(feature = "web")]
+    let (live, api) = if web {
+        let (live, subscriber) = crate::api::web::live_channel(get_hostname());
+        (Some(live), api.with_live(subscriber))
+    } else {
+        (None, api)
+    };
+    let metrics_cache = api.metrics.clone();
     let processes = args.processes;
     let interval = args.interval;
+    let filter = CollectionFilter {
+        order: args.sort_by,
+        ..args.subsystems.collection_filter()
+    };
+    let kubernetes = args.kubernetes;
 
-    // Spawn background task for collecting metrics
-    tokio::spawn(async move {
-        let gpu_readers = get_gpu_readers();
-        let cpu_readers = get_cpu_readers();
-        let memory_readers = get_memory_readers();
-        let mut disks = Disks::new_with_refreshed_list();
-        loop {
-            let all_gpu_info = gpu_readers
-                .iter()
-                .flat_map(|reader| reader.get_gpu_info())
-                .collect();
-
-            let all_cpu_info = cpu_readers
-                .iter()
-                .flat_map(|reader| reader.get_cpu_info())
-                .collect();
-
-            let all_memory_info = memory_readers
-                .iter()
-                .flat_map(|reader| reader.get_memory_info())
-                .collect();
-
-            let all_processes = if processes {
-                gpu_readers
-                    .iter()
-                    .flat_map(|reader| reader.get_process_info())
-                    .collect()
-            } else {
-                Vec::new()
-            };
+    let pusher = args.push.push_url.as_ref().and_then(|url| {
+        match crate::api::push::Pusher::new(&args.push, url, &get_hostname()) {
+            Ok(pusher) => Some(pusher),
+            Err(e) => {
+                tracing::error!("Cannot push metrics to {url}: {e}");
+                eprintln!("Error: Cannot push metrics to {url}: {e}");
+                None
+            }
+        }
+    });
+    let push_interval = Duration::from_secs(args.push.push_interval.unwrap_or(interval));
+    let push_task = pusher.map(|pusher| {
+        tokio::spawn(crate::api::push::run_push(
+            pusher,
+            push_interval,
+            state.clone(),
+            shutdown.clone(),
+        ))
+    });
 
-            // Refresh disk info in-place instead of creating a new Disks instance
-            disks.refresh(true);
-            let storage_info = collect_storage_info_from(&disks);
+    let textfile = args
+        .textfile_dir
+        .as_deref()
+        .and_then(|dir| match TextfileWriter::new(dir) {
+            Ok(writer) => Some(writer),
+            Err(e) => {
+                tracing::error!("Cannot write metrics to {}: {e}", dir.display());
+                eprintln!("Error: Cannot write metrics to {}: {e}", dir.display());
+                None
+            }
+        });
 
+    #[cfg(feature = "grpc")]
+    let (mut publisher, snapshots) = crate::grpc::snapshot_channel(get_hostname());
+    #[cfg(feature = "grpc")]
+    let grpc_server = args
+        .grpc_port
+        .map(|port| tokio::spawn(run_grpc_listener(port, snapshots, shutdown.clone())));
+
+    // Spawn background task for collecting metrics
+    let collector_shutdown = shutdown.clone();
+    let collector = tokio::spawn(async move {
+        let mut collector = Collector::new(filter, processes, kubernetes);
+        let mut schedule = Schedule::new(intervals, Instant::now());
+        #[cfg(all(unix, feature = "systemd"))]
+        let mut notifier = crate::api::systemd::SystemdNotifier::from_env();
+        loop {
+            // Only the subsystems whose interval has elapsed are collected;
+            // the rest keep serving their last values
+            let due = schedule.due(Instant::now());
+            let pass = collector.collect_subsystems(&due).await;
             let mut state = state_clone.write().await;
-            state.gpu_info = all_gpu_info;
-            state.cpu_info = all_cpu_info;
-            state.memory_info = all_memory_info;
-            state.process_info = all_processes;
-            state.storage_info = storage_info;
-            if state.loading {
-                state.loading = false;
+            pass.apply(&mut state);
+            #[cfg(feature = "grpc")]
+            publisher.publish(&state);
+            #[cfg(feature = "web")]
+            if let Some(live) = &live {
+                live.publish(&state);
             }
+            let metrics = metrics_cache.refresh(&state);
 
             drop(state);
-            tokio::time::sleep(Duration::from_secs(interval)).await;
+            if let Some(writer) = &textfile {
+                writer.publish(&metrics);
+            }
+            #[cfg(all(unix, feature = "systemd"))]
+            notifier.collection_finished();
+            schedule.reschedule(&due, Instant::now());
+
+            tokio::select! {
+                _ = tokio::time::sleep_until(schedule.next_due().into()) => {}
+                _ = shutdown_requested(collector_shutdown.clone()) => {
+                    #[cfg(all(unix, feature = "systemd"))]
+                    notifier.stopping();
+                    break;
+                }
+            }
         }
     });
 
-    // Create the router with shared state
-    let app = Router::new()
+    serve(build_router(api, web), args, shutdown.clone()).await;
+
+    // A listener that failed to start returns without a shutdown request
+    if !*shutdown.borrow() {
+        collector.abort();
+    }
+    let _ = collector.await;
+    if let Some(push_task) = push_task {
+        if !*shutdown.borrow() {
+            push_task.abort();
+        }
+        let _ = push_task.await;
+    }
+    // Streams end once the collector drops the publisher
+    #[cfg(feature = "grpc")]
+    if let Some(grpc_server) = grpc_server {
+        if !*shutdown.borrow() {
+            grpc_server.abort();
+        }
+        let _ = grpc_server.await;
+    }
+}
+
+/// Serve the gRPC `MetricsService` on `port` until shutdown is requested
+#[cfg(feature = "grpc")]
+async fn run_grpc_listener(
+    port: u16,
+    snapshots: crate::grpc::SnapshotSubscriber,
+    shutdown: watch::Receiver<bool>,
+) {
+    let listener = match TcpListener::bind(&format!("0.0.0.0:{port}")).await {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::error!("Failed to bind gRPC listener on port {port}: {e}");
+            eprintln!("Error: Failed to bind gRPC listener on port {port}: {e}");
+            return;
+        }
+    };
+    tracing::info!("gRPC server listening on port {port}");
+    let stop = shutdown_requested(shutdown.clone());
+    let server = async move {
+        crate::grpc::serve_grpc(listener, snapshots, stop)
+            .await
+            .map_err(std::io::Error::other)
+    };
+    if let Err(e) = drain_on_shutdown(server, shutdown).await {
+        tracing::error!("gRPC server error: {e}");
+    }
+}
+
+/// Routes served in API mode
+/// Collect once and print the metrics to stdout, for `api --once`
+pub async fn run_api_once(args: &ApiArgs) {
+    crate::api::metrics::set_label_allowlist(args.labels.clone());
+    crate::api::metrics::process::set_process_top(args.process_top);
+    crate::api::metrics::set_pod_labels(args.kubernetes);
+    crate::api::metrics::set_session_labels(
+        crate::utils::runtime_environment::BackendAiSession::from_env().labels(),
+    );
+    let state = collect_once(
+        CollectionFilter {
+            order: args.sort_by,
+            ..args.subsystems.collection_filter()
+        },
+        args.processes,
+        args.kubernetes,
+    )
+    .await;
+    print!("{}", crate::api::handlers::export_all_metrics(&state));
+}
+
+/// State after a single collection pass, as `--once` exports it.
+pub async fn collect_once(filter: CollectionFilter, processes: bool, kubernetes: bool) -> AppState {
+    let mut state = AppState::new();
+    Collector::new(filter, processes, kubernetes)
+        .collect()
+        .await
+        .apply(&mut state);
+    state
+}
+
+/// Readers kept across collection passes
+struct Collector {
+    filter: CollectionFilter,
+    processes: bool,
+    gpu_readers: ReaderSupervisor<dyn GpuReader>,
+    cpu_readers: ReaderSupervisor<dyn CpuReader>,
+    memory_readers: ReaderSupervisor<dyn MemoryReader>,
+    disks: Option<Disks>,
+    network_reader: Option<NetworkReader>,
+    /// Node power, thermal, load and uptime
+    chassis_reader: Box<dyn ChassisReader>,
+    /// Devices seen earlier in the run, reported as lost once they vanish
+    gpu_presence: GpuPresence,
+    /// Pods GPUs are allocated to, `None` off Kubernetes
+    #[cfg(target_os = "linux")]
+    pod_resources: Option<PodResourcesWatcher>,
+}
+
+/// Results of one collection pass. Subsystems that were not due are `None`
+/// and keep their previous values in the state.
+#[derive(Default)]
+struct CollectionPass {
+    gpu_info: Option<Vec<GpuInfo>>,
+    cpu_info: Option<Vec<CpuInfo>>,
+    memory_info: Option<Vec<MemoryInfo>>,
+    process_info: Option<Vec<ProcessInfo>>,
+    user_summaries: Option<Vec<UserProcessSummary>>,
+    storage_info: Option<Vec<StorageInfo>>,
+    network_info: Option<Vec<NetworkInfo>>,
+    infiniband_info: Option<Vec<IbPortInfo>>,
+    chassis_info: Option<Vec<ChassisInfo>>,
+    /// When each enabled collector finished
+    stamps: Vec<(&'static str, CollectionStamp)>,
+}
+
+/// When each subsystem is next due for collection
+struct Schedule {
+    intervals: SubsystemIntervals,
+    next: [Instant; Subsystem::ALL.len()],
+}
+
+impl Schedule {
+    /// Every subsystem is due right away.
+    fn new(intervals: SubsystemIntervals, now: Instant) -> Self {
+        Self {
+            intervals,
+            next: [now; Subsystem::ALL.len()],
+        }
+    }
+
+    fn due(&self, now: Instant) -> Vec<Subsystem> {
+        Subsystem::ALL
+            .into_iter()
+            .filter(|&subsystem| self.next[subsystem as usize] <= now)
+            .collect()
+    }
+
+    /// Wait a full interval after `finished` before collecting `collected`
+    /// again, so a slow reader never runs back to back.
+    fn reschedule(&mut self, collected: &[Subsystem], finished: Instant) {
+        for &subsystem in collected {
+            self.next[subsystem as usize] =
+                finished + Duration::from_secs(self.intervals.get(subsystem));
+        }
+    }
+
+    fn next_due(&self) -> Instant {
+        self.next.into_iter().min().unwrap_or_else(Instant::now)
+    }
+}
+
+impl Collector {
+    #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+    fn new(filter: CollectionFilter, processes: bool, kubernetes: bool) -> Self {
+        let timeout = filter.reader_timeout;
+        Self {
+            gpu_readers: ReaderSupervisor::new("GPU", filter.gpu_readers(), timeout),
+            cpu_readers: ReaderSupervisor::new("CPU", filter.cpu_readers(), timeout),
+            memory_readers: ReaderSupervisor::new("memory", get_memory_readers(), timeout),
+            disks: filter.disk.then(Disks::new_with_refreshed_list),
+            network_reader: filter.network_reader(),
+            chassis_reader: create_chassis_reader(),
+            gpu_presence: GpuPresence::new(),
+            #[cfg(target_os = "linux")]
+            pod_resources: PodResourcesWatcher::detect(kubernetes),
+            filter,
+            processes,
+        }
+    }
+
+    /// Collect every subsystem.
+    async fn collect(&mut self) -> CollectionPass {
+        self.collect_subsystems(&Subsystem::ALL).await
+    }
+
+    /// Device readers that time out keep serving their previous readings,
+    /// but their subsystem is not stamped, so its series go stale. Other
+    /// readers that hang hold up the whole pass, so every stamp ages.
+    async fn collect_subsystems(&mut self, due: &[Subsystem]) -> CollectionPass {
+        let mut pass = CollectionPass::default();
+        for &subsystem in due {
+            match subsystem {
+                Subsystem::Gpu => self.collect_gpu(&mut pass).await,
+                Subsystem::Cpu => self.collect_cpu(&mut pass).await,
+                Subsystem::Memory => self.collect_memory(&mut pass).await,
+                Subsystem::Disk => self.collect_disk(&mut pass),
+                Subsystem::Network => self.collect_network(&mut pass),
+                Subsystem::Chassis => {
+                    pass.chassis_info =
+                        Some(self.chassis_reader.get_chassis_info().into_iter().collect());
+                }
+            }
+        }
+        pass
+    }
+
+    /// Devices and, with --processes, the processes running on them
+    async fn collect_gpu(&mut self, pass: &mut CollectionPass) {
+        let collected = self
+            .gpu_readers
+            .collect("gpu_info", |reader| reader.get_gpu_info())
+            .await;
+        let mut complete = collected.is_complete();
+        let mut gpu_info = collected.into_values();
+        stamp_indices(&mut gpu_info);
+        self.gpu_presence.track(&mut gpu_info);
+        crate::device::numa::annotate_gpu_topology(&mut gpu_info);
+        if self.filter.k8s {
+            crate::device::k8s_allocation::annotate_gpu_allocation(&mut gpu_info);
+        }
+
+        #[cfg_attr(not(target_os = "linux"), allow(unused_mut))]
+        let mut process_info: Vec<_> = if self.processes {
+            let collected = self
+                .gpu_readers
+                .collect("process_info", |reader| reader.get_process_info())
+                .await;
+            complete &= collected.is_complete();
+            collected.into_values()
+        } else {
+            Vec::new()
+        };
+        #[cfg(target_os = "linux")]
+        if let Some(pod_resources) = self.pod_resources.as_mut() {
+            pod_resources.refresh_blocking();
+            pod_resources.annotate(&mut gpu_info, &mut process_info);
+        }
+        // Per-user totals cover every process, not just the exported top N
+        pass.user_summaries = Some(summarize_by_user(&process_info));
+        let order = self.filter.order;
+        gpu_info.sort_by(|a, b| order.compare(a, b));
+        pass.gpu_info = Some(gpu_info);
+        pass.process_info = Some(process_info);
+        if self.filter.gpu && complete {
+            pass.stamps.push(("gpu", CollectionStamp::now()));
+        }
+    }
+
+    async fn collect_cpu(&mut self, pass: &mut CollectionPass) {
+        let collected = self
+            .cpu_readers
+            .collect("cpu_info", |reader| reader.get_cpu_info())
+            .await;
+        let complete = collected.is_complete();
+        pass.cpu_info = Some(collected.into_values());
+        if self.filter.cpu && complete {
+            pass.stamps.push(("cpu", CollectionStamp::now()));
+        }
+    }
+
+    async fn collect_memory(&mut self, pass: &mut CollectionPass) {
+        let collected = self
+            .memory_readers
+            .collect("memory_info", |reader| reader.get_memory_info())
+            .await;
+        if collected.is_complete() {
+            pass.stamps.push(("memory", CollectionStamp::now()));
+        }
+        pass.memory_info = Some(collected.into_values());
+    }
+
+    fn collect_disk(&mut self, pass: &mut CollectionPass) {
+        // Refresh disk info in-place instead of creating a new Disks instance
+        pass.storage_info = Some(match self.disks.as_mut() {
+            Some(disks) => {
+                disks.refresh(true);
+                let storage_info = collect_storage_info_from(disks);
+                pass.stamps.push(("disk", CollectionStamp::now()));
+                storage_info
+            }
+            None => Vec::new(),
+        });
+    }
+
+    /// Interface throughput and InfiniBand ports
+    fn collect_network(&mut self, pass: &mut CollectionPass) {
+        pass.network_info = Some(
+            self.network_reader
+                .as_ref()
+                .map(NetworkReader::get_network_info)
+                .unwrap_or_default(),
+        );
+        pass.infiniband_info = Some(if self.filter.net {
+            let infiniband_info = get_ib_port_info();
+            pass.stamps.push(("network", CollectionStamp::now()));
+            infiniband_info
+        } else {
+            Vec::new()
+        });
+    }
+}
+
+impl CollectionPass {
+    fn apply(self, state: &mut AppState) {
+        fn update<T>(field: &mut Vec<T>, collected: Option<Vec<T>>) {
+            if let Some(collected) = collected {
+                *field = collected;
+            }
+        }
+        update(&mut state.gpu_info, self.gpu_info);
+        update(&mut state.cpu_info, self.cpu_info);
+        update(&mut state.memory_info, self.memory_info);
+        update(&mut state.process_info, self.process_info);
+        update(&mut state.user_summaries, self.user_summaries);
+        update(&mut state.storage_info, self.storage_info);
+        update(&mut state.network_info, self.network_info);
+        update(&mut state.infiniband_info, self.infiniband_info);
+        update(&mut state.chassis_info, self.chassis_info);
+        state.collection_stamps.extend(self.stamps);
+        if state.loading {
+            state.loading = false;
+        }
+    }
+}
+
+/// Routes of API mode, with the browser dashboard when `web` is set
+fn build_router(state: ApiState, web: bool) -> Router {
+    let router = Router::new()
         .route("/metrics", get(metrics_handler))
+        .route("/metadata", get(metadata_handler))
+        .route("/processes", get(processes_handler))
+        .route("/debug/logs", get(debug_logs_handler));
+    #[cfg(feature = "web")]
+    let router = if web {
+        router.merge(crate::api::web::routes())
+    } else {
+        router
+    };
+    #[cfg(not(feature = "web"))]
+    debug_assert!(!web, "built without the web dashboard");
+    router
         .with_state(state)
         .layer(
             CorsLayer::new()
@@ -170,8 +614,11 @@ pub async fn run_api_mode(args: &ApiArgs) {
                 .allow_methods(Any)
                 .allow_headers(Any),
         )
-        .layer(TraceLayer::new_for_http());
+        .layer(TraceLayer::new_for_http())
+}
 
+/// Start the listeners selected by `args` and serve `app` on them.
+async fn serve(app: Router, args: &ApiArgs, shutdown: watch::Receiver<bool>) {
     // Determine which listeners to start
     #[cfg(unix)]
     {
@@ -187,23 +634,27 @@ pub async fn run_api_mode(args: &ApiArgs) {
         match (port, socket_path) {
             // Both TCP and UDS (port > 0 with socket)
             (1..=u16::MAX, Some(path)) => {
-                run_dual_listeners(app, port, path).await;
+                run_dual_listeners(app, port, path, args.socket_mode, shutdown).await;
             }
             // UDS only (port == 0 with socket)
             (0, Some(path)) => {
-                run_unix_listener(app, path).await;
+                run_unix_listener(app, path, args.socket_mode, shutdown).await;
             }
             // TCP only (port > 0, no socket)
             (1..=u16::MAX, None) => {
-                run_tcp_listener(app, port).await;
+                run_tcp_listener(app, port, shutdown).await;
+            }
+            // Push or textfile only (port == 0, no socket, --push-url or --textfile-dir)
+            (0, None) if args.push.push_url.is_some() || args.textfile_dir.is_some() => {
+                shutdown_requested(shutdown).await;
             }
             // No listeners - error (port == 0, no socket)
             (0, None) => {
                 tracing::error!(
-                    "No listeners configured. Use --port or --socket to specify a listener."
+                    "No listeners configured. Use --port, --socket, --push-url or --textfile-dir."
                 );
                 eprintln!(
-                    "Error: No listeners configured. Use --port or --socket to specify a listener."
+                    "Error: No listeners configured. Use --port, --socket, --push-url or --textfile-dir."
                 );
             }
         }
@@ -211,12 +662,16 @@ pub async fn run_api_mode(args: &ApiArgs) {
 
     #[cfg(not(unix))]
     {
-        run_tcp_listener(app, args.port).await;
+        if args.port == 0 && (args.push.push_url.is_some() || args.textfile_dir.is_some()) {
+            shutdown_requested(shutdown).await;
+        } else {
+            run_tcp_listener(app, args.port, shutdown).await;
+        }
     }
 }
 
 /// Run only the TCP listener
-async fn run_tcp_listener(app: Router, port: u16) {
+pub(crate) async fn run_tcp_listener(app: Router, port: u16, shutdown: watch::Receiver<bool>) {
     let listener = match TcpListener::bind(&format!("0.0.0.0:{port}")).await {
         Ok(l) => l,
         Err(e) => {
@@ -231,14 +686,21 @@ async fn run_tcp_listener(app: Router, port: u16) {
             .local_addr()
             .unwrap_or_else(|_| "unknown".parse().unwrap())
     );
-    if let Err(e) = axum::serve(listener, app).await {
+    serve_tcp(listener, app, shutdown).await;
+}
+
+/// Serve `app` on a bound TCP listener until shutdown is requested
+async fn serve_tcp(listener: TcpListener, app: Router, shutdown: watch::Receiver<bool>) {
+    let server =
+        axum::serve(listener, app).with_graceful_shutdown(shutdown_requested(shutdown.clone()));
+    if let Err(e) = drain_on_shutdown(server, shutdown).await {
         tracing::error!("TCP server error: {e}");
     }
 }
 
 /// Run only the Unix Domain Socket listener
 #[cfg(unix)]
-async fn run_unix_listener(app: Router, path: PathBuf) {
+async fn run_unix_listener(app: Router, path: PathBuf, mode: u32, shutdown: watch::Receiver<bool>) {
     // Remove stale socket file if it exists
     if let Err(e) = remove_stale_socket(&path) {
         tracing::warn!("Failed to remove stale socket file: {e}");
@@ -273,35 +735,31 @@ async fn run_unix_listener(app: Router, path: PathBuf) {
         }
     };
 
-    // Set restrictive permissions (0o600) on the socket file
-    if let Err(e) = set_socket_permissions(&path) {
+    // Restrict who may connect before serving any request
+    if let Err(e) = set_socket_permissions(&path, mode) {
         tracing::warn!("Failed to set socket permissions: {e}");
     }
 
     tracing::info!("API server listening on Unix socket: {}", path.display());
 
-    // Set up socket cleanup on shutdown
-    let path_clone = path.clone();
-    let cleanup_handle = tokio::spawn(async move {
-        tokio::signal::ctrl_c()
-            .await
-            .expect("Failed to listen for Ctrl+C");
-        cleanup_socket(&path_clone);
-    });
-
-    // Serve the application
-    if let Err(e) = axum::serve(listener, app).await {
+    let server =
+        axum::serve(listener, app).with_graceful_shutdown(shutdown_requested(shutdown.clone()));
+    if let Err(e) = drain_on_shutdown(server, shutdown).await {
         tracing::error!("Unix socket server error: {e}");
     }
 
-    // Cancel cleanup handle and do cleanup
-    cleanup_handle.abort();
     cleanup_socket(&path);
 }
 
 /// Run both TCP and Unix Domain Socket listeners simultaneously
 #[cfg(unix)]
-async fn run_dual_listeners(app: Router, port: u16, socket_path: PathBuf) {
+async fn run_dual_listeners(
+    app: Router,
+    port: u16,
+    socket_path: PathBuf,
+    mode: u32,
+    shutdown: watch::Receiver<bool>,
+) {
     // Remove stale socket file if it exists
     if let Err(e) = remove_stale_socket(&socket_path) {
         tracing::warn!("Failed to remove stale socket file: {e}");
@@ -350,8 +808,8 @@ async fn run_dual_listeners(app: Router, port: u16, socket_path: PathBuf) {
         }
     };
 
-    // Set restrictive permissions (0o600) on the socket file
-    if let Err(e) = set_socket_permissions(&socket_path) {
+    // Restrict who may connect before serving any request
+    if let Err(e) = set_socket_permissions(&socket_path, mode) {
         tracing::warn!("Failed to set socket permissions: {e}");
     }
 
@@ -366,31 +824,17 @@ async fn run_dual_listeners(app: Router, port: u16, socket_path: PathBuf) {
     // Clone the app for the second server
     let app_clone = app.clone();
 
-    // Set up socket cleanup on shutdown
-    let path_clone = socket_path.clone();
-    let cleanup_handle = tokio::spawn(async move {
-        tokio::signal::ctrl_c()
-            .await
-            .expect("Failed to listen for Ctrl+C");
-        cleanup_socket(&path_clone);
-    });
-
-    // Run both servers concurrently
-    tokio::select! {
-        result = axum::serve(tcp_listener, app) => {
-            if let Err(e) = result {
-                tracing::error!("TCP server error: {e}");
-            }
-        }
-        result = axum::serve(unix_listener, app_clone) => {
-            if let Err(e) = result {
-                tracing::error!("Unix socket server error: {e}");
-            }
-        }
+    // Run both servers concurrently; both drain their requests on shutdown
+    let unix_server = axum::serve(unix_listener, app_clone)
+        .with_graceful_shutdown(shutdown_requested(shutdown.clone()));
+    let (_, unix_result) = tokio::join!(
+        serve_tcp(tcp_listener, app, shutdown.clone()),
+        drain_on_shutdown(unix_server, shutdown),
+    );
+    if let Err(e) = unix_result {
+        tracing::error!("Unix socket server error: {e}");
     }
 
-    // Cancel cleanup handle and do cleanup
-    cleanup_handle.abort();
     cleanup_socket(&socket_path);
 }
 
@@ -426,6 +870,7 @@ fn collect_storage_info_from(disks: &Disks) -> Vec<StorageInfo> {
 
     for (index, disk) in filtered_disks.iter().enumerate() {
         let mount_point_str = disk.mount_point().to_string_lossy();
+        let inodes = read_inode_counts(disk.mount_point());
         storage_info.push(StorageInfo {
             mount_point: mount_point_str.to_string(),
             total_bytes: disk.total_space(),
@@ -433,8 +878,166 @@ fn collect_storage_info_from(disks: &Disks) -> Vec<StorageInfo> {
             host_id: hostname.clone(),
             hostname: hostname.clone(),
             index: index as u32,
+            total_inodes: inodes.map(|c| c.total),
+            free_inodes: inodes.map(|c| c.free),
+            health: disk_health(&disk.name().to_string_lossy()),
         });
     }
 
     storage_info
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_runs_each_subsystem_on_its_interval() {
+        let intervals = SubsystemIntervals::new(1).with(Subsystem::Chassis, 60);
+        let start = Instant::now();
+        let mut schedule = Schedule::new(intervals, start);
+        assert_eq!(schedule.due(start), Subsystem::ALL.to_vec());
+
+        schedule.reschedule(&Subsystem::ALL, start);
+        assert!(schedule.due(start).is_empty());
+        assert_eq!(schedule.next_due(), start + Duration::from_secs(1));
+
+        let later = start + Duration::from_secs(2);
+        let due = schedule.due(later);
+        assert!(!due.contains(&Subsystem::Chassis));
+        assert_eq!(due.len(), Subsystem::ALL.len() - 1);
+        assert_eq!(
+            schedule.due(start + Duration::from_secs(60)),
+            Subsystem::ALL.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_pass_keeps_subsystems_that_were_not_due() {
+        let mut state = AppState::new();
+        state.chassis_info = vec![ChassisInfo::default()];
+        let pass = CollectionPass {
+            memory_info: Some(Vec::new()),
+            ..Default::default()
+        };
+        pass.apply(&mut state);
+        assert_eq!(state.chassis_info.len(), 1);
+        assert!(state.memory_info.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scrape_in_flight_completes_on_shutdown() {
+        let state = SharedState::new(RwLock::new(AppState::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let server = tokio::spawn(serve_tcp(
+            listener,
+            build_router(ApiState::new(state.clone(), Duration::ZERO, None), false),
+            shutdown_rx,
+        ));
+
+        // Holding the write lock keeps the scrape waiting inside the handler
+        let guard = state.write().await;
+        let scrape = tokio::spawn(reqwest::get(format!("http://{addr}/metrics")));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        shutdown_tx.send(true).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(!server.is_finished(), "server quit with a scrape in flight");
+        drop(guard);
+
+        let response = scrape.await.unwrap().unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .expect("server did not stop after draining")
+            .unwrap();
+
+        // New connections are refused once the server is gone
+        assert!(reqwest::get(format!("http://{addr}/metrics"))
+            .await
+            .is_err());
+    }
+
+    #[cfg(feature = "web")]
+    #[tokio::test]
+    async fn test_ws_sends_latest_snapshot() {
+        use futures_util::StreamExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let state = SharedState::new(RwLock::new(AppState::new()));
+        let (live, subscriber) = crate::api::web::live_channel("node1".to_string());
+        let api = ApiState::new(state, Duration::ZERO, None).with_live(subscriber);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let server = tokio::spawn(serve_tcp(listener, build_router(api, true), shutdown_rx));
+
+        let mut app_state = AppState::new();
+        let loading = |message: Option<Result<Message, _>>| match message {
+            Some(Ok(Message::Text(json))) => {
+                serde_json::from_str::<serde_json::Value>(&json).unwrap()["loading"].clone()
+            }
+            other => panic!("expected a snapshot, got {other:?}"),
+        };
+
+        // A client that connects late gets only the newest snapshot
+        live.publish(&app_state);
+        live.publish(&app_state);
+        app_state.loading = false;
+        live.publish(&app_state);
+        let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/ws"))
+            .await
+            .unwrap();
+        assert_eq!(loading(socket.next().await), false);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), socket.next())
+                .await
+                .is_err(),
+            "older snapshots were queued"
+        );
+
+        app_state.loading = true;
+        live.publish(&app_state);
+        assert_eq!(loading(socket.next().await), true);
+
+        // The connection is closed once the collection task is gone
+        drop(live);
+        assert!(matches!(socket.next().await, Some(Ok(Message::Close(_)))));
+
+        shutdown_tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .expect("server did not stop")
+            .unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_unix_socket_mode_and_cleanup() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("all-smi.sock");
+        let state = SharedState::new(RwLock::new(AppState::new()));
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let server = tokio::spawn(run_unix_listener(
+            build_router(ApiState::new(state, Duration::ZERO, None), false),
+            path.clone(),
+            0o660,
+            shutdown_rx,
+        ));
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o660);
+
+        shutdown_tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(2), server)
+            .await
+            .expect("server did not stop")
+            .unwrap();
+        assert!(!path.exists(), "socket file left behind");
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How long collected data stays fresh in API mode, and what happens to
+//! series whose collector has fallen behind.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::cli::StalenessPolicy;
+use crate::device::SubsystemIntervals;
+
+/// Collection passes a collector may miss before its data is stale
+const DEFAULT_MISSED_PASSES: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Staleness {
+    /// `--staleness-window`, the same for every collector
+    pub window: Option<Duration>,
+    /// How often each collector runs, for the default windows
+    pub intervals: SubsystemIntervals,
+    pub policy: StalenessPolicy,
+}
+
+impl Staleness {
+    pub fn new(
+        window: Option<u64>,
+        intervals: SubsystemIntervals,
+        policy: StalenessPolicy,
+    ) -> Self {
+        Self {
+            window: window.map(Duration::from_secs),
+            intervals,
+            policy,
+        }
+    }
+
+    /// How old the data of `collector` may get: `--staleness-window`, or
+    /// three of that collector's intervals.
+    pub fn window(&self, collector: &str) -> Duration {
+        self.window.unwrap_or_else(|| {
+            Duration::from_secs(self.intervals.for_collector(collector)) * DEFAULT_MISSED_PASSES
+        })
+    }
+}
+
+impl Default for Staleness {
+    fn default() -> Self {
+        Self::new(None, SubsystemIntervals::new(3), StalenessPolicy::default())
+    }
+}
+
+static STALENESS: OnceLock<Staleness> = OnceLock::new();
+
+/// Install the settings from `--staleness-window`/`--staleness-policy`.
+/// Only the first call takes effect.
+pub fn set_staleness(staleness: Staleness) {
+    let _ = STALENESS.set(staleness);
+}
+
+/// The installed settings, or the defaults if none were set.
+pub fn staleness() -> Staleness {
+    STALENESS.get().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::Subsystem;
+
+    #[test]
+    fn test_default_window_is_three_intervals() {
+        let intervals = SubsystemIntervals::new(5).with(Subsystem::Chassis, 60);
+        let staleness = Staleness::new(None, intervals, StalenessPolicy::Flag);
+        assert_eq!(staleness.window("gpu"), Duration::from_secs(15));
+        // Collectors on their own interval get three of those
+        assert_eq!(staleness.window("chassis"), Duration::from_secs(180));
+        assert_eq!(
+            Staleness::new(Some(7), intervals, StalenessPolicy::Omit).window("chassis"),
+            Duration::from_secs(7)
+        );
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `sd_notify` support for running API mode as a `Type=notify` service.
+//!
+//! READY=1 is sent after the first collection and WATCHDOG=1 after every
+//! later one, so systemd restarts the service if the collection loop hangs.
+
+use std::os::unix::net::{SocketAddr, UnixDatagram};
+
+pub struct SystemdNotifier {
+    socket: Option<(UnixDatagram, SocketAddr)>,
+    ready_sent: bool,
+}
+
+impl SystemdNotifier {
+    /// Notifier for the socket in `NOTIFY_SOCKET`. Does nothing when the
+    /// variable is unset, i.e. when not started by systemd.
+    pub fn from_env() -> Self {
+        Self::new(std::env::var("NOTIFY_SOCKET").ok().as_deref())
+    }
+
+    fn new(notify_socket: Option<&str>) -> Self {
+        let socket = notify_socket.and_then(|path| match Self::connect(path) {
+            Ok(socket) => Some(socket),
+            Err(e) => {
+                tracing::warn!("Failed to open systemd notify socket {path}: {e}");
+                None
+            }
+        });
+        Self {
+            socket,
+            ready_sent: false,
+        }
+    }
+
+    fn connect(path: &str) -> std::io::Result<(UnixDatagram, SocketAddr)> {
+        // A leading '@' names a socket in the abstract namespace
+        let addr = match path.strip_prefix('@') {
+            #[cfg(target_os = "linux")]
+            Some(name) => {
+                use std::os::linux::net::SocketAddrExt;
+                SocketAddr::from_abstract_name(name)?
+            }
+            _ => SocketAddr::from_pathname(path)?,
+        };
+        Ok((UnixDatagram::unbound()?, addr))
+    }
+
+    /// Report a finished collection: READY=1 the first time, WATCHDOG=1 after.
+    pub fn collection_finished(&mut self) {
+        if self.ready_sent {
+            self.notify("WATCHDOG=1");
+        } else {
+            self.notify("READY=1");
+            self.ready_sent = true;
+        }
+    }
+
+    /// Tell systemd the service is shutting down.
+    pub fn stopping(&self) {
+        self.notify("STOPPING=1");
+    }
+
+    fn notify(&self, state: &str) {
+        if let Some((socket, addr)) = &self.socket {
+            if let Err(e) = socket.send_to_addr(state.as_bytes(), addr) {
+                tracing::warn!("Failed to notify systemd ({state}): {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ready_then_watchdog() {
+        let path = std::env::temp_dir().join(format!("all-smi-notify-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let receiver = UnixDatagram::bind(&path).unwrap();
+        receiver.set_nonblocking(true).unwrap();
+
+        let mut notifier = SystemdNotifier::new(path.to_str());
+        notifier.collection_finished();
+        notifier.collection_finished();
+        notifier.stopping();
+
+        let mut buf = [0u8; 64];
+        let mut received = Vec::new();
+        while let Ok(n) = receiver.recv(&mut buf) {
+            received.push(String::from_utf8_lossy(&buf[..n]).to_string());
+        }
+        assert_eq!(received, vec!["READY=1", "WATCHDOG=1", "STOPPING=1"]);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_noop_without_notify_socket() {
+        let mut notifier = SystemdNotifier::new(None);
+        notifier.collection_finished();
+        notifier.stopping();
+        assert!(notifier.socket.is_none());
+    }
+}
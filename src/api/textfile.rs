@@ -0,0 +1,122 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics file for the node_exporter textfile collector (`--textfile-dir`).
+//!
+//! Each write goes to a temporary file in the same directory that is then
+//! renamed over `all_smi.prom`, so node_exporter never reads a half-written
+//! file. The temporary name does not end in `.prom`, which node_exporter
+//! skips.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::api::handlers::export_all_metrics;
+use crate::api::push::group_families;
+use crate::app_state::AppState;
+
+/// Name of the file written in the textfile directory
+pub const TEXTFILE_NAME: &str = "all_smi.prom";
+
+pub struct TextfileWriter {
+    dir: PathBuf,
+}
+
+impl TextfileWriter {
+    /// Writer into `dir`, which must be an existing directory.
+    pub fn new(dir: &Path) -> io::Result<Self> {
+        if !fs::metadata(dir)?.is_dir() {
+            return Err(io::Error::other(format!(
+                "{} is not a directory",
+                dir.display()
+            )));
+        }
+        Ok(Self {
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(TEXTFILE_NAME)
+    }
+
+    /// Replace the metrics file with `metrics` in one rename.
+    pub fn write(&self, metrics: &str) -> io::Result<()> {
+        let temp = self
+            .dir
+            .join(format!(".{TEXTFILE_NAME}.{}.tmp", std::process::id()));
+        let result = File::create(&temp)
+            .and_then(|mut file| {
+                file.write_all(group_families(metrics).as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temp, self.path()));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        result
+    }
+
+    /// [`Self::write`], logging instead of failing so a full or unmounted
+    /// directory does not stop collection.
+    pub fn publish(&self, metrics: &str) {
+        if let Err(e) = self.write(metrics) {
+            tracing::warn!("Failed to write {}: {e}", self.path().display());
+        }
+    }
+}
+
+/// Metrics of a local mode state. Local mode lists every process on the
+/// host; like API mode, only those using GPU memory are exported.
+pub fn local_metrics(state: &AppState) -> String {
+    let mut exported = state.clone();
+    exported.process_info.retain(|p| p.used_memory > 0);
+    export_all_metrics(&exported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_replaces_file_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let writer = TextfileWriter::new(dir.path()).unwrap();
+        let text = "# HELP a A\n# TYPE a gauge\na{x=\"1\"} 1\n# HELP b B\n# TYPE b gauge\nb 2\n# HELP a A\n# TYPE a gauge\na{x=\"2\"} 3\n";
+
+        writer.write(text).unwrap();
+        writer.write(text).unwrap();
+
+        // Families are grouped, as node_exporter rejects a repeated HELP
+        let written = fs::read_to_string(writer.path()).unwrap();
+        assert_eq!(written.matches("# HELP a A").count(), 1);
+        assert!(written.contains("a{x=\"2\"} 3"));
+        // Only the metrics file is left behind
+        let names: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(names, vec![TEXTFILE_NAME]);
+    }
+
+    #[test]
+    fn test_new_rejects_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(TextfileWriter::new(&dir.path().join("missing")).is_err());
+        let file = dir.path().join("file");
+        fs::write(&file, "").unwrap();
+        assert!(TextfileWriter::new(&file).is_err());
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Browser dashboard served with `--web`.
+//!
+//! The page and its script are compiled into the binary. The script follows
+//! `/ws`, which pushes a JSON snapshot every collection cycle, and draws a
+//! card per device, so a single node can be watched without Prometheus or
+//! Grafana. `/metrics.json` serves the same snapshot on request.
+//!
+//! Each snapshot is serialized once and shared by every connection. Only the
+//! newest is kept, so a client that reads slower than the collection
+//! interval skips to it instead of queueing the ones it missed.
+
+use axum::extract::ws::{Message, Utf8Bytes, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use crate::app_state::AppState;
+use crate::device::{CpuInfo, GpuInfo, MemoryInfo};
+use crate::utils::{format_bytes, get_hostname, unit_system};
+
+use super::handlers::{ApiState, SharedState};
+
+const INDEX_HTML: &str = include_str!("web/index.html");
+const APP_JS: &str = include_str!("web/app.js");
+
+/// A client that takes longer than this to accept one snapshot is dropped
+const SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Routes of the dashboard, merged into the API router
+pub fn routes() -> Router<ApiState> {
+    Router::new()
+        .route("/", get(index_handler))
+        .route("/app.js", get(script_handler))
+        .route("/metrics.json", get(metrics_json_handler))
+        .route("/ws", get(ws_handler))
+}
+
+/// Receives the latest snapshot as JSON. `None` until the first collection
+/// finishes.
+pub type LiveSubscriber = watch::Receiver<Option<Utf8Bytes>>;
+
+/// Publishes one JSON snapshot per collection cycle to the `/ws` clients.
+pub struct LivePublisher {
+    sender: watch::Sender<Option<Utf8Bytes>>,
+    hostname: String,
+}
+
+/// A publisher for the collection task and a subscriber for [`ApiState`].
+pub fn live_channel(hostname: String) -> (LivePublisher, LiveSubscriber) {
+    let (sender, receiver) = watch::channel(None);
+    (LivePublisher { sender, hostname }, receiver)
+}
+
+impl LivePublisher {
+    /// Publish `state` as the latest snapshot
+    pub fn publish(&self, state: &AppState) {
+        let snapshot = WebSnapshot::new(state, self.hostname.clone());
+        match serde_json::to_string(&snapshot) {
+            Ok(json) => {
+                self.sender.send_replace(Some(json.into()));
+            }
+            Err(e) => tracing::warn!("Cannot serialize the live snapshot: {e}"),
+        }
+    }
+}
+
+async fn index_handler() -> Html<&'static str> {
+    Html(INDEX_HTML)
+}
+
+async fn script_handler() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/javascript; charset=utf-8")],
+        APP_JS,
+    )
+}
+
+/// The devices of the last collection, as drawn by the dashboard
+#[derive(Serialize)]
+pub struct WebSnapshot {
+    pub hostname: String,
+    /// True until the first collection finishes
+    pub loading: bool,
+    /// Unit system of the `formatted` sizes: `binary` or `decimal`
+    pub units: String,
+    pub gpus: Vec<Formatted<GpuInfo>>,
+    pub cpus: Vec<CpuInfo>,
+    pub memory: Vec<Formatted<MemoryInfo>>,
+}
+
+/// A device with its byte counts also formatted with `--units`
+#[derive(Serialize)]
+pub struct Formatted<T> {
+    #[serde(flatten)]
+    pub info: T,
+    /// Field name to formatted size, e.g. `"used_memory": "25.2GiB"`
+    pub formatted: BTreeMap<&'static str, String>,
+}
+
+impl<T> Formatted<T> {
+    fn new(info: T, sizes: &[(&'static str, u64)]) -> Self {
+        Self {
+            info,
+            formatted: sizes
+                .iter()
+                .map(|(field, bytes)| (*field, format_bytes(*bytes)))
+                .collect(),
+        }
+    }
+}
+
+impl WebSnapshot {
+    fn new(state: &AppState, hostname: String) -> Self {
+        Self {
+            hostname,
+            loading: state.loading,
+            units: unit_system().to_string(),
+            gpus: state
+                .gpu_info
+                .iter()
+                .map(|gpu| {
+                    let sizes = [
+                        ("used_memory", gpu.used_memory),
+                        ("total_memory", gpu.total_memory),
+                    ];
+                    Formatted::new(gpu.clone(), &sizes)
+                })
+                .collect(),
+            cpus: state.cpu_info.clone(),
+            memory: state
+                .memory_info
+                .iter()
+                .map(|memory| {
+                    let sizes = [
+                        ("total_bytes", memory.total_bytes),
+                        ("used_bytes", memory.used_bytes),
+                        ("available_bytes", memory.available_bytes),
+                    ];
+                    Formatted::new(memory.clone(), &sizes)
+                })
+                .collect(),
+        }
+    }
+}
+
+pub async fn metrics_json_handler(State(state): State<SharedState>) -> Json<WebSnapshot> {
+    Json(WebSnapshot::new(&*state.read().await, get_hostname()))
+}
+
+/// Upgrade to a WebSocket that receives a text frame with a [`WebSnapshot`]
+/// every collection cycle, starting with the latest one.
+pub async fn ws_handler(ws: WebSocketUpgrade, State(api): State<ApiState>) -> Response {
+    let mut snapshots = api.live.clone();
+    // Start with the snapshot already collected, if any
+    snapshots.mark_changed();
+    ws.on_upgrade(move |socket| stream_snapshots(socket, snapshots))
+}
+
+async fn stream_snapshots(mut socket: WebSocket, mut snapshots: LiveSubscriber) {
+    loop {
+        tokio::select! {
+            changed = snapshots.changed() => {
+                // The collection task has stopped
+                if changed.is_err() {
+                    let _ = socket.send(Message::Close(None)).await;
+                    break;
+                }
+                let Some(json) = snapshots.borrow_and_update().clone() else {
+                    continue;
+                };
+                // Snapshots published meanwhile replace each other, so a
+                // slow client is only ever one snapshot behind
+                match tokio::time::timeout(SEND_TIMEOUT, socket.send(Message::Text(json))).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(_)) | Err(_) => break,
+                }
+            }
+            message = socket.recv() => match message {
+                // Pings are answered by axum; other client frames are ignored
+                Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_json() {
+        let mut state = AppState::new();
+        state.loading = false;
+        state.gpu_info = vec![GpuInfo {
+            uuid: "GPU-0".to_string(),
+            name: "Test GPU".to_string(),
+            device_type: "GPU".to_string(),
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+            instance: "node1".to_string(),
+            utilization: 42.0,
+            temperature: 61,
+            used_memory: 1 << 30,
+            total_memory: 8 << 30,
+            frequency: 1500,
+            power_consumption: 180.5,
+            ..Default::default()
+        }];
+
+        let json = serde_json::to_value(WebSnapshot::new(&state, "node1".to_string())).unwrap();
+        assert_eq!(json["hostname"], "node1");
+        assert_eq!(json["loading"], false);
+        // The fields app.js reads
+        let gpu = &json["gpus"][0];
+        assert_eq!(gpu["name"], "Test GPU");
+        assert_eq!(gpu["utilization"], 42.0);
+        assert_eq!(gpu["temperature"], 61);
+        assert_eq!(gpu["used_memory"], 1u64 << 30);
+        assert_eq!(gpu["total_memory"], 8u64 << 30);
+        assert_eq!(gpu["power_consumption"], 180.5);
+        // Raw bytes come with the sizes formatted in the selected units
+        assert_eq!(json["units"], "binary");
+        assert_eq!(gpu["formatted"]["used_memory"], "1.0GiB");
+        assert_eq!(gpu["formatted"]["total_memory"], "8.0GiB");
+        assert!(json["cpus"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_page_loads_script() {
+        assert!(INDEX_HTML.contains(r#"<script src="app.js"></script>"#));
+        assert!(APP_JS.contains("metrics.json"));
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Follows the snapshots pushed on ws and draws one card per GPU/NPU. While
+// the socket is down, metrics.json is fetched instead until it reconnects.
+
+"use strict";
+
+const RECONNECT_MS = 3000;
+
+function level(percent) {
+  if (percent >= 90) return "critical";
+  if (percent >= 70) return "warn";
+  return "";
+}
+
+function element(tag, className, text) {
+  const node = document.createElement(tag);
+  if (className) node.className = className;
+  if (text !== undefined) node.textContent = text;
+  return node;
+}
+
+function row(label, value, percent) {
+  const fragment = document.createDocumentFragment();
+  const line = element("div", "row");
+  line.append(element("span", "label", label), element("span", "", value));
+  fragment.append(line);
+  if (percent !== undefined) {
+    const bar = element("div", "bar");
+    const fill = element("div", level(percent));
+    fill.style.width = `${Math.min(Math.max(percent, 0), 100)}%`;
+    bar.append(fill);
+    fragment.append(bar);
+  }
+  return fragment;
+}
+
+function card(gpu) {
+  const node = element("section", "card");
+  const index = gpu.detail.index !== undefined ? `${gpu.detail.index}: ` : "";
+  node.append(element("h2", "", `${index}${gpu.name}`));
+  node.append(row("Utilization", `${gpu.utilization.toFixed(1)}%`, gpu.utilization));
+  const memory = gpu.total_memory > 0 ? (gpu.used_memory / gpu.total_memory) * 100 : 0;
+  node.append(
+    row("Memory", `${gpu.formatted.used_memory} / ${gpu.formatted.total_memory}`, memory),
+  );
+  node.append(row("Temperature", `${gpu.temperature}°C`));
+  if (gpu.power_consumption >= 0) {
+    node.append(row("Power", `${gpu.power_consumption.toFixed(1)} W`));
+  }
+  node.append(row("Frequency", `${gpu.frequency} MHz`));
+  return node;
+}
+
+function summary(snapshot) {
+  const parts = [];
+  for (const cpu of snapshot.cpus) {
+    parts.push(`CPU ${cpu.utilization.toFixed(1)}%`);
+  }
+  for (const memory of snapshot.memory) {
+    parts.push(
+      `Memory ${memory.formatted.used_bytes} / ${memory.formatted.total_bytes}` +
+        ` (${memory.utilization.toFixed(1)}%)`,
+    );
+  }
+  return parts.join(" · ");
+}
+
+function render(snapshot) {
+  document.title = `${snapshot.hostname} - all-smi`;
+  document.getElementById("hostname").textContent = snapshot.hostname;
+  document.getElementById("summary").textContent = summary(snapshot);
+  const devices = document.getElementById("devices");
+  devices.replaceChildren(...snapshot.gpus.map(card));
+
+  const status = document.getElementById("status");
+  status.className = "";
+  if (snapshot.loading) {
+    status.textContent = "Loading…";
+  } else if (snapshot.gpus.length === 0) {
+    status.textContent = "No GPUs or NPUs found";
+  } else {
+    status.textContent = `Updated ${new Date().toLocaleTimeString()}`;
+  }
+}
+
+function showError(message) {
+  const status = document.getElementById("status");
+  status.className = "error";
+  status.textContent = message;
+}
+
+async function refresh() {
+  try {
+    const response = await fetch("metrics.json", { cache: "no-store" });
+    if (!response.ok) throw new Error(`HTTP ${response.status}`);
+    render(await response.json());
+  } catch (error) {
+    showError(`Update failed: ${error.message}`);
+  }
+}
+
+function connect() {
+  const url = new URL("ws", location.href);
+  url.protocol = location.protocol === "https:" ? "wss:" : "ws:";
+  const socket = new WebSocket(url);
+  socket.onmessage = (event) => render(JSON.parse(event.data));
+  socket.onclose = async () => {
+    await refresh();
+    setTimeout(connect, RECONNECT_MS);
+  };
+}
+
+connect();
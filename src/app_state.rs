@@ -12,13 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::device::device_order::{by_index, DeviceOrder};
+use crate::device::process_list::{summarize_by_user, UserProcessSummary};
 use crate::device::{ChassisInfo, CpuInfo, GpuInfo, MemoryInfo, ProcessInfo};
+use crate::network::{IbPortInfo, NetworkInfo};
 use crate::storage::info::StorageInfo;
+use crate::ui::change_highlight::ChangeHighlight;
+use crate::ui::heatmap::RackLayout;
 use crate::ui::notification::NotificationManager;
+use crate::ui::process_columns::{ColumnChooser, ProcessLayout};
+use crate::ui::replay_timeline::ReplayTimeline;
+use crate::ui::smoothing::UtilizationEma;
+use crate::utils::process_signal::{check_target, describe_error, Signal, SignalSender};
 use crate::utils::RuntimeEnvironment;
+use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::{HashMap, VecDeque};
-use std::time::{Duration, Instant};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use chrono::{DateTime, Local};
+use tokio::sync::Notify;
 
 #[derive(Clone, Debug)]
 pub struct ConnectionStatus {
@@ -31,6 +45,8 @@ pub struct ConnectionStatus {
     pub consecutive_failures: u32,
     pub last_error: Option<String>,
     pub last_update: Instant,
+    /// Processes the last scrape left out to stay within `--process-top`
+    pub processes_omitted: usize,
 }
 
 impl ConnectionStatus {
@@ -44,6 +60,7 @@ impl ConnectionStatus {
             consecutive_failures: 0,
             last_error: None,
             last_update: Instant::now(),
+            processes_omitted: 0,
         }
     }
 
@@ -73,12 +90,49 @@ impl ConnectionStatus {
     }
 }
 
+/// Addresses in the host list that reach the same node, as told by its
+/// `instance` label or bonded under one name in the hostfile.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HostInfo {
+    pub instance: String,
+    /// In host-list order; the first one names the node's tab unless bonded
+    pub addresses: Vec<String>,
+    /// A hostfile bond: every address reports part of the node, and the tab
+    /// is named after the bond
+    pub bonded: bool,
+}
+
+/// `GpuInfo::detail` key set to "true" on devices whose node reported them
+/// stale with `all_smi_gpu_stale`
+pub const STALE_KEY: &str = "stale";
+
+/// When a collector last finished a pass.
+#[derive(Clone, Copy, Debug)]
+pub struct CollectionStamp {
+    /// Monotonic time, for measuring age
+    pub at: Instant,
+    /// Wall-clock time of the same moment, for export
+    pub wall: SystemTime,
+}
+
+impl CollectionStamp {
+    pub fn now() -> Self {
+        Self {
+            at: Instant::now(),
+            wall: SystemTime::now(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AppState {
     pub gpu_info: Vec<GpuInfo>,
     pub cpu_info: Vec<CpuInfo>,
     pub memory_info: Vec<MemoryInfo>,
     pub process_info: Vec<ProcessInfo>,
+    /// GPU memory per user over every collected process, including those
+    /// `--process-top` leaves out of the per-process series in API mode
+    pub user_summaries: Vec<UserProcessSummary>,
     pub chassis_info: Vec<ChassisInfo>,
     pub selected_process_index: usize,
     pub start_index: usize,
@@ -97,8 +151,16 @@ pub struct AppState {
     pub cpu_name_scroll_offsets: HashMap<String, usize>,
     pub frame_counter: u64,
     pub storage_info: Vec<StorageInfo>,
+    pub network_info: Vec<NetworkInfo>,
+    pub infiniband_info: Vec<IbPortInfo>,
+    /// How long each reader category took in the latest local collection
+    pub collection_timings: Vec<(&'static str, Duration)>,
     pub show_help: bool,
+    /// Recent log messages overlay (`L`)
+    pub show_logs: bool,
     pub show_per_core_cpu: bool,
+    /// Color load averages per core (`--normalize-load`)
+    pub normalize_load: bool,
     pub utilization_history: VecDeque<f64>,
     pub memory_history: VecDeque<f64>,
     pub temperature_history: VecDeque<f64>,
@@ -116,14 +178,237 @@ pub struct AppState {
     pub known_hosts: Vec<String>,
     // Reverse lookup: actual_hostname -> host_id for efficient connection status retrieval
     pub hostname_to_host_id: HashMap<String, String>,
+    /// Nodes reached through more than one address, keyed by their tab
+    pub host_info: HashMap<String, HostInfo>,
     // Mode tracking - true for local monitoring, false for remote monitoring
     pub is_local_mode: bool,
+    /// `--readonly`: the kill keys and prompt are disabled
+    pub readonly: bool,
     // Runtime environment (container/VM) information
     pub runtime_environment: RuntimeEnvironment,
     /// Version counter that increments when data changes, used to detect if re-render is needed
     pub data_version: u64,
     /// Filter to show only GPU processes (processes with used_memory > 0)
     pub gpu_filter_enabled: bool,
+    /// Show one row per user in the process list, toggled with `U`
+    pub group_by_user: bool,
+    /// Only list the top processes by memory or utilization, cycled with `t`
+    pub process_top: ProcessTop,
+    /// Hide processes using less GPU and CPU than this percentage, cycled with `i`
+    pub min_process_activity: f64,
+    /// User whose processes are listed under their row in the per-user view
+    pub expanded_user: Option<String>,
+    /// Freeze the displayed snapshot; background collection keeps running
+    pub paused: bool,
+    /// When the displayed snapshot was frozen, shown in the `PAUSED` badge
+    pub paused_at: Option<DateTime<Local>>,
+    /// Data version when a refresh was requested while paused; the display
+    /// is frozen again at the first newer data
+    pub refresh_requested: Option<u64>,
+    /// Wakes the collection loop for a refresh outside its interval (`r`)
+    pub refresh_now: Arc<Notify>,
+    /// Host search input opened with `/` in remote mode; `None` when inactive
+    pub host_search: Option<HostSearch>,
+    /// Full-screen cluster heatmap toggled with `v` in remote mode; `None` when closed
+    pub heatmap: Option<HeatmapState>,
+    /// Host positions from `--rack-layout`, used to arrange the heatmap
+    pub rack_layout: Option<RackLayout>,
+    /// UUIDs of devices listed before all others, toggled with Space
+    pub pinned_gpus: BTreeSet<String>,
+    /// UUID of the device selected with Tab/Shift+Tab or a click
+    pub selected_gpu: Option<String>,
+    /// When each collector ("gpu", "cpu", "memory", "disk", "network") last
+    /// finished, in API mode
+    pub collection_stamps: BTreeMap<&'static str, CollectionStamp>,
+    /// When the live local collector last refreshed `process_info`; replayed
+    /// snapshots leave it unset, so their processes cannot be signaled
+    pub processes_collected_at: Option<Instant>,
+    /// Confirmation prompt opened with `k`/`K` in local mode; `None` when closed
+    pub kill_prompt: Option<KillPrompt>,
+    /// Interval the collector currently waits between rounds, shown in the
+    /// header; `None` until the first round (and in replay)
+    pub refresh_interval: Option<RefreshInterval>,
+    /// Order and visibility of the local process table columns
+    pub process_layout: ProcessLayout,
+    /// Column chooser opened with F2 in local mode; `None` when closed
+    pub column_chooser: Option<ColumnChooser>,
+    /// Smoothed utilization shown in the gauges with `--smooth`
+    pub utilization_ema: Option<UtilizationEma>,
+    /// Flashes values that changed significantly; `None` when turned off
+    /// with `C`
+    pub change_highlight: Option<ChangeHighlight>,
+    /// Playback position when replaying a recording
+    pub replay: Option<ReplayTimeline>,
+}
+
+/// Seconds between collection rounds as last computed by the collector.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RefreshInterval {
+    pub secs: u64,
+    /// p95 fetch latency of recent rounds, when it raised the interval above
+    /// the node-count default
+    pub slowed_by: Option<Duration>,
+}
+
+impl RefreshInterval {
+    /// Header label, e.g. `every 3s` or `every 5s (p95 fetch 4.2s)`
+    pub fn label(&self) -> String {
+        match self.slowed_by {
+            Some(latency) => format!(
+                "every {}s (p95 fetch {:.1}s)",
+                self.secs,
+                latency.as_secs_f64()
+            ),
+            None => format!("every {}s", self.secs),
+        }
+    }
+}
+
+/// Process about to be signaled, waiting for the user to confirm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KillPrompt {
+    pub pid: u32,
+    pub process_name: String,
+    pub user: String,
+    pub used_memory: u64,
+    pub used_memory_estimated: bool,
+    pub signal: Signal,
+    /// The process belongs to another user, so it takes two confirmations
+    pub foreign: bool,
+    /// The first of two confirmations was given
+    pub confirmed_once: bool,
+}
+
+/// Text typed after `/` in remote mode. While it is non-empty the tab bar
+/// only shows matching hosts; Enter jumps to the first of them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HostSearch {
+    pub query: String,
+}
+
+/// Longest host search query accepted from the keyboard
+pub const MAX_HOST_SEARCH_LEN: usize = 64;
+
+/// One selectable row of the per-user process list.
+#[derive(Clone, Copy)]
+pub enum ProcessRow<'a> {
+    User(&'a UserProcessSummary),
+    /// Process of the expanded user, listed right below that user's row
+    Process(&'a ProcessInfo),
+}
+
+/// Rows of the per-user process list: one per user, with the processes of
+/// `expanded_user` inserted after its row in their current sort order.
+pub fn user_view_rows<'a>(
+    summaries: &'a [UserProcessSummary],
+    processes: &'a [ProcessInfo],
+    expanded_user: Option<&str>,
+) -> Vec<ProcessRow<'a>> {
+    let mut rows = Vec::with_capacity(summaries.len());
+    for summary in summaries {
+        rows.push(ProcessRow::User(summary));
+        if expanded_user == Some(summary.user.as_str()) {
+            rows.extend(
+                processes
+                    .iter()
+                    .filter(|p| p.user == summary.user)
+                    .map(ProcessRow::Process),
+            );
+        }
+    }
+    rows
+}
+
+/// Per-host value that colors the heatmap cells, cycled with `m`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapMetric {
+    #[default]
+    GpuUtilization,
+    MemoryUtilization,
+    MaxTemperature,
+    Power,
+}
+
+impl HeatmapMetric {
+    pub fn next(self) -> Self {
+        match self {
+            Self::GpuUtilization => Self::MemoryUtilization,
+            Self::MemoryUtilization => Self::MaxTemperature,
+            Self::MaxTemperature => Self::Power,
+            Self::Power => Self::GpuUtilization,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::GpuUtilization => "GPU Utilization",
+            Self::MemoryUtilization => "GPU Memory",
+            Self::MaxTemperature => "Max Temperature",
+            Self::Power => "Power",
+        }
+    }
+}
+
+/// Top-N cut of the process list, cycled with `t`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProcessTop {
+    #[default]
+    Off,
+    /// The processes holding the most GPU memory, then resident memory
+    Memory,
+    /// The busiest processes by GPU utilization, then CPU usage
+    Utilization,
+}
+
+/// Processes kept by [`ProcessTop`]
+pub const PROCESS_TOP_COUNT: usize = 10;
+
+/// Activity floors cycled with `i`, in percent of GPU or CPU usage;
+/// processes below the floor are hidden as idle
+pub const PROCESS_ACTIVITY_FLOORS: [f64; 3] = [0.0, 1.0, 5.0];
+
+impl ProcessTop {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Memory,
+            Self::Memory => Self::Utilization,
+            Self::Utilization => Self::Off,
+        }
+    }
+
+    /// Function key bar indicator, `None` when off
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            Self::Off => None,
+            Self::Memory => Some("Top10:Mem"),
+            Self::Utilization => Some("Top10:Util"),
+        }
+    }
+
+    /// Order `a` before `b` when it ranks higher.
+    fn rank(self, a: &ProcessInfo, b: &ProcessInfo) -> Ordering {
+        match self {
+            Self::Off => Ordering::Equal,
+            Self::Memory => (b.used_memory, b.memory_rss).cmp(&(a.used_memory, a.memory_rss)),
+            Self::Utilization => b
+                .gpu_utilization
+                .total_cmp(&a.gpu_utilization)
+                .then_with(|| b.cpu_percent.total_cmp(&a.cpu_percent)),
+        }
+    }
+}
+
+/// Higher of a process's GPU utilization and CPU usage, in percent
+fn process_activity(process: &ProcessInfo) -> f64 {
+    process.gpu_utilization.max(process.cpu_percent)
+}
+
+/// Selected metric and cursor cell of the open heatmap.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HeatmapState {
+    pub metric: HeatmapMetric,
+    pub row: usize,
+    pub column: usize,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -144,11 +429,11 @@ pub enum SortCriteria {
     Command,        // Command line
     // GPU sorting (both local and remote modes)
     Default,     // Hostname then index (current behavior)
+    Uuid,        // Device UUID
     Utilization, // GPU utilization
     GpuMemory,   // GPU memory usage
     #[allow(dead_code)]
     Power, // Power consumption
-    #[allow(dead_code)]
     Temperature, // Temperature
 }
 
@@ -171,6 +456,7 @@ impl AppState {
             cpu_info: Vec::new(),
             memory_info: Vec::new(),
             process_info: Vec::new(),
+            user_summaries: Vec::new(),
             chassis_info: Vec::new(),
             selected_process_index: 0,
             start_index: 0,
@@ -194,8 +480,13 @@ impl AppState {
             cpu_name_scroll_offsets: HashMap::new(),
             frame_counter: 0,
             storage_info: Vec::new(),
+            network_info: Vec::new(),
+            infiniband_info: Vec::new(),
             show_help: false,
+            collection_timings: Vec::new(),
+            show_logs: false,
             show_per_core_cpu: false,
+            normalize_load: false,
             utilization_history: VecDeque::new(),
             memory_history: VecDeque::new(),
             temperature_history: VecDeque::new(),
@@ -212,10 +503,34 @@ impl AppState {
             connection_status: HashMap::new(),
             known_hosts: Vec::new(),
             hostname_to_host_id: HashMap::new(),
+            host_info: HashMap::new(),
             is_local_mode: true, // Default to local mode
+            readonly: false,
             runtime_environment: RuntimeEnvironment::detect(),
             data_version: 0,
             gpu_filter_enabled: false, // GPU filter disabled by default
+            group_by_user: false,
+            process_top: ProcessTop::Off,
+            min_process_activity: 0.0,
+            expanded_user: None,
+            paused: false,
+            paused_at: None,
+            refresh_requested: None,
+            refresh_now: Arc::new(Notify::new()),
+            host_search: None,
+            heatmap: None,
+            rack_layout: None,
+            pinned_gpus: BTreeSet::new(),
+            selected_gpu: None,
+            collection_stamps: BTreeMap::new(),
+            processes_collected_at: None,
+            kill_prompt: None,
+            refresh_interval: None,
+            process_layout: ProcessLayout::default(),
+            column_chooser: None,
+            utilization_ema: None,
+            change_highlight: None,
+            replay: None,
         }
     }
 
@@ -223,121 +538,562 @@ impl AppState {
     pub fn mark_data_changed(&mut self) {
         self.data_version = self.data_version.wrapping_add(1);
     }
+
+    /// Whether `collector` last finished more than `window` ago. Collectors
+    /// that never ran are not stale; they have nothing to export.
+    pub fn is_stale(&self, collector: &str, window: Duration) -> bool {
+        self.collection_stamps
+            .get(collector)
+            .is_some_and(|stamp| stamp.at.elapsed() > window)
+    }
+
+    /// Every address of the node on tab `host_id`, when more than one
+    /// reaches it.
+    pub fn merged_addresses(&self, host_id: &str) -> Option<&[String]> {
+        self.host_info
+            .get(host_id)
+            .map(|info| info.addresses.as_slice())
+    }
+
+    /// Whether the node on tab `host_id` answered through any of its
+    /// addresses; `None` when none of them has a status yet.
+    pub fn is_host_connected(&self, host_id: &str) -> Option<bool> {
+        let Some(addresses) = self.merged_addresses(host_id) else {
+            return self
+                .connection_status
+                .get(host_id)
+                .map(|status| status.is_connected);
+        };
+        addresses
+            .iter()
+            .filter_map(|address| self.connection_status.get(address))
+            .map(|status| status.is_connected)
+            .reduce(|any, connected| any || connected)
+    }
+
+    /// Whether node tab `host_id` matches `query`, ignoring case. Both the
+    /// raw host address and the hostname shown on the tab are checked, since
+    /// the instance label often differs from what the operator typed.
+    pub fn host_matches(&self, host_id: &str, query: &str) -> bool {
+        let needle = query.to_lowercase();
+        host_id.to_lowercase().contains(&needle)
+            || self
+                .connection_status
+                .get(host_id)
+                .and_then(|status| status.actual_hostname.as_deref())
+                .is_some_and(|hostname| hostname.to_lowercase().contains(&needle))
+    }
+
+    /// Node tabs matching `query`, in tab order. The "All" tab is never
+    /// matched and an empty query matches nothing.
+    pub fn matching_host_tabs(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        self.tabs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, host_id)| self.host_matches(host_id, query))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// First node tab matching `query`.
+    pub fn find_host_tab(&self, query: &str) -> Option<usize> {
+        self.matching_host_tabs(query).into_iter().next()
+    }
+
+    /// Query of the open host search, if it has any text.
+    pub fn active_host_filter(&self) -> Option<&str> {
+        self.host_search
+            .as_ref()
+            .map(|search| search.query.as_str())
+            .filter(|query| !query.is_empty())
+    }
+
+    /// Open the host search prompt with an empty query.
+    pub fn begin_host_search(&mut self) {
+        self.host_search = Some(HostSearch::default());
+    }
+
+    /// Append a typed character to the open search query.
+    pub fn host_search_push(&mut self, c: char) {
+        if let Some(search) = self.host_search.as_mut() {
+            if search.query.chars().count() < MAX_HOST_SEARCH_LEN {
+                search.query.push(c);
+            }
+        }
+    }
+
+    /// Remove the last character of the open search query.
+    pub fn host_search_pop(&mut self) {
+        if let Some(search) = self.host_search.as_mut() {
+            search.query.pop();
+        }
+    }
+
+    /// Close the search prompt without changing tabs.
+    pub fn cancel_host_search(&mut self) {
+        self.host_search = None;
+    }
+
+    /// Close the search prompt and return the first matching tab, which
+    /// the caller selects.
+    pub fn accept_host_search(&mut self) -> Option<usize> {
+        let search = self.host_search.take()?;
+        self.find_host_tab(&search.query)
+    }
+
+    /// Open the heatmap on its first cell, or close it if already open.
+    pub fn toggle_heatmap(&mut self) {
+        self.heatmap = match self.heatmap {
+            Some(_) => None,
+            None => Some(HeatmapState::default()),
+        };
+    }
+
+    /// Color the heatmap by the next metric.
+    pub fn cycle_heatmap_metric(&mut self) {
+        if let Some(heatmap) = self.heatmap.as_mut() {
+            heatmap.metric = heatmap.metric.next();
+        }
+    }
+
+    /// Processes shown in the process list, honoring the GPU filter (`f`),
+    /// the activity floor (`i`) and the top-N cut (`t`). The current sort
+    /// order is kept.
+    pub fn displayed_processes(&self) -> Cow<'_, [ProcessInfo]> {
+        if !self.gpu_filter_enabled
+            && self.min_process_activity <= 0.0
+            && self.process_top == ProcessTop::Off
+        {
+            return Cow::Borrowed(&self.process_info);
+        }
+
+        let mut shown: Vec<&ProcessInfo> = self
+            .process_info
+            .iter()
+            .filter(|p| !self.gpu_filter_enabled || p.used_memory > 0)
+            .filter(|p| process_activity(p) >= self.min_process_activity)
+            .collect();
+        if self.process_top != ProcessTop::Off && shown.len() > PROCESS_TOP_COUNT {
+            let mut ranked = shown.clone();
+            ranked.sort_by(|a, b| self.process_top.rank(a, b));
+            let top: HashSet<u32> = ranked[..PROCESS_TOP_COUNT].iter().map(|p| p.pid).collect();
+            shown.retain(|p| top.contains(&p.pid));
+        }
+        Cow::Owned(shown.into_iter().cloned().collect())
+    }
+
+    /// Tab name of the node whose processes the current remote tab lists, or
+    /// `None` on the "All" tab, which lists every node's
+    fn process_tab_host(&self) -> Option<&str> {
+        self.tabs
+            .get(self.current_tab)
+            .filter(|_| self.current_tab > 0)
+            .map(String::as_str)
+    }
+
+    /// Processes listed on the current remote tab, filtered like
+    /// [`Self::displayed_processes`]
+    pub fn remote_tab_processes(&self) -> Cow<'_, [ProcessInfo]> {
+        let shown = self.displayed_processes();
+        match self.process_tab_host() {
+            Some(host) => Cow::Owned(
+                shown
+                    .iter()
+                    .filter(|process| process.host_id == host)
+                    .cloned()
+                    .collect(),
+            ),
+            None => shown,
+        }
+    }
+
+    /// Whether the current remote tab has a process list to show
+    pub fn remote_tab_has_processes(&self) -> bool {
+        match self.process_tab_host() {
+            Some(host) => self.process_info.iter().any(|p| p.host_id == host),
+            None => !self.process_info.is_empty(),
+        }
+    }
+
+    /// Processes the last scrape left out by `--process-top`, on the node of
+    /// the current remote tab or on all of them
+    pub fn remote_processes_omitted(&self) -> usize {
+        let host = self.process_tab_host();
+        self.connection_status
+            .values()
+            .filter(|status| match host {
+                Some(host) => self
+                    .host_info
+                    .get(host)
+                    .map_or(status.host_id == host, |info| {
+                        info.addresses.contains(&status.host_id)
+                    }),
+                None => true,
+            })
+            .map(|status| status.processes_omitted)
+            .sum()
+    }
+
+    /// Switch to the next top-N cut of the process list.
+    pub fn cycle_process_top(&mut self) {
+        self.process_top = self.process_top.next();
+        self.selected_process_index = 0;
+        self.start_index = 0;
+    }
+
+    /// Raise the activity floor of the process list, or turn it off after
+    /// the highest one.
+    pub fn cycle_min_process_activity(&mut self) {
+        let current = PROCESS_ACTIVITY_FLOORS
+            .iter()
+            .position(|floor| *floor == self.min_process_activity)
+            .unwrap_or(0);
+        self.min_process_activity =
+            PROCESS_ACTIVITY_FLOORS[(current + 1) % PROCESS_ACTIVITY_FLOORS.len()];
+        self.selected_process_index = 0;
+        self.start_index = 0;
+    }
+
+    /// Number of rows the process list can select.
+    pub fn process_row_count(&self) -> usize {
+        if self.group_by_user {
+            let processes = self.displayed_processes();
+            let summaries = summarize_by_user(&processes);
+            user_view_rows(&summaries, &processes, self.expanded_user.as_deref()).len()
+        } else {
+            self.displayed_processes().len()
+        }
+    }
+
+    /// Freeze or resume the displayed data (Space or `P`).
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        self.paused_at = self.paused.then(Local::now);
+        self.refresh_requested = None;
+    }
+
+    /// Collect now instead of waiting for the interval (`r`). While paused,
+    /// the display moves on to the refreshed data once it arrives and stays
+    /// frozen there.
+    pub fn request_refresh(&mut self) {
+        self.refresh_now.notify_one();
+        if self.paused {
+            self.refresh_requested = Some(self.data_version);
+        }
+    }
+
+    /// Switch between the per-process and per-user process list.
+    pub fn toggle_group_by_user(&mut self) {
+        self.group_by_user = !self.group_by_user;
+        self.expanded_user = None;
+        self.selected_process_index = 0;
+        self.start_index = 0;
+    }
+
+    /// Expand the selected user row to list that user's processes, or
+    /// collapse it if already expanded. The selection stays on the user row.
+    pub fn toggle_selected_user(&mut self) {
+        if !self.group_by_user {
+            return;
+        }
+        let (user, user_index) = {
+            let processes = self.displayed_processes();
+            let summaries = summarize_by_user(&processes);
+            let rows = user_view_rows(&summaries, &processes, self.expanded_user.as_deref());
+            let Some(ProcessRow::User(summary)) = rows.get(self.selected_process_index) else {
+                return;
+            };
+            let user_index = summaries.iter().position(|s| s.user == summary.user);
+            (summary.user.clone(), user_index.unwrap_or_default())
+        };
+
+        self.expanded_user = if self.expanded_user.as_ref() == Some(&user) {
+            None
+        } else {
+            Some(user)
+        };
+        // At most one user is expanded and its processes follow its own row,
+        // so every user row now sits at its position among the users
+        self.selected_process_index = user_index;
+    }
+
+    /// Process on the selected row of the process list. `None` on user rows
+    /// of the per-user view.
+    pub fn selected_process(&self) -> Option<ProcessInfo> {
+        let processes = self.displayed_processes();
+        if !self.group_by_user {
+            return processes.get(self.selected_process_index).cloned();
+        }
+        let summaries = summarize_by_user(&processes);
+        let rows = user_view_rows(&summaries, &processes, self.expanded_user.as_deref());
+        match rows.get(self.selected_process_index) {
+            Some(ProcessRow::Process(process)) => Some((*process).clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether `pid` may be signaled now. While paused the list on screen is
+    /// not the one selections refer to, so it counts as stale.
+    fn check_kill_target(&self, pid: u32, max_age: Duration) -> Result<(), String> {
+        let list_age = self
+            .processes_collected_at
+            .filter(|_| !self.paused)
+            .map(|at| at.elapsed());
+        check_target(pid, std::process::id(), list_age, max_age).map_err(|e| e.to_string())
+    }
+
+    /// Open the prompt for sending `signal` to the selected process, or tell
+    /// in the status line why it cannot be signaled. Lists older than
+    /// `max_age` are refused. With `--readonly` there is no prompt at all.
+    pub fn begin_kill(&mut self, signal: Signal, current_user: &str, max_age: Duration) {
+        if self.readonly {
+            return;
+        }
+        let Some(process) = self.selected_process() else {
+            return;
+        };
+        if let Err(reason) = self.check_kill_target(process.pid, max_age) {
+            let _ = self.notifications.error(format!("Not sent: {reason}"));
+            return;
+        }
+        self.kill_prompt = Some(KillPrompt {
+            pid: process.pid,
+            foreign: process.user != current_user,
+            process_name: process.process_name,
+            user: process.user,
+            used_memory: process.used_memory,
+            used_memory_estimated: process.used_memory_estimated,
+            signal,
+            confirmed_once: false,
+        });
+    }
+
+    /// Confirm the open prompt. Processes of other users need a second
+    /// confirmation; after the last one the signal goes out through `sender`
+    /// and the outcome is reported in the status line.
+    pub fn confirm_kill(&mut self, sender: &dyn SignalSender, max_age: Duration) {
+        let Some(prompt) = self.kill_prompt.as_mut() else {
+            return;
+        };
+        if prompt.foreign && !prompt.confirmed_once {
+            prompt.confirmed_once = true;
+            return;
+        }
+        let Some(prompt) = self.kill_prompt.take() else {
+            return;
+        };
+
+        // The list may have aged or dropped the process while the prompt was open
+        if let Err(reason) = self.check_kill_target(prompt.pid, max_age) {
+            let _ = self.notifications.error(format!("Not sent: {reason}"));
+            return;
+        }
+        let still_listed = self
+            .process_info
+            .iter()
+            .any(|p| p.pid == prompt.pid && p.process_name == prompt.process_name);
+        if !still_listed {
+            let _ = self.notifications.error(format!(
+                "Not sent: {} ({}) has exited",
+                prompt.process_name, prompt.pid
+            ));
+            return;
+        }
+
+        let signal = prompt.signal.name();
+        let _ = match sender.send(prompt.pid, prompt.signal) {
+            Ok(()) => self.notifications.status(format!(
+                "Sent {signal} to {} ({})",
+                prompt.process_name, prompt.pid
+            )),
+            Err(e) => self.notifications.error(format!(
+                "{signal} to {} failed: {}",
+                prompt.pid,
+                describe_error(&e, &prompt.user)
+            )),
+        };
+    }
+
+    /// Close the prompt without signaling.
+    pub fn cancel_kill(&mut self) {
+        self.kill_prompt = None;
+    }
+
+    /// GPUs of the current tab in display order: pinned devices first, then
+    /// the rest, each group ordered by the sort criteria.
+    pub fn displayed_gpus(&self) -> Vec<&GpuInfo> {
+        let mut gpus: Vec<&GpuInfo> = match self.tabs.get(self.current_tab) {
+            Some(tab) if tab != "All" => self
+                .gpu_info
+                .iter()
+                .filter(|info| info.host_id == *tab)
+                .collect(),
+            _ => self.gpu_info.iter().collect(),
+        };
+        let pinned = |info: &GpuInfo| self.pinned_gpus.contains(&info.uuid);
+        gpus.sort_by(|a, b| {
+            pinned(b)
+                .cmp(&pinned(a))
+                .then_with(|| self.sort_criteria.sort_gpus(a, b))
+        });
+        gpus
+    }
+
+    /// Row of the selected device in [`Self::displayed_gpus`]
+    pub fn selected_gpu_row(&self) -> Option<usize> {
+        let selected = self.selected_gpu.as_ref()?;
+        self.displayed_gpus()
+            .iter()
+            .position(|info| info.uuid == *selected)
+    }
+
+    /// Select the next (or previous) displayed device. Starts from the first
+    /// (or last) one when nothing on this tab is selected.
+    pub fn move_gpu_selection(&mut self, forward: bool) {
+        let row = self.selected_gpu_row();
+        let gpus = self.displayed_gpus();
+        let next = match (row, forward) {
+            (Some(row), true) => (row + 1).min(gpus.len().saturating_sub(1)),
+            (Some(row), false) => row.saturating_sub(1),
+            (None, true) => 0,
+            (None, false) => gpus.len().saturating_sub(1),
+        };
+        self.selected_gpu = gpus.get(next).map(|info| info.uuid.clone());
+    }
+
+    /// Pin or unpin the selected device. Returns false if none is selected.
+    pub fn toggle_selected_gpu_pin(&mut self) -> bool {
+        let Some(uuid) = self.selected_gpu.clone() else {
+            return false;
+        };
+        if !self.pinned_gpus.remove(&uuid) {
+            self.pinned_gpus.insert(uuid);
+        }
+        true
+    }
+
+    /// Copy navigation and display settings from the live state onto a frozen
+    /// snapshot, so scrolling, sorting and tab switching keep working while the
+    /// displayed data is paused.
+    pub fn apply_view_state(&mut self, live: &AppState) {
+        let resort =
+            self.sort_criteria != live.sort_criteria || self.sort_direction != live.sort_direction;
+
+        self.selected_process_index = live.selected_process_index;
+        self.start_index = live.start_index;
+        self.sort_criteria = live.sort_criteria;
+        self.sort_direction = live.sort_direction;
+        self.current_tab = live.current_tab.min(self.tabs.len().saturating_sub(1));
+        self.gpu_scroll_offset = live.gpu_scroll_offset;
+        self.storage_scroll_offset = live.storage_scroll_offset;
+        self.tab_scroll_offset = live.tab_scroll_offset;
+        self.process_horizontal_scroll_offset = live.process_horizontal_scroll_offset;
+        self.device_name_scroll_offsets = live.device_name_scroll_offsets.clone();
+        self.host_id_scroll_offsets = live.host_id_scroll_offsets.clone();
+        self.cpu_name_scroll_offsets = live.cpu_name_scroll_offsets.clone();
+        self.frame_counter = live.frame_counter;
+        self.show_help = live.show_help;
+        self.show_logs = live.show_logs;
+        self.show_per_core_cpu = live.show_per_core_cpu;
+        self.gpu_filter_enabled = live.gpu_filter_enabled;
+        self.group_by_user = live.group_by_user;
+        self.process_top = live.process_top;
+        self.min_process_activity = live.min_process_activity;
+        self.expanded_user = live.expanded_user.clone();
+        self.notifications = live.notifications.clone();
+        self.paused = live.paused;
+        self.paused_at = live.paused_at;
+        self.host_search = live.host_search.clone();
+        self.heatmap = live.heatmap.clone();
+        self.pinned_gpus = live.pinned_gpus.clone();
+        self.selected_gpu = live.selected_gpu.clone();
+        self.refresh_interval = live.refresh_interval;
+        self.process_layout = live.process_layout.clone();
+        self.column_chooser = live.column_chooser;
+        self.replay = live.replay;
+        // Frozen data has nothing new to flash; only follow the toggle
+        if self.change_highlight.is_some() != live.change_highlight.is_some() {
+            self.change_highlight = live
+                .change_highlight
+                .as_ref()
+                .map(|_| ChangeHighlight::new());
+        }
+
+        if resort {
+            let (criteria, direction) = (self.sort_criteria, self.sort_direction);
+            self.process_info
+                .sort_by(|a, b| criteria.sort_processes(a, b, direction));
+        }
+    }
+}
+
+/// The state the TUI draws: the live state, or a copy frozen while paused.
+#[derive(Default)]
+pub struct DisplayedSnapshot {
+    frozen: Option<AppState>,
+}
+
+impl DisplayedSnapshot {
+    /// Apply step run before each frame. While paused the frozen data is
+    /// kept and only navigation follows `live`, except that a refresh asked
+    /// for with `r` replaces it with the first data collected afterwards.
+    pub fn update(&mut self, live: &mut AppState) {
+        if !live.paused {
+            self.frozen = None;
+            return;
+        }
+        if live
+            .refresh_requested
+            .is_some_and(|version| live.data_version > version)
+        {
+            live.refresh_requested = None;
+            live.paused_at = Some(Local::now());
+            self.frozen = None;
+        }
+        self.frozen
+            .get_or_insert_with(|| live.clone())
+            .apply_view_state(live);
+    }
+
+    /// The frozen copy, or `None` when the live state is shown.
+    pub fn frozen(&self) -> Option<&AppState> {
+        self.frozen.as_ref()
+    }
+}
+
+impl From<DeviceOrder> for SortCriteria {
+    fn from(order: DeviceOrder) -> Self {
+        match order {
+            DeviceOrder::Uuid => SortCriteria::Uuid,
+            DeviceOrder::Index => SortCriteria::Default,
+            DeviceOrder::Util => SortCriteria::Utilization,
+            DeviceOrder::Temp => SortCriteria::Temperature,
+        }
+    }
 }
 
 impl SortCriteria {
     pub fn sort_gpus(&self, a: &GpuInfo, b: &GpuInfo) -> Ordering {
         match self {
-            SortCriteria::Default => {
-                // Sort by hostname first, then by index (original behavior)
-                a.hostname.cmp(&b.hostname).then_with(|| {
-                    let a_index = a
-                        .detail
-                        .get("index")
-                        .and_then(|s| s.parse::<u32>().ok())
-                        .unwrap_or(0);
-                    let b_index = b
-                        .detail
-                        .get("index")
-                        .and_then(|s| s.parse::<u32>().ok())
-                        .unwrap_or(0);
-                    a_index.cmp(&b_index)
-                })
-            }
-            SortCriteria::Utilization => {
-                // Sort by utilization (descending), then by hostname and index
-                b.utilization
-                    .partial_cmp(&a.utilization)
-                    .unwrap_or(Ordering::Equal)
-                    .then_with(|| a.hostname.cmp(&b.hostname))
-                    .then_with(|| {
-                        let a_index = a
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        let b_index = b
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        a_index.cmp(&b_index)
-                    })
-            }
-            SortCriteria::GpuMemory => {
-                // Sort by memory usage (descending), then by hostname and index
-                b.used_memory
-                    .cmp(&a.used_memory)
-                    .then_with(|| a.hostname.cmp(&b.hostname))
-                    .then_with(|| {
-                        let a_index = a
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        let b_index = b
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        a_index.cmp(&b_index)
-                    })
-            }
-            SortCriteria::Power => {
-                // Sort by power consumption (descending), then by hostname and index
-                b.power_consumption
-                    .partial_cmp(&a.power_consumption)
-                    .unwrap_or(Ordering::Equal)
-                    .then_with(|| a.hostname.cmp(&b.hostname))
-                    .then_with(|| {
-                        let a_index = a
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        let b_index = b
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        a_index.cmp(&b_index)
-                    })
-            }
-            SortCriteria::Temperature => {
-                // Sort by temperature (descending), then by hostname and index
-                b.temperature
-                    .cmp(&a.temperature)
-                    .then_with(|| a.hostname.cmp(&b.hostname))
-                    .then_with(|| {
-                        let a_index = a
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        let b_index = b
-                            .detail
-                            .get("index")
-                            .and_then(|s| s.parse::<u32>().ok())
-                            .unwrap_or(0);
-                        a_index.cmp(&b_index)
-                    })
-            }
-            _ => {
-                // For process sorting criteria, fall back to default GPU sorting
-                a.hostname.cmp(&b.hostname).then_with(|| {
-                    let a_index = a
-                        .detail
-                        .get("index")
-                        .and_then(|s| s.parse::<u32>().ok())
-                        .unwrap_or(0);
-                    let b_index = b
-                        .detail
-                        .get("index")
-                        .and_then(|s| s.parse::<u32>().ok())
-                        .unwrap_or(0);
-                    a_index.cmp(&b_index)
-                })
-            }
+            SortCriteria::Uuid => DeviceOrder::Uuid.compare(a, b),
+            SortCriteria::Utilization => DeviceOrder::Util.compare(a, b),
+            SortCriteria::Temperature => DeviceOrder::Temp.compare(a, b),
+            // Sort by memory usage (descending), then by hostname and index
+            SortCriteria::GpuMemory => b
+                .used_memory
+                .cmp(&a.used_memory)
+                .then_with(|| by_index(a, b)),
+            // Sort by power consumption (descending), then by hostname and index
+            SortCriteria::Power => b
+                .power_consumption
+                .total_cmp(&a.power_consumption)
+                .then_with(|| by_index(a, b)),
+            // Process sorting criteria keep the default GPU order
+            _ => by_index(a, b),
         }
     }
 
@@ -437,6 +1193,140 @@ mod tests {
         assert!(!state.gpu_filter_enabled);
     }
 
+    fn owned_process(pid: u32, user: &str, used_memory: u64) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: String::new(),
+            pid,
+            process_name: "python".to_string(),
+            used_memory,
+            used_memory_estimated: false,
+            cpu_percent: 10.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: user.to_string(),
+            state: "S".to_string(),
+            start_time: 0,
+            cpu_time: 0,
+            command: "python train.py".to_string(),
+            ppid: 1,
+            threads: 1,
+            uses_gpu: used_memory > 0,
+            priority: 20,
+            nice_value: 0,
+            gpu_utilization: 0.0,
+            k8s_pod: None,
+            host_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_process_top_and_activity_floor() {
+        let mut state = AppState::new();
+        // Sorted by PID; GPU memory grows and CPU usage shrinks with the PID
+        state.process_info = (1..=14)
+            .map(|pid| {
+                let mut process = owned_process(pid, "alice", u64::from(pid) << 20);
+                process.cpu_percent = f64::from(15 - pid);
+                process
+            })
+            .collect();
+        state.process_info[0].used_memory = 0;
+        let pids = |state: &AppState| -> Vec<u32> {
+            state.displayed_processes().iter().map(|p| p.pid).collect()
+        };
+        assert_eq!(state.process_row_count(), 14);
+
+        state.selected_process_index = 7;
+        state.cycle_process_top();
+        assert_eq!(state.process_top, ProcessTop::Memory);
+        assert_eq!(state.selected_process_index, 0);
+        // The ten largest GPU memory holders, still in PID order
+        assert_eq!(pids(&state), (5..=14).collect::<Vec<_>>());
+        assert_eq!(state.process_row_count(), PROCESS_TOP_COUNT);
+
+        state.cycle_process_top();
+        assert_eq!(state.process_top, ProcessTop::Utilization);
+        // Without GPU utilization, CPU usage ranks them
+        assert_eq!(pids(&state), (1..=10).collect::<Vec<_>>());
+
+        // The GPU filter applies before the cut
+        state.gpu_filter_enabled = true;
+        assert_eq!(pids(&state), (2..=11).collect::<Vec<_>>());
+        state.gpu_filter_enabled = false;
+
+        state.cycle_process_top();
+        assert_eq!(state.process_top, ProcessTop::Off);
+        assert_eq!(state.process_row_count(), 14);
+
+        // 1% hides the process with 1% CPU, 5% those with 1-4%
+        state.cycle_min_process_activity();
+        assert_eq!(state.min_process_activity, 1.0);
+        assert_eq!(state.process_row_count(), 14);
+        state.process_info[13].cpu_percent = 0.5;
+        assert_eq!(state.process_row_count(), 13);
+        state.cycle_min_process_activity();
+        assert_eq!(pids(&state), (1..=10).collect::<Vec<_>>());
+        // GPU utilization counts as activity too
+        state.process_info[12].gpu_utilization = 40.0;
+        assert_eq!(state.process_row_count(), 11);
+        state.cycle_min_process_activity();
+        assert_eq!(state.min_process_activity, 0.0);
+        assert_eq!(state.process_row_count(), 14);
+    }
+
+    #[test]
+    fn test_user_view_expand_collapse() {
+        let mut state = AppState::new();
+        state.process_info = vec![
+            owned_process(1, "alice", 1 << 30),
+            owned_process(2, "bob", 4 << 30),
+            owned_process(3, "alice", 1 << 30),
+            owned_process(4, "carol", 0),
+        ];
+        assert_eq!(state.process_row_count(), 4);
+
+        state.selected_process_index = 2;
+        state.toggle_group_by_user();
+        assert!(state.group_by_user);
+        assert_eq!(state.selected_process_index, 0);
+        // bob, alice, carol
+        assert_eq!(state.process_row_count(), 3);
+
+        // Enter on alice lists her two processes below her row
+        state.selected_process_index = 1;
+        state.toggle_selected_user();
+        assert_eq!(state.expanded_user.as_deref(), Some("alice"));
+        assert_eq!(state.selected_process_index, 1);
+        assert_eq!(state.process_row_count(), 5);
+
+        // Enter on a process row does nothing
+        state.selected_process_index = 2;
+        state.toggle_selected_user();
+        assert_eq!(state.expanded_user.as_deref(), Some("alice"));
+
+        // Expanding carol collapses alice, whose rows no longer precede carol
+        state.selected_process_index = 4;
+        state.toggle_selected_user();
+        assert_eq!(state.expanded_user.as_deref(), Some("carol"));
+        assert_eq!(state.selected_process_index, 2);
+        assert_eq!(state.process_row_count(), 4);
+
+        // Enter again collapses
+        state.toggle_selected_user();
+        assert_eq!(state.expanded_user, None);
+        assert_eq!(state.process_row_count(), 3);
+
+        // The GPU filter also applies to the per-user rows
+        state.gpu_filter_enabled = true;
+        assert_eq!(state.process_row_count(), 2);
+
+        state.toggle_group_by_user();
+        assert!(!state.group_by_user);
+        assert_eq!(state.expanded_user, None);
+    }
+
     #[test]
     fn test_data_version_increment() {
         let mut state = AppState::new();
@@ -449,12 +1339,108 @@ mod tests {
         assert_eq!(state.data_version, initial_version + 2);
     }
 
+    #[test]
+    fn test_apply_view_state_keeps_frozen_data() {
+        let mut live = AppState::new();
+        live.process_info = vec![create_test_process(1, 100), create_test_process(2, 200)];
+        live.paused = true;
+
+        let mut frozen = live.clone();
+
+        // New data arrives and the user navigates while paused
+        live.process_info = vec![create_test_process(3, 300)];
+        live.mark_data_changed();
+        live.selected_process_index = 1;
+        live.sort_criteria = SortCriteria::GpuMemoryUsage;
+        live.sort_direction = SortDirection::Descending;
+
+        frozen.apply_view_state(&live);
+
+        assert_eq!(frozen.selected_process_index, 1);
+        assert_eq!(frozen.sort_criteria, SortCriteria::GpuMemoryUsage);
+        assert_ne!(frozen.data_version, live.data_version);
+        let pids: Vec<u32> = frozen.process_info.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![2, 1], "frozen processes should be re-sorted");
+    }
+
+    /// New collection results arriving in the live state
+    fn inject(live: &mut AppState, pids: &[u32]) {
+        live.process_info = pids
+            .iter()
+            .map(|&pid| create_test_process(pid, 100))
+            .collect();
+        live.mark_data_changed();
+    }
+
+    fn displayed_pids(displayed: &DisplayedSnapshot, live: &AppState) -> Vec<u32> {
+        let state = displayed.frozen().unwrap_or(live);
+        state.process_info.iter().map(|p| p.pid).collect()
+    }
+
+    #[test]
+    fn test_pause_freezes_displayed_snapshot() {
+        let mut live = AppState::new();
+        let mut displayed = DisplayedSnapshot::default();
+        inject(&mut live, &[1]);
+        displayed.update(&mut live);
+        assert!(displayed.frozen().is_none());
+
+        live.toggle_pause();
+        assert!(live.paused_at.is_some());
+        displayed.update(&mut live);
+        inject(&mut live, &[2]);
+        displayed.update(&mut live);
+        assert_eq!(displayed_pids(&displayed, &live), [1]);
+
+        // Resuming shows the latest collected data
+        live.toggle_pause();
+        displayed.update(&mut live);
+        assert!(live.paused_at.is_none());
+        assert_eq!(displayed_pids(&displayed, &live), [2]);
+    }
+
+    #[test]
+    fn test_refresh_while_paused_refreezes_on_new_data() {
+        let mut live = AppState::new();
+        let mut displayed = DisplayedSnapshot::default();
+        inject(&mut live, &[1]);
+        live.toggle_pause();
+        displayed.update(&mut live);
+
+        live.request_refresh();
+        displayed.update(&mut live);
+        assert_eq!(displayed_pids(&displayed, &live), [1]);
+
+        inject(&mut live, &[2]);
+        displayed.update(&mut live);
+        assert_eq!(displayed_pids(&displayed, &live), [2]);
+        assert!(live.refresh_requested.is_none());
+        assert!(live.paused);
+
+        // Still frozen at the refreshed data
+        inject(&mut live, &[3]);
+        displayed.update(&mut live);
+        assert_eq!(displayed_pids(&displayed, &live), [2]);
+    }
+
+    #[tokio::test]
+    async fn test_request_refresh_wakes_collector() {
+        let mut live = AppState::new();
+        let refresh = live.refresh_now.clone();
+        live.request_refresh();
+        assert!(live.refresh_requested.is_none());
+        tokio::time::timeout(Duration::from_millis(100), refresh.notified())
+            .await
+            .expect("refresh should be signaled");
+    }
+
     fn create_test_process(pid: u32, used_memory: u64) -> ProcessInfo {
         ProcessInfo {
             device_id: 0,
             device_uuid: "test-uuid".to_string(),
             pid,
             used_memory,
+            used_memory_estimated: false,
             process_name: format!("process_{pid}"),
             user: "testuser".to_string(),
             state: "S".to_string(),
@@ -467,10 +1453,12 @@ mod tests {
             memory_vms: 1024 * 1024,
             memory_rss: 512 * 1024,
             cpu_time: 100,
-            start_time: "00:00:00".to_string(),
+            start_time: 0,
             ppid: 1,
             threads: 1,
             uses_gpu: used_memory > 0,
+            k8s_pod: None,
+            host_id: String::new(),
         }
     }
 
@@ -596,4 +1584,302 @@ mod tests {
             "p1 (pid 100) should come after p3 (pid 50) in ascending order"
         );
     }
+
+    #[test]
+    fn test_find_host_tab() {
+        let mut state = AppState::new();
+        state.is_local_mode = false;
+        state.tabs = vec![
+            "All".to_string(),
+            "10.0.0.1:9090".to_string(),
+            "10.0.0.2:9090".to_string(),
+        ];
+        let mut status = ConnectionStatus::new("10.0.0.2:9090".to_string(), String::new());
+        status.actual_hostname = Some("GPU-Node-42".to_string());
+        state
+            .connection_status
+            .insert("10.0.0.2:9090".to_string(), status);
+
+        // Substring of the host id, first match wins
+        assert_eq!(state.find_host_tab("9090"), Some(1));
+        assert_eq!(state.find_host_tab("0.2"), Some(2));
+        // Case-insensitive match on the reported hostname
+        assert_eq!(state.find_host_tab("node-42"), Some(2));
+        // "All" is never a match, nor is an empty query
+        assert_eq!(state.find_host_tab("all"), None);
+        assert_eq!(state.find_host_tab(""), None);
+        assert_eq!(state.find_host_tab("missing"), None);
+    }
+
+    #[test]
+    fn test_host_search_transitions() {
+        let mut state = AppState::new();
+        state.is_local_mode = false;
+        state.tabs = vec![
+            "All".to_string(),
+            "10.0.0.1:9090".to_string(),
+            "10.0.0.2:9090".to_string(),
+        ];
+
+        // Typing builds the query and narrows the matches
+        state.begin_host_search();
+        assert_eq!(state.active_host_filter(), None);
+        for c in "0.0.".chars() {
+            state.host_search_push(c);
+        }
+        assert_eq!(state.matching_host_tabs("0.0."), vec![1, 2]);
+        state.host_search_push('2');
+        assert_eq!(state.active_host_filter(), Some("0.0.2"));
+
+        // Backspace removes the last character
+        state.host_search_pop();
+        assert_eq!(state.active_host_filter(), Some("0.0."));
+
+        // Esc closes the prompt and leaves the selection alone
+        state.cancel_host_search();
+        assert!(state.host_search.is_none());
+        assert_eq!(state.current_tab, 0);
+
+        // Enter closes the prompt and yields the first match
+        state.begin_host_search();
+        state.host_search_push('2');
+        assert_eq!(state.accept_host_search(), Some(2));
+        assert!(state.host_search.is_none());
+
+        // Accepting a query with no match yields nothing
+        state.begin_host_search();
+        state.host_search_push('x');
+        assert_eq!(state.accept_host_search(), None);
+
+        // Input is bounded
+        state.begin_host_search();
+        for _ in 0..MAX_HOST_SEARCH_LEN + 10 {
+            state.host_search_push('a');
+        }
+        assert_eq!(
+            state.active_host_filter().map(|q| q.len()),
+            Some(MAX_HOST_SEARCH_LEN)
+        );
+    }
+
+    fn displayed(state: &AppState) -> Vec<&str> {
+        state
+            .displayed_gpus()
+            .into_iter()
+            .map(|info| info.uuid.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_pinned_gpus_listed_first() {
+        let mut state = AppState::new();
+        state.gpu_info = vec![
+            GpuInfo {
+                utilization: 10.0,
+                ..GpuInfo::fixture("GPU-node2-0", "node2").with_detail(&[("index", "0")])
+            },
+            GpuInfo {
+                utilization: 90.0,
+                ..GpuInfo::fixture("GPU-node1-1", "node1").with_detail(&[("index", "1")])
+            },
+            GpuInfo {
+                utilization: 50.0,
+                ..GpuInfo::fixture("GPU-node1-0", "node1").with_detail(&[("index", "0")])
+            },
+            GpuInfo {
+                utilization: 30.0,
+                ..GpuInfo::fixture("GPU-node2-1", "node2").with_detail(&[("index", "1")])
+            },
+        ];
+        state.pinned_gpus = ["GPU-node2-1".to_string(), "GPU-node1-1".to_string()].into();
+
+        // Pinned devices keep the sort order among themselves
+        assert_eq!(
+            displayed(&state),
+            vec!["GPU-node1-1", "GPU-node2-1", "GPU-node1-0", "GPU-node2-0"]
+        );
+        state.sort_criteria = SortCriteria::Utilization;
+        assert_eq!(
+            displayed(&state),
+            vec!["GPU-node1-1", "GPU-node2-1", "GPU-node1-0", "GPU-node2-0"]
+        );
+        state.pinned_gpus.clear();
+        assert_eq!(
+            displayed(&state),
+            vec!["GPU-node1-1", "GPU-node1-0", "GPU-node2-1", "GPU-node2-0"]
+        );
+
+        // Node tabs only list their own devices
+        state.tabs = vec!["All".to_string(), "node2".to_string()];
+        state.current_tab = 1;
+        state.pinned_gpus.insert("GPU-node2-0".to_string());
+        assert_eq!(displayed(&state), vec!["GPU-node2-0", "GPU-node2-1"]);
+    }
+
+    #[test]
+    fn test_selection_follows_pinned_device() {
+        let mut state = AppState::new();
+        state.gpu_info = (0..4)
+            .map(|i| {
+                GpuInfo::fixture(&format!("GPU-node1-{i}"), "node1")
+                    .with_detail(&[("index", &i.to_string())])
+            })
+            .collect();
+        assert!(!state.toggle_selected_gpu_pin());
+
+        state.move_gpu_selection(false);
+        assert_eq!(state.selected_gpu.as_deref(), Some("GPU-node1-3"));
+        state.move_gpu_selection(false);
+        assert_eq!(state.selected_gpu_row(), Some(2));
+
+        // Pinning moves the selected device, and the selection with it, to the top
+        assert!(state.toggle_selected_gpu_pin());
+        assert_eq!(state.selected_gpu_row(), Some(0));
+        state.move_gpu_selection(true);
+        assert_eq!(state.selected_gpu.as_deref(), Some("GPU-node1-0"));
+
+        // The pin is kept by UUID when devices are enumerated in another order
+        state.gpu_info.reverse();
+        assert_eq!(displayed(&state)[0], "GPU-node1-2");
+
+        state.move_gpu_selection(false);
+        assert!(state.toggle_selected_gpu_pin());
+        assert!(state.pinned_gpus.is_empty());
+        assert_eq!(state.selected_gpu_row(), Some(2));
+    }
+
+    /// Records signals instead of sending them, failing with `error` if set.
+    #[derive(Default)]
+    struct MockSender {
+        sent: std::cell::RefCell<Vec<(u32, Signal)>>,
+        error: Option<i32>,
+    }
+
+    impl SignalSender for MockSender {
+        fn send(&self, pid: u32, signal: Signal) -> std::io::Result<()> {
+            self.sent.borrow_mut().push((pid, signal));
+            match self.error {
+                Some(errno) => Err(std::io::Error::from_raw_os_error(errno)),
+                None => Ok(()),
+            }
+        }
+    }
+
+    const MAX_AGE: Duration = Duration::from_secs(4);
+
+    fn kill_state(processes: Vec<ProcessInfo>) -> AppState {
+        let mut state = AppState::new();
+        state.process_info = processes;
+        state.processes_collected_at = Some(Instant::now());
+        state
+    }
+
+    fn status(state: &AppState) -> &str {
+        state
+            .notifications
+            .get_current_message()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_kill_own_process_after_one_confirmation() {
+        let mut state = kill_state(vec![owned_process(4242, "alice", 2 << 30)]);
+        let sender = MockSender::default();
+
+        state.begin_kill(Signal::Terminate, "alice", MAX_AGE);
+        let prompt = state.kill_prompt.clone().unwrap();
+        assert_eq!((prompt.pid, prompt.used_memory), (4242, 2 << 30));
+        assert!(!prompt.foreign);
+
+        state.confirm_kill(&sender, MAX_AGE);
+        assert_eq!(*sender.sent.borrow(), vec![(4242, Signal::Terminate)]);
+        assert!(state.kill_prompt.is_none());
+        assert_eq!(status(&state), "Sent SIGTERM to python (4242)");
+    }
+
+    #[test]
+    fn test_kill_refuses_init_self_and_stale_lists() {
+        let sender = MockSender::default();
+        let own_pid = std::process::id();
+        for pid in [1, own_pid] {
+            let mut state = kill_state(vec![owned_process(pid, "alice", 0)]);
+            state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+            assert!(state.kill_prompt.is_none());
+            assert!(status(&state).starts_with("Not sent: refusing"));
+        }
+
+        // Older than two refresh cycles, replayed, or paused
+        let mut state = kill_state(vec![owned_process(4242, "alice", 0)]);
+        state.processes_collected_at = Instant::now().checked_sub(MAX_AGE * 2);
+        state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+        assert!(state.kill_prompt.is_none());
+        assert_eq!(
+            status(&state),
+            "Not sent: process list is stale; wait for a refresh"
+        );
+        state.processes_collected_at = None;
+        state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+        assert!(state.kill_prompt.is_none());
+        state.processes_collected_at = Some(Instant::now());
+        state.paused = true;
+        state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+        assert!(state.kill_prompt.is_none());
+
+        // The list going stale while the prompt is open also stops the signal
+        state.paused = false;
+        state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+        assert!(state.kill_prompt.is_some());
+        state.processes_collected_at = Instant::now().checked_sub(MAX_AGE * 2);
+        state.confirm_kill(&sender, MAX_AGE);
+        assert!(sender.sent.borrow().is_empty());
+
+        // --readonly opens no prompt and says nothing
+        let mut state = kill_state(vec![owned_process(4242, "alice", 0)]);
+        state.readonly = true;
+        state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+        assert!(state.kill_prompt.is_none());
+        assert_eq!(status(&state), "");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_other_users_process_needs_second_confirmation() {
+        let mut state = kill_state(vec![owned_process(4242, "bob", 0)]);
+        let sender = MockSender {
+            error: Some(libc::EPERM),
+            ..MockSender::default()
+        };
+
+        state.begin_kill(Signal::Kill, "alice", MAX_AGE);
+        assert!(state.kill_prompt.as_ref().unwrap().foreign);
+        state.confirm_kill(&sender, MAX_AGE);
+        assert!(sender.sent.borrow().is_empty());
+        assert!(state.kill_prompt.as_ref().unwrap().confirmed_once);
+
+        state.confirm_kill(&sender, MAX_AGE);
+        assert_eq!(*sender.sent.borrow(), vec![(4242, Signal::Kill)]);
+        assert_eq!(
+            status(&state),
+            "SIGKILL to 4242 failed: permission denied: process belongs to bob; run all-smi as root"
+        );
+    }
+
+    #[test]
+    fn test_kill_cancel_and_exited_process() {
+        let mut state = kill_state(vec![owned_process(4242, "alice", 0)]);
+        let sender = MockSender::default();
+
+        state.begin_kill(Signal::Terminate, "alice", MAX_AGE);
+        state.cancel_kill();
+        state.confirm_kill(&sender, MAX_AGE);
+        assert!(sender.sent.borrow().is_empty());
+
+        // A refresh that no longer lists the process, or reuses its PID for
+        // another program, drops the request
+        state.begin_kill(Signal::Terminate, "alice", MAX_AGE);
+        state.process_info[0].process_name = "bash".to_string();
+        state.confirm_kill(&sender, MAX_AGE);
+        assert!(sender.sent.borrow().is_empty());
+        assert_eq!(status(&state), "Not sent: python (4242) has exited");
+    }
 }
@@ -0,0 +1,276 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `all-smi audit`: driver, CUDA and firmware versions of every host.
+//!
+//! Each host is scraped once, like a single refresh of `all-smi view`. The
+//! exit code tells CI whether the cluster runs one version of each.
+
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::cli::{AuditArgs, AuditFormat};
+use crate::device::driver_versions::{VersionAudit, VersionField};
+use crate::network::ssh::SshOptions;
+use crate::view::data_collection::hostfile::load_hosts;
+use crate::view::data_collection::{
+    CollectionConfig, DataCollectionStrategy, RemoteCollectorBuilder,
+};
+
+/// Exit code when every field has a single version
+pub const EXIT_UNIFORM: i32 = 0;
+/// Exit code when some field has more than one version
+pub const EXIT_MIXED: i32 = 1;
+/// Exit code when no host could be audited
+pub const EXIT_ERROR: i32 = 2;
+
+/// Versions of one scraped host
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HostReport {
+    pub host: String,
+    pub reachable: bool,
+    pub driver: Option<String>,
+    pub cuda: Option<String>,
+    pub firmware: Option<String>,
+}
+
+/// Hosts reporting one version of a field
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct VersionCount {
+    pub version: String,
+    pub hosts: usize,
+}
+
+/// The version matrix of the cluster
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AuditReport {
+    pub hosts: Vec<HostReport>,
+    pub driver: Vec<VersionCount>,
+    pub cuda: Vec<VersionCount>,
+    pub firmware: Vec<VersionCount>,
+    pub mixed: bool,
+}
+
+impl AuditReport {
+    /// Report on `hosts`, in the given order; `reachable` tells whether a
+    /// host answered the scrape
+    pub fn new(audit: &VersionAudit, hosts: &[String], reachable: impl Fn(&str) -> bool) -> Self {
+        let counts = |field| {
+            audit
+                .tally(field)
+                .into_iter()
+                .map(|(version, hosts)| VersionCount { version, hosts })
+                .collect()
+        };
+        let hosts = hosts
+            .iter()
+            .map(|host| {
+                let versions = audit.hosts.get(host);
+                let field = |field| versions.and_then(|v| v.get(&field)).cloned();
+                HostReport {
+                    host: host.clone(),
+                    reachable: reachable(host),
+                    driver: field(VersionField::Driver),
+                    cuda: field(VersionField::Cuda),
+                    firmware: field(VersionField::Firmware),
+                }
+            })
+            .collect();
+        Self {
+            hosts,
+            driver: counts(VersionField::Driver),
+            cuda: counts(VersionField::Cuda),
+            firmware: counts(VersionField::Firmware),
+            mixed: audit.is_mixed(),
+        }
+    }
+
+    fn counts(&self, field: VersionField) -> &[VersionCount] {
+        match field {
+            VersionField::Driver => &self.driver,
+            VersionField::Cuda => &self.cuda,
+            VersionField::Firmware => &self.firmware,
+        }
+    }
+}
+
+/// One row per host, with versions other than the majority marked `*`,
+/// followed by the hosts per version
+pub fn render_table(report: &AuditReport) -> String {
+    let cell = |host: &HostReport, field: VersionField| -> String {
+        if !host.reachable {
+            return "-".to_string();
+        }
+        let value = match field {
+            VersionField::Driver => &host.driver,
+            VersionField::Cuda => &host.cuda,
+            VersionField::Firmware => &host.firmware,
+        };
+        match (value, report.counts(field).first()) {
+            (Some(value), Some(majority)) if *value != majority.version => format!("{value}*"),
+            (Some(value), _) => value.clone(),
+            (None, _) => "-".to_string(),
+        }
+    };
+
+    let header = ["HOST", "DRIVER", "CUDA", "FIRMWARE"].map(String::from);
+    let mut rows = vec![header];
+    for host in &report.hosts {
+        let [driver, cuda, firmware] = VersionField::ALL.map(|field| cell(host, field));
+        let name = if host.reachable {
+            host.host.clone()
+        } else {
+            format!("{} (unreachable)", host.host)
+        };
+        rows.push([name, driver, cuda, firmware]);
+    }
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in &rows {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(value, width)| format!("{value:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    out.push('\n');
+    for field in VersionField::ALL {
+        let counts = report.counts(field);
+        if counts.is_empty() {
+            continue;
+        }
+        let versions = counts
+            .iter()
+            .map(|count| format!("{} ×{}", count.version, count.hosts))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = writeln!(out, "{}: {versions}", field.name());
+    }
+    out
+}
+
+/// Run `all-smi audit`; returns the process exit code
+pub async fn run(args: &AuditArgs) -> i32 {
+    let hosts = args.hosts.clone().unwrap_or_default();
+    let hostfiles = args.hostfile.clone().unwrap_or_default();
+    let hosts = match load_hosts(&hosts, &hostfiles) {
+        Ok(list) if !list.hosts.is_empty() => list.hosts,
+        Ok(_) => {
+            eprintln!("Error: audit requires --hosts or --hostfile");
+            return EXIT_ERROR;
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return EXIT_ERROR;
+        }
+    };
+
+    let mut builder = RemoteCollectorBuilder::new().with_hosts(hosts.clone());
+    if args.ssh.ssh {
+        builder = builder.with_ssh(SshOptions {
+            identity: args.ssh.ssh_identity.clone(),
+            remote_command: args.ssh.ssh_command.clone(),
+        });
+    }
+    let config = CollectionConfig {
+        hosts: hosts.clone(),
+        ..CollectionConfig::default()
+    };
+    let data = match builder.build().collect(&config).await {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return EXIT_ERROR;
+        }
+    };
+
+    let reachable = |host: &str| {
+        data.connection_statuses
+            .iter()
+            .any(|status| status.host_id == host && status.is_connected)
+    };
+    if !hosts.iter().any(|host| reachable(host)) {
+        eprintln!("Error: no host could be reached");
+        return EXIT_ERROR;
+    }
+    let report = AuditReport::new(&VersionAudit::new(&data.gpu_info), &hosts, reachable);
+    match args.format {
+        AuditFormat::Table => print!("{}", render_table(&report)),
+        AuditFormat::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return EXIT_ERROR;
+            }
+        },
+    }
+    if report.mixed {
+        EXIT_MIXED
+    } else {
+        EXIT_UNIFORM
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_table_marks_stragglers_and_unreachable_hosts() {
+        let host = |driver: &str| {
+            BTreeMap::from([
+                (VersionField::Driver, driver.to_string()),
+                (VersionField::Cuda, "12.2".to_string()),
+            ])
+        };
+        let audit = VersionAudit {
+            hosts: BTreeMap::from([
+                ("node-1:9090".to_string(), host("535.161")),
+                ("node-2:9090".to_string(), host("535.161")),
+                ("node-3:9090".to_string(), host("550.54")),
+            ]),
+        };
+        let hosts = ["node-1:9090", "node-2:9090", "node-3:9090", "node-4:9090"].map(String::from);
+        let report = AuditReport::new(&audit, &hosts, |host| host != "node-4:9090");
+        assert!(report.mixed);
+
+        assert_eq!(
+            render_table(&report),
+            "\
+HOST                       DRIVER   CUDA  FIRMWARE
+node-1:9090                535.161  12.2  -
+node-2:9090                535.161  12.2  -
+node-3:9090                550.54*  12.2  -
+node-4:9090 (unreachable)  -        -     -
+
+driver: 535.161 ×2, 550.54 ×1
+cuda: 12.2 ×3
+"
+        );
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["driver"][1]["version"], "550.54");
+        assert_eq!(json["hosts"][3]["reachable"], false);
+    }
+}
@@ -12,15 +12,65 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Args, CommandFactory, Parser, Subcommand, ValueEnum, ValueHint};
+use clap_complete::Shell;
+
+use crate::device::device_order::DeviceOrder;
+use crate::device::device_selection::{DeviceFilter, DeviceSelection};
+use crate::device::memory_headroom::OomThresholds;
+use crate::device::reader_supervisor::DEFAULT_READER_TIMEOUT;
+use crate::device::{CollectionFilter, Subsystem, SubsystemIntervals};
+use crate::ui::theme::ThemeName;
+use crate::utils::{TemperatureUnit, UnitSystem};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, disable_version_flag = true)]
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
+    /// Print version.
+    #[arg(short = 'V', long)]
+    pub version: bool,
+    /// With --version, print the version, git commit, enabled features and
+    /// supported platforms as JSON.
+    #[arg(long, requires = "version")]
+    pub json: bool,
+    /// Path to a TOML config file. Defaults to ~/.config/all-smi/config.toml if it exists.
+    #[arg(long, global = true, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub config: Option<PathBuf>,
+    /// Print the effective configuration and where each value came from, then exit.
+    #[arg(long, global = true)]
+    pub show_config: bool,
+    /// Write logs to PATH instead of stderr (API mode) or only the log overlay (TUI).
+    /// A new file, named PATH.YYYY-MM-DD, is started every day.
+    #[arg(long, global = true, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub log_file: Option<PathBuf>,
+    /// Log more detail: -v for debug, -vv for trace. `RUST_LOG` takes precedence.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+    /// Show byte sizes in powers of 1024 (GiB) or 1000 (GB). Defaults to
+    /// `ui.units` from the config file, or binary.
+    #[arg(long, global = true, value_enum)]
+    pub units: Option<UnitSystem>,
+    /// Show temperatures in Celsius (c) or Fahrenheit (f). Defaults to
+    /// `ui.temp_unit` from the config file, or Celsius. Metrics stay in Celsius.
+    #[arg(long, global = true, value_enum, value_name = "UNIT")]
+    pub temp_unit: Option<TemperatureUnit>,
+    /// Disable every action that changes the system, such as signaling
+    /// processes with k/K. all-smi only reads.
+    #[arg(long, global = true)]
+    pub readonly: bool,
+    /// Mark this node as draining for maintenance while PATH exists. Tabs show
+    /// `DRAINING` and API mode exports `all_smi_node_draining 1`.
+    #[arg(long, global = true, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub drain_file: Option<PathBuf>,
 }
 
+// Parsed once at startup, so variant size does not matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     /// Run in API mode, exposing metrics in Prometheus format.
@@ -29,6 +79,193 @@ pub enum Commands {
     Local(LocalArgs),
     /// Run in remote view mode, monitoring remote nodes via API endpoints.
     View(ViewArgs),
+    /// Print a Grafana dashboard for the metrics this build exports.
+    GenDashboard(GenDashboardArgs),
+    /// Print a shell completion script.
+    Completions(CompletionsArgs),
+    /// Print the man page, or write one page per subcommand with --dir.
+    Manpage(ManpageArgs),
+    /// Compare two snapshots, e.g. before and after a driver upgrade. Exits
+    /// with 0 when they match, 1 when they differ and 2 on errors.
+    Diff(DiffArgs),
+    /// Play back a --record file or a directory of Prometheus scrapes in the
+    /// TUI. Space pauses, Left/Right step and [/] change the speed.
+    Replay(ReplayArgs),
+    /// Scrape every host once and print its driver, CUDA and firmware
+    /// versions. Exits with 0 when each has one version across the cluster,
+    /// 1 when more than one is present and 2 when no host answered.
+    Audit(AuditArgs),
+    /// Run every reader of this host once and report what can be collected,
+    /// what is missing and how to fix it. Exits with 1 when no GPU or NPU
+    /// was found.
+    Doctor(DoctorArgs),
+    /// Scrape every host on an interval and re-export their metrics on a
+    /// single /metrics endpoint, with `all_smi_proxy_up` for each host.
+    Proxy(ProxyArgs),
+}
+
+#[derive(Parser)]
+pub struct GenDashboardArgs {
+    /// Write the dashboard JSON to FILE instead of stdout.
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+pub struct CompletionsArgs {
+    /// Shell to generate the script for, e.g. `all-smi completions zsh > _all-smi`.
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+#[derive(Parser)]
+pub struct ManpageArgs {
+    /// Write all-smi.1 and an all-smi-<command>.1 page for every subcommand
+    /// into DIR instead of printing the top-level page.
+    #[arg(long, value_name = "DIR", value_hint = ValueHint::DirPath)]
+    pub dir: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+pub struct DiffArgs {
+    /// Snapshot to compare from: a --record file, whose last snapshot is
+    /// used, or a single JSON snapshot.
+    #[arg(value_name = "BEFORE", value_hint = ValueHint::FilePath)]
+    pub before: PathBuf,
+    /// Snapshot to compare to, in the same formats as BEFORE.
+    #[arg(
+        value_name = "AFTER",
+        required_unless_present = "live",
+        conflicts_with = "live",
+        value_hint = ValueHint::FilePath
+    )]
+    pub after: Option<PathBuf>,
+    /// Compare BEFORE with a collection taken on this host now.
+    #[arg(long)]
+    pub live: bool,
+    /// Also report utilization, temperature and power changes of at least
+    /// --threshold. Without it only devices, hosts, versions and capacities
+    /// are compared.
+    #[arg(long)]
+    pub include_metrics: bool,
+    /// Smallest utilization (%), temperature (°C) or power (W) change
+    /// reported by --include-metrics.
+    #[arg(long, default_value_t = 5.0, requires = "include_metrics")]
+    pub threshold: f64,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = DiffFormat::Text)]
+    pub format: DiffFormat,
+}
+
+#[derive(Parser)]
+pub struct ReplayArgs {
+    /// A file written by `local --record`, or a directory holding one
+    /// Prometheus text scrape per file, named by its time in Unix seconds
+    /// or RFC 3339 (e.g. `2025-01-01T00:00:00Z.prom`).
+    #[arg(short, long, value_name = "PATH", value_hint = ValueHint::AnyPath)]
+    pub input: PathBuf,
+    /// Playback rate; 2 plays twice as fast as recorded.
+    #[arg(long, value_name = "FACTOR", default_value_t = 1.0, value_parser = parse_speed)]
+    pub speed: f64,
+    /// Enable mouse support (click tabs/rows, scroll wheel). Disables terminal text selection.
+    #[arg(long)]
+    pub mouse: bool,
+    /// Color theme. Defaults to `ui.theme` from the config file.
+    #[arg(long, value_enum)]
+    pub theme: Option<ThemeName>,
+}
+
+impl ReplayArgs {
+    /// Local mode showing the recording instead of live data
+    pub fn local_args(&self) -> LocalArgs {
+        LocalArgs {
+            interval: None,
+            mouse: self.mouse,
+            theme: self.theme,
+            sort_by: None,
+            normalize_load: false,
+            smooth: None,
+            textfile_dir: None,
+            subsystems: SubsystemArgs::default(),
+            disks: DiskFilterArgs::default(),
+            oom: OomArgs::default(),
+            recording: RecordingArgs {
+                replay: Some(self.input.clone()),
+                replay_speed: self.speed,
+                ..RecordingArgs::default()
+            },
+        }
+    }
+}
+
+#[derive(Parser)]
+pub struct AuditArgs {
+    /// Host addresses to scrape, as for `view --hosts`.
+    #[arg(long, num_args = 1.., value_hint = ValueHint::Url)]
+    pub hosts: Option<Vec<String>>,
+    /// A file listing host addresses, as for `view --hostfile`. Repeat to
+    /// merge several files.
+    #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    pub hostfile: Option<Vec<String>>,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = AuditFormat::Table)]
+    pub format: AuditFormat,
+    #[command(flatten)]
+    pub ssh: SshArgs,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuditFormat {
+    /// One row per host, deviating versions marked with `*`
+    #[default]
+    Table,
+    /// A single JSON object
+    Json,
+}
+
+#[derive(Parser)]
+pub struct ProxyArgs {
+    /// Host addresses to scrape, as for `view --hosts`.
+    #[arg(long, num_args = 1.., value_hint = ValueHint::Url)]
+    pub hosts: Option<Vec<String>>,
+    /// A file listing host addresses, as for `view --hostfile`. Repeat to
+    /// merge several files.
+    #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    pub hostfile: Option<Vec<String>>,
+    /// The port to serve the merged metrics on.
+    #[arg(short, long, default_value_t = 9090)]
+    pub port: u16,
+    /// The interval in seconds at which to scrape the hosts.
+    #[arg(short, long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+}
+
+#[derive(Parser)]
+pub struct DoctorArgs {
+    /// Port `all-smi api` would listen on, checked for being free.
+    #[arg(short, long, default_value_t = 9090)]
+    pub port: u16,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = DoctorFormat::Text)]
+    pub format: DoctorFormat,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DoctorFormat {
+    /// One row per reader, followed by what is missing and how to fix it
+    #[default]
+    Text,
+    /// A single JSON object
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiffFormat {
+    /// One line per difference
+    #[default]
+    Text,
+    /// A single JSON object
+    Json,
 }
 
 #[derive(Parser)]
@@ -39,16 +276,143 @@ pub struct ApiArgs {
     /// The interval in seconds at which to update the GPU information.
     #[arg(short, long, default_value_t = 3)]
     pub interval: u64,
+    /// Collect SUBSYSTEM every SECS seconds instead of every --interval,
+    /// e.g. `gpu=1,chassis=60` (comma-separated or repeated). Subsystems:
+    /// gpu (with processes), cpu, memory, disk, network, chassis.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "SUBSYSTEM=SECS",
+        value_parser = parse_collect_interval
+    )]
+    pub collect_interval: Vec<(Subsystem, u64)>,
     /// Include the process list in the API output.
     #[arg(long)]
     pub processes: bool,
+    /// Export only the N processes using the most GPU memory, ties broken by
+    /// processes on a device first, then lowest PID. Bounds metric
+    /// cardinality on busy hosts.
+    #[arg(long, value_name = "N")]
+    pub process_top: Option<usize>,
+    /// Comma-separated device labels to export (gpu, npu, cpu_model, instance,
+    /// hostname, uuid, index). Defaults to all of them.
+    #[arg(long, value_delimiter = ',')]
+    pub labels: Vec<String>,
+    /// Order devices are exported in. Each device keeps its `index` label
+    /// whatever the order.
+    #[arg(long, value_enum, value_name = "ORDER", default_value_t = DeviceOrder::Index)]
+    pub sort_by: DeviceOrder,
+    /// Seconds after which a collector that has not finished a pass counts
+    /// as stale, e.g. when a wedged driver blocks it. Defaults to three
+    /// times --interval.
+    #[arg(long, value_name = "SECS")]
+    pub staleness_window: Option<u64>,
+    /// What to do with the series of a stale collector.
+    #[arg(long, value_enum, default_value_t = StalenessPolicy::Omit)]
+    pub staleness_policy: StalenessPolicy,
     /// Unix domain socket path for local IPC (Unix only).
     /// When specified without a value, uses platform default:
     /// - Linux: /var/run/all-smi.sock (fallback to /tmp/all-smi.sock if no permission)
     /// - macOS: /tmp/all-smi.sock
     #[cfg(unix)]
-    #[arg(short, long, num_args = 0..=1, default_missing_value = "")]
+    #[arg(
+        short,
+        long,
+        visible_alias = "unix-socket",
+        num_args = 0..=1,
+        default_missing_value = "",
+        value_hint = ValueHint::FilePath
+    )]
     pub socket: Option<String>,
+    /// Octal permission bits for the socket file, e.g. 660 to let the
+    /// owning group connect.
+    #[cfg(unix)]
+    #[arg(
+        long,
+        value_name = "MODE",
+        default_value = "600",
+        value_parser = parse_socket_mode,
+        requires = "socket"
+    )]
+    pub socket_mode: u32,
+    /// Answer at most N scrapes of /metrics a second, in bursts of up to N;
+    /// the rest get 429 Too Many Requests with a Retry-After header.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_requests_per_second: Option<u32>,
+    /// Also serve the gRPC `MetricsService` on this port, pushing a snapshot
+    /// to subscribers every collection cycle.
+    #[cfg(feature = "grpc")]
+    #[arg(long, value_name = "PORT")]
+    pub grpc_port: Option<u16>,
+    /// Serve a dashboard of this node's devices at `/`, refreshed from
+    /// `/metrics.json`.
+    #[cfg(feature = "web")]
+    #[arg(long)]
+    pub web: bool,
+    /// Collect once, print the metrics to stdout and exit instead of
+    /// serving them. Used by `view --ssh` on hosts without the API server.
+    #[arg(long)]
+    pub once: bool,
+    /// Also write the metrics to `all_smi.prom` in DIR after every
+    /// collection, for the node_exporter textfile collector.
+    #[arg(long, value_name = "DIR", value_hint = ValueHint::DirPath)]
+    pub textfile_dir: Option<PathBuf>,
+    /// Add pod and namespace labels to the GPU and process series of devices
+    /// allocated to Kubernetes pods, read from the kubelet PodResources API
+    /// (Linux only). The lookup runs without the flag when the kubelet socket
+    /// exists, reporting the pod only in all_smi_gpu_info.
+    #[arg(long)]
+    pub kubernetes: bool,
+    #[command(flatten)]
+    pub push: PushArgs,
+    #[command(flatten)]
+    pub subsystems: SubsystemArgs,
+    #[command(flatten)]
+    pub disks: DiskFilterArgs,
+    #[command(flatten)]
+    pub oom: OomArgs,
+}
+
+/// Pushing metrics to hosts that cannot be scraped.
+#[derive(Args, Clone, Debug, Default)]
+pub struct PushArgs {
+    /// Periodically push metrics to URL: the base URL of a Prometheus
+    /// Pushgateway, or a remote-write endpoint with `--push-format
+    /// remote-write`. With `--port 0` and no socket, metrics are only pushed.
+    #[arg(long, value_name = "URL", value_hint = ValueHint::Url)]
+    pub push_url: Option<url::Url>,
+    /// Seconds between pushes. Defaults to --interval.
+    #[arg(long, value_name = "SECS", requires = "push_url")]
+    pub push_interval: Option<u64>,
+    /// Protocol spoken to --push-url.
+    #[arg(long, value_enum, default_value_t = PushFormat::Pushgateway)]
+    pub push_format: PushFormat,
+    /// User name for HTTP basic auth on pushes.
+    #[arg(long, value_name = "USER", requires = "push_url")]
+    pub push_username: Option<String>,
+    /// File holding the basic auth password; surrounding whitespace is
+    /// ignored.
+    #[arg(long, value_name = "PATH", requires = "push_username", value_hint = ValueHint::FilePath)]
+    pub push_password_file: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PushFormat {
+    /// Text exposition format, POSTed to /metrics/job/all_smi/instance/<host>
+    #[default]
+    Pushgateway,
+    /// Snappy-compressed remote-write protobuf
+    #[cfg(feature = "remote-write")]
+    RemoteWrite,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StalenessPolicy {
+    /// Stop exporting the series until the collector catches up
+    #[default]
+    Omit,
+    /// Keep exporting the last values, with `all_smi_gpu_stale 1` per device
+    Flag,
 }
 
 #[derive(Parser, Clone)]
@@ -56,17 +420,338 @@ pub struct LocalArgs {
     /// The interval in seconds at which to update the GPU information.
     #[arg(short, long)]
     pub interval: Option<u64>,
+    /// Enable mouse support (click tabs/rows, scroll wheel). Disables terminal text selection.
+    #[arg(long)]
+    pub mouse: bool,
+    /// Color theme. Defaults to `ui.theme` from the config file, or to light on
+    /// terminals whose `COLORFGBG` reports a light background.
+    #[arg(long, value_enum)]
+    pub theme: Option<ThemeName>,
+    /// Initial device order. Defaults to `ui.default_sort` from the config
+    /// file, or to hostname and index.
+    #[arg(long, value_enum, value_name = "ORDER")]
+    pub sort_by: Option<DeviceOrder>,
+    /// Color load averages by load per CPU core, so 1.0 means every core
+    /// is busy, instead of showing the raw load.
+    #[arg(long)]
+    pub normalize_load: bool,
+    /// Smooth the utilization gauges with an exponential moving average.
+    /// ALPHA (0-1, default 0.3) is the weight of the newest reading. The
+    /// `Util:` figure stays raw, as do the metrics served in API mode.
+    #[arg(
+        long,
+        value_name = "ALPHA",
+        num_args = 0..=1,
+        default_missing_value = "0.3",
+        value_parser = parse_alpha
+    )]
+    pub smooth: Option<f64>,
+    /// Also write the metrics to `all_smi.prom` in DIR after every
+    /// collection, for the node_exporter textfile collector.
+    #[arg(long, value_name = "DIR", conflicts_with = "replay", value_hint = ValueHint::DirPath)]
+    pub textfile_dir: Option<PathBuf>,
+    #[command(flatten)]
+    pub subsystems: SubsystemArgs,
+    #[command(flatten)]
+    pub disks: DiskFilterArgs,
+    #[command(flatten)]
+    pub oom: OomArgs,
+    #[command(flatten)]
+    pub recording: RecordingArgs,
+}
+
+/// Saving local-mode snapshots and playing them back instead of live data.
+#[derive(Args, Clone, Debug)]
+pub struct RecordingArgs {
+    /// Write every collected snapshot to FILE as JSON Lines, replacing any
+    /// earlier contents.
+    #[arg(long, value_name = "FILE", conflicts_with = "replay", value_hint = ValueHint::FilePath)]
+    pub record: Option<PathBuf>,
+    /// Cap the --record file at MB megabytes. When full, it is moved to
+    /// FILE.1, replacing an older one, and recording continues in a fresh
+    /// FILE.
+    #[arg(
+        long,
+        value_name = "MB",
+        requires = "record",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub record_max_mb: Option<u64>,
+    /// Show the snapshots in FILE, written by --record, instead of live
+    /// data. Playback loops until quit; see `all-smi replay` for the keys.
+    #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    pub replay: Option<PathBuf>,
+    /// Playback rate for --replay; 2 plays twice as fast as recorded.
+    #[arg(long, value_name = "FACTOR", default_value_t = 1.0, value_parser = parse_speed)]
+    pub replay_speed: f64,
+}
+
+impl Default for RecordingArgs {
+    fn default() -> Self {
+        Self {
+            record: None,
+            record_max_mb: None,
+            replay: None,
+            replay_speed: 1.0,
+        }
+    }
+}
+
+impl ApiArgs {
+    /// --interval with the --collect-interval overrides applied
+    pub fn subsystem_intervals(&self) -> SubsystemIntervals {
+        self.collect_interval.iter().fold(
+            SubsystemIntervals::new(self.interval),
+            |intervals, &(subsystem, secs)| intervals.with(subsystem, secs),
+        )
+    }
+}
+
+/// Parse one `SUBSYSTEM=SECS` entry of --collect-interval.
+pub fn parse_collect_interval(value: &str) -> Result<(Subsystem, u64), String> {
+    let (name, secs) = value
+        .split_once('=')
+        .ok_or_else(|| format!("'{value}' is not SUBSYSTEM=SECS"))?;
+    let subsystem = Subsystem::from_name(name.trim()).ok_or_else(|| {
+        let names: Vec<_> = Subsystem::ALL.iter().map(|s| s.name()).collect();
+        format!(
+            "unknown subsystem '{}' (expected one of: {})",
+            name.trim(),
+            names.join(", ")
+        )
+    })?;
+    match secs.trim().parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok((subsystem, secs)),
+        Ok(_) => Err("interval must be at least 1 second".to_string()),
+        Err(e) => Err(format!("invalid interval '{}': {e}", secs.trim())),
+    }
+}
+
+fn parse_alpha(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(alpha) if alpha > 0.0 && alpha <= 1.0 => Ok(alpha),
+        Ok(_) => Err("must be greater than 0 and at most 1".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn parse_speed(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(speed) if speed.is_finite() && speed > 0.0 => Ok(speed),
+        Ok(_) => Err("must be a positive number".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[cfg(unix)]
+fn parse_socket_mode(value: &str) -> Result<u32, String> {
+    let digits = value.strip_prefix("0o").unwrap_or(value);
+    match u32::from_str_radix(digits, 8) {
+        Ok(mode) if mode <= 0o777 => Ok(mode),
+        Ok(_) => Err("must be at most 777".to_string()),
+        Err(_) => Err(format!("'{value}' is not an octal mode")),
+    }
+}
+
+/// Flags that turn off whole collection subsystems or widen what they report.
+#[derive(Args, Clone, Debug, Default)]
+pub struct SubsystemArgs {
+    /// Do not collect GPU/NPU metrics or GPU processes.
+    #[arg(long)]
+    pub no_gpu: bool,
+    /// Do not collect CPU metrics.
+    #[arg(long)]
+    pub no_cpu: bool,
+    /// Do not collect disk usage.
+    #[arg(long)]
+    pub no_disk: bool,
+    /// Do not collect network interface throughput.
+    #[arg(long)]
+    pub no_net: bool,
+    /// Report the loopback interface alongside the physical ones.
+    #[arg(long)]
+    pub include_loopback: bool,
+    /// Mark GPUs allocated to Kubernetes pods, read from the kubelet device
+    /// checkpoint or NVIDIA_VISIBLE_DEVICES.
+    #[arg(long)]
+    pub k8s: bool,
+    /// Load a device reader from a shared library implementing the all-smi
+    /// plugin ABI. Repeat to load several.
+    #[arg(long, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    pub plugin: Vec<PathBuf>,
+    /// Only collect the GPUs/NPUs at these indices, e.g. `0-3,6`. Indices
+    /// count every device on the host and are kept in the output.
+    #[arg(long, value_name = "LIST")]
+    pub devices: Option<DeviceSelection>,
+    /// Only collect the GPU/NPU with this UUID (case-insensitive). Repeat
+    /// or separate with commas for several; adds to --devices.
+    #[arg(long, value_delimiter = ',', value_name = "UUID")]
+    pub device_uuid: Vec<String>,
+    /// Seconds a device reader may take before its previous readings are
+    /// shown as stale instead. A reader that times out three times in a row
+    /// is skipped for a while. Defaults to 5.
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub reader_timeout: Option<u64>,
 }
 
+/// Mount point globs that override the built-in disk filter.
+#[derive(Args, Clone, Debug, Default)]
+pub struct DiskFilterArgs {
+    /// Only report mounts matching these globs (comma-separated or repeated),
+    /// including ones hidden by default. `*` stays within a path component,
+    /// `**` crosses them.
+    #[arg(long, value_delimiter = ',', value_name = "GLOB")]
+    pub disk_include: Vec<String>,
+    /// Never report mounts matching these globs. Takes precedence over
+    /// --disk-include.
+    #[arg(long, value_delimiter = ',', value_name = "GLOB")]
+    pub disk_exclude: Vec<String>,
+}
+
+/// When a device is flagged as at risk of running out of memory.
+#[derive(Args, Clone, Debug)]
+pub struct OomArgs {
+    /// Flag a device with a yellow `OOM?` badge when free memory falls below
+    /// this percentage of its total.
+    #[arg(long, value_name = "PCT", default_value_t = 5.0)]
+    pub oom_warn_pct: f64,
+    /// Show the badge in red below this percentage.
+    #[arg(long, value_name = "PCT", default_value_t = 2.0)]
+    pub oom_crit_pct: f64,
+}
+
+impl Default for OomArgs {
+    fn default() -> Self {
+        let thresholds = OomThresholds::default();
+        Self {
+            oom_warn_pct: thresholds.warn_pct,
+            oom_crit_pct: thresholds.crit_pct,
+        }
+    }
+}
+
+impl OomArgs {
+    pub fn thresholds(&self) -> OomThresholds {
+        OomThresholds {
+            warn_pct: self.oom_warn_pct,
+            crit_pct: self.oom_crit_pct,
+        }
+    }
+}
+
+impl SubsystemArgs {
+    pub fn collection_filter(&self) -> CollectionFilter {
+        CollectionFilter {
+            gpu: !self.no_gpu,
+            cpu: !self.no_cpu,
+            disk: !self.no_disk,
+            net: !self.no_net,
+            loopback: self.include_loopback,
+            k8s: self.k8s,
+            devices: DeviceFilter::new(self.devices.clone(), &self.device_uuid),
+            reader_timeout: self
+                .reader_timeout
+                .map_or(DEFAULT_READER_TIMEOUT, Duration::from_secs),
+            ..CollectionFilter::default()
+        }
+    }
+}
+
+/// Processes kept per node in remote mode unless `--process-top` is given
+pub const DEFAULT_REMOTE_PROCESS_TOP: usize = 50;
+
 #[derive(Parser, Clone)]
 pub struct ViewArgs {
     /// A list of host addresses to connect to for remote monitoring.
-    #[arg(long, num_args = 1..)]
+    #[arg(long, num_args = 1.., value_hint = ValueHint::Url)]
     pub hosts: Option<Vec<String>>,
     /// A file containing a list of host addresses to connect to for remote monitoring.
-    #[arg(long)]
-    pub hostfile: Option<String>,
+    /// Repeat to merge several files. Edits are picked up while running.
+    #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    pub hostfile: Option<Vec<String>>,
     /// The interval in seconds at which to update the GPU information. If not specified, uses adaptive interval based on node count.
     #[arg(short, long)]
     pub interval: Option<u64>,
+    /// Enable mouse support (click tabs/rows, scroll wheel). Disables terminal text selection.
+    #[arg(long)]
+    pub mouse: bool,
+    /// Color theme. Defaults to `ui.theme` from the config file, or to light on
+    /// terminals whose `COLORFGBG` reports a light background.
+    #[arg(long, value_enum)]
+    pub theme: Option<ThemeName>,
+    /// Initial device order. Defaults to `ui.default_sort` from the config
+    /// file, or to hostname and index.
+    #[arg(long, value_enum, value_name = "ORDER")]
+    pub sort_by: Option<DeviceOrder>,
+    /// Color load averages by load per CPU core, so 1.0 means every core
+    /// is busy, instead of showing the raw load.
+    #[arg(long)]
+    pub normalize_load: bool,
+    /// Smooth the utilization gauges with an exponential moving average.
+    /// ALPHA (0-1, default 0.3) is the weight of the newest reading. The
+    /// `Util:` figure stays raw, as do the metrics served in API mode.
+    #[arg(
+        long,
+        value_name = "ALPHA",
+        num_args = 0..=1,
+        default_missing_value = "0.3",
+        value_parser = parse_alpha
+    )]
+    pub smooth: Option<f64>,
+    /// A file placing hosts in the heatmap view (`v`), one `<host> <row> <column>`
+    /// entry per line, so cells match physical rack positions.
+    #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    pub rack_layout: Option<String>,
+    /// Show only the N processes of each node using the most GPU memory.
+    /// Nodes list their processes when the API runs with --processes.
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_REMOTE_PROCESS_TOP)]
+    pub process_top: usize,
+    #[command(flatten)]
+    pub ssh: SshArgs,
+    #[command(flatten)]
+    pub oom: OomArgs,
+}
+
+/// Reaching nodes that do not run the API server.
+#[derive(Args, Clone, Debug)]
+pub struct SshArgs {
+    /// Treat hosts as SSH destinations, `[user@]host[:port]`, and run
+    /// `all-smi api --once` on each, or nvidia-smi where all-smi is missing.
+    /// Authentication uses the ssh agent, keys and config; connections are
+    /// kept open between refreshes.
+    #[arg(long)]
+    pub ssh: bool,
+    /// Private key for --ssh, tried along with those from the agent.
+    #[arg(long, value_name = "FILE", requires = "ssh", value_hint = ValueHint::FilePath)]
+    pub ssh_identity: Option<PathBuf>,
+    /// all-smi executable on the remote hosts.
+    #[arg(long, value_name = "PATH", default_value = "all-smi", requires = "ssh")]
+    pub ssh_command: String,
+}
+
+impl Default for SshArgs {
+    fn default() -> Self {
+        Self {
+            ssh: false,
+            ssh_identity: None,
+            ssh_command: "all-smi".to_string(),
+        }
+    }
+}
+
+/// Write the completion script for `shell` to `out`.
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+/// Render the top-level man page, which lists the subcommands.
+pub fn write_manpage(out: &mut dyn Write) -> io::Result<()> {
+    clap_mangen::Man::new(Cli::command()).render(out)
+}
+
+/// Write the top-level man page and one page per subcommand into `dir`.
+pub fn write_manpages(dir: &Path) -> io::Result<()> {
+    clap_mangen::generate_to(Cli::command(), dir)
 }
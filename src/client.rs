@@ -187,7 +187,7 @@ impl AllSmi {
                 Err(e) => {
                     // Log but don't fail - some metrics may still work
                     if config.verbose {
-                        eprintln!("Warning: macOS native metrics init failed: {e}");
+                        tracing::warn!("macOS native metrics init failed: {e}");
                     }
                     false
                 }
@@ -201,7 +201,7 @@ impl AllSmi {
                     Ok(()) => true,
                     Err(e) => {
                         if config.verbose {
-                            eprintln!("Warning: Intel Gaudi hl-smi init failed: {e}");
+                            tracing::warn!("Intel Gaudi hl-smi init failed: {e}");
                         }
                         false
                     }
@@ -260,6 +260,7 @@ impl AllSmi {
         for reader in &self.gpu_readers {
             all_gpus.extend(reader.get_gpu_info());
         }
+        crate::device::numa::annotate_gpu_topology(&mut all_gpus);
         all_gpus
     }
 
@@ -12,6 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::app_state::SortCriteria;
+use crate::device::{GpuInfo, Subsystem};
+use crate::ui::theme::{self, ThemeName};
+use crate::utils::{MountPatterns, TemperatureUnit, UnitSystem};
+
 /// Application configuration constants
 #[allow(dead_code)] // Many constants used across modules but clippy may not detect cross-module usage
 pub struct AppConfig;
@@ -49,6 +60,9 @@ impl AppConfig {
     pub const DASHBOARD_ITEM_WIDTH: usize = 15;
     pub const DEFAULT_TERMINAL_WIDTH: u16 = 80;
     pub const DEFAULT_TERMINAL_HEIGHT: u16 = 24;
+    // Below this size a placeholder replaces the full layout
+    pub const MIN_TERMINAL_WIDTH: u16 = 60;
+    pub const MIN_TERMINAL_HEIGHT: u16 = 15;
 
     // Memory and Performance
     #[allow(dead_code)] // Future Linux-specific calculations
@@ -88,6 +102,15 @@ impl EnvConfig {
         }
     }
 
+    /// [`Self::adaptive_interval`], raised to the p95 latency of recent
+    /// fetch rounds so slow nodes have time to answer before the next one.
+    pub fn latency_adaptive_interval(node_count: usize, p95_latency: Option<Duration>) -> u64 {
+        let interval = Self::adaptive_interval(node_count);
+        p95_latency.map_or(interval, |latency| {
+            interval.max(latency.as_secs_f64().ceil() as u64)
+        })
+    }
+
     #[allow(dead_code)] // Future connection management
     pub fn max_concurrent_connections(total_hosts: usize) -> usize {
         std::cmp::min(total_hosts, AppConfig::MAX_CONCURRENT_CONNECTIONS)
@@ -107,36 +130,694 @@ pub struct ThemeConfig;
 
 impl ThemeConfig {
     pub fn progress_bar_color(fill_ratio: f64) -> crossterm::style::Color {
-        use crossterm::style::Color;
-
+        let theme = theme::current();
         if fill_ratio > AppConfig::CRITICAL_THRESHOLD {
-            Color::Red
+            theme.critical
         } else if fill_ratio > AppConfig::WARNING_THRESHOLD {
-            Color::Yellow
+            theme.warning
         } else if fill_ratio > AppConfig::NORMAL_THRESHOLD {
-            Color::Green
+            theme.ok
         } else if fill_ratio > AppConfig::LOW_THRESHOLD {
-            Color::DarkGreen
+            theme.low
         } else {
-            Color::DarkGrey
+            theme.dim
         }
     }
 
     pub fn utilization_color(utilization: f64) -> crossterm::style::Color {
-        use crossterm::style::Color;
-
+        let theme = theme::current();
         if utilization > 80.0 {
-            Color::Red
+            theme.critical
         } else if utilization > 50.0 {
-            Color::Yellow
+            theme.warning
         } else if utilization > 20.0 {
-            Color::Green
+            theme.ok
         } else {
-            Color::DarkGrey
+            theme.dim
         }
     }
 }
 
+/// Where a resolved configuration value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Built-in default
+    Default,
+    /// Loaded from a config file
+    File(PathBuf),
+    /// Given explicitly on the command line
+    Cli,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File(path) => write!(f, "config file {}", path.display()),
+            ConfigSource::Cli => write!(f, "command line"),
+        }
+    }
+}
+
+/// A resolved setting together with its provenance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: ConfigSource,
+}
+
+impl<T> Setting<T> {
+    /// Resolve a value with precedence: command line > config file > default
+    pub fn resolve(cli: Option<T>, file: Option<T>, file_path: Option<&Path>, default: T) -> Self {
+        if let Some(value) = cli {
+            return Self {
+                value,
+                source: ConfigSource::Cli,
+            };
+        }
+        match (file, file_path) {
+            (Some(value), Some(path)) => Self {
+                value,
+                source: ConfigSource::File(path.to_path_buf()),
+            },
+            _ => Self {
+                value: default,
+                source: ConfigSource::Default,
+            },
+        }
+    }
+}
+
+/// `[view]` section of the config file
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct ViewFileConfig {
+    pub hosts: Option<Vec<String>>,
+    pub hostfile: Option<String>,
+    pub interval: Option<u64>,
+}
+
+/// Device identity labels that `api.labels` can select. Other labels (core
+/// ids, mount points, PIDs, ...) tell series of one device apart and are
+/// always exported.
+pub const METRIC_IDENTITY_LABELS: [&str; 7] = [
+    "gpu",
+    "npu",
+    "cpu_model",
+    "instance",
+    "hostname",
+    "uuid",
+    "index",
+];
+
+/// `[api]` section of the config file
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct ApiFileConfig {
+    pub port: Option<u16>,
+    pub interval: Option<u64>,
+    /// Seconds between collections by subsystem name (`[api.collect_intervals]`)
+    pub collect_intervals: Option<BTreeMap<String, u64>>,
+    pub processes: Option<bool>,
+    pub labels: Option<Vec<String>>,
+}
+
+/// `[ui]` section of the config file
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct UiFileConfig {
+    pub default_sort: Option<String>,
+    pub colors: Option<bool>,
+    pub refresh_rate_ms: Option<u64>,
+    pub theme: Option<String>,
+    pub units: Option<String>,
+    pub temp_unit: Option<String>,
+}
+
+/// `[disk]` section of the config file
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct DiskFileConfig {
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Contents of `config.toml`. Every value is optional so partial files work.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct FileConfig {
+    pub view: ViewFileConfig,
+    pub api: ApiFileConfig,
+    pub ui: UiFileConfig,
+    pub disk: DiskFileConfig,
+}
+
+/// Errors raised while loading a config file
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Cannot read config file {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid config file {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+}
+
+/// A parsed config file along with any non-fatal warnings
+#[derive(Debug, Default, Clone)]
+pub struct LoadedConfig {
+    /// Path of the file that was loaded, `None` when no file was found
+    pub path: Option<PathBuf>,
+    pub file: FileConfig,
+    pub warnings: Vec<String>,
+}
+
+impl LoadedConfig {
+    /// Parse config file contents, collecting unknown keys as warnings
+    pub fn parse(content: &str, path: &Path) -> Result<Self, ConfigError> {
+        let mut unknown_keys = Vec::new();
+        let deserializer = toml::Deserializer::parse(content).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        let file: FileConfig = serde_ignored::deserialize(deserializer, |key| {
+            unknown_keys.push(key.to_string());
+        })
+        .map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+
+        let warnings = unknown_keys
+            .into_iter()
+            .map(|key| format!("Unknown config key '{key}' in {}", path.display()))
+            .collect();
+
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            file,
+            warnings,
+        })
+    }
+
+    /// Load the config file from an explicit path, or from the default
+    /// location if none is given. A missing default file is not an error.
+    pub fn load(explicit_path: Option<&Path>) -> Result<Self, ConfigError> {
+        let path = match explicit_path {
+            Some(path) => path.to_path_buf(),
+            None => match default_config_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+
+        let content = std::fs::read_to_string(&path).map_err(|source| ConfigError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Self::parse(&content, &path)
+    }
+}
+
+/// Default config file location: `$XDG_CONFIG_HOME/all-smi/config.toml`,
+/// falling back to `~/.config/all-smi/config.toml`.
+pub fn default_config_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".config"))
+        })?;
+    Some(config_dir.join("all-smi").join("config.toml"))
+}
+
+/// Map a `ui.default_sort` value to a sort criteria
+pub fn parse_sort_criteria(name: &str) -> Option<SortCriteria> {
+    match name.to_ascii_lowercase().replace('-', "_").as_str() {
+        "default" | "index" => Some(SortCriteria::Default),
+        "uuid" => Some(SortCriteria::Uuid),
+        "utilization" | "util" => Some(SortCriteria::Utilization),
+        "temperature" | "temp" => Some(SortCriteria::Temperature),
+        "gpu_memory" => Some(SortCriteria::GpuMemory),
+        "pid" => Some(SortCriteria::Pid),
+        "memory" => Some(SortCriteria::MemoryPercent),
+        _ => None,
+    }
+}
+
+/// Values explicitly given on the command line. `None` means "not given",
+/// so config file values and defaults can fill in.
+#[derive(Debug, Default, Clone)]
+pub struct CliOverrides {
+    pub view_hosts: Option<Vec<String>>,
+    pub view_hostfile: Option<Vec<String>>,
+    pub view_interval: Option<u64>,
+    pub api_port: Option<u16>,
+    pub api_interval: Option<u64>,
+    pub api_collect_intervals: Option<Vec<(Subsystem, u64)>>,
+    pub api_processes: Option<bool>,
+    pub api_labels: Option<Vec<String>>,
+    pub disk_include: Option<Vec<String>>,
+    pub disk_exclude: Option<Vec<String>>,
+    pub ui_theme: Option<ThemeName>,
+    pub ui_units: Option<UnitSystem>,
+    pub ui_temp_unit: Option<TemperatureUnit>,
+    pub ui_default_sort: Option<SortCriteria>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ViewSettings {
+    pub hosts: Setting<Vec<String>>,
+    pub hostfile: Setting<Vec<String>>,
+    /// `None` selects the adaptive interval
+    pub interval: Setting<Option<u64>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiSettings {
+    pub port: Setting<u16>,
+    pub interval: Setting<u64>,
+    /// Subsystems collected on their own interval instead of `interval`
+    pub collect_intervals: Setting<Vec<(Subsystem, u64)>>,
+    pub processes: Setting<bool>,
+    /// Metric label allowlist; empty keeps every label
+    pub labels: Setting<Vec<String>>,
+}
+
+/// Mount point globs applied by local and API disk collection
+#[derive(Debug, Clone)]
+pub struct DiskSettings {
+    pub include: Setting<Vec<String>>,
+    pub exclude: Setting<Vec<String>>,
+}
+
+impl DiskSettings {
+    pub fn mount_patterns(&self) -> MountPatterns {
+        MountPatterns {
+            include: self.include.value.clone(),
+            exclude: self.exclude.value.clone(),
+        }
+    }
+}
+
+/// Terminal UI preferences
+#[derive(Debug, Clone)]
+pub struct UiSettings {
+    pub default_sort: SortCriteria,
+    pub colors: bool,
+    pub refresh_rate_ms: u64,
+    pub theme: ThemeName,
+    pub units: UnitSystem,
+    pub temp_unit: TemperatureUnit,
+}
+
+impl Default for UiSettings {
+    fn default() -> Self {
+        Self {
+            default_sort: SortCriteria::Default,
+            colors: true,
+            refresh_rate_ms: AppConfig::MIN_RENDER_INTERVAL_MS,
+            theme: ThemeName::default(),
+            units: UnitSystem::default(),
+            temp_unit: TemperatureUnit::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UiFileSettings {
+    pub default_sort: Setting<SortCriteria>,
+    pub colors: Setting<bool>,
+    pub refresh_rate_ms: Setting<u64>,
+    /// Defaults to the light theme on terminals that report a light background
+    pub theme: Setting<ThemeName>,
+    /// Byte sizes in powers of 1024 or 1000, in every view and in JSON
+    pub units: Setting<UnitSystem>,
+    /// Temperatures in Celsius or Fahrenheit, in the terminal UI only
+    pub temp_unit: Setting<TemperatureUnit>,
+}
+
+/// Fully resolved configuration (defaults < config file < command line)
+#[derive(Debug, Clone)]
+pub struct EffectiveConfig {
+    pub config_path: Option<PathBuf>,
+    pub view: ViewSettings,
+    pub api: ApiSettings,
+    pub ui: UiFileSettings,
+    pub disk: DiskSettings,
+    /// Invalid values that were ignored during resolution
+    pub warnings: Vec<String>,
+}
+
+impl EffectiveConfig {
+    pub fn resolve(loaded: &LoadedConfig, cli: &CliOverrides) -> Self {
+        let path = loaded.path.as_deref();
+        let file = &loaded.file;
+        let defaults = UiSettings::default();
+        let mut warnings = Vec::new();
+
+        let default_sort = file.ui.default_sort.as_deref().and_then(|name| {
+            let parsed = parse_sort_criteria(name);
+            if parsed.is_none() {
+                warnings.push(format!("Ignoring unknown ui.default_sort value '{name}'"));
+            }
+            parsed
+        });
+
+        let refresh_rate_ms = file.ui.refresh_rate_ms.filter(|&ms| {
+            if ms == 0 {
+                warnings.push("Ignoring ui.refresh_rate_ms = 0".to_string());
+            }
+            ms > 0
+        });
+
+        let theme = file.ui.theme.as_deref().and_then(|name| {
+            let parsed = ThemeName::parse(name);
+            if parsed.is_none() {
+                warnings.push(format!("Ignoring unknown ui.theme value '{name}'"));
+            }
+            parsed
+        });
+
+        let units = file.ui.units.as_deref().and_then(|name| {
+            let parsed = UnitSystem::parse(name);
+            if parsed.is_none() {
+                warnings.push(format!("Ignoring unknown ui.units value '{name}'"));
+            }
+            parsed
+        });
+
+        let temp_unit = file.ui.temp_unit.as_deref().and_then(|name| {
+            let parsed = TemperatureUnit::parse(name);
+            if parsed.is_none() {
+                warnings.push(format!("Ignoring unknown ui.temp_unit value '{name}'"));
+            }
+            parsed
+        });
+
+        let api_interval = file.api.interval.filter(|&secs| {
+            if secs == 0 {
+                warnings.push("Ignoring api.interval = 0".to_string());
+            }
+            secs > 0
+        });
+
+        let file_collect_intervals = file.api.collect_intervals.as_ref().map(|intervals| {
+            intervals
+                .iter()
+                .filter_map(|(name, &secs)| {
+                    let Some(subsystem) = Subsystem::from_name(name) else {
+                        warnings.push(format!(
+                            "Ignoring unknown api.collect_intervals subsystem '{name}'"
+                        ));
+                        return None;
+                    };
+                    if secs == 0 {
+                        warnings.push(format!("Ignoring api.collect_intervals.{name} = 0"));
+                        return None;
+                    }
+                    Some((subsystem, secs))
+                })
+                .collect::<BTreeMap<_, _>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+        });
+        // Command line entries replace the file's entry for the same subsystem
+        let cli_collect_intervals = cli.api_collect_intervals.as_ref().map(|cli_intervals| {
+            let mut merged: BTreeMap<Subsystem, u64> =
+                file_collect_intervals.iter().flatten().copied().collect();
+            merged.extend(cli_intervals.iter().copied());
+            merged.into_iter().collect::<Vec<_>>()
+        });
+
+        let api_labels = cli.api_labels.clone().map(|labels| {
+            let labels = filter_metric_labels(labels, &mut warnings);
+            warn_on_label_collisions(&labels, &mut warnings);
+            labels
+        });
+        let file_api_labels = file.api.labels.clone().map(|labels| {
+            let labels = filter_metric_labels(labels, &mut warnings);
+            if api_labels.is_none() {
+                warn_on_label_collisions(&labels, &mut warnings);
+            }
+            labels
+        });
+
+        let mut disk_patterns = |key: &str, patterns: Option<Vec<String>>| {
+            patterns.map(|patterns| drop_empty_patterns(key, patterns, &mut warnings))
+        };
+        let disk_include = disk_patterns("disk.include", file.disk.include.clone());
+        let disk_exclude = disk_patterns("disk.exclude", file.disk.exclude.clone());
+        let cli_disk_include = disk_patterns("--disk-include", cli.disk_include.clone());
+        let cli_disk_exclude = disk_patterns("--disk-exclude", cli.disk_exclude.clone());
+
+        Self {
+            config_path: loaded.path.clone(),
+            view: ViewSettings {
+                hosts: Setting::resolve(
+                    cli.view_hosts.clone(),
+                    file.view.hosts.clone(),
+                    path,
+                    Vec::new(),
+                ),
+                hostfile: Setting::resolve(
+                    cli.view_hostfile.clone(),
+                    file.view.hostfile.clone().map(|hostfile| vec![hostfile]),
+                    path,
+                    Vec::new(),
+                ),
+                interval: Setting::resolve(
+                    cli.view_interval.map(Some),
+                    file.view.interval.map(Some),
+                    path,
+                    None,
+                ),
+            },
+            api: ApiSettings {
+                port: Setting::resolve(cli.api_port, file.api.port, path, 9090),
+                interval: Setting::resolve(cli.api_interval, api_interval, path, 3),
+                collect_intervals: Setting::resolve(
+                    cli_collect_intervals,
+                    file_collect_intervals,
+                    path,
+                    Vec::new(),
+                ),
+                processes: Setting::resolve(cli.api_processes, file.api.processes, path, false),
+                labels: Setting::resolve(api_labels, file_api_labels, path, Vec::new()),
+            },
+            ui: UiFileSettings {
+                default_sort: Setting::resolve(
+                    cli.ui_default_sort,
+                    default_sort,
+                    path,
+                    defaults.default_sort,
+                ),
+                colors: Setting::resolve(None, file.ui.colors, path, defaults.colors),
+                refresh_rate_ms: Setting::resolve(
+                    None,
+                    refresh_rate_ms,
+                    path,
+                    defaults.refresh_rate_ms,
+                ),
+                theme: Setting::resolve(cli.ui_theme, theme, path, ThemeName::detect()),
+                units: Setting::resolve(cli.ui_units, units, path, defaults.units),
+                temp_unit: Setting::resolve(cli.ui_temp_unit, temp_unit, path, defaults.temp_unit),
+            },
+            disk: DiskSettings {
+                include: Setting::resolve(cli_disk_include, disk_include, path, Vec::new()),
+                exclude: Setting::resolve(cli_disk_exclude, disk_exclude, path, Vec::new()),
+            },
+            warnings,
+        }
+    }
+
+    pub fn ui_settings(&self) -> UiSettings {
+        UiSettings {
+            default_sort: self.ui.default_sort.value,
+            colors: self.ui.colors.value,
+            refresh_rate_ms: self.ui.refresh_rate_ms.value,
+            theme: self.ui.theme.value,
+            units: self.ui.units.value,
+            temp_unit: self.ui.temp_unit.value,
+        }
+    }
+
+    /// Render the effective configuration for `--show-config`
+    pub fn render(&self) -> String {
+        fn line<T>(out: &mut String, key: &str, value: String, setting: &Setting<T>) {
+            out.push_str(&format!("  {key:<16} = {value:<32} # {}\n", setting.source));
+        }
+
+        let mut out = String::new();
+        match &self.config_path {
+            Some(path) => out.push_str(&format!("# Config file: {}\n", path.display())),
+            None => out.push_str("# Config file: none\n"),
+        }
+
+        out.push_str("\n[view]\n");
+        let hosts = &self.view.hosts;
+        line(&mut out, "hosts", format!("{:?}", hosts.value), hosts);
+        let hostfile = &self.view.hostfile;
+        let hostfile_value = match hostfile.value.as_slice() {
+            [] => "(none)".to_string(),
+            [file] => format!("{file:?}"),
+            files => format!("{files:?}"),
+        };
+        line(&mut out, "hostfile", hostfile_value, hostfile);
+        let interval = &self.view.interval;
+        let interval_value = match interval.value {
+            Some(secs) => secs.to_string(),
+            None => "(adaptive)".to_string(),
+        };
+        line(&mut out, "interval", interval_value, interval);
+
+        out.push_str("\n[api]\n");
+        line(
+            &mut out,
+            "port",
+            self.api.port.value.to_string(),
+            &self.api.port,
+        );
+        let interval = &self.api.interval;
+        line(&mut out, "interval", interval.value.to_string(), interval);
+        let collect_intervals = &self.api.collect_intervals;
+        let collect_intervals_value = if collect_intervals.value.is_empty() {
+            "(interval)".to_string()
+        } else {
+            collect_intervals
+                .value
+                .iter()
+                .map(|(subsystem, secs)| format!("{}={secs}", subsystem.name()))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        line(
+            &mut out,
+            "collect_intervals",
+            collect_intervals_value,
+            collect_intervals,
+        );
+        let processes = &self.api.processes;
+        line(
+            &mut out,
+            "processes",
+            processes.value.to_string(),
+            processes,
+        );
+        let labels = &self.api.labels;
+        line(&mut out, "labels", format!("{:?}", labels.value), labels);
+
+        out.push_str("\n[ui]\n");
+        let sort = &self.ui.default_sort;
+        line(&mut out, "default_sort", format!("{:?}", sort.value), sort);
+        let colors = &self.ui.colors;
+        line(&mut out, "colors", colors.value.to_string(), colors);
+        let refresh = &self.ui.refresh_rate_ms;
+        line(
+            &mut out,
+            "refresh_rate_ms",
+            refresh.value.to_string(),
+            refresh,
+        );
+        let theme = &self.ui.theme;
+        line(&mut out, "theme", theme.value.to_string(), theme);
+        let units = &self.ui.units;
+        line(&mut out, "units", units.value.to_string(), units);
+        let temp_unit = &self.ui.temp_unit;
+        line(
+            &mut out,
+            "temp_unit",
+            temp_unit.value.to_string(),
+            temp_unit,
+        );
+
+        out.push_str("\n[disk]\n");
+        let include = &self.disk.include;
+        line(&mut out, "include", format!("{:?}", include.value), include);
+        let exclude = &self.disk.exclude;
+        line(&mut out, "exclude", format!("{:?}", exclude.value), exclude);
+
+        out
+    }
+}
+
+/// Drop blank mount patterns, which would otherwise never match anything.
+fn drop_empty_patterns(
+    key: &str,
+    patterns: Vec<String>,
+    warnings: &mut Vec<String>,
+) -> Vec<String> {
+    patterns
+        .into_iter()
+        .filter(|pattern| {
+            let blank = pattern.trim().is_empty();
+            if blank {
+                warnings.push(format!("Ignoring empty {key} pattern"));
+            }
+            !blank
+        })
+        .collect()
+}
+
+/// Drop `api.labels` entries that are not device identity labels.
+fn filter_metric_labels(labels: Vec<String>, warnings: &mut Vec<String>) -> Vec<String> {
+    labels
+        .into_iter()
+        .filter(|label| {
+            let known = METRIC_IDENTITY_LABELS.contains(&label.as_str());
+            if !known {
+                warnings.push(format!(
+                    "Ignoring unknown api.labels entry '{label}' (expected one of: {})",
+                    METRIC_IDENTITY_LABELS.join(", ")
+                ));
+            }
+            known
+        })
+        .collect()
+}
+
+/// Whether `labels` keep neither `uuid` nor `index`, so devices of the same
+/// model on one host export identical series
+fn labels_collide(labels: &[String]) -> bool {
+    !labels.is_empty() && !labels.iter().any(|l| l == "uuid" || l == "index")
+}
+
+/// The exporter keeps only the first of colliding series.
+fn warn_on_label_collisions(labels: &[String], warnings: &mut Vec<String>) {
+    if !labels_collide(labels) {
+        return;
+    }
+    warnings.push(
+        "api.labels keeps neither 'uuid' nor 'index'; series of identical devices on one host \
+         will collide and only the first is exported"
+            .to_string(),
+    );
+}
+
+/// Refuse `api.labels` that would make the series of two of `devices`, the
+/// devices of this host, collide
+pub fn check_label_collisions(labels: &[String], devices: &[GpuInfo]) -> Result<(), String> {
+    if !labels_collide(labels) {
+        return Ok(());
+    }
+    let mut models = HashSet::new();
+    match devices
+        .iter()
+        .find(|device| !models.insert(device.name.as_str()))
+    {
+        Some(device) => Err(format!(
+            "api.labels keeps neither 'uuid' nor 'index', so the series of the {} devices \
+             on this host would collide; add one of them",
+            device.name
+        )),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +847,21 @@ mod tests {
         assert_eq!(EnvConfig::adaptive_interval(1000), 6);
     }
 
+    #[test]
+    fn test_latency_adaptive_interval() {
+        // Fast fetches keep the node-count interval
+        assert_eq!(EnvConfig::latency_adaptive_interval(5, None), 3);
+        assert_eq!(
+            EnvConfig::latency_adaptive_interval(5, Some(Duration::from_millis(800))),
+            3
+        );
+        // Slow fetches stretch it to the next whole second
+        assert_eq!(
+            EnvConfig::latency_adaptive_interval(5, Some(Duration::from_millis(4200))),
+            5
+        );
+    }
+
     #[test]
     fn test_max_concurrent_connections() {
         assert_eq!(EnvConfig::max_concurrent_connections(10), 10);
@@ -306,4 +1002,293 @@ mod tests {
         assert_eq!(ThemeConfig::utilization_color(-10.0), Color::DarkGrey);
         assert_eq!(ThemeConfig::utilization_color(200.0), Color::Red);
     }
+
+    fn loaded(content: &str) -> LoadedConfig {
+        LoadedConfig::parse(content, Path::new("/etc/all-smi/config.toml")).unwrap()
+    }
+
+    #[test]
+    fn test_config_precedence_cli_over_file_over_default() {
+        let loaded = loaded(
+            r#"
+            [api]
+            port = 9100
+            interval = 5
+            "#,
+        );
+        let cli = CliOverrides {
+            api_port: Some(9200),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+
+        // CLI wins over file
+        assert_eq!(effective.api.port.value, 9200);
+        assert_eq!(effective.api.port.source, ConfigSource::Cli);
+
+        // File wins over default
+        assert_eq!(effective.api.interval.value, 5);
+        assert_eq!(
+            effective.api.interval.source,
+            ConfigSource::File(PathBuf::from("/etc/all-smi/config.toml"))
+        );
+
+        // Default when neither is set
+        assert!(!effective.api.processes.value);
+        assert_eq!(effective.api.processes.source, ConfigSource::Default);
+    }
+
+    #[test]
+    fn test_partial_config_sections() {
+        let loaded = loaded(
+            r#"
+            [view]
+            hosts = ["http://node1:9090", "http://node2:9090"]
+
+            [ui]
+            default_sort = "gpu_memory"
+            "#,
+        );
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+
+        assert_eq!(effective.view.hosts.value.len(), 2);
+        assert!(effective.view.hostfile.value.is_empty());
+        assert_eq!(effective.view.interval.value, None);
+        assert_eq!(effective.view.interval.source, ConfigSource::Default);
+        assert_eq!(effective.api.port.value, 9090);
+        assert_eq!(effective.ui.default_sort.value, SortCriteria::GpuMemory);
+        assert!(effective.ui.colors.value);
+        assert!(effective.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_overrides_default_sort() {
+        let loaded = loaded("[ui]\ndefault_sort = \"temp\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.ui.default_sort.value, SortCriteria::Temperature);
+
+        let cli = CliOverrides {
+            ui_default_sort: Some(SortCriteria::Uuid),
+            ..CliOverrides::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        assert_eq!(effective.ui.default_sort.value, SortCriteria::Uuid);
+        assert_eq!(effective.ui.default_sort.source, ConfigSource::Cli);
+    }
+
+    #[test]
+    fn test_empty_config_uses_defaults() {
+        let effective =
+            EffectiveConfig::resolve(&LoadedConfig::default(), &CliOverrides::default());
+        assert_eq!(effective.api.port.value, 9090);
+        assert_eq!(effective.api.interval.value, 3);
+        assert_eq!(
+            effective.ui.refresh_rate_ms.value,
+            AppConfig::MIN_RENDER_INTERVAL_MS
+        );
+        assert!(effective.render().contains("# Config file: none"));
+    }
+
+    #[test]
+    fn test_unknown_keys_are_warnings() {
+        let loaded = loaded(
+            r#"
+            theme = "dark"
+
+            [view]
+            intervall = 3
+
+            [api]
+            port = 9100
+            "#,
+        );
+        assert_eq!(loaded.file.api.port, Some(9100));
+        assert_eq!(loaded.warnings.len(), 2);
+        assert!(loaded.warnings.iter().any(|w| w.contains("'theme'")));
+        assert!(loaded
+            .warnings
+            .iter()
+            .any(|w| w.contains("'view.intervall'")));
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let result = LoadedConfig::parse("[api]\nport = \"abc\"\n", Path::new("config.toml"));
+        assert!(matches!(result, Err(ConfigError::Parse { .. })));
+
+        let loaded = loaded("[ui]\ndefault_sort = \"bogus\"\nrefresh_rate_ms = 0\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.ui.default_sort.source, ConfigSource::Default);
+        assert_eq!(effective.ui.refresh_rate_ms.source, ConfigSource::Default);
+        assert_eq!(effective.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_ui_theme() {
+        let loaded = loaded("[ui]\ntheme = \"high-contrast\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.ui.theme.value, ThemeName::HighContrast);
+        assert!(effective
+            .render()
+            .contains("theme            = high-contrast"));
+
+        let cli = CliOverrides {
+            ui_theme: Some(ThemeName::Light),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        assert_eq!(effective.ui_settings().theme, ThemeName::Light);
+        assert_eq!(effective.ui.theme.source, ConfigSource::Cli);
+
+        let loaded = self::loaded("[ui]\ntheme = \"solarized\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_ne!(effective.ui.theme.source, ConfigSource::Cli);
+        assert_eq!(effective.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_ui_units() {
+        let effective = EffectiveConfig::resolve(&loaded(""), &CliOverrides::default());
+        assert_eq!(effective.ui_settings().units, UnitSystem::Binary);
+
+        let loaded = loaded("[ui]\nunits = \"decimal\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.ui_settings().units, UnitSystem::Decimal);
+        assert!(effective.render().contains("units            = decimal"));
+
+        let cli = CliOverrides {
+            ui_units: Some(UnitSystem::Binary),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        assert_eq!(effective.ui.units.value, UnitSystem::Binary);
+        assert_eq!(effective.ui.units.source, ConfigSource::Cli);
+
+        let loaded = self::loaded("[ui]\nunits = \"si\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.ui.units.value, UnitSystem::Binary);
+        assert_eq!(effective.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_ui_temp_unit() {
+        let effective = EffectiveConfig::resolve(&loaded(""), &CliOverrides::default());
+        assert_eq!(effective.ui_settings().temp_unit, TemperatureUnit::Celsius);
+
+        let loaded = loaded("[ui]\ntemp_unit = \"fahrenheit\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(
+            effective.ui_settings().temp_unit,
+            TemperatureUnit::Fahrenheit
+        );
+        assert!(effective.render().contains("temp_unit        = f"));
+
+        let cli = CliOverrides {
+            ui_temp_unit: Some(TemperatureUnit::Celsius),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        assert_eq!(effective.ui.temp_unit.value, TemperatureUnit::Celsius);
+        assert_eq!(effective.ui.temp_unit.source, ConfigSource::Cli);
+
+        let loaded = self::loaded("[ui]\ntemp_unit = \"kelvin\"\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.ui.temp_unit.value, TemperatureUnit::Celsius);
+        assert_eq!(effective.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_api_labels_allowlist() {
+        let loaded = loaded("[api]\nlabels = [\"instance\", \"index\", \"pid\"]\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.api.labels.value, vec!["instance", "index"]);
+        assert_eq!(effective.warnings.len(), 1);
+        assert!(effective.warnings[0].contains("'pid'"));
+
+        // CLI replaces the file list; dropping uuid and index warns about collisions
+        let cli = CliOverrides {
+            api_labels: Some(vec!["gpu".to_string(), "instance".to_string()]),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        assert_eq!(effective.api.labels.value, vec!["gpu", "instance"]);
+        assert_eq!(effective.api.labels.source, ConfigSource::Cli);
+        assert!(effective.warnings.iter().any(|w| w.contains("collide")));
+
+        // Default keeps every label
+        let effective =
+            EffectiveConfig::resolve(&LoadedConfig::default(), &CliOverrides::default());
+        assert!(effective.api.labels.value.is_empty());
+        assert!(effective.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_label_collisions_with_identical_devices_fail() {
+        let h100 = |uuid| GpuInfo::fixture(uuid, "node1");
+        let labels = vec!["gpu".to_string(), "instance".to_string()];
+        let err = check_label_collisions(&labels, &[h100("GPU-0"), h100("GPU-1")]).unwrap_err();
+        assert!(err.contains("NVIDIA H100 devices"));
+
+        // One device per model, or labels that tell devices apart, are fine
+        let a100 = GpuInfo {
+            name: "NVIDIA A100".to_string(),
+            ..GpuInfo::fixture("GPU-1", "node1")
+        };
+        assert!(check_label_collisions(&labels, &[h100("GPU-0"), a100]).is_ok());
+        let labels = vec!["gpu".to_string(), "index".to_string()];
+        assert!(check_label_collisions(&labels, &[h100("GPU-0"), h100("GPU-1")]).is_ok());
+        assert!(check_label_collisions(&[], &[h100("GPU-0"), h100("GPU-1")]).is_ok());
+    }
+
+    #[test]
+    fn test_api_collect_intervals() {
+        let loaded = loaded("[api.collect_intervals]\ngpu = 1\nchassis = 60\ndisk = 0\nfans = 5\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(
+            effective.api.collect_intervals.value,
+            vec![(Subsystem::Gpu, 1), (Subsystem::Chassis, 60)]
+        );
+        assert_eq!(effective.warnings.len(), 2);
+        assert!(effective
+            .render()
+            .contains("collect_intervals = gpu=1,chassis=60"));
+
+        // CLI entries override the file per subsystem
+        let cli = CliOverrides {
+            api_collect_intervals: Some(vec![(Subsystem::Gpu, 2), (Subsystem::Disk, 30)]),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        assert_eq!(
+            effective.api.collect_intervals.value,
+            vec![
+                (Subsystem::Gpu, 2),
+                (Subsystem::Disk, 30),
+                (Subsystem::Chassis, 60)
+            ]
+        );
+        assert_eq!(effective.api.collect_intervals.source, ConfigSource::Cli);
+    }
+
+    #[test]
+    fn test_disk_patterns() {
+        let loaded =
+            loaded("[disk]\ninclude = [\"/\", \"/lustre/**\", \"\"]\nexclude = [\"/mnt/old\"]\n");
+        let effective = EffectiveConfig::resolve(&loaded, &CliOverrides::default());
+        assert_eq!(effective.disk.include.value, vec!["/", "/lustre/**"]);
+        assert_eq!(effective.disk.exclude.value, vec!["/mnt/old"]);
+        assert_eq!(effective.warnings.len(), 1);
+        assert!(effective.render().contains("[disk]"));
+
+        // CLI patterns replace the file's list for the same key only
+        let cli = CliOverrides {
+            disk_exclude: Some(vec!["/scratch*".to_string()]),
+            ..Default::default()
+        };
+        let effective = EffectiveConfig::resolve(&loaded, &cli);
+        let patterns = effective.disk.mount_patterns();
+        assert_eq!(patterns.include, vec!["/", "/lustre/**"]);
+        assert_eq!(patterns.exclude, vec!["/scratch*"]);
+        assert_eq!(effective.disk.exclude.source, ConfigSource::Cli);
+    }
 }
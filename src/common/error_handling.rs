@@ -52,19 +52,19 @@ pub struct ErrorHandler;
 impl ErrorHandler {
     /// Log error and return default value
     pub fn log_and_default<T: Default>(error: impl std::error::Error, context: &str) -> T {
-        eprintln!("Warning [{context}]: {error}");
+        tracing::warn!("{context}: {error}");
         T::default()
     }
 
     /// Log error and return provided default
     pub fn log_and_return<T>(error: impl std::error::Error, context: &str, default: T) -> T {
-        eprintln!("Warning [{context}]: {error}");
+        tracing::warn!("{context}: {error}");
         default
     }
 
     /// Log error and continue (for non-critical errors)
     pub fn log_and_continue(error: impl std::error::Error, context: &str) {
-        eprintln!("Warning [{context}]: {error}");
+        tracing::warn!("{context}: {error}");
     }
 
     /// Convert common errors to AppError
@@ -99,7 +99,7 @@ macro_rules! log_error_default {
         match $result {
             Ok(val) => val,
             Err(err) => {
-                eprintln!("Warning [{}]: {}", $context, err);
+                tracing::warn!("{}: {}", $context, err);
                 Default::default()
             }
         }
@@ -113,7 +113,7 @@ macro_rules! log_error_return {
         match $result {
             Ok(val) => val,
             Err(err) => {
-                eprintln!("Warning [{}]: {}", $context, err);
+                tracing::warn!("{}: {}", $context, err);
                 $default
             }
         }
@@ -15,3 +15,4 @@
 pub mod config;
 pub mod error_handling;
 pub mod progress_bar;
+pub mod ui_state;
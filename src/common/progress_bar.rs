@@ -18,6 +18,7 @@ use crossterm::style::Color;
 
 use crate::common::config::{AppConfig, ThemeConfig};
 use crate::ui::text::print_colored_text;
+use crate::ui::theme;
 
 /// Enhanced progress bar with consistent styling and configuration
 #[allow(dead_code)] // Future progress bar architecture
@@ -34,6 +35,7 @@ impl ProgressBar {
         width: usize,
         options: ProgressBarOptions,
     ) {
+        let theme = theme::current();
         let formatted_label = Self::format_label(label);
         let available_bar_width = width.saturating_sub(
             AppConfig::PROGRESS_BAR_LABEL_WIDTH + AppConfig::PROGRESS_BAR_BRACKET_WIDTH + 1,
@@ -48,8 +50,8 @@ impl ProgressBar {
         let display_text = Self::format_display_text(value, max_value, fill_ratio, &options);
 
         // Print label and opening bracket
-        print_colored_text(stdout, &formatted_label, Color::White, None, None);
-        print_colored_text(stdout, ": [", Color::White, None, None);
+        print_colored_text(stdout, &formatted_label, theme.text, None, None);
+        print_colored_text(stdout, ": [", theme.text, None, None);
 
         // Draw bar with text overlay
         Self::draw_bar_content(
@@ -61,7 +63,7 @@ impl ProgressBar {
         );
 
         // Print closing bracket
-        print_colored_text(stdout, "]", Color::White, None, None);
+        print_colored_text(stdout, "]", theme.text, None, None);
     }
 
     /// Draw a simple bar (backward compatibility)
@@ -122,6 +124,7 @@ impl ProgressBar {
         display_text: &str,
         color: Color,
     ) {
+        let theme = theme::current();
         let text_len = display_text.len();
         let text_pos = total_width.saturating_sub(text_len);
 
@@ -130,14 +133,14 @@ impl ProgressBar {
                 // Print text character with high contrast
                 let char_index = i - text_pos;
                 if let Some(ch) = display_text.chars().nth(char_index) {
-                    print_colored_text(stdout, &ch.to_string(), Color::White, None, None);
+                    print_colored_text(stdout, &ch.to_string(), theme.text, None, None);
                 }
             } else if i < filled_width {
                 // Print filled area
                 print_colored_text(stdout, "▬", color, None, None);
             } else {
                 // Print empty area
-                print_colored_text(stdout, "─", Color::DarkGrey, None, None);
+                print_colored_text(stdout, "─", theme.dim, None, None);
             }
         }
     }
@@ -218,13 +221,14 @@ impl SpecializedBars {
 
     /// Temperature bar with heat-based colors
     pub fn temperature<W: Write>(stdout: &mut W, temp_celsius: u32, max_temp: u32, width: usize) {
+        let theme = theme::current();
         let temp_ratio = temp_celsius as f64 / max_temp as f64;
         let color = if temp_ratio > 0.9 {
-            Color::Red
+            theme.critical
         } else if temp_ratio > 0.7 {
-            Color::Yellow
+            theme.warning
         } else {
-            Color::Green
+            theme.ok
         };
 
         let options = ProgressBarOptions::new()
@@ -0,0 +1,128 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! UI state kept between runs of the TUI.
+//!
+//! Unlike the config file this is written by all-smi itself, so it lives in
+//! the state directory and is JSON rather than hand-edited TOML.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ui::process_columns::ProcessLayout;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiStateFile {
+    /// UUIDs of devices pinned to the top of the GPU list
+    pub pinned_gpus: BTreeSet<String>,
+    /// Order and visibility of the local process table columns
+    pub process_columns: ProcessLayout,
+}
+
+impl UiStateFile {
+    /// Read the state file. A missing or unreadable file yields the default
+    /// state; it is rewritten on the next save.
+    pub fn load(path: &Path) -> Self {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring UI state file {}: {e}", path.display());
+            Self::default()
+        })
+    }
+
+    /// Write the state file, replacing it atomically.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, path)
+    }
+}
+
+/// Default state file location: `$XDG_STATE_HOME/all-smi/ui-state.json`,
+/// falling back to `~/.local/state/all-smi/ui-state.json`.
+pub fn default_ui_state_path() -> Option<PathBuf> {
+    let state_dir = std::env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".local").join("state"))
+        })?;
+    Some(state_dir.join("all-smi").join("ui-state.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pins_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("all-smi").join("ui-state.json");
+
+        let state = UiStateFile {
+            pinned_gpus: ["GPU-b".to_string(), "GPU-a".to_string()].into(),
+            ..Default::default()
+        };
+        state.save(&path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let json = serde_json::from_str::<serde_json::Value>(&content).unwrap();
+        assert_eq!(json["pinned_gpus"], serde_json::json!(["GPU-a", "GPU-b"]));
+        assert_eq!(UiStateFile::load(&path), state);
+    }
+
+    #[test]
+    fn test_process_columns_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ui-state.json");
+
+        let mut state = UiStateFile::default();
+        state.process_columns.toggle(2); // Show the container column
+        state.process_columns.move_column(0, 1);
+        state.save(&path).unwrap();
+        assert_eq!(UiStateFile::load(&path), state);
+
+        // Files written before the layout was kept get the default columns
+        std::fs::write(&path, r#"{"pinned_gpus": ["GPU-a"]}"#).unwrap();
+        assert_eq!(
+            UiStateFile::load(&path).process_columns,
+            ProcessLayout::default()
+        );
+    }
+
+    #[test]
+    fn test_missing_or_invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ui-state.json");
+        assert_eq!(UiStateFile::load(&path), UiStateFile::default());
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert_eq!(UiStateFile::load(&path), UiStateFile::default());
+
+        // Keys from newer versions are ignored, missing ones default
+        std::fs::write(&path, r#"{"future_key": 1}"#).unwrap();
+        assert_eq!(UiStateFile::load(&path), UiStateFile::default());
+    }
+}
@@ -88,10 +88,10 @@ pub fn execute_command(
             format!("{command} {}", args.join(" "))
         };
 
-        eprintln!("Command execution failed: '{full_command}' (exit code: {status_code})");
+        tracing::debug!("Command execution failed: '{full_command}' (exit code: {status_code})");
 
         if !out.stderr.is_empty() {
-            eprintln!("Stderr output: {}", out.stderr);
+            tracing::debug!("Stderr output: {}", out.stderr);
         }
 
         return Err(DeviceError::CommandFailed {
@@ -26,7 +26,6 @@ pub fn parse_csv_line(line: &str) -> Vec<String> {
 }
 
 /// Parse u64 from string with error context.
-#[allow(dead_code)]
 pub fn parse_u64(s: &str) -> DeviceResult<u64> {
     s.trim()
         .parse::<u64>()
@@ -42,7 +41,6 @@ pub fn parse_u32(s: &str) -> DeviceResult<u32> {
 }
 
 /// Parse f64 from string with error context.
-#[allow(dead_code)]
 pub fn parse_f64(s: &str) -> DeviceResult<f64> {
     s.trim()
         .parse::<f64>()
@@ -50,7 +48,6 @@ pub fn parse_f64(s: &str) -> DeviceResult<f64> {
 }
 
 /// Get a JSON field by key, returning a DeviceError if not found.
-#[allow(dead_code)]
 pub fn json_get<'a>(v: &'a Value, key: &str) -> DeviceResult<&'a Value> {
     v.get(key)
         .ok_or_else(|| DeviceError::ParseError(format!("Missing JSON key '{key}'")))
@@ -79,7 +76,6 @@ pub fn json_string(v: &Value, key: &str) -> DeviceResult<String> {
 }
 
 /// Extract u64 from JSON number or string.
-#[allow(dead_code)]
 pub fn json_u64(v: &Value, key: &str) -> DeviceResult<u64> {
     match json_get(v, key)? {
         Value::Number(n) => n
@@ -93,7 +89,6 @@ pub fn json_u64(v: &Value, key: &str) -> DeviceResult<u64> {
 }
 
 /// Extract f64 from JSON number or string.
-#[allow(dead_code)]
 pub fn json_f64(v: &Value, key: &str) -> DeviceResult<f64> {
     match json_get(v, key)? {
         Value::Number(n) => n
@@ -77,12 +77,22 @@ where
     match value_str.parse::<T>() {
         Ok(val) => val,
         Err(e) => {
-            eprintln!("Parse error in {context}: {e} (input: '{value_str}')");
+            tracing::debug!("Parse error in {context}: {e} (input: '{value_str}')");
             default
         }
     }
 }
 
+/// Parse the PID and process name from an IORegistry `IOUserClientCreator`
+/// value (e.g., "pid 1234, WindowServer")
+/// Returns None if parsing fails
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn parse_user_client_creator(creator: &str) -> Option<(u32, &str)> {
+    let rest = creator.trim().strip_prefix("pid ")?;
+    let (pid, name) = rest.split_once(',').unwrap_or((rest, ""));
+    Some((pid.trim().parse().ok()?, name.trim()))
+}
+
 /// Parse a device ID from a string like "npu0" or "gpu1"
 /// Returns None if parsing fails
 pub fn parse_device_id(device_str: &str) -> Option<usize> {
@@ -128,6 +138,21 @@ mod tests {
         assert_eq!(parse_memory_mb_to_bytes("invalid"), None);
     }
 
+    #[test]
+    fn test_parse_user_client_creator() {
+        assert_eq!(
+            parse_user_client_creator("pid 412, WindowServer"),
+            Some((412, "WindowServer"))
+        );
+        assert_eq!(
+            parse_user_client_creator("pid 98765, Google Chrome Helper (GPU)"),
+            Some((98765, "Google Chrome Helper (GPU)"))
+        );
+        assert_eq!(parse_user_client_creator("pid 7"), Some((7, "")));
+        assert_eq!(parse_user_client_creator("WindowServer"), None);
+        assert_eq!(parse_user_client_creator("pid abc, x"), None);
+    }
+
     #[test]
     fn test_parse_device_id() {
         assert_eq!(parse_device_id("npu0"), Some(0));
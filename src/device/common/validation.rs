@@ -29,13 +29,13 @@ pub fn validate_command(command: &str) -> bool {
         ';', '&', '|', '>', '<', '$', '`', '\n', '\r', '(', ')', '{', '}',
     ];
     if command.chars().any(|c| DANGEROUS_CHARS.contains(&c)) {
-        eprintln!("Potentially dangerous command rejected: {command}");
+        tracing::warn!("Potentially dangerous command rejected: {command}");
         return false;
     }
 
     // Reject path traversal attempts
     if command.contains("..") {
-        eprintln!("Command with path traversal rejected: {command}");
+        tracing::warn!("Command with path traversal rejected: {command}");
         return false;
     }
 
@@ -54,7 +54,7 @@ pub fn validate_args(args: &[&str]) -> bool {
         // Reject arguments with shell metacharacters that could cause injection
         const DANGEROUS_CHARS: &[char] = &[';', '&', '|', '`', '\n', '\r', '$'];
         if arg.chars().any(|c| DANGEROUS_CHARS.contains(&c)) {
-            eprintln!("Potentially dangerous argument rejected: {arg}");
+            tracing::warn!("Potentially dangerous argument rejected: {arg}");
             return false;
         }
     }
@@ -68,14 +68,14 @@ pub fn validate_args(args: &[&str]) -> bool {
 pub fn validate_command_path(path: &Path) -> bool {
     // Must be an absolute path
     if !path.is_absolute() {
-        eprintln!("Non-absolute command path rejected: {path:?}");
+        tracing::warn!("Non-absolute command path rejected: {path:?}");
         return false;
     }
 
     // Must not contain path traversal
     if let Some(path_str) = path.to_str() {
         if path_str.contains("..") {
-            eprintln!("Command path with traversal rejected: {path_str}");
+            tracing::warn!("Command path with traversal rejected: {path_str}");
             return false;
         }
     }
@@ -88,12 +88,12 @@ pub fn validate_command_path(path: &Path) -> bool {
             let permissions = metadata.permissions();
             // Check if any execute bit is set
             if permissions.mode() & 0o111 == 0 {
-                eprintln!("Non-executable command path: {path:?}");
+                tracing::warn!("Non-executable command path: {path:?}");
                 return false;
             }
         } else {
             // Path doesn't exist
-            eprintln!("Command path does not exist: {path:?}");
+            tracing::warn!("Command path does not exist: {path:?}");
             return false;
         }
     }
@@ -107,7 +107,7 @@ pub fn get_self_pid_mapping() -> Option<(u32, u32)> {
 
                 // Debug output if enabled
                 if std::env::var("ALL_SMI_DEBUG_PID").is_ok() {
-                    eprintln!("Debug: Self NSpid: {line}");
+                    tracing::debug!("Self NSpid: {line}");
                 }
 
                 // NSpid format varies:
@@ -205,7 +205,7 @@ pub fn map_host_to_container_pid(host_pid: u32) -> Option<u32> {
 
                                 // Debug: print the NSpid line for troubleshooting
                                 if std::env::var("ALL_SMI_DEBUG_PID").is_ok() {
-                                    eprintln!("Debug: PID {pid} has NSpid: {line}");
+                                    tracing::debug!("PID {pid} has NSpid: {line}");
                                 }
 
                                 // When inside container reading /proc/[pid]/status:
@@ -360,6 +360,74 @@ pub fn build_pid_mapping_cache() -> HashMap<u32, u32> {
     cache
 }
 
+/// Container ID of a process, shortened to 12 characters like `docker ps`.
+/// `None` for processes outside a container or when /proc is unreadable.
+pub fn process_container_id(pid: u32) -> Option<String> {
+    let cgroup = fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+    container_id_from_cgroup(&cgroup)
+}
+
+/// First 64-hex-digit container ID in the paths of a `/proc/<pid>/cgroup`
+/// file, as written by Docker (`/docker/<id>`, `docker-<id>.scope`),
+/// containerd (`cri-containerd-<id>.scope`) and CRI-O (`crio-<id>.scope`).
+pub fn container_id_from_cgroup(cgroup: &str) -> Option<String> {
+    cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .flat_map(|path| path.split('/'))
+        .find_map(|component| {
+            let id = component.strip_suffix(".scope").unwrap_or(component);
+            let id = id.rsplit('-').next()?;
+            (id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()))
+                .then(|| id[..12].to_string())
+        })
+}
+
+/// SLURM job and step a process runs in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlurmJob {
+    pub job_id: String,
+    /// `0`, `1`, ... or `batch`, `extern`, `interactive`; `None` for a
+    /// process in the job but outside its steps
+    pub step_id: Option<String>,
+}
+
+/// SLURM job of a process, from the cgroup slurmd placed it in. `None` on
+/// nodes without SLURM, for processes outside a job or when /proc is
+/// unreadable.
+pub fn process_slurm_job(pid: u32) -> Option<SlurmJob> {
+    let cgroup = fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+    slurm_job_from_cgroup(&cgroup)
+}
+
+/// Job and step in the paths of a `/proc/<pid>/cgroup` file, as created by
+/// slurmd's cgroup plugins: `/slurm/uid_<uid>/job_<id>/step_<step>/...` with
+/// cgroup v1 and `/system.slice/slurmstepd.scope/job_<id>/step_<step>/...`
+/// with v2.
+pub fn slurm_job_from_cgroup(cgroup: &str) -> Option<SlurmJob> {
+    cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .find_map(|path| {
+            let mut components = path
+                .split('/')
+                .skip_while(|component| !component.starts_with("slurm"));
+            let job_id = components.find_map(|component| {
+                component
+                    .strip_prefix("job_")
+                    .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+            })?;
+            let step_id = components
+                .next()
+                .and_then(|component| component.strip_prefix("step_"))
+                .filter(|step| !step.is_empty());
+            Some(SlurmJob {
+                job_id: job_id.to_string(),
+                step_id: step_id.map(str::to_string),
+            })
+        })
+}
+
 /// Format process name with container indicator if applicable
 #[allow(dead_code)]
 pub fn format_process_name_with_container_info(process_name: String, pid: u32) -> String {
@@ -400,4 +468,65 @@ mod tests {
             assert!(host_pid > 0);
         }
     }
+
+    #[test]
+    fn test_container_id_from_cgroup() {
+        let id = "4f2a9c0e7b1d3a5c6e8f0a2b4c6d8e0f1a3b5c7d9e1f2a4b6c8d0e2f4a6b8c0d";
+        let docker = format!("12:memory:/docker/{id}\n0::/docker/{id}\n");
+        assert_eq!(
+            container_id_from_cgroup(&docker).as_deref(),
+            Some("4f2a9c0e7b1d")
+        );
+        let containerd = format!(
+            "0::/kubepods.slice/kubepods-besteffort.slice/kubepods-besteffort-pod1234.slice/cri-containerd-{id}.scope\n"
+        );
+        assert_eq!(
+            container_id_from_cgroup(&containerd).as_deref(),
+            Some("4f2a9c0e7b1d")
+        );
+        assert_eq!(
+            container_id_from_cgroup("0::/user.slice/user-1000.slice/session-2.scope\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_slurm_job_from_cgroup() {
+        let v1 = "12:devices:/slurm/uid_1000/job_48213/step_0/task_0\n\
+                  4:memory:/slurm/uid_1000/job_48213/step_0/task_0\n";
+        assert_eq!(
+            slurm_job_from_cgroup(v1),
+            Some(SlurmJob {
+                job_id: "48213".to_string(),
+                step_id: Some("0".to_string()),
+            })
+        );
+        let v2 = "0::/system.slice/slurmstepd.scope/job_48214/step_batch/user/task_0\n";
+        assert_eq!(
+            slurm_job_from_cgroup(v2),
+            Some(SlurmJob {
+                job_id: "48214".to_string(),
+                step_id: Some("batch".to_string()),
+            })
+        );
+        // Held by the job itself, outside any step
+        let job_only = "0::/slurm_node07/uid_1000/job_48215\n";
+        assert_eq!(
+            slurm_job_from_cgroup(job_only),
+            Some(SlurmJob {
+                job_id: "48215".to_string(),
+                step_id: None,
+            })
+        );
+        // slurmstepd's own cgroup and cgroups of other job schedulers
+        assert_eq!(
+            slurm_job_from_cgroup("0::/system.slice/slurmstepd.scope/system\n"),
+            None
+        );
+        assert_eq!(slurm_job_from_cgroup("0::/batch/job_42/step_1\n"), None);
+        assert_eq!(
+            slurm_job_from_cgroup("0::/user.slice/user-1000.slice/session-2.scope\n"),
+            None
+        );
+    }
 }
@@ -21,6 +21,7 @@ use chrono::Local;
 use once_cell::sync::Lazy;
 
 use crate::device::container_info::{parse_cpu_stat_with_container_limits, ContainerInfo};
+use crate::device::numa::NumaTopology;
 use crate::device::{
     CoreType, CoreUtilization, CpuInfo, CpuPlatformType, CpuReader, CpuSocketInfo,
 };
@@ -237,6 +238,7 @@ impl LinuxCpuReader {
             apple_silicon_info: None, // Not applicable for Linux
             per_core_utilization,
             time,
+            stale: false,
         })
     }
 
@@ -756,16 +758,72 @@ impl CpuReader for LinuxCpuReader {
                     socket_info.frequency_mhz = cpu_info.base_frequency_mhz;
                 }
 
+                // On multi-node systems report one entry per NUMA node with its
+                // real load. Container core ids are renumbered, so skip there.
+                if !self.container_info.is_container {
+                    if let Some(per_node) = numa_socket_info(NumaTopology::get(), &cpu_info) {
+                        cpu_info.per_socket_info = per_node;
+                    }
+                }
+
                 vec![cpu_info]
             }
             Err(e) => {
-                eprintln!("Error reading CPU info: {e}");
+                tracing::error!("Error reading CPU info: {e}");
                 vec![]
             }
         }
     }
 }
 
+/// Build per-socket entries from the NUMA node layout, averaging per-core
+/// utilization over each node's CPUs. Returns `None` on single-node systems.
+pub(crate) fn numa_socket_info(
+    topology: &NumaTopology,
+    cpu_info: &CpuInfo,
+) -> Option<Vec<CpuSocketInfo>> {
+    if !topology.is_multi_node() {
+        return None;
+    }
+
+    let per_core: HashMap<u32, f64> = cpu_info
+        .per_core_utilization
+        .iter()
+        .map(|core| (core.core_id, core.utilization))
+        .collect();
+
+    let per_node = topology
+        .nodes()
+        .map(|(node, cpus)| {
+            let utils: Vec<f64> = cpus
+                .iter()
+                .filter_map(|cpu| per_core.get(cpu).copied())
+                .collect();
+            let utilization = if utils.is_empty() {
+                cpu_info.utilization
+            } else {
+                utils.iter().sum::<f64>() / utils.len() as f64
+            };
+            let threads = cpus.len() as u32;
+            let cores = if cpu_info.total_threads > 0 {
+                (threads as u64 * cpu_info.total_cores as u64 / cpu_info.total_threads as u64)
+                    as u32
+            } else {
+                threads
+            };
+            CpuSocketInfo {
+                socket_id: node,
+                utilization,
+                cores,
+                threads,
+                temperature: None,
+                frequency_mhz: cpu_info.base_frequency_mhz,
+            }
+        })
+        .collect();
+    Some(per_node)
+}
+
 #[cfg(test)]
 #[path = "cpu_linux/tests.rs"]
 mod tests;
@@ -159,3 +159,57 @@ fn test_get_cache_size_from_lscpu() {
         None => println!("No cache size found (lscpu not available or failed)"),
     }
 }
+
+#[test]
+fn test_numa_socket_info() {
+    use crate::device::cpu_linux::numa_socket_info;
+    use crate::device::numa::{parse_cpulist, NumaTopology};
+    use crate::device::{CoreUtilization, CpuInfo};
+    use std::collections::BTreeMap;
+
+    let per_core_utilization = (0..8)
+        .map(|core_id| CoreUtilization {
+            core_id,
+            core_type: CoreType::Standard,
+            utilization: if core_id % 4 < 2 { 80.0 } else { 20.0 },
+        })
+        .collect();
+    let cpu_info = CpuInfo {
+        host_id: "host".to_string(),
+        hostname: "host".to_string(),
+        instance: "host".to_string(),
+        cpu_model: "Test CPU".to_string(),
+        architecture: "x86_64".to_string(),
+        platform_type: CpuPlatformType::Intel,
+        socket_count: 2,
+        total_cores: 4,
+        total_threads: 8,
+        base_frequency_mhz: 2000,
+        max_frequency_mhz: 3000,
+        cache_size_mb: 0,
+        utilization: 50.0,
+        temperature: None,
+        power_consumption: None,
+        per_socket_info: Vec::new(),
+        apple_silicon_info: None,
+        per_core_utilization,
+        time: String::new(),
+        stale: false,
+    };
+
+    // Interleaved layout: node 0 holds the busy CPUs
+    let topology = NumaTopology::from_nodes(BTreeMap::from([
+        (0, parse_cpulist("0-1,4-5")),
+        (1, parse_cpulist("2-3,6-7")),
+    ]));
+    let per_node = numa_socket_info(&topology, &cpu_info).unwrap();
+    assert_eq!(per_node.len(), 2);
+    assert_eq!(per_node[0].socket_id, 0);
+    assert_eq!(per_node[0].utilization, 80.0);
+    assert_eq!(per_node[0].threads, 4);
+    assert_eq!(per_node[0].cores, 2);
+    assert_eq!(per_node[1].utilization, 20.0);
+
+    let single = NumaTopology::from_nodes(BTreeMap::from([(0, parse_cpulist("0-7"))]));
+    assert!(numa_socket_info(&single, &cpu_info).is_none());
+}
@@ -258,6 +258,7 @@ impl MacOsCpuReader {
             apple_silicon_info,
             per_core_utilization,
             time,
+            stale: false,
         })
     }
 
@@ -328,6 +329,7 @@ impl MacOsCpuReader {
             apple_silicon_info: None,
             per_core_utilization: Vec::new(), // Intel Macs don't have easy per-core data
             time,
+            stale: false,
         })
     }
 
@@ -823,7 +825,7 @@ impl CpuReader for MacOsCpuReader {
                 vec![cpu_info]
             }
             Err(e) => {
-                eprintln!("Error reading CPU info: {e}");
+                tracing::error!("Error reading CPU info: {e}");
                 vec![]
             }
         }
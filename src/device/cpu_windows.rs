@@ -321,6 +321,7 @@ impl WindowsCpuReader {
             apple_silicon_info: None,
             per_core_utilization,
             time,
+            stale: false,
         })
     }
 }
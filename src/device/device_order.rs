@@ -0,0 +1,149 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `--sort-by`: the order devices are shown and exported in.
+//!
+//! Every order ends with hostname, index and UUID, so two devices never
+//! compare equal and the order is the same on every refresh. Devices get
+//! their index recorded before sorting, which keeps the exported `index`
+//! label tied to the device rather than to its place in the order.
+
+use std::cmp::Ordering;
+
+use clap::ValueEnum;
+
+use crate::device::device_selection::DEVICE_INDEX_KEY;
+use crate::device::GpuInfo;
+
+/// Order of devices in the TUI and in API output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum DeviceOrder {
+    /// By UUID, which survives reboots and re-enumeration
+    Uuid,
+    /// By hostname, then index
+    #[default]
+    Index,
+    /// Busiest first
+    Util,
+    /// Hottest first
+    Temp,
+}
+
+impl DeviceOrder {
+    pub fn compare(self, a: &GpuInfo, b: &GpuInfo) -> Ordering {
+        match self {
+            DeviceOrder::Uuid => a.uuid.cmp(&b.uuid).then_with(|| by_index(a, b)),
+            DeviceOrder::Index => by_index(a, b),
+            DeviceOrder::Util => b
+                .utilization
+                .total_cmp(&a.utilization)
+                .then_with(|| by_index(a, b)),
+            DeviceOrder::Temp => b
+                .temperature
+                .cmp(&a.temperature)
+                .then_with(|| by_index(a, b)),
+        }
+    }
+}
+
+/// Hostname, then index, then UUID
+pub fn by_index(a: &GpuInfo, b: &GpuInfo) -> Ordering {
+    let index = |info: &GpuInfo| {
+        info.detail
+            .get(DEVICE_INDEX_KEY)
+            .and_then(|index| index.parse::<usize>().ok())
+            .unwrap_or(0)
+    };
+    a.hostname
+        .cmp(&b.hostname)
+        .then_with(|| index(a).cmp(&index(b)))
+        .then_with(|| a.uuid.cmp(&b.uuid))
+}
+
+/// Record each device's position as its index, unless a device selection
+/// or the remote host already did
+pub fn stamp_indices(devices: &mut [GpuInfo]) {
+    for (position, info) in devices.iter_mut().enumerate() {
+        info.detail
+            .entry(DEVICE_INDEX_KEY.to_string())
+            .or_insert_with(|| position.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sort_devices(devices: &mut [GpuInfo], order: DeviceOrder) {
+        stamp_indices(devices);
+        devices.sort_by(|a, b| order.compare(a, b));
+    }
+
+    fn order(devices: &[GpuInfo]) -> Vec<(&str, &str)> {
+        devices
+            .iter()
+            .map(|info| (info.uuid.as_str(), info.detail[DEVICE_INDEX_KEY].as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_sorting_keeps_indices() {
+        let collected = vec![
+            GpuInfo {
+                utilization: 10.0,
+                temperature: 50,
+                ..GpuInfo::fixture("GPU-c", "node-1")
+            },
+            GpuInfo {
+                utilization: 90.0,
+                temperature: 40,
+                ..GpuInfo::fixture("GPU-a", "node-1")
+            },
+            GpuInfo {
+                utilization: 10.0,
+                temperature: 70,
+                ..GpuInfo::fixture("GPU-b", "node-1")
+            },
+        ];
+
+        let mut devices = collected.clone();
+        sort_devices(&mut devices, DeviceOrder::Uuid);
+        assert_eq!(
+            order(&devices),
+            [("GPU-a", "1"), ("GPU-b", "2"), ("GPU-c", "0")]
+        );
+
+        let mut devices = collected.clone();
+        sort_devices(&mut devices, DeviceOrder::Temp);
+        assert_eq!(
+            order(&devices),
+            [("GPU-b", "2"), ("GPU-c", "0"), ("GPU-a", "1")]
+        );
+
+        // Equal utilization falls back to the index
+        let mut devices = collected.clone();
+        sort_devices(&mut devices, DeviceOrder::Util);
+        assert_eq!(
+            order(&devices),
+            [("GPU-a", "1"), ("GPU-c", "0"), ("GPU-b", "2")]
+        );
+
+        // Indices already recorded are kept when sorting again
+        sort_devices(&mut devices, DeviceOrder::Index);
+        assert_eq!(
+            order(&devices),
+            [("GPU-c", "0"), ("GPU-a", "1"), ("GPU-b", "2")]
+        );
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `--devices` and `--device-uuid`: collect only some of the accelerators.
+//!
+//! An index is a device's position among everything the GPU readers report,
+//! the same number the API exports as the `index` label. The selected
+//! devices keep their index, so `--devices 2,5` exports `index="2"` and
+//! `index="5"`. Indices can change across reboots; UUIDs do not.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use crate::device::{GpuInfo, GpuReader, ProcessInfo};
+
+/// Detail key holding a device's index among all devices of the host
+pub const DEVICE_INDEX_KEY: &str = "index";
+
+/// Index of the device at `position` in a collected list: the one recorded
+/// by a device selection, or else the position itself
+pub fn device_index(info: &GpuInfo, position: usize) -> usize {
+    info.detail
+        .get(DEVICE_INDEX_KEY)
+        .and_then(|index| index.parse().ok())
+        .unwrap_or(position)
+}
+
+/// Device indices given to `--devices`, e.g. `0-3,6`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceSelection {
+    indices: BTreeSet<usize>,
+}
+
+impl DeviceSelection {
+    pub fn contains(&self, index: usize) -> bool {
+        self.indices.contains(&index)
+    }
+
+    /// Whether every selected index exists among `available` devices
+    pub fn check(&self, available: usize) -> Result<(), String> {
+        let missing: BTreeSet<usize> = self.indices.range(available..).copied().collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        let devices = if missing.len() == 1 {
+            "no device with index"
+        } else {
+            "no devices with indices"
+        };
+        if available == 0 {
+            Err(format!(
+                "{devices} {}; no GPUs or NPUs were found",
+                format_ranges(&missing)
+            ))
+        } else {
+            Err(format!(
+                "{devices} {}; available indices: {}",
+                format_ranges(&missing),
+                format_ranges(&(0..available).collect())
+            ))
+        }
+    }
+}
+
+/// Devices to collect: those at the `--devices` indices and those with the
+/// `--device-uuid` UUIDs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeviceFilter {
+    pub indices: Option<DeviceSelection>,
+    /// As given; matched ignoring case
+    pub uuids: BTreeSet<String>,
+}
+
+impl DeviceFilter {
+    /// None when neither indices nor UUIDs were given
+    pub fn new(indices: Option<DeviceSelection>, uuids: &[String]) -> Option<Self> {
+        let uuids: BTreeSet<String> = uuids
+            .iter()
+            .map(|uuid| uuid.trim().to_string())
+            .filter(|uuid| !uuid.is_empty())
+            .collect();
+        (indices.is_some() || !uuids.is_empty()).then_some(Self { indices, uuids })
+    }
+
+    pub fn keeps(&self, index: usize, uuid: &str) -> bool {
+        self.indices
+            .as_ref()
+            .is_some_and(|indices| indices.contains(index))
+            || self
+                .uuids
+                .iter()
+                .any(|selected| selected.eq_ignore_ascii_case(uuid))
+    }
+
+    /// Whether every selected index and UUID exists among `devices`, all
+    /// devices of the host
+    pub fn check(&self, devices: &[GpuInfo]) -> Result<(), String> {
+        if let Some(indices) = &self.indices {
+            indices
+                .check(devices.len())
+                .map_err(|e| format!("--devices: {e}"))?;
+        }
+        let known: HashSet<String> = devices
+            .iter()
+            .map(|info| info.uuid.to_lowercase())
+            .collect();
+        let missing: Vec<&str> = self
+            .uuids
+            .iter()
+            .filter(|uuid| !known.contains(&uuid.to_lowercase()))
+            .map(String::as_str)
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        let uuids = if missing.len() == 1 {
+            "no device with UUID"
+        } else {
+            "no devices with UUIDs"
+        };
+        if devices.is_empty() {
+            return Err(format!(
+                "--device-uuid: {uuids} {}; no GPUs or NPUs were found",
+                missing.join(", ")
+            ));
+        }
+        let available: Vec<&str> = devices.iter().map(|info| info.uuid.as_str()).collect();
+        Err(format!(
+            "--device-uuid: {uuids} {}; available UUIDs: {}",
+            missing.join(", "),
+            available.join(", ")
+        ))
+    }
+}
+
+impl FromStr for DeviceSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_index = |part: &str| {
+            part.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid device index '{}'", part.trim()))
+        };
+        let mut indices = BTreeSet::new();
+        for part in s.split(',').filter(|part| !part.trim().is_empty()) {
+            match part.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_index(start)?, parse_index(end)?);
+                    if start > end {
+                        return Err(format!("invalid device range '{}'", part.trim()));
+                    }
+                    indices.extend(start..=end);
+                }
+                None => {
+                    indices.insert(parse_index(part)?);
+                }
+            }
+        }
+        if indices.is_empty() {
+            return Err("expected device indices such as 0,2 or 0-3,6".to_string());
+        }
+        Ok(Self { indices })
+    }
+}
+
+impl fmt::Display for DeviceSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_ranges(&self.indices))
+    }
+}
+
+/// `0-3,6` for {0, 1, 2, 3, 6}
+fn format_ranges(indices: &BTreeSet<usize>) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &index in indices {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == index => *end = index,
+            _ => ranges.push((index, index)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{start}-{end}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The selected devices of all `readers`, and the processes running on them
+pub struct SelectedGpuReader {
+    readers: Vec<Box<dyn GpuReader>>,
+    filter: DeviceFilter,
+    /// UUIDs of the devices kept by the last `get_gpu_info`
+    selected_uuids: Mutex<Option<HashSet<String>>>,
+}
+
+impl SelectedGpuReader {
+    pub fn new(readers: Vec<Box<dyn GpuReader>>, filter: DeviceFilter) -> Self {
+        Self {
+            readers,
+            filter,
+            selected_uuids: Mutex::new(None),
+        }
+    }
+}
+
+impl GpuReader for SelectedGpuReader {
+    fn get_gpu_info(&self) -> Vec<GpuInfo> {
+        let selected: Vec<GpuInfo> = self
+            .readers
+            .iter()
+            .flat_map(|reader| reader.get_gpu_info())
+            .enumerate()
+            .filter(|(index, info)| self.filter.keeps(*index, &info.uuid))
+            .map(|(index, mut info)| {
+                info.detail
+                    .insert(DEVICE_INDEX_KEY.to_string(), index.to_string());
+                info
+            })
+            .collect();
+        if let Ok(mut uuids) = self.selected_uuids.lock() {
+            *uuids = Some(selected.iter().map(|info| info.uuid.clone()).collect());
+        }
+        selected
+    }
+
+    fn get_process_info(&self) -> Vec<ProcessInfo> {
+        // Processes may be read before the first device list
+        let known = self
+            .selected_uuids
+            .lock()
+            .ok()
+            .and_then(|uuids| uuids.clone());
+        let uuids = known.unwrap_or_else(|| {
+            self.get_gpu_info()
+                .into_iter()
+                .map(|info| info.uuid)
+                .collect()
+        });
+        self.readers
+            .iter()
+            .flat_map(|reader| reader.get_process_info())
+            .filter(|process| uuids.contains(&process.device_uuid))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lists_and_ranges() {
+        let selection: DeviceSelection = "0-3,6".parse().unwrap();
+        assert!((0..4).all(|index| selection.contains(index)));
+        assert!(!selection.contains(4) && selection.contains(6));
+        assert_eq!(selection.to_string(), "0-3,6");
+        assert_eq!(
+            " 5, 2,2 ".parse::<DeviceSelection>().unwrap().to_string(),
+            "2,5"
+        );
+
+        assert!("".parse::<DeviceSelection>().is_err());
+        assert_eq!(
+            "3-1".parse::<DeviceSelection>().unwrap_err(),
+            "invalid device range '3-1'"
+        );
+        assert_eq!(
+            "0,gpu1".parse::<DeviceSelection>().unwrap_err(),
+            "invalid device index 'gpu1'"
+        );
+    }
+
+    #[test]
+    fn test_check_lists_available_indices() {
+        let selection: DeviceSelection = "2,6-8".parse().unwrap();
+        assert!(selection.check(9).is_ok());
+        assert_eq!(
+            selection.check(4).unwrap_err(),
+            "no devices with indices 6-8; available indices: 0-3"
+        );
+        assert_eq!(
+            "1".parse::<DeviceSelection>()
+                .unwrap()
+                .check(0)
+                .unwrap_err(),
+            "no device with index 1; no GPUs or NPUs were found"
+        );
+    }
+
+    struct FakeReader(Vec<&'static str>);
+
+    impl GpuReader for FakeReader {
+        fn get_gpu_info(&self) -> Vec<GpuInfo> {
+            self.0
+                .iter()
+                .map(|uuid| GpuInfo {
+                    uuid: uuid.to_string(),
+                    name: "GPU".to_string(),
+                    device_type: "GPU".to_string(),
+                    ..Default::default()
+                })
+                .collect()
+        }
+
+        fn get_process_info(&self) -> Vec<ProcessInfo> {
+            self.0
+                .iter()
+                .enumerate()
+                .map(|(i, uuid)| ProcessInfo {
+                    device_id: i,
+                    device_uuid: uuid.to_string(),
+                    pid: 100 + i as u32,
+                    process_name: "python".to_string(),
+                    used_memory: 0,
+                    used_memory_estimated: false,
+                    cpu_percent: 0.0,
+                    memory_percent: 0.0,
+                    memory_rss: 0,
+                    memory_vms: 0,
+                    user: String::new(),
+                    state: "R".to_string(),
+                    start_time: 0,
+                    cpu_time: 0,
+                    command: String::new(),
+                    ppid: 1,
+                    threads: 1,
+                    uses_gpu: true,
+                    priority: 0,
+                    nice_value: 0,
+                    gpu_utilization: 0.0,
+                    k8s_pod: None,
+                    host_id: String::new(),
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_indices_span_readers() {
+        let reader = SelectedGpuReader::new(
+            vec![
+                Box::new(FakeReader(vec!["GPU-0", "GPU-1"])),
+                Box::new(FakeReader(vec!["NPU-0", "NPU-1"])),
+            ],
+            DeviceFilter::new(Some("1-2".parse().unwrap()), &[]).unwrap(),
+        );
+
+        // Processes first, as the TUI may ask before any device list
+        let pids: Vec<u32> = reader.get_process_info().iter().map(|p| p.pid).collect();
+        assert_eq!(pids, [101, 100]);
+
+        let gpus = reader.get_gpu_info();
+        let kept: Vec<(&str, &str)> = gpus
+            .iter()
+            .map(|gpu| (gpu.uuid.as_str(), gpu.detail[DEVICE_INDEX_KEY].as_str()))
+            .collect();
+        assert_eq!(kept, [("GPU-1", "1"), ("NPU-0", "2")]);
+    }
+
+    #[test]
+    fn test_uuids_select_devices_ignoring_case() {
+        let filter = DeviceFilter::new(None, &["npu-1".to_string()]).unwrap();
+        let reader = SelectedGpuReader::new(
+            vec![
+                Box::new(FakeReader(vec!["GPU-0", "GPU-1"])),
+                Box::new(FakeReader(vec!["NPU-0", "NPU-1"])),
+            ],
+            filter.clone(),
+        );
+        let gpus = reader.get_gpu_info();
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].uuid, "NPU-1");
+        assert_eq!(gpus[0].detail[DEVICE_INDEX_KEY], "3");
+
+        // Indices and UUIDs add up
+        let both = DeviceFilter::new(Some("0".parse().unwrap()), &["NPU-1".to_string()]).unwrap();
+        assert!(both.keeps(0, "GPU-0") && both.keeps(3, "NPU-1") && !both.keeps(1, "GPU-1"));
+        assert_eq!(DeviceFilter::new(None, &[]), None);
+
+        let all = FakeReader(vec!["GPU-0", "GPU-1"]).get_gpu_info();
+        assert_eq!(
+            filter.check(&all).unwrap_err(),
+            "--device-uuid: no device with UUID npu-1; available UUIDs: GPU-0, GPU-1"
+        );
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Driver, CUDA and firmware versions across hosts.
+//!
+//! After a rolling upgrade a few hosts are often left on the old driver.
+//! Each host's versions are taken from its devices' detail maps, which hold
+//! the `all_smi_gpu_info` labels in remote mode and the reader's own keys
+//! locally. The version most hosts run is the majority; ties go to the
+//! newest version, since stragglers are usually behind.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::device::GpuInfo;
+
+/// A version field and the detail keys that may hold it, by preference
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VersionField {
+    Driver,
+    Cuda,
+    Firmware,
+}
+
+impl VersionField {
+    pub const ALL: [VersionField; 3] = [
+        VersionField::Driver,
+        VersionField::Cuda,
+        VersionField::Firmware,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            VersionField::Driver => "driver",
+            VersionField::Cuda => "cuda",
+            VersionField::Firmware => "firmware",
+        }
+    }
+
+    fn detail_keys(self) -> &'static [&'static str] {
+        match self {
+            VersionField::Driver => &["driver_version", "Driver Version"],
+            VersionField::Cuda => &["cuda_version", "CUDA Version"],
+            VersionField::Firmware => &["firmware", "firmware_version", "vbios_version"],
+        }
+    }
+
+    fn value(self, gpu: &GpuInfo) -> Option<&str> {
+        self.detail_keys()
+            .iter()
+            .find_map(|key| gpu.detail.get(*key))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// Versions reported by the devices of one host. A host normally has one
+/// version per field; mixed devices are joined with ", ".
+pub type HostVersions = BTreeMap<VersionField, String>;
+
+/// Version fields of every host that reported at least one
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VersionAudit {
+    pub hosts: BTreeMap<String, HostVersions>,
+}
+
+impl VersionAudit {
+    /// Versions of each host in `gpus`, keyed by host id
+    pub fn new(gpus: &[GpuInfo]) -> Self {
+        let mut seen: BTreeMap<&str, BTreeMap<VersionField, BTreeSet<&str>>> = BTreeMap::new();
+        for gpu in gpus {
+            for field in VersionField::ALL {
+                if let Some(version) = field.value(gpu) {
+                    seen.entry(gpu.host_id.as_str())
+                        .or_default()
+                        .entry(field)
+                        .or_default()
+                        .insert(version);
+                }
+            }
+        }
+        let hosts = seen
+            .into_iter()
+            .map(|(host, fields)| {
+                let versions = fields
+                    .into_iter()
+                    .map(|(field, values)| {
+                        let mut values: Vec<_> = values.into_iter().collect();
+                        values.sort_by(|a, b| compare_versions(a, b));
+                        (field, values.join(", "))
+                    })
+                    .collect();
+                (host.to_string(), versions)
+            })
+            .collect();
+        Self { hosts }
+    }
+
+    /// `(version, hosts)` for `field`, most common first and newest first
+    /// among equally common ones
+    pub fn tally(&self, field: VersionField) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for versions in self.hosts.values() {
+            if let Some(version) = versions.get(&field) {
+                *counts.entry(version.as_str()).or_default() += 1;
+            }
+        }
+        let mut tally: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(version, count)| (version.to_string(), count))
+            .collect();
+        tally.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| compare_versions(&b.0, &a.0)));
+        tally
+    }
+
+    /// The version most hosts run for `field`
+    pub fn majority(&self, field: VersionField) -> Option<String> {
+        self.tally(field)
+            .into_iter()
+            .next()
+            .map(|(version, _)| version)
+    }
+
+    /// Whether any field has more than one version across the hosts
+    pub fn is_mixed(&self) -> bool {
+        VersionField::ALL
+            .iter()
+            .any(|&field| self.tally(field).len() > 1)
+    }
+
+    /// Hosts whose driver or CUDA version differs from the majority.
+    /// Firmware is left out: it legitimately differs between board models.
+    pub fn deviating_hosts(&self) -> BTreeSet<&str> {
+        let majorities: Vec<_> = [VersionField::Driver, VersionField::Cuda]
+            .iter()
+            .filter_map(|&field| Some((field, self.majority(field)?)))
+            .collect();
+        self.hosts
+            .iter()
+            .filter(|(_, versions)| {
+                majorities.iter().any(|(field, majority)| {
+                    versions
+                        .get(field)
+                        .is_some_and(|version| version != majority)
+                })
+            })
+            .map(|(host, _)| host.as_str())
+            .collect()
+    }
+
+    /// `535.161 ×48, 550.54 ×2`, or None when no host reported `field`
+    pub fn summary(&self, field: VersionField) -> Option<String> {
+        let tally = self.tally(field);
+        (!tally.is_empty()).then(|| {
+            tally
+                .iter()
+                .map(|(version, count)| format!("{version} ×{count}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+    }
+}
+
+/// Orders versions by their numeric components, so 550.54 > 535.161 and
+/// 12.10 > 12.9. Non-numeric parts are compared as text.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |s: &'_ str| -> Vec<String> {
+        s.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    let (a_parts, b_parts) = (parts(a), parts(b));
+    for (x, y) in a_parts.iter().zip(&b_parts) {
+        let order = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.cmp(y),
+        };
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+    a_parts.len().cmp(&b_parts.len()).then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_ordering() {
+        assert_eq!(compare_versions("550.54", "535.161"), Ordering::Greater);
+        assert_eq!(compare_versions("12.10", "12.9"), Ordering::Greater);
+        assert_eq!(compare_versions("550.54.15", "550.54"), Ordering::Greater);
+        assert_eq!(compare_versions("6.2.4", "6.2.4"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_majority_and_deviating_hosts() {
+        let gpus = vec![
+            GpuInfo::fixture("GPU-node-1", "node-1")
+                .with_detail(&[("driver_version", "535.161"), ("cuda_version", "12.2")]),
+            GpuInfo::fixture("GPU-node-1", "node-1")
+                .with_detail(&[("driver_version", "535.161"), ("cuda_version", "12.2")]),
+            GpuInfo::fixture("GPU-node-2", "node-2")
+                .with_detail(&[("driver_version", "535.161"), ("cuda_version", "12.2")]),
+            GpuInfo::fixture("GPU-node-3", "node-3")
+                .with_detail(&[("driver_version", "550.54"), ("cuda_version", "12.2")]),
+            // A host with no version labels takes no part
+            GpuInfo::fixture("GPU-node-4", "node-4"),
+        ];
+        let audit = VersionAudit::new(&gpus);
+
+        // Hosts are counted, not devices
+        assert_eq!(
+            audit.tally(VersionField::Driver),
+            [("535.161".to_string(), 2), ("550.54".to_string(), 1)]
+        );
+        assert_eq!(
+            audit.summary(VersionField::Driver).unwrap(),
+            "535.161 ×2, 550.54 ×1"
+        );
+        assert_eq!(audit.summary(VersionField::Firmware), None);
+        assert!(audit.is_mixed());
+        assert_eq!(audit.deviating_hosts(), BTreeSet::from(["node-3"]));
+    }
+
+    #[test]
+    fn test_tie_goes_to_newest_version() {
+        let gpus = vec![
+            GpuInfo::fixture("GPU-node-1", "node-1").with_detail(&[("driver_version", "535.161")]),
+            GpuInfo::fixture("GPU-node-2", "node-2").with_detail(&[("driver_version", "550.54")]),
+            GpuInfo::fixture("GPU-node-3", "node-3").with_detail(&[("driver_version", "535.161")]),
+            GpuInfo::fixture("GPU-node-4", "node-4").with_detail(&[("driver_version", "550.54")]),
+        ];
+        let audit = VersionAudit::new(&gpus);
+        assert_eq!(audit.majority(VersionField::Driver).unwrap(), "550.54");
+        assert_eq!(
+            audit.deviating_hosts(),
+            BTreeSet::from(["node-1", "node-3"])
+        );
+    }
+
+    #[test]
+    fn test_uniform_hosts_and_local_keys() {
+        let gpus = vec![
+            GpuInfo::fixture("GPU-node-1", "node-1")
+                .with_detail(&[("Driver Version", "550.54"), ("vbios_version", "96.00.89")]),
+            GpuInfo::fixture("GPU-node-2", "node-2")
+                .with_detail(&[("driver_version", "550.54"), ("firmware", "96.00.89")]),
+        ];
+        let audit = VersionAudit::new(&gpus);
+        assert!(!audit.is_mixed());
+        assert!(audit.deviating_hosts().is_empty());
+        assert_eq!(audit.hosts["node-1"][&VersionField::Firmware], "96.00.89");
+    }
+
+    #[test]
+    fn test_mixed_devices_within_a_host() {
+        let gpus = vec![
+            GpuInfo::fixture("GPU-node-1", "node-1").with_detail(&[("vbios_version", "96.00.89")]),
+            GpuInfo::fixture("GPU-node-1", "node-1").with_detail(&[("vbios_version", "96.00.74")]),
+            GpuInfo::fixture("GPU-node-2", "node-2").with_detail(&[("vbios_version", "96.00.89")]),
+        ];
+        let audit = VersionAudit::new(&gpus);
+        assert_eq!(
+            audit.hosts["node-1"][&VersionField::Firmware],
+            "96.00.74, 96.00.89"
+        );
+        // Mixed firmware fails the audit but does not mark a host
+        assert!(audit.is_mixed());
+        assert!(audit.deviating_hosts().is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-fan speed of actively cooled cards.
+//!
+//! Readers put each fan's speed, in percent of its maximum, in a detail key
+//! ending with the fan index; AMD cards also report the speed their fan
+//! curve asks for. Passively cooled cards have no keys, so they export no
+//! fan series and show no `FAN` item.
+
+use std::collections::HashMap;
+
+/// Detail key prefix of a fan's current speed in percent, e.g. `fan_speed_percent_0`
+pub const FAN_SPEED_KEY_PREFIX: &str = "fan_speed_percent_";
+/// Detail key prefix of the speed in percent the fan curve asks for
+pub const FAN_TARGET_KEY_PREFIX: &str = "fan_target_percent_";
+
+/// Fans read per device; cards have one to three
+pub const MAX_FANS: u32 = 8;
+
+/// `value` in percent of `max`, rounded and capped at 100. Normalizes both
+/// RPM readings (against `fanN_max`) and PWM duty (against `pwmN_max`).
+/// `None` when the maximum is unknown.
+pub fn percent_of(value: u32, max: u32) -> Option<u32> {
+    if max == 0 {
+        return None;
+    }
+    let percent = (u64::from(value) * 100 + u64::from(max) / 2) / u64::from(max);
+    Some(percent.min(100) as u32)
+}
+
+/// Whether a detail key holds a fan reading
+pub fn is_fan_key(key: &str) -> bool {
+    key.starts_with(FAN_SPEED_KEY_PREFIX) || key.starts_with(FAN_TARGET_KEY_PREFIX)
+}
+
+fn per_fan(detail: &HashMap<String, String>, prefix: &str) -> Vec<(u32, u32)> {
+    let mut fans: Vec<(u32, u32)> = detail
+        .iter()
+        .filter_map(|(key, value)| {
+            let fan = key.strip_prefix(prefix)?;
+            Some((fan.parse().ok()?, value.parse().ok()?))
+        })
+        .collect();
+    fans.sort_by_key(|(fan, _)| *fan);
+    fans
+}
+
+/// `(fan index, speed %)` of each fan, in fan order
+pub fn fan_speeds(detail: &HashMap<String, String>) -> Vec<(u32, u32)> {
+    per_fan(detail, FAN_SPEED_KEY_PREFIX)
+}
+
+/// `(fan index, target %)` of each fan that reports one, in fan order
+pub fn fan_targets(detail: &HashMap<String, String>) -> Vec<(u32, u32)> {
+    per_fan(detail, FAN_TARGET_KEY_PREFIX)
+}
+
+/// Fan speeds for the device line, such as `43%` or `43/45%` on a
+/// multi-fan card. `None` without fans.
+pub fn fan_speed_summary(detail: &HashMap<String, String>) -> Option<String> {
+    let fans = fan_speeds(detail);
+    if fans.is_empty() {
+        return None;
+    }
+    let speeds: Vec<String> = fans.iter().map(|(_, speed)| speed.to_string()).collect();
+    Some(format!("{}%", speeds.join("/")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_of() {
+        // PWM duty out of 255
+        assert_eq!(percent_of(0, 255), Some(0));
+        assert_eq!(percent_of(128, 255), Some(50));
+        assert_eq!(percent_of(255, 255), Some(100));
+        // RPM against the fan's maximum
+        assert_eq!(percent_of(1150, 3300), Some(35));
+        // Spinning past the rated maximum still reads 100%
+        assert_eq!(percent_of(3500, 3300), Some(100));
+        assert_eq!(percent_of(1150, 0), None);
+    }
+
+    #[test]
+    fn test_fan_speeds_in_fan_order() {
+        let detail: HashMap<String, String> = [
+            ("fan_speed_percent_1", "45"),
+            ("fan_speed_percent_0", "43"),
+            ("fan_target_percent_0", "50"),
+            ("fan_speed_rpm", "1150"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        assert_eq!(fan_speeds(&detail), [(0, 43), (1, 45)]);
+        assert_eq!(fan_targets(&detail), [(0, 50)]);
+        assert_eq!(fan_speed_summary(&detail).as_deref(), Some("43/45%"));
+        assert!(is_fan_key("fan_target_percent_0"));
+        assert!(!is_fan_key("fan_speed_rpm"));
+
+        assert_eq!(fan_speed_summary(&HashMap::new()), None);
+    }
+}
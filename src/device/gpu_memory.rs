@@ -0,0 +1,43 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BAR1 aperture and reserved framebuffer memory.
+//!
+//! BAR1 is the window through which the CPU and peer devices map GPU memory;
+//! running out of it fails allocations while the framebuffer still has room.
+//! Reserved memory is set aside by the driver and firmware, including the
+//! ECC reservation, so `used + free + reserved == total`. Readers put the
+//! values in these detail keys, in bytes; devices that cannot report them
+//! have no key.
+
+use crate::device::GpuInfo;
+
+/// Detail key holding the BAR1 memory in use
+pub const BAR1_USED_KEY: &str = "bar1_memory_used";
+/// Detail key holding the size of the BAR1 aperture
+pub const BAR1_TOTAL_KEY: &str = "bar1_memory_total";
+/// Detail key holding the framebuffer memory reserved by the driver
+pub const RESERVED_MEMORY_KEY: &str = "memory_reserved";
+
+/// BAR1 memory in use and its total, if the device reports them
+pub fn bar1_memory(info: &GpuInfo) -> Option<(u64, u64)> {
+    let used = info.detail.get(BAR1_USED_KEY)?.parse().ok()?;
+    let total = info.detail.get(BAR1_TOTAL_KEY)?.parse().ok()?;
+    Some((used, total))
+}
+
+/// Reserved framebuffer memory, if the device reports it
+pub fn reserved_memory(info: &GpuInfo) -> Option<u64> {
+    info.detail.get(RESERVED_MEMORY_KEY)?.parse().ok()
+}
@@ -0,0 +1,145 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Devices that were read once and then disappeared.
+//!
+//! A GPU that falls off the bus (NVIDIA Xid 79 and the like) simply stops
+//! being enumerated by its driver. [`GpuPresence`] remembers every UUID it
+//! has seen during the run and puts the missing ones back into each reading,
+//! marked with [`GPU_LOST_KEY`], so they show up as failed instead of
+//! vanishing.
+
+use crate::device::GpuInfo;
+
+/// Detail key set to `"true"` on a device that stopped being reported.
+/// Exported as `all_smi_gpu_up 0`.
+pub const GPU_LOST_KEY: &str = "lost";
+
+/// Whether `info` is a device that stopped being reported
+pub fn is_lost(info: &GpuInfo) -> bool {
+    info.detail.get(GPU_LOST_KEY).is_some_and(|v| v == "true")
+}
+
+/// The last reading of every device seen so far, in the order they were
+/// first seen.
+#[derive(Debug, Default)]
+pub struct GpuPresence {
+    known: Vec<GpuInfo>,
+}
+
+impl GpuPresence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the devices in `gpus` and append each known device missing
+    /// from it, marked lost. Lost devices keep their identity and memory
+    /// total; their readings are zeroed so they do not count towards
+    /// utilization or power totals.
+    pub fn track(&mut self, gpus: &mut Vec<GpuInfo>) {
+        for gpu in gpus.iter().filter(|gpu| !gpu.uuid.is_empty()) {
+            match self.known.iter_mut().find(|known| known.uuid == gpu.uuid) {
+                Some(known) => known.clone_from(gpu),
+                None => self.known.push(gpu.clone()),
+            }
+        }
+
+        for known in &self.known {
+            if gpus.iter().any(|gpu| gpu.uuid == known.uuid) {
+                continue;
+            }
+            let mut lost = known.clone();
+            lost.utilization = 0.0;
+            lost.ane_utilization = 0.0;
+            lost.dla_utilization = None;
+            lost.tensorcore_utilization = None;
+            lost.encoder_utilization = None;
+            lost.decoder_utilization = None;
+            lost.temperature = 0;
+            lost.used_memory = 0;
+            lost.frequency = 0;
+            lost.power_consumption = 0.0;
+            lost.detail
+                .insert(GPU_LOST_KEY.to_string(), "true".to_string());
+            gpus.push(lost);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_device_is_kept_as_lost() {
+        let mut presence = GpuPresence::new();
+        let mut first = vec![
+            GpuInfo {
+                utilization: 10.0,
+                power_consumption: 350.0,
+                total_memory: 80 << 30,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            },
+            GpuInfo {
+                utilization: 90.0,
+                ..GpuInfo::fixture("GPU-1", "node1")
+            },
+        ];
+        presence.track(&mut first);
+        assert_eq!(first.len(), 2);
+        assert!(!first.iter().any(is_lost));
+
+        // GPU-0 falls off the bus
+        let mut second = vec![GpuInfo {
+            utilization: 80.0,
+            ..GpuInfo::fixture("GPU-1", "node1")
+        }];
+        presence.track(&mut second);
+        assert_eq!(second.len(), 2);
+        assert!(!is_lost(&second[0]));
+        let lost = &second[1];
+        assert_eq!(lost.uuid, "GPU-0");
+        assert!(is_lost(lost));
+        assert_eq!(lost.utilization, 0.0);
+        assert_eq!(lost.power_consumption, 0.0);
+        assert_eq!(lost.total_memory, 80 << 30);
+
+        // It comes back after a reset
+        let mut third = vec![
+            GpuInfo {
+                utilization: 70.0,
+                ..GpuInfo::fixture("GPU-1", "node1")
+            },
+            GpuInfo {
+                utilization: 5.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            },
+        ];
+        presence.track(&mut third);
+        assert_eq!(third.len(), 2);
+        assert!(!third.iter().any(is_lost));
+    }
+
+    #[test]
+    fn test_devices_without_uuid_are_not_tracked() {
+        let mut presence = GpuPresence::new();
+        presence.track(&mut vec![GpuInfo {
+            utilization: 10.0,
+            ..GpuInfo::fixture("", "node1")
+        }]);
+        let mut next = Vec::new();
+        presence.track(&mut next);
+        assert!(next.is_empty());
+    }
+}
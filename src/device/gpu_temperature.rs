@@ -0,0 +1,52 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory (HBM/GDDR) and hotspot (junction) temperatures.
+//!
+//! `GpuInfo::temperature` is the core or edge sensor. Readers that can see
+//! the other sensors put them in these detail keys; devices without the
+//! sensors have no key, so their series are not exported at all.
+
+use crate::device::GpuInfo;
+
+/// Detail key holding the memory temperature in °C
+pub const MEMORY_TEMPERATURE_KEY: &str = "temperature_memory";
+/// Detail key holding the hotspot (junction) temperature in °C
+pub const HOTSPOT_TEMPERATURE_KEY: &str = "temperature_hotspot";
+
+/// Memory temperature in °C, if the device reports one
+pub fn memory_temperature(info: &GpuInfo) -> Option<u32> {
+    info.detail.get(MEMORY_TEMPERATURE_KEY)?.parse().ok()
+}
+
+/// Hotspot temperature in °C, if the device reports one
+pub fn hotspot_temperature(info: &GpuInfo) -> Option<u32> {
+    info.detail.get(HOTSPOT_TEMPERATURE_KEY)?.parse().ok()
+}
+
+/// Sensor behind the temperature shown for a device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureSensor {
+    Core,
+    Hotspot,
+}
+
+/// The higher of the core and hotspot readings and the sensor it came from.
+/// Ties go to the core sensor.
+pub fn hottest(info: &GpuInfo) -> (u32, TemperatureSensor) {
+    match hotspot_temperature(info) {
+        Some(hotspot) if hotspot > info.temperature => (hotspot, TemperatureSensor::Hotspot),
+        _ => (info.temperature, TemperatureSensor::Core),
+    }
+}
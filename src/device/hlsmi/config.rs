@@ -93,7 +93,7 @@ impl HlsmiConfig {
     pub fn get_hlsmi_args(&self) -> Vec<String> {
         // Validate configuration before generating arguments
         if let Err(e) = self.validate() {
-            eprintln!("hl-smi config validation failed: {e}");
+            tracing::warn!("hl-smi config validation failed: {e}");
             // Return safe defaults
             return vec![
                 "-Q".to_string(),
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 /// Data structure for Intel Gaudi accelerator metrics
 /// Parses CSV output from hl-smi command
 #[derive(Debug, Default, Clone)]
@@ -170,6 +172,158 @@ pub fn parse_hlsmi_output(output: &str) -> Result<GaudiMetricsData, Box<dyn std:
     Ok(data)
 }
 
+/// A process from the "Compute Processes" table of plain `hl-smi` output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaudiProcess {
+    /// AIP index of the device the process runs on
+    pub aip: u32,
+    pub pid: u32,
+    pub name: String,
+    /// Device memory used in MiB
+    pub memory_used: u64,
+}
+
+/// Parse the "Compute Processes" table of plain `hl-smi` output.
+/// hl-smi 1.17 prints `AIP PID Type Process name Usage` with `45056MiB`;
+/// 1.21 adds a `Module ID` column after AIP and prints `45056 MiB`.
+/// Rows of devices without processes (`N/A`) are skipped.
+pub fn parse_hlsmi_processes(output: &str) -> Vec<GaudiProcess> {
+    let mut lines = output
+        .lines()
+        .skip_while(|line| !line.contains("Compute Processes"));
+    let mut has_module_column = false;
+    for line in lines.by_ref() {
+        if line.contains("PID") {
+            has_module_column = line.contains("Module ID");
+        }
+        if line.contains("====") {
+            break;
+        }
+    }
+
+    let mut processes = Vec::new();
+    for line in lines {
+        let Some(row) = line.trim().strip_prefix('|') else {
+            break;
+        };
+        let tokens: Vec<&str> = row.trim_end_matches('|').split_whitespace().collect();
+        let pid_column = if has_module_column { 2 } else { 1 };
+        if tokens.len() < pid_column + 4 {
+            continue;
+        }
+        let (Ok(aip), Ok(pid)) = (tokens[0].parse(), tokens[pid_column].parse()) else {
+            continue;
+        };
+        // Memory is the last token ("45056MiB") or the last two ("45056 MiB")
+        let (memory, name_end) = match tokens[tokens.len() - 1] {
+            "MiB" => (tokens[tokens.len() - 2], tokens.len() - 2),
+            memory => (memory, tokens.len() - 1),
+        };
+        let Ok(memory_used) = parse_memory_mib(memory) else {
+            continue;
+        };
+        // The Type column precedes the process name
+        let name_start = pid_column + 2;
+        if name_start >= name_end {
+            continue;
+        }
+        processes.push(GaudiProcess {
+            aip,
+            pid,
+            name: tokens[name_start..name_end].join(" "),
+            memory_used,
+        });
+    }
+    processes
+}
+
+/// Usage and temperature of one HBM stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HbmStack {
+    pub stack: u32,
+    /// Used memory in MiB
+    pub memory_used: Option<u64>,
+    /// Temperature in Celsius
+    pub temperature: Option<u32>,
+}
+
+/// Parse the HBM stacks of each AIP from `hl-smi -q` output, keyed by AIP
+/// index. hl-smi 1.17 lists them as `HBM 0 Used` under "HBM Memory Usage"
+/// and `HBM 0` under "Temperature"; 1.21 nests `Used` and `Temperature`
+/// under an `HBM0` heading in "Memory Usage". Values reported as `N/A`
+/// are left out.
+pub fn parse_hlsmi_hbm(output: &str) -> BTreeMap<u32, Vec<HbmStack>> {
+    let mut devices: BTreeMap<u32, BTreeMap<u32, HbmStack>> = BTreeMap::new();
+    let mut aip: Option<u32> = None;
+    // Stack of the `HBM0` heading being read and that heading's indent
+    let mut stack_block: Option<(u32, usize)> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        // "[0] AIP (accel0) 0000:33:00.0" starts the next device
+        if let Some(rest) = trimmed.strip_prefix('[') {
+            aip = rest.split(']').next().and_then(|index| index.parse().ok());
+            stack_block = None;
+            continue;
+        }
+        let Some(aip) = aip else {
+            continue;
+        };
+        if stack_block.is_some_and(|(_, heading_indent)| indent <= heading_indent) {
+            stack_block = None;
+        }
+
+        let Some((key, value)) = trimmed.split_once(':') else {
+            if let Some((stack, "")) = split_hbm_key(trimmed) {
+                stack_block = Some((stack, indent));
+            }
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        let (stack, field) = match stack_block {
+            Some((stack, _)) => (stack, key),
+            None => match split_hbm_key(key) {
+                Some(entry) => entry,
+                None => continue,
+            },
+        };
+
+        let entry = devices
+            .entry(aip)
+            .or_default()
+            .entry(stack)
+            .or_insert(HbmStack {
+                stack,
+                memory_used: None,
+                temperature: None,
+            });
+        if value.ends_with('C') && (field.is_empty() || field.eq_ignore_ascii_case("temperature")) {
+            entry.temperature = parse_temperature(value).ok();
+        } else if value.ends_with("MiB") && field.eq_ignore_ascii_case("used") {
+            entry.memory_used = parse_memory_mib(value).ok();
+        }
+    }
+
+    devices
+        .into_iter()
+        .map(|(aip, stacks)| (aip, stacks.into_values().collect()))
+        .collect()
+}
+
+/// Split `HBM0`, `HBM 0` or `HBM 0 Used` into the stack index and the
+/// rest of the key
+fn split_hbm_key(key: &str) -> Option<(u32, &str)> {
+    let rest = key.strip_prefix("HBM")?.trim_start();
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let stack = rest[..digits].parse().ok()?;
+    Some((stack, rest[digits..].trim()))
+}
+
 /// Parse device index
 fn parse_index(s: &str) -> Result<u32, Box<dyn std::error::Error>> {
     s.trim()
@@ -252,6 +406,83 @@ mod tests {
         assert_eq!(data.devices[1].temperature, 39);
     }
 
+    #[test]
+    fn test_parse_hlsmi_processes_1_17() {
+        let processes = parse_hlsmi_processes(include_str!("fixtures/hl-smi-1.17.txt"));
+        assert_eq!(
+            processes,
+            vec![GaudiProcess {
+                aip: 1,
+                pid: 41352,
+                name: "python3".to_string(),
+                memory_used: 45056,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_hlsmi_processes_1_21() {
+        let processes = parse_hlsmi_processes(include_str!("fixtures/hl-smi-1.21.txt"));
+        assert_eq!(processes.len(), 3);
+        assert_eq!(processes[0].aip, 0);
+        assert_eq!(processes[0].pid, 2873);
+        assert_eq!(processes[0].name, "python3 train.py");
+        assert_eq!(processes[0].memory_used, 65536);
+        assert_eq!(processes[1].pid, 2911);
+        assert_eq!(processes[1].memory_used, 32768);
+        assert_eq!(processes[2].aip, 1);
+        assert_eq!(processes[2].pid, 40177);
+        assert_eq!(processes[2].name, "pt_main_thread");
+        assert_eq!(processes[2].memory_used, 32096);
+
+        // Output without a process table has no processes
+        assert!(parse_hlsmi_processes("0, uuid, HL-325L").is_empty());
+    }
+
+    #[test]
+    fn test_parse_hlsmi_hbm_1_17() {
+        let devices = parse_hlsmi_hbm(include_str!("fixtures/hl-smi-q-1.17.txt"));
+        assert_eq!(devices.len(), 2);
+        assert_eq!(
+            devices[&0],
+            vec![
+                HbmStack {
+                    stack: 0,
+                    memory_used: Some(192),
+                    temperature: Some(33),
+                },
+                HbmStack {
+                    stack: 1,
+                    memory_used: Some(192),
+                    temperature: Some(34),
+                },
+            ]
+        );
+        assert_eq!(devices[&1][0].memory_used, Some(22912));
+        assert_eq!(devices[&1][0].temperature, Some(41));
+        assert_eq!(devices[&1][1].temperature, None);
+    }
+
+    #[test]
+    fn test_parse_hlsmi_hbm_1_21() {
+        let devices = parse_hlsmi_hbm(include_str!("fixtures/hl-smi-q-1.21.txt"));
+        assert_eq!(
+            devices[&0],
+            vec![
+                HbmStack {
+                    stack: 0,
+                    memory_used: Some(12372),
+                    temperature: Some(44),
+                },
+                HbmStack {
+                    stack: 1,
+                    memory_used: Some(12372),
+                    temperature: Some(45),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_map_device_name() {
         // Gaudi 1
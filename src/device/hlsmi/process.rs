@@ -215,14 +215,12 @@ impl ProcessManager {
                         match child.try_wait() {
                             Ok(Some(_)) => {
                                 // Process has exited, need to restart
-                                #[cfg(debug_assertions)]
-                                eprintln!("hl-smi process died, restarting...");
+                                tracing::warn!("hl-smi process died, restarting...");
                                 true
                             }
                             Ok(None) => false, // Still running
-                            Err(_e) => {
-                                #[cfg(debug_assertions)]
-                                eprintln!("Error checking hl-smi status: {_e}");
+                            Err(e) => {
+                                tracing::warn!("Error checking hl-smi status: {e}");
                                 true
                             }
                         }
@@ -242,10 +240,8 @@ impl ProcessManager {
                     let (_new_tx, new_rx) = mpsc::channel();
 
                     // Restart hl-smi
-                    if let Err(_e) = Self::restart_hlsmi(&process_arc, &store_arc, new_rx, &config)
-                    {
-                        #[cfg(debug_assertions)]
-                        eprintln!("Failed to restart hl-smi: {_e}");
+                    if let Err(e) = Self::restart_hlsmi(&process_arc, &store_arc, new_rx, &config) {
+                        tracing::error!("Failed to restart hl-smi: {e}");
                     }
                 }
             }
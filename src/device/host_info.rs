@@ -0,0 +1,127 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Load average, uptime and kernel/OS identity of the local host.
+//!
+//! Linux reads `/proc/loadavg`, `/proc/sys/kernel/osrelease` and
+//! `/etc/os-release`; other platforms, and Linux when those are missing,
+//! go through sysinfo. The kernel and OS names are read once per run.
+
+use std::sync::OnceLock;
+
+use sysinfo::System;
+
+use crate::device::HostSystemInfo;
+
+/// `window` label values of `all_smi_load_average`, in load average order
+pub const LOAD_WINDOWS: [&str; 3] = ["1m", "5m", "15m"];
+
+/// Kernel release and OS pretty name, which do not change while running
+static IDENTITY: OnceLock<(String, String)> = OnceLock::new();
+
+/// Read the host's current load, uptime and identity.
+pub fn read_host_system_info() -> HostSystemInfo {
+    let (kernel_version, os_name) = IDENTITY.get_or_init(read_identity).clone();
+    HostSystemInfo {
+        load_average: read_load_average(),
+        uptime_seconds: System::uptime(),
+        kernel_version,
+        os_name,
+    }
+}
+
+fn read_load_average() -> [f64; 3] {
+    #[cfg(target_os = "linux")]
+    if let Some(load) = std::fs::read_to_string("/proc/loadavg")
+        .ok()
+        .and_then(|content| parse_loadavg(&content))
+    {
+        return load;
+    }
+    let load = System::load_average();
+    [load.one, load.five, load.fifteen]
+}
+
+fn read_identity() -> (String, String) {
+    #[cfg(target_os = "linux")]
+    let kernel = std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .ok()
+        .map(|release| release.trim().to_string())
+        .filter(|release| !release.is_empty());
+    #[cfg(not(target_os = "linux"))]
+    let kernel: Option<String> = None;
+
+    #[cfg(target_os = "linux")]
+    let os = std::fs::read_to_string("/etc/os-release")
+        .ok()
+        .and_then(|content| parse_os_release_pretty_name(&content));
+    #[cfg(not(target_os = "linux"))]
+    let os: Option<String> = None;
+
+    (
+        kernel
+            .or_else(System::kernel_version)
+            .unwrap_or_else(|| "unknown".to_string()),
+        os.or_else(System::long_os_version)
+            .unwrap_or_else(|| "unknown".to_string()),
+    )
+}
+
+/// The three load averages at the start of `/proc/loadavg`.
+pub fn parse_loadavg(content: &str) -> Option<[f64; 3]> {
+    let mut fields = content.split_whitespace().map(|f| f.parse::<f64>().ok());
+    Some([fields.next()??, fields.next()??, fields.next()??])
+}
+
+/// `PRETTY_NAME` from an os-release file, unquoted.
+pub fn parse_os_release_pretty_name(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("PRETTY_NAME=")?;
+        let value = value.trim_matches(|c| c == '"' || c == '\'');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loadavg() {
+        assert_eq!(
+            parse_loadavg("0.52 1.04 2.50 3/812 41234\n"),
+            Some([0.52, 1.04, 2.5])
+        );
+        assert_eq!(parse_loadavg("0.52 1.04"), None);
+        assert_eq!(parse_loadavg(""), None);
+    }
+
+    #[test]
+    fn test_parse_os_release_pretty_name() {
+        let content = "NAME=\"Ubuntu\"\nVERSION_ID=\"24.04\"\nPRETTY_NAME=\"Ubuntu 24.04.1 LTS\"\n";
+        assert_eq!(
+            parse_os_release_pretty_name(content).as_deref(),
+            Some("Ubuntu 24.04.1 LTS")
+        );
+        assert_eq!(parse_os_release_pretty_name("NAME=Arch\n"), None);
+    }
+
+    #[test]
+    fn test_read_host_system_info() {
+        let info = read_host_system_info();
+        assert!(!info.kernel_version.is_empty());
+        assert!(!info.os_name.is_empty());
+        assert!(info.load_average.iter().all(|load| *load >= 0.0));
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Which GPUs Kubernetes has allocated to pods (`--k8s`).
+//!
+//! On a node the kubelet device manager checkpoint lists the devices handed
+//! to each container. Inside a container without access to it,
+//! `NVIDIA_VISIBLE_DEVICES` names the GPUs allocated to that container.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::device::GpuInfo;
+
+/// Device manager checkpoint written by the kubelet
+const KUBELET_CHECKPOINT: &str = "/var/lib/kubelet/device-plugins/kubelet_internal_checkpoint";
+
+/// Detail key set to "true" or "false" on every GPU when `--k8s` is given
+pub const K8S_ALLOCATED_KEY: &str = "k8s_allocated";
+
+/// Detail keys naming the pod a GPU is allocated to, from the kubelet
+/// PodResources API (see `pod_resources`)
+pub const K8S_POD_KEY: &str = "k8s_pod";
+pub const K8S_NAMESPACE_KEY: &str = "k8s_namespace";
+
+/// GPUs allocated to pods, by UUID or by index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GpuAllocation {
+    All,
+    Devices {
+        uuids: HashSet<String>,
+        indices: HashSet<usize>,
+    },
+}
+
+impl GpuAllocation {
+    fn is_allocated(&self, gpu: &GpuInfo, index: usize) -> bool {
+        match self {
+            GpuAllocation::All => true,
+            GpuAllocation::Devices { uuids, indices } => {
+                uuids.contains(&gpu.uuid) || indices.contains(&index)
+            }
+        }
+    }
+}
+
+/// Device IDs in all containers' entries of a kubelet checkpoint. Shared
+/// (time-sliced) replicas are reported as `<uuid>::<replica>` and are
+/// mapped back to the GPU UUID.
+pub fn parse_kubelet_checkpoint(content: &str) -> Option<GpuAllocation> {
+    let checkpoint: Value = serde_json::from_str(content).ok()?;
+    let entries = checkpoint.pointer("/Data/PodDeviceEntries")?.as_array()?;
+
+    let mut uuids = HashSet::new();
+    for entry in entries {
+        // A list before Kubernetes 1.20, a map of NUMA node to list after
+        let ids: Vec<&Value> = match entry.get("DeviceIDs") {
+            Some(Value::Array(ids)) => ids.iter().collect(),
+            Some(Value::Object(by_node)) => by_node
+                .values()
+                .filter_map(Value::as_array)
+                .flatten()
+                .collect(),
+            _ => continue,
+        };
+        for id in ids.into_iter().filter_map(Value::as_str) {
+            let uuid = id.split_once("::").map_or(id, |(uuid, _)| uuid);
+            uuids.insert(uuid.to_string());
+        }
+    }
+
+    Some(GpuAllocation::Devices {
+        uuids,
+        indices: HashSet::new(),
+    })
+}
+
+/// GPUs named by `NVIDIA_VISIBLE_DEVICES`: `all`, `none`/`void`, or a
+/// comma-separated list of UUIDs and indices.
+pub fn parse_visible_devices(value: &str) -> GpuAllocation {
+    let value = value.trim();
+    if value == "all" {
+        return GpuAllocation::All;
+    }
+
+    let mut uuids = HashSet::new();
+    let mut indices = HashSet::new();
+    if value != "none" && value != "void" {
+        for device in value.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match device.parse::<usize>() {
+                Ok(index) => {
+                    indices.insert(index);
+                }
+                Err(_) => {
+                    uuids.insert(device.to_string());
+                }
+            }
+        }
+    }
+    GpuAllocation::Devices { uuids, indices }
+}
+
+/// The allocation from the kubelet checkpoint, or from
+/// `NVIDIA_VISIBLE_DEVICES` when the checkpoint cannot be read.
+pub fn read_gpu_allocation() -> Option<GpuAllocation> {
+    read_gpu_allocation_from(
+        Path::new(KUBELET_CHECKPOINT),
+        std::env::var("NVIDIA_VISIBLE_DEVICES").ok().as_deref(),
+    )
+}
+
+fn read_gpu_allocation_from(
+    checkpoint: &Path,
+    visible_devices: Option<&str>,
+) -> Option<GpuAllocation> {
+    std::fs::read_to_string(checkpoint)
+        .ok()
+        .and_then(|content| parse_kubelet_checkpoint(&content))
+        .or_else(|| visible_devices.map(parse_visible_devices))
+}
+
+/// Set `k8s_allocated` on each GPU. GPUs are left untouched when no
+/// allocation source is available.
+pub fn annotate_gpu_allocation(gpus: &mut [GpuInfo]) {
+    if let Some(allocation) = read_gpu_allocation() {
+        annotate_gpu_allocation_with(gpus, &allocation);
+    }
+}
+
+fn annotate_gpu_allocation_with(gpus: &mut [GpuInfo], allocation: &GpuAllocation) {
+    for (index, gpu) in gpus.iter_mut().enumerate() {
+        let allocated = allocation.is_allocated(gpu, index);
+        gpu.detail
+            .insert(K8S_ALLOCATED_KEY.to_string(), allocated.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allocated(gpus: &[GpuInfo]) -> Vec<&str> {
+        gpus.iter()
+            .map(|g| g.detail.get(K8S_ALLOCATED_KEY).unwrap().as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_kubelet_checkpoint() {
+        let checkpoint = r#"{
+            "Data": {
+                "PodDeviceEntries": [
+                    {"PodUID": "a", "ContainerName": "train", "ResourceName": "nvidia.com/gpu",
+                     "DeviceIDs": {"0": ["GPU-0", "GPU-1"]}, "AllocResp": ""},
+                    {"PodUID": "b", "ContainerName": "infer", "ResourceName": "nvidia.com/gpu",
+                     "DeviceIDs": ["GPU-3::2"], "AllocResp": ""}
+                ],
+                "RegisteredDevices": {"nvidia.com/gpu": ["GPU-0", "GPU-1", "GPU-2", "GPU-3"]}
+            },
+            "Checksum": 1
+        }"#;
+        let allocation = parse_kubelet_checkpoint(checkpoint).unwrap();
+        let mut gpus = vec![
+            GpuInfo::fixture("GPU-0", "node1"),
+            GpuInfo::fixture("GPU-1", "node1"),
+            GpuInfo::fixture("GPU-2", "node1"),
+            GpuInfo::fixture("GPU-3", "node1"),
+        ];
+        annotate_gpu_allocation_with(&mut gpus, &allocation);
+        assert_eq!(allocated(&gpus), vec!["true", "true", "false", "true"]);
+
+        assert_eq!(parse_kubelet_checkpoint("not json"), None);
+    }
+
+    #[test]
+    fn test_visible_devices() {
+        let mut gpus = vec![
+            GpuInfo::fixture("GPU-0", "node1"),
+            GpuInfo::fixture("GPU-1", "node1"),
+            GpuInfo::fixture("GPU-2", "node1"),
+        ];
+        annotate_gpu_allocation_with(&mut gpus, &parse_visible_devices("GPU-2, 0"));
+        assert_eq!(allocated(&gpus), vec!["true", "false", "true"]);
+
+        assert_eq!(parse_visible_devices("all"), GpuAllocation::All);
+        annotate_gpu_allocation_with(&mut gpus, &parse_visible_devices("void"));
+        assert_eq!(allocated(&gpus), vec!["false", "false", "false"]);
+    }
+
+    #[test]
+    fn test_checkpoint_takes_precedence() {
+        let missing = Path::new("/nonexistent/kubelet_internal_checkpoint");
+        assert_eq!(read_gpu_allocation_from(missing, None), None);
+        assert_eq!(
+            read_gpu_allocation_from(missing, Some("all")),
+            Some(GpuAllocation::All)
+        );
+
+        let path = std::env::temp_dir().join(format!("all-smi-checkpoint-{}", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"Data": {"PodDeviceEntries": [{"DeviceIDs": ["GPU-1"]}]}}"#,
+        )
+        .unwrap();
+        let allocation = read_gpu_allocation_from(&path, Some("all")).unwrap();
+        assert_ne!(allocation, GpuAllocation::All);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -40,12 +40,13 @@
 use super::ioreport::{IOReport, IOReportMetrics};
 use super::metrics::NativeMetricsData;
 use super::smc::SMCMetrics;
-use super::thermal::get_thermal_state;
+use super::thermal::{get_thermal_state, observe_thermal_state_changes, ThermalState};
+use crate::device::thermal_pressure::ThermalHistory;
 use once_cell::sync::Lazy;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Global singleton for NativeMetricsManager
 static NATIVE_METRICS_MANAGER: Lazy<Mutex<Option<Arc<NativeMetricsManager>>>> =
@@ -54,6 +55,17 @@ static NATIVE_METRICS_MANAGER: Lazy<Mutex<Option<Arc<NativeMetricsManager>>>> =
 /// Track if first data has been received
 static FIRST_DATA_RECEIVED: AtomicBool = AtomicBool::new(false);
 
+/// Thermal level changes since the manager was initialized. Fed by the
+/// thermal state notification as well as every collection.
+static THERMAL_HISTORY: Lazy<Mutex<ThermalHistory>> =
+    Lazy::new(|| Mutex::new(ThermalHistory::default()));
+
+fn record_thermal_state(state: ThermalState) {
+    if let Ok(mut history) = THERMAL_HISTORY.lock() {
+        history.record(state as u8, Instant::now());
+    }
+}
+
 /// Configuration for the native metrics manager
 #[derive(Debug, Clone)]
 pub struct NativeMetricsConfig {
@@ -181,9 +193,8 @@ impl NativeMetricsManager {
                         let metrics = IOReportMetrics::from_sample(iterator, duration_ns);
                         samples.push(metrics);
                     }
-                    Err(_e) => {
-                        #[cfg(debug_assertions)]
-                        eprintln!("IOReport sample failed: {_e}");
+                    Err(e) => {
+                        tracing::debug!("IOReport sample failed: {e}");
                     }
                 }
             }
@@ -205,6 +216,7 @@ impl NativeMetricsManager {
 
             // Get thermal state
             let thermal_state = get_thermal_state();
+            record_thermal_state(thermal_state);
 
             // Combine into unified metrics
             let native_data =
@@ -358,6 +370,7 @@ impl NativeMetricsManager {
 
         // Get thermal state
         let thermal_state = get_thermal_state();
+        record_thermal_state(thermal_state);
 
         // Combine
         let data = NativeMetricsData::from_components(avg_metrics, smc_metrics, thermal_state);
@@ -374,6 +387,15 @@ impl NativeMetricsManager {
         Ok(data)
     }
 
+    /// Seconds per thermal level and the number of level changes, as
+    /// chassis detail entries
+    pub fn thermal_history_detail(&self) -> Vec<(String, String)> {
+        THERMAL_HISTORY
+            .lock()
+            .map(|history| history.detail_entries(Instant::now()))
+            .unwrap_or_default()
+    }
+
     /// Shutdown the manager
     pub fn shutdown(&self) {
         self.is_running.store(false, Ordering::Release);
@@ -417,6 +439,10 @@ pub fn initialize_native_metrics_manager(
     if manager_guard.is_none() {
         let manager = NativeMetricsManager::new(interval_ms)?;
 
+        // Catch levels that come and go between collections
+        record_thermal_state(get_thermal_state());
+        observe_thermal_state_changes(record_thermal_state);
+
         // Pre-collect first data sample to warm up the cache
         // This ensures all subsequent calls from readers use cached data
         let _ = manager.collect_once();
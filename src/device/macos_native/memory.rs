@@ -0,0 +1,143 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory pressure of macOS
+//!
+//! The pressure level is the one Activity Monitor shows, read from the
+//! `kern.memorystatus_vm_pressure_level` sysctl. Compressed memory and swap
+//! activity come from `host_statistics64(HOST_VM_INFO64)`.
+//!
+//! ## References
+//! - xnu: osfmk/mach/vm_statistics.h (`vm_statistics64`)
+//! - xnu: bsd/kern/kern_memorystatus.c (`kern.memorystatus_vm_pressure_level`)
+
+use std::ffi::CStr;
+
+use crate::device::memory_pressure::{page_size, PressureLevel};
+
+const HOST_VM_INFO64: i32 = 4;
+const KERN_SUCCESS: i32 = 0;
+
+/// `kern.memorystatus_vm_pressure_level` values
+const PRESSURE_NORMAL: i32 = 1;
+const PRESSURE_WARN: i32 = 2;
+const PRESSURE_CRITICAL: i32 = 4;
+
+/// `struct vm_statistics64`; counts are in pages
+#[repr(C)]
+#[derive(Default)]
+struct VmStatistics64 {
+    free_count: u32,
+    active_count: u32,
+    inactive_count: u32,
+    wire_count: u32,
+    zero_fill_count: u64,
+    reactivations: u64,
+    pageins: u64,
+    pageouts: u64,
+    faults: u64,
+    cow_faults: u64,
+    lookups: u64,
+    hits: u64,
+    purges: u64,
+    purgeable_count: u32,
+    speculative_count: u32,
+    decompressions: u64,
+    compressions: u64,
+    swapins: u64,
+    swapouts: u64,
+    compressor_page_count: u32,
+    throttled_count: u32,
+    external_page_count: u32,
+    internal_page_count: u32,
+    total_uncompressed_pages_in_compressor: u64,
+}
+
+/// `HOST_VM_INFO64_COUNT`: the size of `vm_statistics64` in `integer_t`s
+const HOST_VM_INFO64_COUNT: u32 =
+    (std::mem::size_of::<VmStatistics64>() / std::mem::size_of::<i32>()) as u32;
+
+unsafe extern "C" {
+    fn mach_host_self() -> u32;
+    fn host_statistics64(host: u32, flavor: i32, info: *mut i32, count: *mut u32) -> i32;
+}
+
+/// Compressor and swap counters, in bytes
+#[derive(Debug, Clone, Copy)]
+pub struct VmActivity {
+    pub compressed_bytes: u64,
+    pub swap_in_bytes_total: u64,
+    pub swap_out_bytes_total: u64,
+}
+
+/// The kernel's memory pressure level
+pub fn memory_pressure_level() -> Option<PressureLevel> {
+    let name = CStr::from_bytes_with_nul(b"kern.memorystatus_vm_pressure_level\0").ok()?;
+    let mut level: i32 = 0;
+    let mut size = std::mem::size_of::<i32>();
+    // SAFETY: `level` and `size` describe a writable i32
+    let result = unsafe {
+        libc::sysctlbyname(
+            name.as_ptr(),
+            &mut level as *mut i32 as *mut libc::c_void,
+            &mut size,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+    if result != 0 {
+        return None;
+    }
+    match level {
+        PRESSURE_NORMAL => Some(PressureLevel::Normal),
+        PRESSURE_WARN => Some(PressureLevel::Warning),
+        PRESSURE_CRITICAL => Some(PressureLevel::Critical),
+        _ => None,
+    }
+}
+
+/// Memory held by the compressor and pages swapped since boot
+pub fn vm_activity() -> Option<VmActivity> {
+    let mut stats = VmStatistics64::default();
+    let mut count = HOST_VM_INFO64_COUNT;
+    // SAFETY: `stats` is a vm_statistics64 of `count` integers
+    let result = unsafe {
+        host_statistics64(
+            mach_host_self(),
+            HOST_VM_INFO64,
+            &mut stats as *mut VmStatistics64 as *mut i32,
+            &mut count,
+        )
+    };
+    if result != KERN_SUCCESS {
+        return None;
+    }
+    let page_size = page_size();
+    Some(VmActivity {
+        compressed_bytes: stats.compressor_page_count as u64 * page_size,
+        swap_in_bytes_total: stats.swapins * page_size,
+        swap_out_bytes_total: stats.swapouts * page_size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vm_statistics64_layout() {
+        assert_eq!(std::mem::size_of::<VmStatistics64>(), 152);
+        assert_eq!(HOST_VM_INFO64_COUNT, 38);
+    }
+}
@@ -27,9 +27,13 @@
 //! - `ioreport`: IOReport API for power and residency metrics
 //! - `smc`: Apple SMC for temperature and system power metrics
 //! - `thermal`: NSProcessInfo thermal state binding
+//! - `gpu_clients`: Processes with GPU contexts and their estimated GPU memory
+//! - `memory`: Memory pressure level, compressed memory and swap activity
 //! - `manager`: Unified manager for native metrics collection
 
+mod gpu_clients;
 mod ioreport;
+mod memory;
 mod metrics;
 mod smc;
 mod thermal;
@@ -38,7 +42,11 @@ pub mod manager;
 
 // Re-export public types for use by apple_silicon_native reader and main
 #[allow(unused_imports)]
+pub use gpu_clients::{gpu_clients, GpuClient};
+#[allow(unused_imports)]
 pub use manager::{
     get_native_metrics_manager, initialize_native_metrics_manager, shutdown_native_metrics_manager,
     NativeMetricsManager,
 };
+#[allow(unused_imports)]
+pub use memory::{memory_pressure_level, vm_activity, VmActivity};
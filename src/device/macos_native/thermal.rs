@@ -24,10 +24,16 @@
 //! - Serious: High thermal load, system may throttle
 //! - Critical: Maximum thermal load, heavy throttling
 //!
+//! Changes are also delivered through
+//! NSProcessInfoThermalStateDidChangeNotification, so levels that only last
+//! between two samples are still seen (see [`observe_thermal_state_changes`]).
+//!
 //! ## References
 //! - Apple Developer Documentation: NSProcessInfo.thermalState
+//! - Apple Developer Documentation: NSProcessInfoThermalStateDidChangeNotification
 
 use std::ffi::c_void;
+use std::sync::OnceLock;
 
 // Objective-C runtime linkage
 #[link(name = "objc", kind = "dylib")]
@@ -35,8 +41,29 @@ unsafe extern "C" {
     fn objc_getClass(name: *const i8) -> *mut c_void;
     fn sel_registerName(name: *const i8) -> *mut c_void;
     fn objc_msgSend(receiver: *mut c_void, selector: *mut c_void, ...) -> *mut c_void;
+    fn objc_allocateClassPair(
+        superclass: *mut c_void,
+        name: *const i8,
+        extra_bytes: usize,
+    ) -> *mut c_void;
+    fn objc_registerClassPair(class: *mut c_void);
+    fn class_addMethod(
+        class: *mut c_void,
+        selector: *mut c_void,
+        imp: *const c_void,
+        types: *const i8,
+    ) -> bool;
+    fn class_createInstance(class: *mut c_void, extra_bytes: usize) -> *mut c_void;
 }
 
+#[link(name = "Foundation", kind = "framework")]
+unsafe extern "C" {
+    static NSProcessInfoThermalStateDidChangeNotification: *mut c_void;
+}
+
+/// Called with the new state on every thermal state change notification
+static ON_THERMAL_CHANGE: OnceLock<fn(ThermalState)> = OnceLock::new();
+
 /// Thermal state levels as defined by NSProcessInfo
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i64)]
@@ -129,6 +156,71 @@ pub fn get_thermal_state() -> ThermalState {
     }
 }
 
+/// `-thermalStateChanged:` of the observer registered with the notification
+/// center. Runs on the thread that posts the notification.
+extern "C" fn thermal_state_changed(_this: *mut c_void, _cmd: *mut c_void, _note: *mut c_void) {
+    if let Some(on_change) = ON_THERMAL_CHANGE.get() {
+        on_change(get_thermal_state());
+    }
+}
+
+/// Call `on_change` with the new state whenever the system thermal state
+/// changes. Only the first call registers an observer; it stays registered
+/// for the life of the process.
+pub fn observe_thermal_state_changes(on_change: fn(ThermalState)) {
+    if ON_THERMAL_CHANGE.set(on_change).is_err() {
+        return;
+    }
+
+    unsafe {
+        // objc_msgSend must be called through a pointer of the exact method
+        // type; arguments passed as C varargs are misplaced on arm64
+        type AddObserver = unsafe extern "C" fn(
+            *mut c_void,
+            *mut c_void,
+            *mut c_void,
+            *mut c_void,
+            *mut c_void,
+            *mut c_void,
+        );
+        let add_observer: AddObserver = std::mem::transmute(objc_msgSend as *const c_void);
+
+        let superclass = objc_getClass(c"NSObject".as_ptr());
+        let class = objc_allocateClassPair(superclass, c"AllSmiThermalObserver".as_ptr(), 0);
+        if class.is_null() {
+            tracing::warn!("Failed to create thermal state observer class");
+            return;
+        }
+        let handler_sel = sel_registerName(c"thermalStateChanged:".as_ptr());
+        class_addMethod(
+            class,
+            handler_sel,
+            thermal_state_changed as *const c_void,
+            c"v@:@".as_ptr(),
+        );
+        objc_registerClassPair(class);
+        let observer = class_createInstance(class, 0);
+
+        let center_class = objc_getClass(c"NSNotificationCenter".as_ptr());
+        let default_center_sel = sel_registerName(c"defaultCenter".as_ptr());
+        let center = objc_msgSend(center_class, default_center_sel);
+        if observer.is_null() || center.is_null() {
+            tracing::warn!("Failed to register thermal state observer");
+            return;
+        }
+
+        let add_observer_sel = sel_registerName(c"addObserver:selector:name:object:".as_ptr());
+        add_observer(
+            center,
+            add_observer_sel,
+            observer,
+            handler_sel,
+            NSProcessInfoThermalStateDidChangeNotification,
+            std::ptr::null_mut(),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
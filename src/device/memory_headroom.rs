@@ -0,0 +1,239 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GPU memory headroom and out-of-memory risk.
+//!
+//! The TUI badge and the exported `all_smi_gpu_memory_headroom_bytes` /
+//! `all_smi_gpu_oom_risk` series are both computed by [`memory_headroom`],
+//! so they always agree.
+
+use std::sync::OnceLock;
+
+use crate::device::{GpuInfo, ProcessInfo};
+
+/// Free memory, as a percentage of total, below which a device is at risk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OomThresholds {
+    /// Below this the device is flagged as a warning
+    pub warn_pct: f64,
+    /// Below this the device is flagged as critical
+    pub crit_pct: f64,
+}
+
+impl Default for OomThresholds {
+    fn default() -> Self {
+        Self {
+            warn_pct: 5.0,
+            crit_pct: 2.0,
+        }
+    }
+}
+
+static OOM_THRESHOLDS: OnceLock<OomThresholds> = OnceLock::new();
+
+/// Install the thresholds from `--oom-warn-pct`/`--oom-crit-pct`. Only the
+/// first call takes effect.
+pub fn set_oom_thresholds(thresholds: OomThresholds) {
+    let _ = OOM_THRESHOLDS.set(thresholds);
+}
+
+/// The installed thresholds, or the defaults if none were set.
+pub fn oom_thresholds() -> OomThresholds {
+    OOM_THRESHOLDS.get().copied().unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OomRisk {
+    None,
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryHeadroom {
+    /// Total minus used memory, in bytes
+    pub headroom_bytes: u64,
+    /// Headroom as a percentage of total memory
+    pub headroom_pct: f64,
+    /// Largest allocation of a single process on the device, if processes
+    /// were collected
+    pub largest_process_bytes: Option<u64>,
+    pub risk: OomRisk,
+}
+
+/// Headroom of `gpu`, with the largest of `processes` running on it.
+/// Returns `None` for devices that report no memory total, since no
+/// percentage can be computed for them.
+pub fn memory_headroom(
+    gpu: &GpuInfo,
+    processes: &[ProcessInfo],
+    thresholds: OomThresholds,
+) -> Option<MemoryHeadroom> {
+    if gpu.total_memory == 0 {
+        return None;
+    }
+
+    let headroom_bytes = gpu.total_memory.saturating_sub(gpu.used_memory);
+    let headroom_pct = headroom_bytes as f64 / gpu.total_memory as f64 * 100.0;
+    let risk = if headroom_pct < thresholds.crit_pct {
+        OomRisk::Critical
+    } else if headroom_pct < thresholds.warn_pct {
+        OomRisk::Warning
+    } else {
+        OomRisk::None
+    };
+    let largest_process_bytes = processes
+        .iter()
+        .filter(|p| !gpu.uuid.is_empty() && p.device_uuid == gpu.uuid)
+        .map(|p| p.used_memory)
+        .max();
+
+    Some(MemoryHeadroom {
+        headroom_bytes,
+        headroom_pct,
+        largest_process_bytes,
+        risk,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1 << 30;
+
+    fn process(pid: u32, device_uuid: &str, used_memory: u64) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: device_uuid.to_string(),
+            pid,
+            process_name: "python".to_string(),
+            used_memory,
+            used_memory_estimated: false,
+            cpu_percent: 0.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: "user".to_string(),
+            state: "R".to_string(),
+            start_time: 0,
+            cpu_time: 0,
+            command: "python".to_string(),
+            ppid: 1,
+            threads: 1,
+            uses_gpu: true,
+            priority: 20,
+            nice_value: 0,
+            gpu_utilization: 0.0,
+            k8s_pod: None,
+            host_id: String::new(),
+        }
+    }
+
+    fn risk(total: u64, used: u64) -> OomRisk {
+        memory_headroom(
+            &GpuInfo {
+                total_memory: total,
+                used_memory: used,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            },
+            &[],
+            OomThresholds::default(),
+        )
+        .unwrap()
+        .risk
+    }
+
+    #[test]
+    fn test_risk_boundaries() {
+        // Exactly at a threshold is not below it
+        assert_eq!(risk(100 * GIB, 95 * GIB), OomRisk::None);
+        assert_eq!(risk(100 * GIB, 96 * GIB), OomRisk::Warning);
+        assert_eq!(risk(100 * GIB, 98 * GIB), OomRisk::Warning);
+        assert_eq!(risk(100 * GIB, 99 * GIB), OomRisk::Critical);
+        assert_eq!(risk(100 * GIB, 100 * GIB), OomRisk::Critical);
+        assert_eq!(risk(100 * GIB, 10 * GIB), OomRisk::None);
+    }
+
+    #[test]
+    fn test_headroom_values() {
+        let processes = [
+            process(1, "GPU-0", 30 * GIB),
+            process(2, "GPU-0", 50 * GIB),
+            process(3, "GPU-1", 70 * GIB),
+        ];
+        let headroom = memory_headroom(
+            &GpuInfo {
+                total_memory: 80 * GIB,
+                used_memory: 79 * GIB,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            },
+            &processes,
+            OomThresholds::default(),
+        )
+        .unwrap();
+        assert_eq!(headroom.headroom_bytes, GIB);
+        assert!((headroom.headroom_pct - 1.25).abs() < 1e-9);
+        assert_eq!(headroom.largest_process_bytes, Some(50 * GIB));
+
+        let headroom = memory_headroom(
+            &GpuInfo {
+                total_memory: 80 * GIB,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            },
+            &[],
+            OomThresholds::default(),
+        )
+        .unwrap();
+        assert_eq!(headroom.largest_process_bytes, None);
+    }
+
+    #[test]
+    fn test_zero_total_memory_is_skipped() {
+        assert_eq!(
+            memory_headroom(
+                &GpuInfo::fixture("GPU-0", "node1"),
+                &[],
+                OomThresholds::default()
+            ),
+            None
+        );
+        // Used above total (racy readings) saturates to no headroom
+        assert_eq!(risk(GIB, 2 * GIB), OomRisk::Critical);
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        let thresholds = OomThresholds {
+            warn_pct: 20.0,
+            crit_pct: 10.0,
+        };
+        let at = |used| {
+            memory_headroom(
+                &GpuInfo {
+                    total_memory: 100 * GIB,
+                    used_memory: used,
+                    ..GpuInfo::fixture("GPU-0", "node1")
+                },
+                &[],
+                thresholds,
+            )
+            .unwrap()
+            .risk
+        };
+        assert_eq!(at(80 * GIB), OomRisk::None);
+        assert_eq!(at(85 * GIB), OomRisk::Warning);
+        assert_eq!(at(91 * GIB), OomRisk::Critical);
+    }
+}
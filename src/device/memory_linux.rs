@@ -17,6 +17,7 @@ use once_cell::sync::Lazy;
 use std::fs;
 
 use crate::device::container_info::ContainerInfo;
+use crate::device::memory_pressure;
 use crate::device::{MemoryInfo, MemoryReader};
 use crate::utils::get_hostname;
 
@@ -52,6 +53,7 @@ impl LinuxMemoryReader {
 impl MemoryReader for LinuxMemoryReader {
     fn get_memory_info(&self) -> Vec<MemoryInfo> {
         let mut memory_info = Vec::new();
+        let (psi, swap_activity) = memory_pressure::read_linux();
 
         // Check if we're in a container and have memory limits
         if let Some(ref container_info) = self.container_info {
@@ -77,6 +79,12 @@ impl MemoryReader for LinuxMemoryReader {
                     swap_free_bytes: 0,
                     utilization,
                     time: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    psi,
+                    pressure_level: None,
+                    compressed_bytes: None,
+                    swap_in_bytes_total: swap_activity.map(|(swap_in, _)| swap_in),
+                    swap_out_bytes_total: swap_activity.map(|(_, swap_out)| swap_out),
+                    stale: false,
                 });
 
                 return memory_info;
@@ -143,6 +151,12 @@ impl MemoryReader for LinuxMemoryReader {
                 swap_free_bytes,
                 utilization,
                 time: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+                psi,
+                pressure_level: None,
+                compressed_bytes: None,
+                swap_in_bytes_total: swap_activity.map(|(swap_in, _)| swap_in),
+                swap_out_bytes_total: swap_activity.map(|(_, swap_out)| swap_out),
+                stale: false,
             });
         }
 
@@ -16,6 +16,7 @@ use chrono::Local;
 use std::sync::RwLock;
 use sysinfo::System;
 
+use crate::device::macos_native::{memory_pressure_level, vm_activity};
 use crate::device::{MemoryInfo, MemoryReader};
 use crate::utils::get_hostname;
 
@@ -68,6 +69,8 @@ impl MemoryReader for MacOsMemoryReader {
         let swap_used_bytes = system.used_swap();
         let swap_free_bytes = system.free_swap();
 
+        let vm = vm_activity();
+
         let hostname = get_hostname();
         let now = Local::now();
 
@@ -86,6 +89,12 @@ impl MemoryReader for MacOsMemoryReader {
             swap_free_bytes,
             utilization,
             time: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+            psi: None,
+            pressure_level: memory_pressure_level(),
+            compressed_bytes: vm.map(|vm| vm.compressed_bytes),
+            swap_in_bytes_total: vm.map(|vm| vm.swap_in_bytes_total),
+            swap_out_bytes_total: vm.map(|vm| vm.swap_out_bytes_total),
+            stale: false,
         });
 
         memory_info
@@ -0,0 +1,250 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory pressure and swap activity.
+//!
+//! Used/total memory says little about thrashing: a full page cache is
+//! healthy, while tasks stalling on reclaim are not. Linux reports the stall
+//! time through PSI (`/proc/pressure/memory`); macOS reports a pressure level
+//! and how much memory the compressor holds. Both count pages swapped in and
+//! out. Kernels without PSI simply leave the fields empty.
+
+use serde::{Deserialize, Serialize};
+
+use crate::device::MemoryInfo;
+
+/// Share (%) of the last 10 s some task stalled on memory
+const WARNING_SOME_AVG10: f64 = 10.0;
+const CRITICAL_SOME_AVG10: f64 = 40.0;
+/// All non-idle tasks stalled on memory this share (%) of the last 10 s
+const CRITICAL_FULL_AVG10: f64 = 10.0;
+
+/// One line of a PSI file: the share of time (%) tasks stalled over the
+/// last 10, 60 and 300 seconds, and the total stall time in microseconds
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub struct PsiLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total_us: u64,
+}
+
+impl PsiLine {
+    /// `(window, avg)` pairs, as exported in the `window` label
+    pub fn windows(&self) -> [(&'static str, f64); 3] {
+        [
+            ("10s", self.avg10),
+            ("60s", self.avg60),
+            ("300s", self.avg300),
+        ]
+    }
+
+    /// Set the average of `window` ("10s", "60s" or "300s")
+    pub fn set_window(&mut self, window: &str, avg: f64) {
+        match window {
+            "10s" => self.avg10 = avg,
+            "60s" => self.avg60 = avg,
+            "300s" => self.avg300 = avg,
+            _ => {}
+        }
+    }
+}
+
+/// Memory PSI: `some` counts time at least one task stalled, `full` time
+/// all non-idle tasks did. Some kernels report only `some`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub struct MemoryPsi {
+    pub some: PsiLine,
+    pub full: Option<PsiLine>,
+}
+
+/// How hard the kernel is working to find memory
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PressureLevel {
+    Normal,
+    Warning,
+    Critical,
+}
+
+impl PressureLevel {
+    /// Value of `all_smi_memory_pressure_level`
+    pub fn value(self) -> u8 {
+        match self {
+            PressureLevel::Normal => 0,
+            PressureLevel::Warning => 1,
+            PressureLevel::Critical => 2,
+        }
+    }
+
+    pub fn from_value(value: f64) -> Option<Self> {
+        match value as u8 {
+            0 => Some(PressureLevel::Normal),
+            1 => Some(PressureLevel::Warning),
+            2 => Some(PressureLevel::Critical),
+            _ => None,
+        }
+    }
+
+    /// Level for PSI averages
+    pub fn from_psi(psi: &MemoryPsi) -> Self {
+        let full_avg10 = psi.full.map_or(0.0, |full| full.avg10);
+        if psi.some.avg10 >= CRITICAL_SOME_AVG10 || full_avg10 >= CRITICAL_FULL_AVG10 {
+            PressureLevel::Critical
+        } else if psi.some.avg10 >= WARNING_SOME_AVG10 {
+            PressureLevel::Warning
+        } else {
+            PressureLevel::Normal
+        }
+    }
+}
+
+/// Pressure of a host's memory: the level macOS reports, or one derived
+/// from PSI. None when the platform reports neither.
+pub fn pressure_level(info: &MemoryInfo) -> Option<PressureLevel> {
+    info.pressure_level
+        .or_else(|| info.psi.as_ref().map(PressureLevel::from_psi))
+}
+
+/// Parse `/proc/pressure/memory`:
+///
+/// ```text
+/// some avg10=0.00 avg60=0.00 avg300=0.00 total=0
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+/// ```
+pub fn parse_psi(text: &str) -> Option<MemoryPsi> {
+    let mut some = None;
+    let mut full = None;
+    for line in text.lines() {
+        let mut fields = line.split_whitespace();
+        let target = match fields.next() {
+            Some("some") => &mut some,
+            Some("full") => &mut full,
+            _ => continue,
+        };
+        let mut psi = PsiLine::default();
+        for field in fields {
+            let Some((key, value)) = field.split_once('=') else {
+                continue;
+            };
+            match key {
+                "avg10" => psi.avg10 = value.parse().ok()?,
+                "avg60" => psi.avg60 = value.parse().ok()?,
+                "avg300" => psi.avg300 = value.parse().ok()?,
+                "total" => psi.total_us = value.parse().ok()?,
+                _ => {}
+            }
+        }
+        *target = Some(psi);
+    }
+    Some(MemoryPsi { some: some?, full })
+}
+
+/// Pages swapped in and out since boot, from `/proc/vmstat`
+pub fn parse_vmstat_swap(text: &str) -> Option<(u64, u64)> {
+    let mut swap_in = None;
+    let mut swap_out = None;
+    for line in text.lines() {
+        match line.split_once(' ') {
+            Some(("pswpin", value)) => swap_in = value.trim().parse().ok(),
+            Some(("pswpout", value)) => swap_out = value.trim().parse().ok(),
+            _ => {}
+        }
+    }
+    Some((swap_in?, swap_out?))
+}
+
+/// Size of a memory page in bytes
+#[cfg(unix)]
+pub fn page_size() -> u64 {
+    // SAFETY: sysconf has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    u64::try_from(size).unwrap_or(4096)
+}
+
+/// PSI and swap counters of this host; empty fields where the kernel does
+/// not provide them
+#[cfg(target_os = "linux")]
+pub fn read_linux() -> (Option<MemoryPsi>, Option<(u64, u64)>) {
+    let psi = std::fs::read_to_string("/proc/pressure/memory")
+        .ok()
+        .and_then(|text| parse_psi(&text));
+    let page_size = page_size();
+    let swap = std::fs::read_to_string("/proc/vmstat")
+        .ok()
+        .and_then(|text| parse_vmstat_swap(&text))
+        .map(|(pages_in, pages_out)| (pages_in * page_size, pages_out * page_size));
+    (psi, swap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_psi_with_full_line() {
+        let psi = parse_psi(
+            "some avg10=12.50 avg60=3.21 avg300=0.75 total=48213007\n\
+             full avg10=1.05 avg60=0.40 avg300=0.09 total=6011234\n",
+        )
+        .unwrap();
+        assert_eq!(
+            psi.some,
+            PsiLine {
+                avg10: 12.5,
+                avg60: 3.21,
+                avg300: 0.75,
+                total_us: 48_213_007,
+            }
+        );
+        assert_eq!(psi.full.unwrap().total_us, 6_011_234);
+        assert_eq!(psi.full.unwrap().avg10, 1.05);
+        assert_eq!(PressureLevel::from_psi(&psi), PressureLevel::Warning);
+    }
+
+    #[test]
+    fn test_parse_psi_without_full_line() {
+        let psi = parse_psi("some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").unwrap();
+        assert_eq!(psi.full, None);
+        assert_eq!(PressureLevel::from_psi(&psi), PressureLevel::Normal);
+    }
+
+    #[test]
+    fn test_parse_psi_rejects_garbage() {
+        assert_eq!(parse_psi(""), None);
+        assert_eq!(parse_psi("full avg10=1.00 avg60=0 avg300=0 total=1"), None);
+        assert_eq!(parse_psi("some avg10=abc avg60=0 avg300=0 total=1"), None);
+    }
+
+    #[test]
+    fn test_full_stalls_are_critical() {
+        let psi = MemoryPsi {
+            some: PsiLine {
+                avg10: 15.0,
+                ..PsiLine::default()
+            },
+            full: Some(PsiLine {
+                avg10: 11.0,
+                ..PsiLine::default()
+            }),
+        };
+        assert_eq!(PressureLevel::from_psi(&psi), PressureLevel::Critical);
+    }
+
+    #[test]
+    fn test_parse_vmstat_swap() {
+        let vmstat = "nr_free_pages 1000\npswpin 42\npswpout 7\npgfault 9\n";
+        assert_eq!(parse_vmstat_swap(vmstat), Some((42, 7)));
+        assert_eq!(parse_vmstat_swap("nr_free_pages 1000\n"), None);
+    }
+}
@@ -89,6 +89,12 @@ impl MemoryReader for WindowsMemoryReader {
             swap_free_bytes,
             utilization,
             time: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+            psi: None,
+            pressure_level: None,
+            compressed_bytes: None,
+            swap_in_bytes_total: None,
+            swap_out_bytes_total: None,
+            stale: false,
         });
 
         memory_info
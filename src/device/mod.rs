@@ -54,11 +54,30 @@ pub mod hlsmi;
 /* Refactored modules */
 pub mod common;
 pub mod container_utils;
+pub mod device_order;
+pub mod device_selection;
+pub mod driver_versions;
+pub mod gpu_fan;
+pub mod gpu_memory;
+pub mod gpu_presence;
+pub mod gpu_temperature;
+pub mod host_info;
+pub mod k8s_allocation;
+pub mod memory_headroom;
+pub mod memory_pressure;
+pub mod numa;
 pub mod platform_detection;
+pub mod plugin_abi;
+#[cfg(target_os = "linux")]
+pub mod pod_resources;
+pub mod power_headroom;
+pub mod privileges;
 pub mod process_list;
 pub mod process_utils;
 pub mod reader_factory;
+pub mod reader_supervisor;
 pub mod readers;
+pub mod thermal_pressure;
 pub mod traits;
 pub mod types;
 
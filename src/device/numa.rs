@@ -0,0 +1,370 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NUMA topology, GPU-to-CPU affinity and PCIe placement.
+//!
+//! On Linux the node layout comes from `/sys/devices/system/node/node*/cpulist`
+//! and each device's node from `/sys/bus/pci/devices/<bdf>/numa_node`; the
+//! bridges above a device from where that link resolves under `/sys/devices`.
+//! Other platforms report a single implicit node and no PCI tree, so nothing
+//! is annotated there.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::device::GpuInfo;
+
+/// Detail keys under which readers store a device's PCI address.
+const PCI_ADDRESS_KEYS: [&str; 5] = [
+    "PCI Bus ID",
+    "PCI Bus",
+    "PCIe Address",
+    "pci_bdf",
+    "pci_address",
+];
+
+/// Detail key holding the PCIe path from the root complex to the device
+pub const PCIE_PATH_KEY: &str = "pcie_path";
+
+/// Largest CPU id accepted from a cpulist, to bound malformed input.
+const MAX_CPU_ID: u32 = 65_535;
+
+/// Parse a kernel cpulist such as `"0-15,32-47"` into sorted CPU ids.
+/// Invalid fragments are skipped.
+pub fn parse_cpulist(list: &str) -> Vec<u32> {
+    let mut cpus = Vec::new();
+    for part in list.trim().split(',').map(str::trim) {
+        if part.is_empty() {
+            continue;
+        }
+        match part.split_once('-') {
+            Some((start, end)) => {
+                if let (Ok(start), Ok(end)) = (start.parse::<u32>(), end.parse::<u32>()) {
+                    if start <= end && end <= MAX_CPU_ID {
+                        cpus.extend(start..=end);
+                    }
+                }
+            }
+            None => {
+                if let Ok(cpu) = part.parse::<u32>() {
+                    if cpu <= MAX_CPU_ID {
+                        cpus.push(cpu);
+                    }
+                }
+            }
+        }
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    cpus
+}
+
+/// Format CPU ids back into compact cpulist form, e.g. `"0-15,32-47"`.
+pub fn format_cpulist(cpus: &[u32]) -> String {
+    let mut sorted = cpus.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut ranges: Vec<String> = Vec::new();
+    let mut iter = sorted.into_iter().peekable();
+    while let Some(start) = iter.next() {
+        let mut end = start;
+        while iter.peek() == Some(&(end + 1)) {
+            end += 1;
+            iter.next();
+        }
+        if start == end {
+            ranges.push(start.to_string());
+        } else {
+            ranges.push(format!("{start}-{end}"));
+        }
+    }
+    ranges.join(",")
+}
+
+/// CPUs belonging to each NUMA node.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NumaTopology {
+    nodes: BTreeMap<u32, Vec<u32>>,
+}
+
+impl NumaTopology {
+    /// Topology of this machine, read once and cached.
+    pub fn get() -> &'static NumaTopology {
+        static TOPOLOGY: OnceLock<NumaTopology> = OnceLock::new();
+        TOPOLOGY.get_or_init(|| {
+            if cfg!(target_os = "linux") {
+                Self::from_sysfs(Path::new("/sys/devices/system/node"))
+            } else {
+                Self::default()
+            }
+        })
+    }
+
+    /// Read `node<N>/cpulist` entries below `root`.
+    pub fn from_sysfs(root: &Path) -> Self {
+        let mut nodes = BTreeMap::new();
+        let Ok(entries) = fs::read_dir(root) else {
+            return Self::default();
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let Some(node) = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("node"))
+                .and_then(|id| id.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            if let Ok(list) = fs::read_to_string(entry.path().join("cpulist")) {
+                nodes.insert(node, parse_cpulist(&list));
+            }
+        }
+        Self { nodes }
+    }
+
+    /// Topology built from explicit node-to-CPU lists.
+    #[cfg(test)]
+    pub(crate) fn from_nodes(nodes: BTreeMap<u32, Vec<u32>>) -> Self {
+        Self { nodes }
+    }
+
+    /// True when more than one node is online; single-node systems get no
+    /// NUMA annotations at all.
+    pub fn is_multi_node(&self) -> bool {
+        self.nodes.len() > 1
+    }
+
+    pub fn cpus(&self, node: u32) -> Option<&[u32]> {
+        self.nodes.get(&node).map(Vec::as_slice)
+    }
+
+    /// Nodes in ascending order with their CPU ids.
+    pub fn nodes(&self) -> impl Iterator<Item = (u32, &[u32])> {
+        self.nodes
+            .iter()
+            .map(|(node, cpus)| (*node, cpus.as_slice()))
+    }
+}
+
+/// Normalize a PCI address to the sysfs form `dddd:bb:dd.f`. NVML reports an
+/// 8-digit domain and some tools omit the domain entirely.
+fn normalize_bdf(address: &str) -> Option<String> {
+    let address = address.trim().to_ascii_lowercase();
+    let (domain, rest) = match address.matches(':').count() {
+        1 => ("0000", address.as_str()),
+        2 => address.split_once(':')?,
+        _ => return None,
+    };
+    if domain.is_empty() || !domain.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let domain = &domain[domain.len().saturating_sub(4)..];
+    Some(format!("{domain:0>4}:{rest}"))
+}
+
+/// NUMA node of a PCI device, or `None` if unknown (the kernel reports -1
+/// when the platform has no affinity information).
+pub fn pci_numa_node(pci_root: &Path, address: &str) -> Option<u32> {
+    let bdf = normalize_bdf(address)?;
+    let content = fs::read_to_string(pci_root.join(bdf).join("numa_node")).ok()?;
+    content
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .and_then(|node| u32::try_from(node).ok())
+}
+
+/// Root complex, bridges and switch ports leading to a PCI device, e.g.
+/// `pci0000:00/0000:00:01.0/0000:01:00.0/0000:03:00.0`. GPUs that share a
+/// prefix sit behind the same switch or root port.
+pub fn pcie_path(pci_root: &Path, address: &str) -> Option<String> {
+    let bdf = normalize_bdf(address)?;
+    let resolved = fs::canonicalize(pci_root.join(&bdf)).ok()?;
+    let components: Vec<&str> = resolved
+        .iter()
+        .filter_map(|component| component.to_str())
+        .skip_while(|component| !(component.starts_with("pci") && component.contains(':')))
+        .collect();
+    (components.len() > 1 && components.last() == Some(&bdf.as_str())).then(|| components.join("/"))
+}
+
+/// Add the `pcie_path` of each GPU and, on multi-node systems, its
+/// `numa_node` and `cpu_affinity`. Devices without a known PCI address are
+/// left untouched.
+pub fn annotate_gpu_topology(gpus: &mut [GpuInfo]) {
+    annotate_gpu_topology_with(gpus, NumaTopology::get(), Path::new("/sys/bus/pci/devices"));
+}
+
+fn annotate_gpu_topology_with(gpus: &mut [GpuInfo], topology: &NumaTopology, pci_root: &Path) {
+    for gpu in gpus {
+        let Some(address) = PCI_ADDRESS_KEYS
+            .iter()
+            .find_map(|key| gpu.detail.get(*key))
+            .cloned()
+        else {
+            continue;
+        };
+        if let Some(path) = pcie_path(pci_root, &address) {
+            gpu.detail.insert(PCIE_PATH_KEY.to_string(), path);
+        }
+
+        if !topology.is_multi_node() {
+            continue;
+        }
+        let Some(node) = pci_numa_node(pci_root, &address) else {
+            continue;
+        };
+        gpu.detail.insert("numa_node".to_string(), node.to_string());
+        if let Some(cpus) = topology.cpus(node) {
+            gpu.detail
+                .insert("cpu_affinity".to_string(), format_cpulist(cpus));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_node_topology() -> NumaTopology {
+        NumaTopology::from_nodes(BTreeMap::from([
+            (0, parse_cpulist("0-3,8-11")),
+            (1, parse_cpulist("4-7,12-15")),
+        ]))
+    }
+
+    #[test]
+    fn test_parse_cpulist() {
+        assert_eq!(parse_cpulist("0-3"), vec![0, 1, 2, 3]);
+        assert_eq!(parse_cpulist("0-1,32-33\n"), vec![0, 1, 32, 33]);
+        assert_eq!(parse_cpulist("5,1,3"), vec![1, 3, 5]);
+        assert_eq!(parse_cpulist("0-2,1-3"), vec![0, 1, 2, 3]);
+        assert_eq!(parse_cpulist(""), Vec::<u32>::new());
+        assert_eq!(parse_cpulist("a-b,7,9-8"), vec![7]);
+        assert_eq!(parse_cpulist("0-4294967295"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_format_cpulist() {
+        assert_eq!(format_cpulist(&parse_cpulist("0-15,32-47")), "0-15,32-47");
+        assert_eq!(format_cpulist(&[3, 1, 2, 7]), "1-3,7");
+        assert_eq!(format_cpulist(&[]), "");
+    }
+
+    #[test]
+    fn test_normalize_bdf() {
+        assert_eq!(
+            normalize_bdf("00000000:17:00.0").as_deref(),
+            Some("0000:17:00.0")
+        );
+        assert_eq!(
+            normalize_bdf("0000:3B:00.0").as_deref(),
+            Some("0000:3b:00.0")
+        );
+        assert_eq!(normalize_bdf("03:00.0").as_deref(), Some("0000:03:00.0"));
+        assert_eq!(normalize_bdf("not-a-bdf"), None);
+    }
+
+    #[test]
+    fn test_topology_from_sysfs() {
+        let dir = tempfile::tempdir().unwrap();
+        for (node, list) in [("node0", "0-3\n"), ("node1", "4-7\n")] {
+            fs::create_dir(dir.path().join(node)).unwrap();
+            fs::write(dir.path().join(node).join("cpulist"), list).unwrap();
+        }
+        fs::create_dir(dir.path().join("power")).unwrap();
+
+        let topology = NumaTopology::from_sysfs(dir.path());
+        assert!(topology.is_multi_node());
+        assert_eq!(topology.cpus(1), Some(&[4, 5, 6, 7][..]));
+        assert_eq!(topology.nodes().count(), 2);
+    }
+
+    #[test]
+    fn test_annotate_gpu_numa() {
+        let pci_root = tempfile::tempdir().unwrap();
+        let device = pci_root.path().join("0000:17:00.0");
+        fs::create_dir(&device).unwrap();
+        fs::write(device.join("numa_node"), "1\n").unwrap();
+        let unknown = pci_root.path().join("0000:18:00.0");
+        fs::create_dir(&unknown).unwrap();
+        fs::write(unknown.join("numa_node"), "-1\n").unwrap();
+
+        let mut gpus = vec![
+            GpuInfo::fixture("GPU-0", "host").with_detail(&[("PCI Bus ID", "00000000:17:00.0")]),
+            GpuInfo::fixture("GPU-0", "host").with_detail(&[("pci_bdf", "0000:18:00.0")]),
+        ];
+        annotate_gpu_topology_with(&mut gpus, &two_node_topology(), pci_root.path());
+
+        assert_eq!(
+            gpus[0].detail.get("numa_node").map(String::as_str),
+            Some("1")
+        );
+        assert_eq!(
+            gpus[0].detail.get("cpu_affinity").map(String::as_str),
+            Some("4-7,12-15")
+        );
+        assert!(!gpus[1].detail.contains_key("numa_node"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pcie_path() {
+        let sysfs = tempfile::tempdir().unwrap();
+        let device = sysfs
+            .path()
+            .join("devices/pci0000:16/0000:16:01.0/0000:17:00.0/0000:18:00.0");
+        fs::create_dir_all(&device).unwrap();
+        fs::write(device.join("numa_node"), "0\n").unwrap();
+        let pci_root = sysfs.path().join("bus/pci/devices");
+        fs::create_dir_all(&pci_root).unwrap();
+        std::os::unix::fs::symlink(&device, pci_root.join("0000:18:00.0")).unwrap();
+
+        assert_eq!(
+            pcie_path(&pci_root, "00000000:18:00.0").as_deref(),
+            Some("pci0000:16/0000:16:01.0/0000:17:00.0/0000:18:00.0")
+        );
+        assert_eq!(pcie_path(&pci_root, "0000:19:00.0"), None);
+
+        // The path is reported on single-node systems too
+        let topology = NumaTopology::from_nodes(BTreeMap::from([(0, parse_cpulist("0-7"))]));
+        let mut gpus =
+            vec![GpuInfo::fixture("GPU-0", "host").with_detail(&[("PCI Bus", "0000:18:00.0")])];
+        annotate_gpu_topology_with(&mut gpus, &topology, &pci_root);
+        assert_eq!(
+            gpus[0].detail.get(PCIE_PATH_KEY).map(String::as_str),
+            Some("pci0000:16/0000:16:01.0/0000:17:00.0/0000:18:00.0")
+        );
+        assert!(!gpus[0].detail.contains_key("numa_node"));
+    }
+
+    #[test]
+    fn test_single_node_is_not_annotated() {
+        let pci_root = tempfile::tempdir().unwrap();
+        let device = pci_root.path().join("0000:17:00.0");
+        fs::create_dir(&device).unwrap();
+        fs::write(device.join("numa_node"), "0\n").unwrap();
+
+        let topology = NumaTopology::from_nodes(BTreeMap::from([(0, parse_cpulist("0-7"))]));
+        let mut gpus =
+            vec![GpuInfo::fixture("GPU-0", "host").with_detail(&[("PCI Bus ID", "0000:17:00.0")])];
+        annotate_gpu_topology_with(&mut gpus, &topology, pci_root.path());
+
+        assert!(!gpus[0].detail.contains_key("numa_node"));
+        assert!(!gpus[0].detail.contains_key("cpu_affinity"));
+    }
+}
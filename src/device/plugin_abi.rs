@@ -0,0 +1,125 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! C ABI for device reader plugins loaded with `--plugin`.
+//!
+//! A plugin is a shared library (`.so`, `.dylib`, `.dll`) exporting three
+//! unmangled C functions:
+//!
+//! ```c
+//! uint32_t    all_smi_plugin_abi_version(void);   /* must return 1 */
+//! const char *all_smi_plugin_name(void);          /* static, NUL-terminated */
+//! int32_t     all_smi_plugin_devices(AllSmiDevice *out, size_t capacity);
+//! ```
+//!
+//! `all_smi_plugin_devices` writes up to `capacity` devices to `out` and
+//! returns how many the plugin has, which may be more than `capacity`; the
+//! caller then retries with a larger buffer. A negative return reports a
+//! failure and is logged. Calls into one plugin are never concurrent, but
+//! may come from different threads.
+//!
+//! The layout below is frozen for [`ABI_VERSION`] 1. Any change to it or to
+//! the function signatures gets a new version, and plugins built for
+//! another version are refused at load time.
+
+use std::ffi::c_char;
+
+/// Version of the plugin ABI this build speaks
+pub const ABI_VERSION: u32 = 1;
+
+pub const ABI_VERSION_SYMBOL: &str = "all_smi_plugin_abi_version";
+pub const NAME_SYMBOL: &str = "all_smi_plugin_name";
+pub const DEVICES_SYMBOL: &str = "all_smi_plugin_devices";
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+pub type NameFn = unsafe extern "C" fn() -> *const c_char;
+pub type DevicesFn = unsafe extern "C" fn(out: *mut AllSmiDevice, capacity: usize) -> i32;
+
+/// One device as reported by a plugin. Strings are NUL-terminated UTF-8,
+/// truncated to fit; unknown numeric values are zero.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct AllSmiDevice {
+    /// Stable unique identifier, used to track the device across refreshes
+    pub uuid: [c_char; 64],
+    /// Model name, e.g. "Acme X1"
+    pub name: [c_char; 128],
+    /// Device class shown in the UI and `gpu`/`npu` labels: "GPU" or "NPU"
+    pub device_type: [c_char; 16],
+    /// Driver or firmware version
+    pub driver_version: [c_char; 64],
+    /// Compute utilization in percent
+    pub utilization: f64,
+    /// Celsius
+    pub temperature: u32,
+    /// MHz
+    pub frequency: u32,
+    /// Bytes
+    pub used_memory: u64,
+    /// Bytes
+    pub total_memory: u64,
+    /// Watts
+    pub power_consumption: f64,
+}
+
+impl Default for AllSmiDevice {
+    fn default() -> Self {
+        Self {
+            uuid: [0; 64],
+            name: [0; 128],
+            device_type: [0; 16],
+            driver_version: [0; 64],
+            utilization: 0.0,
+            temperature: 0,
+            frequency: 0,
+            used_memory: 0,
+            total_memory: 0,
+            power_consumption: 0.0,
+        }
+    }
+}
+
+/// Read a fixed-size string field, stopping at the first NUL or the end of
+/// the buffer if the plugin did not terminate it.
+pub fn field_str(field: &[c_char]) -> String {
+    let bytes: Vec<u8> = field
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_str() {
+        let mut field = [0 as c_char; 8];
+        for (slot, byte) in field.iter_mut().zip(b"X1") {
+            *slot = *byte as c_char;
+        }
+        assert_eq!(field_str(&field), "X1");
+        // Unterminated strings end at the buffer
+        assert_eq!(field_str(&[b'a' as c_char; 4]), "aaaa");
+        assert_eq!(field_str(&[0; 4]), "");
+    }
+
+    #[test]
+    fn test_layout_is_frozen() {
+        // Changing this is an ABI break and needs a new ABI_VERSION
+        assert_eq!(std::mem::size_of::<AllSmiDevice>(), 312);
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pods that GPUs are allocated to, from the kubelet PodResources API.
+//!
+//! The kubelet serves `v1.PodResourcesLister` on a Unix socket. Its `List`
+//! response names the devices handed to each container, which maps GPU
+//! UUIDs to pods. The mapping is cached and refreshed every
+//! [`REFRESH_INTERVAL`]; on nodes without the socket nothing is queried.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use hyper_util::rt::TokioIo;
+use tokio::net::UnixStream;
+use tonic::transport::{Endpoint, Uri};
+
+use crate::device::k8s_allocation::{K8S_NAMESPACE_KEY, K8S_POD_KEY};
+use crate::device::{GpuInfo, K8sPod, ProcessInfo};
+
+pub mod pod_resources_proto {
+    tonic::include_proto!("v1");
+}
+
+use pod_resources_proto::pod_resources_lister_client::PodResourcesListerClient;
+use pod_resources_proto::{ListPodResourcesRequest, ListPodResourcesResponse};
+
+/// Socket the kubelet serves the PodResources API on
+pub const KUBELET_POD_RESOURCES_SOCKET: &str = "/var/lib/kubelet/pod-resources/kubelet.sock";
+
+/// How long a fetched mapping is used before asking the kubelet again
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, thiserror::Error)]
+pub enum PodResourcesError {
+    #[error("cannot connect to the kubelet: {0}")]
+    Connect(#[from] tonic::transport::Error),
+    #[error("kubelet List failed: {0}")]
+    List(#[from] tonic::Status),
+}
+
+/// Pods by the device IDs allocated to their containers. Shared
+/// (time-sliced) replicas are reported as `<uuid>::<replica>` and are
+/// mapped back to the GPU UUID.
+pub fn device_assignments(response: &ListPodResourcesResponse) -> HashMap<String, K8sPod> {
+    let mut assignments = HashMap::new();
+    for pod in &response.pod_resources {
+        for container in &pod.containers {
+            let ids = container
+                .devices
+                .iter()
+                .flat_map(|devices| &devices.device_ids);
+            for id in ids {
+                let uuid = id.split_once("::").map_or(id.as_str(), |(uuid, _)| uuid);
+                assignments.insert(
+                    uuid.to_string(),
+                    K8sPod {
+                        namespace: pod.namespace.clone(),
+                        name: pod.name.clone(),
+                        container: container.name.clone(),
+                    },
+                );
+            }
+        }
+    }
+    assignments
+}
+
+/// Ask the kubelet listening on `socket` which devices each container holds.
+pub async fn list_pod_resources(
+    socket: &Path,
+) -> Result<ListPodResourcesResponse, PodResourcesError> {
+    let socket = socket.to_path_buf();
+    // The URI is required but unused; every connection goes to the socket
+    let channel = Endpoint::from_static("http://localhost")
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .connect_with_connector(tower::service_fn(move |_: Uri| {
+            let socket = socket.clone();
+            async move { UnixStream::connect(socket).await.map(TokioIo::new) }
+        }))
+        .await?;
+    let response = PodResourcesListerClient::new(channel)
+        .list(ListPodResourcesRequest {})
+        .await?;
+    Ok(response.into_inner())
+}
+
+/// Cached device-to-pod mapping of one kubelet.
+pub struct PodResourcesWatcher {
+    socket: PathBuf,
+    assignments: HashMap<String, K8sPod>,
+    refreshed_at: Option<Instant>,
+    /// The last refresh failed, so the next failure is not logged again
+    failing: bool,
+}
+
+impl PodResourcesWatcher {
+    pub fn new(socket: PathBuf) -> Self {
+        Self {
+            socket,
+            assignments: HashMap::new(),
+            refreshed_at: None,
+            failing: false,
+        }
+    }
+
+    /// Watcher for the kubelet socket when it exists, or regardless when
+    /// `forced` (`--kubernetes`) in case the kubelet starts later. `None`
+    /// otherwise, so nodes outside Kubernetes do no work at all.
+    pub fn detect(forced: bool) -> Option<Self> {
+        let socket = Path::new(KUBELET_POD_RESOURCES_SOCKET);
+        (forced || socket.exists()).then(|| Self::new(socket.to_path_buf()))
+    }
+
+    /// Fetch the mapping again once it is older than [`REFRESH_INTERVAL`].
+    /// A failed fetch keeps the previous mapping.
+    pub async fn refresh(&mut self) {
+        if self
+            .refreshed_at
+            .is_some_and(|at| at.elapsed() < REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.refreshed_at = Some(Instant::now());
+
+        match list_pod_resources(&self.socket).await {
+            Ok(response) => {
+                self.assignments = device_assignments(&response);
+                self.failing = false;
+            }
+            Err(e) => {
+                if !self.failing {
+                    tracing::warn!(
+                        "Cannot read pod resources from {}: {e}",
+                        self.socket.display()
+                    );
+                }
+                self.failing = true;
+            }
+        }
+    }
+
+    /// [`Self::refresh`] from the synchronous API mode collection pass.
+    pub fn refresh_blocking(&mut self) {
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => tokio::task::block_in_place(|| handle.block_on(self.refresh())),
+            Err(_) => {
+                if let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    runtime.block_on(self.refresh());
+                }
+            }
+        }
+    }
+
+    /// Set `k8s_pod`/`k8s_namespace` on allocated GPUs and the pod on
+    /// processes running on them.
+    pub fn annotate(&self, gpus: &mut [GpuInfo], processes: &mut [ProcessInfo]) {
+        for gpu in gpus {
+            if let Some(pod) = self.assignments.get(&gpu.uuid) {
+                gpu.detail.insert(K8S_POD_KEY.to_string(), pod.name.clone());
+                gpu.detail
+                    .insert(K8S_NAMESPACE_KEY.to_string(), pod.namespace.clone());
+            }
+        }
+        for process in processes {
+            process.k8s_pod = self.assignments.get(&process.device_uuid).cloned();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pod_resources_proto::pod_resources_lister_server::{
+        PodResourcesLister, PodResourcesListerServer,
+    };
+    use super::pod_resources_proto::{ContainerDevices, ContainerResources, PodResources};
+    use super::*;
+    use tokio::net::UnixListener;
+    use tonic::{Request, Response, Status};
+
+    /// Kubelet that answers every `List` with the same response
+    struct MockKubelet(ListPodResourcesResponse);
+
+    #[tonic::async_trait]
+    impl PodResourcesLister for MockKubelet {
+        async fn list(
+            &self,
+            _request: Request<ListPodResourcesRequest>,
+        ) -> Result<Response<ListPodResourcesResponse>, Status> {
+            Ok(Response::new(self.0.clone()))
+        }
+    }
+
+    fn pod(namespace: &str, name: &str, container: &str, ids: &[&str]) -> PodResources {
+        PodResources {
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            containers: vec![ContainerResources {
+                name: container.to_string(),
+                devices: vec![ContainerDevices {
+                    resource_name: "nvidia.com/gpu".to_string(),
+                    device_ids: ids.iter().map(|id| id.to_string()).collect(),
+                }],
+            }],
+        }
+    }
+
+    fn process(pid: u32, device_uuid: &str) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: device_uuid.to_string(),
+            pid,
+            process_name: "python".to_string(),
+            used_memory: 1 << 30,
+            used_memory_estimated: false,
+            cpu_percent: 0.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: "root".to_string(),
+            state: "S".to_string(),
+            start_time: 0,
+            cpu_time: 0,
+            command: "python train.py".to_string(),
+            ppid: 1,
+            threads: 1,
+            uses_gpu: true,
+            priority: 20,
+            nice_value: 0,
+            gpu_utilization: 0.0,
+            k8s_pod: None,
+            host_id: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watcher_maps_gpus_to_pods_from_mock_kubelet() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("kubelet.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        let incoming = futures_util::stream::unfold(listener, |listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
+        let response = ListPodResourcesResponse {
+            pod_resources: vec![
+                pod("ml", "train-0", "trainer", &["GPU-0", "GPU-1"]),
+                pod("serving", "infer-7", "server", &["GPU-3::2"]),
+            ],
+        };
+        let server = tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(PodResourcesListerServer::new(MockKubelet(response)))
+                .serve_with_incoming(incoming),
+        );
+
+        let mut watcher = PodResourcesWatcher::new(socket);
+        watcher.refresh().await;
+        server.abort();
+
+        let mut gpus = vec![
+            GpuInfo::fixture("GPU-0", "node1"),
+            GpuInfo::fixture("GPU-2", "node1"),
+            GpuInfo::fixture("GPU-3", "node1"),
+        ];
+        let mut processes = vec![process(100, "GPU-3"), process(200, "GPU-2")];
+        watcher.annotate(&mut gpus, &mut processes);
+
+        assert_eq!(gpus[0].detail[K8S_POD_KEY], "train-0");
+        assert_eq!(gpus[0].detail[K8S_NAMESPACE_KEY], "ml");
+        assert!(!gpus[1].detail.contains_key(K8S_POD_KEY));
+        // Time-sliced replicas belong to the GPU they are cut from
+        assert_eq!(gpus[2].detail[K8S_POD_KEY], "infer-7");
+        assert_eq!(
+            processes[0].k8s_pod,
+            Some(K8sPod {
+                namespace: "serving".to_string(),
+                name: "infer-7".to_string(),
+                container: "server".to_string(),
+            })
+        );
+        assert_eq!(processes[1].k8s_pod, None);
+
+        // The mapping is cached until the refresh interval passes
+        watcher.refresh().await;
+        assert!(!watcher.failing);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_kubelet_keeps_no_mapping() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut watcher = PodResourcesWatcher::new(dir.path().join("missing.sock"));
+        watcher.refresh().await;
+        assert!(watcher.failing);
+
+        let mut gpus = vec![GpuInfo::fixture("GPU-0", "node1")];
+        watcher.annotate(&mut gpus, &mut []);
+        assert!(gpus[0].detail.is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Power headroom: how far a GPU's draw is from the limit it is held to.
+//!
+//! The enforced limit is the lowest of the limits in effect (the one set
+//! with `nvidia-smi -pl`, in-band and out-of-band caps), so it is what the
+//! driver actually throttles against. Devices that only report the
+//! management limit use that one. A draw above the limit leaves negative
+//! headroom: the device is being capped.
+
+use crate::device::GpuInfo;
+
+/// `GpuInfo::detail` key of the enforced power limit, in watts
+pub const POWER_LIMIT_ENFORCED_KEY: &str = "power_limit_enforced";
+/// `GpuInfo::detail` key of the power management limit, in watts
+pub const POWER_LIMIT_CURRENT_KEY: &str = "power_limit_current";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerHeadroom {
+    /// The limit the headroom is measured against, in watts
+    pub limit_watts: f64,
+    /// Limit minus draw; negative while the device is capped
+    pub headroom_watts: f64,
+}
+
+impl PowerHeadroom {
+    pub fn is_capped(&self) -> bool {
+        self.headroom_watts < 0.0
+    }
+}
+
+/// Headroom of `gpu`, or `None` when it reports no limit or no draw
+pub fn power_headroom(gpu: &GpuInfo) -> Option<PowerHeadroom> {
+    if gpu.power_consumption < 0.0 {
+        return None;
+    }
+    let limit_watts = [POWER_LIMIT_ENFORCED_KEY, POWER_LIMIT_CURRENT_KEY]
+        .iter()
+        .filter_map(|key| gpu.detail.get(*key)?.parse::<f64>().ok())
+        .find(|limit| *limit > 0.0)?;
+    Some(PowerHeadroom {
+        limit_watts,
+        headroom_watts: limit_watts - gpu.power_consumption,
+    })
+}
+
+/// Headroom across the devices that report one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClusterHeadroom {
+    pub average_watts: f64,
+    pub capped: usize,
+    pub devices: usize,
+}
+
+/// `None` when no device reports a limit
+pub fn cluster_headroom(gpus: &[GpuInfo]) -> Option<ClusterHeadroom> {
+    let headrooms: Vec<PowerHeadroom> = gpus.iter().filter_map(power_headroom).collect();
+    if headrooms.is_empty() {
+        return None;
+    }
+    Some(ClusterHeadroom {
+        average_watts: headrooms.iter().map(|h| h.headroom_watts).sum::<f64>()
+            / headrooms.len() as f64,
+        capped: headrooms.iter().filter(|h| h.is_capped()).count(),
+        devices: headrooms.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enforced_limit_takes_precedence() {
+        let headroom = power_headroom(&GpuInfo {
+            power_consumption: 250.0,
+            ..GpuInfo::fixture("GPU-0", "node1").with_detail(&[
+                (POWER_LIMIT_CURRENT_KEY, "700.00"),
+                (POWER_LIMIT_ENFORCED_KEY, "300.00"),
+            ])
+        })
+        .unwrap();
+        assert_eq!(headroom.limit_watts, 300.0);
+        assert_eq!(headroom.headroom_watts, 50.0);
+        assert!(!headroom.is_capped());
+
+        // Without it, the management limit is used
+        let headroom = power_headroom(&GpuInfo {
+            power_consumption: 250.0,
+            ..GpuInfo::fixture("GPU-0", "node1").with_detail(&[(POWER_LIMIT_CURRENT_KEY, "700")])
+        })
+        .unwrap();
+        assert_eq!(headroom.headroom_watts, 450.0);
+    }
+
+    #[test]
+    fn test_draw_above_limit_is_capped() {
+        let headroom = power_headroom(&GpuInfo {
+            power_consumption: 312.5,
+            ..GpuInfo::fixture("GPU-0", "node1").with_detail(&[(POWER_LIMIT_ENFORCED_KEY, "300")])
+        })
+        .unwrap();
+        assert_eq!(headroom.headroom_watts, -12.5);
+        assert!(headroom.is_capped());
+    }
+
+    #[test]
+    fn test_missing_limit_or_draw() {
+        assert_eq!(
+            power_headroom(&GpuInfo {
+                power_consumption: 100.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            }),
+            None
+        );
+        assert_eq!(
+            power_headroom(&GpuInfo {
+                power_consumption: 100.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+                    .with_detail(&[(POWER_LIMIT_ENFORCED_KEY, "N/A")])
+            }),
+            None
+        );
+        assert_eq!(
+            power_headroom(&GpuInfo {
+                power_consumption: -1.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+                    .with_detail(&[(POWER_LIMIT_ENFORCED_KEY, "300")])
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cluster_headroom() {
+        let gpus = [
+            GpuInfo {
+                power_consumption: 250.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+                    .with_detail(&[(POWER_LIMIT_ENFORCED_KEY, "300")])
+            },
+            GpuInfo {
+                power_consumption: 310.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+                    .with_detail(&[(POWER_LIMIT_ENFORCED_KEY, "300")])
+            },
+            GpuInfo {
+                power_consumption: 100.0,
+                ..GpuInfo::fixture("GPU-0", "node1")
+            },
+        ];
+        assert_eq!(
+            cluster_headroom(&gpus),
+            Some(ClusterHeadroom {
+                average_watts: 20.0,
+                capped: 1,
+                devices: 2,
+            })
+        );
+        assert_eq!(cluster_headroom(&gpus[2..]), None);
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Features the current user cannot read.
+//!
+//! all-smi never asks for sudo. NVML, sysfs and procfs serve a normal user
+//! nearly everything; the few sources that need root or group membership
+//! are checked once at startup, and each one that is out of reach is
+//! reported (log, status line, `all_smi_feature_unavailable`) instead of
+//! silently missing.
+
+use std::sync::OnceLock;
+
+static MISSING_FEATURES: OnceLock<Vec<MissingFeature>> = OnceLock::new();
+
+/// Whether the device nodes of a vendor can be opened
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Access {
+    /// No such device nodes
+    #[default]
+    Absent,
+    Granted,
+    Denied,
+}
+
+/// What the current user may read, as far as it matters to the readers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Privileges {
+    /// Effective UID 0
+    pub root: bool,
+    /// AMD GPU nodes under /dev/dri
+    pub amd: Access,
+    /// Intel Gaudi control nodes, opened by hl-smi
+    pub gaudi: Access,
+    /// A device whose processes are found through the open files of every
+    /// process (AMD, Tenstorrent)
+    pub fd_scanned_devices: bool,
+    /// smartctl is installed
+    pub smartctl: bool,
+    /// Power and energy sensors under /sys/class/hwmon
+    pub hwmon_power: Access,
+}
+
+/// A feature left out for lack of privileges
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingFeature {
+    /// Identifier, the `feature` label of `all_smi_feature_unavailable`
+    pub id: &'static str,
+    /// What the user does not get
+    pub name: &'static str,
+    /// Why, and how to get it
+    pub reason: &'static str,
+}
+
+const AMD_GPU: MissingFeature = MissingFeature {
+    id: "amd_gpu",
+    name: "AMD GPU metrics",
+    reason: "/dev/dri is not readable and writable; add the user to the video and render groups",
+};
+
+const GAUDI: MissingFeature = MissingFeature {
+    id: "gaudi",
+    name: "Intel Gaudi metrics",
+    reason: "hl-smi cannot open the Gaudi control devices; run as root",
+};
+
+const SMART_HEALTH: MissingFeature = MissingFeature {
+    id: "smart_health",
+    name: "drive SMART health",
+    reason: "smartctl needs root to open the drives",
+};
+
+const HWMON_POWER: MissingFeature = MissingFeature {
+    id: "hwmon_power",
+    name: "hwmon power readings",
+    reason: "some power sensors under /sys/class/hwmon are readable only by root; run as root",
+};
+
+const OTHER_USERS_PROCESSES: MissingFeature = MissingFeature {
+    id: "other_users_processes",
+    name: "other users' GPU/NPU processes",
+    reason: "their open files under /proc are readable only by root",
+};
+
+/// Features `privileges` rules out, in a fixed order
+pub fn missing_features(privileges: &Privileges) -> Vec<MissingFeature> {
+    let mut missing = Vec::new();
+    if privileges.amd == Access::Denied {
+        missing.push(AMD_GPU);
+    }
+    if privileges.gaudi == Access::Denied {
+        missing.push(GAUDI);
+    }
+    if !privileges.root {
+        if privileges.smartctl {
+            missing.push(SMART_HEALTH);
+        }
+        if privileges.hwmon_power == Access::Denied {
+            missing.push(HWMON_POWER);
+        }
+        if privileges.fd_scanned_devices {
+            missing.push(OTHER_USERS_PROCESSES);
+        }
+    }
+    missing
+}
+
+/// Privileges of this process. Outside Linux nothing needs more than the
+/// current user, so everything is reported absent.
+#[cfg(target_os = "linux")]
+pub fn detect() -> Privileges {
+    use super::platform_detection::{has_gaudi, has_tenstorrent};
+
+    // SAFETY: geteuid(2) takes no arguments and cannot fail
+    let root = unsafe { libc::geteuid() } == 0;
+    // AMD GPUs are only read by glibc builds
+    #[cfg(not(target_env = "musl"))]
+    let amd = super::platform_detection::has_amd();
+    #[cfg(target_env = "musl")]
+    let amd = false;
+
+    Privileges {
+        root,
+        amd: node_access(amd, root, &[("/dev/dri", &["card", "render"])]),
+        gaudi: node_access(
+            has_gaudi(),
+            root,
+            &[
+                ("/dev/accel", &["accel_controlD"]),
+                ("/dev", &["hl_controlD"]),
+            ],
+        ),
+        fd_scanned_devices: amd || has_tenstorrent(),
+        smartctl: in_path("smartctl"),
+        hwmon_power: hwmon_power_access(std::path::Path::new("/sys/class/hwmon")),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn detect() -> Privileges {
+    Privileges::default()
+}
+
+/// Access to a vendor's device nodes, looked up in the first directory of
+/// `nodes` that has any
+#[cfg(target_os = "linux")]
+fn node_access(present: bool, root: bool, nodes: &[(&str, &[&str])]) -> Access {
+    if !present {
+        return Access::Absent;
+    }
+    if root {
+        return Access::Granted;
+    }
+    nodes
+        .iter()
+        .map(|&(dir, prefixes)| device_access(std::path::Path::new(dir), prefixes))
+        .find(|access| *access != Access::Absent)
+        .unwrap_or(Access::Absent)
+}
+
+/// Whether the nodes in `dir` named with one of `prefixes` can be opened
+/// for reading and writing. Granted if any one can.
+#[cfg(target_os = "linux")]
+pub fn device_access(dir: &std::path::Path, prefixes: &[&str]) -> Access {
+    use std::os::unix::ffi::OsStrExt;
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Access::Absent;
+    };
+    let mut access = Access::Absent;
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        if !prefixes
+            .iter()
+            .any(|prefix| name.as_bytes().starts_with(prefix.as_bytes()))
+        {
+            continue;
+        }
+        let Ok(path) = std::ffi::CString::new(entry.path().as_os_str().as_bytes()) else {
+            continue;
+        };
+        // SAFETY: access(2) only reads the NUL-terminated path
+        if unsafe { libc::access(path.as_ptr(), libc::R_OK | libc::W_OK) } == 0 {
+            return Access::Granted;
+        }
+        access = Access::Denied;
+    }
+    access
+}
+
+/// Whether the power and energy sensors of every hwmon device under
+/// `class_hwmon` can be read. Denied if any one cannot, since its readings
+/// go missing.
+#[cfg(target_os = "linux")]
+pub fn hwmon_power_access(class_hwmon: &std::path::Path) -> Access {
+    use std::os::unix::ffi::OsStrExt;
+
+    let Ok(devices) = std::fs::read_dir(class_hwmon) else {
+        return Access::Absent;
+    };
+    let mut access = Access::Absent;
+    for device in devices.flatten() {
+        let Ok(files) = std::fs::read_dir(device.path()) else {
+            continue;
+        };
+        for file in files.flatten() {
+            let name = file.file_name();
+            let name = name.to_string_lossy();
+            let is_power = (name.starts_with("power") || name.starts_with("energy"))
+                && (name.ends_with("_input") || name.ends_with("_average"));
+            if !is_power {
+                continue;
+            }
+            let Ok(path) = std::ffi::CString::new(file.path().as_os_str().as_bytes()) else {
+                continue;
+            };
+            // SAFETY: access(2) only reads the NUL-terminated path
+            if unsafe { libc::access(path.as_ptr(), libc::R_OK) } != 0 {
+                return Access::Denied;
+            }
+            access = Access::Granted;
+        }
+    }
+    access
+}
+
+#[cfg(target_os = "linux")]
+fn in_path(program: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+}
+
+/// Detect the missing features and log each one. Later calls return the
+/// first result.
+pub fn init_missing_features() -> &'static [MissingFeature] {
+    MISSING_FEATURES.get_or_init(|| {
+        let missing = missing_features(&detect());
+        for feature in &missing {
+            tracing::warn!("{} unavailable: {}", feature.name, feature.reason);
+        }
+        missing
+    })
+}
+
+/// Features found missing by [`init_missing_features`]; empty before it
+/// runs, e.g. while replaying a recording.
+pub fn unavailable_features() -> &'static [MissingFeature] {
+    MISSING_FEATURES
+        .get()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// One status line naming the missing features, `None` if there are none
+pub fn missing_features_summary(missing: &[MissingFeature]) -> Option<String> {
+    if missing.is_empty() {
+        return None;
+    }
+    let names: Vec<&str> = missing.iter().map(|feature| feature.name).collect();
+    Some(format!(
+        "Unavailable to this user: {}. Press L for details",
+        names.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(privileges: Privileges) -> Vec<&'static str> {
+        missing_features(&privileges)
+            .iter()
+            .map(|feature| feature.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_missing_features_matrix() {
+        let user = Privileges::default();
+        // A normal user on an NVIDIA box without smartctl misses nothing
+        assert!(ids(user).is_empty());
+
+        let with_devices = Privileges {
+            amd: Access::Denied,
+            gaudi: Access::Denied,
+            fd_scanned_devices: true,
+            smartctl: true,
+            hwmon_power: Access::Denied,
+            ..user
+        };
+        assert_eq!(
+            ids(with_devices),
+            [
+                "amd_gpu",
+                "gaudi",
+                "smart_health",
+                "hwmon_power",
+                "other_users_processes"
+            ]
+        );
+
+        // Group membership grants the devices but not other users' files
+        let in_groups = Privileges {
+            amd: Access::Granted,
+            gaudi: Access::Granted,
+            hwmon_power: Access::Granted,
+            ..with_devices
+        };
+        assert_eq!(ids(in_groups), ["smart_health", "other_users_processes"]);
+
+        // Root-only power sensors are missed until running as root
+        let hwmon_denied = Privileges {
+            hwmon_power: Access::Denied,
+            ..in_groups
+        };
+        assert_eq!(
+            ids(hwmon_denied),
+            ["smart_health", "hwmon_power", "other_users_processes"]
+        );
+        assert!(ids(Privileges {
+            root: true,
+            ..hwmon_denied
+        })
+        .is_empty());
+
+        let no_smartctl = Privileges {
+            smartctl: false,
+            ..in_groups
+        };
+        assert_eq!(ids(no_smartctl), ["other_users_processes"]);
+
+        let root = Privileges {
+            root: true,
+            ..in_groups
+        };
+        assert!(ids(root).is_empty());
+
+        // Devices that are not there are never reported
+        let absent = Privileges {
+            amd: Access::Absent,
+            gaudi: Access::Absent,
+            ..user
+        };
+        assert!(ids(absent).is_empty());
+    }
+
+    #[test]
+    fn test_missing_features_summary() {
+        assert_eq!(missing_features_summary(&[]), None);
+        assert_eq!(
+            missing_features_summary(&[AMD_GPU, SMART_HEALTH]).as_deref(),
+            Some("Unavailable to this user: AMD GPU metrics, drive SMART health. Press L for details")
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_device_access() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(device_access(dir.path(), &["render"]), Access::Absent);
+        assert_eq!(
+            device_access(&dir.path().join("missing"), &["render"]),
+            Access::Absent
+        );
+
+        std::fs::write(dir.path().join("renderD128"), "").unwrap();
+        std::fs::write(dir.path().join("other"), "").unwrap();
+        assert_eq!(device_access(dir.path(), &["card"]), Access::Absent);
+        assert_eq!(
+            device_access(dir.path(), &["card", "render"]),
+            Access::Granted
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_hwmon_power_access() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(hwmon_power_access(dir.path()), Access::Absent);
+
+        let hwmon = dir.path().join("hwmon0");
+        std::fs::create_dir(&hwmon).unwrap();
+        std::fs::write(hwmon.join("temp1_input"), "42000").unwrap();
+        assert_eq!(hwmon_power_access(dir.path()), Access::Absent);
+
+        std::fs::write(hwmon.join("power1_average"), "35000000").unwrap();
+        assert_eq!(hwmon_power_access(dir.path()), Access::Granted);
+    }
+}
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use crate::device::types::ProcessInfo;
+use crate::utils::system::with_global_system;
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
-use sysinfo::{ProcessStatus, System};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, System};
 
 /// Get all system processes with GPU usage information
 pub fn get_all_processes(system: &System, gpu_pids: &HashSet<u32>) -> Vec<ProcessInfo> {
@@ -39,13 +41,14 @@ pub fn get_all_processes(system: &System, gpu_pids: &HashSet<u32>) -> Vec<Proces
             pid: pid_u32,
             process_name: process.name().to_string_lossy().to_string(),
             used_memory: 0, // GPU memory, will be set by GPU-specific code
+            used_memory_estimated: false,
             cpu_percent: process.cpu_usage() as f64,
             memory_percent: (process.memory() as f64 / system.total_memory() as f64) * 100.0,
             memory_rss: process.memory(),         // Already in bytes
             memory_vms: process.virtual_memory(), // Already in bytes
             user: get_process_user(process),
             state: convert_process_state(process.status()),
-            start_time: format!("{}", process.start_time()),
+            start_time: process.start_time(),
             cpu_time: process.run_time(),
             command: get_process_command(process),
             ppid: process.parent().map(|p| p.as_u32()).unwrap_or(0),
@@ -54,6 +57,8 @@ pub fn get_all_processes(system: &System, gpu_pids: &HashSet<u32>) -> Vec<Proces
             priority,
             nice_value,
             gpu_utilization: 0.0, // Will be set by GPU-specific code
+            k8s_pod: None,
+            host_id: String::new(),
         };
 
         processes.push(process_info);
@@ -82,7 +87,11 @@ pub fn update_process_cache(
 
         let uses_gpu = gpu_pids.contains(&pid_u32);
 
-        if let Some(cached) = cache.get_mut(&pid_u32) {
+        // A different start time means the PID was recycled by a new process
+        if let Some(cached) = cache
+            .get_mut(&pid_u32)
+            .filter(|cached| cached.start_time == process.start_time())
+        {
             // Update existing entry - only update dynamic fields to reduce allocations
             cached.cpu_percent = process.cpu_usage() as f64;
             cached.memory_percent = (process.memory() as f64 / total_memory as f64) * 100.0;
@@ -110,13 +119,14 @@ pub fn update_process_cache(
                 pid: pid_u32,
                 process_name: process.name().to_string_lossy().to_string(),
                 used_memory: 0,
+                used_memory_estimated: false,
                 cpu_percent: process.cpu_usage() as f64,
                 memory_percent: (process.memory() as f64 / total_memory as f64) * 100.0,
                 memory_rss: process.memory(),
                 memory_vms: process.virtual_memory(),
                 user: get_process_user(process),
                 state: convert_process_state(process.status()),
-                start_time: format!("{}", process.start_time()),
+                start_time: process.start_time(),
                 cpu_time: process.run_time(),
                 command: get_process_command(process),
                 ppid: process.parent().map(|p| p.as_u32()).unwrap_or(0),
@@ -125,6 +135,8 @@ pub fn update_process_cache(
                 priority,
                 nice_value,
                 gpu_utilization: 0.0,
+                k8s_pod: None,
+                host_id: String::new(),
             };
             cache.insert(pid_u32, process_info);
         }
@@ -160,24 +172,82 @@ fn convert_process_state(status: ProcessStatus) -> String {
 
 /// Get process user name
 fn get_process_user(process: &sysinfo::Process) -> String {
-    if let Some(user_id) = process.user_id() {
-        // Try to get username from user ID
+    match process.user_id() {
         #[cfg(unix)]
-        {
-            use std::ffi::CStr;
-            unsafe {
-                let passwd = libc::getpwuid(**user_id);
-                if !passwd.is_null() {
-                    if let Ok(name) = CStr::from_ptr((*passwd).pw_name).to_str() {
-                        return name.to_string();
-                    }
-                }
-            }
+        Some(user_id) => username_for_uid(**user_id),
+        #[cfg(not(unix))]
+        Some(user_id) => user_id.to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+#[cfg(unix)]
+static USERNAME_CACHE: std::sync::OnceLock<std::sync::Mutex<HashMap<u32, String>>> =
+    std::sync::OnceLock::new();
+
+/// User name for `uid`, looked up once per uid. A uid without a passwd entry
+/// (e.g. a deleted user) is shown as the number itself.
+#[cfg(unix)]
+fn username_for_uid(uid: u32) -> String {
+    let cache = USERNAME_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry(uid)
+        .or_insert_with(|| lookup_username(uid).unwrap_or_else(|| uid.to_string()))
+        .clone()
+}
+
+#[cfg(unix)]
+fn lookup_username(uid: u32) -> Option<String> {
+    use std::ffi::CStr;
+    // SAFETY: getpwuid returns null or a pointer to a static passwd entry,
+    // which is copied out before any other passwd call can overwrite it
+    unsafe {
+        let passwd = libc::getpwuid(uid);
+        if passwd.is_null() {
+            return None;
         }
-        user_id.to_string()
-    } else {
-        "unknown".to_string()
+        CStr::from_ptr((*passwd).pw_name)
+            .to_str()
+            .ok()
+            .map(str::to_string)
+    }
+}
+
+/// Processes of one user added up, one row of the per-user process view
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserProcessSummary {
+    pub user: String,
+    pub process_count: usize,
+    /// GPU memory used by all of the user's processes, in bytes
+    pub gpu_memory: u64,
+    pub cpu_percent: f64,
+}
+
+/// Group processes by user, most GPU memory first and then by name.
+pub fn summarize_by_user(processes: &[ProcessInfo]) -> Vec<UserProcessSummary> {
+    let mut by_user: HashMap<&str, UserProcessSummary> = HashMap::new();
+    for process in processes {
+        let summary = by_user
+            .entry(process.user.as_str())
+            .or_insert_with(|| UserProcessSummary {
+                user: process.user.clone(),
+                process_count: 0,
+                gpu_memory: 0,
+                cpu_percent: 0.0,
+            });
+        summary.process_count += 1;
+        summary.gpu_memory += process.used_memory;
+        summary.cpu_percent += process.cpu_percent;
     }
+
+    let mut summaries: Vec<UserProcessSummary> = by_user.into_values().collect();
+    summaries.sort_by(|a, b| {
+        Reverse(a.gpu_memory)
+            .cmp(&Reverse(b.gpu_memory))
+            .then_with(|| a.user.cmp(&b.user))
+    });
+    summaries
 }
 
 /// Get process command line
@@ -258,6 +328,50 @@ fn get_process_priority_nice(pid: u32) -> (i32, i32) {
     (20, 0)
 }
 
+/// Fill in `start_time` for processes reported by vendor tools, which only
+/// know the PID. Processes that have already exited keep a start time of 0.
+pub fn fill_start_times(processes: &mut [ProcessInfo]) {
+    let pids: Vec<Pid> = processes
+        .iter()
+        .filter(|p| p.start_time == 0)
+        .map(|p| Pid::from_u32(p.pid))
+        .collect();
+    if pids.is_empty() {
+        return;
+    }
+
+    with_global_system(|system| {
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&pids),
+            false,
+            ProcessRefreshKind::nothing(),
+        );
+        for process in processes.iter_mut().filter(|p| p.start_time == 0) {
+            if let Some(sys_process) = system.process(Pid::from_u32(process.pid)) {
+                process.start_time = sys_process.start_time();
+            }
+        }
+    });
+}
+
+/// Split `total` bytes of device memory across GPU client processes in
+/// proportion to their physical footprint, never attributing more than a
+/// process's own footprint. For platforms that only report a device-wide
+/// figure, so the results are estimates.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn apportion_gpu_memory(total: u64, footprints: &[(u32, u64)]) -> Vec<(u32, u64)> {
+    let footprint_sum: u128 = footprints.iter().map(|&(_, bytes)| bytes as u128).sum();
+    footprints
+        .iter()
+        .map(|&(pid, footprint)| {
+            let share = (total as u128 * footprint as u128)
+                .checked_div(footprint_sum)
+                .unwrap_or(0) as u64;
+            (pid, share.min(footprint))
+        })
+        .collect()
+}
+
 /// Merge GPU process information with system process list
 pub fn merge_gpu_processes(all_processes: &mut [ProcessInfo], gpu_processes: Vec<ProcessInfo>) {
     // Create a map of GPU processes by PID
@@ -270,8 +384,133 @@ pub fn merge_gpu_processes(all_processes: &mut [ProcessInfo], gpu_processes: Vec
             process.device_id = gpu_process.device_id;
             process.device_uuid = gpu_process.device_uuid.clone();
             process.used_memory = gpu_process.used_memory;
+            process.used_memory_estimated = gpu_process.used_memory_estimated;
             process.gpu_utilization = gpu_process.gpu_utilization;
             process.uses_gpu = true;
         }
     }
 }
+
+/// Keep the `limit` processes using the most GPU memory, ordered by memory
+/// descending and then by PID so the selection is stable across scrapes.
+/// Processes on a device come before the others at the same memory, so NPU
+/// processes whose memory is not reported (0) are kept.
+pub fn retain_top_processes(processes: &mut Vec<ProcessInfo>, limit: usize) {
+    processes.sort_by_key(|p| (Reverse(p.used_memory), !p.uses_gpu, p.pid, p.start_time));
+    processes.truncate(limit);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn process(pid: u32) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: String::new(),
+            pid,
+            process_name: format!("proc{pid}"),
+            used_memory: 0,
+            used_memory_estimated: false,
+            cpu_percent: 0.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: String::new(),
+            state: "S".to_string(),
+            start_time: 0,
+            cpu_time: 0,
+            command: String::new(),
+            ppid: 1,
+            threads: 1,
+            uses_gpu: false,
+            priority: 20,
+            nice_value: 0,
+            gpu_utilization: 0.0,
+            k8s_pod: None,
+            host_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_summarize_by_user() {
+        let owned = |pid, user: &str, used_memory, cpu_percent| ProcessInfo {
+            user: user.to_string(),
+            used_memory,
+            cpu_percent,
+            ..process(pid)
+        };
+        let processes = vec![
+            owned(1, "alice", 1 << 30, 50.0),
+            owned(2, "bob", 4 << 30, 100.0),
+            owned(3, "alice", 2 << 30, 25.5),
+            owned(4, "carol", 0, 1.0),
+            owned(5, "1001", 0, 2.0),
+        ];
+
+        let summaries = summarize_by_user(&processes);
+        let users: Vec<&str> = summaries.iter().map(|s| s.user.as_str()).collect();
+        assert_eq!(users, vec!["bob", "alice", "1001", "carol"]);
+
+        assert_eq!(summaries[1].process_count, 2);
+        assert_eq!(summaries[1].gpu_memory, 3 << 30);
+        assert!((summaries[1].cpu_percent - 75.5).abs() < 1e-9);
+        assert_eq!(summaries[0].process_count, 1);
+        assert!(summarize_by_user(&[]).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unknown_uid_renders_as_number() {
+        // No system assigns a passwd entry to the highest uid
+        let uid = u32::MAX - 1;
+        assert_eq!(username_for_uid(uid), uid.to_string());
+        assert_eq!(username_for_uid(0), "root");
+    }
+
+    #[test]
+    fn test_apportion_gpu_memory() {
+        let shares = apportion_gpu_memory(600, &[(1, 100), (2, 300), (3, 200)]);
+        assert_eq!(shares, vec![(1, 100), (2, 300), (3, 200)]);
+
+        // The device total is split by footprint share
+        let shares = apportion_gpu_memory(300, &[(1, 1000), (2, 2000)]);
+        assert_eq!(shares, vec![(1, 100), (2, 200)]);
+
+        // Nobody gets more than its own footprint
+        let shares = apportion_gpu_memory(10_000, &[(1, 50), (2, 150)]);
+        assert_eq!(shares, vec![(1, 50), (2, 150)]);
+
+        assert_eq!(apportion_gpu_memory(1000, &[(1, 0)]), vec![(1, 0)]);
+        assert!(apportion_gpu_memory(1000, &[]).is_empty());
+    }
+
+    /// Estimating and merging GPU memory for a typical 300-process system
+    /// must stay well within a collection cycle. The bound is loose enough
+    /// for unoptimized builds on shared CI runners.
+    #[test]
+    fn test_gpu_memory_merge_latency() {
+        const ROUNDS: u32 = 50;
+        let footprints: Vec<(u32, u64)> = (0..40).map(|pid| (pid * 7, 1 << 26)).collect();
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let mut all_processes: Vec<ProcessInfo> = (0..300).map(process).collect();
+            let gpu_processes = apportion_gpu_memory(1 << 31, &footprints)
+                .into_iter()
+                .map(|(pid, used_memory)| ProcessInfo {
+                    used_memory,
+                    used_memory_estimated: true,
+                    ..process(pid)
+                })
+                .collect();
+            merge_gpu_processes(&mut all_processes, gpu_processes);
+            assert!(all_processes[7].used_memory_estimated);
+        }
+        let per_round = start.elapsed() / ROUNDS;
+        assert!(
+            per_round < Duration::from_millis(5),
+            "GPU memory merge took {per_round:?} per round"
+        );
+    }
+}
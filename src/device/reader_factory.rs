@@ -12,13 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use crate::device::{
+    device_order::DeviceOrder,
+    device_selection::{DeviceFilter, SelectedGpuReader},
     platform_detection::{
         get_os_type, has_furiosa, has_gaudi, has_nvidia, has_rebellions, is_jetson,
     },
-    readers::{furiosa, gaudi, nvidia, nvidia_jetson, rebellions},
+    reader_supervisor::DEFAULT_READER_TIMEOUT,
+    readers::{furiosa, gaudi, nvidia, nvidia_jetson, plugin, rebellions},
     traits::{CpuReader, GpuReader, MemoryReader},
+    GpuInfo,
 };
+use crate::network::NetworkReader;
 
 #[cfg(target_os = "linux")]
 use crate::device::platform_detection::{has_google_tpu, has_tenstorrent};
@@ -39,7 +46,7 @@ use crate::device::{cpu_linux, memory_linux};
 use crate::device::{cpu_windows, memory_windows};
 
 #[cfg(target_os = "windows")]
-use crate::device::readers::amd_windows;
+use crate::device::readers::{amd_windows, windows_gpu};
 
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
 use crate::device::platform_detection::has_amd;
@@ -47,12 +54,183 @@ use crate::device::platform_detection::has_amd;
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
 use crate::device::readers::amd;
 
+/// Collection subsystems that can run on their own interval in API mode.
+/// GPU processes are collected with `gpu`, InfiniBand ports with `network`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Subsystem {
+    Gpu,
+    Cpu,
+    Memory,
+    Disk,
+    Network,
+    Chassis,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 6] = [
+        Subsystem::Gpu,
+        Subsystem::Cpu,
+        Subsystem::Memory,
+        Subsystem::Disk,
+        Subsystem::Network,
+        Subsystem::Chassis,
+    ];
+
+    /// Name used on the command line, in the config file and as the
+    /// `collector` label of `all_smi_last_collection_timestamp_seconds`
+    pub fn name(self) -> &'static str {
+        match self {
+            Subsystem::Gpu => "gpu",
+            Subsystem::Cpu => "cpu",
+            Subsystem::Memory => "memory",
+            Subsystem::Disk => "disk",
+            Subsystem::Network => "network",
+            Subsystem::Chassis => "chassis",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|subsystem| subsystem.name() == name)
+    }
+}
+
+/// Seconds between collections of each subsystem: the global interval
+/// unless a subsystem has its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubsystemIntervals {
+    default: u64,
+    overrides: [Option<u64>; Subsystem::ALL.len()],
+}
+
+impl SubsystemIntervals {
+    pub fn new(default: u64) -> Self {
+        Self {
+            default,
+            overrides: [None; Subsystem::ALL.len()],
+        }
+    }
+
+    /// Collect `subsystem` every `secs` seconds instead of the default.
+    pub fn with(mut self, subsystem: Subsystem, secs: u64) -> Self {
+        self.overrides[subsystem as usize] = Some(secs);
+        self
+    }
+
+    pub fn get(&self, subsystem: Subsystem) -> u64 {
+        self.overrides[subsystem as usize].unwrap_or(self.default)
+    }
+
+    /// The interval of `collector`, a [`Subsystem::name`], or the global
+    /// interval for other names
+    pub fn for_collector(&self, collector: &str) -> u64 {
+        Subsystem::from_name(collector).map_or(self.default, |subsystem| self.get(subsystem))
+    }
+}
+
+/// Subsystems to collect. Disabled ones get no readers, so neither the TUI
+/// nor the API exporters see any data for them. Their TUI sections take no
+/// rows; the dashboard keeps their tiles and shows them as having no data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionFilter {
+    pub gpu: bool,
+    pub cpu: bool,
+    pub disk: bool,
+    pub net: bool,
+    /// Include the loopback interface in network metrics
+    pub loopback: bool,
+    /// Mark which GPUs Kubernetes has allocated to pods
+    pub k8s: bool,
+    /// Only collect the devices selected by index or UUID
+    pub devices: Option<DeviceFilter>,
+    /// Order devices are exported in
+    pub order: DeviceOrder,
+    /// How long a reader call may take before its previous readings are
+    /// served instead
+    pub reader_timeout: Duration,
+}
+
+impl Default for CollectionFilter {
+    fn default() -> Self {
+        Self {
+            gpu: true,
+            cpu: true,
+            disk: true,
+            net: true,
+            loopback: false,
+            k8s: false,
+            devices: None,
+            order: DeviceOrder::default(),
+            reader_timeout: DEFAULT_READER_TIMEOUT,
+        }
+    }
+}
+
+impl CollectionFilter {
+    pub fn gpu_readers(&self) -> Vec<Box<dyn GpuReader>> {
+        if !self.gpu {
+            return Vec::new();
+        }
+        self.select_gpu_readers(get_gpu_readers())
+    }
+
+    /// `readers` as one reader of the `--devices` selection, if any
+    fn select_gpu_readers(&self, readers: Vec<Box<dyn GpuReader>>) -> Vec<Box<dyn GpuReader>> {
+        match &self.devices {
+            Some(filter) => vec![Box::new(SelectedGpuReader::new(readers, filter.clone()))],
+            None => readers,
+        }
+    }
+
+    /// Whether every `--devices` index and `--device-uuid` exists on this
+    /// host
+    pub fn check_devices(&self) -> Result<(), String> {
+        let Some(filter) = self.devices.as_ref().filter(|_| self.gpu) else {
+            return Ok(());
+        };
+        let devices: Vec<GpuInfo> = get_gpu_readers()
+            .iter()
+            .flat_map(|reader| reader.get_gpu_info())
+            .collect();
+        filter.check(&devices)
+    }
+
+    pub fn cpu_readers(&self) -> Vec<Box<dyn CpuReader>> {
+        if self.cpu {
+            get_cpu_readers()
+        } else {
+            Vec::new()
+        }
+    }
+
+    pub fn network_reader(&self) -> Option<NetworkReader> {
+        self.net.then(|| NetworkReader::new(self.loopback))
+    }
+}
+
 pub fn get_gpu_readers() -> Vec<Box<dyn GpuReader>> {
-    let mut readers: Vec<Box<dyn GpuReader>> = Vec::new();
+    get_named_gpu_readers()
+        .into_iter()
+        .map(|(_, reader)| reader)
+        .collect()
+}
+
+/// The GPU/NPU readers of this host with the name of each, e.g. `nvidia`
+/// or `gaudi`, as listed by `all-smi doctor`
+pub fn get_named_gpu_readers() -> Vec<(&'static str, Box<dyn GpuReader>)> {
+    // Plugins were asked for explicitly, so they are kept even when
+    // detection is skipped
+    let mut readers: Vec<(&'static str, Box<dyn GpuReader>)> = plugin::plugin_readers()
+        .into_iter()
+        .map(|reader| ("plugin", reader))
+        .collect();
 
     // Check if GPU detection should be skipped (useful for containers)
     if std::env::var("SKIP_GPU_DETECTION").is_ok() || std::env::var("NO_GPU").is_ok() {
-        eprintln!("GPU detection skipped (SKIP_GPU_DETECTION or NO_GPU environment variable set)");
+        tracing::info!(
+            "GPU detection skipped (SKIP_GPU_DETECTION or NO_GPU environment variable set)"
+        );
         return readers;
     }
 
@@ -62,50 +240,60 @@ pub fn get_gpu_readers() -> Vec<Box<dyn GpuReader>> {
         "linux" => {
             // Only create NVIDIA reader if we actually have NVIDIA GPUs
             if is_jetson() && has_nvidia() {
-                readers.push(Box::new(nvidia_jetson::NvidiaJetsonGpuReader::new()));
+                readers.push((
+                    "nvidia_jetson",
+                    Box::new(nvidia_jetson::NvidiaJetsonGpuReader::new()),
+                ));
             } else if has_nvidia() && !is_jetson() {
-                readers.push(Box::new(nvidia::NvidiaGpuReader::new()));
+                readers.push(("nvidia", Box::new(nvidia::NvidiaGpuReader::new())));
             }
 
             // Check for Furiosa NPU support
             if has_furiosa() {
-                readers.push(Box::new(furiosa::FuriosaNpuReader::new()));
+                readers.push(("furiosa", Box::new(furiosa::FuriosaNpuReader::new())));
             }
 
             // Check for Tenstorrent NPU support
             #[cfg(target_os = "linux")]
             if has_tenstorrent() {
-                readers.push(Box::new(tenstorrent::TenstorrentReader::new()));
+                readers.push((
+                    "tenstorrent",
+                    Box::new(tenstorrent::TenstorrentReader::new()),
+                ));
             }
 
             // Check for Rebellions NPU support
             if has_rebellions() {
-                readers.push(Box::new(rebellions::RebellionsNpuReader::new()));
+                readers.push((
+                    "rebellions",
+                    Box::new(rebellions::RebellionsNpuReader::new()),
+                ));
             }
 
             // Check for Intel Gaudi NPU support
             if has_gaudi() {
-                readers.push(Box::new(gaudi::GaudiNpuReader::new()));
+                readers.push(("gaudi", Box::new(gaudi::GaudiNpuReader::new())));
             }
 
             // Check for Google TPU support
             #[cfg(target_os = "linux")]
             if has_google_tpu() {
-                readers.push(Box::new(google_tpu::GoogleTpuReader::new()));
+                readers.push(("google_tpu", Box::new(google_tpu::GoogleTpuReader::new())));
             }
 
             // Check for AMD GPU support (glibc only, not musl))
             #[cfg(all(target_os = "linux", not(target_env = "musl")))]
             if has_amd() {
-                readers.push(Box::new(amd::AmdGpuReader::new()));
+                readers.push(("amd", Box::new(amd::AmdGpuReader::new())));
             }
         }
         "macos" => {
             #[cfg(target_os = "macos")]
             if is_apple_silicon() {
                 // Use native APIs (no sudo required)
-                readers.push(Box::new(
-                    apple_silicon_native::AppleSiliconNativeGpuReader::new(),
+                readers.push((
+                    "apple_silicon",
+                    Box::new(apple_silicon_native::AppleSiliconNativeGpuReader::new()),
                 ));
             }
         }
@@ -113,17 +301,32 @@ pub fn get_gpu_readers() -> Vec<Box<dyn GpuReader>> {
             #[cfg(target_os = "windows")]
             {
                 // Check for NVIDIA GPU on Windows
-                if has_nvidia() {
-                    readers.push(Box::new(nvidia::NvidiaGpuReader::new()));
+                let nvml_available = has_nvidia();
+                if nvml_available {
+                    readers.push(("nvidia", Box::new(nvidia::NvidiaGpuReader::new())));
                 }
 
-                // Check for AMD GPU on Windows (including APU)
-                if amd_windows::has_amd_gpu_windows() {
-                    readers.push(Box::new(amd_windows::AmdWindowsGpuReader::new()));
+                // Intel/AMD (and NVIDIA without NVML) via DXGI + PDH counters.
+                // Fall back to the WMI-based AMD reader if DXGI is unavailable.
+                let skip_vendor_ids = if nvml_available {
+                    vec![windows_gpu::VENDOR_ID_NVIDIA]
+                } else {
+                    Vec::new()
+                };
+                if windows_gpu::has_dxgi_adapters(&skip_vendor_ids) {
+                    readers.push((
+                        "windows_gpu",
+                        Box::new(windows_gpu::WindowsGpuReader::new(skip_vendor_ids)),
+                    ));
+                } else if amd_windows::has_amd_gpu_windows() {
+                    readers.push((
+                        "amd_windows",
+                        Box::new(amd_windows::AmdWindowsGpuReader::new()),
+                    ));
                 }
             }
         }
-        _ => println!("Unsupported OS type: {os_type}"),
+        _ => tracing::warn!("Unsupported OS type: {os_type}"),
     }
 
     readers
@@ -147,7 +350,7 @@ pub fn get_cpu_readers() -> Vec<Box<dyn CpuReader>> {
             #[cfg(target_os = "windows")]
             readers.push(Box::new(cpu_windows::WindowsCpuReader::new()));
         }
-        _ => println!("CPU monitoring not supported for OS type: {os_type}"),
+        _ => tracing::warn!("CPU monitoring not supported for OS type: {os_type}"),
     }
 
     readers
@@ -171,8 +374,88 @@ pub fn get_memory_readers() -> Vec<Box<dyn MemoryReader>> {
             #[cfg(target_os = "windows")]
             readers.push(Box::new(memory_windows::WindowsMemoryReader::new()));
         }
-        _ => println!("Memory monitoring not supported for OS type: {os_type}"),
+        _ => tracing::warn!("Memory monitoring not supported for OS type: {os_type}"),
     }
 
     readers
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::SubsystemArgs;
+    use crate::device::ProcessInfo;
+
+    struct FakeReader(Vec<&'static str>);
+
+    impl GpuReader for FakeReader {
+        fn get_gpu_info(&self) -> Vec<GpuInfo> {
+            self.0
+                .iter()
+                .map(|uuid| GpuInfo::fixture(uuid, "node1"))
+                .collect()
+        }
+
+        fn get_process_info(&self) -> Vec<ProcessInfo> {
+            Vec::new()
+        }
+    }
+
+    fn fake_readers() -> Vec<Box<dyn GpuReader>> {
+        vec![
+            Box::new(FakeReader(vec!["GPU-0", "GPU-1"])),
+            Box::new(FakeReader(vec!["NPU-0"])),
+        ]
+    }
+
+    fn uuids(readers: &[Box<dyn GpuReader>]) -> Vec<String> {
+        readers
+            .iter()
+            .flat_map(|reader| reader.get_gpu_info())
+            .map(|info| info.uuid)
+            .collect()
+    }
+
+    #[test]
+    fn test_disabled_subsystems_get_no_readers() {
+        let filter = SubsystemArgs {
+            no_gpu: true,
+            no_cpu: true,
+            no_net: true,
+            devices: Some("0".parse().unwrap()),
+            ..Default::default()
+        }
+        .collection_filter();
+        assert!(filter.gpu_readers().is_empty());
+        assert!(filter.cpu_readers().is_empty());
+        assert!(filter.network_reader().is_none());
+        // --devices is not checked against a host whose GPUs are skipped
+        assert!(filter.check_devices().is_ok());
+
+        let filter = SubsystemArgs {
+            no_cpu: true,
+            ..Default::default()
+        }
+        .collection_filter();
+        assert!(filter.gpu && !filter.cpu && filter.net);
+    }
+
+    #[test]
+    fn test_devices_wrap_the_gpu_readers() {
+        let all = SubsystemArgs::default()
+            .collection_filter()
+            .select_gpu_readers(fake_readers());
+        assert_eq!(all.len(), 2);
+        assert_eq!(uuids(&all), ["GPU-0", "GPU-1", "NPU-0"]);
+
+        let filter = SubsystemArgs {
+            devices: Some("0".parse().unwrap()),
+            device_uuid: vec!["npu-0".to_string()],
+            ..Default::default()
+        }
+        .collection_filter();
+        let selected = filter.select_gpu_readers(fake_readers());
+        assert_eq!(selected.len(), 1);
+        assert_eq!(uuids(&selected), ["GPU-0", "NPU-0"]);
+    }
+}
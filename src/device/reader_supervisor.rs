@@ -0,0 +1,377 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deadlines for device readers. A wedged driver can block a reader call
+//! forever; the supervisor runs each call on the blocking pool, gives up on
+//! it after the reader timeout, serves the reader's previous readings
+//! instead and quarantines readers that keep timing out.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures_util::future::join_all;
+
+/// Default for `--reader-timeout`
+pub const DEFAULT_READER_TIMEOUT: Duration = Duration::from_secs(5);
+/// Timeouts in a row after which a reader is quarantined
+const QUARANTINE_AFTER: u32 = 3;
+/// First quarantine; each further one in a row doubles it
+const QUARANTINE: Duration = Duration::from_secs(30);
+/// Longest quarantine
+const MAX_QUARANTINE: Duration = Duration::from_secs(600);
+
+/// Why a reader's readings in a pass are not fresh
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReaderFault {
+    /// Missed the deadline, or its previous call is still stuck
+    TimedOut { reader: String, after: Duration },
+    /// Timed out three times in a row and is skipped for `backoff`
+    Quarantined { reader: String, backoff: Duration },
+    /// Still in quarantine
+    Skipped { reader: String },
+}
+
+impl ReaderFault {
+    /// Whether the reader was quarantined in this pass, which is worth a
+    /// visible warning rather than just a log line.
+    pub fn is_new_quarantine(&self) -> bool {
+        matches!(self, ReaderFault::Quarantined { .. })
+    }
+}
+
+impl fmt::Display for ReaderFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReaderFault::TimedOut { reader, after } => {
+                write!(f, "{reader} timed out after {}s", after.as_secs_f64())
+            }
+            ReaderFault::Quarantined { reader, backoff } => write!(
+                f,
+                "{reader} keeps timing out; skipping it for {}s",
+                backoff.as_secs()
+            ),
+            ReaderFault::Skipped { reader } => write!(f, "{reader} is quarantined"),
+        }
+    }
+}
+
+/// Readings of one supervised call across all readers, in reader order
+pub struct Collected<T> {
+    /// Each reader's readings and whether they are left over from an
+    /// earlier pass
+    readings: Vec<(Vec<T>, bool)>,
+    pub faults: Vec<ReaderFault>,
+}
+
+impl<T> Collected<T> {
+    /// Whether every reader answered in time
+    pub fn is_complete(&self) -> bool {
+        self.faults.is_empty()
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.into_values_marked(|_| {})
+    }
+
+    /// All readings, with `mark` applied to the ones left over from an
+    /// earlier pass.
+    pub fn into_values_marked(self, mark: impl Fn(&mut T)) -> Vec<T> {
+        self.readings
+            .into_iter()
+            .flat_map(|(mut values, stale)| {
+                if stale {
+                    values.iter_mut().for_each(&mark);
+                }
+                values
+            })
+            .collect()
+    }
+}
+
+/// Readers of one kind, each with its own timeout and quarantine record
+pub struct ReaderSupervisor<R: ?Sized> {
+    kind: &'static str,
+    timeout: Duration,
+    readers: Vec<Supervised<R>>,
+}
+
+struct Supervised<R: ?Sized> {
+    reader: Arc<R>,
+    health: Mutex<Health>,
+}
+
+#[derive(Default)]
+struct Health {
+    /// Timeouts in a row; reset by a call that finishes in time
+    timeouts: u32,
+    /// Quarantines in a row, doubling the next one
+    quarantines: u32,
+    quarantined_until: Option<Instant>,
+    /// Set by call while it runs, which it still does after timing out
+    busy: HashMap<&'static str, Arc<AtomicBool>>,
+    /// Last readings by call, served while the reader is stuck
+    last: HashMap<&'static str, Box<dyn Any + Send>>,
+}
+
+impl Health {
+    fn last<T: Clone + 'static>(&self, call: &'static str) -> Vec<T> {
+        self.last
+            .get(call)
+            .and_then(|values| values.downcast_ref::<Vec<T>>())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Count a timeout, quarantining the reader once they add up.
+    fn timed_out(&mut self, reader: String, after: Duration, now: Instant) -> ReaderFault {
+        self.timeouts += 1;
+        if self.timeouts < QUARANTINE_AFTER {
+            return ReaderFault::TimedOut { reader, after };
+        }
+        let backoff = QUARANTINE
+            .saturating_mul(1 << self.quarantines.min(5))
+            .min(MAX_QUARANTINE);
+        self.timeouts = 0;
+        self.quarantines += 1;
+        self.quarantined_until = Some(now + backoff);
+        ReaderFault::Quarantined { reader, backoff }
+    }
+}
+
+/// Clears the busy flag once a call returns, or panics
+struct BusyGuard(Arc<AtomicBool>);
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl<R: ?Sized + Send + Sync + 'static> ReaderSupervisor<R> {
+    /// Supervise `readers`, named `"{kind} reader {index}"` in faults.
+    pub fn new(kind: &'static str, readers: Vec<Box<R>>, timeout: Duration) -> Self {
+        Self {
+            kind,
+            timeout,
+            readers: readers
+                .into_iter()
+                .map(|reader| Supervised {
+                    reader: Arc::from(reader),
+                    health: Mutex::new(Health::default()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Run `read` on every reader at once. `call` names what `read` returns,
+    /// so each kind of reading keeps its own fallback.
+    pub async fn collect<T>(&self, call: &'static str, read: fn(&R) -> Vec<T>) -> Collected<T>
+    where
+        T: Clone + Send + 'static,
+    {
+        let results = join_all(
+            self.readers
+                .iter()
+                .enumerate()
+                .map(|(index, supervised)| self.collect_one(index, supervised, call, read)),
+        )
+        .await;
+
+        let mut collected = Collected {
+            readings: Vec::with_capacity(results.len()),
+            faults: Vec::new(),
+        };
+        for (values, fault) in results {
+            collected.readings.push((values, fault.is_some()));
+            if let Some(fault) = fault {
+                tracing::warn!(call, "{fault}");
+                collected.faults.push(fault);
+            }
+        }
+        collected
+    }
+
+    async fn collect_one<T>(
+        &self,
+        index: usize,
+        supervised: &Supervised<R>,
+        call: &'static str,
+        read: fn(&R) -> Vec<T>,
+    ) -> (Vec<T>, Option<ReaderFault>)
+    where
+        T: Clone + Send + 'static,
+    {
+        let name = || format!("{} reader {index}", self.kind);
+        let now = Instant::now();
+        let busy = {
+            let mut health = supervised.health.lock().unwrap();
+            if health.quarantined_until.is_some_and(|until| until > now) {
+                let fault = ReaderFault::Skipped { reader: name() };
+                return (health.last(call), Some(fault));
+            }
+            health.quarantined_until = None;
+            // Another thread stuck on the same call would not return either
+            let busy = Arc::clone(health.busy.entry(call).or_default());
+            if busy.swap(true, Ordering::AcqRel) {
+                let fault = health.timed_out(name(), self.timeout, now);
+                return (health.last(call), Some(fault));
+            }
+            busy
+        };
+
+        let reader = Arc::clone(&supervised.reader);
+        let task = tokio::task::spawn_blocking(move || {
+            let _busy = BusyGuard(busy);
+            read(&*reader)
+        });
+        let result = tokio::time::timeout(self.timeout, task).await;
+
+        let mut health = supervised.health.lock().unwrap();
+        match result {
+            Ok(Ok(values)) => {
+                health.timeouts = 0;
+                health.quarantines = 0;
+                health.last.insert(call, Box::new(values.clone()));
+                (values, None)
+            }
+            // A reader that panics yields nothing for this pass
+            Ok(Err(_)) => (Vec::new(), None),
+            Err(_) => {
+                let fault = health.timed_out(name(), self.timeout, now);
+                (health.last(call), Some(fault))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    const HANG: Duration = Duration::from_millis(400);
+
+    /// Reader that answers `value` right away, or sleeps past the timeout
+    /// while `hang` is set.
+    struct MockReader {
+        value: u32,
+        hang: AtomicBool,
+    }
+
+    impl MockReader {
+        fn new(value: u32, hang: bool) -> Box<Self> {
+            Box::new(Self {
+                value,
+                hang: AtomicBool::new(hang),
+            })
+        }
+
+        fn read(&self) -> Vec<u32> {
+            if self.hang.load(Ordering::Relaxed) {
+                std::thread::sleep(HANG);
+            }
+            vec![self.value]
+        }
+    }
+
+    fn supervisor(readers: Vec<Box<MockReader>>) -> ReaderSupervisor<MockReader> {
+        ReaderSupervisor::new("mock", readers, TIMEOUT)
+    }
+
+    #[tokio::test]
+    async fn test_hung_reader_does_not_hold_up_the_others() {
+        let supervisor = supervisor(vec![MockReader::new(1, true), MockReader::new(2, false)]);
+
+        let started = Instant::now();
+        let collected = supervisor.collect("value", MockReader::read).await;
+        let elapsed = started.elapsed();
+
+        assert!(elapsed < HANG / 2, "collection took {elapsed:?}");
+        assert!(!collected.is_complete());
+        assert_eq!(
+            collected.faults,
+            vec![ReaderFault::TimedOut {
+                reader: "mock reader 0".to_string(),
+                after: TIMEOUT,
+            }]
+        );
+        assert_eq!(collected.into_values(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_reader_serves_its_previous_readings_as_stale() {
+        let supervisor = supervisor(vec![MockReader::new(1, false), MockReader::new(2, false)]);
+        let collected = supervisor.collect("value", MockReader::read).await;
+        assert!(collected.is_complete());
+        assert_eq!(collected.into_values(), vec![1, 2]);
+
+        supervisor.readers[0]
+            .reader
+            .hang
+            .store(true, Ordering::Relaxed);
+        let collected = supervisor.collect("value", MockReader::read).await;
+        assert_eq!(collected.faults.len(), 1);
+        assert_eq!(collected.into_values_marked(|v| *v += 100), vec![101, 2]);
+
+        // Each call keeps its own readings, so there are none to serve yet
+        let collected = supervisor
+            .collect("tenfold", |reader| {
+                reader.read().into_iter().map(|v| v * 10).collect()
+            })
+            .await;
+        assert_eq!(collected.faults.len(), 1);
+        assert_eq!(collected.into_values(), vec![20]);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_timeouts_quarantine_the_reader() {
+        let supervisor = supervisor(vec![MockReader::new(1, true)]);
+
+        let mut faults = Vec::new();
+        for _ in 0..4 {
+            let started = Instant::now();
+            let collected = supervisor.collect("value", MockReader::read).await;
+            assert!(started.elapsed() < HANG / 2);
+            faults.extend(collected.faults);
+        }
+
+        // The stuck call counts as a timeout until it returns
+        let reader = "mock reader 0".to_string();
+        let timed_out = ReaderFault::TimedOut {
+            reader: reader.clone(),
+            after: TIMEOUT,
+        };
+        assert_eq!(
+            faults,
+            vec![
+                timed_out.clone(),
+                timed_out,
+                ReaderFault::Quarantined {
+                    reader: reader.clone(),
+                    backoff: QUARANTINE,
+                },
+                ReaderFault::Skipped { reader },
+            ]
+        );
+        assert!(faults[2].is_new_quarantine());
+        assert_eq!(
+            faults[2].to_string(),
+            "mock reader 0 keeps timing out; skipping it for 30s"
+        );
+    }
+}
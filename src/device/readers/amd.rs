@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::device::gpu_temperature::{HOTSPOT_TEMPERATURE_KEY, MEMORY_TEMPERATURE_KEY};
+use crate::device::privileges::{device_access, Access};
+use crate::device::readers::amd_sysfs;
 use crate::device::readers::common_cache::{DetailBuilder, DeviceStaticInfo};
 use crate::device::types::{GpuInfo, ProcessInfo};
 use crate::device::GpuReader;
@@ -21,6 +24,7 @@ use libamdgpu_top::stat::{self, FdInfoStat, ProcInfo};
 use libamdgpu_top::AMDGPU::{DeviceHandle, GpuMetrics, MetricsInfo, GPU_INFO};
 use libamdgpu_top::{AppDeviceInfo, DevicePath, VramUsage};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Mutex, OnceLock};
 
 // GPU metric validation constants
@@ -82,9 +86,9 @@ impl Default for AmdGpuReader {
 impl AmdGpuReader {
     pub fn new() -> Self {
         // Check if we have permission to access AMD GPU devices
-        // This prevents panic from libamdgpu_top when running without sudo
+        // This prevents panic from libamdgpu_top when running without root
         // If no permission, silently return empty device list
-        // The main program will handle showing sudo message before TUI starts
+        // The missing access is reported at startup (see device::privileges)
         if !Self::check_amd_gpu_permissions() {
             return Self {
                 devices: Vec::new(),
@@ -115,18 +119,15 @@ impl AmdGpuReader {
                             });
                         }
                         Err(e) => {
-                            eprintln!(
-                                "Warning: Failed to get memory info for AMD GPU {}: {e}",
+                            tracing::warn!(
+                                "Failed to get memory info for AMD GPU {}: {e}",
                                 device_path.pci
                             );
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!(
-                        "Warning: Failed to initialize AMD GPU {}: {e}",
-                        device_path.pci
-                    );
+                    tracing::warn!("Failed to initialize AMD GPU {}: {e}", device_path.pci);
                 }
             }
         }
@@ -146,16 +147,13 @@ impl AmdGpuReader {
 
     /// Get cached static device info for a device, initializing if needed
     fn get_device_static_info<'a>(&self, device: &'a AmdGpuDevice) -> &'a DeviceStaticInfo {
-        device
-            .static_info
-            .get_or_init(|| {
-                // Fetch static device information once
-                let ext_info = device.device_handle.device_info().ok();
-                let memory_info = device.device_handle.memory_info().ok();
-
-                let (device_name, mut detail) = if let (Some(ext), Some(mem)) =
-                    (ext_info.as_ref(), memory_info.as_ref())
-                {
+        device.static_info.get_or_init(|| {
+            // Fetch static device information once
+            let ext_info = device.device_handle.device_info().ok();
+            let memory_info = device.device_handle.memory_info().ok();
+
+            let (device_name, mut detail) =
+                if let (Some(ext), Some(mem)) = (ext_info.as_ref(), memory_info.as_ref()) {
                     let sensors = libamdgpu_top::stat::Sensors::new(
                         &device.device_handle,
                         &device.device_path.pci,
@@ -185,10 +183,7 @@ impl AmdGpuReader {
                     let mut detail = builder.build();
 
                     // Add device details
-                    detail.insert(
-                        "Device ID".to_string(),
-                        format!("{:#06x}", ext.device_id()),
-                    );
+                    detail.insert("Device ID".to_string(), format!("{:#06x}", ext.device_id()));
                     detail.insert(
                         "Revision ID".to_string(),
                         format!("{:#04x}", ext.pci_rev_id()),
@@ -199,7 +194,7 @@ impl AmdGpuReader {
                     );
 
                     if let Some(ref vbios) = app_device_info.vbios {
-                        detail.insert("VBIOS Version".to_string(), vbios.ver.clone());
+                        detail.insert("vbios_version".to_string(), vbios.ver.clone());
                         detail.insert("VBIOS Date".to_string(), vbios.date.clone());
                     }
 
@@ -242,82 +237,55 @@ impl AmdGpuReader {
                     (String::from("Unknown GPU"), HashMap::new())
                 };
 
-                // Get driver version
-                match device.device_handle.get_drm_version_struct() {
-                    Ok(drm) => {
-                        if drm.version_major >= 0
-                            && drm.version_major <= MAX_VERSION_COMPONENT
-                            && drm.version_minor >= 0
-                            && drm.version_minor <= MAX_VERSION_COMPONENT
-                            && drm.version_patchlevel >= 0
-                            && drm.version_patchlevel <= MAX_VERSION_COMPONENT
-                        {
-                            let ver = format!(
-                                "{}.{}.{}",
-                                drm.version_major, drm.version_minor, drm.version_patchlevel
-                            );
-                            detail.insert("Driver Version".to_string(), ver);
-                        } else {
-                            eprintln!(
-                                "Warning: Invalid driver version components detected: {}.{}.{} for device {}",
-                                drm.version_major, drm.version_minor, drm.version_patchlevel,
-                                device.device_path.pci
-                            );
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "Warning: Failed to get driver version for device {}: {e}",
+            // Get driver version
+            match device.device_handle.get_drm_version_struct() {
+                Ok(drm) => {
+                    if drm.version_major >= 0
+                        && drm.version_major <= MAX_VERSION_COMPONENT
+                        && drm.version_minor >= 0
+                        && drm.version_minor <= MAX_VERSION_COMPONENT
+                        && drm.version_patchlevel >= 0
+                        && drm.version_patchlevel <= MAX_VERSION_COMPONENT
+                    {
+                        let ver = format!(
+                            "{}.{}.{}",
+                            drm.version_major, drm.version_minor, drm.version_patchlevel
+                        );
+                        detail.insert("Driver Version".to_string(), ver);
+                    } else {
+                        tracing::warn!(
+                            "Invalid driver version components detected: {}.{}.{} for device {}",
+                            drm.version_major,
+                            drm.version_minor,
+                            drm.version_patchlevel,
                             device.device_path.pci
                         );
                     }
-                };
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to get driver version for device {}: {e}",
+                        device.device_path.pci
+                    );
+                }
+            };
 
-                DeviceStaticInfo::with_details(device_name, None, detail)
-            })
+            amd_sysfs::insert_static_details(&device.device_path.sysfs_path, &mut detail);
+
+            DeviceStaticInfo::with_details(device_name, None, detail)
+        })
     }
 
     /// Check if we have permission to access AMD GPU devices
     /// Returns false if /dev/dri devices are not accessible
     fn check_amd_gpu_permissions() -> bool {
-        use std::fs;
-
-        // Check if /dev/dri directory exists and is accessible
-        let dri_path = std::path::Path::new("/dev/dri");
-        if !dri_path.exists() {
-            return false;
-        }
-
-        // Try to read the directory to check permissions
-        match fs::read_dir(dri_path) {
-            Ok(entries) => {
-                // Check if we can read at least one render or card device
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-                    // Check card or render devices
-                    if file_name.starts_with("card") || file_name.starts_with("render") {
-                        // Check if we have read/write permissions
-                        // For root: always has access
-                        // SAFETY: libc::geteuid() is always safe to call - it's a simple
-                        // system call that reads the effective user ID from the kernel.
-                        // It cannot fail and doesn't access any memory we provide.
-                        if unsafe { libc::geteuid() } == 0 {
-                            return true; // Root always has access
-                        }
-
-                        // For non-root, check if we can actually open the device
-                        if let Ok(_file) = fs::OpenOptions::new().read(true).write(true).open(&path)
-                        {
-                            return true; // We have access
-                        }
-                    }
-                }
-                false // No accessible devices found
-            }
-            Err(_) => false, // Cannot read /dev/dri directory
+        // SAFETY: libc::geteuid() is always safe to call - it's a simple
+        // system call that reads the effective user ID from the kernel.
+        // It cannot fail and doesn't access any memory we provide.
+        if unsafe { libc::geteuid() } == 0 {
+            return true; // Root always has access
         }
+        device_access(Path::new("/dev/dri"), &["card", "render"]) == Access::Granted
     }
 }
 
@@ -335,8 +303,8 @@ impl GpuReader for AmdGpuReader {
             let ext_info = match device.device_handle.device_info() {
                 Ok(info) => info,
                 Err(e) => {
-                    eprintln!(
-                        "Warning: Failed to get device info for AMD GPU {}: {e}",
+                    tracing::warn!(
+                        "Failed to get device info for AMD GPU {}: {e}",
                         device.device_path.pci
                     );
                     continue; // Skip this GPU if we can't get device info
@@ -358,8 +326,8 @@ impl GpuReader for AmdGpuReader {
                     Err(poisoned) => {
                         // Mutex was poisoned - recover by getting fresh memory info
                         // This prevents denial of service from panics in other threads
-                        eprintln!(
-                            "Warning: VramUsage mutex was poisoned for device {}, recovering...",
+                        tracing::warn!(
+                            "VramUsage mutex was poisoned for device {}, recovering...",
                             device.device_path.pci
                         );
 
@@ -382,8 +350,8 @@ impl GpuReader for AmdGpuReader {
                                     }
                                     Err(_) => {
                                         // Recovery failed - skip this GPU
-                                        eprintln!(
-                                            "Critical: Failed to recover poisoned mutex for device {}, skipping",
+                                        tracing::error!(
+                                            "Failed to recover poisoned mutex for device {}, skipping",
                                             device.device_path.pci
                                         );
                                         continue;
@@ -391,7 +359,9 @@ impl GpuReader for AmdGpuReader {
                                 }
                             }
                             Err(e) => {
-                                eprintln!("Failed to get fresh memory info during recovery: {e}");
+                                tracing::error!(
+                                    "Failed to get fresh memory info during recovery: {e}"
+                                );
                                 continue; // Skip this GPU if we can't recover
                             }
                         }
@@ -406,19 +376,38 @@ impl GpuReader for AmdGpuReader {
                 &ext_info,
             );
 
-            // Add dynamic sensor data to details
+            // Clocks, fan, temperatures, link and throttle status from sysfs, with the
+            // sensors filling in whatever sysfs did not report
+            amd_sysfs::insert_dynamic_details(&device.device_path.sysfs_path, &mut detail);
             if let Some(ref sensors) = sensors {
                 if let Some(link) = sensors.current_link {
-                    detail.insert(
-                        "Current Link".to_string(),
-                        format!("Gen{} x{}", link.gen, link.width),
-                    );
+                    detail
+                        .entry("pcie_gen_current".to_string())
+                        .or_insert_with(|| link.gen.to_string());
+                    detail
+                        .entry("pcie_width_current".to_string())
+                        .or_insert_with(|| link.width.to_string());
                 }
                 if let Some(fan) = sensors.fan_rpm {
-                    detail.insert("Fan Speed".to_string(), format!("{fan} RPM"));
+                    detail
+                        .entry(amd_sysfs::FAN_SPEED_KEY.to_string())
+                        .or_insert_with(|| fan.to_string());
                 }
                 if let Some(mclk) = sensors.mclk {
-                    detail.insert("Memory Clock".to_string(), format!("{mclk} MHz"));
+                    detail
+                        .entry(amd_sysfs::CLOCK_MEMORY_KEY.to_string())
+                        .or_insert_with(|| mclk.to_string());
+                }
+                for (temp, key) in [
+                    (&sensors.junction_temp, HOTSPOT_TEMPERATURE_KEY),
+                    (&sensors.memory_temp, MEMORY_TEMPERATURE_KEY),
+                ] {
+                    if let Some(t) = temp.as_ref().filter(|t| t.current > 0) {
+                        let celsius = (t.current as u32).min(MAX_GPU_TEMP_CELSIUS);
+                        detail
+                            .entry(key.to_string())
+                            .or_insert_with(|| celsius.to_string());
+                    }
                 }
             }
 
@@ -503,16 +492,13 @@ impl GpuReader for AmdGpuReader {
                 hostname: get_hostname(),
                 instance: get_hostname(),
                 utilization,
-                ane_utilization: 0.0,
-                dla_utilization: None,
-                tensorcore_utilization: None,
                 temperature,
                 used_memory,
                 total_memory,
                 frequency,
                 power_consumption,
-                gpu_core_count: None,
                 detail,
+                ..Default::default()
             };
             gpu_info.push(info);
         }
@@ -610,13 +596,14 @@ impl GpuReader for AmdGpuReader {
                 pid: gpu_proc.pid,
                 process_name: gpu_proc.name,
                 used_memory: gpu_memory_bytes,
+                used_memory_estimated: false,
                 cpu_percent: sys_proc.map(|p| p.cpu_percent).unwrap_or(0.0),
                 memory_percent: sys_proc.map(|p| p.memory_percent).unwrap_or(0.0),
                 memory_rss: sys_proc.map(|p| p.memory_rss).unwrap_or(0),
                 memory_vms: sys_proc.map(|p| p.memory_vms).unwrap_or(0),
                 user: sys_proc.map(|p| p.user.clone()).unwrap_or_default(),
                 state: sys_proc.map(|p| p.state.clone()).unwrap_or_default(),
-                start_time: sys_proc.map(|p| p.start_time.clone()).unwrap_or_default(),
+                start_time: sys_proc.map(|p| p.start_time).unwrap_or(0),
                 cpu_time: sys_proc.map(|p| p.cpu_time).unwrap_or(0),
                 command: sys_proc.map(|p| p.command.clone()).unwrap_or_default(),
                 ppid: sys_proc.map(|p| p.ppid).unwrap_or(0),
@@ -625,6 +612,8 @@ impl GpuReader for AmdGpuReader {
                 priority: sys_proc.map(|p| p.priority).unwrap_or(0),
                 nice_value: sys_proc.map(|p| p.nice_value).unwrap_or(0),
                 gpu_utilization: 0.0, // fdinfo doesn't directly provide this per-process
+                k8s_pod: None,
+                host_id: String::new(),
             };
 
             process_info_list.push(process_info);
@@ -641,11 +630,11 @@ mod tests {
     #[test]
     fn test_max_version_component_validation() {
         // Test that MAX_VERSION_COMPONENT is reasonable for Linux kernel versions
-        assert!(
+        const _: () = assert!(
             MAX_VERSION_COMPONENT >= 99,
             "Should support two-digit version components"
         );
-        assert!(
+        const _: () = assert!(
             MAX_VERSION_COMPONENT <= 9999,
             "Should not be excessively large"
         );
@@ -687,9 +676,9 @@ mod tests {
         ];
 
         for (major, minor, patch) in invalid_versions {
-            let major_valid = major >= 0 && major <= MAX_VERSION_COMPONENT;
-            let minor_valid = minor >= 0 && minor <= MAX_VERSION_COMPONENT;
-            let patch_valid = patch >= 0 && patch <= MAX_VERSION_COMPONENT;
+            let major_valid = (0..=MAX_VERSION_COMPONENT).contains(&major);
+            let minor_valid = (0..=MAX_VERSION_COMPONENT).contains(&minor);
+            let patch_valid = (0..=MAX_VERSION_COMPONENT).contains(&patch);
 
             assert!(
                 !(major_valid && minor_valid && patch_valid),
@@ -0,0 +1,612 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! amdgpu attributes read straight from the PCI device's sysfs directory.
+//!
+//! Detail keys follow the NVIDIA reader where the value is analogous
+//! (`vbios_version`, `pcie_gen_current`, `clock_memory_max`, ...). Throttle
+//! status comes from the binary `gpu_metrics` table; versions we do not know
+//! the layout of are skipped and only the text attributes are reported.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::device::gpu_fan::{percent_of, FAN_SPEED_KEY_PREFIX, FAN_TARGET_KEY_PREFIX, MAX_FANS};
+use crate::device::gpu_temperature::{HOTSPOT_TEMPERATURE_KEY, MEMORY_TEMPERATURE_KEY};
+
+pub const FAN_SPEED_KEY: &str = "fan_speed_rpm";
+pub const CLOCK_MEMORY_KEY: &str = "clock_memory_current";
+pub const THROTTLE_STATUS_KEY: &str = "throttle_status";
+pub const THROTTLE_REASONS_KEY: &str = "throttle_reasons";
+
+/// Bytes of `metrics_table_header`: structure_size (u16), format_revision
+/// (u8), content_revision (u8)
+const HEADER_SIZE: usize = 4;
+
+/// Throttle state from `gpu_metrics`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottleStatus {
+    /// ASIC-specific throttler bits
+    pub status: u32,
+    /// ASIC-independent `SMU_THROTTLER_*` bits, on v1.3 and v2.2+ tables
+    pub independent: Option<u64>,
+}
+
+impl ThrottleStatus {
+    /// Reason groups from the independent bits: PPT/SPL limits are
+    /// `power`, TDC/EDC `current`, temperatures and VR/PROCHOT `thermal`,
+    /// PPM/FIT `other`. `None` when the table only has ASIC-specific bits.
+    pub fn reasons(&self) -> Option<String> {
+        let bits = self.independent?;
+        let groups = [
+            (0..16, "power"),
+            (16..32, "current"),
+            (32..48, "thermal"),
+            (48..64, "other"),
+        ];
+        let reasons: Vec<&str> = groups
+            .into_iter()
+            .filter(|(range, _)| range.clone().any(|bit| bits & (1 << bit) != 0))
+            .map(|(_, name)| name)
+            .collect();
+        Some(if reasons.is_empty() {
+            "none".to_string()
+        } else {
+            reasons.join(",")
+        })
+    }
+}
+
+/// Parse the throttle fields of a `gpu_metrics` table.
+///
+/// Offsets are those of the kernel's `gpu_metrics_vX_Y` structs: format 1
+/// is the dGPU layout (Navi, MI100/MI200), format 2 the APU layout (Renoir,
+/// Van Gogh, Rembrandt). Other versions, truncated tables and fields the
+/// firmware leaves unset (all ones) give `None`.
+pub fn parse_gpu_metrics(raw: &[u8]) -> Option<ThrottleStatus> {
+    if raw.len() < HEADER_SIZE {
+        return None;
+    }
+    let structure_size = usize::from(u16::from_le_bytes([raw[0], raw[1]]));
+    let (format_revision, content_revision) = (raw[2], raw[3]);
+    let (status_offset, independent_offset) = match (format_revision, content_revision) {
+        (1, 0..=2) => (68, None),
+        (1, 3) => (68, Some(112)),
+        (2, 0) => (112, None),
+        (2, 1) => (108, None),
+        (2, 2..=3) => (108, Some(120)),
+        _ => {
+            tracing::debug!("Unsupported gpu_metrics version {format_revision}.{content_revision}");
+            return None;
+        }
+    };
+    let table = &raw[..structure_size.min(raw.len())];
+
+    let status = read_u32(table, status_offset).filter(|&status| status != u32::MAX)?;
+    let independent = independent_offset
+        .and_then(|offset| read_u64(table, offset))
+        .filter(|&bits| bits != u64::MAX);
+    Some(ThrottleStatus {
+        status,
+        independent,
+    })
+}
+
+fn read_u32(table: &[u8], offset: usize) -> Option<u32> {
+    let bytes = table.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn read_u64(table: &[u8], offset: usize) -> Option<u64> {
+    let bytes = table.get(offset..offset + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Current and highest DPM level of a `pp_dpm_*` file, in MHz. The current
+/// level is the one marked with `*`.
+pub fn parse_dpm_levels(content: &str) -> Option<(Option<u32>, u32)> {
+    let mut current = None;
+    let mut max = None;
+    for line in content.lines() {
+        let Some((_, level)) = line.split_once(':') else {
+            continue;
+        };
+        let level = level.trim();
+        let is_current = level.ends_with('*');
+        let mhz = level
+            .trim_end_matches('*')
+            .trim()
+            .to_ascii_lowercase()
+            .strip_suffix("mhz")
+            .and_then(|mhz| mhz.trim().parse::<u32>().ok());
+        let Some(mhz) = mhz else {
+            continue;
+        };
+        if is_current {
+            current = Some(mhz);
+        }
+        max = max.max(Some(mhz));
+    }
+    max.map(|max| (current, max))
+}
+
+/// PCIe generation for a `current_link_speed` value such as "16.0 GT/s PCIe".
+pub fn parse_link_generation(speed: &str) -> Option<u32> {
+    let gts: f64 = speed.split_whitespace().next()?.parse().ok()?;
+    [2.5, 5.0, 8.0, 16.0, 32.0, 64.0]
+        .iter()
+        .position(|&rate| (gts - rate).abs() < 0.1)
+        .map(|index| index as u32 + 1)
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Attributes that do not change while the driver is loaded.
+pub fn insert_static_details(device_dir: &Path, detail: &mut HashMap<String, String>) {
+    if let Some(vbios) = read_trimmed(&device_dir.join("vbios_version")) {
+        detail.entry("vbios_version".to_string()).or_insert(vbios);
+    }
+    if let Some(vendor) = read_trimmed(&device_dir.join("mem_info_vram_vendor")) {
+        detail.insert("vram_vendor".to_string(), vendor);
+    }
+    let max_level = |file: &str| {
+        read_trimmed(&device_dir.join(file))
+            .and_then(|content| parse_dpm_levels(&content))
+            .map(|(_, max)| max.to_string())
+    };
+    if let Some(max) = max_level("pp_dpm_sclk") {
+        detail.insert("clock_graphics_max".to_string(), max);
+    }
+    if let Some(max) = max_level("pp_dpm_mclk") {
+        detail.insert("clock_memory_max".to_string(), max);
+    }
+}
+
+/// Attributes refreshed every collection cycle.
+pub fn insert_dynamic_details(device_dir: &Path, detail: &mut HashMap<String, String>) {
+    let current_level = |file: &str| {
+        read_trimmed(&device_dir.join(file))
+            .and_then(|content| parse_dpm_levels(&content))
+            .and_then(|(current, _)| current)
+    };
+    if let Some(sclk) = current_level("pp_dpm_sclk") {
+        detail.insert("clock_graphics_current".to_string(), sclk.to_string());
+    }
+    if let Some(mclk) = current_level("pp_dpm_mclk") {
+        detail.insert(CLOCK_MEMORY_KEY.to_string(), mclk.to_string());
+    }
+    if let Some(rpm) = hwmon_fan_rpm(device_dir) {
+        detail.insert(FAN_SPEED_KEY.to_string(), rpm.to_string());
+    }
+    for (fan, reading) in hwmon_fans(device_dir).into_iter().enumerate() {
+        if let Some(speed) = reading.speed {
+            detail.insert(format!("{FAN_SPEED_KEY_PREFIX}{fan}"), speed.to_string());
+        }
+        if let Some(target) = reading.target {
+            detail.insert(format!("{FAN_TARGET_KEY_PREFIX}{fan}"), target.to_string());
+        }
+    }
+    let temperatures = hwmon_temperatures(device_dir);
+    if let Some(hotspot) = temperatures.hotspot {
+        detail.insert(HOTSPOT_TEMPERATURE_KEY.to_string(), hotspot.to_string());
+    }
+    if let Some(memory) = temperatures.memory {
+        detail.insert(MEMORY_TEMPERATURE_KEY.to_string(), memory.to_string());
+    }
+    if let Some(generation) =
+        read_trimmed(&device_dir.join("current_link_speed")).and_then(|s| parse_link_generation(&s))
+    {
+        detail.insert("pcie_gen_current".to_string(), generation.to_string());
+    }
+    if let Some(width) = read_trimmed(&device_dir.join("current_link_width"))
+        .and_then(|width| width.parse::<u32>().ok())
+    {
+        detail.insert("pcie_width_current".to_string(), width.to_string());
+    }
+    if let Some(throttle) = fs::read(device_dir.join("gpu_metrics"))
+        .ok()
+        .and_then(|raw| parse_gpu_metrics(&raw))
+    {
+        detail.insert(
+            THROTTLE_STATUS_KEY.to_string(),
+            format!("0x{:08x}", throttle.status),
+        );
+        if let Some(reasons) = throttle.reasons() {
+            detail.insert(THROTTLE_REASONS_KEY.to_string(), reasons);
+        }
+    }
+}
+
+/// Fan speed from the first `hwmon/hwmonN/fan1_input`. Passively cooled
+/// Instinct cards have no fan and no such file.
+fn hwmon_fan_rpm(device_dir: &Path) -> Option<u32> {
+    fs::read_dir(device_dir.join("hwmon"))
+        .ok()?
+        .flatten()
+        .find_map(|entry| read_trimmed(&entry.path().join("fan1_input"))?.parse().ok())
+}
+
+/// Speed of one hwmon fan in percent of its maximum
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HwmonFan {
+    /// Measured speed: `fanN_input` against `fanN_max` in RPM, or the PWM
+    /// duty when the fan has no RPM range
+    pub speed: Option<u32>,
+    /// What the fan curve asks for: `fanN_target` in RPM, or the PWM duty
+    pub target: Option<u32>,
+}
+
+/// Fans of the first hwmon directory that has any, `fan1`/`pwm1` first.
+/// amdgpu reports RPM in `fanN_*` and a 0-255 duty in `pwmN`; both are
+/// normalized to percent with the matching `_max` file (255 for PWM when it
+/// is missing). Fans with neither reading are skipped.
+pub fn hwmon_fans(device_dir: &Path) -> Vec<HwmonFan> {
+    let Ok(entries) = fs::read_dir(device_dir.join("hwmon")) else {
+        return Vec::new();
+    };
+    for hwmon in entries.flatten().map(|entry| entry.path()) {
+        let read = |name: String| read_trimmed(&hwmon.join(name))?.parse::<u32>().ok();
+        let fans: Vec<HwmonFan> = (1..=MAX_FANS)
+            .map(|n| {
+                let rpm_max = read(format!("fan{n}_max"));
+                let pwm = read(format!("pwm{n}"));
+                let pwm_max = read(format!("pwm{n}_max")).unwrap_or(255);
+                let duty = pwm.and_then(|pwm| percent_of(pwm, pwm_max));
+                let rpm_percent = |file: String| percent_of(read(file)?, rpm_max?);
+                HwmonFan {
+                    speed: rpm_percent(format!("fan{n}_input")).or(duty),
+                    target: rpm_percent(format!("fan{n}_target")).or(duty),
+                }
+            })
+            .filter(|fan| *fan != HwmonFan::default())
+            .collect();
+        if !fans.is_empty() {
+            return fans;
+        }
+    }
+    Vec::new()
+}
+
+/// Junction and memory temperatures in °C from the hwmon sensors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HwmonTemperatures {
+    pub hotspot: Option<u32>,
+    pub memory: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HwmonSensor {
+    Hotspot,
+    Memory,
+}
+
+/// Which temperature a `tempN` sensor measures. amdgpu labels them `edge`,
+/// `junction` and `mem`; without a label file, `temp2` is the junction and
+/// `temp3` the memory sensor.
+fn hwmon_sensor(index: u32, label: Option<&str>) -> Option<HwmonSensor> {
+    match label.map(str::to_ascii_lowercase).as_deref() {
+        Some("junction" | "hotspot") => Some(HwmonSensor::Hotspot),
+        Some("mem" | "memory" | "hbm") => Some(HwmonSensor::Memory),
+        Some(_) => None,
+        None => match index {
+            2 => Some(HwmonSensor::Hotspot),
+            3 => Some(HwmonSensor::Memory),
+            _ => None,
+        },
+    }
+}
+
+/// Junction and memory temperatures from `hwmon/hwmonN/tempM_input`
+/// (millidegrees). Cards without the sensors, and sensors reading zero,
+/// give `None`.
+pub fn hwmon_temperatures(device_dir: &Path) -> HwmonTemperatures {
+    let mut temperatures = HwmonTemperatures::default();
+    let Ok(entries) = fs::read_dir(device_dir.join("hwmon")) else {
+        return temperatures;
+    };
+    for hwmon in entries.flatten().map(|entry| entry.path()) {
+        let Ok(files) = fs::read_dir(&hwmon) else {
+            continue;
+        };
+        for file in files.flatten() {
+            let name = file.file_name();
+            let Some(index) = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("temp")?.strip_suffix("_input"))
+                .and_then(|index| index.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            let label = read_trimmed(&hwmon.join(format!("temp{index}_label")));
+            let Some(celsius) = read_trimmed(&file.path())
+                .and_then(|value| value.parse::<i64>().ok())
+                .filter(|&millidegrees| millidegrees > 0)
+                .map(|millidegrees| (millidegrees / 1000) as u32)
+            else {
+                continue;
+            };
+            match hwmon_sensor(index, label.as_deref()) {
+                Some(HwmonSensor::Hotspot) => {
+                    temperatures.hotspot.get_or_insert(celsius);
+                }
+                Some(HwmonSensor::Memory) => {
+                    temperatures.memory.get_or_insert(celsius);
+                }
+                None => {}
+            }
+        }
+        if temperatures != HwmonTemperatures::default() {
+            break;
+        }
+    }
+    temperatures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `gpu_metrics` table of `size` bytes with the given header and
+    /// fields; everything else stays zero.
+    fn table(size: u16, format: u8, content: u8, fields: &[(usize, &[u8])]) -> Vec<u8> {
+        let mut raw = vec![0u8; usize::from(size)];
+        raw[..2].copy_from_slice(&size.to_le_bytes());
+        raw[2] = format;
+        raw[3] = content;
+        for (offset, bytes) in fields {
+            raw[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+        }
+        raw
+    }
+
+    #[test]
+    fn test_gpu_metrics_navi10_v1_0() {
+        let raw = table(80, 1, 0, &[(68, &0x24u32.to_le_bytes())]);
+        let throttle = parse_gpu_metrics(&raw).unwrap();
+        assert_eq!(throttle.status, 0x24);
+        assert_eq!(throttle.independent, None);
+        assert_eq!(throttle.reasons(), None);
+    }
+
+    #[test]
+    fn test_gpu_metrics_aldebaran_v1_3() {
+        // PPT0 and TEMP_HOTSPOT
+        let independent: u64 = (1 << 0) | (1 << 36);
+        let raw = table(
+            120,
+            1,
+            3,
+            &[
+                (68, &0x1u32.to_le_bytes()),
+                (112, &independent.to_le_bytes()),
+            ],
+        );
+        let throttle = parse_gpu_metrics(&raw).unwrap();
+        assert_eq!(throttle.status, 0x1);
+        assert_eq!(throttle.independent, Some(independent));
+        assert_eq!(throttle.reasons().as_deref(), Some("power,thermal"));
+    }
+
+    #[test]
+    fn test_gpu_metrics_apu_layouts() {
+        // Renoir
+        let raw = table(120, 2, 0, &[(112, &0x8u32.to_le_bytes())]);
+        assert_eq!(parse_gpu_metrics(&raw).unwrap().status, 0x8);
+        let raw = table(120, 2, 1, &[(108, &0x8u32.to_le_bytes())]);
+        assert_eq!(parse_gpu_metrics(&raw).unwrap().status, 0x8);
+
+        // Van Gogh: TDC_GFX
+        let raw = table(128, 2, 2, &[(120, &(1u64 << 16).to_le_bytes())]);
+        let throttle = parse_gpu_metrics(&raw).unwrap();
+        assert_eq!(throttle.status, 0);
+        assert_eq!(throttle.reasons().as_deref(), Some("current"));
+
+        let raw = table(152, 2, 3, &[]);
+        assert_eq!(
+            parse_gpu_metrics(&raw).unwrap().reasons().as_deref(),
+            Some("none")
+        );
+    }
+
+    #[test]
+    fn test_gpu_metrics_unsupported() {
+        // MI300 (v1.5) and newer APUs (v3.0) have no throttle_status field
+        assert_eq!(parse_gpu_metrics(&table(128, 1, 5, &[])), None);
+        assert_eq!(parse_gpu_metrics(&table(128, 3, 0, &[])), None);
+        // Truncated table
+        assert_eq!(parse_gpu_metrics(&table(64, 1, 0, &[])), None);
+        assert_eq!(parse_gpu_metrics(&[1, 2]), None);
+        // Field left unset by the firmware
+        let raw = table(80, 1, 0, &[(68, &u32::MAX.to_le_bytes())]);
+        assert_eq!(parse_gpu_metrics(&raw), None);
+        // Independent bits unset, ASIC bits kept
+        let raw = table(120, 1, 3, &[(112, &u64::MAX.to_le_bytes())]);
+        assert_eq!(parse_gpu_metrics(&raw).unwrap().independent, None);
+    }
+
+    #[test]
+    fn test_parse_dpm_levels() {
+        let sclk = "0: 500Mhz \n1: 1800Mhz *\n2: 2100Mhz \n";
+        assert_eq!(parse_dpm_levels(sclk), Some((Some(1800), 2100)));
+        // Sleep level on newer ASICs
+        assert_eq!(
+            parse_dpm_levels("S: 19Mhz *\n0: 500Mhz\n1: 2400Mhz\n"),
+            Some((Some(19), 2400))
+        );
+        assert_eq!(parse_dpm_levels("0: 96MHz\n1: 1000MHz"), Some((None, 1000)));
+        assert_eq!(parse_dpm_levels(""), None);
+    }
+
+    #[test]
+    fn test_parse_link_generation() {
+        assert_eq!(parse_link_generation("16.0 GT/s PCIe"), Some(4));
+        assert_eq!(parse_link_generation("2.5 GT/s PCIe"), Some(1));
+        assert_eq!(parse_link_generation("32.0 GT/s"), Some(5));
+        assert_eq!(parse_link_generation("Unknown"), None);
+    }
+
+    #[test]
+    fn test_details_from_sysfs() {
+        let dir = tempfile::tempdir().unwrap();
+        let device = dir.path();
+        fs::write(device.join("vbios_version"), "113-D4120100-100\n").unwrap();
+        fs::write(device.join("mem_info_vram_vendor"), "samsung\n").unwrap();
+        fs::write(device.join("pp_dpm_sclk"), "0: 500Mhz\n1: 2100Mhz *\n").unwrap();
+        fs::write(device.join("pp_dpm_mclk"), "0: 96Mhz\n1: 1000Mhz *\n").unwrap();
+        fs::write(device.join("current_link_speed"), "16.0 GT/s PCIe\n").unwrap();
+        fs::write(device.join("current_link_width"), "16\n").unwrap();
+        fs::create_dir_all(device.join("hwmon/hwmon3")).unwrap();
+        fs::write(device.join("hwmon/hwmon3/fan1_input"), "1150\n").unwrap();
+        // A version without known offsets only drops the throttle keys
+        fs::write(device.join("gpu_metrics"), table(128, 1, 5, &[])).unwrap();
+
+        let mut detail = HashMap::new();
+        insert_static_details(device, &mut detail);
+        insert_dynamic_details(device, &mut detail);
+        assert_eq!(detail["vbios_version"], "113-D4120100-100");
+        assert_eq!(detail["vram_vendor"], "samsung");
+        assert_eq!(detail["clock_graphics_max"], "2100");
+        assert_eq!(detail["clock_memory_max"], "1000");
+        assert_eq!(detail["clock_graphics_current"], "2100");
+        assert_eq!(detail[CLOCK_MEMORY_KEY], "1000");
+        assert_eq!(detail[FAN_SPEED_KEY], "1150");
+        assert_eq!(detail["pcie_gen_current"], "4");
+        assert_eq!(detail["pcie_width_current"], "16");
+        assert!(!detail.contains_key(THROTTLE_STATUS_KEY));
+
+        let independent: u64 = 1 << 35;
+        fs::write(
+            device.join("gpu_metrics"),
+            table(
+                120,
+                1,
+                3,
+                &[
+                    (68, &0x2u32.to_le_bytes()),
+                    (112, &independent.to_le_bytes()),
+                ],
+            ),
+        )
+        .unwrap();
+        insert_dynamic_details(device, &mut detail);
+        assert_eq!(detail[THROTTLE_STATUS_KEY], "0x00000002");
+        assert_eq!(detail[THROTTLE_REASONS_KEY], "thermal");
+    }
+
+    #[test]
+    fn test_hwmon_temperatures_by_label() {
+        let dir = tempfile::tempdir().unwrap();
+        let hwmon = dir.path().join("hwmon/hwmon2");
+        fs::create_dir_all(&hwmon).unwrap();
+        // Labels win over the sensor numbers
+        for (index, label, millidegrees) in [
+            (1, "edge", "45000"),
+            (2, "mem", "71000"),
+            (3, "junction", "88000"),
+        ] {
+            fs::write(
+                hwmon.join(format!("temp{index}_label")),
+                format!("{label}\n"),
+            )
+            .unwrap();
+            fs::write(hwmon.join(format!("temp{index}_input")), millidegrees).unwrap();
+        }
+        fs::write(hwmon.join("fan1_input"), "1150\n").unwrap();
+
+        assert_eq!(
+            hwmon_temperatures(dir.path()),
+            HwmonTemperatures {
+                hotspot: Some(88),
+                memory: Some(71),
+            }
+        );
+    }
+
+    #[test]
+    fn test_hwmon_temperatures_without_labels() {
+        let dir = tempfile::tempdir().unwrap();
+        let hwmon = dir.path().join("hwmon/hwmon0");
+        fs::create_dir_all(&hwmon).unwrap();
+        fs::write(hwmon.join("temp1_input"), "40000\n").unwrap();
+        fs::write(hwmon.join("temp2_input"), "52000\n").unwrap();
+        // A sensor the firmware leaves at zero is missing, not 0 °C
+        fs::write(hwmon.join("temp3_input"), "0\n").unwrap();
+
+        let mut detail = HashMap::new();
+        insert_dynamic_details(dir.path(), &mut detail);
+        assert_eq!(detail[HOTSPOT_TEMPERATURE_KEY], "52");
+        assert!(!detail.contains_key(MEMORY_TEMPERATURE_KEY));
+
+        // Edge-only cards report neither
+        fs::remove_file(hwmon.join("temp2_input")).unwrap();
+        fs::remove_file(hwmon.join("temp3_input")).unwrap();
+        assert_eq!(hwmon_temperatures(dir.path()), HwmonTemperatures::default());
+        assert_eq!(
+            hwmon_temperatures(&dir.path().join("missing")),
+            HwmonTemperatures::default()
+        );
+    }
+
+    #[test]
+    fn test_hwmon_fans() {
+        let dir = tempfile::tempdir().unwrap();
+        let hwmon = dir.path().join("hwmon/hwmon1");
+        fs::create_dir_all(&hwmon).unwrap();
+        // RPM with a known range: measured and target speeds against fan1_max
+        fs::write(hwmon.join("fan1_input"), "1650\n").unwrap();
+        fs::write(hwmon.join("fan1_target"), "1980\n").unwrap();
+        fs::write(hwmon.join("fan1_max"), "3300\n").unwrap();
+        fs::write(hwmon.join("pwm1"), "128\n").unwrap();
+        // PWM only: the duty out of the default 255
+        fs::write(hwmon.join("pwm2"), "51\n").unwrap();
+
+        assert_eq!(
+            hwmon_fans(dir.path()),
+            [
+                HwmonFan {
+                    speed: Some(50),
+                    target: Some(60),
+                },
+                HwmonFan {
+                    speed: Some(20),
+                    target: Some(20),
+                },
+            ]
+        );
+
+        let mut detail = HashMap::new();
+        insert_dynamic_details(dir.path(), &mut detail);
+        assert_eq!(detail["fan_speed_percent_0"], "50");
+        assert_eq!(detail["fan_target_percent_0"], "60");
+        assert_eq!(detail["fan_speed_percent_1"], "20");
+
+        // Without fan1_max the RPM cannot be normalized; the duty is used
+        fs::remove_file(hwmon.join("fan1_max")).unwrap();
+        assert_eq!(
+            hwmon_fans(dir.path())[0],
+            HwmonFan {
+                speed: Some(50),
+                target: Some(50),
+            }
+        );
+
+        // Passively cooled cards have no fans
+        assert!(hwmon_fans(&dir.path().join("missing")).is_empty());
+    }
+}
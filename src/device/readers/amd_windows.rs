@@ -41,7 +41,7 @@ fn with_wmi_connection<T, F: FnOnce(&WMIConnection) -> T>(f: F) -> Option<T> {
                     *conn_ref = Some(wmi_con);
                 }
                 Err(e) => {
-                    eprintln!("AMD GPU: Failed to create WMI connection: {e}");
+                    tracing::warn!("AMD GPU: Failed to create WMI connection: {e}");
                 }
             }
         }
@@ -123,10 +123,10 @@ impl AmdWindowsGpuReader {
                 // Warn if the reported VRAM is suspiciously close to 4GB limit or 0
                 const FOUR_GB: u64 = 4 * 1024 * 1024 * 1024; // 4,294,967,296 bytes
                 if total_memory == 0 {
-                    eprintln!("AMD GPU '{name}': VRAM size unavailable (reported as 0)");
+                    tracing::warn!("AMD GPU '{name}': VRAM size unavailable (reported as 0)");
                 } else if total_memory >= FOUR_GB - (512 * 1024 * 1024) {
                     // If reported value is >= 3.5GB, it might be capped/wrapped for >4GB GPU
-                    eprintln!("AMD GPU '{name}': VRAM reported as {total_memory} bytes, may be inaccurate for >4GB GPUs due to WMI 32-bit limitation");
+                    tracing::warn!("AMD GPU '{name}': VRAM reported as {total_memory} bytes, may be inaccurate for >4GB GPUs due to WMI 32-bit limitation");
                 }
 
                 // Build detail map
@@ -160,16 +160,13 @@ impl AmdWindowsGpuReader {
                     hostname: hostname.clone(),
                     instance: hostname.clone(),
                     utilization: 0.0, // Not available via WMI
-                    ane_utilization: 0.0,
-                    dla_utilization: None,
-                    tensorcore_utilization: None,
                     temperature: 0, // Not available via WMI
                     used_memory: 0, // Not available via WMI
                     total_memory,
                     frequency: 0,         // Not available via WMI
                     power_consumption: 0.0, // Not available via WMI
-                    gpu_core_count: None,
                     detail,
+                    ..Default::default()
                 });
             }
             }
@@ -200,7 +197,7 @@ pub fn has_amd_gpu_windows() -> bool {
     let wmi_con = match WMIConnection::new() {
         Ok(w) => w,
         Err(e) => {
-            eprintln!("AMD GPU detection: Failed to create WMI connection: {e}");
+            tracing::warn!("AMD GPU detection: Failed to create WMI connection: {e}");
             return false;
         }
     };
@@ -223,7 +220,7 @@ pub fn has_amd_gpu_windows() -> bool {
             }
         }
         Err(e) => {
-            eprintln!("AMD GPU detection: WMI query failed: {e}");
+            tracing::warn!("AMD GPU detection: WMI query failed: {e}");
             return false;
         }
     }
@@ -25,8 +25,10 @@
 
 use crate::device::common::command_executor::execute_command_default;
 use crate::device::macos_native::{
-    get_native_metrics_manager, initialize_native_metrics_manager, NativeMetricsManager,
+    get_native_metrics_manager, gpu_clients, initialize_native_metrics_manager,
+    NativeMetricsManager,
 };
+use crate::device::process_list::fill_start_times;
 use crate::device::readers::common_cache::{DetailBuilder, DeviceStaticInfo};
 use crate::device::{GpuInfo, GpuReader, ProcessInfo};
 use crate::utils::get_hostname;
@@ -91,7 +93,7 @@ impl AppleSiliconNativeGpuReader {
         let mut cache = match CACHED_GPU_INFO.lock() {
             Ok(guard) => guard,
             Err(e) => {
-                eprintln!("Failed to acquire lock for Apple Silicon GPU cache: {e}");
+                tracing::warn!("Failed to acquire lock for Apple Silicon GPU cache: {e}");
                 return;
             }
         };
@@ -224,8 +226,6 @@ impl GpuReader for AppleSiliconNativeGpuReader {
             instance: get_hostname(),
             utilization: metrics.utilization.unwrap_or(0.0),
             ane_utilization: metrics.ane_utilization.unwrap_or(0.0),
-            dla_utilization: None,
-            tensorcore_utilization: None,
             temperature,
             used_memory: get_used_memory(),
             total_memory: get_total_memory(),
@@ -233,13 +233,50 @@ impl GpuReader for AppleSiliconNativeGpuReader {
             power_consumption: metrics.power_consumption.unwrap_or(0.0),
             gpu_core_count: apple_info.and_then(|i| i.gpu_core_count),
             detail,
+            ..Default::default()
         }]
     }
 
     fn get_process_info(&self) -> Vec<ProcessInfo> {
-        // Native APIs don't provide per-process GPU usage
-        // Return empty for now - could be enhanced with Metal Performance Shaders API
-        vec![]
+        self.ensure_initialized();
+        let device_uuid = self
+            .static_info
+            .get()
+            .and_then(|info| info.uuid.clone())
+            .unwrap_or_else(|| "AppleSiliconGPU".to_string());
+
+        // GPU memory is unified with system memory, so per-process figures
+        // are estimates derived from the GPU-wide total
+        let mut processes: Vec<ProcessInfo> = gpu_clients()
+            .into_iter()
+            .map(|client| ProcessInfo {
+                device_id: 0,
+                device_uuid: device_uuid.clone(),
+                pid: client.pid,
+                process_name: client.name,
+                used_memory: client.used_memory.unwrap_or(0),
+                used_memory_estimated: client.used_memory.is_some(),
+                cpu_percent: 0.0,
+                memory_percent: 0.0,
+                memory_rss: 0,
+                memory_vms: 0,
+                user: String::new(),
+                state: String::new(),
+                start_time: 0,
+                cpu_time: 0,
+                command: String::new(),
+                ppid: 0,
+                threads: 0,
+                uses_gpu: true,
+                priority: 0,
+                nice_value: 0,
+                gpu_utilization: 0.0,
+                k8s_pod: None,
+                host_id: String::new(),
+            })
+            .collect();
+        fill_start_times(&mut processes);
+        processes
     }
 }
 
@@ -21,6 +21,7 @@
 //!
 //! This reader uses native macOS APIs (no sudo required)
 
+use crate::device::host_info::read_host_system_info;
 use crate::device::macos_native::get_native_metrics_manager;
 use crate::device::{ChassisInfo, ChassisReader};
 use crate::utils::get_hostname;
@@ -92,6 +93,9 @@ impl ChassisReader for AppleSiliconNativeChassisReader {
             );
         }
 
+        // Time spent at each thermal pressure level since start
+        detail.extend(manager.thermal_history_detail());
+
         // Calculate total power (combined_power_mw includes CPU+GPU+ANE)
         let total_power_watts = if data.combined_power_mw > 0.0 {
             Some(validate_power(data.combined_power_mw))
@@ -117,6 +121,8 @@ impl ChassisReader for AppleSiliconNativeChassisReader {
             thermal_pressure: data.thermal_pressure_level,
             fan_speeds: Vec::new(), // Fan control is managed by macOS
             psu_status: Vec::new(), // Not applicable for laptops/desktops
+            system: Some(read_host_system_info()),
+            draining: false, // Reported by the runtime environment instead
             detail,
             time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
         })
@@ -17,6 +17,7 @@
 //! This reader aggregates GPU power consumption to provide chassis-level
 //! power metrics. It serves as a foundation for future BMC/IPMI integration.
 
+use crate::device::host_info::read_host_system_info;
 use crate::device::{ChassisInfo, ChassisReader};
 use crate::utils::get_hostname;
 use chrono::Local;
@@ -91,6 +92,8 @@ impl ChassisReader for GenericChassisReader {
             thermal_pressure: None,   // Not applicable for non-Apple platforms
             fan_speeds: Vec::new(),   // Future: IPMI integration
             psu_status: Vec::new(),   // Future: IPMI integration
+            system: Some(read_host_system_info()),
+            draining: false, // Reported by the runtime environment instead
             detail,
             time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
         })
@@ -17,6 +17,7 @@ use crate::device::common::execute_command_default;
 use crate::device::common::parsers::{
     parse_device_id, parse_frequency_mhz, parse_memory_mb_to_bytes, parse_power, parse_temperature,
 };
+use crate::device::process_list::fill_start_times;
 use crate::device::readers::common_cache::{DetailBuilder, DeviceStaticInfo};
 use crate::device::types::{GpuInfo, ProcessInfo};
 use crate::device::GpuReader;
@@ -92,6 +93,7 @@ struct FuriosaPsOutputJson {
     npu: String,
     pid: u32,
     cmd: String,
+    #[serde(default)]
     memory: String,
 }
 
@@ -333,15 +335,9 @@ impl FuriosaNpuReader {
             Err(_) => return Vec::new(),
         };
 
-        let processes: Vec<FuriosaPsOutputJson> = match serde_json::from_str(&output.stdout) {
-            Ok(procs) => procs,
-            Err(_) => return Vec::new(),
-        };
-
+        let mut processes = parse_ps_output(&output.stdout, self.get_device_static_info_cli());
+        fill_start_times(&mut processes);
         processes
-            .into_iter()
-            .map(|proc| create_process_info_from_ps(&proc))
-            .collect()
     }
 }
 
@@ -362,9 +358,10 @@ fn calculate_device_memory_usage(processes: &[FuriosaPsOutputJson]) -> HashMap<S
 
     for proc in processes {
         let memory_bytes = parse_memory_mb_to_bytes(&proc.memory).unwrap_or_else(|| {
-            eprintln!(
+            tracing::debug!(
                 "Failed to parse memory for process {}: {}",
-                proc.pid, proc.memory
+                proc.pid,
+                proc.memory
             );
             0
         });
@@ -390,15 +387,15 @@ fn create_gpu_info_from_cli_cached(
 
     // Parse dynamic metrics only
     let temperature = parse_temperature(&device.temperature).unwrap_or_else(|| {
-        eprintln!("Failed to parse temperature: {}", device.temperature);
+        tracing::debug!("Failed to parse temperature: {}", device.temperature);
         0
     });
     let power = parse_power(&device.power).unwrap_or_else(|| {
-        eprintln!("Failed to parse power: {}", device.power);
+        tracing::debug!("Failed to parse power: {}", device.power);
         0.0
     });
     let frequency = parse_frequency_mhz(&device.core_clock).unwrap_or_else(|| {
-        eprintln!("Failed to parse frequency: {}", device.core_clock);
+        tracing::debug!("Failed to parse frequency: {}", device.core_clock);
         0
     });
 
@@ -434,16 +431,13 @@ fn create_gpu_info_from_cli_cached(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization,
-        ane_utilization: 0.0,
-        dla_utilization: None,
-        tensorcore_utilization: None,
         temperature,
         used_memory,
         total_memory: FURIOSA_HBM3_MEMORY_BYTES,
         frequency,
         power_consumption: power,
-        gpu_core_count: None,
         detail,
+        ..Default::default()
     })
 }
 
@@ -477,15 +471,15 @@ fn create_gpu_info_from_cli(
     detail.insert("lib_version".to_string(), device.pert.clone());
 
     let temperature = parse_temperature(&device.temperature).unwrap_or_else(|| {
-        eprintln!("Failed to parse temperature: {}", device.temperature);
+        tracing::debug!("Failed to parse temperature: {}", device.temperature);
         0
     });
     let power = parse_power(&device.power).unwrap_or_else(|| {
-        eprintln!("Failed to parse power: {}", device.power);
+        tracing::debug!("Failed to parse power: {}", device.power);
         0.0
     });
     let frequency = parse_frequency_mhz(&device.core_clock).unwrap_or_else(|| {
-        eprintln!("Failed to parse frequency: {}", device.core_clock);
+        tracing::debug!("Failed to parse frequency: {}", device.core_clock);
         0
     });
 
@@ -518,16 +512,13 @@ fn create_gpu_info_from_cli(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization,
-        ane_utilization: 0.0,
-        dla_utilization: None,
-        tensorcore_utilization: None,
         temperature,
         used_memory,
         total_memory: FURIOSA_HBM3_MEMORY_BYTES,
         frequency,
         power_consumption: power,
-        gpu_core_count: None,
         detail,
+        ..Default::default()
     })
 }
 
@@ -578,8 +569,6 @@ fn create_gpu_info_from_device_2025_cached(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization: avg_util,
-        ane_utilization: 0.0,
-        dla_utilization: None,
         temperature: temperature.0 as u32, // DeviceTemperature is a tuple struct
         used_memory,
         total_memory,
@@ -587,6 +576,7 @@ fn create_gpu_info_from_device_2025_cached(
         power_consumption: *power,
         gpu_core_count,
         detail,
+        ..Default::default()
     })
 }
 
@@ -650,8 +640,6 @@ fn create_gpu_info_from_device_2025(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization: avg_util,
-        ane_utilization: 0.0,
-        dla_utilization: None,
         temperature: temperature.0 as u32, // DeviceTemperature is a tuple struct
         used_memory,
         total_memory,
@@ -659,35 +647,61 @@ fn create_gpu_info_from_device_2025(
         power_consumption: *power,
         gpu_core_count: Some(info.core_num()),
         detail,
+        ..Default::default()
     })
 }
 
-fn create_process_info_from_ps(proc: &FuriosaPsOutputJson) -> ProcessInfo {
+/// Processes listed by `furiosa-smi ps`, with the NPU names (`npu0`) mapped
+/// to the UUIDs of the devices in `static_info`, keyed by device index
+fn parse_ps_output(
+    stdout: &str,
+    static_info: &HashMap<String, DeviceStaticInfo>,
+) -> Vec<ProcessInfo> {
+    let processes: Vec<FuriosaPsOutputJson> = match serde_json::from_str(stdout) {
+        Ok(procs) => procs,
+        Err(_) => return Vec::new(),
+    };
+
+    processes
+        .iter()
+        .map(|proc| {
+            let device_uuid = parse_device_id(&proc.npu)
+                .and_then(|index| static_info.get(&index.to_string()))
+                .and_then(|info| info.uuid.clone())
+                .unwrap_or_else(|| proc.npu.clone());
+            create_process_info_from_ps(proc, device_uuid)
+        })
+        .collect()
+}
+
+fn create_process_info_from_ps(proc: &FuriosaPsOutputJson, device_uuid: String) -> ProcessInfo {
     let device_id = parse_device_id(&proc.npu).unwrap_or_else(|| {
-        eprintln!("Failed to parse device ID: {}", proc.npu);
+        tracing::debug!("Failed to parse device ID: {}", proc.npu);
         0
     });
     let used_memory = parse_memory_mb_to_bytes(&proc.memory).unwrap_or_else(|| {
-        eprintln!(
+        tracing::debug!(
             "Failed to parse memory for process {}: {}",
-            proc.pid, proc.memory
+            proc.pid,
+            proc.memory
         );
         0
     });
 
     ProcessInfo {
         device_id,
-        device_uuid: proc.npu.clone(),
+        device_uuid,
         pid: proc.pid,
         process_name: extract_process_name(&proc.cmd),
         used_memory,
+        used_memory_estimated: false,
         cpu_percent: 0.0,
         memory_percent: 0.0,
         memory_rss: 0,
         memory_vms: 0,
         user: String::new(),
         state: String::new(),
-        start_time: String::new(),
+        start_time: 0,
         cpu_time: 0,
         command: proc.cmd.clone(),
         ppid: 0,
@@ -696,6 +710,8 @@ fn create_process_info_from_ps(proc: &FuriosaPsOutputJson) -> ProcessInfo {
         priority: 0,
         nice_value: 0,
         gpu_utilization: 0.0,
+        k8s_pod: None,
+        host_id: String::new(),
     }
 }
 
@@ -706,3 +722,53 @@ fn extract_process_name(cmd: &str) -> String {
         .unwrap_or("unknown")
         .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ps_output_maps_to_device_uuids() {
+        let static_info = HashMap::from([(
+            "0".to_string(),
+            DeviceStaticInfo::with_details(
+                "Furiosa RNGD".to_string(),
+                Some("A76AAD68-6855-40B1-9E86-D080852D1C80".to_string()),
+                HashMap::new(),
+            ),
+        )]);
+        let stdout = r#"[
+            {"npu": "npu0", "pid": 1201, "cmd": "/usr/bin/python3 infer.py", "memory": "2048MB"},
+            {"npu": "npu1", "pid": 1302, "cmd": "furiosa-llm serve"}
+        ]"#;
+
+        let processes: Vec<(u32, usize, String, String, u64)> =
+            parse_ps_output(stdout, &static_info)
+                .into_iter()
+                .map(|p| {
+                    (
+                        p.pid,
+                        p.device_id,
+                        p.device_uuid,
+                        p.process_name,
+                        p.used_memory,
+                    )
+                })
+                .collect();
+        assert_eq!(
+            processes,
+            vec![
+                (
+                    1201,
+                    0,
+                    "A76AAD68-6855-40B1-9E86-D080852D1C80".to_string(),
+                    "python3".to_string(),
+                    2048 << 20
+                ),
+                // Not in the device list and no memory figure
+                (1302, 1, "npu1".to_string(), "furiosa-llm".to_string(), 0),
+            ]
+        );
+        assert!(parse_ps_output("not json", &static_info).is_empty());
+    }
+}
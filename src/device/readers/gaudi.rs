@@ -13,7 +13,14 @@
 // limitations under the License.
 
 #[cfg(target_os = "linux")]
-use crate::device::hlsmi::parser::{map_device_name, GaudiDeviceMetrics};
+use crate::device::common::execute_command_default;
+#[cfg(target_os = "linux")]
+use crate::device::hlsmi::parser::{
+    map_device_name, parse_hlsmi_hbm, parse_hlsmi_processes, GaudiDeviceMetrics, GaudiProcess,
+    HbmStack,
+};
+#[cfg(target_os = "linux")]
+use crate::device::process_list::fill_start_times;
 #[cfg(target_os = "linux")]
 use crate::device::readers::common_cache::{DetailBuilder, DeviceStaticInfo};
 use crate::device::types::{GpuInfo, ProcessInfo};
@@ -167,16 +174,56 @@ impl GaudiNpuReader {
 
         let time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let hostname = get_hostname();
+        let hbm = query_hbm_stacks();
 
         metrics_data
             .devices
             .into_iter()
             .filter_map(|device| {
                 let static_info = self.get_device_static_info(&device.uuid);
-                create_gpu_info_from_device(device, static_info, &time, &hostname)
+                let stacks = hbm.get(&device.index).cloned().unwrap_or_default();
+                let mut info = create_gpu_info_from_device(device, static_info, &time, &hostname)?;
+                insert_hbm_detail(&mut info.detail, &stacks);
+                Some(info)
             })
             .collect()
     }
+
+    /// Get the processes in the "Compute Processes" table of `hl-smi`
+    #[cfg(target_os = "linux")]
+    fn get_process_info_internal(&self) -> Vec<ProcessInfo> {
+        if !Self::is_hlsmi_available() {
+            return Vec::new();
+        }
+        let output = match execute_command_default("hl-smi", &[]) {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::debug!("hl-smi process query failed: {e}");
+                return Vec::new();
+            }
+        };
+
+        // hl-smi lists processes by AIP index; the CSV stream has the UUIDs
+        let uuids: HashMap<u32, String> = hlsmi::get_hlsmi_manager()
+            .and_then(|manager| manager.get_latest_data_result().ok())
+            .map(|data| {
+                data.devices
+                    .into_iter()
+                    .map(|device| (device.index, device.uuid))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut processes: Vec<ProcessInfo> = parse_hlsmi_processes(&output.stdout)
+            .into_iter()
+            .map(|process| {
+                let uuid = uuids.get(&process.aip).cloned().unwrap_or_default();
+                create_process_info(process, uuid)
+            })
+            .collect();
+        fill_start_times(&mut processes);
+        processes
+    }
 }
 
 impl GpuReader for GaudiNpuReader {
@@ -192,14 +239,112 @@ impl GpuReader for GaudiNpuReader {
     }
 
     fn get_process_info(&self) -> Vec<ProcessInfo> {
-        // Intel Gaudi hl-smi doesn't provide process information in the same way
-        // This would require additional integration with Habana tools
-        Vec::new()
+        #[cfg(target_os = "linux")]
+        {
+            self.get_process_info_internal()
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Vec::new()
+        }
     }
 }
 
+/// Usage and temperature of one HBM stack, from the `hbm{N}_used_bytes`
+/// and `hbm{N}_temperature` detail keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HbmUsage {
+    pub stack: u32,
+    pub used_bytes: Option<u64>,
+    pub temperature: Option<u32>,
+}
+
+/// HBM stacks reported for a Gaudi device, ordered by stack
+pub fn hbm_usage(detail: &std::collections::HashMap<String, String>) -> Vec<HbmUsage> {
+    let mut stacks: std::collections::BTreeMap<u32, HbmUsage> = Default::default();
+    for (key, value) in detail {
+        let Some((stack, field)) = key.strip_prefix("hbm").and_then(|key| key.split_once('_'))
+        else {
+            continue;
+        };
+        let Ok(stack) = stack.parse() else {
+            continue;
+        };
+        let usage = stacks.entry(stack).or_insert(HbmUsage {
+            stack,
+            used_bytes: None,
+            temperature: None,
+        });
+        match field {
+            "used_bytes" => usage.used_bytes = value.parse().ok(),
+            "temperature" => usage.temperature = value.parse().ok(),
+            _ => {}
+        }
+    }
+    stacks.into_values().collect()
+}
+
 // Helper functions
 
+/// HBM stacks of every AIP from `hl-smi -q`, empty if the query fails
+#[cfg(target_os = "linux")]
+fn query_hbm_stacks() -> std::collections::BTreeMap<u32, Vec<HbmStack>> {
+    match execute_command_default("hl-smi", &["-q"]) {
+        Ok(output) => parse_hlsmi_hbm(&output.stdout),
+        Err(e) => {
+            tracing::debug!("hl-smi -q failed: {e}");
+            Default::default()
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn insert_hbm_detail(detail: &mut HashMap<String, String>, stacks: &[HbmStack]) {
+    for stack in stacks {
+        if let Some(used) = stack.memory_used {
+            detail.insert(
+                format!("hbm{}_used_bytes", stack.stack),
+                (used * 1024 * 1024).to_string(),
+            );
+        }
+        if let Some(temperature) = stack.temperature {
+            detail.insert(
+                format!("hbm{}_temperature", stack.stack),
+                temperature.to_string(),
+            );
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn create_process_info(process: GaudiProcess, device_uuid: String) -> ProcessInfo {
+    ProcessInfo {
+        device_id: process.aip as usize,
+        device_uuid,
+        pid: process.pid,
+        process_name: process.name.clone(),
+        used_memory: process.memory_used * 1024 * 1024,
+        used_memory_estimated: false,
+        cpu_percent: 0.0,
+        memory_percent: 0.0,
+        memory_rss: 0,
+        memory_vms: 0,
+        user: String::new(),
+        state: String::new(),
+        start_time: 0,
+        cpu_time: 0,
+        command: process.name,
+        ppid: 0,
+        threads: 0,
+        uses_gpu: true,
+        priority: 0,
+        nice_value: 0,
+        gpu_utilization: 0.0,
+        k8s_pod: None,
+        host_id: String::new(),
+    }
+}
+
 #[cfg(target_os = "linux")]
 fn create_gpu_info_from_device(
     device: GaudiDeviceMetrics,
@@ -263,16 +408,13 @@ fn create_gpu_info_from_device(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization: device.utilization,
-        ane_utilization: 0.0,
-        dla_utilization: None,
-        tensorcore_utilization: None,
         temperature: device.temperature,
         used_memory,
         total_memory,
         frequency: 0, // Intel Gaudi doesn't report frequency via hl-smi CSV
         power_consumption: device.power_draw,
-        gpu_core_count: None,
         detail,
+        ..Default::default()
     })
 }
 
@@ -287,6 +429,43 @@ mod tests {
         let _ = reader.get_gpu_info();
     }
 
+    #[test]
+    fn test_hbm_detail_round_trip() {
+        let mut detail = HashMap::new();
+        insert_hbm_detail(
+            &mut detail,
+            &[
+                HbmStack {
+                    stack: 1,
+                    memory_used: Some(2),
+                    temperature: None,
+                },
+                HbmStack {
+                    stack: 0,
+                    memory_used: Some(1),
+                    temperature: Some(44),
+                },
+            ],
+        );
+        assert_eq!(detail["hbm0_used_bytes"], "1048576");
+        assert_eq!(detail["hbm0_temperature"], "44");
+        assert_eq!(
+            hbm_usage(&detail),
+            vec![
+                HbmUsage {
+                    stack: 0,
+                    used_bytes: Some(1048576),
+                    temperature: Some(44),
+                },
+                HbmUsage {
+                    stack: 1,
+                    used_bytes: Some(2097152),
+                    temperature: None,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_is_hlsmi_available() {
         // This will check actual system availability
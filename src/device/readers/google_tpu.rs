@@ -963,8 +963,6 @@ fn create_gpu_info_from_device(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization: device.utilization,
-        ane_utilization: 0.0,
-        dla_utilization: None,
         tensorcore_utilization: tensorcore_util,
         temperature: device.temperature,
         used_memory: device.memory_used,
@@ -973,6 +971,7 @@ fn create_gpu_info_from_device(
         power_consumption: device.power_draw,
         gpu_core_count: Some(device.core_count),
         detail,
+        ..Default::default()
     })
 }
 
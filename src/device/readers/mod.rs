@@ -30,6 +30,8 @@ pub mod gaudi;
 pub mod google_tpu;
 pub mod nvidia;
 pub mod nvidia_jetson;
+// Readers loaded from --plugin shared libraries
+pub mod plugin;
 pub mod rebellions;
 #[cfg(target_os = "linux")]
 pub mod tpu_grpc;
@@ -45,6 +47,12 @@ pub mod tenstorrent;
 
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
 pub mod amd;
+#[cfg(all(target_os = "linux", not(target_env = "musl")))]
+pub mod amd_sysfs;
 
 #[cfg(target_os = "windows")]
 pub mod amd_windows;
+
+// Generic Windows GPU reader (DXGI adapters + PDH performance counters)
+#[cfg(target_os = "windows")]
+pub mod windows_gpu;
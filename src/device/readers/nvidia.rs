@@ -14,12 +14,18 @@
 
 use crate::device::common::constants::BYTES_PER_MB;
 use crate::device::common::{execute_command_default, parse_csv_line};
+use crate::device::gpu_fan::{FAN_SPEED_KEY_PREFIX, MAX_FANS};
+use crate::device::gpu_memory::{BAR1_TOTAL_KEY, BAR1_USED_KEY, RESERVED_MEMORY_KEY};
+use crate::device::gpu_temperature::MEMORY_TEMPERATURE_KEY;
+use crate::device::power_headroom::{POWER_LIMIT_CURRENT_KEY, POWER_LIMIT_ENFORCED_KEY};
 use crate::device::process_list::{get_all_processes, merge_gpu_processes};
 use crate::device::readers::common_cache::{DetailBuilder, DeviceStaticInfo, MAX_DEVICES};
 use crate::device::types::{GpuInfo, ProcessInfo};
 use crate::device::GpuReader;
 use crate::utils::{get_hostname, with_global_system};
 use chrono::Local;
+#[cfg(target_os = "linux")]
+use nvml_wrapper::enum_wrappers::device::{P2pCapabilitiesIndex, P2pStatus, TopologyLevel};
 use nvml_wrapper::enums::device::UsedGpuMemory;
 use nvml_wrapper::error::NvmlError;
 use nvml_wrapper::{cuda_driver_version_major, cuda_driver_version_minor, Nvml};
@@ -122,7 +128,13 @@ impl NvidiaGpuReader {
 
                 for i in 0..device_count {
                     if let Ok(device) = nvml.device_by_index(i) {
-                        let detail = create_device_detail(&device, &driver_version, &cuda_version);
+                        #[cfg_attr(not(target_os = "linux"), allow(unused_mut))]
+                        let mut detail =
+                            create_device_detail(&device, &driver_version, &cuda_version);
+                        #[cfg(target_os = "linux")]
+                        if let Some(peers) = peer_topology(nvml, &device, i, device_count) {
+                            detail.insert(PEER_TOPOLOGY_KEY.to_string(), peers);
+                        }
                         let name = device.name().unwrap_or_else(|_| "Unknown GPU".to_string());
                         let uuid = device.uuid().ok();
                         device_info_map
@@ -156,10 +168,19 @@ impl NvidiaGpuReader {
             for i in 0..device_count {
                 if let Ok(device) = nvml.device_by_index(i) {
                     // Get cached static detail for this device
-                    let detail = device_static_info
+                    let mut detail = device_static_info
                         .get(&i)
                         .map(|info| info.detail.clone())
                         .unwrap_or_default();
+                    add_current_clocks(&device, &mut detail);
+                    add_memory_temperature(&device, &mut detail);
+                    add_bar1_memory(&device, &mut detail);
+                    add_enforced_power_limit(&device, &mut detail);
+                    add_fan_speeds(&device, &mut detail);
+                    let memory = device.memory_info().ok();
+                    if let Some(memory) = &memory {
+                        detail.insert(RESERVED_MEMORY_KEY.to_string(), memory.reserved.to_string());
+                    }
 
                     let info = GpuInfo {
                         uuid: device.uuid().unwrap_or_else(|_| format!("GPU-{i}")),
@@ -173,28 +194,32 @@ impl NvidiaGpuReader {
                             .utilization_rates()
                             .map(|u| u.gpu as f64)
                             .unwrap_or(0.0),
-                        ane_utilization: 0.0,
-                        dla_utilization: None,
-                        tensorcore_utilization: None,
+                        // Not supported on cards without NVENC/NVDEC
+                        encoder_utilization: device
+                            .encoder_utilization()
+                            .ok()
+                            .map(|u| u.utilization as f64),
+                        decoder_utilization: device
+                            .decoder_utilization()
+                            .ok()
+                            .map(|u| u.utilization as f64),
                         temperature: device
                             .temperature(
                                 nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu,
                             )
                             .unwrap_or(0),
-                        used_memory: device.memory_info().map(|m| m.used).unwrap_or(0),
-                        total_memory: device.memory_info().map(|m| m.total).unwrap_or(0),
-                        frequency: device
-                            .clock(
-                                nvml_wrapper::enum_wrappers::device::Clock::Graphics,
-                                nvml_wrapper::enum_wrappers::device::ClockId::Current,
-                            )
+                        used_memory: memory.as_ref().map_or(0, |m| m.used),
+                        total_memory: memory.as_ref().map_or(0, |m| m.total),
+                        frequency: detail
+                            .get("clock_graphics_current")
+                            .and_then(|clock| clock.parse().ok())
                             .unwrap_or(0),
                         power_consumption: device
                             .power_usage()
                             .map(|p| p as f64 / 1000.0)
                             .unwrap_or(0.0),
-                        gpu_core_count: None,
                         detail,
+                        ..Default::default()
                     };
                     gpu_info.push(info);
                 }
@@ -342,21 +367,24 @@ fn create_base_process_info(
         pid,
         process_name: String::new(), // Will be filled by sysinfo
         used_memory: used_memory_mb * BYTES_PER_MB, // Convert MB to bytes
-        cpu_percent: 0.0,            // Will be filled by sysinfo
-        memory_percent: 0.0,         // Will be filled by sysinfo
-        memory_rss: 0,               // Will be filled by sysinfo
-        memory_vms: 0,               // Will be filled by sysinfo
-        user: String::new(),         // Will be filled by sysinfo
-        state: String::new(),        // Will be filled by sysinfo
-        start_time: String::new(),   // Will be filled by sysinfo
-        cpu_time: 0,                 // Will be filled by sysinfo
-        command: String::new(),      // Will be filled by sysinfo
-        ppid: 0,                     // Will be filled by sysinfo
-        threads: 0,                  // Will be filled by sysinfo
+        used_memory_estimated: false,
+        cpu_percent: 0.0,       // Will be filled by sysinfo
+        memory_percent: 0.0,    // Will be filled by sysinfo
+        memory_rss: 0,          // Will be filled by sysinfo
+        memory_vms: 0,          // Will be filled by sysinfo
+        user: String::new(),    // Will be filled by sysinfo
+        state: String::new(),   // Will be filled by sysinfo
+        start_time: 0,          // Will be filled by sysinfo
+        cpu_time: 0,            // Will be filled by sysinfo
+        command: String::new(), // Will be filled by sysinfo
+        ppid: 0,                // Will be filled by sysinfo
+        threads: 0,             // Will be filled by sysinfo
         uses_gpu: true,
         priority: 0,          // Will be filled by sysinfo
         nice_value: 0,        // Will be filled by sysinfo
         gpu_utilization: 0.0, // NVIDIA doesn't provide per-process GPU utilization
+        k8s_pod: None,
+        host_id: String::new(),
     }
 }
 
@@ -377,6 +405,124 @@ macro_rules! add_detail_fmt {
     };
 }
 
+// Current clock of each domain, refreshed every cycle
+fn add_current_clocks(device: &nvml_wrapper::Device, detail: &mut HashMap<String, String>) {
+    use nvml_wrapper::enum_wrappers::device::{Clock, ClockId};
+    for (clock, key) in [
+        (Clock::SM, "clock_sm_current"),
+        (Clock::Graphics, "clock_graphics_current"),
+        (Clock::Memory, "clock_memory_current"),
+        (Clock::Video, "clock_video_current"),
+    ] {
+        add_detail!(detail, device.clock(clock, ClockId::Current), key);
+    }
+}
+
+/// HBM/GDDR temperature from the `NVML_FI_DEV_MEMORY_TEMP` field. Cards
+/// without a memory sensor fail the field or read 0 and get no key. NVML has
+/// no hotspot sensor, so NVIDIA devices never report one.
+fn add_memory_temperature(device: &nvml_wrapper::Device, detail: &mut HashMap<String, String>) {
+    use nvml_wrapper::enums::device::SampleValue;
+    use nvml_wrapper::structs::device::FieldId;
+    use nvml_wrapper::sys_exports::field_id::NVML_FI_DEV_MEMORY_TEMP;
+
+    let Ok(samples) = device.field_values_for(&[FieldId(NVML_FI_DEV_MEMORY_TEMP)]) else {
+        return;
+    };
+    let celsius = match samples.into_iter().next() {
+        Some(Ok(sample)) => match sample.value {
+            Ok(SampleValue::U32(value)) => u64::from(value),
+            Ok(SampleValue::U64(value)) => value,
+            Ok(SampleValue::I64(value)) => value.max(0) as u64,
+            Ok(SampleValue::F64(value)) => value.max(0.0) as u64,
+            Err(_) => return,
+        },
+        _ => return,
+    };
+    if celsius > 0 {
+        detail.insert(MEMORY_TEMPERATURE_KEY.to_string(), celsius.to_string());
+    }
+}
+
+/// BAR1 usage; cards and vGPUs that do not support the query get no keys.
+fn add_bar1_memory(device: &nvml_wrapper::Device, detail: &mut HashMap<String, String>) {
+    match device.bar1_memory_info() {
+        Ok(bar1) if bar1.total > 0 => {
+            detail.insert(BAR1_USED_KEY.to_string(), bar1.used.to_string());
+            detail.insert(BAR1_TOTAL_KEY.to_string(), bar1.total.to_string());
+        }
+        _ => {}
+    }
+}
+
+/// The power limit the driver enforces; read every cycle, since caps can be
+/// changed at runtime.
+fn add_enforced_power_limit(device: &nvml_wrapper::Device, detail: &mut HashMap<String, String>) {
+    if let Ok(milliwatts) = device.enforced_power_limit() {
+        detail.insert(
+            POWER_LIMIT_ENFORCED_KEY.to_string(),
+            format!("{:.2}", milliwatts as f64 / 1000.0),
+        );
+    }
+}
+
+/// Speed of each fan in percent (`nvmlDeviceGetFanSpeed_v2`). Passively
+/// cooled cards report no fans or NotSupported and get no keys; a fan that
+/// fails on its own is skipped.
+fn add_fan_speeds(device: &nvml_wrapper::Device, detail: &mut HashMap<String, String>) {
+    let Ok(fans) = device.num_fans() else {
+        return;
+    };
+    for fan in 0..fans.min(MAX_FANS) {
+        if let Ok(percent) = device.fan_speed(fan) {
+            detail.insert(format!("{FAN_SPEED_KEY_PREFIX}{fan}"), percent.to_string());
+        }
+    }
+}
+
+/// Detail key listing how a GPU reaches each peer, e.g. `GPU1:NV,GPU2:SYS`
+pub const PEER_TOPOLOGY_KEY: &str = "gpu_peer_topology";
+
+/// `nvidia-smi topo -m` code for the path between two GPUs: NVLink, or the
+/// farthest PCIe hop traffic between them crosses.
+#[cfg(target_os = "linux")]
+pub fn peer_link_code(level: TopologyLevel, nvlink: bool) -> &'static str {
+    if nvlink {
+        return "NV";
+    }
+    match level {
+        TopologyLevel::Internal | TopologyLevel::Single => "PIX",
+        TopologyLevel::Multiple => "PXB",
+        TopologyLevel::HostBridge => "PHB",
+        TopologyLevel::Node => "NODE",
+        TopologyLevel::System => "SYS",
+    }
+}
+
+/// Links from `device` (index `index`) to every other GPU, in index order.
+/// Topology never changes at runtime, so this is read with the static info.
+#[cfg(target_os = "linux")]
+fn peer_topology(
+    nvml: &Nvml,
+    device: &nvml_wrapper::Device,
+    index: u32,
+    device_count: u32,
+) -> Option<String> {
+    let peers: Vec<String> = (0..device_count)
+        .filter(|&peer| peer != index)
+        .filter_map(|peer| {
+            let other = nvml.device_by_index(peer).ok()?;
+            let nvlink = matches!(
+                device.p2p_status(&other, P2pCapabilitiesIndex::NvLink),
+                Ok(P2pStatus::Ok)
+            );
+            let level = device.topology_common_ancestor(other).ok()?;
+            Some(format!("GPU{peer}:{}", peer_link_code(level, nvlink)))
+        })
+        .collect();
+    (!peers.is_empty()).then(|| peers.join(","))
+}
+
 // Helper to create device detail HashMap
 fn create_device_detail(
     device: &nvml_wrapper::Device,
@@ -405,11 +551,14 @@ fn create_device_detail(
     add_detail!(detail, device.max_pcie_link_gen(), "pcie_gen_max");
     add_detail!(detail, device.max_pcie_link_width(), "pcie_width_max");
     add_detail!(detail, device.performance_state(), "performance_state");
+    if let Ok(pci_info) = device.pci_info() {
+        detail.insert("PCI Bus ID".to_string(), pci_info.bus_id);
+    }
 
     // Power limits
     if let Ok(power_limit) = device.power_management_limit() {
         detail.insert(
-            "power_limit_current".to_string(),
+            POWER_LIMIT_CURRENT_KEY.to_string(),
             format!("{:.2}", power_limit as f64 / 1000.0),
         );
     }
@@ -487,18 +636,29 @@ fn create_device_detail(
     detail
 }
 
+/// `--query-gpu` fields read by [`parse_nvidia_smi_gpus`]
+pub const NVIDIA_SMI_GPU_QUERY: &str = "index,uuid,name,utilization.gpu,temperature.gpu,memory.used,memory.total,clocks.gr,power.draw,clocks.sm,clocks.mem,clocks.video";
+
 // Fallback implementation using nvidia-smi
 fn get_gpu_info_nvidia_smi() -> Vec<GpuInfo> {
-    let output = match execute_command_default("nvidia-smi", &[
-        "--query-gpu=index,uuid,name,utilization.gpu,temperature.gpu,memory.used,memory.total,clocks.gr,power.draw",
-        "--format=csv,noheader,nounits"
-    ]) {
+    let output = match execute_command_default(
+        "nvidia-smi",
+        &[
+            &format!("--query-gpu={NVIDIA_SMI_GPU_QUERY}"),
+            "--format=csv,noheader,nounits",
+        ],
+    ) {
         Ok(output) => output.stdout,
         Err(_) => return Vec::new(),
     };
+    parse_nvidia_smi_gpus(&output, &get_hostname())
+}
 
+/// GPUs in the output of `nvidia-smi --query-gpu=<NVIDIA_SMI_GPU_QUERY>
+/// --format=csv,noheader,nounits` run on `hostname`
+pub fn parse_nvidia_smi_gpus(output: &str, hostname: &str) -> Vec<GpuInfo> {
     let time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-    let hostname = get_hostname();
+    let hostname = hostname.to_string();
 
     output
         .lines()
@@ -514,17 +674,14 @@ fn get_gpu_info_nvidia_smi() -> Vec<GpuInfo> {
                     hostname: hostname.clone(),
                     instance: hostname.clone(),
                     utilization: parts[3].parse().unwrap_or(0.0),
-                    ane_utilization: 0.0,
-                    dla_utilization: None,
-                    tensorcore_utilization: None,
                     temperature: parts[4].parse().unwrap_or(0),
                     used_memory: parse_memory_value(&parts[5]),
                     total_memory: parse_memory_value(&parts[6]),
                     frequency: parts[7].parse().unwrap_or(0),
                     power_consumption: parts[8].replace("[N/A]", "0").parse::<f64>().unwrap_or(0.0)
                         / 1000.0,
-                    gpu_core_count: None,
-                    detail: HashMap::new(),
+                    detail: smi_clock_details(&parts),
+                    ..Default::default()
                 })
             } else {
                 None
@@ -533,6 +690,24 @@ fn get_gpu_info_nvidia_smi() -> Vec<GpuInfo> {
         .collect()
 }
 
+// Clock columns following power.draw in the --query-gpu list; "[N/A]" and
+// missing columns are skipped
+fn smi_clock_details(parts: &[String]) -> HashMap<String, String> {
+    let clocks = [
+        (7, "clock_graphics_current"),
+        (9, "clock_sm_current"),
+        (10, "clock_memory_current"),
+        (11, "clock_video_current"),
+    ];
+    clocks
+        .into_iter()
+        .filter_map(|(column, key)| {
+            let clock = parts.get(column)?.parse::<u32>().ok()?;
+            Some((key.to_string(), clock.to_string()))
+        })
+        .collect()
+}
+
 // Get GPU processes using nvidia-smi
 fn get_gpu_processes_nvidia_smi() -> (Vec<ProcessInfo>, HashSet<u32>) {
     let mut gpu_processes = Vec::new();
@@ -560,13 +735,14 @@ fn get_gpu_processes_nvidia_smi() -> (Vec<ProcessInfo>, HashSet<u32>) {
                     pid,
                     process_name: String::new(),
                     used_memory: parse_memory_value(&parts[2]),
+                    used_memory_estimated: false,
                     cpu_percent: 0.0,
                     memory_percent: 0.0,
                     memory_rss: 0,
                     memory_vms: 0,
                     user: String::new(),
                     state: String::new(),
-                    start_time: String::new(),
+                    start_time: 0,
                     cpu_time: 0,
                     command: String::new(),
                     ppid: 0,
@@ -575,6 +751,8 @@ fn get_gpu_processes_nvidia_smi() -> (Vec<ProcessInfo>, HashSet<u32>) {
                     priority: 0,
                     nice_value: 0,
                     gpu_utilization: 0.0,
+                    k8s_pod: None,
+                    host_id: String::new(),
                 });
             }
         }
@@ -587,3 +765,18 @@ fn get_gpu_processes_nvidia_smi() -> (Vec<ProcessInfo>, HashSet<u32>) {
 fn parse_memory_value(value: &str) -> u64 {
     value.parse::<u64>().unwrap_or(0) * BYTES_PER_MB // Convert MB to bytes
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_link_code() {
+        assert_eq!(peer_link_code(TopologyLevel::System, true), "NV");
+        assert_eq!(peer_link_code(TopologyLevel::Single, false), "PIX");
+        assert_eq!(peer_link_code(TopologyLevel::Multiple, false), "PXB");
+        assert_eq!(peer_link_code(TopologyLevel::HostBridge, false), "PHB");
+        assert_eq!(peer_link_code(TopologyLevel::Node, false), "NODE");
+        assert_eq!(peer_link_code(TopologyLevel::System, false), "SYS");
+    }
+}
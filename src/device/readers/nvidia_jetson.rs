@@ -19,10 +19,29 @@ use crate::device::types::{GpuInfo, ProcessInfo};
 use crate::device::GpuReader;
 use crate::utils::{get_hostname, hz_to_mhz, millicelsius_to_celsius, with_global_system};
 use chrono::Local;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::path::Path;
 use std::sync::OnceLock;
 
+/// Detail key holding the external memory controller (EMC) clock in Hz
+pub const EMC_FREQUENCY_KEY: &str = "emc_frequency_hz";
+/// Prefix of the detail keys holding each power rail's draw in milliwatts,
+/// e.g. `rail_power_mw_VDD_GPU_SOC`
+pub const RAIL_POWER_KEY_PREFIX: &str = "rail_power_mw_";
+/// Prefix of the detail keys holding each DLA's utilization, e.g.
+/// `dla_utilization_0`
+pub const DLA_UTILIZATION_KEY_PREFIX: &str = "dla_utilization_";
+
+/// EMC clock files, newest L4T first; both report Hz
+const EMC_RATE_PATHS: [&str; 2] = [
+    "sys/kernel/debug/bpmp/debug/clk/emc/rate",
+    "sys/kernel/debug/clk/emc/clk_rate",
+];
+
+/// INA3221 channels per sensor
+const INA_CHANNELS: u32 = 3;
+
 pub struct NvidiaJetsonGpuReader {
     /// Cached static device information (fetched only once)
     static_info: OnceLock<DeviceStaticInfo>,
@@ -132,18 +151,38 @@ impl GpuReader for NvidiaJetsonGpuReader {
             fs::read_to_string("/sys/bus/i2c/drivers/ina3221x/0-0040/iio:device0/in_power0_input")
                 .map_or(0.0, |s| s.trim().parse::<f64>().unwrap_or(0.0) / 1000.0);
 
-        let dla0_util = fs::read_to_string("/sys/kernel/debug/dla_0/load")
-            .map_or(0.0, |s| s.trim().parse::<f64>().unwrap_or(0.0));
-        let dla1_util = fs::read_to_string("/sys/kernel/debug/dla_1/load")
-            .map_or(0.0, |s| s.trim().parse::<f64>().unwrap_or(0.0));
-        let dla_utilization = if dla0_util > 0.0 || dla1_util > 0.0 {
-            Some(dla0_util + dla1_util)
+        let mut detail = static_info.detail.clone();
+        let dla_loads: Vec<f64> = (0..2)
+            .filter_map(|dla| {
+                let load = fs::read_to_string(format!("/sys/kernel/debug/dla_{dla}/load")).ok()?;
+                let load = load.trim().parse::<f64>().ok()?;
+                detail.insert(
+                    format!("{DLA_UTILIZATION_KEY_PREFIX}{dla}"),
+                    load.to_string(),
+                );
+                Some(load)
+            })
+            .collect();
+        let dla_utilization = dla_loads
+            .iter()
+            .any(|&load| load > 0.0)
+            .then(|| dla_loads.iter().sum());
+
+        // tegrastats fills in what sysfs does not show, e.g. without debugfs
+        let tegrastats = read_tegrastats();
+        let (total_memory, used_memory) = get_memory_info(tegrastats.as_deref());
+        #[cfg(target_os = "linux")]
+        add_memory_controller_and_rails(Path::new("/"), tegrastats.as_deref(), &mut detail);
+        // Older boards have no fixed GPU power input; use the GPU rail
+        let power_consumption = if power_consumption > 0.0 {
+            power_consumption
         } else {
-            None
+            rail_power_watts(&detail)
+                .iter()
+                .find(|(rail, _)| rail.contains("GPU"))
+                .map_or(0.0, |(_, watts)| *watts)
         };
 
-        let (total_memory, used_memory) = get_memory_info();
-
         let info = GpuInfo {
             uuid: "JetsonGPU".to_string(),
             time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
@@ -153,16 +192,14 @@ impl GpuReader for NvidiaJetsonGpuReader {
             hostname: get_hostname(), // DNS hostname
             instance: get_hostname(),
             utilization,
-            ane_utilization: 0.0,
             dla_utilization,
-            tensorcore_utilization: None,
             temperature,
             used_memory,
             total_memory,
             frequency,
             power_consumption,
-            gpu_core_count: None,
-            detail: static_info.detail.clone(),
+            detail,
+            ..Default::default()
         };
 
         gpu_info.push(info);
@@ -223,21 +260,24 @@ fn get_gpu_processes() -> (Vec<ProcessInfo>, HashSet<u32>) {
                                 pid,
                                 process_name: String::new(), // Will be filled by sysinfo
                                 used_memory: used_memory_mb * 1024 * 1024, // Convert MB to bytes
-                                cpu_percent: 0.0,            // Will be filled by sysinfo
-                                memory_percent: 0.0,         // Will be filled by sysinfo
-                                memory_rss: 0,               // Will be filled by sysinfo
-                                memory_vms: 0,               // Will be filled by sysinfo
-                                user: String::new(),         // Will be filled by sysinfo
-                                state: String::new(),        // Will be filled by sysinfo
-                                start_time: String::new(),   // Will be filled by sysinfo
-                                cpu_time: 0,                 // Will be filled by sysinfo
-                                command: String::new(),      // Will be filled by sysinfo
-                                ppid: 0,                     // Will be filled by sysinfo
-                                threads: 0,                  // Will be filled by sysinfo
+                                used_memory_estimated: false,
+                                cpu_percent: 0.0,       // Will be filled by sysinfo
+                                memory_percent: 0.0,    // Will be filled by sysinfo
+                                memory_rss: 0,          // Will be filled by sysinfo
+                                memory_vms: 0,          // Will be filled by sysinfo
+                                user: String::new(),    // Will be filled by sysinfo
+                                state: String::new(),   // Will be filled by sysinfo
+                                start_time: 0,          // Will be filled by sysinfo
+                                cpu_time: 0,            // Will be filled by sysinfo
+                                command: String::new(), // Will be filled by sysinfo
+                                ppid: 0,                // Will be filled by sysinfo
+                                threads: 0,             // Will be filled by sysinfo
                                 uses_gpu: true,
                                 priority: 0,          // Will be filled by sysinfo
                                 nice_value: 0,        // Will be filled by sysinfo
                                 gpu_utilization: 0.0, // nvidia-smi on Jetson doesn't provide per-process GPU utilization
+                                k8s_pod: None,
+                                host_id: String::new(),
                             });
                         }
                     }
@@ -273,21 +313,24 @@ fn get_gpu_processes() -> (Vec<ProcessInfo>, HashSet<u32>) {
                             pid: pid_u32,
                             process_name: String::new(), // Will be filled by sysinfo
                             used_memory: 0, // Can't determine GPU memory usage without nvidia-smi
-                            cpu_percent: 0.0, // Will be filled by sysinfo
-                            memory_percent: 0.0, // Will be filled by sysinfo
-                            memory_rss: 0,  // Will be filled by sysinfo
-                            memory_vms: 0,  // Will be filled by sysinfo
-                            user: String::new(), // Will be filled by sysinfo
-                            state: String::new(), // Will be filled by sysinfo
-                            start_time: String::new(), // Will be filled by sysinfo
-                            cpu_time: 0,    // Will be filled by sysinfo
+                            used_memory_estimated: false,
+                            cpu_percent: 0.0,       // Will be filled by sysinfo
+                            memory_percent: 0.0,    // Will be filled by sysinfo
+                            memory_rss: 0,          // Will be filled by sysinfo
+                            memory_vms: 0,          // Will be filled by sysinfo
+                            user: String::new(),    // Will be filled by sysinfo
+                            state: String::new(),   // Will be filled by sysinfo
+                            start_time: 0,          // Will be filled by sysinfo
+                            cpu_time: 0,            // Will be filled by sysinfo
                             command: String::new(), // Will be filled by sysinfo
-                            ppid: 0,        // Will be filled by sysinfo
-                            threads: 0,     // Will be filled by sysinfo
+                            ppid: 0,                // Will be filled by sysinfo
+                            threads: 0,             // Will be filled by sysinfo
                             uses_gpu: true,
                             priority: 0,          // Will be filled by sysinfo
                             nice_value: 0,        // Will be filled by sysinfo
                             gpu_utilization: 0.0, // Can't determine per-process GPU utilization
+                            k8s_pod: None,
+                            host_id: String::new(),
                         });
                         break;
                     }
@@ -299,21 +342,24 @@ fn get_gpu_processes() -> (Vec<ProcessInfo>, HashSet<u32>) {
     (gpu_processes, gpu_pids)
 }
 
-fn get_memory_info() -> (u64, u64) {
+/// One line of `tegrastats` output, if the tool is available
+fn read_tegrastats() -> Option<String> {
+    let output = execute_command_default("tegrastats", &["--once"]).ok()?;
+    (output.status == 0).then_some(output.stdout)
+}
+
+fn get_memory_info(tegrastats: Option<&str>) -> (u64, u64) {
     // Try to get GPU memory from tegrastats
-    if let Ok(output) = execute_command_default("tegrastats", &["--once"]) {
-        if output.status == 0 {
-            let output_str = &output.stdout;
-            // Parse memory info from tegrastats output
-            // Format: RAM 2298/3964MB (lfb 25x4MB) SWAP 0/1982MB (cached 0MB)
-            if let Some(ram_part) = output_str.split("RAM ").nth(1) {
-                if let Some(ram_info) = ram_part.split("MB").next() {
-                    let parts: Vec<&str> = ram_info.split('/').collect();
-                    if parts.len() == 2 {
-                        let used = parts[0].parse::<u64>().unwrap_or(0) * 1024 * 1024;
-                        let total = parts[1].parse::<u64>().unwrap_or(0) * 1024 * 1024;
-                        return (total, used);
-                    }
+    if let Some(output_str) = tegrastats {
+        // Parse memory info from tegrastats output
+        // Format: RAM 2298/3964MB (lfb 25x4MB) SWAP 0/1982MB (cached 0MB)
+        if let Some(ram_part) = output_str.split("RAM ").nth(1) {
+            if let Some(ram_info) = ram_part.split("MB").next() {
+                let parts: Vec<&str> = ram_info.split('/').collect();
+                if parts.len() == 2 {
+                    let used = parts[0].parse::<u64>().unwrap_or(0) * 1024 * 1024;
+                    let total = parts[1].parse::<u64>().unwrap_or(0) * 1024 * 1024;
+                    return (total, used);
                 }
             }
         }
@@ -342,3 +388,230 @@ fn get_memory_info() -> (u64, u64) {
         (0, 0)
     }
 }
+
+/// EMC clock and rail power from sysfs under `root`, falling back to the
+/// `tegrastats` line for whatever sysfs does not show.
+#[cfg(target_os = "linux")]
+fn add_memory_controller_and_rails(
+    root: &Path,
+    tegrastats: Option<&str>,
+    detail: &mut HashMap<String, String>,
+) {
+    let emc_hz = EMC_RATE_PATHS
+        .iter()
+        .find_map(|path| {
+            fs::read_to_string(root.join(path))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()
+        })
+        .or_else(|| tegrastats.and_then(tegrastats_emc_hz));
+    if let Some(hz) = emc_hz.filter(|&hz: &u64| hz > 0) {
+        detail.insert(EMC_FREQUENCY_KEY.to_string(), hz.to_string());
+    }
+
+    let mut rails = ina3221_rails(root);
+    if rails.is_empty() {
+        rails = tegrastats.map(tegrastats_rails).unwrap_or_default();
+    }
+    for (rail, milliwatts) in rails {
+        detail.insert(
+            format!("{RAIL_POWER_KEY_PREFIX}{rail}"),
+            milliwatts.to_string(),
+        );
+    }
+}
+
+/// Power of each labelled INA3221 channel in mW. Current kernels expose the
+/// sensors through hwmon (`inN_label`, `inN_input` in mV and `currN_input`
+/// in mA); older ones through iio (`rail_name_N`, `in_power{N}_input` in mW).
+#[cfg(target_os = "linux")]
+fn ina3221_rails(root: &Path) -> Vec<(String, u64)> {
+    fn read_trimmed(path: &Path) -> Option<String> {
+        Some(fs::read_to_string(path).ok()?.trim().to_string())
+    }
+    fn sub_dirs(dir: &Path, prefix: &str) -> Vec<std::path::PathBuf> {
+        let mut dirs: Vec<_> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with(prefix))
+            .map(|entry| entry.path())
+            .collect();
+        dirs.sort();
+        dirs
+    }
+
+    let mut rails = Vec::new();
+    for sensor in sub_dirs(&root.join("sys/bus/i2c/drivers/ina3221"), "") {
+        for hwmon in sub_dirs(&sensor.join("hwmon"), "hwmon") {
+            for channel in 1..=INA_CHANNELS {
+                let Some(label) = read_trimmed(&hwmon.join(format!("in{channel}_label"))) else {
+                    continue;
+                };
+                let millivolts = read_trimmed(&hwmon.join(format!("in{channel}_input")))
+                    .and_then(|v| v.parse::<u64>().ok());
+                let milliamps = read_trimmed(&hwmon.join(format!("curr{channel}_input")))
+                    .and_then(|v| v.parse::<u64>().ok());
+                if let (Some(mv), Some(ma)) = (millivolts, milliamps) {
+                    rails.push((label, mv * ma / 1000));
+                }
+            }
+        }
+    }
+    for sensor in sub_dirs(&root.join("sys/bus/i2c/drivers/ina3221x"), "") {
+        for iio in sub_dirs(&sensor, "iio:device") {
+            for channel in 0..INA_CHANNELS {
+                let name = read_trimmed(&iio.join(format!("rail_name_{channel}")));
+                let power = read_trimmed(&iio.join(format!("in_power{channel}_input")))
+                    .and_then(|v| v.parse::<u64>().ok());
+                if let (Some(name), Some(power)) = (name, power) {
+                    rails.push((name, power));
+                }
+            }
+        }
+    }
+    rails
+}
+
+/// EMC clock from `EMC_FREQ 12%@2133` (MHz) in a `tegrastats` line
+fn tegrastats_emc_hz(line: &str) -> Option<u64> {
+    let field = line.split("EMC_FREQ ").nth(1)?.split_whitespace().next()?;
+    let mhz: u64 = field.split('@').nth(1)?.parse().ok()?;
+    Some(mhz * 1_000_000)
+}
+
+/// Rail power in mW from a `tegrastats` line: `VDD_GPU_SOC 1234mW/1100mW`
+/// on current releases, `POM_5V_GPU 1234/1100` on older ones. The first
+/// figure is the current draw, the second the average.
+fn tegrastats_rails(line: &str) -> Vec<(String, u64)> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    tokens
+        .windows(2)
+        .filter_map(|pair| {
+            let (name, reading) = (pair[0], pair[1]);
+            let is_rail =
+                name.starts_with("VDD_") || name.starts_with("POM_") || name.starts_with("VIN_");
+            if !is_rail {
+                return None;
+            }
+            let current = reading.split('/').next()?.trim_end_matches("mW");
+            Some((name.to_string(), current.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Whether a detail key holds a Jetson reading that changes every cycle
+pub fn is_jetson_dynamic_key(key: &str) -> bool {
+    key == EMC_FREQUENCY_KEY
+        || key.starts_with(RAIL_POWER_KEY_PREFIX)
+        || key.starts_with(DLA_UTILIZATION_KEY_PREFIX)
+}
+
+/// `(rail, watts)` from the rail power entries of a device's detail map, by
+/// rail name.
+pub fn rail_power_watts(detail: &HashMap<String, String>) -> Vec<(&str, f64)> {
+    let mut rails: Vec<(&str, f64)> = detail
+        .iter()
+        .filter_map(|(key, value)| {
+            let rail = key.strip_prefix(RAIL_POWER_KEY_PREFIX)?;
+            Some((rail, value.parse::<f64>().ok()? / 1000.0))
+        })
+        .collect();
+    rails.sort_by(|a, b| a.0.cmp(b.0));
+    rails
+}
+
+/// `(DLA index, utilization %)` from the per-DLA entries of a device's
+/// detail map, in DLA order.
+pub fn dla_utilization(detail: &HashMap<String, String>) -> Vec<(u32, f64)> {
+    let mut dlas: Vec<(u32, f64)> = detail
+        .iter()
+        .filter_map(|(key, value)| {
+            let dla = key.strip_prefix(DLA_UTILIZATION_KEY_PREFIX)?;
+            Some((dla.parse().ok()?, value.parse().ok()?))
+        })
+        .collect();
+    dlas.sort_by_key(|(dla, _)| *dla);
+    dlas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIN_LINE: &str = "RAM 2298/7620MB (lfb 25x4MB) SWAP 0/3810MB (cached 0MB) \
+        CPU [2%@729,1%@729] EMC_FREQ 3%@2133 GR3D_FREQ 0%@[305] \
+        VDD_GPU_SOC 1597mW/1597mW VDD_CPU_CV 399mW/399mW VIN_SYS_5V0 2818mW/2818mW";
+
+    #[test]
+    fn test_parse_tegrastats() {
+        assert_eq!(tegrastats_emc_hz(ORIN_LINE), Some(2_133_000_000));
+        assert_eq!(
+            tegrastats_rails(ORIN_LINE),
+            [
+                ("VDD_GPU_SOC".to_string(), 1597),
+                ("VDD_CPU_CV".to_string(), 399),
+                ("VIN_SYS_5V0".to_string(), 2818),
+            ]
+        );
+        let nano = "RAM 1200/3964MB EMC_FREQ 0%@1600 POM_5V_IN 2512/2512 POM_5V_GPU 0/12";
+        assert_eq!(
+            tegrastats_rails(nano),
+            [
+                ("POM_5V_IN".to_string(), 2512),
+                ("POM_5V_GPU".to_string(), 0)
+            ]
+        );
+        assert_eq!(tegrastats_emc_hz("RAM 1/2MB"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_sysfs_rails_and_emc() {
+        let root = tempfile::tempdir().unwrap();
+        let hwmon = root
+            .path()
+            .join("sys/bus/i2c/drivers/ina3221/1-0040/hwmon/hwmon3");
+        fs::create_dir_all(&hwmon).unwrap();
+        for (file, value) in [
+            ("in1_label", "VDD_GPU_SOC\n"),
+            ("in1_input", "5000\n"),
+            ("curr1_input", "320\n"),
+            ("in2_label", "VDD_CPU_CV\n"),
+            ("in2_input", "5000\n"),
+            ("curr2_input", "80\n"),
+        ] {
+            fs::write(hwmon.join(file), value).unwrap();
+        }
+        let emc = root.path().join("sys/kernel/debug/bpmp/debug/clk/emc");
+        fs::create_dir_all(&emc).unwrap();
+        fs::write(emc.join("rate"), "3199000000\n").unwrap();
+
+        let mut detail = HashMap::new();
+        add_memory_controller_and_rails(root.path(), Some(ORIN_LINE), &mut detail);
+        assert_eq!(detail[EMC_FREQUENCY_KEY], "3199000000");
+        assert_eq!(
+            rail_power_watts(&detail),
+            [("VDD_CPU_CV", 0.4), ("VDD_GPU_SOC", 1.6)]
+        );
+
+        // Without sysfs, tegrastats fills in
+        let empty = tempfile::tempdir().unwrap();
+        let mut detail = HashMap::new();
+        add_memory_controller_and_rails(empty.path(), Some(ORIN_LINE), &mut detail);
+        assert_eq!(detail[EMC_FREQUENCY_KEY], "2133000000");
+        assert_eq!(rail_power_watts(&detail).len(), 3);
+        assert!(detail.keys().all(|key| is_jetson_dynamic_key(key)));
+    }
+
+    #[test]
+    fn test_dla_utilization_in_order() {
+        let detail = [("dla_utilization_1", "40"), ("dla_utilization_0", "12.5")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        assert_eq!(dla_utilization(&detail), [(0, 12.5), (1, 40.0)]);
+    }
+}
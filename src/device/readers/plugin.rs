@@ -0,0 +1,312 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Device readers backed by `--plugin` shared libraries.
+//!
+//! See [`crate::device::plugin_abi`] for what a plugin has to export.
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chrono::Local;
+use libloading::Library;
+
+use crate::device::plugin_abi::{
+    field_str, AbiVersionFn, AllSmiDevice, DevicesFn, NameFn, ABI_VERSION, ABI_VERSION_SYMBOL,
+    DEVICES_SYMBOL, NAME_SYMBOL,
+};
+use crate::device::{GpuInfo, GpuReader, ProcessInfo};
+use crate::utils::get_hostname;
+
+/// Devices requested per call before retrying with the count the plugin
+/// reports
+const INITIAL_CAPACITY: usize = 64;
+/// Upper bound on devices taken from one plugin
+const MAX_DEVICES: usize = 4096;
+
+static PLUGINS: OnceLock<Vec<Arc<Plugin>>> = OnceLock::new();
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("failed to load plugin {}: {}", path.display(), describe(source))]
+    Load {
+        path: PathBuf,
+        source: libloading::Error,
+    },
+    #[error("plugin {} does not export {symbol}: {}", path.display(), describe(source))]
+    MissingSymbol {
+        path: PathBuf,
+        symbol: &'static str,
+        source: libloading::Error,
+    },
+    #[error(
+        "plugin {} was built for ABI version {found}, this all-smi supports {ABI_VERSION}",
+        path.display()
+    )]
+    AbiVersion { path: PathBuf, found: u32 },
+}
+
+/// libloading puts the dlerror() text in the error's source.
+fn describe(error: &libloading::Error) -> String {
+    match std::error::Error::source(error) {
+        Some(source) => format!("{error}: {source}"),
+        None => error.to_string(),
+    }
+}
+
+/// A loaded plugin library.
+pub struct Plugin {
+    name: String,
+    devices_fn: DevicesFn,
+    /// Serializes calls into the plugin, as the ABI promises
+    lock: Mutex<()>,
+    // Keeps `devices_fn` valid; dropped last
+    _library: Library,
+}
+
+impl Plugin {
+    /// Load the plugin at `path` and check its ABI version.
+    pub fn load(path: &Path) -> Result<Self, PluginError> {
+        // SAFETY: loading a library runs its initializers. The user asked for
+        // this library with --plugin, which is the same trust as running it.
+        let library = unsafe { Library::new(path) }.map_err(|source| PluginError::Load {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let missing = |symbol: &'static str| {
+            move |source| PluginError::MissingSymbol {
+                path: path.to_path_buf(),
+                symbol,
+                source,
+            }
+        };
+
+        // SAFETY: the symbol types are the ones the ABI defines. Function
+        // pointers copied out of the symbols stay valid while `library` lives,
+        // and `Plugin` keeps it alive.
+        let (abi_version, name_fn, devices_fn) = unsafe {
+            let abi_version = *library
+                .get::<AbiVersionFn>(ABI_VERSION_SYMBOL.as_bytes())
+                .map_err(missing(ABI_VERSION_SYMBOL))?;
+            let name_fn = *library
+                .get::<NameFn>(NAME_SYMBOL.as_bytes())
+                .map_err(missing(NAME_SYMBOL))?;
+            let devices_fn = *library
+                .get::<DevicesFn>(DEVICES_SYMBOL.as_bytes())
+                .map_err(missing(DEVICES_SYMBOL))?;
+            (abi_version, name_fn, devices_fn)
+        };
+
+        // SAFETY: ABI functions take no arguments and the name is a static
+        // NUL-terminated string
+        let found = unsafe { abi_version() };
+        if found != ABI_VERSION {
+            return Err(PluginError::AbiVersion {
+                path: path.to_path_buf(),
+                found,
+            });
+        }
+        let name = unsafe {
+            let name = name_fn();
+            if name.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(name).to_string_lossy().into_owned()
+            }
+        };
+        let name = if name.is_empty() {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        } else {
+            name
+        };
+
+        Ok(Self {
+            name,
+            devices_fn,
+            lock: Mutex::new(()),
+            _library: library,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Current devices, or none if the plugin reports an error.
+    pub fn devices(&self) -> Vec<AllSmiDevice> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut buffer = vec![AllSmiDevice::default(); INITIAL_CAPACITY];
+        let mut count = self.call_devices(&mut buffer);
+        if count > buffer.len() {
+            buffer.resize(count.min(MAX_DEVICES), AllSmiDevice::default());
+            count = self.call_devices(&mut buffer);
+        }
+        buffer.truncate(count);
+        buffer
+    }
+
+    fn call_devices(&self, buffer: &mut [AllSmiDevice]) -> usize {
+        // SAFETY: `buffer` holds `buffer.len()` initialized devices for the
+        // plugin to overwrite
+        let count = unsafe { (self.devices_fn)(buffer.as_mut_ptr(), buffer.len()) };
+        usize::try_from(count).unwrap_or_else(|_| {
+            tracing::warn!("Plugin {} failed to list devices ({count})", self.name);
+            0
+        })
+    }
+}
+
+/// Load the `--plugin` libraries. Only the first call takes effect; later
+/// calls return `Ok` without loading anything.
+pub fn load_plugins(paths: &[PathBuf]) -> Result<(), PluginError> {
+    if PLUGINS.get().is_some() {
+        return Ok(());
+    }
+    let plugins = paths
+        .iter()
+        .map(|path| {
+            let plugin = Plugin::load(path)?;
+            tracing::info!("Loaded plugin {} from {}", plugin.name(), path.display());
+            Ok(Arc::new(plugin))
+        })
+        .collect::<Result<Vec<_>, PluginError>>()?;
+    let _ = PLUGINS.set(plugins);
+    Ok(())
+}
+
+/// One reader per loaded plugin.
+pub fn plugin_readers() -> Vec<Box<dyn GpuReader>> {
+    PLUGINS
+        .get()
+        .into_iter()
+        .flatten()
+        .map(|plugin| Box::new(PluginGpuReader::new(plugin.clone())) as Box<dyn GpuReader>)
+        .collect()
+}
+
+/// Wraps a plugin as a [`GpuReader`]. Plugins report devices only, so the
+/// process list is always empty.
+pub struct PluginGpuReader {
+    plugin: Arc<Plugin>,
+}
+
+impl PluginGpuReader {
+    pub fn new(plugin: Arc<Plugin>) -> Self {
+        Self { plugin }
+    }
+}
+
+impl GpuReader for PluginGpuReader {
+    fn get_gpu_info(&self) -> Vec<GpuInfo> {
+        let time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let hostname = get_hostname();
+        self.plugin
+            .devices()
+            .iter()
+            .map(|device| to_gpu_info(device, self.plugin.name(), &time, &hostname))
+            .collect()
+    }
+
+    fn get_process_info(&self) -> Vec<ProcessInfo> {
+        Vec::new()
+    }
+}
+
+fn to_gpu_info(device: &AllSmiDevice, plugin: &str, time: &str, hostname: &str) -> GpuInfo {
+    let device_type = match field_str(&device.device_type) {
+        device_type if device_type.is_empty() => "GPU".to_string(),
+        device_type => device_type,
+    };
+    let mut detail = HashMap::new();
+    detail.insert("plugin".to_string(), plugin.to_string());
+    let driver_version = field_str(&device.driver_version);
+    if !driver_version.is_empty() {
+        detail.insert("driver_version".to_string(), driver_version);
+    }
+
+    GpuInfo {
+        uuid: field_str(&device.uuid),
+        time: time.to_string(),
+        name: field_str(&device.name),
+        device_type,
+        host_id: hostname.to_string(),
+        hostname: hostname.to_string(),
+        instance: hostname.to_string(),
+        utilization: device.utilization,
+        temperature: device.temperature,
+        used_memory: device.used_memory,
+        total_memory: device.total_memory,
+        frequency: device.frequency,
+        power_consumption: device.power_consumption,
+        detail,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::c_char;
+
+    fn set(field: &mut [c_char], value: &str) {
+        for (slot, byte) in field.iter_mut().zip(value.bytes()) {
+            *slot = byte as c_char;
+        }
+    }
+
+    #[test]
+    fn test_to_gpu_info() {
+        let mut device = AllSmiDevice {
+            utilization: 42.5,
+            temperature: 55,
+            frequency: 1500,
+            used_memory: 1 << 30,
+            total_memory: 16 << 30,
+            power_consumption: 120.0,
+            ..Default::default()
+        };
+        set(&mut device.uuid, "acme-0");
+        set(&mut device.name, "Acme X1");
+        set(&mut device.device_type, "NPU");
+        set(&mut device.driver_version, "1.2.3");
+
+        let info = to_gpu_info(&device, "acme", "2025-01-01 00:00:00", "node1");
+        assert_eq!(info.uuid, "acme-0");
+        assert_eq!(info.name, "Acme X1");
+        assert_eq!(info.device_type, "NPU");
+        assert_eq!(info.hostname, "node1");
+        assert_eq!(info.utilization, 42.5);
+        assert_eq!(info.temperature, 55);
+        assert_eq!(info.total_memory, 16 << 30);
+        assert_eq!(info.detail["plugin"], "acme");
+        assert_eq!(info.detail["driver_version"], "1.2.3");
+
+        // Missing device type defaults to GPU
+        let info = to_gpu_info(&AllSmiDevice::default(), "acme", "", "node1");
+        assert_eq!(info.device_type, "GPU");
+        assert!(!info.detail.contains_key("driver_version"));
+    }
+
+    #[test]
+    fn test_load_missing_library() {
+        let err = Plugin::load(Path::new("/nonexistent/libnothing.so"))
+            .err()
+            .unwrap();
+        assert!(matches!(err, PluginError::Load { .. }));
+    }
+}
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use crate::device::common::execute_command_default;
+use crate::device::common::json_parser::{json_f64, json_u64};
 use crate::device::common::parsers::{
-    parse_device_id, parse_memory_mb_to_bytes, parse_power, parse_temperature, parse_utilization,
+    parse_memory_mb_to_bytes, parse_power, parse_temperature, parse_utilization,
 };
+use crate::device::process_list::fill_start_times;
 use crate::device::readers::common_cache::{DetailBuilder, DeviceStaticInfo};
 use crate::device::types::{GpuInfo, ProcessInfo};
 use crate::device::GpuReader;
@@ -24,6 +26,7 @@ use chrono::Local;
 use once_cell::sync::Lazy;
 use serde::de::{self, Deserializer, Visitor};
 use serde::Deserialize;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, OnceLock};
@@ -78,7 +81,6 @@ struct RblnMemoryInfo {
 #[derive(Debug, Deserialize)]
 struct RblnDevice {
     #[serde(deserialize_with = "deserialize_string_or_u32")]
-    #[allow(dead_code)]
     npu: u32,
     name: String,
     sid: String,
@@ -95,8 +97,18 @@ struct RblnDevice {
     board_info: String,
     #[allow(dead_code)]
     location: u32,
+    /// Per-neural-engine utilization, e.g. `[{"ne": 0, "util": "45.2"}]`,
+    /// with the engine's memory in MiB (`"memory": "512.0MiB"`) on drivers
+    /// that split device memory between engines. Older drivers omit it;
+    /// entries are read leniently since the value types have changed
+    /// between SDK releases.
+    #[serde(default)]
+    ne_util: Vec<Value>,
 }
 
+/// Upper bound on neural engines read per device.
+const MAX_NEURAL_ENGINES: usize = 64;
+
 #[derive(Debug, Deserialize)]
 struct RblnResponse {
     #[serde(rename = "KMD_version")]
@@ -106,14 +118,18 @@ struct RblnResponse {
     contexts: Vec<RblnContext>,
 }
 
+/// A process with a context open on an NPU. Read as leniently as the
+/// devices, since the value types follow the same SDK changes.
 #[derive(Debug, Deserialize)]
 struct RblnContext {
-    #[allow(dead_code)]
-    ctx_id: String,
-    npu: String,
+    #[serde(deserialize_with = "deserialize_string_or_u32")]
+    npu: u32,
+    #[serde(deserialize_with = "deserialize_string_or_u32")]
     pid: u32,
     cmd: String,
-    memory: String,
+    /// MiB as a number or a string such as `"512.0MiB"`
+    #[serde(default)]
+    memory: Value,
 }
 
 /// Type alias for the cached command information
@@ -260,11 +276,11 @@ impl RebellionsNpuReader {
         let path_str = match path.to_str() {
             Some(s) if path.is_absolute() && !s.contains("..") => s,
             Some(s) => {
-                eprintln!("Suspicious path detected: {s}");
+                tracing::warn!("Suspicious path detected: {s}");
                 return Vec::new();
             }
             None => {
-                eprintln!("Invalid path for Rebellions command");
+                tracing::warn!("Invalid path for Rebellions command");
                 return Vec::new();
             }
         };
@@ -312,11 +328,11 @@ impl RebellionsNpuReader {
         let path_str = match path.to_str() {
             Some(s) if path.is_absolute() && !s.contains("..") => s,
             Some(s) => {
-                eprintln!("Suspicious path detected: {s}");
+                tracing::warn!("Suspicious path detected: {s}");
                 return Vec::new();
             }
             None => {
-                eprintln!("Invalid path for Rebellions command");
+                tracing::warn!("Invalid path for Rebellions command");
                 return Vec::new();
             }
         };
@@ -331,11 +347,9 @@ impl RebellionsNpuReader {
             Err(_) => return Vec::new(),
         };
 
-        response
-            .contexts
-            .into_iter()
-            .map(create_process_info_from_context)
-            .collect()
+        let mut processes = processes_from_response(response);
+        fill_start_times(&mut processes);
+        processes
     }
 }
 
@@ -386,6 +400,19 @@ fn create_gpu_info_from_device(
     // Dynamic values
     detail.insert("Status".to_string(), device.status.clone());
     detail.insert("Performance State".to_string(), device.pstate.clone());
+    detail.insert("pstate".to_string(), device.pstate.clone());
+    let engines = parse_neural_engines(&device.ne_util);
+    for engine in &engines {
+        let ne = engine.ne;
+        detail.insert(
+            format!("ne_{ne}_utilization"),
+            format!("{:.1}", engine.utilization),
+        );
+        if let Some(memory) = engine.memory_used {
+            detail.insert(format!("ne_{ne}_memory_used"), memory.to_string());
+        }
+    }
+    let gpu_core_count = (!engines.is_empty()).then_some(engines.len() as u32);
 
     // Add unified AI acceleration library labels
     detail.insert("lib_name".to_string(), "RBLN-SDK".to_string());
@@ -406,45 +433,62 @@ fn create_gpu_info_from_device(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization,
-        ane_utilization: 0.0,
-        dla_utilization: None,
-        tensorcore_utilization: None,
         temperature,
         used_memory,
         total_memory,
         frequency: 0, // Rebellions doesn't report frequency
         power_consumption: power,
-        gpu_core_count: None,
+        gpu_core_count,
         detail,
+        ..Default::default()
     })
 }
 
-fn create_process_info_from_context(ctx: RblnContext) -> ProcessInfo {
-    let device_id = parse_device_id(&ctx.npu).unwrap_or_else(|| {
-        eprintln!("Failed to parse device ID: {}", ctx.npu);
-        0
-    });
-    let used_memory = parse_memory_mb_to_bytes(&ctx.memory).unwrap_or_else(|| {
-        eprintln!(
+/// The processes of the `contexts` section, on the devices of the same
+/// response
+fn processes_from_response(response: RblnResponse) -> Vec<ProcessInfo> {
+    let uuids: HashMap<u32, String> = response
+        .devices
+        .into_iter()
+        .map(|device| (device.npu, device.uuid))
+        .collect();
+    response
+        .contexts
+        .into_iter()
+        .map(|ctx| {
+            let uuid = uuids
+                .get(&ctx.npu)
+                .cloned()
+                .unwrap_or_else(|| ctx.npu.to_string());
+            create_process_info_from_context(ctx, uuid)
+        })
+        .collect()
+}
+
+fn create_process_info_from_context(ctx: RblnContext, device_uuid: String) -> ProcessInfo {
+    let used_memory = parse_mib_value(&ctx.memory).unwrap_or_else(|| {
+        tracing::debug!(
             "Failed to parse memory for process {}: {}",
-            ctx.pid, ctx.memory
+            ctx.pid,
+            ctx.memory
         );
         0
     });
 
     ProcessInfo {
-        device_id,
-        device_uuid: ctx.npu,
+        device_id: ctx.npu as usize,
+        device_uuid,
         pid: ctx.pid,
         process_name: extract_process_name(&ctx.cmd),
         used_memory,
+        used_memory_estimated: false,
         cpu_percent: 0.0,
         memory_percent: 0.0,
         memory_rss: 0,
         memory_vms: 0,
         user: String::new(),
         state: String::new(),
-        start_time: String::new(),
+        start_time: 0,
         cpu_time: 0,
         command: ctx.cmd,
         ppid: 0,
@@ -453,13 +497,15 @@ fn create_process_info_from_context(ctx: RblnContext) -> ProcessInfo {
         priority: 0,
         nice_value: 0,
         gpu_utilization: 0.0,
+        k8s_pod: None,
+        host_id: String::new(),
     }
 }
 
 // Helper function to parse temperature with fallback
 fn parse_temp_safe(temp_str: &str) -> u32 {
     parse_temperature(temp_str).unwrap_or_else(|| {
-        eprintln!("Failed to parse temperature: {temp_str}");
+        tracing::debug!("Failed to parse temperature: {temp_str}");
         0
     })
 }
@@ -467,7 +513,7 @@ fn parse_temp_safe(temp_str: &str) -> u32 {
 // Helper function to parse power with fallback
 fn parse_power_safe(power_str: &str) -> f64 {
     parse_power(power_str).unwrap_or_else(|| {
-        eprintln!("Failed to parse power: {power_str}");
+        tracing::debug!("Failed to parse power: {power_str}");
         0.0
     })
 }
@@ -475,25 +521,119 @@ fn parse_power_safe(power_str: &str) -> f64 {
 // Helper function to parse utilization with fallback
 fn parse_util_safe(util_str: &str) -> f64 {
     parse_utilization(util_str).unwrap_or_else(|| {
-        eprintln!("Failed to parse utilization: {util_str}");
+        tracing::debug!("Failed to parse utilization: {util_str}");
         0.0
     })
 }
 
 fn parse_memory(mem: &RblnMemoryInfo) -> (u64, u64) {
     let used = parse_memory_mb_to_bytes(&mem.used).unwrap_or_else(|| {
-        eprintln!("Failed to parse used memory: {}", mem.used);
+        tracing::debug!("Failed to parse used memory: {}", mem.used);
         0
     });
 
     let total = parse_memory_mb_to_bytes(&mem.total).unwrap_or_else(|| {
-        eprintln!("Failed to parse total memory: {}", mem.total);
+        tracing::debug!("Failed to parse total memory: {}", mem.total);
         0
     });
 
     (used, total)
 }
 
+/// One entry of the `ne_util` section
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NeuralEngine {
+    ne: u64,
+    utilization: f64,
+    memory_used: Option<u64>,
+}
+
+/// Engines from the `ne_util` section, sorted by index. Entries without a
+/// valid index and utilization are skipped.
+fn parse_neural_engines(entries: &[Value]) -> Vec<NeuralEngine> {
+    let mut engines: Vec<NeuralEngine> = entries
+        .iter()
+        .take(MAX_NEURAL_ENGINES)
+        .filter_map(|entry| {
+            let ne = json_u64(entry, "ne").ok()?;
+            let util = json_f64(entry, "util").ok()?;
+            util.is_finite().then(|| NeuralEngine {
+                ne,
+                utilization: util.clamp(0.0, 100.0),
+                memory_used: entry.get("memory").and_then(parse_mib_value),
+            })
+        })
+        .collect();
+    engines.sort_by_key(|engine| engine.ne);
+    engines.dedup_by_key(|engine| engine.ne);
+    engines
+}
+
+/// Bytes from a MiB amount given as a number or a string such as
+/// `"512.0MiB"`.
+fn parse_mib_value(value: &Value) -> Option<u64> {
+    let mib = match value {
+        Value::Number(n) => n.as_f64()?,
+        Value::String(s) => s
+            .trim()
+            .trim_end_matches("MiB")
+            .trim_end_matches("MB")
+            .trim()
+            .parse()
+            .ok()?,
+        _ => return None,
+    };
+    (mib.is_finite() && mib >= 0.0).then_some((mib * 1024.0 * 1024.0) as u64)
+}
+
+/// `(engine index, utilization %)` from the `ne_<n>_utilization` entries of
+/// a device's detail map, in engine order.
+pub fn neural_engine_utilization(detail: &HashMap<String, String>) -> Vec<(u32, f64)> {
+    let mut engines: Vec<(u32, f64)> = detail
+        .iter()
+        .filter_map(|(key, value)| {
+            let ne = key.strip_prefix("ne_")?.strip_suffix("_utilization")?;
+            Some((ne.parse().ok()?, value.parse().ok()?))
+        })
+        .collect();
+    engines.sort_by_key(|(ne, _)| *ne);
+    engines
+}
+
+/// Utilization and memory of one NPU core
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoreUsage {
+    pub core: u32,
+    pub utilization: f64,
+    /// Bytes used, when the driver splits memory between cores
+    pub memory_used: Option<u64>,
+}
+
+/// Per-core usage of a Rebellions device. Multi-core devices report each
+/// neural engine; a device without that breakdown is a single core with
+/// the device's utilization and memory.
+pub fn core_usage(info: &GpuInfo) -> Vec<CoreUsage> {
+    let engines = neural_engine_utilization(&info.detail);
+    if engines.is_empty() {
+        return vec![CoreUsage {
+            core: 0,
+            utilization: info.utilization,
+            memory_used: Some(info.used_memory),
+        }];
+    }
+    engines
+        .into_iter()
+        .map(|(core, utilization)| CoreUsage {
+            core,
+            utilization,
+            memory_used: info
+                .detail
+                .get(&format!("ne_{core}_memory_used"))
+                .and_then(|v| v.parse().ok()),
+        })
+        .collect()
+}
+
 fn extract_process_name(cmd: &str) -> String {
     cmd.split_whitespace()
         .next()
@@ -501,3 +641,158 @@ fn extract_process_name(cmd: &str) -> String {
         .unwrap_or("unknown")
         .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `rbln-stat -j` from a driver without the per-engine section.
+    const RBLN_STAT_OLD: &str = r#"{
+        "KMD_version": "1.2.92",
+        "devices": [{
+            "npu": "0",
+            "name": "RBLN-CA12",
+            "sid": "00000000",
+            "uuid": "5A4A5F1E-0000-0000-0000-000000000000",
+            "device": "rbln0",
+            "status": "normal",
+            "fw_ver": "1.0.8",
+            "pci": {"dev": "0000:01:00.0", "bus_id": "0000:01:00.0", "numa_node": "0",
+                    "link_speed": "16.0GT/s", "link_width": "16"},
+            "temperature": "38C",
+            "card_power": "42.5mW",
+            "pstate": "P14",
+            "memory": {"used": "0.0MiB", "total": "16384.0MiB"},
+            "util": "0.0",
+            "board_info": "ATOM",
+            "location": 5
+        }],
+        "contexts": []
+    }"#;
+
+    /// `rbln-stat -j` from a driver that reports neural engines; the engine
+    /// index and value types vary between releases.
+    const RBLN_STAT_NEW: &str = r#"{
+        "KMD_version": "2.0.1",
+        "devices": [{
+            "npu": 0,
+            "name": "RBLN-CA25",
+            "sid": "00000001",
+            "uuid": "5A4A5F1E-0000-0000-0000-000000000001",
+            "device": "rbln0",
+            "status": "normal",
+            "fw_ver": "2.0.0",
+            "pci": {"dev": "0000:02:00.0", "bus_id": "0000:02:00.0", "numa_node": "1",
+                    "link_speed": "32.0GT/s", "link_width": "16"},
+            "temperature": "51C",
+            "card_power": "85.0mW",
+            "pstate": "P0",
+            "memory": {"used": "2048.0MiB", "total": "65536.0MiB"},
+            "util": "47.5",
+            "board_info": "ATOM Max",
+            "location": 5,
+            "ne_util": [
+                {"ne": 1, "util": "50.0", "memory": "1024.0MiB"},
+                {"ne": "0", "util": 45.0, "memory": 512},
+                {"ne": 2, "util": "n/a"},
+                {"ne": 3, "util": 40.5}
+            ]
+        }],
+        "contexts": []
+    }"#;
+
+    fn parse_first_device(json: &str) -> GpuInfo {
+        let response: RblnResponse = serde_json::from_str(json).unwrap();
+        let kmd_version = response.kmd_version.clone();
+        let device = response.devices.into_iter().next().unwrap();
+        create_gpu_info_from_device(device, None, &kmd_version, "t", "node1").unwrap()
+    }
+
+    #[test]
+    fn test_old_driver_json_without_neural_engines() {
+        let info = parse_first_device(RBLN_STAT_OLD);
+
+        assert_eq!(info.detail.get("pstate").map(String::as_str), Some("P14"));
+        assert_eq!(info.utilization, 0.0);
+        assert!(!info.detail.keys().any(|k| k.starts_with("ne_")));
+    }
+
+    #[test]
+    fn test_new_driver_json_with_neural_engines() {
+        let info = parse_first_device(RBLN_STAT_NEW);
+
+        assert_eq!(info.detail.get("pstate").map(String::as_str), Some("P0"));
+        assert_eq!(info.utilization, 47.5);
+        let ne = |i: u32| info.detail.get(&format!("ne_{i}_utilization")).cloned();
+        assert_eq!(ne(0).as_deref(), Some("45.0"));
+        assert_eq!(ne(1).as_deref(), Some("50.0"));
+        assert_eq!(ne(2), None);
+        assert_eq!(ne(3).as_deref(), Some("40.5"));
+        assert_eq!(info.gpu_core_count, Some(3));
+    }
+
+    #[test]
+    fn test_core_usage_single_and_multi_core() {
+        let single = parse_first_device(RBLN_STAT_OLD);
+        assert_eq!(
+            core_usage(&single),
+            vec![CoreUsage {
+                core: 0,
+                utilization: 0.0,
+                memory_used: Some(single.used_memory),
+            }]
+        );
+        assert_eq!(single.gpu_core_count, None);
+
+        let multi = parse_first_device(RBLN_STAT_NEW);
+        let cores: Vec<(u32, f64, Option<u64>)> = core_usage(&multi)
+            .into_iter()
+            .map(|c| (c.core, c.utilization, c.memory_used))
+            .collect();
+        assert_eq!(
+            cores,
+            vec![
+                (0, 45.0, Some(512 << 20)),
+                (1, 50.0, Some(1024 << 20)),
+                (3, 40.5, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contexts_map_to_device_uuids() {
+        let json = RBLN_STAT_NEW.replace(
+            r#""contexts": []"#,
+            r#""contexts": [
+                {"ctx_id": "1", "npu": "0", "pid": "4242", "cmd": "/usr/bin/python3 serve.py",
+                 "memory": "1024.0MiB"},
+                {"ctx_id": 2, "npu": 0, "pid": 4343, "cmd": "rbln-bench", "memory": 256},
+                {"ctx_id": 3, "npu": 7, "pid": 4444, "cmd": "orphan"}
+            ]"#,
+        );
+        let response: RblnResponse = serde_json::from_str(&json).unwrap();
+        let processes: Vec<(u32, usize, String, String, u64)> = processes_from_response(response)
+            .into_iter()
+            .map(|p| {
+                (
+                    p.pid,
+                    p.device_id,
+                    p.device_uuid,
+                    p.process_name,
+                    p.used_memory,
+                )
+            })
+            .collect();
+
+        let uuid = "5A4A5F1E-0000-0000-0000-000000000001".to_string();
+        assert_eq!(
+            processes,
+            vec![
+                (4242, 0, uuid.clone(), "python3".to_string(), 1024 << 20),
+                (4343, 0, uuid, "rbln-bench".to_string(), 256 << 20),
+                // Unknown device and no memory figure
+                (4444, 7, "7".to_string(), "orphan".to_string(), 0),
+            ]
+        );
+    }
+}
@@ -18,13 +18,16 @@ use crate::device::types::{GpuInfo, ProcessInfo};
 use crate::device::GpuReader;
 use crate::utils::{get_hostname, with_global_system};
 use all_smi_luwen_core;
-use all_smi_luwen_if::chip::{Chip, ChipImpl, Telemetry};
+use all_smi_luwen_if::chip::{Chip, ChipImpl, HlComms, Telemetry, Wormhole};
 use all_smi_luwen_if::ChipDetectOptions;
 use all_smi_luwen_ref;
 use chrono::Local;
 use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// Collection method for Tenstorrent NPU metrics
 #[derive(Debug, Clone, Copy)]
@@ -55,6 +58,8 @@ static TENSTORRENT_STATUS: Mutex<Option<String>> = Mutex::new(None);
 struct TenstorrentStaticInfo {
     total_memory: u64,
     tdp_limit: f64,
+    /// N of the `/dev/tenstorrent/N` device file
+    interface_id: Option<u32>,
 }
 
 // Cache entry containing both chip and its static info
@@ -67,6 +72,34 @@ struct CachedChipInfo {
 // Cache for initialized chips and their static info to avoid re-initialization on every measurement
 static INITIALIZED_CHIPS: Lazy<Mutex<Option<Vec<CachedChipInfo>>>> = Lazy::new(|| Mutex::new(None));
 
+// Telemetry heartbeat from the previous sample, keyed by board serial
+static LAST_HEARTBEATS: Lazy<Mutex<HashMap<String, u32>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// Wormhole Ethernet firmware heartbeats, keyed by board serial and port
+static LAST_ETH_HEARTBEATS: Lazy<Mutex<HashMap<(String, usize), u32>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Connection states the Wormhole Ethernet firmware reports per port in
+/// `eth_conn_info`; higher values are connected ports.
+const ETH_UNKNOWN: u32 = 0;
+const ETH_UNCONNECTED: u32 = 1;
+
+/// Device files that processes open to use a board
+const DEVICE_FILE_PREFIX: &str = "/dev/tenstorrent/";
+
+/// The fd scan is skipped on hosts running more processes than this
+const MAX_SCANNED_PROCESSES: usize = 5000;
+
+/// Owners found by the last fd scan are reused for this long
+const OWNER_SCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// When an fd scan ran and the (pid, device file number) pairs it found
+type OwnerScan = (Instant, Vec<(u32, u32)>);
+
+// Last fd scan
+static DEVICE_OWNERS: Lazy<Mutex<Option<OwnerScan>>> = Lazy::new(|| Mutex::new(None));
+
 pub struct TenstorrentReader {
     _config: TenstorrentConfig,
 }
@@ -94,7 +127,7 @@ impl TenstorrentReader {
         let mut chips_guard = match INITIALIZED_CHIPS.lock() {
             Ok(guard) => guard,
             Err(e) => {
-                eprintln!("Failed to acquire lock for Tenstorrent chips: {e}");
+                tracing::warn!("Failed to acquire lock for Tenstorrent chips: {e}");
                 return;
             }
         };
@@ -144,18 +177,50 @@ impl TenstorrentReader {
     }
 
     /// Invalidate cache to force re-detection on next access
-    #[allow(dead_code)]
     pub fn invalidate_cache() {
         if let Ok(mut chips_guard) = INITIALIZED_CHIPS.lock() {
             *chips_guard = None;
         } else {
-            eprintln!("Failed to acquire lock to invalidate Tenstorrent cache");
+            tracing::warn!("Failed to acquire lock to invalidate Tenstorrent cache");
+        }
+        if let Ok(mut heartbeats) = LAST_HEARTBEATS.lock() {
+            heartbeats.clear();
+        }
+        if let Ok(mut heartbeats) = LAST_ETH_HEARTBEATS.lock() {
+            heartbeats.clear();
         }
     }
 
-    /// Get NPU processes (currently returns empty - Tenstorrent doesn't provide process info)
+    /// Processes holding a board's device file open. The driver does not
+    /// report per-process memory, so `used_memory` is 0.
     fn get_npu_processes(&self) -> (Vec<ProcessInfo>, HashSet<u32>) {
-        (Vec::new(), HashSet::new())
+        // Device file number to the board's index and UUID
+        let boards: HashMap<u32, (usize, String)> = match INITIALIZED_CHIPS.lock() {
+            Ok(guard) => guard
+                .iter()
+                .flatten()
+                .enumerate()
+                .filter_map(|(index, cached)| {
+                    let interface_id = cached.tenstorrent_info.interface_id?;
+                    let uuid = cached.static_info.uuid.clone().unwrap_or_default();
+                    Some((interface_id, (index, uuid)))
+                })
+                .collect(),
+            Err(_) => return (Vec::new(), HashSet::new()),
+        };
+        if boards.is_empty() {
+            return (Vec::new(), HashSet::new());
+        }
+
+        let processes: Vec<ProcessInfo> = device_owners()
+            .into_iter()
+            .filter_map(|(pid, interface_id)| {
+                let (index, uuid) = boards.get(&interface_id)?;
+                Some(create_process_info(pid, *index, uuid.clone()))
+            })
+            .collect();
+        let pids = processes.iter().map(|p| p.pid).collect();
+        (processes, pids)
     }
 }
 
@@ -166,7 +231,7 @@ impl GpuReader for TenstorrentReader {
         let chips_guard = match INITIALIZED_CHIPS.lock() {
             Ok(guard) => guard,
             Err(e) => {
-                eprintln!("Failed to acquire lock for Tenstorrent chips: {e}");
+                tracing::warn!("Failed to acquire lock for Tenstorrent chips: {e}");
                 return Vec::new();
             }
         };
@@ -178,7 +243,7 @@ impl GpuReader for TenstorrentReader {
         let time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let hostname = get_hostname();
 
-        cached_chips
+        let gpu_info: Vec<GpuInfo> = cached_chips
             .iter()
             .enumerate()
             .filter_map(|(index, cached)| {
@@ -191,13 +256,24 @@ impl GpuReader for TenstorrentReader {
                     &hostname,
                 )
             })
-            .collect()
+            .collect();
+
+        // Telemetry reads fail on handles opened before a board reset; drop
+        // them so the chips are detected again on the next sample.
+        if gpu_info.len() < cached_chips.len() {
+            drop(chips_guard);
+            Self::invalidate_cache();
+            set_tenstorrent_status(
+                "Tenstorrent telemetry read failed, re-detecting devices".to_string(),
+            );
+        }
+
+        gpu_info
     }
 
     fn get_process_info(&self) -> Vec<ProcessInfo> {
         use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, UpdateKind};
 
-        // Get NPU processes (currently empty for Tenstorrent)
         let (npu_processes, npu_pids) = self.get_npu_processes();
 
         // Use global system instance to avoid file descriptor leak
@@ -257,15 +333,21 @@ fn extract_static_info(chip: &Chip) -> Option<(DeviceStaticInfo, TenstorrentStat
 
     let uuid = Some(telem.board_serial_number_hex());
 
-    // Build detail map using DetailBuilder
+    // Build detail map using DetailBuilder. Keys match the ones read by the
+    // Tenstorrent metric exporter.
     let mut builder = DetailBuilder::new()
-        .insert("Board Type", board_type)
-        .insert("Board ID", telem.board_serial_number_hex())
-        .insert("ARC FW Version", telem.arc_fw_version())
-        .insert("ETH FW Version", telem.eth_fw_version())
-        .insert("FW Date", telem.firmware_date());
+        .insert("board_type", board_type)
+        .insert("board_id", telem.board_serial_number_hex())
+        .insert("arc_fw_version", telem.arc_fw_version())
+        .insert("eth_fw_version", telem.eth_fw_version())
+        .insert("fw_date", telem.firmware_date())
+        .insert("collection_method", "luwen")
+        // Processes are found through their open device files, which tell
+        // nothing about the memory they use
+        .insert("process_memory", "unavailable");
 
     // Extract PCIe information if available
+    let mut interface_id = None;
     if let Ok(Some(device_info)) = chip.get_device_info() {
         let pcie_address = format!(
             "{:04x}:{:02x}:{:02x}.{:x}",
@@ -274,10 +356,13 @@ fn extract_static_info(chip: &Chip) -> Option<(DeviceStaticInfo, TenstorrentStat
         let pcie_link_width = format!("x{}", device_info.pcie_current_link_width());
         let pcie_link_gen = format!("{}", device_info.pcie_current_link_gen());
 
+        interface_id = Some(device_info.interface_id);
         builder = builder
-            .insert("PCIe Address", &pcie_address)
-            .insert("PCIe Vendor ID", format!("0x{:04x}", device_info.vendor))
-            .insert("PCIe Device ID", format!("0x{:04x}", device_info.device_id))
+            .insert("pcie_address", &pcie_address)
+            .insert("pcie_vendor_id", format!("0x{:04x}", device_info.vendor))
+            .insert("pcie_device_id", format!("0x{:04x}", device_info.device_id))
+            .insert("pcie_link_gen", format!("Gen{pcie_link_gen}"))
+            .insert("pcie_link_width", &pcie_link_width)
             .insert_pci_info(
                 Some(&pcie_address),
                 Some(&pcie_link_gen),
@@ -296,7 +381,7 @@ fn extract_static_info(chip: &Chip) -> Option<(DeviceStaticInfo, TenstorrentStat
     } else {
         None
     };
-    builder = builder.insert_optional("DDR FW Version", ddr_fw_version);
+    builder = builder.insert_optional("ddr_fw_version", ddr_fw_version);
 
     let spibootrom_fw_version = if telem.spibootrom_fw_version != 0 {
         Some(format!(
@@ -308,7 +393,7 @@ fn extract_static_info(chip: &Chip) -> Option<(DeviceStaticInfo, TenstorrentStat
     } else {
         None
     };
-    builder = builder.insert_optional("SPIBOOTROM FW Version", spibootrom_fw_version);
+    builder = builder.insert_optional("spibootrom_fw_version", spibootrom_fw_version);
 
     // Determine memory size and TDP based on board type
     let (total_memory, tdp_limit) = determine_memory_and_tdp(board_type);
@@ -319,11 +404,99 @@ fn extract_static_info(chip: &Chip) -> Option<(DeviceStaticInfo, TenstorrentStat
     let tenstorrent_info = TenstorrentStaticInfo {
         total_memory,
         tdp_limit,
+        interface_id,
     };
 
     Some((static_info, tenstorrent_info))
 }
 
+/// Owners of the device files, from a scan at most `OWNER_SCAN_INTERVAL`
+/// old
+fn device_owners() -> Vec<(u32, u32)> {
+    let mut last_scan = match DEVICE_OWNERS.lock() {
+        Ok(guard) => guard,
+        Err(_) => return Vec::new(),
+    };
+    if let Some((at, owners)) = last_scan.as_ref() {
+        if at.elapsed() < OWNER_SCAN_INTERVAL {
+            return owners.clone();
+        }
+    }
+
+    let owners =
+        scan_device_owners(Path::new("/proc"), MAX_SCANNED_PROCESSES).unwrap_or_else(|| {
+            tracing::debug!(
+                "Not scanning for Tenstorrent processes: over {MAX_SCANNED_PROCESSES} processes"
+            );
+            Vec::new()
+        });
+    *last_scan = Some((Instant::now(), owners.clone()));
+    owners
+}
+
+/// `(pid, N)` for each process under `proc_root` with `/dev/tenstorrent/N`
+/// open. `None` when there are more than `max_processes` processes.
+fn scan_device_owners(proc_root: &Path, max_processes: usize) -> Option<Vec<(u32, u32)>> {
+    let pids: Vec<u32> = fs::read_dir(proc_root)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    if pids.len() > max_processes {
+        return None;
+    }
+
+    let mut owners = Vec::new();
+    for pid in pids {
+        // The fds of other users' processes are unreadable without privileges
+        let Ok(fds) = fs::read_dir(proc_root.join(pid.to_string()).join("fd")) else {
+            continue;
+        };
+        let mut devices: Vec<u32> = fds
+            .filter_map(|fd| {
+                let target = fs::read_link(fd.ok()?.path()).ok()?;
+                target
+                    .to_str()?
+                    .strip_prefix(DEVICE_FILE_PREFIX)?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        devices.sort_unstable();
+        devices.dedup();
+        owners.extend(devices.into_iter().map(|device| (pid, device)));
+    }
+    owners.sort_unstable();
+    Some(owners)
+}
+
+fn create_process_info(pid: u32, device_id: usize, device_uuid: String) -> ProcessInfo {
+    ProcessInfo {
+        device_id,
+        device_uuid,
+        pid,
+        process_name: String::new(),
+        used_memory: 0,
+        used_memory_estimated: false,
+        cpu_percent: 0.0,
+        memory_percent: 0.0,
+        memory_rss: 0,
+        memory_vms: 0,
+        user: String::new(),
+        state: String::new(),
+        start_time: 0,
+        cpu_time: 0,
+        command: String::new(),
+        ppid: 0,
+        threads: 0,
+        uses_gpu: true,
+        priority: 0,
+        nice_value: 0,
+        gpu_utilization: 0.0,
+        k8s_pod: None,
+        host_id: String::new(),
+    }
+}
+
 fn determine_memory_and_tdp(board_type: &str) -> (u64, f64) {
     match board_type {
         s if s.contains("e75") => (2 * 1024 * 1024 * 1024, 75.0), // 2GB, 75W
@@ -348,7 +521,12 @@ fn create_gpu_info(
     let telem = chip.get_telemetry().ok()?;
 
     // Build device details
-    let detail = build_device_details(static_info, tenstorrent_info, &telem);
+    let mut detail = build_device_details(static_info, tenstorrent_info, &telem);
+    let health = record_heartbeat(&detail, telem.telemetry_heartbeat());
+    detail.insert("health".to_string(), health.to_string());
+    for (port, up) in eth_links(chip, &telem, &detail) {
+        detail.insert(format!("eth_{port}_link_up"), u8::from(up).to_string());
+    }
 
     // Get dynamic metrics with safe defaults
     let temperature = telem.asic_temperature().round() as u32;
@@ -368,16 +546,13 @@ fn create_gpu_info(
         hostname: hostname.to_string(),
         instance: hostname.to_string(),
         utilization,
-        ane_utilization: 0.0,
-        dla_utilization: None,
-        tensorcore_utilization: None,
         temperature,
         used_memory: 0, // TODO: Implement memory tracking
         total_memory: tenstorrent_info.total_memory,
         frequency,
         power_consumption: power,
-        gpu_core_count: None,
         detail,
+        ..Default::default()
     })
 }
 
@@ -388,44 +563,180 @@ fn build_device_details(
 ) -> HashMap<String, String> {
     // Clone the static details from DeviceStaticInfo
     let mut detail = static_info.detail.clone();
+    detail.extend(telemetry_details(telem));
 
-    // Dynamic telemetry
-    detail.insert(
-        "VDD Voltage".to_string(),
-        format!("{:.3}V", telem.voltage()),
-    );
-    detail.insert("Current".to_string(), format!("{:.2}A", telem.current()));
-    detail.insert(
-        "ASIC Temperature".to_string(),
-        format!("{:.1}°C", telem.asic_temperature()),
+    // Add unified AI acceleration library labels if not already present
+    detail
+        .entry("lib_name".to_string())
+        .or_insert("Luwen".to_string());
+    if let Some(arc_fw) = detail.get("arc_fw_version") {
+        detail.insert("lib_version".to_string(), arc_fw.clone());
+    }
+
+    detail
+}
+
+/// Convert one telemetry sample into detail entries. Values are plain
+/// numbers (registers as hex) so the exporter can parse them directly.
+fn telemetry_details(telem: &Telemetry) -> HashMap<String, String> {
+    let mut detail = HashMap::new();
+    let mut insert = |key: &str, value: String| {
+        detail.insert(key.to_string(), value);
+    };
+
+    // Clocks
+    insert("aiclk_mhz", telem.ai_clk().to_string());
+    insert("axiclk_mhz", telem.axi_clk().to_string());
+    insert("arcclk_mhz", telem.arc_clk().to_string());
+
+    // Temperatures
+    insert(
+        "asic_temperature",
+        format!("{:.1}", telem.asic_temperature()),
     );
-    detail.insert(
-        "VR Temperature".to_string(),
-        format!("{:.1}°C", telem.vreg_temperature()),
+    insert(
+        "vreg_temperature",
+        format!("{:.1}", telem.vreg_temperature()),
     );
-
     if telem.board_temperature != 0 {
-        detail.insert(
-            "Inlet Temperature".to_string(),
-            format!("{:.1}°C", telem.inlet_temperature()),
+        insert(
+            "inlet_temperature",
+            format!("{:.1}", telem.inlet_temperature()),
+        );
+        insert(
+            "outlet_temperature1",
+            format!("{:.1}", telem.outlet_temperature1()),
+        );
+        insert(
+            "outlet_temperature2",
+            format!("{:.1}", telem.outlet_temperature2()),
         );
     }
 
-    detail.insert("AI Clock".to_string(), format!("{}MHz", telem.ai_clk()));
-    detail.insert("ARC Clock".to_string(), format!("{}MHz", telem.arc_clk()));
-    detail.insert("AXI Clock".to_string(), format!("{}MHz", telem.axi_clk()));
+    // Power delivery; the upper halves of TDP/TDC hold the configured limits
+    insert("voltage", format!("{:.3}", telem.voltage()));
+    insert("current", format!("{:.1}", telem.current()));
+    insert("power_watts", format!("{:.1}", telem.power()));
+    let tdp_limit = telem.tdp >> 16;
+    if tdp_limit != 0 {
+        insert("tdp_limit", tdp_limit.to_string());
+    }
+    let tdc_limit = telem.tdc >> 16;
+    if tdc_limit != 0 {
+        insert("tdc_limit", tdc_limit.to_string());
+    }
+    let thermal_limit = telem.thm_limits & 0xffff;
+    if thermal_limit != 0 {
+        insert("thermal_limit", thermal_limit.to_string());
+    }
 
-    // Add unified AI acceleration library labels if not already present
-    detail
-        .entry("lib_name".to_string())
-        .or_insert("Luwen".to_string());
-    if let Some(arc_fw) = detail.get("ARC FW Version") {
-        detail.insert("lib_version".to_string(), arc_fw.clone());
+    // Status registers and health counters
+    insert("heartbeat", telem.telemetry_heartbeat().to_string());
+    insert("arc0_health", telem.arc0_health.to_string());
+    insert("arc3_health", telem.arc3_health.to_string());
+    insert("pcie_status", format!("0x{:08x}", telem.pcie_status));
+    insert("eth_status0", format!("0x{:08x}", telem.eth_status0));
+    insert("eth_status1", format!("0x{:08x}", telem.eth_status1));
+    insert("ddr_status", format!("0x{:08x}", telem.ddr_status));
+    insert("faults", format!("0x{:08x}", telem.faults));
+    insert("throttler", format!("0x{:08x}", telem.throttler));
+    if let Some(ddr_speed) = telem.ddr_speed {
+        insert("dram_speed", ddr_speed.to_string());
+    }
+
+    // Cooling, only on boards with an on-board fan
+    if telem.fan_speed != 0 {
+        insert("fan_speed", telem.fan_speed.to_string());
+    }
+    if telem.fan_rpm != 0 {
+        insert("fan_rpm", telem.fan_rpm.to_string());
     }
 
     detail
 }
 
+/// Compare the heartbeat with the previous sample for this board and
+/// remember it for the next one.
+fn record_heartbeat(detail: &HashMap<String, String>, heartbeat: u32) -> &'static str {
+    let Some(board_id) = detail.get("board_id") else {
+        return "OK";
+    };
+    let Ok(mut heartbeats) = LAST_HEARTBEATS.lock() else {
+        return "OK";
+    };
+    heartbeat_health(heartbeats.insert(board_id.clone(), heartbeat), heartbeat)
+}
+
+/// The ARC firmware advances the heartbeat while it is alive, so a counter
+/// that did not move since the last sample means the board is degraded.
+fn heartbeat_health(previous: Option<u32>, current: u32) -> &'static str {
+    match previous {
+        Some(previous) if previous == current => "Degraded",
+        _ => "OK",
+    }
+}
+
+/// `(port, link up)` for the chip-to-chip Ethernet ports of Wormhole and
+/// Blackhole chips. Grayskull has no Ethernet.
+fn eth_links(
+    chip: &Chip,
+    telem: &Telemetry,
+    detail: &HashMap<String, String>,
+) -> Vec<(usize, bool)> {
+    if let Some(wh) = chip.as_wh() {
+        let board_id = detail.get("board_id").cloned().unwrap_or_default();
+        wormhole_eth_links(chip, wh, &board_id)
+    } else if chip.as_bh().is_some() {
+        blackhole_eth_links(telem.enabled_eth, telem.eth_status0)
+    } else {
+        Vec::new()
+    }
+}
+
+/// Read each Ethernet core's connection state and firmware heartbeat over
+/// the NoC. Ports in an unknown state are skipped.
+fn wormhole_eth_links(chip: &Chip, wh: &Wormhole, board_id: &str) -> Vec<(usize, bool)> {
+    // Firmware addresses are only known once chip init has read the
+    // Ethernet firmware version
+    let addrs = &wh.eth_addrs;
+    if addrs.masked_version == 0 {
+        return Vec::new();
+    }
+    let Ok(mut heartbeats) = LAST_ETH_HEARTBEATS.lock() else {
+        return Vec::new();
+    };
+
+    wh.eth_locations
+        .iter()
+        .enumerate()
+        .filter_map(|(port, core)| {
+            let conn_info = addrs.eth_conn_info + port as u64 * 4;
+            let status = chip.noc_read32(0, core.x, core.y, conn_info).ok()?;
+            if status == ETH_UNKNOWN {
+                return None;
+            }
+            let heartbeat = chip.noc_read32(0, core.x, core.y, addrs.heartbeat).ok()?;
+            let previous = heartbeats.insert((board_id.to_string(), port), heartbeat);
+            Some((port, wormhole_link_up(status, previous, heartbeat)))
+        })
+        .collect()
+}
+
+/// A cabled port is only up while its firmware heartbeat advances, since the
+/// link cannot retrain once the firmware stopped.
+fn wormhole_link_up(status: u32, previous_heartbeat: Option<u32>, heartbeat: u32) -> bool {
+    status != ETH_UNCONNECTED && heartbeat_health(previous_heartbeat, heartbeat) == "OK"
+}
+
+/// Blackhole reports enabled ports in a bitmask and their link state in the
+/// low bits of the live Ethernet status (`eth_status0`).
+fn blackhole_eth_links(enabled_eth: u32, live_status: u32) -> Vec<(usize, bool)> {
+    (0..16)
+        .filter(|port| enabled_eth & (1 << port) != 0)
+        .map(|port| (port, live_status & (1 << port) != 0))
+        .collect()
+}
+
 fn calculate_power(telem: &Telemetry) -> f64 {
     // Calculate power from voltage and current
     // Use telem.power() which internally does voltage * current
@@ -452,3 +763,135 @@ fn estimate_utilization(telem: &Telemetry, tdp_limit: f64) -> f64 {
     // Power is most reliable (60%), clock is secondary (30%), heartbeat is tertiary (10%)
     (power_utilization * 0.6 + clock_utilization * 0.3 + heartbeat_active * 10.0).min(100.0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use all_smi_luwen_core::Arch;
+
+    /// Telemetry captured from an n300 board under load.
+    fn wormhole_fixture() -> Telemetry {
+        Telemetry {
+            arch: Arch::Wormhole,
+            aiclk: 1000,
+            axiclk: 900,
+            arcclk: 540,
+            vcore: 850,
+            asic_temperature: 0x0350,
+            vreg_temperature: 48,
+            board_temperature: 0x001e_2a2c,
+            tdp: (300 << 16) | 87,
+            tdc: (160 << 16) | 102,
+            thm_limits: 75,
+            arc0_health: 123_456,
+            arc3_health: 789,
+            pcie_status: 0x1100_0000,
+            ddr_status: 0x0000_5555,
+            faults: 0,
+            throttler: 0x2,
+            ddr_speed: Some(12000),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_telemetry_details() {
+        let detail = telemetry_details(&wormhole_fixture());
+        let get = |key: &str| detail.get(key).map(String::as_str);
+
+        assert_eq!(get("aiclk_mhz"), Some("1000"));
+        assert_eq!(get("axiclk_mhz"), Some("900"));
+        assert_eq!(get("arcclk_mhz"), Some("540"));
+        assert_eq!(get("asic_temperature"), Some("53.0"));
+        assert_eq!(get("vreg_temperature"), Some("48.0"));
+        assert_eq!(get("inlet_temperature"), Some("30.0"));
+        assert_eq!(get("outlet_temperature1"), Some("42.0"));
+        assert_eq!(get("outlet_temperature2"), Some("44.0"));
+        assert_eq!(get("voltage"), Some("0.850"));
+        assert_eq!(get("current"), Some("102.0"));
+        assert_eq!(get("power_watts"), Some("87.0"));
+        assert_eq!(get("tdp_limit"), Some("300"));
+        assert_eq!(get("tdc_limit"), Some("160"));
+        assert_eq!(get("thermal_limit"), Some("75"));
+        assert_eq!(get("heartbeat"), Some("123456"));
+        assert_eq!(get("pcie_status"), Some("0x11000000"));
+        assert_eq!(get("ddr_status"), Some("0x00005555"));
+        assert_eq!(get("throttler"), Some("0x00000002"));
+        assert_eq!(get("dram_speed"), Some("12000"));
+        assert_eq!(get("fan_speed"), None);
+    }
+
+    #[test]
+    fn test_telemetry_details_without_board_sensors() {
+        let telem = Telemetry {
+            arch: Arch::Blackhole,
+            asic_temperature: (61 << 16) | 0x8000,
+            timer_heartbeat: 42,
+            arc0_health: 7,
+            ..Default::default()
+        };
+        let detail = telemetry_details(&telem);
+
+        assert_eq!(detail.get("asic_temperature").unwrap(), "61.5");
+        // Blackhole reports its heartbeat from the timer, not ARC0 health
+        assert_eq!(detail.get("heartbeat").unwrap(), "42");
+        assert!(!detail.contains_key("inlet_temperature"));
+        assert!(!detail.contains_key("tdp_limit"));
+        assert!(!detail.contains_key("dram_speed"));
+    }
+
+    #[test]
+    fn test_heartbeat_health() {
+        assert_eq!(heartbeat_health(None, 10), "OK");
+        assert_eq!(heartbeat_health(Some(10), 11), "OK");
+        assert_eq!(heartbeat_health(Some(10), 10), "Degraded");
+        // A reset restarts the counter; that still counts as progress
+        assert_eq!(heartbeat_health(Some(500), 3), "OK");
+    }
+
+    #[test]
+    fn test_eth_link_state() {
+        // Ports 0, 1 and 6 enabled; 0 and 6 trained
+        assert_eq!(
+            blackhole_eth_links(0b100_0011, 0b100_0001),
+            vec![(0, true), (1, false), (6, true)]
+        );
+        assert!(blackhole_eth_links(0, 0xffff).is_empty());
+
+        assert!(wormhole_link_up(3, None, 7));
+        assert!(wormhole_link_up(2, Some(7), 8));
+        assert!(!wormhole_link_up(ETH_UNCONNECTED, Some(7), 8));
+        // Cabled, but the firmware stopped
+        assert!(!wormhole_link_up(3, Some(8), 8));
+    }
+
+    #[test]
+    fn test_scan_device_owners() {
+        use std::os::unix::fs::symlink;
+
+        let proc_root = tempfile::tempdir().unwrap();
+        let fd = |pid: &str, fd: &str, target: &str| {
+            let dir = proc_root.path().join(pid).join("fd");
+            fs::create_dir_all(&dir).unwrap();
+            symlink(target, dir.join(fd)).unwrap();
+        };
+        fd("100", "0", "/dev/pts/0");
+        fd("100", "7", "/dev/tenstorrent/1");
+        // Opened twice, listed once
+        fd("200", "3", "/dev/tenstorrent/0");
+        fd("200", "4", "/dev/tenstorrent/0");
+        fd("200", "5", "/dev/tenstorrent/1");
+        fd("300", "3", "/dev/null");
+        fd("400", "3", "/dev/tenstorrent/not-a-board");
+        // Exited between listing /proc and reading its fds
+        fs::create_dir(proc_root.path().join("500")).unwrap();
+        fs::create_dir(proc_root.path().join("self")).unwrap();
+
+        assert_eq!(
+            scan_device_owners(proc_root.path(), 10),
+            Some(vec![(100, 1), (200, 0), (200, 1)])
+        );
+        // Too many processes to scan
+        assert_eq!(scan_device_owners(proc_root.path(), 4), None);
+    }
+}
@@ -0,0 +1,701 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generic Windows GPU reader using DXGI and PDH performance counters
+//!
+//! Adapters are enumerated through DXGI (`IDXGIFactory1::EnumAdapters1`) for
+//! name, vendor and VRAM totals. Utilization and memory usage come from the
+//! "GPU Engine", "GPU Adapter Memory" and "GPU Process Memory" performance
+//! counter sets, the same sources Task Manager uses. Counter instances are
+//! matched to adapters by LUID, which is also used as the device UUID.
+//!
+//! NVIDIA boards are expected to be handled by NVML; this reader covers
+//! Intel and AMD (integrated and discrete) adapters. Temperature is not
+//! exposed through these APIs and is reported as 0.
+
+use crate::device::process_list::fill_start_times;
+use crate::device::types::{GpuInfo, ProcessInfo};
+use crate::device::GpuReader;
+use crate::utils::get_hostname;
+use chrono::Local;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use windows::core::PCWSTR;
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIFactory1, DXGI_ADAPTER_FLAG_SOFTWARE,
+};
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterArrayW,
+    PdhOpenQueryW, PDH_CSTATUS_VALID_DATA, PDH_FMT_COUNTERVALUE_ITEM_W, PDH_FMT_DOUBLE,
+    PDH_FMT_LARGE, PDH_HCOUNTER, PDH_HQUERY, PDH_MORE_DATA,
+};
+
+/// PCI vendor ID for NVIDIA (handled by the NVML reader)
+pub const VENDOR_ID_NVIDIA: u32 = 0x10DE;
+/// PCI vendor ID for AMD/ATI
+pub const VENDOR_ID_AMD: u32 = 0x1002;
+/// PCI vendor ID for Intel
+pub const VENDOR_ID_INTEL: u32 = 0x8086;
+/// PCI vendor ID used by the Microsoft Basic Render Driver
+const VENDOR_ID_MICROSOFT: u32 = 0x1414;
+
+const ENGINE_UTILIZATION_COUNTER: &str = r"\GPU Engine(*)\Utilization Percentage";
+const ADAPTER_DEDICATED_USAGE_COUNTER: &str = r"\GPU Adapter Memory(*)\Dedicated Usage";
+const PROCESS_DEDICATED_USAGE_COUNTER: &str = r"\GPU Process Memory(*)\Dedicated Usage";
+
+/// Adapter locally unique identifier as it appears in PDH instance names
+/// (`luid_0x00000000_0x0000C5F1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AdapterLuid {
+    pub high: u32,
+    pub low: u32,
+}
+
+impl fmt::Display for AdapterLuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:08X}_0x{:08X}", self.high, self.low)
+    }
+}
+
+/// Parsed components of a PDH GPU counter instance name.
+///
+/// Engine instances look like
+/// `pid_1234_luid_0x00000000_0x0000C5F1_phys_0_eng_0_engtype_3D`, adapter memory
+/// instances like `luid_0x00000000_0x0000C5F1_phys_0` and process memory
+/// instances like `pid_1234_luid_0x00000000_0x0000C5F1_phys_0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuCounterInstance {
+    pub pid: Option<u32>,
+    pub luid: AdapterLuid,
+    pub phys: u32,
+    pub engine: Option<u32>,
+    pub engine_type: Option<String>,
+}
+
+fn parse_hex_u32(s: &str) -> Option<u32> {
+    let digits = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Parse a PDH GPU counter instance name into its components.
+///
+/// Returns `None` if the name does not contain a well-formed LUID.
+pub fn parse_counter_instance(name: &str) -> Option<GpuCounterInstance> {
+    let mut pid = None;
+    let mut luid = None;
+    let mut phys = 0;
+    let mut engine = None;
+    let mut engine_type = None;
+
+    let mut parts = name.split('_');
+    while let Some(key) = parts.next() {
+        match key {
+            "pid" => pid = parts.next().and_then(|v| v.parse().ok()),
+            "luid" => {
+                let high = parts.next().and_then(parse_hex_u32)?;
+                let low = parts.next().and_then(parse_hex_u32)?;
+                luid = Some(AdapterLuid { high, low });
+            }
+            "phys" => phys = parts.next().and_then(|v| v.parse().ok()).unwrap_or(0),
+            "eng" => engine = parts.next().and_then(|v| v.parse().ok()),
+            "engtype" => {
+                // Engine type names may themselves contain underscores
+                // (e.g. "VideoDecode_1"), so take the remainder verbatim.
+                let rest: Vec<&str> = parts.by_ref().collect();
+                if !rest.is_empty() {
+                    engine_type = Some(rest.join("_"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(GpuCounterInstance {
+        pid,
+        luid: luid?,
+        phys,
+        engine,
+        engine_type,
+    })
+}
+
+/// Aggregate per-engine utilization samples into per-adapter utilization.
+///
+/// Samples for the same engine type are summed across processes and the
+/// adapter utilization is the busiest engine type, matching Task Manager.
+pub fn aggregate_adapter_utilization(samples: &[(String, f64)]) -> HashMap<AdapterLuid, f64> {
+    let mut per_engine_type: HashMap<(AdapterLuid, String), f64> = HashMap::new();
+    for (name, value) in samples {
+        if let Some(instance) = parse_counter_instance(name) {
+            let engine_type = instance.engine_type.unwrap_or_default();
+            *per_engine_type
+                .entry((instance.luid, engine_type))
+                .or_insert(0.0) += value;
+        }
+    }
+
+    let mut per_adapter: HashMap<AdapterLuid, f64> = HashMap::new();
+    for ((luid, _), value) in per_engine_type {
+        let entry = per_adapter.entry(luid).or_insert(0.0);
+        *entry = entry.max(value.min(100.0));
+    }
+    per_adapter
+}
+
+/// Aggregate per-engine utilization samples into per-process utilization on
+/// each adapter, keyed by `(pid, luid)`.
+pub fn aggregate_process_utilization(
+    samples: &[(String, f64)],
+) -> HashMap<(u32, AdapterLuid), f64> {
+    let mut per_engine_type: HashMap<(u32, AdapterLuid, String), f64> = HashMap::new();
+    for (name, value) in samples {
+        if let Some(instance) = parse_counter_instance(name) {
+            if let Some(pid) = instance.pid {
+                let engine_type = instance.engine_type.unwrap_or_default();
+                *per_engine_type
+                    .entry((pid, instance.luid, engine_type))
+                    .or_insert(0.0) += value;
+            }
+        }
+    }
+
+    let mut per_process: HashMap<(u32, AdapterLuid), f64> = HashMap::new();
+    for ((pid, luid, _), value) in per_engine_type {
+        let entry = per_process.entry((pid, luid)).or_insert(0.0);
+        *entry = entry.max(value.min(100.0));
+    }
+    per_process
+}
+
+/// Sum byte-valued memory samples per adapter (across physical adapters).
+pub fn aggregate_adapter_memory(samples: &[(String, f64)]) -> HashMap<AdapterLuid, u64> {
+    let mut per_adapter: HashMap<AdapterLuid, u64> = HashMap::new();
+    for (name, value) in samples {
+        if let Some(instance) = parse_counter_instance(name) {
+            if instance.pid.is_none() {
+                *per_adapter.entry(instance.luid).or_insert(0) += value.max(0.0) as u64;
+            }
+        }
+    }
+    per_adapter
+}
+
+/// Sum byte-valued memory samples per `(pid, luid)`.
+pub fn aggregate_process_memory(samples: &[(String, f64)]) -> HashMap<(u32, AdapterLuid), u64> {
+    let mut per_process: HashMap<(u32, AdapterLuid), u64> = HashMap::new();
+    for (name, value) in samples {
+        if let Some(instance) = parse_counter_instance(name) {
+            if let Some(pid) = instance.pid {
+                *per_process.entry((pid, instance.luid)).or_insert(0) += value.max(0.0) as u64;
+            }
+        }
+    }
+    per_process
+}
+
+/// Static adapter description obtained from DXGI
+#[derive(Debug, Clone)]
+struct DxgiAdapter {
+    name: String,
+    vendor_id: u32,
+    device_id: u32,
+    luid: AdapterLuid,
+    dedicated_video_memory: u64,
+    shared_system_memory: u64,
+}
+
+/// Enumerate hardware adapters through DXGI, skipping software adapters.
+fn enumerate_dxgi_adapters() -> Vec<DxgiAdapter> {
+    let mut adapters = Vec::new();
+
+    let factory: IDXGIFactory1 = match unsafe { CreateDXGIFactory1() } {
+        Ok(f) => f,
+        Err(e) => {
+            tracing::warn!("Windows GPU: Failed to create DXGI factory: {e}");
+            return adapters;
+        }
+    };
+
+    let mut index = 0;
+    while let Ok(adapter) = unsafe { factory.EnumAdapters1(index) } {
+        index += 1;
+
+        let desc = match unsafe { adapter.GetDesc1() } {
+            Ok(d) => d,
+            Err(_) => continue,
+        };
+
+        if desc.Flags & (DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32) != 0
+            || desc.VendorId == VENDOR_ID_MICROSOFT
+        {
+            continue;
+        }
+
+        let name_len = desc
+            .Description
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(desc.Description.len());
+        let name = String::from_utf16_lossy(&desc.Description[..name_len])
+            .trim()
+            .to_string();
+
+        adapters.push(DxgiAdapter {
+            name,
+            vendor_id: desc.VendorId,
+            device_id: desc.DeviceId,
+            luid: AdapterLuid {
+                high: desc.AdapterLuid.HighPart as u32,
+                low: desc.AdapterLuid.LowPart,
+            },
+            dedicated_video_memory: desc.DedicatedVideoMemory as u64,
+            shared_system_memory: desc.SharedSystemMemory as u64,
+        });
+    }
+
+    adapters
+}
+
+/// Check whether DXGI reports any hardware adapter outside the skipped vendors
+pub fn has_dxgi_adapters(skip_vendor_ids: &[u32]) -> bool {
+    enumerate_dxgi_adapters()
+        .iter()
+        .any(|a| !skip_vendor_ids.contains(&a.vendor_id))
+}
+
+fn to_wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// A PDH query holding the GPU counters we sample.
+///
+/// Utilization is a rate counter, so the query is kept open across calls and
+/// the first sample after opening reports zero.
+struct PdhGpuQuery {
+    query: PDH_HQUERY,
+    engine_utilization: PDH_HCOUNTER,
+    adapter_dedicated: PDH_HCOUNTER,
+    process_dedicated: PDH_HCOUNTER,
+}
+
+// PDH handles are plain opaque pointers that may be used from any thread as
+// long as access is serialized, which the reader's Mutex guarantees.
+unsafe impl Send for PdhGpuQuery {}
+
+impl PdhGpuQuery {
+    fn open() -> Option<Self> {
+        let mut query = PDH_HQUERY::default();
+        if unsafe { PdhOpenQueryW(PCWSTR::null(), 0, &mut query) } != 0 {
+            return None;
+        }
+
+        let add = |path: &str| -> Option<PDH_HCOUNTER> {
+            let wide = to_wide(path);
+            let mut counter = PDH_HCOUNTER::default();
+            let status =
+                unsafe { PdhAddEnglishCounterW(query, PCWSTR(wide.as_ptr()), 0, &mut counter) };
+            (status == 0).then_some(counter)
+        };
+
+        let (Some(engine_utilization), Some(adapter_dedicated), Some(process_dedicated)) = (
+            add(ENGINE_UTILIZATION_COUNTER),
+            add(ADAPTER_DEDICATED_USAGE_COUNTER),
+            add(PROCESS_DEDICATED_USAGE_COUNTER),
+        ) else {
+            unsafe { PdhCloseQuery(query) };
+            return None;
+        };
+
+        // Prime rate counters so the next collection yields real values
+        unsafe { PdhCollectQueryData(query) };
+
+        Some(Self {
+            query,
+            engine_utilization,
+            adapter_dedicated,
+            process_dedicated,
+        })
+    }
+
+    fn collect(&self) -> bool {
+        unsafe { PdhCollectQueryData(self.query) == 0 }
+    }
+
+    /// Read all instances of a counter as `(instance name, value)` pairs
+    fn read_array(counter: PDH_HCOUNTER, as_large: bool) -> Vec<(String, f64)> {
+        let format = if as_large {
+            PDH_FMT_LARGE
+        } else {
+            PDH_FMT_DOUBLE
+        };
+
+        let mut buffer_size: u32 = 0;
+        let mut item_count: u32 = 0;
+        let status = unsafe {
+            PdhGetFormattedCounterArrayW(counter, format, &mut buffer_size, &mut item_count, None)
+        };
+        if status != PDH_MORE_DATA || buffer_size == 0 {
+            return Vec::new();
+        }
+
+        // Items are followed by their name strings in the same buffer; allocate
+        // in units of the item struct so alignment is correct.
+        let item_size = std::mem::size_of::<PDH_FMT_COUNTERVALUE_ITEM_W>();
+        let capacity = (buffer_size as usize).div_ceil(item_size);
+        let mut buffer: Vec<PDH_FMT_COUNTERVALUE_ITEM_W> = vec![Default::default(); capacity];
+
+        let status = unsafe {
+            PdhGetFormattedCounterArrayW(
+                counter,
+                format,
+                &mut buffer_size,
+                &mut item_count,
+                Some(buffer.as_mut_ptr()),
+            )
+        };
+        if status != 0 {
+            return Vec::new();
+        }
+
+        buffer
+            .iter()
+            .take(item_count as usize)
+            .filter(|item| item.FmtValue.CStatus == PDH_CSTATUS_VALID_DATA)
+            .filter_map(|item| {
+                let name = unsafe { item.szName.to_string() }.ok()?;
+                let value = unsafe {
+                    if as_large {
+                        item.FmtValue.Anonymous.largeValue as f64
+                    } else {
+                        item.FmtValue.Anonymous.doubleValue
+                    }
+                };
+                Some((name, value))
+            })
+            .collect()
+    }
+}
+
+impl Drop for PdhGpuQuery {
+    fn drop(&mut self) {
+        unsafe { PdhCloseQuery(self.query) };
+    }
+}
+
+/// Raw counter samples from a single PDH collection
+#[derive(Default)]
+struct CounterSnapshot {
+    engine_utilization: Vec<(String, f64)>,
+    adapter_dedicated: Vec<(String, f64)>,
+    process_dedicated: Vec<(String, f64)>,
+}
+
+pub struct WindowsGpuReader {
+    /// Vendors handled by a more specific reader (e.g. NVIDIA via NVML)
+    skip_vendor_ids: Vec<u32>,
+    adapters: Vec<DxgiAdapter>,
+    query: Mutex<Option<PdhGpuQuery>>,
+}
+
+impl WindowsGpuReader {
+    pub fn new(skip_vendor_ids: Vec<u32>) -> Self {
+        let adapters = enumerate_dxgi_adapters()
+            .into_iter()
+            .filter(|a| !skip_vendor_ids.contains(&a.vendor_id))
+            .collect();
+
+        let query = PdhGpuQuery::open();
+        if query.is_none() {
+            tracing::warn!(
+                "Windows GPU: GPU performance counters unavailable, reporting static info only"
+            );
+        }
+
+        Self {
+            skip_vendor_ids,
+            adapters,
+            query: Mutex::new(query),
+        }
+    }
+
+    fn sample(&self) -> CounterSnapshot {
+        let guard = match self.query.lock() {
+            Ok(g) => g,
+            Err(_) => return CounterSnapshot::default(),
+        };
+        let Some(query) = guard.as_ref() else {
+            return CounterSnapshot::default();
+        };
+        if !query.collect() {
+            return CounterSnapshot::default();
+        }
+
+        CounterSnapshot {
+            engine_utilization: PdhGpuQuery::read_array(query.engine_utilization, false),
+            adapter_dedicated: PdhGpuQuery::read_array(query.adapter_dedicated, true),
+            process_dedicated: PdhGpuQuery::read_array(query.process_dedicated, true),
+        }
+    }
+
+    fn vendor_name(vendor_id: u32) -> &'static str {
+        match vendor_id {
+            VENDOR_ID_AMD => "AMD",
+            VENDOR_ID_INTEL => "Intel",
+            VENDOR_ID_NVIDIA => "NVIDIA",
+            _ => "Unknown",
+        }
+    }
+}
+
+impl GpuReader for WindowsGpuReader {
+    fn get_gpu_info(&self) -> Vec<GpuInfo> {
+        let snapshot = self.sample();
+        let utilization = aggregate_adapter_utilization(&snapshot.engine_utilization);
+        let used_memory = aggregate_adapter_memory(&snapshot.adapter_dedicated);
+
+        let hostname = get_hostname();
+        let time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        self.adapters
+            .iter()
+            .enumerate()
+            .map(|(index, adapter)| {
+                let mut detail = HashMap::new();
+                detail.insert("index".to_string(), index.to_string());
+                detail.insert(
+                    "Vendor".to_string(),
+                    Self::vendor_name(adapter.vendor_id).to_string(),
+                );
+                detail.insert(
+                    "Device ID".to_string(),
+                    format!("0x{:04X}", adapter.device_id),
+                );
+                detail.insert(
+                    "Shared System Memory".to_string(),
+                    adapter.shared_system_memory.to_string(),
+                );
+                detail.insert("lib_name".to_string(), "DXGI/PDH".to_string());
+
+                // Integrated adapters have little or no dedicated memory and
+                // allocate from shared system memory instead.
+                let total_memory = if adapter.dedicated_video_memory > 0 {
+                    adapter.dedicated_video_memory
+                } else {
+                    adapter.shared_system_memory
+                };
+
+                GpuInfo {
+                    uuid: adapter.luid.to_string(),
+                    time: time.clone(),
+                    name: adapter.name.clone(),
+                    device_type: "GPU".to_string(),
+                    host_id: hostname.clone(),
+                    hostname: hostname.clone(),
+                    instance: hostname.clone(),
+                    utilization: utilization.get(&adapter.luid).copied().unwrap_or(0.0),
+                    temperature: 0, // Not exposed via DXGI/PDH
+                    used_memory: used_memory.get(&adapter.luid).copied().unwrap_or(0),
+                    total_memory,
+                    detail,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn get_process_info(&self) -> Vec<ProcessInfo> {
+        let snapshot = self.sample();
+        let utilization = aggregate_process_utilization(&snapshot.engine_utilization);
+        let memory = aggregate_process_memory(&snapshot.process_dedicated);
+
+        let adapter_index: HashMap<AdapterLuid, usize> = self
+            .adapters
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (a.luid, i))
+            .collect();
+
+        let mut keys: Vec<(u32, AdapterLuid)> = utilization
+            .keys()
+            .chain(memory.keys())
+            .copied()
+            .filter(|(_, luid)| adapter_index.contains_key(luid))
+            .collect();
+        keys.sort_by_key(|(pid, luid)| (*pid, adapter_index[luid]));
+        keys.dedup();
+
+        let mut processes: Vec<ProcessInfo> = keys
+            .into_iter()
+            .filter_map(|key| {
+                let used_memory = memory.get(&key).copied().unwrap_or(0);
+                let gpu_utilization = utilization.get(&key).copied().unwrap_or(0.0);
+                if used_memory == 0 && gpu_utilization <= 0.0 {
+                    return None;
+                }
+                let (pid, luid) = key;
+                Some(ProcessInfo {
+                    device_id: adapter_index[&luid],
+                    device_uuid: luid.to_string(),
+                    pid,
+                    process_name: String::new(), // Will be filled by sysinfo
+                    used_memory,
+                    used_memory_estimated: false,
+                    cpu_percent: 0.0,       // Will be filled by sysinfo
+                    memory_percent: 0.0,    // Will be filled by sysinfo
+                    memory_rss: 0,          // Will be filled by sysinfo
+                    memory_vms: 0,          // Will be filled by sysinfo
+                    user: String::new(),    // Will be filled by sysinfo
+                    state: String::new(),   // Will be filled by sysinfo
+                    start_time: 0,          // Will be filled by sysinfo
+                    cpu_time: 0,            // Will be filled by sysinfo
+                    command: String::new(), // Will be filled by sysinfo
+                    ppid: 0,                // Will be filled by sysinfo
+                    threads: 0,             // Will be filled by sysinfo
+                    uses_gpu: true,
+                    priority: 0,   // Will be filled by sysinfo
+                    nice_value: 0, // Will be filled by sysinfo
+                    gpu_utilization,
+                    k8s_pod: None,
+                    host_id: String::new(),
+                })
+            })
+            .collect();
+        fill_start_times(&mut processes);
+        processes
+    }
+}
+
+impl fmt::Debug for WindowsGpuReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowsGpuReader")
+            .field("skip_vendor_ids", &self.skip_vendor_ids)
+            .field("adapters", &self.adapters.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LUID_A: AdapterLuid = AdapterLuid {
+        high: 0,
+        low: 0xC5F1,
+    };
+    const LUID_B: AdapterLuid = AdapterLuid {
+        high: 0,
+        low: 0xD1B8,
+    };
+
+    #[test]
+    fn test_parse_engine_instance() {
+        let parsed =
+            parse_counter_instance("pid_1234_luid_0x00000000_0x0000C5F1_phys_0_eng_3_engtype_3D")
+                .unwrap();
+        assert_eq!(parsed.pid, Some(1234));
+        assert_eq!(parsed.luid, LUID_A);
+        assert_eq!(parsed.phys, 0);
+        assert_eq!(parsed.engine, Some(3));
+        assert_eq!(parsed.engine_type.as_deref(), Some("3D"));
+    }
+
+    #[test]
+    fn test_parse_engine_type_with_underscore() {
+        let parsed = parse_counter_instance(
+            "pid_42_luid_0x00000000_0x0000D1B8_phys_0_eng_5_engtype_VideoDecode_1",
+        )
+        .unwrap();
+        assert_eq!(parsed.luid, LUID_B);
+        assert_eq!(parsed.engine_type.as_deref(), Some("VideoDecode_1"));
+    }
+
+    #[test]
+    fn test_parse_adapter_memory_instance() {
+        let parsed = parse_counter_instance("luid_0x00000000_0x0000c5f1_phys_1").unwrap();
+        assert_eq!(parsed.pid, None);
+        assert_eq!(parsed.luid, LUID_A);
+        assert_eq!(parsed.phys, 1);
+        assert_eq!(parsed.engine_type, None);
+    }
+
+    #[test]
+    fn test_parse_invalid_instance() {
+        assert!(parse_counter_instance("_Total").is_none());
+        assert!(parse_counter_instance("pid_12_luid_0xZZ_0x01_phys_0").is_none());
+        assert!(parse_counter_instance("pid_12_luid_0x00000000").is_none());
+    }
+
+    #[test]
+    fn test_luid_display_matches_pdh_format() {
+        assert_eq!(LUID_A.to_string(), "0x00000000_0x0000C5F1");
+    }
+
+    #[test]
+    fn test_adapter_utilization_uses_busiest_engine_type() {
+        let samples = vec![
+            (
+                "pid_1_luid_0x00000000_0x0000C5F1_phys_0_eng_0_engtype_3D".to_string(),
+                20.0,
+            ),
+            (
+                "pid_2_luid_0x00000000_0x0000C5F1_phys_0_eng_0_engtype_3D".to_string(),
+                15.0,
+            ),
+            (
+                "pid_2_luid_0x00000000_0x0000C5F1_phys_0_eng_1_engtype_Compute_0".to_string(),
+                30.0,
+            ),
+            (
+                "pid_3_luid_0x00000000_0x0000D1B8_phys_0_eng_0_engtype_3D".to_string(),
+                5.0,
+            ),
+        ];
+
+        let per_adapter = aggregate_adapter_utilization(&samples);
+        assert_eq!(per_adapter[&LUID_A], 35.0);
+        assert_eq!(per_adapter[&LUID_B], 5.0);
+
+        let per_process = aggregate_process_utilization(&samples);
+        assert_eq!(per_process[&(1, LUID_A)], 20.0);
+        assert_eq!(per_process[&(2, LUID_A)], 30.0);
+        assert_eq!(per_process[&(3, LUID_B)], 5.0);
+    }
+
+    #[test]
+    fn test_memory_aggregation() {
+        let adapter_samples = vec![
+            ("luid_0x00000000_0x0000C5F1_phys_0".to_string(), 1024.0),
+            ("luid_0x00000000_0x0000C5F1_phys_1".to_string(), 512.0),
+        ];
+        let per_adapter = aggregate_adapter_memory(&adapter_samples);
+        assert_eq!(per_adapter[&LUID_A], 1536);
+
+        let process_samples = vec![
+            (
+                "pid_10_luid_0x00000000_0x0000C5F1_phys_0".to_string(),
+                4096.0,
+            ),
+            (
+                "pid_11_luid_0x00000000_0x0000D1B8_phys_0".to_string(),
+                2048.0,
+            ),
+        ];
+        let per_process = aggregate_process_memory(&process_samples);
+        assert_eq!(per_process[&(10, LUID_A)], 4096);
+        assert_eq!(per_process[&(11, LUID_B)], 2048);
+        assert!(aggregate_adapter_memory(&process_samples).is_empty());
+    }
+}
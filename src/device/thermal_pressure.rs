@@ -0,0 +1,184 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Thermal pressure levels and how long a host has spent in each.
+//!
+//! The levels are those of NSProcessInfo.thermalState on macOS. The history
+//! is filled on Apple Silicon; the level mapping is also used to export
+//! levels read from chassis info of any host.
+
+// The history is only recorded by the macOS native metrics manager
+#![cfg_attr(not(target_os = "macos"), allow(dead_code))]
+
+use std::collections::VecDeque;
+use std::time::{Instant, SystemTime};
+
+/// Level names, indexed by their numeric value
+pub const THERMAL_LEVELS: [&str; 4] = ["Nominal", "Fair", "Serious", "Critical"];
+
+/// Transitions kept in [`ThermalHistory`]; older ones are dropped.
+const MAX_TRANSITIONS: usize = 64;
+
+/// Numeric value of a level name (Nominal=0 … Critical=3), ignoring case.
+pub fn thermal_level_value(level: &str) -> Option<u8> {
+    THERMAL_LEVELS
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(level))
+        .map(|value| value as u8)
+}
+
+/// Detail key holding the seconds spent at `level`, e.g.
+/// `thermal_time_nominal_s`.
+pub fn thermal_time_key(level: &str) -> String {
+    format!("thermal_time_{}_s", level.to_ascii_lowercase())
+}
+
+/// Detail key holding the number of level changes since start
+pub const THERMAL_TRANSITIONS_KEY: &str = "thermal_transitions";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThermalTransition {
+    pub at: SystemTime,
+    pub from: u8,
+    pub to: u8,
+}
+
+/// Level changes and cumulative time per level since the first sample.
+#[derive(Debug, Default)]
+pub struct ThermalHistory {
+    /// Current level and when it was entered
+    current: Option<(u8, Instant)>,
+    /// Seconds spent at each level before the current one was entered
+    completed_secs: [f64; THERMAL_LEVELS.len()],
+    transition_count: u64,
+    recent: VecDeque<ThermalTransition>,
+}
+
+impl ThermalHistory {
+    /// Record the level seen at `now`. Repeating the current level only
+    /// extends it.
+    pub fn record(&mut self, level: u8, now: Instant) {
+        let level = level.min(THERMAL_LEVELS.len() as u8 - 1);
+        match self.current {
+            Some((current, _)) if current == level => {}
+            Some((current, since)) => {
+                self.completed_secs[current as usize] +=
+                    now.saturating_duration_since(since).as_secs_f64();
+                self.current = Some((level, now));
+                self.transition_count += 1;
+                if self.recent.len() == MAX_TRANSITIONS {
+                    self.recent.pop_front();
+                }
+                self.recent.push_back(ThermalTransition {
+                    at: SystemTime::now(),
+                    from: current,
+                    to: level,
+                });
+            }
+            None => self.current = Some((level, now)),
+        }
+    }
+
+    /// Seconds spent at each level up to `now`, indexed like [`THERMAL_LEVELS`]
+    pub fn seconds_per_level(&self, now: Instant) -> [f64; THERMAL_LEVELS.len()] {
+        let mut secs = self.completed_secs;
+        if let Some((current, since)) = self.current {
+            secs[current as usize] += now.saturating_duration_since(since).as_secs_f64();
+        }
+        secs
+    }
+
+    /// Level changes since the first sample, including ones no longer kept
+    pub fn transition_count(&self) -> u64 {
+        self.transition_count
+    }
+
+    /// The most recent level changes, oldest first
+    #[allow(dead_code)]
+    pub fn recent_transitions(&self) -> impl Iterator<Item = &ThermalTransition> {
+        self.recent.iter()
+    }
+
+    /// Detail entries: seconds per level and the transition count
+    pub fn detail_entries(&self, now: Instant) -> Vec<(String, String)> {
+        let secs = self.seconds_per_level(now);
+        let mut entries: Vec<(String, String)> = THERMAL_LEVELS
+            .iter()
+            .zip(secs)
+            .map(|(level, secs)| (thermal_time_key(level), format!("{secs:.0}")))
+            .collect();
+        entries.push((
+            THERMAL_TRANSITIONS_KEY.to_string(),
+            self.transition_count.to_string(),
+        ));
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_level_values() {
+        assert_eq!(thermal_level_value("Nominal"), Some(0));
+        assert_eq!(thermal_level_value("fair"), Some(1));
+        assert_eq!(thermal_level_value("SERIOUS"), Some(2));
+        assert_eq!(thermal_level_value("Critical"), Some(3));
+        assert_eq!(thermal_level_value("Unknown"), None);
+        assert_eq!(thermal_time_key("Nominal"), "thermal_time_nominal_s");
+    }
+
+    #[test]
+    fn test_duration_accounting() {
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+        let mut history = ThermalHistory::default();
+
+        history.record(0, at(0));
+        history.record(0, at(10));
+        // A transient Serious period between two samples of the TUI
+        history.record(2, at(30));
+        history.record(0, at(32));
+        history.record(1, at(50));
+
+        assert_eq!(history.seconds_per_level(at(60)), [48.0, 10.0, 2.0, 0.0]);
+        assert_eq!(history.transition_count(), 3);
+        let changes: Vec<(u8, u8)> = history
+            .recent_transitions()
+            .map(|t| (t.from, t.to))
+            .collect();
+        assert_eq!(changes, vec![(0, 2), (2, 0), (0, 1)]);
+
+        let detail = history.detail_entries(at(60));
+        assert!(detail.contains(&("thermal_time_nominal_s".to_string(), "48".to_string())));
+        assert!(detail.contains(&("thermal_transitions".to_string(), "3".to_string())));
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let start = Instant::now();
+        let mut history = ThermalHistory::default();
+        for i in 0..(MAX_TRANSITIONS as u64 + 11) {
+            history.record((i % 2) as u8, start + Duration::from_secs(i));
+        }
+
+        assert_eq!(history.recent_transitions().count(), MAX_TRANSITIONS);
+        assert_eq!(history.transition_count(), MAX_TRANSITIONS as u64 + 10);
+        let secs =
+            history.seconds_per_level(start + Duration::from_secs(MAX_TRANSITIONS as u64 + 11));
+        assert_eq!(secs.iter().sum::<f64>(), MAX_TRANSITIONS as f64 + 11.0);
+    }
+}
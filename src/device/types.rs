@@ -15,6 +15,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::device::memory_pressure::{MemoryPsi, PressureLevel};
+
 // Type aliases for complex return types
 #[allow(dead_code)]
 pub type ProcessInfoResult = Option<(
@@ -31,7 +33,7 @@ pub type ProcessInfoResult = Option<(
     u32,
 )>;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct GpuInfo {
     pub uuid: String,
     pub time: String,
@@ -44,6 +46,10 @@ pub struct GpuInfo {
     pub ane_utilization: f64,
     pub dla_utilization: Option<f64>,
     pub tensorcore_utilization: Option<f64>, // TPU TensorCore utilization
+    #[serde(default)]
+    pub encoder_utilization: Option<f64>, // NVENC utilization, None without an encoder
+    #[serde(default)]
+    pub decoder_utilization: Option<f64>, // NVDEC utilization, None without a decoder
     pub temperature: u32,
     pub used_memory: u64,
     pub total_memory: u64,
@@ -53,6 +59,33 @@ pub struct GpuInfo {
     pub detail: HashMap<String, String>,
 }
 
+#[cfg(test)]
+impl GpuInfo {
+    /// GPU `uuid` on `host` with every reading at its default. Tests set
+    /// what they check with `GpuInfo { field, ..GpuInfo::fixture(uuid, host) }`.
+    pub fn fixture(uuid: &str, host: &str) -> Self {
+        Self {
+            uuid: uuid.to_string(),
+            name: "NVIDIA H100".to_string(),
+            device_type: "GPU".to_string(),
+            host_id: host.to_string(),
+            hostname: host.to_string(),
+            instance: host.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The same device with `detail` entries added
+    pub fn with_detail(mut self, detail: &[(&str, &str)]) -> Self {
+        self.detail.extend(
+            detail
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        self
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProcessInfo {
     pub device_id: usize,     // GPU index (internal)
@@ -60,13 +93,15 @@ pub struct ProcessInfo {
     pub pid: u32,             // Process ID
     pub process_name: String, // Process name
     pub used_memory: u64,     // GPU memory usage in bytes
+    #[serde(default)]
+    pub used_memory_estimated: bool, // used_memory is an approximation
     pub cpu_percent: f64,     // CPU usage percentage
     pub memory_percent: f64,  // System memory usage percentage
     pub memory_rss: u64,      // Resident Set Size in bytes
     pub memory_vms: u64,      // Virtual Memory Size in bytes
     pub user: String,         // User name
     pub state: String,        // Process state (R, S, D, etc.)
-    pub start_time: String,   // Process start time
+    pub start_time: u64,      // Process start time (seconds since the Unix epoch)
     pub cpu_time: u64,        // Total CPU time in seconds
     pub command: String,      // Full command line
     pub ppid: u32,            // Parent process ID
@@ -75,6 +110,19 @@ pub struct ProcessInfo {
     pub priority: i32,        // Process priority (PRI)
     pub nice_value: i32,      // Nice value (NI)
     pub gpu_utilization: f64, // GPU utilization percentage
+    #[serde(default)]
+    pub k8s_pod: Option<K8sPod>, // Pod the process's GPU is allocated to
+    #[serde(default)]
+    pub host_id: String, // Host the process was scraped from (remote mode)
+}
+
+/// Kubernetes container a device is allocated to, from the kubelet
+/// PodResources API
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct K8sPod {
+    pub namespace: String,
+    pub name: String,
+    pub container: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -98,6 +146,10 @@ pub struct CpuInfo {
     pub apple_silicon_info: Option<AppleSiliconCpuInfo>, // Apple Silicon specific info
     pub per_core_utilization: Vec<CoreUtilization>, // Per-core utilization data
     pub time: String,                        // Timestamp
+
+    // Kept from an earlier pass after the reader timed out
+    #[serde(default)]
+    pub stale: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -163,6 +215,22 @@ pub struct MemoryInfo {
     pub swap_free_bytes: u64,  // Free swap space in bytes
     pub utilization: f64,      // Memory utilization percentage
     pub time: String,          // Timestamp
+
+    // Pressure and swap activity, where the platform reports them
+    #[serde(default)]
+    pub psi: Option<MemoryPsi>, // Stall time on memory (Linux PSI)
+    #[serde(default)]
+    pub pressure_level: Option<PressureLevel>, // Level reported by the kernel (macOS)
+    #[serde(default)]
+    pub compressed_bytes: Option<u64>, // Memory held by the compressor (macOS)
+    #[serde(default)]
+    pub swap_in_bytes_total: Option<u64>, // Bytes swapped in since boot
+    #[serde(default)]
+    pub swap_out_bytes_total: Option<u64>, // Bytes swapped out since boot
+
+    // Kept from an earlier pass after the reader timed out
+    #[serde(default)]
+    pub stale: bool,
 }
 
 /// Chassis/Node-level information for system-wide metrics
@@ -187,12 +255,29 @@ pub struct ChassisInfo {
     // PSU
     pub psu_status: Vec<PsuInfo>, // PSU status information
 
+    // Load, uptime and kernel/OS of the host
+    #[serde(default)]
+    pub system: Option<HostSystemInfo>,
+
+    // Marked for maintenance; set in view mode from all_smi_node_draining
+    #[serde(default)]
+    pub draining: bool,
+
     // Platform-specific details
     pub detail: HashMap<String, String>,
 
     pub time: String, // Timestamp
 }
 
+/// Load average, uptime and software identity of a host
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct HostSystemInfo {
+    pub load_average: [f64; 3], // 1, 5 and 15 minute load averages
+    pub uptime_seconds: u64,    // Seconds since boot
+    pub kernel_version: String, // Kernel release, e.g. "6.8.0-45-generic"
+    pub os_name: String,        // OS pretty name, e.g. "Ubuntu 24.04.1 LTS"
+}
+
 /// Fan information for cooling monitoring
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FanInfo {
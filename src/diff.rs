@@ -0,0 +1,523 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `all-smi diff`: what changed between two snapshots.
+//!
+//! Devices are matched by UUID and hosts by instance. By default only
+//! structural differences are reported: hosts and devices that came or went,
+//! device names, memory and version fields of their detail maps, CPU models,
+//! system memory and kernel/OS. Utilization, temperature and power are noisy,
+//! so they are only compared with `--include-metrics`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::time::SystemTime;
+
+use serde::Serialize;
+
+use crate::cli::{DiffArgs, DiffFormat, SubsystemArgs};
+use crate::device::GpuInfo;
+use crate::utils::format_bytes;
+use crate::view::data_collection::replay::{load_snapshots, Snapshot};
+
+/// Exit code when the snapshots match
+pub const EXIT_SAME: i32 = 0;
+/// Exit code when the snapshots differ
+pub const EXIT_DIFFERENT: i32 = 1;
+/// Exit code when a snapshot cannot be read
+pub const EXIT_ERROR: i32 = 2;
+
+/// Which numeric fields are compared, and how far apart they must be
+#[derive(Clone, Copy, Debug)]
+pub struct DiffOptions {
+    pub include_metrics: bool,
+    /// Smallest reported change, in the unit of the field
+    pub threshold: f64,
+}
+
+/// Device present in only one of the snapshots
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DeviceRef {
+    pub uuid: String,
+    pub name: String,
+    pub host: String,
+}
+
+/// Field whose value differs between the snapshots
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Change {
+    /// Device UUID, or the host instance for host-level fields
+    pub subject: String,
+    pub host: String,
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Everything that differs between two snapshots
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SnapshotDiff {
+    pub hosts_added: Vec<String>,
+    pub hosts_removed: Vec<String>,
+    pub devices_added: Vec<DeviceRef>,
+    pub devices_removed: Vec<DeviceRef>,
+    pub changes: Vec<Change>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.hosts_added.is_empty()
+            && self.hosts_removed.is_empty()
+            && self.devices_added.is_empty()
+            && self.devices_removed.is_empty()
+            && self.changes.is_empty()
+    }
+
+    fn change(&mut self, subject: &str, host: &str, field: &str, before: String, after: String) {
+        if before != after {
+            self.changes.push(Change {
+                subject: subject.to_string(),
+                host: host.to_string(),
+                field: field.to_string(),
+                before,
+                after,
+            });
+        }
+    }
+
+    fn metric_change(
+        &mut self,
+        gpu: &GpuInfo,
+        field: &str,
+        (before, after): (f64, f64),
+        unit: &str,
+        options: &DiffOptions,
+    ) {
+        if options.include_metrics && (after - before).abs() >= options.threshold {
+            self.change(
+                &gpu.uuid,
+                &gpu.instance,
+                field,
+                format!("{before:.1}{unit}"),
+                format!("{after:.1}{unit}"),
+            );
+        }
+    }
+}
+
+/// Detail map entries that only change with the software or firmware, such
+/// as "Driver Version", "vbios_version" or "KMD Version"
+fn is_version_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.contains("version") || key.contains("firmware") || key.contains("vbios")
+}
+
+fn device_ref(gpu: &GpuInfo) -> DeviceRef {
+    DeviceRef {
+        uuid: gpu.uuid.clone(),
+        name: gpu.name.clone(),
+        host: gpu.instance.clone(),
+    }
+}
+
+/// Hosts seen in any device list of `snapshot`, by instance
+fn hosts(snapshot: &Snapshot) -> BTreeSet<&str> {
+    let gpus = snapshot.gpu_info.iter().map(|g| g.instance.as_str());
+    let cpus = snapshot.cpu_info.iter().map(|c| c.instance.as_str());
+    let memory = snapshot.memory_info.iter().map(|m| m.instance.as_str());
+    let chassis = snapshot.chassis_info.iter().map(|c| c.instance.as_str());
+    gpus.chain(cpus).chain(memory).chain(chassis).collect()
+}
+
+/// Compare `before` with `after`.
+pub fn diff_snapshots(before: &Snapshot, after: &Snapshot, options: &DiffOptions) -> SnapshotDiff {
+    let mut diff = SnapshotDiff::default();
+
+    let (hosts_before, hosts_after) = (hosts(before), hosts(after));
+    diff.hosts_added = hosts_after
+        .difference(&hosts_before)
+        .map(|host| host.to_string())
+        .collect();
+    diff.hosts_removed = hosts_before
+        .difference(&hosts_after)
+        .map(|host| host.to_string())
+        .collect();
+
+    let gpus_before: BTreeMap<&str, &GpuInfo> = before
+        .gpu_info
+        .iter()
+        .map(|gpu| (gpu.uuid.as_str(), gpu))
+        .collect();
+    let gpus_after: BTreeMap<&str, &GpuInfo> = after
+        .gpu_info
+        .iter()
+        .map(|gpu| (gpu.uuid.as_str(), gpu))
+        .collect();
+    diff.devices_removed = gpus_before
+        .iter()
+        .filter(|(uuid, _)| !gpus_after.contains_key(*uuid))
+        .map(|(_, gpu)| device_ref(gpu))
+        .collect();
+    diff.devices_added = gpus_after
+        .iter()
+        .filter(|(uuid, _)| !gpus_before.contains_key(*uuid))
+        .map(|(_, gpu)| device_ref(gpu))
+        .collect();
+
+    for (uuid, old) in &gpus_before {
+        let Some(new) = gpus_after.get(uuid) else {
+            continue;
+        };
+        diff.change(
+            uuid,
+            &new.instance,
+            "name",
+            old.name.clone(),
+            new.name.clone(),
+        );
+        diff.change(
+            uuid,
+            &new.instance,
+            "total_memory",
+            format_bytes(old.total_memory),
+            format_bytes(new.total_memory),
+        );
+        let keys: BTreeSet<&String> = old.detail.keys().chain(new.detail.keys()).collect();
+        for key in keys.into_iter().filter(|key| is_version_key(key)) {
+            let value = |gpu: &GpuInfo| gpu.detail.get(key).cloned().unwrap_or_else(|| "-".into());
+            diff.change(uuid, &new.instance, key, value(old), value(new));
+        }
+
+        diff.metric_change(
+            new,
+            "utilization",
+            (old.utilization, new.utilization),
+            "%",
+            options,
+        );
+        diff.metric_change(
+            new,
+            "temperature",
+            (f64::from(old.temperature), f64::from(new.temperature)),
+            "°C",
+            options,
+        );
+        diff.metric_change(
+            new,
+            "power",
+            (old.power_consumption, new.power_consumption),
+            "W",
+            options,
+        );
+    }
+
+    for host in hosts_before.intersection(&hosts_after) {
+        let cpu = |s: &'_ Snapshot| s.cpu_info.iter().find(|c| c.instance == *host).cloned();
+        if let (Some(old), Some(new)) = (cpu(before), cpu(after)) {
+            diff.change(host, host, "cpu_model", old.cpu_model, new.cpu_model);
+            diff.change(
+                host,
+                host,
+                "cpu_cores",
+                old.total_cores.to_string(),
+                new.total_cores.to_string(),
+            );
+        }
+
+        let memory = |s: &'_ Snapshot| s.memory_info.iter().find(|m| m.instance == *host).cloned();
+        if let (Some(old), Some(new)) = (memory(before), memory(after)) {
+            diff.change(
+                host,
+                host,
+                "memory_total",
+                format_bytes(old.total_bytes),
+                format_bytes(new.total_bytes),
+            );
+        }
+
+        let system = |s: &'_ Snapshot| {
+            s.chassis_info
+                .iter()
+                .find(|c| c.instance == *host)
+                .and_then(|c| c.system.clone())
+        };
+        if let (Some(old), Some(new)) = (system(before), system(after)) {
+            diff.change(host, host, "kernel", old.kernel_version, new.kernel_version);
+            diff.change(host, host, "os", old.os_name, new.os_name);
+        }
+    }
+
+    diff
+}
+
+/// One line per difference, or a note that there are none.
+pub fn render_text(diff: &SnapshotDiff) -> String {
+    if diff.is_empty() {
+        return "No differences\n".to_string();
+    }
+    let mut out = String::new();
+    for host in &diff.hosts_added {
+        let _ = writeln!(out, "+ host {host}");
+    }
+    for host in &diff.hosts_removed {
+        let _ = writeln!(out, "- host {host}");
+    }
+    for device in &diff.devices_added {
+        let _ = writeln!(out, "+ {} {} on {}", device.uuid, device.name, device.host);
+    }
+    for device in &diff.devices_removed {
+        let _ = writeln!(out, "- {} {} on {}", device.uuid, device.name, device.host);
+    }
+    for change in &diff.changes {
+        let subject = if change.subject == change.host {
+            change.host.clone()
+        } else {
+            format!("{} on {}", change.subject, change.host)
+        };
+        let _ = writeln!(
+            out,
+            "~ {subject}: {} {} -> {}",
+            change.field, change.before, change.after
+        );
+    }
+    out
+}
+
+/// Read a single JSON snapshot, or the last snapshot of a `--record` file.
+pub fn load_snapshot(path: &Path) -> io::Result<Snapshot> {
+    let content = std::fs::read_to_string(path)?;
+    if let Ok(snapshot) = serde_json::from_str(&content) {
+        return Ok(snapshot);
+    }
+    let mut snapshots = load_snapshots(path)?;
+    snapshots
+        .pop()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no snapshots"))
+}
+
+/// Run `all-smi diff` and return the process exit code.
+pub async fn run(args: &DiffArgs) -> i32 {
+    let load = |path: &Path| {
+        load_snapshot(path).map_err(|e| format!("cannot read {}: {e}", path.display()))
+    };
+    let snapshots = match load(&args.before) {
+        Ok(before) => match &args.after {
+            Some(path) => load(path).map(|after| (before, after)),
+            None => {
+                let filter = SubsystemArgs::default().collection_filter();
+                let state = crate::api::collect_once(filter, false, false).await;
+                Ok((before, Snapshot::from_state(&state, SystemTime::now())))
+            }
+        },
+        Err(e) => Err(e),
+    };
+    let (before, after) = match snapshots {
+        Ok(snapshots) => snapshots,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return EXIT_ERROR;
+        }
+    };
+
+    let options = DiffOptions {
+        include_metrics: args.include_metrics,
+        threshold: args.threshold,
+    };
+    let diff = diff_snapshots(&before, &after, &options);
+    match args.format {
+        DiffFormat::Text => print!("{}", render_text(&diff)),
+        DiffFormat::Json => match serde_json::to_string_pretty(&diff) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return EXIT_ERROR;
+            }
+        },
+    }
+    if diff.is_empty() {
+        EXIT_SAME
+    } else {
+        EXIT_DIFFERENT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::{ChassisInfo, HostSystemInfo};
+
+    fn snapshot(gpu_info: Vec<GpuInfo>) -> Snapshot {
+        Snapshot {
+            timestamp: 0.0,
+            gpu_info,
+            cpu_info: Vec::new(),
+            memory_info: Vec::new(),
+            process_info: Vec::new(),
+            storage_info: Vec::new(),
+            network_info: Vec::new(),
+            chassis_info: Vec::new(),
+        }
+    }
+
+    const STRUCTURAL: DiffOptions = DiffOptions {
+        include_metrics: false,
+        threshold: 5.0,
+    };
+
+    #[test]
+    fn test_identical_snapshots_have_no_differences() {
+        let before = snapshot(vec![GpuInfo::fixture("GPU-0", "node-1")
+            .with_detail(&[("Driver Version", "550.54"), ("pstate", "P0")])]);
+        let diff = diff_snapshots(&before, &before.clone(), &STRUCTURAL);
+        assert!(diff.is_empty());
+        assert_eq!(render_text(&diff), "No differences\n");
+    }
+
+    #[test]
+    fn test_matching_with_missing_devices_and_hosts() {
+        let before = snapshot(vec![
+            GpuInfo::fixture("GPU-0", "node-1")
+                .with_detail(&[("Driver Version", "550.54"), ("pstate", "P0")]),
+            GpuInfo::fixture("GPU-1", "node-1")
+                .with_detail(&[("Driver Version", "550.54"), ("pstate", "P0")]),
+            GpuInfo::fixture("GPU-2", "node-2")
+                .with_detail(&[("Driver Version", "550.54"), ("pstate", "P0")]),
+        ]);
+        // GPU-1 fell off the bus, node-2 is gone, node-3 is new, and the
+        // list order changed
+        let mut after = snapshot(vec![
+            GpuInfo::fixture("GPU-3", "node-3")
+                .with_detail(&[("Driver Version", "560.28"), ("pstate", "P0")]),
+            GpuInfo::fixture("GPU-0", "node-1")
+                .with_detail(&[("Driver Version", "560.28"), ("pstate", "P0")]),
+        ]);
+        after.gpu_info[1]
+            .detail
+            .insert("pstate".into(), "P8".into());
+        after.gpu_info[1].utilization = 0.0;
+
+        let diff = diff_snapshots(&before, &after, &STRUCTURAL);
+        assert_eq!(diff.hosts_added, ["node-3"]);
+        assert_eq!(diff.hosts_removed, ["node-2"]);
+        assert_eq!(
+            diff.devices_removed
+                .iter()
+                .map(|d| d.uuid.as_str())
+                .collect::<Vec<_>>(),
+            ["GPU-1", "GPU-2"]
+        );
+        assert_eq!(diff.devices_added[0].host, "node-3");
+        // Only the driver version of the matched device; pstate and
+        // utilization are not structural
+        assert_eq!(
+            diff.changes,
+            [Change {
+                subject: "GPU-0".to_string(),
+                host: "node-1".to_string(),
+                field: "Driver Version".to_string(),
+                before: "550.54".to_string(),
+                after: "560.28".to_string(),
+            }]
+        );
+
+        let text = render_text(&diff);
+        assert!(text.contains("- GPU-1 NVIDIA H100 on node-1\n"));
+        assert!(text.contains("~ GPU-0 on node-1: Driver Version 550.54 -> 560.28\n"));
+    }
+
+    #[test]
+    fn test_metrics_below_threshold_are_ignored() {
+        let before = snapshot(vec![GpuInfo {
+            utilization: 40.0,
+            temperature: 60,
+            power_consumption: 300.0,
+            ..GpuInfo::fixture("GPU-0", "node-1")
+                .with_detail(&[("Driver Version", "550.54"), ("pstate", "P0")])
+        }]);
+        let mut after = before.clone();
+        after.gpu_info[0].utilization = 43.0;
+        after.gpu_info[0].temperature = 72;
+        after.gpu_info[0].power_consumption = 304.9;
+
+        assert!(diff_snapshots(&before, &after, &STRUCTURAL).is_empty());
+
+        let options = DiffOptions {
+            include_metrics: true,
+            threshold: 5.0,
+        };
+        let diff = diff_snapshots(&before, &after, &options);
+        let fields: Vec<_> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, ["temperature"]);
+        assert_eq!(diff.changes[0].before, "60.0°C");
+
+        let options = DiffOptions {
+            include_metrics: true,
+            threshold: 3.0,
+        };
+        let diff = diff_snapshots(&before, &after, &options);
+        let fields: Vec<_> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, ["utilization", "temperature", "power"]);
+    }
+
+    #[test]
+    fn test_host_kernel_and_memory_changes() {
+        let chassis = |kernel: &str| ChassisInfo {
+            host_id: "node-1".to_string(),
+            hostname: "node-1".to_string(),
+            instance: "node-1".to_string(),
+            system: Some(HostSystemInfo {
+                kernel_version: kernel.to_string(),
+                os_name: "Ubuntu 24.04.1 LTS".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut before = snapshot(Vec::new());
+        before.chassis_info = vec![chassis("6.8.0-45-generic")];
+        let mut after = snapshot(Vec::new());
+        after.chassis_info = vec![chassis("6.8.0-49-generic")];
+
+        let diff = diff_snapshots(&before, &after, &STRUCTURAL);
+        assert_eq!(
+            render_text(&diff),
+            "~ node-1: kernel 6.8.0-45-generic -> 6.8.0-49-generic\n"
+        );
+    }
+
+    #[test]
+    fn test_load_snapshot_from_recording_and_single_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = snapshot(vec![GpuInfo::fixture("GPU-0", "node-1")
+            .with_detail(&[("Driver Version", "550.54"), ("pstate", "P0")])]);
+        let last = snapshot(vec![GpuInfo::fixture("GPU-0", "node-1")
+            .with_detail(&[("Driver Version", "560.28"), ("pstate", "P0")])]);
+
+        let recording = dir.path().join("session.jsonl");
+        let lines = [&first, &last]
+            .map(|s| serde_json::to_string(s).unwrap())
+            .join("\n");
+        std::fs::write(&recording, lines).unwrap();
+        let loaded = load_snapshot(&recording).unwrap();
+        assert_eq!(loaded.gpu_info[0].detail["Driver Version"], "560.28");
+
+        let single = dir.path().join("before.json");
+        std::fs::write(&single, serde_json::to_string_pretty(&first).unwrap()).unwrap();
+        let loaded = load_snapshot(&single).unwrap();
+        assert_eq!(loaded.gpu_info[0].detail["Driver Version"], "550.54");
+
+        assert!(load_snapshot(&dir.path().join("missing.json")).is_err());
+    }
+}
@@ -0,0 +1,563 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `all-smi doctor`: what this host can collect, and what it cannot.
+//!
+//! Every reader `local` and `api` would create is run once, with the same
+//! factories, privilege checks and vendor status messages those modes
+//! use. Reader errors are logged to stderr; the report names what is
+//! missing and how to get it. The exit code tells an installer whether any
+//! GPU or NPU was found.
+
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::cli::{DoctorArgs, DoctorFormat};
+use crate::device::privileges::{self, MissingFeature};
+use crate::device::{
+    create_chassis_reader, get_cpu_readers, get_memory_readers, get_named_gpu_readers,
+    get_nvml_status_message, GpuInfo, GpuReader, ProcessInfo,
+};
+use crate::network::NetworkReader;
+use crate::utils::format_bytes;
+
+/// Exit code when at least one GPU or NPU was found
+pub const EXIT_OK: i32 = 0;
+/// Exit code when no device reader produced a device
+pub const EXIT_NO_DEVICES: i32 = 1;
+/// Exit code when the report could not be printed
+pub const EXIT_ERROR: i32 = 2;
+
+/// What a probe looked at
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeKind {
+    /// A GPU/NPU reader
+    Device,
+    /// CPU, memory, chassis or network
+    Host,
+    /// A prerequisite that is not a reader, e.g. the API port
+    Check,
+}
+
+/// Something a probe could not get, and how to get it
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Problem {
+    pub missing: String,
+    pub hint: &'static str,
+}
+
+/// The result of running one reader or check once
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Probe {
+    pub reader: &'static str,
+    pub kind: ProbeKind,
+    /// The hardware found, e.g. `2× NVIDIA H100 80GB HBM3`
+    pub hardware: String,
+    /// Devices returned by a GPU/NPU reader
+    pub devices: usize,
+    /// Metric categories the reader filled in
+    pub metrics: Vec<&'static str>,
+    pub problems: Vec<Problem>,
+}
+
+impl Probe {
+    fn new(reader: &'static str, kind: ProbeKind, hardware: impl Into<String>) -> Self {
+        Self {
+            reader,
+            kind,
+            hardware: hardware.into(),
+            devices: 0,
+            metrics: Vec::new(),
+            problems: Vec::new(),
+        }
+    }
+
+    fn problem(&mut self, missing: impl Into<String>, hint: &'static str) {
+        self.problems.push(Problem {
+            missing: missing.into(),
+            hint,
+        });
+    }
+}
+
+/// Every probe of this host
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DoctorReport {
+    pub probes: Vec<Probe>,
+    /// GPUs and NPUs found by all device readers
+    pub devices: usize,
+}
+
+impl DoctorReport {
+    pub fn new(probes: Vec<Probe>) -> Self {
+        let devices = probes
+            .iter()
+            .filter(|probe| probe.kind == ProbeKind::Device)
+            .map(|probe| probe.devices)
+            .sum();
+        Self { probes, devices }
+    }
+
+    /// Run every reader and check once. The API port is left out when
+    /// `port` is 0, which disables the listener.
+    pub fn collect(port: u16) -> Self {
+        // The background managers must be up before their readers run
+        let managers = init_managers();
+
+        let mut probes: Vec<Probe> = get_named_gpu_readers()
+            .iter()
+            .map(|(name, reader)| probe_gpu_reader(*name, reader.as_ref()))
+            .collect();
+        if probes.is_empty() {
+            let mut probe = Probe::new("gpu", ProbeKind::Device, "none");
+            probe.problem(
+                "no GPU or NPU detected",
+                "check that the driver is loaded and its device nodes exist, e.g. /dev/nvidia*, /dev/dri or /dev/accel",
+            );
+            probes.push(probe);
+        }
+        probes.extend([
+            probe_cpu(),
+            probe_memory(),
+            probe_chassis(),
+            probe_network(),
+        ]);
+        probes.extend(managers);
+        probes.push(probe_privileges(&privileges::detect()));
+        if port != 0 {
+            probes.push(probe_api_port(port));
+        }
+        Self::new(probes)
+    }
+}
+
+fn probe_gpu_reader(name: &'static str, reader: &dyn GpuReader) -> Probe {
+    let devices = reader.get_gpu_info();
+    let processes = reader.get_process_info();
+    let mut probe = Probe::new(
+        name,
+        ProbeKind::Device,
+        count_names(devices.iter().map(|device| device.name.as_str())),
+    );
+    probe.devices = devices.len();
+    probe.metrics = gpu_metrics(&devices, &processes);
+    if devices.is_empty() {
+        let status = reader_status(name)
+            .unwrap_or_else(|| "detected, but the reader returned no devices".to_string());
+        probe.problem(format!("{name}: {status}"), reader_hint(name));
+    }
+    probe
+}
+
+/// Metric categories `devices` carry
+fn gpu_metrics(devices: &[GpuInfo], processes: &[ProcessInfo]) -> Vec<&'static str> {
+    let any = |filled: fn(&GpuInfo) -> bool| devices.iter().any(filled);
+    let mut metrics = Vec::new();
+    if !devices.is_empty() {
+        metrics.push("utilization");
+    }
+    if any(|device| device.total_memory > 0) {
+        metrics.push("memory");
+    }
+    if any(|device| device.temperature > 0) {
+        metrics.push("temperature");
+    }
+    if any(|device| device.power_consumption > 0.0) {
+        metrics.push("power");
+    }
+    if any(|device| device.frequency > 0) {
+        metrics.push("frequency");
+    }
+    if processes.iter().any(|process| process.uses_gpu) {
+        metrics.push("processes");
+    }
+    metrics
+}
+
+/// `names` with repeats counted, in the order first seen, e.g.
+/// `2× NVIDIA H100, 1× NVIDIA A100`
+fn count_names<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for name in names {
+        match counts.iter_mut().find(|(seen, _)| *seen == name) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((name, 1)),
+        }
+    }
+    if counts.is_empty() {
+        return "none".to_string();
+    }
+    counts
+        .iter()
+        .map(|(name, count)| format!("{count}× {name}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The status message a reader left for the TUI, if any
+fn reader_status(name: &str) -> Option<String> {
+    match name {
+        "nvidia" => get_nvml_status_message(),
+        #[cfg(target_os = "linux")]
+        "tenstorrent" => crate::device::get_tenstorrent_status_message(),
+        #[cfg(target_os = "linux")]
+        "google_tpu" => crate::device::get_tpu_status_message(),
+        _ => None,
+    }
+}
+
+/// How to get a reader that was detected but returned nothing working
+fn reader_hint(name: &str) -> &'static str {
+    match name {
+        "nvidia" => {
+            "install the NVIDIA driver, or add the directory of libnvidia-ml.so to the library path"
+        }
+        "nvidia_jetson" => {
+            "install JetPack; the GPU is read from /sys/devices and the nvpmodel tools"
+        }
+        "amd" => "load the amdgpu driver and add the user to the video and render groups",
+        "gaudi" => "install the Habana driver and put hl-smi on PATH",
+        "furiosa" => "install the Furiosa driver and put furiosa-smi on PATH",
+        "rebellions" => "install the RBLN driver and put rbln-stat or rbln-smi on PATH",
+        "tenstorrent" => "load the tt-kmd driver so /dev/tenstorrent is populated",
+        "google_tpu" => "install libtpu or tpu-info (pip install tpu-info)",
+        "apple_silicon" => "the IOReport and SMC interfaces need macOS 12 or later",
+        "plugin" => "check the plugin log output above",
+        _ => "update the GPU driver",
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn init_managers() -> Vec<Probe> {
+    use crate::device::is_apple_silicon;
+    use crate::device::macos_native::initialize_native_metrics_manager;
+
+    if !is_apple_silicon() {
+        return Vec::new();
+    }
+    let mut probe = Probe::new("native_metrics", ProbeKind::Check, "IOReport, SMC");
+    if let Err(e) = initialize_native_metrics_manager(1000) {
+        probe.problem(
+            format!("native metrics: {e}"),
+            "the IOReport and SMC interfaces need macOS 12 or later; no sudo is required",
+        );
+    }
+    vec![probe]
+}
+
+#[cfg(target_os = "linux")]
+fn init_managers() -> Vec<Probe> {
+    use crate::device::has_gaudi;
+    use crate::device::hlsmi::initialize_hlsmi_manager;
+
+    if !has_gaudi() {
+        return Vec::new();
+    }
+    let mut probe = Probe::new("hlsmi", ProbeKind::Check, "hl-smi");
+    if let Err(e) = initialize_hlsmi_manager(1) {
+        probe.problem(format!("hlsmi: {e}"), reader_hint("gaudi"));
+    }
+    vec![probe]
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn init_managers() -> Vec<Probe> {
+    Vec::new()
+}
+
+fn probe_cpu() -> Probe {
+    let cpus: Vec<_> = get_cpu_readers()
+        .iter()
+        .flat_map(|reader| reader.get_cpu_info())
+        .collect();
+    let Some(cpu) = cpus.first() else {
+        let mut probe = Probe::new("cpu", ProbeKind::Host, "none");
+        probe.problem(
+            "cpu: no CPU information",
+            "/proc/stat and /proc/cpuinfo must be readable",
+        );
+        return probe;
+    };
+    let mut probe = Probe::new("cpu", ProbeKind::Host, cpu.cpu_model.clone());
+    probe.metrics.push("utilization");
+    if !cpu.per_core_utilization.is_empty() {
+        probe.metrics.push("per-core");
+    }
+    if cpu.max_frequency_mhz > 0 || cpu.base_frequency_mhz > 0 {
+        probe.metrics.push("frequency");
+    }
+    if cpu.temperature.is_some() {
+        probe.metrics.push("temperature");
+    }
+    if cpu.power_consumption.is_some() {
+        probe.metrics.push("power");
+    }
+    probe
+}
+
+fn probe_memory() -> Probe {
+    let memory: Vec<_> = get_memory_readers()
+        .iter()
+        .flat_map(|reader| reader.get_memory_info())
+        .collect();
+    let Some(memory) = memory.first() else {
+        let mut probe = Probe::new("memory", ProbeKind::Host, "none");
+        probe.problem(
+            "memory: no memory information",
+            "/proc/meminfo must be readable",
+        );
+        return probe;
+    };
+    let mut probe = Probe::new("memory", ProbeKind::Host, format_bytes(memory.total_bytes));
+    probe.metrics.push("usage");
+    if memory.swap_total_bytes > 0 {
+        probe.metrics.push("swap");
+    }
+    probe
+}
+
+fn probe_chassis() -> Probe {
+    let Some(chassis) = create_chassis_reader().get_chassis_info() else {
+        return Probe::new("chassis", ProbeKind::Host, "none");
+    };
+    let mut probe = Probe::new("chassis", ProbeKind::Host, chassis.hostname.clone());
+    if chassis.total_power_watts.is_some() {
+        probe.metrics.push("power");
+    }
+    if chassis.inlet_temperature.is_some()
+        || chassis.outlet_temperature.is_some()
+        || chassis.thermal_pressure.is_some()
+    {
+        probe.metrics.push("thermal");
+    }
+    if !chassis.fan_speeds.is_empty() {
+        probe.metrics.push("fans");
+    }
+    if !chassis.psu_status.is_empty() {
+        probe.metrics.push("psu");
+    }
+    if chassis.system.is_some() {
+        probe.metrics.push("system");
+    }
+    probe
+}
+
+fn probe_network() -> Probe {
+    let interfaces = NetworkReader::new(false).get_network_info();
+    let mut probe = Probe::new(
+        "network",
+        ProbeKind::Host,
+        format!("{} interfaces", interfaces.len()),
+    );
+    if !interfaces.is_empty() {
+        probe.metrics.extend(["throughput", "errors"]);
+    }
+    probe
+}
+
+/// Features out of reach of the current user, from the check every mode
+/// runs at startup
+fn probe_privileges(privileges: &privileges::Privileges) -> Probe {
+    let user = if privileges.root { "root" } else { "user" };
+    let mut probe = Probe::new("privileges", ProbeKind::Check, user);
+    for MissingFeature { name, reason, .. } in privileges::missing_features(privileges) {
+        probe.problem(format!("{name}: not readable by this user"), reason);
+    }
+    probe
+}
+
+/// Whether `all-smi api` could listen on `port`
+fn probe_api_port(port: u16) -> Probe {
+    let address = format!("0.0.0.0:{port}");
+    let mut probe = Probe::new("api_port", ProbeKind::Check, address.clone());
+    if let Err(e) = std::net::TcpListener::bind(&address) {
+        let hint = if e.kind() == std::io::ErrorKind::PermissionDenied {
+            "ports below 1024 need root; pass a higher --port"
+        } else {
+            "stop the process using the port, or pass another --port"
+        };
+        probe.problem(format!("api port: cannot listen on {address}: {e}"), hint);
+    }
+    probe
+}
+
+/// One row per probe, followed by each problem and how to fix it
+pub fn render_text(report: &DoctorReport) -> String {
+    let header = ["READER", "HARDWARE", "METRICS", "STATUS"].map(String::from);
+    let mut rows = vec![header];
+    for probe in &report.probes {
+        let metrics = if probe.metrics.is_empty() {
+            "-".to_string()
+        } else {
+            probe.metrics.join(", ")
+        };
+        let status = if probe.problems.is_empty() {
+            "ok"
+        } else {
+            "missing"
+        };
+        rows.push([
+            probe.reader.to_string(),
+            probe.hardware.clone(),
+            metrics,
+            status.to_string(),
+        ]);
+    }
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in &rows {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(value, width)| format!("{value:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+
+    let problems: Vec<&Problem> = report
+        .probes
+        .iter()
+        .flat_map(|probe| &probe.problems)
+        .collect();
+    if !problems.is_empty() {
+        out.push_str("\nMissing:\n");
+        for problem in problems {
+            let _ = writeln!(out, "  {}", problem.missing);
+            let _ = writeln!(out, "    fix: {}", problem.hint);
+        }
+    }
+    out.push('\n');
+    match report.devices {
+        0 => out.push_str("No GPU or NPU found\n"),
+        1 => out.push_str("1 GPU/NPU found\n"),
+        devices => {
+            let _ = writeln!(out, "{devices} GPUs/NPUs found");
+        }
+    }
+    out
+}
+
+/// Run `all-smi doctor`; returns the process exit code
+pub fn run(args: &DoctorArgs) -> i32 {
+    let report = DoctorReport::collect(args.port);
+    match args.format {
+        DoctorFormat::Text => print!("{}", render_text(&report)),
+        DoctorFormat::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return EXIT_ERROR;
+            }
+        },
+    }
+    if report.devices > 0 {
+        EXIT_OK
+    } else {
+        EXIT_NO_DEVICES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lists_probes_and_fixes() {
+        let mut nvidia = Probe::new("nvidia", ProbeKind::Device, "2× NVIDIA H100");
+        nvidia.devices = 2;
+        nvidia.metrics = vec!["utilization", "memory", "power"];
+        let mut gaudi = Probe::new("gaudi", ProbeKind::Device, "none");
+        gaudi.problem("hlsmi: hl-smi not found", reader_hint("gaudi"));
+        let mut cpu = Probe::new("cpu", ProbeKind::Host, "AMD EPYC 9654");
+        cpu.metrics = vec!["utilization"];
+        let mut port = Probe::new("api_port", ProbeKind::Check, "0.0.0.0:9090");
+        port.problem(
+            "api port: cannot listen on 0.0.0.0:9090: Address in use",
+            "stop the process using the port, or pass another --port",
+        );
+        let report = DoctorReport::new(vec![nvidia, gaudi, cpu, port]);
+        assert_eq!(report.devices, 2);
+
+        assert_eq!(
+            render_text(&report),
+            "\
+READER    HARDWARE        METRICS                     STATUS
+nvidia    2× NVIDIA H100  utilization, memory, power  ok
+gaudi     none            -                           missing
+cpu       AMD EPYC 9654   utilization                 ok
+api_port  0.0.0.0:9090    -                           missing
+
+Missing:
+  hlsmi: hl-smi not found
+    fix: install the Habana driver and put hl-smi on PATH
+  api port: cannot listen on 0.0.0.0:9090: Address in use
+    fix: stop the process using the port, or pass another --port
+
+2 GPUs/NPUs found
+"
+        );
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["devices"], 2);
+        assert_eq!(json["probes"][1]["kind"], "device");
+        assert_eq!(
+            json["probes"][1]["problems"][0]["missing"],
+            "hlsmi: hl-smi not found"
+        );
+        assert_eq!(json["probes"][3]["kind"], "check");
+    }
+
+    #[test]
+    fn test_report_without_devices() {
+        let report = DoctorReport::new(vec![
+            Probe::new("gpu", ProbeKind::Device, "none"),
+            Probe::new("memory", ProbeKind::Host, "512 GiB"),
+        ]);
+        assert_eq!(report.devices, 0);
+        assert!(render_text(&report).ends_with("\nNo GPU or NPU found\n"));
+
+        let privileges = privileges::Privileges {
+            smartctl: true,
+            ..Default::default()
+        };
+        let probe = probe_privileges(&privileges);
+        assert_eq!(probe.hardware, "user");
+        assert_eq!(
+            probe.problems,
+            [Problem {
+                missing: "drive SMART health: not readable by this user".to_string(),
+                hint: "smartctl needs root to open the drives",
+            }]
+        );
+    }
+
+    #[test]
+    fn test_count_names() {
+        let names = ["NVIDIA H100", "NVIDIA A100", "NVIDIA H100"];
+        assert_eq!(
+            count_names(names.into_iter()),
+            "2× NVIDIA H100, 1× NVIDIA A100"
+        );
+        assert_eq!(count_names(std::iter::empty()), "none");
+    }
+}
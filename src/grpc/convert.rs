@@ -0,0 +1,133 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions from the collected structs to their protobuf messages. A field
+//! added to one of the structs should be added to the message here as well.
+
+use super::proto;
+use crate::app_state::AppState;
+use crate::device::{CpuInfo, GpuInfo, MemoryInfo, ProcessInfo};
+use crate::storage::info::StorageInfo;
+
+impl From<&GpuInfo> for proto::Device {
+    fn from(info: &GpuInfo) -> Self {
+        Self {
+            uuid: info.uuid.clone(),
+            name: info.name.clone(),
+            device_type: info.device_type.clone(),
+            hostname: info.hostname.clone(),
+            instance: info.instance.clone(),
+            utilization: info.utilization,
+            ane_utilization: info.ane_utilization,
+            dla_utilization: info.dla_utilization,
+            tensorcore_utilization: info.tensorcore_utilization,
+            encoder_utilization: info.encoder_utilization,
+            decoder_utilization: info.decoder_utilization,
+            temperature: info.temperature,
+            used_memory: info.used_memory,
+            total_memory: info.total_memory,
+            frequency: info.frequency,
+            power_consumption: info.power_consumption,
+            gpu_core_count: info.gpu_core_count,
+            detail: info.detail.clone(),
+        }
+    }
+}
+
+impl From<&CpuInfo> for proto::Cpu {
+    fn from(info: &CpuInfo) -> Self {
+        Self {
+            hostname: info.hostname.clone(),
+            instance: info.instance.clone(),
+            cpu_model: info.cpu_model.clone(),
+            architecture: info.architecture.clone(),
+            socket_count: info.socket_count,
+            total_cores: info.total_cores,
+            total_threads: info.total_threads,
+            base_frequency_mhz: info.base_frequency_mhz,
+            max_frequency_mhz: info.max_frequency_mhz,
+            cache_size_mb: info.cache_size_mb,
+            utilization: info.utilization,
+            temperature: info.temperature,
+            power_consumption: info.power_consumption,
+        }
+    }
+}
+
+impl From<&MemoryInfo> for proto::Memory {
+    fn from(info: &MemoryInfo) -> Self {
+        Self {
+            hostname: info.hostname.clone(),
+            instance: info.instance.clone(),
+            total_bytes: info.total_bytes,
+            used_bytes: info.used_bytes,
+            available_bytes: info.available_bytes,
+            free_bytes: info.free_bytes,
+            buffers_bytes: info.buffers_bytes,
+            cached_bytes: info.cached_bytes,
+            swap_total_bytes: info.swap_total_bytes,
+            swap_used_bytes: info.swap_used_bytes,
+            swap_free_bytes: info.swap_free_bytes,
+            utilization: info.utilization,
+        }
+    }
+}
+
+impl From<&StorageInfo> for proto::Disk {
+    fn from(info: &StorageInfo) -> Self {
+        Self {
+            mount_point: info.mount_point.clone(),
+            hostname: info.hostname.clone(),
+            index: info.index,
+            total_bytes: info.total_bytes,
+            available_bytes: info.available_bytes,
+            total_inodes: info.total_inodes,
+            free_inodes: info.free_inodes,
+        }
+    }
+}
+
+impl From<&ProcessInfo> for proto::Process {
+    fn from(info: &ProcessInfo) -> Self {
+        Self {
+            pid: info.pid,
+            ppid: info.ppid,
+            device_uuid: info.device_uuid.clone(),
+            device_id: info.device_id as u64,
+            process_name: info.process_name.clone(),
+            command: info.command.clone(),
+            user: info.user.clone(),
+            used_memory: info.used_memory,
+            gpu_utilization: info.gpu_utilization,
+            cpu_percent: info.cpu_percent,
+            memory_rss: info.memory_rss,
+            start_time: info.start_time,
+        }
+    }
+}
+
+impl proto::Snapshot {
+    /// Snapshot of `state` taken at `timestamp_ms`.
+    pub fn from_state(state: &AppState, hostname: &str, timestamp_ms: i64) -> Self {
+        Self {
+            timestamp_ms,
+            hostname: hostname.to_string(),
+            devices: state.gpu_info.iter().map(Into::into).collect(),
+            cpus: state.cpu_info.iter().map(Into::into).collect(),
+            memory: state.memory_info.iter().map(Into::into).collect(),
+            disks: state.storage_info.iter().map(Into::into).collect(),
+            processes: state.process_info.iter().map(Into::into).collect(),
+        }
+    }
+}
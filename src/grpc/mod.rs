@@ -0,0 +1,28 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! gRPC push of API mode snapshots (`grpc` feature).
+//!
+//! The collection loop hands each cycle's state to a [`SnapshotPublisher`];
+//! [`serve_grpc`] answers `GetSnapshot` with the latest one and streams every
+//! new one to `StreamMetrics` subscribers.
+
+mod convert;
+mod service;
+
+pub mod proto {
+    tonic::include_proto!("all_smi.v1");
+}
+
+pub use service::{serve_grpc, snapshot_channel, SnapshotSubscriber};
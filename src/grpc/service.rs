@@ -0,0 +1,116 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures_util::{stream, Stream};
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tonic::transport::server::TcpIncoming;
+use tonic::{Request, Response, Status};
+
+use super::proto::metrics_service_server::{MetricsService, MetricsServiceServer};
+use super::proto::{Snapshot, SnapshotRequest};
+use crate::app_state::AppState;
+
+/// Receives the latest snapshot. `None` until the first collection finishes.
+pub type SnapshotSubscriber = watch::Receiver<Option<Arc<Snapshot>>>;
+
+/// Publishes one snapshot per collection cycle.
+///
+/// Only the newest snapshot is kept, so a subscriber that falls behind skips
+/// to it instead of queueing the ones it missed.
+pub struct SnapshotPublisher {
+    sender: watch::Sender<Option<Arc<Snapshot>>>,
+    hostname: String,
+    last_timestamp_ms: i64,
+}
+
+/// A publisher and a subscriber to hand to [`serve_grpc`].
+pub fn snapshot_channel(hostname: String) -> (SnapshotPublisher, SnapshotSubscriber) {
+    let (sender, receiver) = watch::channel(None);
+    let publisher = SnapshotPublisher {
+        sender,
+        hostname,
+        last_timestamp_ms: 0,
+    };
+    (publisher, receiver)
+}
+
+impl SnapshotPublisher {
+    /// Publish `state` as the latest snapshot. Timestamps are strictly
+    /// increasing even if the wall clock steps back.
+    pub fn publish(&mut self, state: &AppState) {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        self.last_timestamp_ms = now_ms.max(self.last_timestamp_ms + 1);
+        let snapshot = Snapshot::from_state(state, &self.hostname, self.last_timestamp_ms);
+        self.sender.send_replace(Some(Arc::new(snapshot)));
+    }
+}
+
+type SnapshotStream = Pin<Box<dyn Stream<Item = Result<Snapshot, Status>> + Send>>;
+
+struct MetricsGrpc {
+    snapshots: SnapshotSubscriber,
+}
+
+#[tonic::async_trait]
+impl MetricsService for MetricsGrpc {
+    async fn get_snapshot(
+        &self,
+        _request: Request<SnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        match self.snapshots.borrow().as_deref() {
+            Some(snapshot) => Ok(Response::new(snapshot.clone())),
+            None => Err(Status::unavailable("No snapshot has been collected yet")),
+        }
+    }
+
+    type StreamMetricsStream = SnapshotStream;
+
+    async fn stream_metrics(
+        &self,
+        _request: Request<SnapshotRequest>,
+    ) -> Result<Response<Self::StreamMetricsStream>, Status> {
+        let mut snapshots = self.snapshots.clone();
+        // Start with the snapshot already collected, if any
+        snapshots.mark_changed();
+
+        let stream = stream::unfold(snapshots, |mut snapshots| async move {
+            loop {
+                // Ends the stream once the publisher is gone
+                snapshots.changed().await.ok()?;
+                let latest = snapshots.borrow_and_update().clone();
+                if let Some(snapshot) = latest {
+                    return Some((Ok(Snapshot::clone(&snapshot)), snapshots));
+                }
+            }
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// Serve `MetricsService` on `listener` until `shutdown` resolves.
+pub async fn serve_grpc(
+    listener: TcpListener,
+    snapshots: SnapshotSubscriber,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(MetricsServiceServer::new(MetricsGrpc { snapshots }))
+        .serve_with_incoming_shutdown(TcpIncoming::from(listener), shutdown)
+        .await
+}
@@ -105,6 +105,9 @@ pub use error::{Error, Result};
 /// Device readers and types for GPU, CPU, memory monitoring.
 pub mod device;
 
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
 /// Parsing utilities and macros.
 #[macro_use]
 pub mod parsing;
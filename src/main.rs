@@ -14,9 +14,14 @@
 
 mod api;
 mod app_state;
+mod audit;
 mod cli;
 mod common;
 mod device;
+mod diff;
+mod doctor;
+#[cfg(feature = "grpc")]
+mod grpc;
 #[macro_use]
 mod parsing;
 mod metrics;
@@ -26,15 +31,23 @@ mod ui;
 mod utils;
 mod view;
 
-use api::run_api_mode;
-use clap::Parser;
-use cli::{Cli, Commands, LocalArgs};
+use api::{run_api_mode, run_api_once};
+use app_state::SortCriteria;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches};
+use cli::{
+    ApiArgs, Cli, Commands, DiskFilterArgs, LocalArgs, OomArgs, RecordingArgs, SubsystemArgs,
+};
+use common::config::{check_label_collisions, CliOverrides, EffectiveConfig, LoadedConfig};
+use device::memory_headroom::set_oom_thresholds;
+use device::privileges::init_missing_features;
+use device::readers::plugin::load_plugins;
+use std::sync::Once;
 use tokio::signal;
-use utils::{ensure_sudo_permissions_for_api, RuntimeEnvironment};
-
-// Sudo permission functions only needed on non-macOS platforms
-#[cfg(not(target_os = "macos"))]
-use utils::{ensure_sudo_permissions, ensure_sudo_permissions_with_fallback};
+use tokio::sync::watch;
+use tracing::Level;
+use utils::logging::{init_logging, raise_level, LogOutput};
+use utils::RuntimeEnvironment;
 
 #[cfg(target_os = "macos")]
 use device::is_apple_silicon;
@@ -63,57 +76,128 @@ async fn main() {
     #[cfg(target_os = "macos")]
     setup_panic_handler();
 
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
-    // Set up signal handler for clean shutdown
-    tokio::spawn(async {
-        signal::ctrl_c().await.expect("Failed to listen for Ctrl+C");
-        #[cfg(target_os = "macos")]
-        {
-            // Cleanup native metrics manager on signal
-            shutdown_native_metrics_manager();
-        }
-        #[cfg(target_os = "linux")]
-        {
-            // Always cleanup hlsmi on signal
-            shutdown_hlsmi_manager();
+    if cli.version {
+        print_version(cli.json);
+        return;
+    }
+
+    // Merge defaults, config file and command line flags
+    let loaded_config = match LoadedConfig::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
         }
-        std::process::exit(0);
-    });
+    };
+    let effective_config = EffectiveConfig::resolve(&loaded_config, &cli_overrides(&cli, &matches));
+    for warning in loaded_config
+        .warnings
+        .iter()
+        .chain(effective_config.warnings.iter())
+    {
+        eprintln!("Warning: {warning}");
+    }
 
-    // Also handle SIGTERM on Unix systems
-    #[cfg(unix)]
-    tokio::spawn(async {
-        let mut sigterm = signal::unix::signal(signal::unix::SignalKind::terminate())
-            .expect("Failed to listen for SIGTERM");
-        sigterm.recv().await;
-        #[cfg(target_os = "macos")]
-        {
-            // Cleanup native metrics manager on signal
-            shutdown_native_metrics_manager();
+    if cli.show_config {
+        print!("{}", effective_config.render());
+        return;
+    }
+
+    apply_effective_config(&mut cli, &effective_config);
+    let logging = match cli.command {
+        Some(Commands::Api(_) | Commands::Proxy(_)) => {
+            let level = raise_level(Level::DEBUG, cli.verbose);
+            Some((
+                LogOutput::Stderr,
+                format!("all_smi={level},tower_http={level}"),
+            ))
         }
-        #[cfg(target_os = "linux")]
-        {
-            // Always cleanup hlsmi on signal
-            shutdown_hlsmi_manager();
+        // Reader errors go to stderr next to the report
+        Some(Commands::Doctor(_)) => {
+            let level = raise_level(Level::WARN, cli.verbose);
+            Some((LogOutput::Stderr, format!("all_smi={level}")))
         }
-        std::process::exit(0);
-    });
+        Some(
+            Commands::GenDashboard(_)
+            | Commands::Completions(_)
+            | Commands::Manpage(_)
+            | Commands::Diff(_)
+            | Commands::Audit(_),
+        ) => None,
+        // The TUI owns the terminal; records are shown in the log overlay
+        _ => {
+            let level = raise_level(Level::INFO, cli.verbose);
+            Some((LogOutput::Buffer, format!("all_smi={level}")))
+        }
+    };
+    if let Some((output, filter)) = logging {
+        // A log file replaces stderr and is the only place TUI records are written
+        let output = cli.log_file.clone().map_or(output, LogOutput::File);
+        if let Err(e) = init_logging(output, &filter) {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+    }
+    utils::set_mount_patterns(effective_config.disk.mount_patterns());
+    let oom = match &cli.command {
+        Some(Commands::Api(args)) => Some(&args.oom),
+        Some(Commands::Local(args)) => Some(&args.oom),
+        Some(Commands::View(args)) => Some(&args.oom),
+        _ => None,
+    };
+    if let Some(oom) = oom {
+        set_oom_thresholds(oom.thresholds());
+    }
+    let plugins = match &cli.command {
+        Some(Commands::Api(args)) => args.subsystems.plugin.as_slice(),
+        Some(Commands::Local(args)) => args.subsystems.plugin.as_slice(),
+        _ => &[],
+    };
+    if let Err(e) = load_plugins(plugins) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+    let ui_settings = effective_config.ui_settings();
+    if !ui_settings.colors {
+        crossterm::style::force_color_output(false);
+    }
+    ui::theme::set_theme(ui_settings.theme);
+    utils::set_unit_system(ui_settings.units);
+    utils::set_temperature_unit(ui_settings.temp_unit);
+    utils::runtime_environment::set_drain_file(cli.drain_file.clone());
+
+    // API mode drains in-flight requests and returns from main on a signal.
+    // The TUI modes exit right away.
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    if matches!(cli.command, Some(Commands::Api(_) | Commands::Proxy(_))) {
+        tokio::spawn(async move {
+            wait_for_shutdown_signal().await;
+            tracing::info!("Shutdown requested, finishing in-flight requests");
+            let _ = shutdown_tx.send(true);
+        });
+    } else {
+        tokio::spawn(async {
+            wait_for_shutdown_signal().await;
+            shutdown_managers();
+            std::process::exit(0);
+        });
+    }
 
     match cli.command {
         Some(Commands::Api(args)) => {
-            // When using native macOS APIs, no sudo is needed
-            #[cfg(target_os = "macos")]
-            let _ = ensure_sudo_permissions_for_api(); // Just for any other checks
-
-            #[cfg(not(target_os = "macos"))]
-            let _has_sudo = ensure_sudo_permissions_for_api();
+            // --once prints nothing but metrics to stdout
+            if !args.once {
+                init_missing_features();
+            }
 
             // Initialize native metrics manager (no sudo required)
             #[cfg(target_os = "macos")]
             if is_apple_silicon() {
                 if let Err(e) = initialize_native_metrics_manager(args.interval * 1000) {
-                    eprintln!("Warning: Failed to initialize native metrics manager: {e}");
+                    tracing::warn!("Failed to initialize native metrics manager: {e}");
                 } else {
                     use std::sync::atomic::Ordering;
                     NATIVE_METRICS_INITIALIZED.store(true, Ordering::Relaxed);
@@ -122,49 +206,58 @@ async fn main() {
 
             // Initialize hlsmi manager for Intel Gaudi on Linux
             #[cfg(target_os = "linux")]
-            if has_gaudi() {
+            if has_gaudi() && !args.subsystems.no_gpu {
                 if let Err(e) = initialize_hlsmi_manager(args.interval) {
-                    eprintln!("Warning: Failed to initialize hlsmi manager: {e}");
+                    tracing::warn!("Failed to initialize hlsmi manager: {e}");
                 } else {
                     use std::sync::atomic::Ordering;
                     HLSMI_INITIALIZED.store(true, Ordering::Relaxed);
                 }
             }
 
-            run_api_mode(&args).await;
+            exit_on_unknown_devices(&args.subsystems);
+            exit_on_label_collisions(&args);
+            if args.once {
+                run_api_once(&args).await;
+            } else {
+                run_api_mode(&args, shutdown_rx).await;
+            }
         }
         Some(Commands::Local(args)) => {
-            // On non-macOS platforms, require sudo
-            #[cfg(not(target_os = "macos"))]
-            ensure_sudo_permissions();
-
-            // Initialize native metrics manager (no sudo required)
-            #[cfg(target_os = "macos")]
-            if is_apple_silicon() {
-                let interval = args.interval.unwrap_or(2);
-                if let Err(e) = initialize_native_metrics_manager(interval * 1000) {
-                    eprintln!("Warning: Failed to initialize native metrics manager: {e}");
-                } else {
-                    use std::sync::atomic::Ordering;
-                    NATIVE_METRICS_INITIALIZED.store(true, Ordering::Relaxed);
-                }
-            }
+            // A replay only reads the recording, so it needs neither
+            // privileges nor the device managers
+            if args.recording.replay.is_none() {
+                init_missing_features();
 
-            // Initialize hlsmi manager for Intel Gaudi on Linux
-            #[cfg(target_os = "linux")]
-            if has_gaudi() {
-                let interval = args.interval.unwrap_or(2);
-                std::thread::spawn(move || {
-                    if let Err(e) = initialize_hlsmi_manager(interval) {
-                        eprintln!("Warning: Failed to initialize hlsmi manager: {e}");
+                // Initialize native metrics manager (no sudo required)
+                #[cfg(target_os = "macos")]
+                if is_apple_silicon() {
+                    let interval = args.interval.unwrap_or(2);
+                    if let Err(e) = initialize_native_metrics_manager(interval * 1000) {
+                        tracing::warn!("Failed to initialize native metrics manager: {e}");
                     } else {
                         use std::sync::atomic::Ordering;
-                        HLSMI_INITIALIZED.store(true, Ordering::Relaxed);
+                        NATIVE_METRICS_INITIALIZED.store(true, Ordering::Relaxed);
                     }
-                });
+                }
+
+                // Initialize hlsmi manager for Intel Gaudi on Linux
+                #[cfg(target_os = "linux")]
+                if has_gaudi() && !args.subsystems.no_gpu {
+                    let interval = args.interval.unwrap_or(2);
+                    std::thread::spawn(move || {
+                        if let Err(e) = initialize_hlsmi_manager(interval) {
+                            tracing::warn!("Failed to initialize hlsmi manager: {e}");
+                        } else {
+                            use std::sync::atomic::Ordering;
+                            HLSMI_INITIALIZED.store(true, Ordering::Relaxed);
+                        }
+                    });
+                }
+                exit_on_unknown_devices(&args.subsystems);
             }
 
-            view::run_local_mode(&args).await;
+            view::run_local_mode(&args, &ui_settings, cli.readonly).await;
         }
         Some(Commands::View(mut args)) => {
             // Remote mode - no sudo required
@@ -194,81 +287,279 @@ async fn main() {
                     std::process::exit(1);
                 }
             }
-            view::run_view_mode(&args).await;
-
-            // Cleanup after view mode exits
-            #[cfg(target_os = "macos")]
-            {
-                // Cleanup native metrics manager
-                shutdown_native_metrics_manager();
+            view::run_view_mode(&args, &ui_settings).await;
+        }
+        Some(Commands::GenDashboard(args)) => {
+            if let Err(e) = api::dashboard::write_dashboard(args.output.as_deref()) {
+                eprintln!("Error: failed to write dashboard: {e}");
+                std::process::exit(1);
             }
-            #[cfg(target_os = "linux")]
-            {
-                // Always try to shutdown hlsmi, even if not fully initialized
-                shutdown_hlsmi_manager();
+        }
+        Some(Commands::Completions(args)) => {
+            cli::write_completions(args.shell, &mut std::io::stdout());
+        }
+        Some(Commands::Diff(args)) => {
+            std::process::exit(diff::run(&args).await);
+        }
+        Some(Commands::Audit(args)) => {
+            std::process::exit(audit::run(&args).await);
+        }
+        Some(Commands::Proxy(args)) => {
+            std::process::exit(api::proxy::run_proxy_mode(&args, shutdown_rx).await);
+        }
+        Some(Commands::Doctor(args)) => {
+            let code = doctor::run(&args);
+            shutdown_managers();
+            std::process::exit(code);
+        }
+        Some(Commands::Replay(args)) => {
+            // Like `local --replay`: no privileges or device managers needed
+            view::run_local_mode(&args.local_args(), &ui_settings, cli.readonly).await;
+        }
+        Some(Commands::Manpage(args)) => {
+            let result = match &args.dir {
+                Some(dir) => cli::write_manpages(dir),
+                None => cli::write_manpage(&mut std::io::stdout()),
+            };
+            if let Err(e) = result {
+                eprintln!("Error: failed to write man page: {e}");
+                std::process::exit(1);
             }
         }
         None => {
             // Default to local mode when no command is specified
-            // On macOS, no sudo is needed
-            #[cfg(target_os = "macos")]
-            let has_sudo = true; // Always proceed, no sudo needed
+            init_missing_features();
 
-            #[cfg(not(target_os = "macos"))]
-            let has_sudo = ensure_sudo_permissions_with_fallback();
+            // Initialize native metrics manager (no sudo required)
+            #[cfg(target_os = "macos")]
+            if is_apple_silicon() {
+                if let Err(e) = initialize_native_metrics_manager(2000) {
+                    tracing::warn!("Failed to initialize native metrics manager: {e}");
+                } else {
+                    use std::sync::atomic::Ordering;
+                    NATIVE_METRICS_INITIALIZED.store(true, Ordering::Relaxed);
+                }
+            }
 
-            if has_sudo {
-                // Initialize native metrics manager (no sudo required)
-                #[cfg(target_os = "macos")]
-                if is_apple_silicon() {
-                    if let Err(e) = initialize_native_metrics_manager(2000) {
-                        eprintln!("Warning: Failed to initialize native metrics manager: {e}");
+            // Initialize hlsmi manager for Intel Gaudi on Linux
+            #[cfg(target_os = "linux")]
+            if has_gaudi() {
+                std::thread::spawn(|| {
+                    if let Err(e) = initialize_hlsmi_manager(2) {
+                        tracing::warn!("Failed to initialize hlsmi manager: {e}");
                     } else {
                         use std::sync::atomic::Ordering;
-                        NATIVE_METRICS_INITIALIZED.store(true, Ordering::Relaxed);
+                        HLSMI_INITIALIZED.store(true, Ordering::Relaxed);
                     }
-                }
+                });
+            }
 
-                // Initialize hlsmi manager for Intel Gaudi on Linux
-                #[cfg(target_os = "linux")]
-                if has_gaudi() {
-                    std::thread::spawn(|| {
-                        if let Err(e) = initialize_hlsmi_manager(2) {
-                            eprintln!("Warning: Failed to initialize hlsmi manager: {e}");
-                        } else {
-                            use std::sync::atomic::Ordering;
-                            HLSMI_INITIALIZED.store(true, Ordering::Relaxed);
-                        }
-                    });
-                }
+            let args = LocalArgs {
+                interval: effective_config.view.interval.value,
+                mouse: false,
+                theme: None,
+                sort_by: None,
+                normalize_load: false,
+                smooth: None,
+                textfile_dir: None,
+                subsystems: SubsystemArgs::default(),
+                disks: DiskFilterArgs::default(),
+                oom: OomArgs::default(),
+                recording: RecordingArgs::default(),
+            };
+            view::run_local_mode(&args, &ui_settings, cli.readonly).await;
+        }
+    }
 
-                view::run_local_mode(&LocalArgs { interval: None }).await;
+    // Final cleanup - ensure all managers are terminated
+    shutdown_managers();
+}
 
-                // Cleanup after local mode exits
-                #[cfg(target_os = "macos")]
-                {
-                    // Cleanup native metrics manager
-                    shutdown_native_metrics_manager();
-                }
-                #[cfg(target_os = "linux")]
-                {
-                    // Always try to shutdown hlsmi, even if not fully initialized
-                    shutdown_hlsmi_manager();
-                }
-            }
-            // If user declined sudo and chose remote monitoring,
-            // they were given instructions and the function exits
+/// `--version`, or with `--json` the build details
+fn print_version(json: bool) {
+    if !json {
+        print!("{}", Cli::command().render_version());
+        return;
+    }
+    match serde_json::to_string_pretty(&utils::build_info::BuildInfo::current()) {
+        Ok(json) => println!("{json}"),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
         }
     }
+}
 
-    // Final cleanup - ensure all managers are terminated
-    #[cfg(target_os = "macos")]
-    {
-        shutdown_native_metrics_manager();
+/// Resolves on Ctrl+C, or SIGTERM on Unix.
+async fn wait_for_shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("Failed to listen for Ctrl+C");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
     }
-    #[cfg(target_os = "linux")]
-    {
+}
+
+/// Fail before serving when `--labels`/`api.labels` cannot tell the GPUs
+/// of this host apart
+fn exit_on_label_collisions(args: &ApiArgs) {
+    if args.labels.is_empty() {
+        return;
+    }
+    let devices: Vec<_> = args
+        .subsystems
+        .collection_filter()
+        .gpu_readers()
+        .iter()
+        .flat_map(|reader| reader.get_gpu_info())
+        .collect();
+    if let Err(e) = check_label_collisions(&args.labels, &devices) {
+        eprintln!("Error: {e}");
+        shutdown_managers();
+        std::process::exit(1);
+    }
+}
+
+/// Fail before collecting when `--devices` or `--device-uuid` names a
+/// device this host lacks
+fn exit_on_unknown_devices(subsystems: &SubsystemArgs) {
+    if let Err(e) = subsystems.collection_filter().check_devices() {
+        eprintln!("Error: {e}");
+        shutdown_managers();
+        std::process::exit(1);
+    }
+}
+
+/// Stop the background metric managers. Runs once no matter how often it is
+/// called, so the signal handler and the end of `main` cannot race.
+fn shutdown_managers() {
+    static SHUT_DOWN: Once = Once::new();
+    SHUT_DOWN.call_once(|| {
+        #[cfg(target_os = "macos")]
+        shutdown_native_metrics_manager();
+        // Always try to shutdown hlsmi, even if not fully initialized
+        #[cfg(target_os = "linux")]
         shutdown_hlsmi_manager();
+    });
+}
+
+/// Collect the values the user explicitly passed on the command line, so that
+/// clap defaults do not shadow config file values.
+fn cli_overrides(cli: &Cli, matches: &ArgMatches) -> CliOverrides {
+    fn given(matches: &ArgMatches, id: &str) -> bool {
+        matches.value_source(id) == Some(ValueSource::CommandLine)
+    }
+    fn non_empty(values: &[String]) -> Option<Vec<String>> {
+        (!values.is_empty()).then(|| values.to_vec())
+    }
+
+    let mut overrides = CliOverrides {
+        ui_units: cli.units,
+        ui_temp_unit: cli.temp_unit,
+        ..CliOverrides::default()
+    };
+    match (&cli.command, matches.subcommand()) {
+        (Some(Commands::Api(args)), Some((_, sub))) => {
+            overrides.api_port = given(sub, "port").then_some(args.port);
+            overrides.api_interval = given(sub, "interval").then_some(args.interval);
+            overrides.api_collect_intervals =
+                (!args.collect_interval.is_empty()).then(|| args.collect_interval.clone());
+            overrides.api_processes = given(sub, "processes").then_some(args.processes);
+            overrides.api_labels = given(sub, "labels").then(|| args.labels.clone());
+            overrides.disk_include = non_empty(&args.disks.disk_include);
+            overrides.disk_exclude = non_empty(&args.disks.disk_exclude);
+        }
+        (Some(Commands::Local(args)), _) => {
+            overrides.view_interval = args.interval;
+            overrides.disk_include = non_empty(&args.disks.disk_include);
+            overrides.disk_exclude = non_empty(&args.disks.disk_exclude);
+            overrides.ui_theme = args.theme;
+            overrides.ui_default_sort = args.sort_by.map(SortCriteria::from);
+        }
+        (Some(Commands::Replay(args)), _) => {
+            overrides.ui_theme = args.theme;
+        }
+        (Some(Commands::View(args)), _) => {
+            overrides.view_hosts = args.hosts.clone();
+            overrides.view_hostfile = args.hostfile.clone();
+            overrides.view_interval = args.interval;
+            overrides.ui_theme = args.theme;
+            overrides.ui_default_sort = args.sort_by.map(SortCriteria::from);
+        }
+        (Some(Commands::Audit(args)), _) => {
+            overrides.view_hosts = args.hosts.clone();
+            overrides.view_hostfile = args.hostfile.clone();
+        }
+        (Some(Commands::Proxy(args)), _) => {
+            overrides.view_hosts = args.hosts.clone();
+            overrides.view_hostfile = args.hostfile.clone();
+        }
+        (Some(Commands::Doctor(args)), Some((_, sub))) => {
+            overrides.api_port = given(sub, "port").then_some(args.port);
+        }
+        _ => {}
+    }
+    overrides
+}
+
+/// Write the resolved configuration back into the parsed subcommand args
+fn apply_effective_config(cli: &mut Cli, config: &EffectiveConfig) {
+    match &mut cli.command {
+        Some(Commands::Api(args)) => {
+            args.port = config.api.port.value;
+            args.interval = config.api.interval.value;
+            args.collect_interval = config.api.collect_intervals.value.clone();
+            args.processes = config.api.processes.value;
+            args.labels = config.api.labels.value.clone();
+        }
+        Some(Commands::Local(args)) => {
+            args.interval = config.view.interval.value;
+        }
+        Some(Commands::View(args)) => {
+            let hosts = &config.view.hosts.value;
+            args.hosts = (!hosts.is_empty()).then(|| hosts.clone());
+            let hostfiles = &config.view.hostfile.value;
+            args.hostfile = (!hostfiles.is_empty()).then(|| hostfiles.clone());
+            args.interval = config.view.interval.value;
+        }
+        Some(Commands::Audit(args)) => {
+            // Audit the hosts `view` would show
+            let hosts = &config.view.hosts.value;
+            args.hosts = (!hosts.is_empty()).then(|| hosts.clone());
+            let hostfiles = &config.view.hostfile.value;
+            args.hostfile = (!hostfiles.is_empty()).then(|| hostfiles.clone());
+        }
+        Some(Commands::Proxy(args)) => {
+            // Federate the hosts `view` would show
+            let hosts = &config.view.hosts.value;
+            args.hosts = (!hosts.is_empty()).then(|| hosts.clone());
+            let hostfiles = &config.view.hostfile.value;
+            args.hostfile = (!hostfiles.is_empty()).then(|| hostfiles.clone());
+        }
+        Some(Commands::Doctor(args)) => {
+            // Check the port `api` would use
+            args.port = config.api.port.value;
+        }
+        Some(
+            Commands::GenDashboard(_)
+            | Commands::Completions(_)
+            | Commands::Manpage(_)
+            | Commands::Diff(_)
+            | Commands::Replay(_),
+        )
+        | None => {}
     }
 }
 
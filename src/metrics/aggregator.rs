@@ -275,7 +275,6 @@ pub struct HostMetrics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
 
     fn create_test_gpu() -> GpuInfo {
         GpuInfo {
@@ -287,16 +286,12 @@ mod tests {
             hostname: "test-host".to_string(),
             instance: "test-instance".to_string(),
             utilization: 75.0,
-            ane_utilization: 0.0,
-            dla_utilization: None,
-            tensorcore_utilization: None,
             temperature: 80,
             used_memory: 8 * 1024 * 1024 * 1024,   // 8GB
             total_memory: 16 * 1024 * 1024 * 1024, // 16GB
             frequency: 1500,
             power_consumption: 250.0,
-            gpu_core_count: None,
-            detail: HashMap::new(),
+            ..Default::default()
         }
     }
 
@@ -48,6 +48,9 @@ impl NvidiaMockGenerator {
         // NVIDIA-specific: P-state metrics
         self.add_pstate_metrics(&mut template, gpus);
 
+        // Per-domain clocks
+        self.add_clock_metrics(&mut template, gpus);
+
         // NVIDIA-specific: Process metrics
         self.add_process_metrics(&mut template, gpus);
 
@@ -129,6 +132,39 @@ impl NvidiaMockGenerator {
         }
     }
 
+    fn add_clock_metrics(&self, template: &mut String, gpus: &[GpuMetrics]) {
+        // SM and graphics follow the simulated frequency; memory and video
+        // clocks sit at H100 HBM3 and NVDEC defaults
+        let clocks = [
+            ("sm", "Current SM clock in MHz", None),
+            ("graphics", "Current graphics clock in MHz", None),
+            ("memory", "Current memory clock in MHz", Some(2619)),
+            (
+                "video",
+                "Current video encoder/decoder clock in MHz",
+                Some(1755),
+            ),
+        ];
+
+        for (domain, help_text, fixed) in clocks {
+            let metric_name = format!("all_smi_gpu_clock_{domain}_mhz");
+            template.push_str(&format!("# HELP {metric_name} {help_text}\n"));
+            template.push_str(&format!("# TYPE {metric_name} gauge\n"));
+
+            for (i, gpu) in gpus.iter().enumerate() {
+                let labels = format!(
+                    "gpu=\"{}\", instance=\"{}\", uuid=\"{}\", index=\"{i}\"",
+                    self.gpu_name, self.instance_name, gpu.uuid
+                );
+                let value = match fixed {
+                    Some(clock) => clock.to_string(),
+                    None => format!("{{{{FREQ_{i}}}}}"),
+                };
+                template.push_str(&format!("{metric_name}{{{labels}}} {value}\n"));
+            }
+        }
+    }
+
     fn add_pstate_metrics(&self, template: &mut String, gpus: &[GpuMetrics]) {
         template.push_str("# HELP all_smi_gpu_pstate GPU performance state\n");
         template.push_str("# TYPE all_smi_gpu_pstate gauge\n");
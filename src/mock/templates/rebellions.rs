@@ -20,6 +20,9 @@ use all_smi::traits::mock_generator::{
 };
 use rand::{rng, RngExt};
 
+/// Neural engines per mocked device
+const MOCK_CORES: usize = 16;
+
 /// Rebellions NPU mock generator
 pub struct RebellionsMockGenerator {
     gpu_name: String,
@@ -115,9 +118,40 @@ impl RebellionsMockGenerator {
                 self.gpu_name, self.instance_name, gpu.uuid
             );
             template.push_str(&format!(
-                "all_smi_npu_cores_total{{{labels}}} 16\n" // ATOM has 16 cores
+                "all_smi_npu_cores_total{{{labels}}} {MOCK_CORES}\n"
             ));
         }
+
+        // Per-core utilization and memory
+        template.push_str(
+            "# HELP all_smi_rebellions_core_utilization NPU core utilization percentage\n",
+        );
+        template.push_str("# TYPE all_smi_rebellions_core_utilization gauge\n");
+        for (i, gpu) in gpus.iter().enumerate() {
+            for core in 0..MOCK_CORES {
+                let labels = format!(
+                    "gpu=\"{}\", instance=\"{}\", uuid=\"{}\", index=\"{i}\", core=\"{core}\"",
+                    self.gpu_name, self.instance_name, gpu.uuid
+                );
+                template.push_str(&format!(
+                    "all_smi_rebellions_core_utilization{{{labels}}} {{{{CORE_UTIL_{i}_{core}}}}}\n"
+                ));
+            }
+        }
+
+        template.push_str("# HELP all_smi_rebellions_core_memory_used_bytes Device memory used by an NPU core in bytes\n");
+        template.push_str("# TYPE all_smi_rebellions_core_memory_used_bytes gauge\n");
+        for (i, gpu) in gpus.iter().enumerate() {
+            for core in 0..MOCK_CORES {
+                let labels = format!(
+                    "gpu=\"{}\", instance=\"{}\", uuid=\"{}\", index=\"{i}\", core=\"{core}\"",
+                    self.gpu_name, self.instance_name, gpu.uuid
+                );
+                template.push_str(&format!(
+                    "all_smi_rebellions_core_memory_used_bytes{{{labels}}} {{{{CORE_MEM_{i}_{core}}}}}\n"
+                ));
+            }
+        }
     }
 
     fn add_driver_metrics(&self, template: &mut String) {
@@ -149,7 +183,7 @@ impl RebellionsMockGenerator {
 
             // Active cores (based on utilization)
             let cores_active = if gpu.utilization > 80.0 {
-                16 // All cores active
+                MOCK_CORES // All cores active
             } else if gpu.utilization > 50.0 {
                 12
             } else if gpu.utilization > 20.0 {
@@ -163,6 +197,23 @@ impl RebellionsMockGenerator {
                 &format!("{{{{CORES_ACTIVE_{i}}}}}"),
                 &cores_active.to_string(),
             );
+
+            // Busy cores share the device's load and memory, idle ones report 0
+            let core_memory = gpu.memory_used_bytes / cores_active.max(1) as u64;
+            for core in 0..MOCK_CORES {
+                let (util, memory) = if core < cores_active {
+                    let util = (gpu.utilization + rng.random_range(-5.0..5.0)).clamp(0.0, 100.0);
+                    (util, core_memory)
+                } else {
+                    (0.0, 0)
+                };
+                response = response.replace(
+                    &format!("{{{{CORE_UTIL_{i}_{core}}}}}"),
+                    &format!("{util:.2}"),
+                );
+                response =
+                    response.replace(&format!("{{{{CORE_MEM_{i}_{core}}}}}"), &memory.to_string());
+            }
         }
 
         // Render system metrics
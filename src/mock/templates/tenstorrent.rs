@@ -57,6 +57,9 @@ impl TenstorrentMockGenerator {
         // Tenstorrent-specific: Voltage and current
         self.add_power_metrics(&mut template, gpus);
 
+        // Tenstorrent-specific: chip-to-chip Ethernet links
+        self.add_eth_link_metrics(&mut template, gpus);
+
         // System metrics
         super::common::add_system_metrics(&mut template, &self.instance_name);
 
@@ -168,6 +171,24 @@ impl TenstorrentMockGenerator {
         }
     }
 
+    fn add_eth_link_metrics(&self, template: &mut String, gpus: &[GpuMetrics]) {
+        template.push_str(
+            "# HELP all_smi_tenstorrent_eth_link_up 1 if the chip-to-chip Ethernet link is up\n",
+        );
+        template.push_str("# TYPE all_smi_tenstorrent_eth_link_up gauge\n");
+
+        // Two cabled QSFP-DD ports per board, both trained
+        for (i, gpu) in gpus.iter().enumerate() {
+            for link in 0..2 {
+                let labels = format!(
+                    "gpu=\"{}\", instance=\"{}\", uuid=\"{}\", index=\"{i}\", link=\"{link}\"",
+                    self.gpu_name, self.instance_name, gpu.uuid
+                );
+                template.push_str(&format!("all_smi_tenstorrent_eth_link_up{{{labels}}} 1\n"));
+            }
+        }
+    }
+
     fn add_driver_metrics(&self, template: &mut String) {
         template.push_str("# HELP all_smi_tenstorrent_driver_version Tenstorrent driver version\n");
         template.push_str("# TYPE all_smi_tenstorrent_driver_version gauge\n");
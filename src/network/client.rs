@@ -20,15 +20,28 @@ use std::sync::Once;
 use std::time::{Duration, Instant};
 
 use futures_util::stream::{FuturesUnordered, StreamExt};
-use regex::Regex;
 use tokio::sync::RwLock;
 use url::Url;
 
 use crate::app_state::ConnectionStatus;
 use crate::common::config::{AppConfig, EnvConfig};
-use crate::device::{CpuInfo, GpuInfo, MemoryInfo};
+use crate::device::{ChassisInfo, CpuInfo, GpuInfo, MemoryInfo, ProcessInfo};
+use crate::network::NetworkInfo;
 use crate::storage::info::StorageInfo;
 
+/// Devices scraped from every host, how each host responded, and the GPU
+/// processes of hosts that export them
+pub type RemoteData = (
+    Vec<GpuInfo>,
+    Vec<CpuInfo>,
+    Vec<MemoryInfo>,
+    Vec<StorageInfo>,
+    Vec<NetworkInfo>,
+    Vec<ChassisInfo>,
+    Vec<ConnectionStatus>,
+    Vec<ProcessInfo>,
+);
+
 pub struct NetworkClient {
     client: reqwest::Client,
     auth_token: Option<String>,
@@ -101,7 +114,9 @@ impl NetworkClient {
         // Check for authentication token in environment variable
         let auth_token = std::env::var("ALL_SMI_AUTH_TOKEN").ok();
         if auth_token.is_some() {
-            eprintln!("Using authentication token from ALL_SMI_AUTH_TOKEN environment variable");
+            tracing::info!(
+                "Using authentication token from ALL_SMI_AUTH_TOKEN environment variable"
+            );
         }
 
         Self {
@@ -157,7 +172,9 @@ impl NetworkClient {
                 static LOCALHOST_WARNING: Once = Once::new();
                 if std::env::var("SUPPRESS_LOCALHOST_WARNING").is_err() {
                     LOCALHOST_WARNING.call_once(|| {
-                        eprintln!("Warning: Connecting to localhost address (subsequent warnings suppressed)");
+                        tracing::warn!(
+                            "Connecting to localhost address (subsequent warnings suppressed)"
+                        );
                     });
                 }
             }
@@ -170,7 +187,7 @@ impl NetworkClient {
                             static PRIVATE_IP_WARNING: Once = Once::new();
                             if std::env::var("SUPPRESS_LOCALHOST_WARNING").is_err() {
                                 PRIVATE_IP_WARNING.call_once(|| {
-                                    eprintln!("Warning: Connecting to private/local IP addresses (subsequent warnings suppressed)");
+                                    tracing::warn!("Connecting to private/local IP addresses (subsequent warnings suppressed)");
                                 });
                             }
                         }
@@ -180,7 +197,7 @@ impl NetworkClient {
                             static IPV6_WARNING: Once = Once::new();
                             if std::env::var("SUPPRESS_LOCALHOST_WARNING").is_err() {
                                 IPV6_WARNING.call_once(|| {
-                                    eprintln!("Warning: Connecting to loopback/unspecified IPv6 addresses (subsequent warnings suppressed)");
+                                    tracing::warn!("Connecting to loopback/unspecified IPv6 addresses (subsequent warnings suppressed)");
                                 });
                             }
                         }
@@ -238,8 +255,8 @@ impl NetworkClient {
             if let Some(sys_limit) = limit {
                 let safe_limit = sys_limit / 10; // Use max 10% of system limit
                 if requested > safe_limit {
-                    eprintln!(
-                        "Warning: Requested pool size {requested} exceeds safe limit {safe_limit}, using {safe_limit}"
+                    tracing::warn!(
+                        "Requested pool size {requested} exceeds safe limit {safe_limit}, using {safe_limit}"
                     );
                     return safe_limit;
                 }
@@ -253,33 +270,101 @@ impl NetworkClient {
         if requested < MIN_POOL_SIZE {
             MIN_POOL_SIZE
         } else if requested > MAX_POOL_SIZE {
-            eprintln!(
-                "Warning: Pool size {requested} exceeds maximum {MAX_POOL_SIZE}, using maximum"
-            );
+            tracing::warn!("Pool size {requested} exceeds maximum {MAX_POOL_SIZE}, using maximum");
             MAX_POOL_SIZE
         } else {
             requested
         }
     }
 
+    /// Scrape every host, at most as many at once as `semaphore` allows,
+    /// keeping the `process_top` processes of each using the most GPU memory.
     pub async fn fetch_remote_data(
         &self,
         hosts: &[String],
         semaphore: &Arc<tokio::sync::Semaphore>,
-        re: &Regex,
-    ) -> (
-        Vec<GpuInfo>,
-        Vec<CpuInfo>,
-        Vec<MemoryInfo>,
-        Vec<StorageInfo>,
-        Vec<ConnectionStatus>,
-    ) {
+        process_top: usize,
+    ) -> RemoteData {
         let mut all_gpu_info = Vec::new();
         let mut all_cpu_info = Vec::new();
         let mut all_memory_info = Vec::new();
         let mut all_storage_info = Vec::new();
+        let mut all_network_info = Vec::new();
+        let mut all_chassis_info = Vec::new();
+        let mut all_process_info = Vec::new();
         let mut connection_statuses = Vec::new();
 
+        self.fetch_payloads(hosts, semaphore, |host, payload| {
+            let mut connection_status = ConnectionStatus::new(host.clone(), host.clone());
+            let text = match payload {
+                Ok(text) => text,
+                Err(error_msg) => {
+                    connection_status.mark_failure(error_msg);
+                    connection_statuses.push(connection_status);
+                    return;
+                }
+            };
+            connection_status.mark_success();
+            if text.is_empty() {
+                connection_statuses.push(connection_status);
+                return;
+            }
+
+            let parser = super::metrics_parser::MetricsParser::new();
+            let (gpu_info, cpu_info, memory_info, storage_info, network_info, chassis_info) =
+                parser.parse_metrics(&text, &host);
+            let processes = parser.parse_processes(&text, &host, process_top);
+            connection_status.processes_omitted = processes.omitted;
+
+            // Extract the instance name from device info if available
+            let instance_name = if let Some(first_gpu) = gpu_info.first() {
+                Some(first_gpu.instance.clone())
+            } else if let Some(first_cpu) = cpu_info.first() {
+                Some(first_cpu.instance.clone())
+            } else {
+                memory_info
+                    .first()
+                    .map(|first_memory| first_memory.instance.clone())
+            };
+
+            // Store the instance name as actual_hostname for display purposes
+            connection_status.actual_hostname = instance_name;
+            connection_statuses.push(connection_status);
+
+            all_gpu_info.extend(gpu_info);
+            all_cpu_info.extend(cpu_info);
+            all_memory_info.extend(memory_info);
+            all_storage_info.extend(storage_info);
+            all_network_info.extend(network_info);
+            all_chassis_info.extend(chassis_info);
+            all_process_info.extend(processes.processes);
+        })
+        .await;
+
+        (
+            all_gpu_info,
+            all_cpu_info,
+            all_memory_info,
+            all_storage_info,
+            all_network_info,
+            all_chassis_info,
+            connection_statuses,
+            all_process_info,
+        )
+    }
+
+    /// Fetch `/metrics` from every host, at most as many at once as
+    /// `semaphore` allows, and hand each payload or error to `on_payload` as
+    /// it arrives, so it can be dropped before the next one. Hosts that have
+    /// not answered within the overall timeout are left out.
+    pub async fn fetch_payloads<F>(
+        &self,
+        hosts: &[String],
+        semaphore: &Arc<tokio::sync::Semaphore>,
+        mut on_payload: F,
+    ) where
+        F: FnMut(String, Result<String, String>),
+    {
         // Parallel data collection with concurrency limiting and retries
         let total_hosts = hosts.len();
         let mut fetch_futures = FuturesUnordered::new();
@@ -377,8 +462,8 @@ impl NetworkClient {
         }
 
         // Process results as they arrive using streaming with overall timeout
-        let mut _successful_connections = 0;
-        let mut _failed_connections = 0;
+        let mut successful_connections = 0;
+        let mut failed_connections = 0;
         let mut responses_received = 0;
 
         // Set overall timeout for collecting results (4 seconds)
@@ -393,51 +478,17 @@ impl NetworkClient {
                     responses_received += 1;
 
                     match task_result {
-                        Ok(Some((host, text, error))) => {
-                            let host_identifier = host.clone();
-                            let mut connection_status =
-                                ConnectionStatus::new(host_identifier.clone(), host.clone());
-
-                            if let Some(error_msg) = error {
-                                _failed_connections += 1;
-                                connection_status.mark_failure(error_msg);
-                                connection_statuses.push(connection_status);
-                            } else {
-                                _successful_connections += 1;
-                                connection_status.mark_success();
-
-                                if text.is_empty() {
-                                    connection_statuses.push(connection_status);
-                                } else {
-                                    let parser = super::metrics_parser::MetricsParser::new();
-                                    let (gpu_info, cpu_info, memory_info, storage_info) =
-                                        parser.parse_metrics(&text, &host, re);
-
-                                    // Extract the instance name from device info if available
-                                    let instance_name = if let Some(first_gpu) = gpu_info.first() {
-                                        Some(first_gpu.instance.clone())
-                                    } else if let Some(first_cpu) = cpu_info.first() {
-                                        Some(first_cpu.instance.clone())
-                                    } else { memory_info.first().map(|first_memory| first_memory.instance.clone()) };
-
-                                    // Store the instance name as actual_hostname for display purposes
-                                    connection_status.actual_hostname = instance_name;
-                                    connection_statuses.push(connection_status);
-
-                                    all_gpu_info.extend(gpu_info);
-                                    all_cpu_info.extend(cpu_info);
-                                    all_memory_info.extend(memory_info);
-                                    all_storage_info.extend(storage_info);
-                                }
-                            }
+                        Ok(Some((host, _, Some(error_msg)))) => {
+                            failed_connections += 1;
+                            on_payload(host, Err(error_msg));
                         }
-                        Ok(None) => {
-                            _failed_connections += 1;
-                            // We don't have host information for None results, so we can't create a connection status
+                        Ok(Some((host, text, None))) => {
+                            successful_connections += 1;
+                            on_payload(host, Ok(text));
                         }
-                        Err(_) => {
-                            _failed_connections += 1;
-                            // We don't have host information for Err results, so we can't create a connection status
+                        // Without the host there is no one to report the failure for
+                        Ok(None) | Err(_) => {
+                            failed_connections += 1;
                         }
                     }
 
@@ -448,26 +499,19 @@ impl NetworkClient {
                 }
                 // Timeout reached - return partial results
                 _ = &mut timeout_future => {
-                    // Mark remaining hosts as timed out
                     break;
                 }
             }
         }
 
-        // Debug logging for connection success rate - commented out to avoid interfering with TUI
-        // if failed_connections > 0 {
-        //     eprintln!(
-        //         "Connection stats: {successful_connections} successful, {failed_connections} failed out of {total_hosts} total"
-        //     );
-        // }
-
-        (
-            all_gpu_info,
-            all_cpu_info,
-            all_memory_info,
-            all_storage_info,
-            connection_statuses,
-        )
+        if failed_connections > 0 {
+            tracing::debug!(
+                successful = successful_connections,
+                failed = failed_connections,
+                total = total_hosts,
+                "Remote fetch finished with failures"
+            );
+        }
     }
 }
 
@@ -0,0 +1,218 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! InfiniBand/RDMA port counters.
+//!
+//! On Linux every HCA port appears under
+//! `/sys/class/infiniband/<device>/ports/<port>/` with its link state, rate and
+//! a `counters/` directory. Hosts without IB hardware (and other platforms)
+//! simply report no ports.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::get_hostname;
+
+/// `port_xmit_data` and `port_rcv_data` count in units of four octets.
+const DATA_COUNTER_UNIT: u64 = 4;
+
+/// Upper bound on ports reported, to bound a malformed sysfs tree.
+const MAX_PORTS: usize = 256;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IbPortInfo {
+    /// HCA name, e.g. `mlx5_0`
+    pub device: String,
+    pub port: u32,
+    /// Logical port state name, e.g. `ACTIVE` or `DOWN`
+    pub state: String,
+    /// Signalling rate in Gb/s, if reported
+    pub rate_gbps: Option<f64>,
+    pub xmit_bytes: u64,
+    pub rcv_bytes: u64,
+    /// Ticks the port had data to send but no flow-control credits
+    pub xmit_wait: Option<u64>,
+    pub xmit_discards: Option<u64>,
+    pub rcv_errors: Option<u64>,
+    pub host_id: String,
+    pub hostname: String,
+}
+
+impl IbPortInfo {
+    pub fn is_active(&self) -> bool {
+        self.state == "ACTIVE"
+    }
+}
+
+/// Ports of all HCAs on this host. Empty when there is no IB hardware.
+pub fn get_ib_port_info() -> Vec<IbPortInfo> {
+    if cfg!(target_os = "linux") {
+        read_ib_ports(Path::new("/sys/class/infiniband"), &get_hostname())
+    } else {
+        Vec::new()
+    }
+}
+
+/// Read every `<device>/ports/<port>` below `root`. Ports without readable
+/// data counters are skipped.
+pub fn read_ib_ports(root: &Path, hostname: &str) -> Vec<IbPortInfo> {
+    let Ok(devices) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut ports = Vec::new();
+    for device in devices.flatten() {
+        let device_name = device.file_name().to_string_lossy().to_string();
+        let Ok(entries) = fs::read_dir(device.path().join("ports")) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Some(port) = entry
+                .file_name()
+                .to_str()
+                .and_then(|p| p.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            if let Some(info) = read_port(&entry.path(), &device_name, port, hostname) {
+                ports.push(info);
+            }
+            if ports.len() >= MAX_PORTS {
+                break;
+            }
+        }
+    }
+    ports.sort_by(|a, b| a.device.cmp(&b.device).then(a.port.cmp(&b.port)));
+    ports
+}
+
+fn read_port(dir: &Path, device: &str, port: u32, hostname: &str) -> Option<IbPortInfo> {
+    let counters = dir.join("counters");
+    let counter = |name: &str| read_u64(&counters.join(name));
+
+    Some(IbPortInfo {
+        device: device.to_string(),
+        port,
+        state: fs::read_to_string(dir.join("state"))
+            .map(|s| parse_port_state(&s))
+            .unwrap_or_else(|_| "UNKNOWN".to_string()),
+        rate_gbps: fs::read_to_string(dir.join("rate"))
+            .ok()
+            .and_then(|s| parse_rate_gbps(&s)),
+        xmit_bytes: counter("port_xmit_data")?.saturating_mul(DATA_COUNTER_UNIT),
+        rcv_bytes: counter("port_rcv_data")?.saturating_mul(DATA_COUNTER_UNIT),
+        xmit_wait: counter("port_xmit_wait"),
+        xmit_discards: counter("port_xmit_discards"),
+        rcv_errors: counter("port_rcv_errors"),
+        host_id: hostname.to_string(),
+        hostname: hostname.to_string(),
+    })
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// `"4: ACTIVE"` -> `"ACTIVE"`.
+fn parse_port_state(content: &str) -> String {
+    let content = content.trim();
+    content
+        .split_once(':')
+        .map_or(content, |(_, name)| name.trim())
+        .to_string()
+}
+
+/// `"100 Gb/sec (4X EDR)"` -> `100.0`.
+fn parse_rate_gbps(content: &str) -> Option<f64> {
+    content.split_whitespace().next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_port(root: &Path, device: &str, port: &str, files: &[(&str, &str)]) {
+        let dir = root.join(device).join("ports").join(port);
+        fs::create_dir_all(dir.join("counters")).unwrap();
+        for (name, content) in files {
+            fs::write(dir.join(name), content).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_parse_port_attributes() {
+        assert_eq!(parse_port_state("4: ACTIVE\n"), "ACTIVE");
+        assert_eq!(parse_port_state("1: DOWN"), "DOWN");
+        assert_eq!(parse_port_state("INIT"), "INIT");
+        assert_eq!(parse_rate_gbps("100 Gb/sec (4X EDR)\n"), Some(100.0));
+        assert_eq!(parse_rate_gbps("2.5 Gb/sec (1X SDR)"), Some(2.5));
+        assert_eq!(parse_rate_gbps(""), None);
+    }
+
+    #[test]
+    fn test_read_ib_ports() {
+        let root = tempfile::tempdir().unwrap();
+        write_port(
+            root.path(),
+            "mlx5_1",
+            "1",
+            &[
+                ("state", "4: ACTIVE\n"),
+                ("rate", "400 Gb/sec (4X NDR)\n"),
+                ("counters/port_xmit_data", "1000\n"),
+                ("counters/port_rcv_data", "250\n"),
+                ("counters/port_xmit_wait", "17\n"),
+                ("counters/port_rcv_errors", "2\n"),
+            ],
+        );
+        write_port(
+            root.path(),
+            "mlx5_0",
+            "1",
+            &[
+                ("state", "1: DOWN\n"),
+                ("counters/port_xmit_data", "0\n"),
+                ("counters/port_rcv_data", "0\n"),
+            ],
+        );
+        // No data counters: skipped
+        write_port(root.path(), "mlx5_0", "2", &[("state", "4: ACTIVE\n")]);
+
+        let ports = read_ib_ports(root.path(), "node1");
+        assert_eq!(ports.len(), 2);
+
+        assert_eq!(ports[0].device, "mlx5_0");
+        assert!(!ports[0].is_active());
+        assert_eq!(ports[0].rate_gbps, None);
+
+        let active = &ports[1];
+        assert_eq!((active.device.as_str(), active.port), ("mlx5_1", 1));
+        assert!(active.is_active());
+        assert_eq!(active.rate_gbps, Some(400.0));
+        assert_eq!(active.xmit_bytes, 4000);
+        assert_eq!(active.rcv_bytes, 1000);
+        assert_eq!(active.xmit_wait, Some(17));
+        assert_eq!(active.xmit_discards, None);
+        assert_eq!(active.rcv_errors, Some(2));
+        assert_eq!(active.hostname, "node1");
+    }
+
+    #[test]
+    fn test_missing_sysfs_root() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(read_ib_ports(&root.path().join("infiniband"), "node1").is_empty());
+    }
+}
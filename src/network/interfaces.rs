@@ -0,0 +1,246 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Network interface throughput.
+//!
+//! On Linux the byte and error counters come from `/proc/net/dev`; rates are
+//! the difference between two consecutive reads. Other platforms report no
+//! interfaces.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::get_hostname;
+
+/// Name of the loopback interface, hidden unless explicitly requested.
+const LOOPBACK_INTERFACE: &str = "lo";
+
+/// Upper bound on interfaces tracked, to bound malformed input.
+const MAX_INTERFACES: usize = 256;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NetworkInfo {
+    pub interface: String,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    /// Receive errors since the interface came up
+    pub rx_errors: u64,
+    /// Transmit errors since the interface came up
+    pub tx_errors: u64,
+    pub host_id: String,
+    pub hostname: String,
+}
+
+/// Cumulative counters of one interface as reported by the kernel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InterfaceCounters {
+    pub rx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_bytes: u64,
+    pub tx_errors: u64,
+}
+
+/// Parse the contents of `/proc/net/dev`. The two header lines and any line
+/// without the expected 16 counters are skipped.
+pub fn parse_proc_net_dev(content: &str) -> Vec<(String, InterfaceCounters)> {
+    let mut interfaces = Vec::new();
+    for line in content.lines() {
+        let Some((name, fields)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        if name.is_empty() || name.contains('|') {
+            continue;
+        }
+        let values: Vec<u64> = fields
+            .split_ascii_whitespace()
+            .map_while(|field| field.parse().ok())
+            .collect();
+        if values.len() < 16 {
+            continue;
+        }
+        interfaces.push((
+            name.to_string(),
+            InterfaceCounters {
+                rx_bytes: values[0],
+                rx_errors: values[2],
+                tx_bytes: values[8],
+                tx_errors: values[10],
+            },
+        ));
+        if interfaces.len() >= MAX_INTERFACES {
+            break;
+        }
+    }
+    interfaces
+}
+
+/// Bytes per second between two counter readings. A counter that went
+/// backwards (interface reset or wrap) yields zero rather than a spike.
+fn rate(previous: u64, current: u64, elapsed_secs: f64) -> f64 {
+    if elapsed_secs <= 0.0 || current < previous {
+        0.0
+    } else {
+        (current - previous) as f64 / elapsed_secs
+    }
+}
+
+struct Sample {
+    at: Instant,
+    counters: HashMap<String, InterfaceCounters>,
+}
+
+/// Per-interface throughput reader. Keeps the previous counters so each call
+/// reports the rate since the last one; the first call reports zero rates.
+pub struct NetworkReader {
+    include_loopback: bool,
+    hostname: String,
+    previous: Mutex<Option<Sample>>,
+}
+
+impl NetworkReader {
+    pub fn new(include_loopback: bool) -> Self {
+        Self {
+            include_loopback,
+            hostname: get_hostname(),
+            previous: Mutex::new(None),
+        }
+    }
+
+    pub fn get_network_info(&self) -> Vec<NetworkInfo> {
+        #[cfg(target_os = "linux")]
+        {
+            match std::fs::read_to_string("/proc/net/dev") {
+                Ok(content) => self.sample(&content, Instant::now()),
+                Err(_) => Vec::new(),
+            }
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Turn one `/proc/net/dev` reading taken at `now` into rates.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    fn sample(&self, content: &str, now: Instant) -> Vec<NetworkInfo> {
+        let counters: HashMap<String, InterfaceCounters> = parse_proc_net_dev(content)
+            .into_iter()
+            .filter(|(name, _)| self.include_loopback || name != LOOPBACK_INTERFACE)
+            .collect();
+
+        let Ok(mut previous) = self.previous.lock() else {
+            return Vec::new();
+        };
+        let mut interfaces: Vec<NetworkInfo> = counters
+            .iter()
+            .map(|(name, current)| {
+                let (rx_bytes_per_sec, tx_bytes_per_sec) = previous
+                    .as_ref()
+                    .and_then(|prev| {
+                        let before = prev.counters.get(name)?;
+                        let elapsed = now.saturating_duration_since(prev.at).as_secs_f64();
+                        Some((
+                            rate(before.rx_bytes, current.rx_bytes, elapsed),
+                            rate(before.tx_bytes, current.tx_bytes, elapsed),
+                        ))
+                    })
+                    .unwrap_or((0.0, 0.0));
+                NetworkInfo {
+                    interface: name.clone(),
+                    rx_bytes_per_sec,
+                    tx_bytes_per_sec,
+                    rx_errors: current.rx_errors,
+                    tx_errors: current.tx_errors,
+                    host_id: self.hostname.clone(),
+                    hostname: self.hostname.clone(),
+                }
+            })
+            .collect();
+        interfaces.sort_by(|a, b| a.interface.cmp(&b.interface));
+
+        *previous = Some(Sample { at: now, counters });
+        interfaces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn proc_net_dev(eth0_rx: u64, eth0_tx: u64) -> String {
+        format!(
+            "Inter-|   Receive                                                |  Transmit\n \
+             face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n    \
+             lo: 5000 50 0 0 0 0 0 0 5000 50 0 0 0 0 0 0\n  \
+             eth0: {eth0_rx} 900 3 0 0 0 0 12 {eth0_tx} 800 1 0 0 0 0 0\n"
+        )
+    }
+
+    #[test]
+    fn test_parse_proc_net_dev() {
+        let interfaces = parse_proc_net_dev(&proc_net_dev(123_456, 654_321));
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(interfaces[0].0, "lo");
+        assert_eq!(
+            interfaces[1],
+            (
+                "eth0".to_string(),
+                InterfaceCounters {
+                    rx_bytes: 123_456,
+                    rx_errors: 3,
+                    tx_bytes: 654_321,
+                    tx_errors: 1,
+                }
+            )
+        );
+
+        assert!(parse_proc_net_dev("").is_empty());
+        assert!(parse_proc_net_dev("eth0: 1 2 3\nbogus line\n").is_empty());
+    }
+
+    #[test]
+    fn test_rates_between_samples() {
+        let reader = NetworkReader::new(false);
+        let start = Instant::now();
+
+        let first = reader.sample(&proc_net_dev(1_000, 2_000), start);
+        assert_eq!(first.len(), 1, "loopback is excluded by default");
+        assert_eq!(first[0].interface, "eth0");
+        assert_eq!(first[0].rx_bytes_per_sec, 0.0);
+        assert_eq!(first[0].rx_errors, 3);
+
+        let second = reader.sample(&proc_net_dev(5_000, 4_000), start + Duration::from_secs(2));
+        assert_eq!(second[0].rx_bytes_per_sec, 2_000.0);
+        assert_eq!(second[0].tx_bytes_per_sec, 1_000.0);
+
+        // Counter reset after the interface bounced
+        let third = reader.sample(&proc_net_dev(100, 4_500), start + Duration::from_secs(3));
+        assert_eq!(third[0].rx_bytes_per_sec, 0.0);
+        assert_eq!(third[0].tx_bytes_per_sec, 500.0);
+    }
+
+    #[test]
+    fn test_include_loopback() {
+        let reader = NetworkReader::new(true);
+        let interfaces = reader.sample(&proc_net_dev(0, 0), Instant::now());
+        let names: Vec<&str> = interfaces.iter().map(|i| i.interface.as_str()).collect();
+        assert_eq!(names, vec!["eth0", "lo"]);
+    }
+}
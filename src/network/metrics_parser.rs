@@ -14,13 +14,68 @@
 
 use std::collections::HashMap;
 
-use crate::parsing::common::sanitize_label_value;
+use crate::parsing::prometheus::{parse_line, LabelMap};
 use chrono::Local;
-use regex::Regex;
 
-use crate::device::{AppleSiliconCpuInfo, CpuInfo, CpuPlatformType, GpuInfo, MemoryInfo};
+use crate::device::gpu_fan::{FAN_SPEED_KEY_PREFIX, FAN_TARGET_KEY_PREFIX, MAX_FANS};
+use crate::device::gpu_memory::{BAR1_TOTAL_KEY, BAR1_USED_KEY, RESERVED_MEMORY_KEY};
+use crate::device::gpu_temperature::{HOTSPOT_TEMPERATURE_KEY, MEMORY_TEMPERATURE_KEY};
+use crate::device::host_info::LOAD_WINDOWS;
+use crate::device::memory_pressure::{MemoryPsi, PressureLevel, PsiLine};
+use crate::device::power_headroom::{POWER_LIMIT_CURRENT_KEY, POWER_LIMIT_ENFORCED_KEY};
+use crate::device::process_list::retain_top_processes;
+use crate::device::readers::nvidia_jetson::{
+    DLA_UTILIZATION_KEY_PREFIX, EMC_FREQUENCY_KEY, RAIL_POWER_KEY_PREFIX,
+};
+use crate::device::{
+    AppleSiliconCpuInfo, ChassisInfo, CpuInfo, CpuPlatformType, CpuSocketInfo, FanInfo, GpuInfo,
+    K8sPod, MemoryInfo, ProcessInfo,
+};
+use crate::network::NetworkInfo;
+use crate::storage::health::DiskHealth;
 use crate::storage::info::StorageInfo;
 
+/// Limit the number of devices per type to prevent memory exhaustion.
+const MAX_DEVICES_PER_TYPE: usize = 256;
+
+/// Limit per-core entries of one CPU; `core_id` comes straight from the payload.
+const MAX_CORES_PER_CPU: usize = 4096;
+
+/// Limit the processes read from one scrape before the top-N cut.
+const MAX_PROCESSES_PER_HOST: usize = 65_536;
+
+/// Devices reconstructed from a single host's scrape, keyed the same way
+/// samples for the same device are merged.
+#[derive(Default)]
+pub struct HostMetrics {
+    pub gpus: HashMap<String, GpuInfo>,
+    pub cpus: HashMap<String, CpuInfo>,
+    pub memory: HashMap<String, MemoryInfo>,
+    pub storage: HashMap<String, StorageInfo>,
+    pub network: HashMap<String, NetworkInfo>,
+    pub chassis: HashMap<String, ChassisInfo>,
+}
+
+/// Devices parsed from one scrape: GPUs, CPUs, memory, storage, network
+/// interfaces and the chassis.
+pub type ParsedMetrics = (
+    Vec<GpuInfo>,
+    Vec<CpuInfo>,
+    Vec<MemoryInfo>,
+    Vec<StorageInfo>,
+    Vec<NetworkInfo>,
+    Vec<ChassisInfo>,
+);
+
+/// Processes parsed from one scrape
+#[derive(Default)]
+pub struct ParsedProcesses {
+    /// The processes using the most GPU memory, at most the requested number
+    pub processes: Vec<ProcessInfo>,
+    /// Processes in the scrape left out by the limit
+    pub omitted: usize,
+}
+
 pub struct MetricsParser;
 
 impl MetricsParser {
@@ -28,579 +83,1155 @@ impl MetricsParser {
         Self
     }
 
-    pub fn parse_metrics(
-        &self,
-        text: &str,
-        host: &str,
-        re: &Regex,
-    ) -> (
-        Vec<GpuInfo>,
-        Vec<CpuInfo>,
-        Vec<MemoryInfo>,
-        Vec<StorageInfo>,
-    ) {
-        // Limit the maximum size of HashMaps to prevent memory exhaustion
-        const MAX_DEVICES_PER_TYPE: usize = 256;
+    pub fn parse_metrics(&self, text: &str, host: &str) -> ParsedMetrics {
         const MAX_TEXT_SIZE: usize = 10_485_760; // 10MB max input
 
         // Validate input size
         if text.len() > MAX_TEXT_SIZE {
-            eprintln!(
-                "Warning: Metrics text too large ({}), truncating to 10MB",
+            tracing::warn!(
+                "Metrics text too large ({}), truncating to 10MB",
                 text.len()
             );
             let truncated = &text[..MAX_TEXT_SIZE];
-            return self.parse_metrics(truncated, host, re);
+            return self.parse_metrics(truncated, host);
         }
 
-        let mut gpu_info_map: HashMap<String, GpuInfo> = HashMap::with_capacity(16);
-        let mut cpu_info_map: HashMap<String, CpuInfo> = HashMap::with_capacity(8);
-        let mut memory_info_map: HashMap<String, MemoryInfo> = HashMap::with_capacity(8);
-        let mut storage_info_map: HashMap<String, StorageInfo> = HashMap::with_capacity(32);
+        let mut metrics = HostMetrics {
+            gpus: HashMap::with_capacity(16),
+            cpus: HashMap::with_capacity(8),
+            memory: HashMap::with_capacity(8),
+            storage: HashMap::with_capacity(32),
+            network: HashMap::with_capacity(8),
+            chassis: HashMap::with_capacity(1),
+        };
+        // Reused for every line; holds slices borrowed from `text`
+        let mut labels = LabelMap::with_capacity(16);
         let mut host_instance_name: Option<String> = None;
 
         for line in text.lines() {
-            if let Some((metric_name, labels_str, value)) = parse_prometheus!(line, re) {
-                let labels = self.parse_labels(&labels_str);
+            let Some(sample) = parse_line(line, &mut labels) else {
+                continue;
+            };
+            let Some(metric_name) = sample.name.strip_prefix("all_smi_") else {
+                continue;
+            };
 
-                // Extract instance name from the first metric that has it
-                if host_instance_name.is_none() {
-                    if let Some(instance) = labels.get("instance") {
-                        host_instance_name = Some(instance.clone());
-                    }
-                }
-
-                // Process different metric types with size limits
-                if metric_name.starts_with("gpu_")
-                    || metric_name.starts_with("npu_")
-                    || metric_name == "ane_utilization"
-                {
-                    if gpu_info_map.len() < MAX_DEVICES_PER_TYPE {
-                        self.process_gpu_metrics(
-                            &mut gpu_info_map,
-                            &metric_name,
-                            &labels,
-                            value,
-                            host,
-                        );
-                    }
-                } else if metric_name.starts_with("cpu_") {
-                    if cpu_info_map.len() < MAX_DEVICES_PER_TYPE {
-                        self.process_cpu_metrics(
-                            &mut cpu_info_map,
-                            &metric_name,
-                            &labels,
-                            value,
-                            host,
-                        );
-                    }
-                } else if metric_name.starts_with("memory_") {
-                    if memory_info_map.len() < MAX_DEVICES_PER_TYPE {
-                        self.process_memory_metrics(
-                            &mut memory_info_map,
-                            &metric_name,
-                            &labels,
-                            value,
-                            host,
-                        );
-                    }
-                } else if (metric_name.starts_with("storage_") || metric_name.starts_with("disk_"))
-                    && storage_info_map.len() < MAX_DEVICES_PER_TYPE
-                {
-                    self.process_storage_metrics(
-                        &mut storage_info_map,
-                        &metric_name,
-                        &labels,
-                        value,
-                        host,
-                    );
+            // Extract instance name from the first metric that has it
+            if host_instance_name.is_none() {
+                if let Some(instance) = labels.get("instance") {
+                    host_instance_name = Some(instance.to_string());
                 }
             }
+
+            apply_metric(&mut metrics, metric_name, &labels, sample.value, host);
         }
 
         // Store instance name in detail field if available, but keep host as the key
         if let Some(instance_name) = host_instance_name {
-            self.update_instance_names(
-                &mut gpu_info_map,
-                &mut cpu_info_map,
-                &mut memory_info_map,
-                &mut storage_info_map,
-                &instance_name,
-            );
+            self.update_instance_names(&mut metrics, &instance_name);
         }
 
         (
-            gpu_info_map.into_values().collect(),
-            cpu_info_map.into_values().collect(),
-            memory_info_map.into_values().collect(),
-            storage_info_map.into_values().collect(),
+            metrics.gpus.into_values().collect(),
+            metrics.cpus.into_values().collect(),
+            metrics.memory.into_values().collect(),
+            metrics.storage.into_values().collect(),
+            metrics.network.into_values().collect(),
+            metrics.chassis.into_values().collect(),
         )
     }
 
-    fn parse_labels(&self, labels_str: &str) -> HashMap<String, String> {
-        const MAX_LABELS: usize = 100; // Prevent unbounded growth
-        const MAX_LABEL_LENGTH: usize = 1024; // Prevent large string allocations
-        const MAX_INPUT_LENGTH: usize = 32768; // Maximum label string length to process
+    /// Rebuild the processes of a node running `api --processes` from its
+    /// `all_smi_process_memory_used_bytes` series, keeping the `limit` using
+    /// the most GPU memory. Only what the series carry is known: the
+    /// process name stands in for the command line and CPU figures are 0.
+    pub fn parse_processes(&self, text: &str, host: &str, limit: usize) -> ParsedProcesses {
+        let mut processes: HashMap<(u32, u64, String), ProcessInfo> = HashMap::new();
+        let mut labels = LabelMap::with_capacity(16);
 
-        // Limit input size to prevent DoS
-        if labels_str.len() > MAX_INPUT_LENGTH {
-            eprintln!("Warning: Label string too long, truncating to {MAX_INPUT_LENGTH} bytes");
-            return HashMap::new();
+        for line in text.lines() {
+            // Skip the parse for the other series
+            if !line.trim_start().starts_with("all_smi_process_") {
+                continue;
+            }
+            let Some(sample) = parse_line(line, &mut labels) else {
+                continue;
+            };
+            if sample.name != "all_smi_process_memory_used_bytes" {
+                continue;
+            }
+            let Some(process) = process_from_labels(&labels, sample.value, host) else {
+                continue;
+            };
+            let key = (process.pid, process.start_time, process.device_uuid.clone());
+            if processes.len() < MAX_PROCESSES_PER_HOST || processes.contains_key(&key) {
+                processes.insert(key, process);
+            }
         }
 
-        let mut labels: HashMap<String, String> = HashMap::with_capacity(16);
-        let mut label_count = 0;
+        let mut processes: Vec<ProcessInfo> = processes.into_values().collect();
+        let found = processes.len();
+        retain_top_processes(&mut processes, limit);
+        ParsedProcesses {
+            omitted: found - processes.len(),
+            processes,
+        }
+    }
 
-        // Optimized parsing without intermediate allocations
-        for label in labels_str.split(',') {
-            if label_count >= MAX_LABELS {
-                break;
-            }
+    fn update_instance_names(&self, metrics: &mut HostMetrics, instance_name: &str) {
+        // Store instance name in detail field but keep hostname as the host address.
+        // CPU, memory and storage info have no detail map to carry it.
+        for gpu_info in metrics.gpus.values_mut() {
+            gpu_info
+                .detail
+                .insert("instance_name".to_string(), instance_name.to_string());
+        }
+    }
+}
 
-            // Find the '=' separator without allocating a vector
-            if let Some(eq_pos) = label.find('=') {
-                let key = &label[..eq_pos];
-                let value = &label[eq_pos + 1..];
+impl Default for MetricsParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-                // Sanitize and validate in one pass
-                let key_clean = sanitize_label_value(key);
-                let value_clean = sanitize_label_value(value);
+/// A process from the labels of its memory series; `None` without a PID
+fn process_from_labels(labels: &LabelMap<'_>, used_memory: f64, host: &str) -> Option<ProcessInfo> {
+    let pid = labels.get("pid")?.parse().ok()?;
+    let name = crate::get_label_or_default!(labels, "name");
+    let k8s_pod = labels.get("pod").map(|pod| K8sPod {
+        namespace: crate::get_label_or_default!(labels, "namespace"),
+        name: pod.to_string(),
+        container: crate::get_label_or_default!(labels, "container"),
+    });
+    Some(ProcessInfo {
+        device_id: labels
+            .get("device_id")
+            .and_then(|id| id.parse().ok())
+            .unwrap_or(0),
+        device_uuid: crate::get_label_or_default!(labels, "device_uuid"),
+        pid,
+        process_name: name.clone(),
+        used_memory: used_memory.max(0.0) as u64,
+        used_memory_estimated: false,
+        cpu_percent: 0.0,
+        memory_percent: 0.0,
+        memory_rss: 0,
+        memory_vms: 0,
+        user: String::new(),
+        state: String::new(),
+        start_time: labels
+            .get("start_time")
+            .and_then(|time| time.parse().ok())
+            .unwrap_or(0),
+        cpu_time: 0,
+        command: name,
+        ppid: 0,
+        threads: 0,
+        uses_gpu: true,
+        priority: 0,
+        nice_value: 0,
+        gpu_utilization: 0.0,
+        k8s_pod,
+        host_id: host.to_string(),
+    })
+}
 
-                // Check lengths and insert
-                if key_clean.len() <= MAX_LABEL_LENGTH && value_clean.len() <= MAX_LABEL_LENGTH {
-                    labels.insert(key_clean, value_clean);
-                    label_count += 1;
-                }
-            }
+/// Map one sample (metric name without the `all_smi_` prefix) onto the
+/// device struct it describes, creating the device on first sight.
+pub fn apply_metric(
+    metrics: &mut HostMetrics,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    // Process different metric types with size limits
+    if metric_name.starts_with("gpu_")
+        || metric_name.starts_with("npu_")
+        || metric_name.starts_with("jetson_")
+        || metric_name == "ane_utilization"
+    {
+        if metrics.gpus.len() < MAX_DEVICES_PER_TYPE {
+            process_gpu_metrics(&mut metrics.gpus, metric_name, labels, value, host);
         }
-        labels
-    }
-
-    fn process_gpu_metrics(
-        &self,
-        gpu_info_map: &mut HashMap<String, GpuInfo>,
-        metric_name: &str,
-        labels: &HashMap<String, String>,
-        value: f64,
-        host: &str,
-    ) {
-        let gpu_name = crate::get_label_or_default!(labels, "gpu");
-        let gpu_uuid = crate::get_label_or_default!(labels, "uuid");
-        let gpu_index = crate::get_label_or_default!(labels, "index");
-
-        if gpu_name.is_empty() || gpu_uuid.is_empty() {
-            return;
-        }
-
-        let gpu_info = gpu_info_map.entry(gpu_uuid.clone()).or_insert_with(|| {
-            let mut detail = HashMap::new();
-            detail.insert("index".to_string(), gpu_index.clone());
-            GpuInfo {
-                uuid: gpu_uuid.clone(),
-                time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
-                name: gpu_name,
-                device_type: "GPU".to_string(), // Default to GPU, can be overridden by gpu_info metric
-                host_id: host.to_string(),      // Host identifier (e.g., "10.82.128.41:9090")
-                hostname: crate::get_label_or_default!(labels, "instance", host), // DNS hostname from instance label
-                instance: crate::get_label_or_default!(labels, "instance", host),
-                utilization: 0.0,
-                ane_utilization: 0.0,
-                dla_utilization: None,
-                tensorcore_utilization: None,
-                temperature: 0,
-                used_memory: 0,
-                total_memory: 0,
-                frequency: 0,
-                power_consumption: 0.0,
-                gpu_core_count: None,
-                detail,
-            }
-        });
+    } else if metric_name.starts_with("cpu_") {
+        if metrics.cpus.len() < MAX_DEVICES_PER_TYPE {
+            process_cpu_metrics(&mut metrics.cpus, metric_name, labels, value, host);
+        }
+    } else if metric_name.starts_with("memory_") || metric_name.starts_with("swap_") {
+        if metrics.memory.len() < MAX_DEVICES_PER_TYPE {
+            process_memory_metrics(&mut metrics.memory, metric_name, labels, value, host);
+        }
+    } else if (metric_name.starts_with("storage_") || metric_name.starts_with("disk_"))
+        && metrics.storage.len() < MAX_DEVICES_PER_TYPE
+    {
+        process_storage_metrics(&mut metrics.storage, metric_name, labels, value, host);
+    } else if metric_name.starts_with("net_") && metrics.network.len() < MAX_DEVICES_PER_TYPE {
+        process_network_metrics(&mut metrics.network, metric_name, labels, value, host);
+    } else if metric_name.starts_with("chassis_")
+        || matches!(metric_name, "load_average" | "uptime_seconds" | "node_info")
+        // A node in service gets no chassis entry just for saying so
+        || (metric_name == "node_draining" && value > 0.0)
+    {
+        process_chassis_metrics(&mut metrics.chassis, metric_name, labels, value, host);
+    }
+}
 
-        crate::update_metric_field!(metric_name, value, gpu_info, {
-            "gpu_utilization" => utilization as f64,
-            "gpu_memory_used_bytes" => used_memory as u64,
-            "gpu_memory_total_bytes" => total_memory as u64,
-            "gpu_temperature_celsius" => temperature as u32,
-            "gpu_power_consumption_watts" => power_consumption as f64,
-            "gpu_frequency_mhz" => frequency as u32,
-            "ane_utilization" => ane_utilization as f64
-        });
+fn process_gpu_metrics(
+    gpu_info_map: &mut HashMap<String, GpuInfo>,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    let gpu_name = crate::get_label_or_default!(labels, "gpu");
+    let gpu_uuid = crate::get_label_or_default!(labels, "uuid");
+    let gpu_index = crate::get_label_or_default!(labels, "index");
+
+    if gpu_name.is_empty() || gpu_uuid.is_empty() {
+        return;
+    }
 
-        match metric_name {
-            "gpu_power_limit_max_watts" => {
-                gpu_info
-                    .detail
-                    .insert("power_limit_max".to_string(), value.to_string());
+    let gpu_info = gpu_info_map.entry(gpu_uuid.clone()).or_insert_with(|| {
+        let mut detail = HashMap::new();
+        detail.insert("index".to_string(), gpu_index.clone());
+        GpuInfo {
+            uuid: gpu_uuid.clone(),
+            time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            name: gpu_name,
+            device_type: "GPU".to_string(), // Default to GPU, can be overridden by gpu_info metric
+            host_id: host.to_string(),      // Host identifier (e.g., "10.82.128.41:9090")
+            hostname: crate::get_label_or_default!(labels, "instance", host), // DNS hostname from instance label
+            instance: crate::get_label_or_default!(labels, "instance", host),
+            detail,
+            ..Default::default()
+        }
+    });
+
+    crate::update_metric_field!(metric_name, value, gpu_info, {
+        "gpu_utilization" => utilization as f64,
+        "gpu_memory_used_bytes" => used_memory as u64,
+        "gpu_memory_total_bytes" => total_memory as u64,
+        "gpu_temperature_celsius" => temperature as u32,
+        "gpu_power_consumption_watts" => power_consumption as f64,
+        "gpu_frequency_mhz" => frequency as u32,
+        "ane_utilization" => ane_utilization as f64
+    });
+
+    match metric_name {
+        "gpu_power_limit_max_watts" => {
+            gpu_info
+                .detail
+                .insert("power_limit_max".to_string(), value.to_string());
+        }
+        "gpu_power_limit_current_watts" => {
+            gpu_info
+                .detail
+                .insert(POWER_LIMIT_CURRENT_KEY.to_string(), value.to_string());
+        }
+        "gpu_power_limit_enforced_watts" => {
+            gpu_info
+                .detail
+                .insert(POWER_LIMIT_ENFORCED_KEY.to_string(), value.to_string());
+        }
+        "gpu_encoder_utilization" => gpu_info.encoder_utilization = Some(value),
+        "gpu_decoder_utilization" => gpu_info.decoder_utilization = Some(value),
+        "gpu_info" => {
+            // Extract device type
+            if let Some(device_type) = labels.get("type") {
+                gpu_info.device_type = device_type.to_string();
             }
-            "gpu_info" => {
-                // Extract device type
-                if let Some(device_type) = labels.get("type") {
-                    gpu_info.device_type = device_type.clone();
-                }
 
-                // Extract all GPU metadata labels in batch
-                crate::extract_labels_batch!(
-                    labels,
-                    gpu_info.detail,
-                    [
-                        "cuda_version",
-                        "driver_version",
-                        "architecture",
-                        "compute_capability",
-                        "firmware",
-                        "serial_number",
-                        "pci_address",
-                        "pci_device"
-                    ]
-                );
-            }
-            "npu_firmware_info" => {
-                // Handle NPU-specific firmware info metric
-                crate::extract_label_to_detail!(labels, "firmware", gpu_info.detail);
-            }
-            _ => {}
-        }
-    }
-
-    fn process_cpu_metrics(
-        &self,
-        cpu_info_map: &mut HashMap<String, CpuInfo>,
-        metric_name: &str,
-        labels: &HashMap<String, String>,
-        value: f64,
-        host: &str,
-    ) {
-        let cpu_model = crate::get_label_or_default!(labels, "cpu_model");
-        // Keep the full host address including port
-        let cpu_index = crate::get_label_or_default!(labels, "index", "0");
-
-        let cpu_key = format!("{host}:{cpu_index}");
-
-        let cpu_info = cpu_info_map.entry(cpu_key).or_insert_with(|| {
-            let platform_type = if cpu_model.contains("Apple") {
-                CpuPlatformType::AppleSilicon
-            } else if cpu_model.contains("Intel") {
-                CpuPlatformType::Intel
-            } else if cpu_model.contains("AMD") {
-                CpuPlatformType::Amd
+            // Extract all GPU metadata labels in batch
+            crate::extract_labels_batch!(
+                labels,
+                gpu_info.detail,
+                [
+                    "cuda_version",
+                    "driver_version",
+                    "architecture",
+                    "compute_capability",
+                    "firmware",
+                    "firmware_version",
+                    "vbios_version",
+                    "serial_number",
+                    "pci_address",
+                    "pci_device",
+                    "cpu_affinity",
+                    "pcie_path",
+                    "gpu_peer_topology"
+                ]
+            );
+        }
+        "gpu_fan_speed_rpm" => {
+            gpu_info
+                .detail
+                .insert("fan_speed_rpm".to_string(), (value as u32).to_string());
+        }
+        "gpu_fan_speed_percent" | "gpu_fan_target_percent" => {
+            let prefix = if metric_name == "gpu_fan_speed_percent" {
+                FAN_SPEED_KEY_PREFIX
             } else {
-                CpuPlatformType::Other("Unknown".to_string())
+                FAN_TARGET_KEY_PREFIX
             };
-
-            CpuInfo {
-                host_id: host.to_string(), // Host identifier (e.g., "10.82.128.41:9090")
-                hostname: crate::get_label_or_default!(labels, "instance", host), // DNS hostname from instance label
-                instance: crate::get_label_or_default!(labels, "instance", host),
-                cpu_model: cpu_model.clone(),
-                architecture: "".to_string(),
-                platform_type,
-                socket_count: 1,
-                total_cores: 0,
-                total_threads: 0,
-                base_frequency_mhz: 0,
-                max_frequency_mhz: 0,
-                cache_size_mb: 0,
-                utilization: 0.0,
-                temperature: None,
-                power_consumption: None,
-                per_socket_info: Vec::new(),
-                apple_silicon_info: None,
-                per_core_utilization: Vec::new(),
-                time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            // The fan index becomes part of a key; keep it a small number
+            if let Some(fan) = labels
+                .get("fan")
+                .and_then(|fan| fan.parse::<u32>().ok())
+                .filter(|&fan| fan < MAX_FANS)
+            {
+                gpu_info
+                    .detail
+                    .insert(format!("{prefix}{fan}"), (value as u32).to_string());
             }
-        });
-
-        crate::update_metric_field!(metric_name, value, cpu_info, {
-            "cpu_utilization" => utilization as f64,
-            "cpu_socket_count" => socket_count as u32,
-            "cpu_core_count" => total_cores as u32,
-            "cpu_thread_count" => total_threads as u32
-        });
-
-        match metric_name {
-            "cpu_model" => {
-                // Handle all_smi_cpu_model info metric
-                if let Some(model) = labels.get("model") {
-                    cpu_info.cpu_model = model.clone();
-
-                    // Update platform type based on new model info
-                    cpu_info.platform_type = if model.contains("Apple") {
-                        CpuPlatformType::AppleSilicon
-                    } else if model.contains("Intel") {
-                        CpuPlatformType::Intel
-                    } else if model.contains("AMD")
-                        || model.contains("EPYC")
-                        || model.contains("Ryzen")
-                    {
-                        CpuPlatformType::Amd
-                    } else {
-                        CpuPlatformType::Other("Unknown".to_string())
-                    };
-                }
+        }
+        "gpu_bar1_memory_used_bytes" => {
+            gpu_info
+                .detail
+                .insert(BAR1_USED_KEY.to_string(), (value as u64).to_string());
+        }
+        "gpu_bar1_memory_total_bytes" => {
+            gpu_info
+                .detail
+                .insert(BAR1_TOTAL_KEY.to_string(), (value as u64).to_string());
+        }
+        "gpu_memory_reserved_bytes" => {
+            gpu_info
+                .detail
+                .insert(RESERVED_MEMORY_KEY.to_string(), (value as u64).to_string());
+        }
+        "gpu_memory_temperature_celsius" => {
+            gpu_info.detail.insert(
+                MEMORY_TEMPERATURE_KEY.to_string(),
+                (value as u32).to_string(),
+            );
+        }
+        "gpu_hotspot_temperature_celsius" => {
+            gpu_info.detail.insert(
+                HOTSPOT_TEMPERATURE_KEY.to_string(),
+                (value as u32).to_string(),
+            );
+        }
+        "gpu_clock_sm_mhz"
+        | "gpu_clock_graphics_mhz"
+        | "gpu_clock_memory_mhz"
+        | "gpu_clock_video_mhz" => {
+            let domain = &metric_name["gpu_clock_".len()..metric_name.len() - "_mhz".len()];
+            gpu_info.detail.insert(
+                format!("clock_{domain}_current"),
+                (value as u32).to_string(),
+            );
+        }
+        "gpu_throttle_status_info" => {
+            if let Some(status) = labels.get("status") {
+                gpu_info
+                    .detail
+                    .insert("throttle_status".to_string(), status.to_string());
             }
-            "cpu_frequency_mhz" => {
-                cpu_info.base_frequency_mhz = value as u32;
-                cpu_info.max_frequency_mhz = value as u32;
+            if let Some(reasons) = labels.get("reasons") {
+                gpu_info
+                    .detail
+                    .insert("throttle_reasons".to_string(), reasons.to_string());
             }
-            "cpu_temperature_celsius" => cpu_info.temperature = Some(value as u32),
-            "cpu_power_consumption_watts" => cpu_info.power_consumption = Some(value),
-            "cpu_p_core_count" => {
-                self.ensure_apple_silicon_info(cpu_info);
-                crate::update_optional_field!(
-                    cpu_info,
-                    apple_silicon_info,
-                    p_core_count,
-                    value as u32
+        }
+        "jetson_emc_frequency_hz" => {
+            gpu_info
+                .detail
+                .insert(EMC_FREQUENCY_KEY.to_string(), (value as u64).to_string());
+        }
+        "jetson_rail_power_watts" => {
+            if let Some(rail) = labels.get("rail") {
+                gpu_info.detail.insert(
+                    format!("{RAIL_POWER_KEY_PREFIX}{rail}"),
+                    ((value * 1000.0).round() as u64).to_string(),
                 );
             }
-            "cpu_e_core_count" => {
-                self.ensure_apple_silicon_info(cpu_info);
-                crate::update_optional_field!(
-                    cpu_info,
-                    apple_silicon_info,
-                    e_core_count,
-                    value as u32
+        }
+        "jetson_dla_utilization" => {
+            if let Some(dla) = labels.get("dla") {
+                gpu_info.detail.insert(
+                    format!("{DLA_UTILIZATION_KEY_PREFIX}{dla}"),
+                    value.to_string(),
                 );
             }
-            "cpu_p_core_utilization" => {
-                self.ensure_apple_silicon_info(cpu_info);
-                crate::update_optional_field!(
-                    cpu_info,
-                    apple_silicon_info,
-                    p_core_utilization,
-                    value
-                );
+        }
+        "gpu_numa_node" => {
+            gpu_info
+                .detail
+                .insert("numa_node".to_string(), (value as u32).to_string());
+        }
+        "gpu_stale" => {
+            gpu_info.detail.insert(
+                crate::app_state::STALE_KEY.to_string(),
+                (value != 0.0).to_string(),
+            );
+        }
+        "gpu_up" if value == 0.0 => {
+            gpu_info.detail.insert(
+                crate::device::gpu_presence::GPU_LOST_KEY.to_string(),
+                "true".to_string(),
+            );
+        }
+        "gpu_allocated" => {
+            gpu_info.detail.insert(
+                crate::device::k8s_allocation::K8S_ALLOCATED_KEY.to_string(),
+                (value != 0.0).to_string(),
+            );
+        }
+        "npu_firmware_info" => {
+            // Handle NPU-specific firmware info metric
+            crate::extract_label_to_detail!(labels, "firmware", gpu_info.detail);
+        }
+        _ => {}
+    }
+}
+
+fn process_cpu_metrics(
+    cpu_info_map: &mut HashMap<String, CpuInfo>,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    let cpu_model = crate::get_label_or_default!(labels, "cpu_model");
+    // Keep the full host address including port. Per-socket samples name the
+    // CPU with `cpu_index` because `index` would be ambiguous there.
+    let cpu_index = labels
+        .get("index")
+        .or_else(|| labels.get("cpu_index"))
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "0".to_string());
+
+    let cpu_key = format!("{host}:{cpu_index}");
+
+    let cpu_info = cpu_info_map.entry(cpu_key).or_insert_with(|| {
+        let platform_type = if cpu_model.contains("Apple") {
+            CpuPlatformType::AppleSilicon
+        } else if cpu_model.contains("Intel") {
+            CpuPlatformType::Intel
+        } else if cpu_model.contains("AMD") {
+            CpuPlatformType::Amd
+        } else {
+            CpuPlatformType::Other("Unknown".to_string())
+        };
+
+        CpuInfo {
+            host_id: host.to_string(), // Host identifier (e.g., "10.82.128.41:9090")
+            hostname: crate::get_label_or_default!(labels, "instance", host), // DNS hostname from instance label
+            instance: crate::get_label_or_default!(labels, "instance", host),
+            cpu_model: cpu_model.clone(),
+            architecture: "".to_string(),
+            platform_type,
+            socket_count: 1,
+            total_cores: 0,
+            total_threads: 0,
+            base_frequency_mhz: 0,
+            max_frequency_mhz: 0,
+            cache_size_mb: 0,
+            utilization: 0.0,
+            temperature: None,
+            power_consumption: None,
+            per_socket_info: Vec::new(),
+            apple_silicon_info: None,
+            per_core_utilization: Vec::new(),
+            time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            stale: false,
+        }
+    });
+
+    crate::update_metric_field!(metric_name, value, cpu_info, {
+        "cpu_utilization" => utilization as f64,
+        "cpu_socket_count" => socket_count as u32,
+        "cpu_core_count" => total_cores as u32,
+        "cpu_thread_count" => total_threads as u32
+    });
+
+    match metric_name {
+        "cpu_model" => {
+            // Handle all_smi_cpu_model info metric
+            if let Some(model) = labels.get("model") {
+                cpu_info.cpu_model = model.to_string();
+
+                // Update platform type based on new model info
+                cpu_info.platform_type = if model.contains("Apple") {
+                    CpuPlatformType::AppleSilicon
+                } else if model.contains("Intel") {
+                    CpuPlatformType::Intel
+                } else if model.contains("AMD") || model.contains("EPYC") || model.contains("Ryzen")
+                {
+                    CpuPlatformType::Amd
+                } else {
+                    CpuPlatformType::Other("Unknown".to_string())
+                };
             }
-            "cpu_e_core_utilization" => {
-                self.ensure_apple_silicon_info(cpu_info);
-                crate::update_optional_field!(
-                    cpu_info,
-                    apple_silicon_info,
-                    e_core_utilization,
-                    value
-                );
+        }
+        "cpu_frequency_mhz" => {
+            cpu_info.base_frequency_mhz = value as u32;
+            cpu_info.max_frequency_mhz = value as u32;
+        }
+        "cpu_temperature_celsius" => cpu_info.temperature = Some(value as u32),
+        "cpu_power_consumption_watts" => cpu_info.power_consumption = Some(value),
+        "cpu_p_core_count" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(cpu_info, apple_silicon_info, p_core_count, value as u32);
+        }
+        "cpu_e_core_count" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(cpu_info, apple_silicon_info, e_core_count, value as u32);
+        }
+        "cpu_p_core_utilization" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(cpu_info, apple_silicon_info, p_core_utilization, value);
+        }
+        "cpu_e_core_utilization" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(cpu_info, apple_silicon_info, e_core_utilization, value);
+        }
+        "cpu_gpu_core_count" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(
+                cpu_info,
+                apple_silicon_info,
+                gpu_core_count,
+                value as u32
+            );
+        }
+        "cpu_ane_ops_per_second" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(
+                cpu_info,
+                apple_silicon_info,
+                ane_ops_per_second,
+                Some(value)
+            );
+        }
+        "cpu_p_cluster_frequency_mhz" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(
+                cpu_info,
+                apple_silicon_info,
+                p_cluster_frequency_mhz,
+                Some(value as u32)
+            );
+        }
+        "cpu_e_cluster_frequency_mhz" => {
+            ensure_apple_silicon_info(cpu_info);
+            crate::update_optional_field!(
+                cpu_info,
+                apple_silicon_info,
+                e_cluster_frequency_mhz,
+                Some(value as u32)
+            );
+        }
+        "cpu_socket_utilization"
+        | "cpu_socket_frequency_mhz"
+        | "cpu_socket_temperature_celsius" => {
+            let Some(socket_id) = labels.get("socket_id").and_then(|s| s.parse::<u32>().ok())
+            else {
+                return;
+            };
+            let sockets = &mut cpu_info.per_socket_info;
+            let position = match sockets.iter().position(|s| s.socket_id == socket_id) {
+                Some(position) => position,
+                None if sockets.len() < MAX_DEVICES_PER_TYPE => {
+                    sockets.push(CpuSocketInfo {
+                        socket_id,
+                        utilization: 0.0,
+                        cores: 0,
+                        threads: 0,
+                        temperature: None,
+                        frequency_mhz: 0,
+                    });
+                    sockets.len() - 1
+                }
+                None => return,
+            };
+            let socket = &mut sockets[position];
+            match metric_name {
+                "cpu_socket_utilization" => socket.utilization = value,
+                "cpu_socket_frequency_mhz" => socket.frequency_mhz = value as u32,
+                _ => socket.temperature = Some(value as u32),
             }
-            "cpu_core_utilization" => {
-                // Parse per-core utilization
-                if let (Some(core_id_str), Some(core_type_str)) =
-                    (labels.get("core_id"), labels.get("core_type"))
+        }
+        "cpu_core_utilization" => {
+            // Parse per-core utilization
+            if let (Some(core_id_str), Some(core_type_str)) =
+                (labels.get("core_id"), labels.get("core_type"))
+            {
+                if let Some(core_id) = core_id_str
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&id| (id as usize) < MAX_CORES_PER_CPU)
                 {
-                    if let Ok(core_id) = core_id_str.parse::<u32>() {
-                        let core_type = match core_type_str.as_str() {
-                            "P" => crate::device::CoreType::Performance,
-                            "E" => crate::device::CoreType::Efficiency,
-                            _ => crate::device::CoreType::Standard,
-                        };
+                    let core_type = match core_type_str.as_ref() {
+                        "P" => crate::device::CoreType::Performance,
+                        "E" => crate::device::CoreType::Efficiency,
+                        _ => crate::device::CoreType::Standard,
+                    };
 
-                        // Ensure vector is large enough
-                        while cpu_info.per_core_utilization.len() <= core_id as usize {
-                            cpu_info
-                                .per_core_utilization
-                                .push(crate::device::CoreUtilization {
-                                    core_id: cpu_info.per_core_utilization.len() as u32,
-                                    core_type: crate::device::CoreType::Standard,
-                                    utilization: 0.0,
-                                });
-                        }
-
-                        // Update the specific core
-                        cpu_info.per_core_utilization[core_id as usize] =
-                            crate::device::CoreUtilization {
-                                core_id,
-                                core_type,
-                                utilization: value,
-                            };
+                    // Ensure vector is large enough
+                    while cpu_info.per_core_utilization.len() <= core_id as usize {
+                        cpu_info
+                            .per_core_utilization
+                            .push(crate::device::CoreUtilization {
+                                core_id: cpu_info.per_core_utilization.len() as u32,
+                                core_type: crate::device::CoreType::Standard,
+                                utilization: 0.0,
+                            });
                     }
+
+                    // Update the specific core
+                    cpu_info.per_core_utilization[core_id as usize] =
+                        crate::device::CoreUtilization {
+                            core_id,
+                            core_type,
+                            utilization: value,
+                        };
                 }
             }
-            "cpu_info" => {
-                // Extract architecture and platform type from cpu_info metric
-                if let Some(architecture) = labels.get("architecture") {
-                    cpu_info.architecture = architecture.clone();
-                }
-                if let Some(platform_type_str) = labels.get("platform_type") {
-                    // Parse the platform type from the Debug format
-                    cpu_info.platform_type = if platform_type_str.contains("AppleSilicon") {
-                        CpuPlatformType::AppleSilicon
-                    } else if platform_type_str.contains("Intel") {
-                        CpuPlatformType::Intel
-                    } else if platform_type_str.contains("Amd") {
-                        CpuPlatformType::Amd
-                    } else {
-                        CpuPlatformType::Other(platform_type_str.clone())
-                    };
-                }
+        }
+        "cpu_info" => {
+            // Extract architecture and platform type from cpu_info metric
+            if let Some(architecture) = labels.get("architecture") {
+                cpu_info.architecture = architecture.to_string();
+            }
+            if let Some(platform_type_str) = labels.get("platform_type") {
+                // Parse the platform type from the Debug format
+                cpu_info.platform_type = if platform_type_str.contains("AppleSilicon") {
+                    CpuPlatformType::AppleSilicon
+                } else if platform_type_str.contains("Intel") {
+                    CpuPlatformType::Intel
+                } else if platform_type_str.contains("Amd") {
+                    CpuPlatformType::Amd
+                } else {
+                    CpuPlatformType::Other(platform_type_str.to_string())
+                };
             }
-            _ => {}
-        }
-    }
-
-    fn process_memory_metrics(
-        &self,
-        memory_info_map: &mut HashMap<String, MemoryInfo>,
-        metric_name: &str,
-        labels: &HashMap<String, String>,
-        value: f64,
-        host: &str,
-    ) {
-        // Keep the full host address including port
-        let memory_index = crate::get_label_or_default!(labels, "index", "0");
-        let memory_key = format!("{host}:{memory_index}");
-
-        let memory_info = memory_info_map
-            .entry(memory_key)
-            .or_insert_with(|| MemoryInfo {
-                host_id: host.to_string(), // Host identifier (e.g., "10.82.128.41:9090")
-                hostname: crate::get_label_or_default!(labels, "instance", host), // DNS hostname from instance label
-                instance: crate::get_label_or_default!(labels, "instance", host),
-                total_bytes: 0,
-                used_bytes: 0,
-                available_bytes: 0,
-                free_bytes: 0,
-                buffers_bytes: 0,
-                cached_bytes: 0,
-                swap_total_bytes: 0,
-                swap_used_bytes: 0,
-                swap_free_bytes: 0,
-                utilization: 0.0,
-                time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
-            });
-
-        crate::update_metric_field!(metric_name, value, memory_info, {
-            "memory_total_bytes" => total_bytes as u64,
-            "memory_used_bytes" => used_bytes as u64,
-            "memory_available_bytes" => available_bytes as u64,
-            "memory_buffers_bytes" => buffers_bytes as u64,
-            "memory_cached_bytes" => cached_bytes as u64,
-            "memory_utilization" => utilization as f64
+        }
+        _ => {}
+    }
+}
+
+fn process_memory_metrics(
+    memory_info_map: &mut HashMap<String, MemoryInfo>,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    // Keep the full host address including port
+    let memory_index = crate::get_label_or_default!(labels, "index", "0");
+    let memory_key = format!("{host}:{memory_index}");
+
+    let memory_info = memory_info_map
+        .entry(memory_key)
+        .or_insert_with(|| MemoryInfo {
+            host_id: host.to_string(), // Host identifier (e.g., "10.82.128.41:9090")
+            hostname: crate::get_label_or_default!(labels, "instance", host), // DNS hostname from instance label
+            instance: crate::get_label_or_default!(labels, "instance", host),
+            total_bytes: 0,
+            used_bytes: 0,
+            available_bytes: 0,
+            free_bytes: 0,
+            buffers_bytes: 0,
+            cached_bytes: 0,
+            swap_total_bytes: 0,
+            swap_used_bytes: 0,
+            swap_free_bytes: 0,
+            utilization: 0.0,
+            time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            psi: None,
+            pressure_level: None,
+            compressed_bytes: None,
+            swap_in_bytes_total: None,
+            swap_out_bytes_total: None,
+            stale: false,
         });
+
+    crate::update_metric_field!(metric_name, value, memory_info, {
+        "memory_total_bytes" => total_bytes as u64,
+        "memory_used_bytes" => used_bytes as u64,
+        "memory_available_bytes" => available_bytes as u64,
+        "memory_buffers_bytes" => buffers_bytes as u64,
+        "memory_cached_bytes" => cached_bytes as u64,
+        "memory_free_bytes" => free_bytes as u64,
+        "memory_utilization" => utilization as f64,
+        "swap_total_bytes" => swap_total_bytes as u64,
+        "swap_used_bytes" => swap_used_bytes as u64,
+        "swap_free_bytes" => swap_free_bytes as u64
+    });
+    match metric_name {
+        "memory_pressure_ratio" | "memory_stall_seconds_total" => {
+            let kind = crate::get_label_or_default!(labels, "kind", "some");
+            let psi = memory_info.psi.get_or_insert_with(MemoryPsi::default);
+            let line = match kind.as_str() {
+                "some" => &mut psi.some,
+                "full" => psi.full.get_or_insert_with(PsiLine::default),
+                _ => return,
+            };
+            if metric_name == "memory_stall_seconds_total" {
+                line.total_us = (value.max(0.0) * 1e6) as u64;
+            } else {
+                let window = crate::get_label_or_default!(labels, "window");
+                line.set_window(&window, value * 100.0);
+            }
+        }
+        "memory_pressure_level" => memory_info.pressure_level = PressureLevel::from_value(value),
+        "memory_compressed_bytes" => memory_info.compressed_bytes = Some(value.max(0.0) as u64),
+        "swap_in_bytes_total" => memory_info.swap_in_bytes_total = Some(value.max(0.0) as u64),
+        "swap_out_bytes_total" => memory_info.swap_out_bytes_total = Some(value.max(0.0) as u64),
+        _ => {}
     }
+}
 
-    fn process_storage_metrics(
-        &self,
-        storage_info_map: &mut HashMap<String, StorageInfo>,
-        metric_name: &str,
-        labels: &HashMap<String, String>,
-        value: f64,
-        host: &str,
-    ) {
-        // Keep the full host address including port
-        let mount_point = crate::get_label_or_default!(labels, "mount_point");
-        let storage_index = crate::get_label_or_default!(labels, "index", "0");
-
-        if mount_point.is_empty() {
-            return;
-        }
-
-        let storage_key = format!("{host}:{mount_point}");
-        let storage_info = storage_info_map
-            .entry(storage_key)
-            .or_insert_with(|| StorageInfo {
-                host_id: host.to_string(), // Host identifier (e.g., "10.82.128.41:9090")
-                hostname: labels
-                    .get("instance")
-                    .cloned()
-                    .unwrap_or_else(|| host.to_string()), // DNS hostname from instance label
-                mount_point: mount_point.clone(),
-                total_bytes: 0,
-                available_bytes: 0,
-                index: storage_index.parse().unwrap_or(0),
-            });
-
-        crate::update_metric_field!(metric_name, value, storage_info, {
-            "disk_total_bytes" => total_bytes as u64,
-            "disk_available_bytes" => available_bytes as u64
+fn process_storage_metrics(
+    storage_info_map: &mut HashMap<String, StorageInfo>,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    // Keep the full host address including port
+    let mount_point = crate::get_label_or_default!(labels, "mount_point");
+    let storage_index = crate::get_label_or_default!(labels, "index", "0");
+
+    if mount_point.is_empty() {
+        return;
+    }
+
+    let storage_key = format!("{host}:{mount_point}");
+    let storage_info = storage_info_map
+        .entry(storage_key)
+        .or_insert_with(|| StorageInfo {
+            host_id: host.to_string(), // Host identifier (e.g., "10.82.128.41:9090")
+            hostname: labels
+                .get("instance")
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| host.to_string()), // DNS hostname from instance label
+            mount_point: mount_point.clone(),
+            total_bytes: 0,
+            available_bytes: 0,
+            index: storage_index.parse().unwrap_or(0),
+            total_inodes: None,
+            free_inodes: None,
+            health: DiskHealth::default(),
         });
+
+    crate::update_metric_field!(metric_name, value, storage_info, {
+        "disk_total_bytes" => total_bytes as u64,
+        "disk_available_bytes" => available_bytes as u64
+    });
+    match metric_name {
+        "disk_inodes_total" => storage_info.total_inodes = Some(value.max(0.0) as u64),
+        "disk_inodes_free" => storage_info.free_inodes = Some(value.max(0.0) as u64),
+        "disk_temperature_celsius" => {
+            storage_info.health.temperature_celsius = Some(value.max(0.0) as u32)
+        }
+        "disk_percentage_used" => storage_info.health.percentage_used = Some(value.max(0.0) as u32),
+        "disk_available_spare_percent" => {
+            storage_info.health.available_spare_percent = Some(value.max(0.0) as u32)
+        }
+        "disk_reallocated_sectors" => {
+            storage_info.health.reallocated_sectors = Some(value.max(0.0) as u64)
+        }
+        "disk_health_ok" => storage_info.health.health_ok = Some(value > 0.0),
+        _ => {}
     }
+}
 
-    fn ensure_apple_silicon_info(&self, cpu_info: &mut CpuInfo) {
-        if cpu_info.apple_silicon_info.is_none() {
-            cpu_info.apple_silicon_info = Some(AppleSiliconCpuInfo {
-                p_core_count: 0,
-                e_core_count: 0,
-                gpu_core_count: 0,
-                p_core_utilization: 0.0,
-                e_core_utilization: 0.0,
-                ane_ops_per_second: None,
-                p_cluster_frequency_mhz: None,
-                e_cluster_frequency_mhz: None,
-                p_core_l2_cache_mb: None,
-                e_core_l2_cache_mb: None,
-            });
-        }
-    }
-
-    fn update_instance_names(
-        &self,
-        gpu_info_map: &mut HashMap<String, GpuInfo>,
-        cpu_info_map: &mut HashMap<String, CpuInfo>,
-        memory_info_map: &mut HashMap<String, MemoryInfo>,
-        storage_info_map: &mut HashMap<String, StorageInfo>,
-        instance_name: &str,
-    ) {
-        // Store instance name in detail field but keep hostname as the host address
-        for gpu_info in gpu_info_map.values_mut() {
-            gpu_info
+fn process_network_metrics(
+    network_info_map: &mut HashMap<String, NetworkInfo>,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    let interface = crate::get_label_or_default!(labels, "iface");
+    if interface.is_empty() {
+        return;
+    }
+
+    let network_info = network_info_map
+        .entry(format!("{host}:{interface}"))
+        .or_insert_with(|| NetworkInfo {
+            interface: interface.clone(),
+            rx_bytes_per_sec: 0.0,
+            tx_bytes_per_sec: 0.0,
+            rx_errors: 0,
+            tx_errors: 0,
+            host_id: host.to_string(),
+            hostname: labels
+                .get("instance")
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| host.to_string()),
+        });
+
+    crate::update_metric_field!(metric_name, value, network_info, {
+        "net_rx_bytes_per_sec" => rx_bytes_per_sec as f64,
+        "net_tx_bytes_per_sec" => tx_bytes_per_sec as f64,
+        "net_rx_errors_total" => rx_errors as u64,
+        "net_tx_errors_total" => tx_errors as u64
+    });
+}
+
+fn process_chassis_metrics(
+    chassis_info_map: &mut HashMap<String, ChassisInfo>,
+    metric_name: &str,
+    labels: &LabelMap<'_>,
+    value: f64,
+    host: &str,
+) {
+    // One chassis per host
+    let chassis = chassis_info_map
+        .entry(host.to_string())
+        .or_insert_with(|| ChassisInfo {
+            host_id: host.to_string(),
+            hostname: crate::get_label_or_default!(labels, "instance", host),
+            instance: crate::get_label_or_default!(labels, "instance", host),
+            time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            ..Default::default()
+        });
+
+    match metric_name {
+        "chassis_power_watts" => chassis.total_power_watts = Some(value),
+        "chassis_inlet_temperature_celsius" => chassis.inlet_temperature = Some(value),
+        "chassis_outlet_temperature_celsius" => chassis.outlet_temperature = Some(value),
+        "chassis_thermal_pressure_info" => {
+            if let Some(level) = labels.get("level") {
+                chassis.thermal_pressure = Some(level.to_string());
+            }
+        }
+        "chassis_cpu_power_watts" | "chassis_gpu_power_watts" | "chassis_ane_power_watts" => {
+            let key = metric_name.trim_start_matches("chassis_");
+            chassis
                 .detail
-                .insert("instance_name".to_string(), instance_name.to_string());
+                .insert(key.to_string(), format!("{value:.2}"));
         }
-        for _cpu_info in cpu_info_map.values_mut() {
-            // For CPU info, we may want to store instance name differently
-            // since it doesn't have a detail field by default
+        "load_average" => {
+            let window = labels.get("window").map(|w| w.as_ref());
+            if let Some(i) = LOAD_WINDOWS.iter().position(|w| Some(*w) == window) {
+                chassis
+                    .system
+                    .get_or_insert_with(Default::default)
+                    .load_average[i] = value;
+            }
         }
-        for _memory_info in memory_info_map.values_mut() {
-            // Similarly for memory info
+        "uptime_seconds" => {
+            chassis
+                .system
+                .get_or_insert_with(Default::default)
+                .uptime_seconds = value.max(0.0) as u64;
         }
-        for _storage_info in storage_info_map.values_mut() {
-            // And storage info
+        "node_info" => {
+            let system = chassis.system.get_or_insert_with(Default::default);
+            system.kernel_version = crate::get_label_or_default!(labels, "kernel");
+            system.os_name = crate::get_label_or_default!(labels, "os");
         }
+        "node_draining" => chassis.draining = true,
+        "chassis_fan_speed_rpm" => {
+            let Some(id) = labels.get("fan_id").and_then(|s| s.parse::<u32>().ok()) else {
+                return;
+            };
+            let speed_rpm = value.max(0.0) as u32;
+            if let Some(fan) = chassis.fan_speeds.iter_mut().find(|f| f.id == id) {
+                fan.speed_rpm = speed_rpm;
+            } else if chassis.fan_speeds.len() < MAX_DEVICES_PER_TYPE {
+                chassis.fan_speeds.push(FanInfo {
+                    id,
+                    name: crate::get_label_or_default!(labels, "fan_name"),
+                    speed_rpm,
+                    max_rpm: 0,
+                });
+            }
+        }
+        _ => {}
     }
 }
 
-impl Default for MetricsParser {
-    fn default() -> Self {
-        Self::new()
+fn ensure_apple_silicon_info(cpu_info: &mut CpuInfo) {
+    if cpu_info.apple_silicon_info.is_none() {
+        cpu_info.apple_silicon_info = Some(AppleSiliconCpuInfo {
+            p_core_count: 0,
+            e_core_count: 0,
+            gpu_core_count: 0,
+            p_core_utilization: 0.0,
+            e_core_utilization: 0.0,
+            ane_ops_per_second: None,
+            p_cluster_frequency_mhz: None,
+            e_cluster_frequency_mhz: None,
+            p_core_l2_cache_mb: None,
+            e_core_l2_cache_mb: None,
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use regex::Regex;
 
     fn create_test_parser() -> MetricsParser {
         MetricsParser::new()
     }
 
-    fn create_test_regex() -> Regex {
-        Regex::new(r"^all_smi_([^\{]+)\{([^}]+)\} ([\d\.]+)$").unwrap()
+    fn labels<'a>(pairs: &[(&'a str, &'a str)]) -> LabelMap<'a> {
+        pairs
+            .iter()
+            .map(|(k, v)| (*k, std::borrow::Cow::Borrowed(*v)))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_metric_gpu() {
+        let host = "10.0.0.1:9090";
+        let mut metrics = HostMetrics::default();
+        let gpu_labels = labels(&[
+            ("gpu", "NVIDIA H200"),
+            ("uuid", "GPU-1"),
+            ("index", "3"),
+            ("instance", "node-1"),
+        ]);
+
+        apply_metric(&mut metrics, "gpu_utilization", &gpu_labels, 42.5, host);
+        apply_metric(
+            &mut metrics,
+            "gpu_memory_used_bytes",
+            &gpu_labels,
+            1024.0,
+            host,
+        );
+        apply_metric(
+            &mut metrics,
+            "gpu_temperature_celsius",
+            &gpu_labels,
+            -3.0,
+            host,
+        );
+
+        let mut info_labels = gpu_labels.clone();
+        info_labels.insert("type", "NPU".into());
+        info_labels.insert("driver_version", "550.54".into());
+        apply_metric(&mut metrics, "gpu_info", &info_labels, 1.0, host);
+
+        assert_eq!(metrics.gpus.len(), 1);
+        let gpu = &metrics.gpus["GPU-1"];
+        assert_eq!(gpu.name, "NVIDIA H200");
+        assert_eq!(gpu.host_id, host);
+        assert_eq!(gpu.hostname, "node-1");
+        assert_eq!(gpu.utilization, 42.5);
+        assert_eq!(gpu.used_memory, 1024);
+        // Negative values saturate to zero for unsigned fields
+        assert_eq!(gpu.temperature, 0);
+        assert_eq!(gpu.device_type, "NPU");
+        assert_eq!(gpu.detail.get("index").map(String::as_str), Some("3"));
+        assert_eq!(
+            gpu.detail.get("driver_version").map(String::as_str),
+            Some("550.54")
+        );
+
+        // Samples without a name or uuid cannot be attributed to a device
+        apply_metric(
+            &mut metrics,
+            "gpu_utilization",
+            &labels(&[("uuid", "GPU-2")]),
+            1.0,
+            host,
+        );
+        assert_eq!(metrics.gpus.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_metric_cpu_cores() {
+        let host = "10.0.0.1:9090";
+        let mut metrics = HostMetrics::default();
+
+        let mut core_labels = labels(&[("cpu_model", "Apple M3"), ("index", "0")]);
+        core_labels.insert("core_id", "2".into());
+        core_labels.insert("core_type", "E".into());
+        apply_metric(
+            &mut metrics,
+            "cpu_core_utilization",
+            &core_labels,
+            12.0,
+            host,
+        );
+        apply_metric(&mut metrics, "cpu_p_core_count", &core_labels, 8.0, host);
+
+        let cpu = &metrics.cpus["10.0.0.1:9090:0"];
+        assert!(matches!(
+            cpu.platform_type,
+            crate::device::CpuPlatformType::AppleSilicon
+        ));
+        assert_eq!(cpu.per_core_utilization.len(), 3);
+        assert_eq!(cpu.per_core_utilization[2].utilization, 12.0);
+        assert!(matches!(
+            cpu.per_core_utilization[2].core_type,
+            crate::device::CoreType::Efficiency
+        ));
+        assert_eq!(cpu.apple_silicon_info.as_ref().unwrap().p_core_count, 8);
     }
 
     #[test]
-    fn test_parse_labels() {
+    fn test_apply_metric_memory_and_storage() {
+        let host = "10.0.0.1:9090";
+        let mut metrics = HostMetrics::default();
+
+        apply_metric(
+            &mut metrics,
+            "memory_used_bytes",
+            &labels(&[("instance", "node-1")]),
+            2048.0,
+            host,
+        );
+        apply_metric(
+            &mut metrics,
+            "disk_total_bytes",
+            &labels(&[("mount_point", "/data"), ("index", "1")]),
+            4096.0,
+            host,
+        );
+        apply_metric(
+            &mut metrics,
+            "disk_total_bytes",
+            &labels(&[("index", "2")]),
+            1.0,
+            host,
+        );
+        apply_metric(&mut metrics, "unknown_metric", &labels(&[]), 1.0, host);
+
+        let memory = &metrics.memory["10.0.0.1:9090:0"];
+        assert_eq!(memory.used_bytes, 2048);
+        assert_eq!(memory.hostname, "node-1");
+
+        assert_eq!(metrics.storage.len(), 1);
+        let storage = &metrics.storage["10.0.0.1:9090:/data"];
+        assert_eq!(storage.total_bytes, 4096);
+        assert_eq!(storage.index, 1);
+        assert_eq!(storage.hostname, host);
+    }
+
+    #[test]
+    fn test_parse_processes() {
+        let parser = create_test_parser();
+        let host = "10.0.0.1:9090";
+        // From `all-smi api --processes --kubernetes`
+        let test_data = r#"
+# HELP all_smi_gpu_utilization GPU utilization percentage
+# TYPE all_smi_gpu_utilization gauge
+all_smi_gpu_utilization{gpu="NVIDIA H100 80GB HBM3", instance="node-1", uuid="GPU-a", index="0"} 87
+# HELP all_smi_process_memory_used_bytes Process GPU memory used in bytes.
+# TYPE all_smi_process_memory_used_bytes gauge
+all_smi_process_memory_used_bytes{pid="4242", start_time="1700000000", name="python", device_id="0", device_uuid="GPU-a", pod="train-0", namespace="ml", container="trainer"} 34359738368
+all_smi_process_memory_used_bytes{pid="4242", start_time="1700000000", name="python", device_id="1", device_uuid="GPU-b", pod="train-0", namespace="ml", container="trainer"} 1073741824
+all_smi_process_memory_used_bytes{pid="5151", start_time="1700000100", name="vllm", device_id="1", device_uuid="GPU-b"} 68719476736
+all_smi_process_memory_used_bytes{pid="6000", start_time="1700000200", name="nvtop", device_id="0", device_uuid="GPU-a"} 0
+all_smi_process_memory_used_bytes{start_time="1700000300", name="orphan", device_id="0", device_uuid="GPU-a"} 1
+# HELP all_smi_gpu_process_count Number of processes using the GPU
+# TYPE all_smi_gpu_process_count gauge
+all_smi_gpu_process_count{uuid="GPU-a"} 2
+"#;
+
+        let parsed = parser.parse_processes(test_data, host, 50);
+        assert_eq!(parsed.omitted, 0);
+        let pids: Vec<(u32, &str)> = parsed
+            .processes
+            .iter()
+            .map(|p| (p.pid, p.device_uuid.as_str()))
+            .collect();
+        // By GPU memory; a process on two GPUs is listed on each
+        assert_eq!(
+            pids,
+            vec![
+                (5151, "GPU-b"),
+                (4242, "GPU-a"),
+                (4242, "GPU-b"),
+                (6000, "GPU-a")
+            ]
+        );
+
+        let trainer = &parsed.processes[1];
+        assert_eq!(trainer.host_id, host);
+        assert_eq!(trainer.process_name, "python");
+        assert_eq!(trainer.start_time, 1_700_000_000);
+        assert_eq!(trainer.device_id, 0);
+        assert_eq!(trainer.used_memory, 32 << 30);
+        assert!(trainer.uses_gpu);
+        let pod = trainer.k8s_pod.as_ref().unwrap();
+        assert_eq!(
+            (pod.namespace.as_str(), pod.name.as_str()),
+            ("ml", "train-0")
+        );
+        assert_eq!(parsed.processes[0].k8s_pod, None);
+
+        // The cap keeps the heaviest and counts the rest
+        let parsed = parser.parse_processes(test_data, host, 2);
+        assert_eq!(parsed.processes.len(), 2);
+        assert_eq!(parsed.processes[0].pid, 5151);
+        assert_eq!(parsed.omitted, 2);
+
+        assert!(parser
+            .parse_processes("all_smi_gpu_utilization{uuid=\"GPU-a\"} 1\n", host, 50)
+            .processes
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_network_metrics() {
         let parser = create_test_parser();
+        let host = "10.0.0.1:9090";
+        let test_data = r#"
+all_smi_net_rx_bytes_per_sec{instance="node-1", iface="eth0"} 1250.50
+all_smi_net_tx_bytes_per_sec{instance="node-1", iface="eth0"} 800
+all_smi_net_rx_errors_total{instance="node-1", iface="eth0"} 3
+all_smi_net_tx_errors_total{instance="node-1", iface="eth0"} -1
+all_smi_net_rx_bytes_per_sec{instance="node-1"} 1
+"#;
 
-        let labels = parser.parse_labels(r#"instance="node-0058", mount_point="/", index="0""#);
-        assert_eq!(labels.get("instance").unwrap(), "node-0058");
-        assert_eq!(labels.get("mount_point").unwrap(), "/");
-        assert_eq!(labels.get("index").unwrap(), "0");
+        let (_, _, _, _, network_info, _) = parser.parse_metrics(test_data, host);
+        assert_eq!(network_info.len(), 1);
+        let eth0 = &network_info[0];
+        assert_eq!(eth0.interface, "eth0");
+        assert_eq!(eth0.host_id, host);
+        assert_eq!(eth0.hostname, "node-1");
+        assert_eq!(eth0.rx_bytes_per_sec, 1250.5);
+        assert_eq!(eth0.tx_bytes_per_sec, 800.0);
+        assert_eq!(eth0.rx_errors, 3);
+        assert_eq!(eth0.tx_errors, 0);
+    }
 
-        let labels = parser.parse_labels(r#"gpu="NVIDIA H200 141GB HBM3", uuid="GPU-12345""#);
-        assert_eq!(labels.get("gpu").unwrap(), "NVIDIA H200 141GB HBM3");
-        assert_eq!(labels.get("uuid").unwrap(), "GPU-12345");
+    #[test]
+    fn test_apply_metric_device_limit() {
+        let mut metrics = HostMetrics::default();
+        for i in 0..MAX_DEVICES_PER_TYPE + 10 {
+            let uuid = format!("GPU-{i}");
+            let gpu_labels = labels(&[("gpu", "GPU"), ("uuid", &uuid)]);
+            apply_metric(&mut metrics, "gpu_utilization", &gpu_labels, 1.0, "h:1");
+        }
+        assert_eq!(metrics.gpus.len(), MAX_DEVICES_PER_TYPE);
+    }
 
-        let labels = parser.parse_labels("");
-        assert!(labels.is_empty());
+    #[test]
+    fn test_parse_comments_and_escapes() {
+        let parser = create_test_parser();
+        let host = "127.0.0.1:10058";
+
+        let test_data = r#"
+# HELP all_smi_gpu_info GPU info
+# TYPE all_smi_gpu_info gauge
+all_smi_gpu_info{gpu="Vendor \"X\", Inc.",uuid="GPU-9",index="0",type="GPU"} 1
+all_smi_gpu_utilization{gpu="Vendor \"X\", Inc.",uuid="GPU-9",index="0"}   55 1700000000000
+"#;
 
-        let labels = parser.parse_labels("malformed");
-        assert!(labels.is_empty());
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, host);
+
+        assert_eq!(gpu_info.len(), 1);
+        assert_eq!(gpu_info[0].name, r#"Vendor "X", Inc."#);
+        assert_eq!(gpu_info[0].utilization, 55.0);
     }
 
     #[test]
     fn test_parse_gpu_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -610,9 +1241,10 @@ all_smi_gpu_memory_total_bytes{gpu="NVIDIA H200 141GB HBM3", instance="node-0058
 all_smi_gpu_temperature_celsius{gpu="NVIDIA H200 141GB HBM3", instance="node-0058", uuid="GPU-12345", index="0"} 65
 all_smi_gpu_power_consumption_watts{gpu="NVIDIA H200 141GB HBM3", instance="node-0058", uuid="GPU-12345", index="0"} 400.5
 all_smi_ane_utilization{gpu="NVIDIA H200 141GB HBM3", instance="node-0058", uuid="GPU-12345", index="0"} 15.2
+all_smi_gpu_stale{gpu="NVIDIA H200 141GB HBM3", instance="node-0058", uuid="GPU-12345", index="0"} 1
 "#;
 
-        let (gpu_info, _, _, _) = parser.parse_metrics(test_data, host, &re);
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, host);
 
         assert_eq!(gpu_info.len(), 1);
         let gpu = &gpu_info[0];
@@ -627,12 +1259,176 @@ all_smi_ane_utilization{gpu="NVIDIA H200 141GB HBM3", instance="node-0058", uuid
         assert_eq!(gpu.temperature, 65);
         assert_eq!(gpu.power_consumption, 400.5);
         assert_eq!(gpu.ane_utilization, 15.2);
+        assert_eq!(gpu.detail[crate::app_state::STALE_KEY], "true");
+    }
+
+    #[test]
+    fn test_parse_gpu_up() {
+        let parser = create_test_parser();
+        let test_data = r#"
+all_smi_gpu_up{gpu="NVIDIA H100", instance="node-1", uuid="GPU-0", index="0"} 1
+all_smi_gpu_up{gpu="NVIDIA H100", instance="node-1", uuid="GPU-1", index="1"} 0
+"#;
+
+        let (mut gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, "node-1:9090");
+        gpu_info.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+
+        assert_eq!(gpu_info.len(), 2);
+        assert!(!crate::device::gpu_presence::is_lost(&gpu_info[0]));
+        assert!(crate::device::gpu_presence::is_lost(&gpu_info[1]));
+    }
+
+    #[test]
+    fn test_parse_amd_gpu_metrics() {
+        let parser = create_test_parser();
+        let test_data = r#"
+all_smi_gpu_utilization{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 80
+all_smi_gpu_fan_speed_rpm{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 1150
+all_smi_gpu_clock_memory_mhz{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 1600
+all_smi_gpu_throttle_status_info{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0", status="0x00000001", reasons="power"} 1
+all_smi_gpu_memory_temperature_celsius{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 71
+all_smi_gpu_hotspot_temperature_celsius{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 88
+all_smi_gpu_bar1_memory_used_bytes{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 4194304
+all_smi_gpu_bar1_memory_total_bytes{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 268435456
+all_smi_gpu_memory_reserved_bytes{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0"} 536870912
+all_smi_gpu_fan_speed_percent{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0", fan="0"} 43
+all_smi_gpu_fan_speed_percent{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0", fan="1"} 45
+all_smi_gpu_fan_target_percent{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0", fan="0"} 50
+all_smi_gpu_fan_speed_percent{gpu="AMD Instinct MI210", instance="node-0001", uuid="GPU-0000:03:00.0", index="0", fan="99999"} 1
+"#;
+
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, "node-0001:9090");
+
+        let gpu = &gpu_info[0];
+        assert_eq!(gpu.detail["fan_speed_rpm"], "1150");
+        assert_eq!(gpu.detail["clock_memory_current"], "1600");
+        assert_eq!(gpu.detail["throttle_status"], "0x00000001");
+        assert_eq!(gpu.detail["throttle_reasons"], "power");
+        assert_eq!(gpu.detail[MEMORY_TEMPERATURE_KEY], "71");
+        assert_eq!(gpu.detail[HOTSPOT_TEMPERATURE_KEY], "88");
+        assert_eq!(gpu.detail[BAR1_USED_KEY], "4194304");
+        assert_eq!(gpu.detail[BAR1_TOTAL_KEY], "268435456");
+        assert_eq!(gpu.detail[RESERVED_MEMORY_KEY], "536870912");
+        // An out-of-range fan index is dropped
+        assert_eq!(
+            crate::device::gpu_fan::fan_speeds(&gpu.detail),
+            [(0, 43), (1, 45)]
+        );
+        assert_eq!(crate::device::gpu_fan::fan_targets(&gpu.detail), [(0, 50)]);
+    }
+
+    #[test]
+    fn test_parse_gpu_info_versions() {
+        use crate::device::driver_versions::{VersionAudit, VersionField};
+
+        let parser = create_test_parser();
+        let scrape = |driver: &str| {
+            format!(
+                r#"all_smi_gpu_info{{gpu="NVIDIA H100", instance="node-1", uuid="GPU-1", index="0", type="GPU", driver_version="{driver}", cuda_version="12.4", vbios_version="96.00.89.00.01", lib_name="CUDA"}} 1
+"#
+            )
+        };
+        let (mut gpus, _, _, _, _, _) = parser.parse_metrics(&scrape("550.54.15"), "node-1:9090");
+        let (other, _, _, _, _, _) = parser.parse_metrics(&scrape("535.161.08"), "node-2:9090");
+        gpus.extend(other);
+
+        assert_eq!(gpus[0].detail["driver_version"], "550.54.15");
+        assert_eq!(gpus[0].detail["cuda_version"], "12.4");
+        assert_eq!(gpus[0].detail["vbios_version"], "96.00.89.00.01");
+
+        let audit = VersionAudit::new(&gpus);
+        assert_eq!(
+            audit.hosts["node-2:9090"][&VersionField::Driver],
+            "535.161.08"
+        );
+        assert!(audit.is_mixed());
+        assert_eq!(
+            audit.summary(VersionField::Cuda).as_deref(),
+            Some("12.4 ×2")
+        );
+    }
+
+    #[test]
+    fn test_parse_jetson_metrics() {
+        let parser = create_test_parser();
+        let test_data = r#"
+all_smi_gpu_utilization{gpu="NVIDIA Jetson AGX Orin", instance="orin-01", uuid="JETSON-1", index="0"} 35
+all_smi_jetson_emc_frequency_hz{gpu="NVIDIA Jetson AGX Orin", instance="orin-01", uuid="JETSON-1", index="0"} 3199000000
+all_smi_jetson_rail_power_watts{gpu="NVIDIA Jetson AGX Orin", instance="orin-01", uuid="JETSON-1", index="0", rail="VDD_GPU_SOC"} 1.597
+all_smi_jetson_dla_utilization{gpu="NVIDIA Jetson AGX Orin", instance="orin-01", uuid="JETSON-1", index="0", dla="1"} 40
+"#;
+
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, "orin-01:9090");
+
+        assert_eq!(gpu_info.len(), 1);
+        let gpu = &gpu_info[0];
+        assert_eq!(gpu.detail[EMC_FREQUENCY_KEY], "3199000000");
+        assert_eq!(gpu.detail["rail_power_mw_VDD_GPU_SOC"], "1597");
+        assert_eq!(gpu.detail["dla_utilization_1"], "40");
+    }
+
+    #[test]
+    fn test_parse_gpu_clock_domains() {
+        let parser = create_test_parser();
+        let test_data = r#"
+all_smi_gpu_frequency_mhz{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 1980
+all_smi_gpu_clock_sm_mhz{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 1980
+all_smi_gpu_clock_graphics_mhz{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 1980
+all_smi_gpu_clock_memory_mhz{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 2619
+all_smi_gpu_clock_video_mhz{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 1755
+"#;
+
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, "node-0001:9090");
+
+        let gpu = &gpu_info[0];
+        assert_eq!(gpu.frequency, 1980);
+        assert_eq!(gpu.detail["clock_sm_current"], "1980");
+        assert_eq!(gpu.detail["clock_graphics_current"], "1980");
+        assert_eq!(gpu.detail["clock_memory_current"], "2619");
+        assert_eq!(gpu.detail["clock_video_current"], "1755");
+    }
+
+    #[test]
+    fn test_parse_gpu_video_engines() {
+        let parser = create_test_parser();
+        let test_data = r#"
+all_smi_gpu_utilization{gpu="NVIDIA L4", instance="node-0001", uuid="GPU-1", index="0"} 35
+all_smi_gpu_encoder_utilization{gpu="NVIDIA L4", instance="node-0001", uuid="GPU-1", index="0"} 12
+all_smi_gpu_decoder_utilization{gpu="NVIDIA L4", instance="node-0001", uuid="GPU-1", index="0"} 87
+all_smi_gpu_utilization{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-2", index="1"} 90
+"#;
+
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, "node-0001:9090");
+
+        let l4 = gpu_info.iter().find(|gpu| gpu.uuid == "GPU-1").unwrap();
+        assert_eq!(l4.encoder_utilization, Some(12.0));
+        assert_eq!(l4.decoder_utilization, Some(87.0));
+        // No series for a card without video engines
+        let h100 = gpu_info.iter().find(|gpu| gpu.uuid == "GPU-2").unwrap();
+        assert_eq!(h100.encoder_utilization, None);
+        assert_eq!(h100.decoder_utilization, None);
+    }
+
+    #[test]
+    fn test_parse_gpu_power_limits() {
+        let parser = create_test_parser();
+        let test_data = r#"
+all_smi_gpu_power_consumption_watts{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 712.4
+all_smi_gpu_power_limit_current_watts{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 700
+all_smi_gpu_power_limit_enforced_watts{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} 700
+all_smi_gpu_power_headroom_watts{gpu="NVIDIA H100", instance="node-0001", uuid="GPU-1", index="0"} -12.40
+"#;
+
+        let (gpu_info, _, _, _, _, _) = parser.parse_metrics(test_data, "node-0001:9090");
+
+        let headroom = crate::device::power_headroom::power_headroom(&gpu_info[0]).unwrap();
+        assert_eq!(headroom.limit_watts, 700.0);
+        assert!(headroom.is_capped());
     }
 
     #[test]
     fn test_parse_cpu_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -645,7 +1441,7 @@ all_smi_cpu_temperature_celsius{cpu_model="Intel Xeon", instance="node-0058", ho
 all_smi_cpu_power_consumption_watts{cpu_model="Intel Xeon", instance="node-0058", hostname="node-0058", index="0"} 125.5
 "#;
 
-        let (_, cpu_info, _, _) = parser.parse_metrics(test_data, host, &re);
+        let (_, cpu_info, _, _, _, _) = parser.parse_metrics(test_data, host);
 
         assert_eq!(cpu_info.len(), 1);
         let cpu = &cpu_info[0];
@@ -670,7 +1466,6 @@ all_smi_cpu_power_consumption_watts{cpu_model="Intel Xeon", instance="node-0058"
     #[test]
     fn test_parse_apple_silicon_cpu_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -681,7 +1476,7 @@ all_smi_cpu_p_core_utilization{cpu_model="Apple M2 Max", instance="node-0058", h
 all_smi_cpu_e_core_utilization{cpu_model="Apple M2 Max", instance="node-0058", hostname="node-0058", index="0"} 10.8
 "#;
 
-        let (_, cpu_info, _, _) = parser.parse_metrics(test_data, host, &re);
+        let (_, cpu_info, _, _, _, _) = parser.parse_metrics(test_data, host);
 
         assert_eq!(cpu_info.len(), 1);
         let cpu = &cpu_info[0];
@@ -702,7 +1497,6 @@ all_smi_cpu_e_core_utilization{cpu_model="Apple M2 Max", instance="node-0058", h
     #[test]
     fn test_parse_memory_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -712,7 +1506,7 @@ all_smi_memory_available_bytes{instance="node-0058", hostname="node-0058", index
 all_smi_memory_utilization{instance="node-0058", hostname="node-0058", index="0"} 50.0
 "#;
 
-        let (_, _, memory_info, _) = parser.parse_metrics(test_data, host, &re);
+        let (_, _, memory_info, _, _, _) = parser.parse_metrics(test_data, host);
 
         assert_eq!(memory_info.len(), 1);
         let memory = &memory_info[0];
@@ -725,20 +1519,55 @@ all_smi_memory_utilization{instance="node-0058", hostname="node-0058", index="0"
         assert_eq!(memory.utilization, 50.0);
     }
 
+    #[test]
+    fn test_parse_memory_pressure_metrics() {
+        let parser = create_test_parser();
+        let host = "127.0.0.1:10058";
+
+        let test_data = r#"
+all_smi_memory_total_bytes{instance="node-0058", hostname="node-0058", index="0"} 137438953472
+all_smi_memory_pressure_ratio{instance="node-0058", hostname="node-0058", index="0", kind="some", window="10s"} 0.125
+all_smi_memory_pressure_ratio{instance="node-0058", hostname="node-0058", index="0", kind="some", window="300s"} 0.0075
+all_smi_memory_pressure_ratio{instance="node-0058", hostname="node-0058", index="0", kind="full", window="10s"} 0.02
+all_smi_memory_stall_seconds_total{instance="node-0058", hostname="node-0058", index="0", kind="some"} 48.213007
+all_smi_swap_in_bytes_total{instance="node-0058", hostname="node-0058", index="0"} 172032
+all_smi_swap_out_bytes_total{instance="node-0058", hostname="node-0058", index="0"} 28672
+"#;
+
+        let (_, _, memory_info, _, _, _) = parser.parse_metrics(test_data, host);
+
+        let memory = &memory_info[0];
+        let psi = memory.psi.unwrap();
+        assert_eq!(psi.some.avg10, 12.5);
+        assert_eq!(psi.some.avg300, 0.75);
+        assert_eq!(psi.some.total_us, 48_213_007);
+        assert_eq!(psi.full.unwrap().avg10, 2.0);
+        assert_eq!(memory.swap_in_bytes_total, Some(172032));
+        assert_eq!(memory.swap_out_bytes_total, Some(28672));
+        assert_eq!(
+            crate::device::memory_pressure::pressure_level(memory),
+            Some(PressureLevel::Warning)
+        );
+    }
+
     #[test]
     fn test_parse_storage_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
 all_smi_disk_total_bytes{instance="node-0058", mount_point="/", index="0"} 4398046511104
 all_smi_disk_available_bytes{instance="node-0058", mount_point="/", index="0"} 891915494941
+all_smi_disk_inodes_total{instance="node-0058", mount_point="/", index="0"} 268435456
+all_smi_disk_inodes_free{instance="node-0058", mount_point="/", index="0"} 1048576
+all_smi_disk_temperature_celsius{instance="node-0058", mount_point="/", index="0"} 64
+all_smi_disk_percentage_used{instance="node-0058", mount_point="/", index="0"} 7
+all_smi_disk_health_ok{instance="node-0058", mount_point="/", index="0"} 0
 all_smi_disk_total_bytes{instance="node-0058", mount_point="/home", index="1"} 1099511627776
 all_smi_disk_available_bytes{instance="node-0058", mount_point="/home", index="1"} 549755813888
 "#;
 
-        let (_, _, _, storage_info) = parser.parse_metrics(test_data, host, &re);
+        let (_, _, _, storage_info, _, _) = parser.parse_metrics(test_data, host);
 
         assert_eq!(storage_info.len(), 2);
 
@@ -748,6 +1577,11 @@ all_smi_disk_available_bytes{instance="node-0058", mount_point="/home", index="1
         assert_eq!(root_storage.total_bytes, 4398046511104);
         assert_eq!(root_storage.available_bytes, 891915494941);
         assert_eq!(root_storage.index, 0);
+        assert_eq!(root_storage.total_inodes, Some(268435456));
+        assert_eq!(root_storage.free_inodes, Some(1048576));
+        assert_eq!(root_storage.health.temperature_celsius, Some(64));
+        assert_eq!(root_storage.health.percentage_used, Some(7));
+        assert_eq!(root_storage.health.health_ok, Some(false));
 
         let home_storage = storage_info
             .iter()
@@ -758,12 +1592,13 @@ all_smi_disk_available_bytes{instance="node-0058", mount_point="/home", index="1
         assert_eq!(home_storage.total_bytes, 1099511627776);
         assert_eq!(home_storage.available_bytes, 549755813888);
         assert_eq!(home_storage.index, 1);
+        assert_eq!(home_storage.total_inodes, None);
+        assert_eq!(home_storage.health, DiskHealth::default());
     }
 
     #[test]
     fn test_parse_mixed_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -773,8 +1608,8 @@ all_smi_memory_total_bytes{instance="node-0001", hostname="node-0001", index="0"
 all_smi_disk_total_bytes{instance="node-0001", mount_point="/", index="0"} 2199023255552
 "#;
 
-        let (gpu_info, cpu_info, memory_info, storage_info) =
-            parser.parse_metrics(test_data, host, &re);
+        let (gpu_info, cpu_info, memory_info, storage_info, _, _) =
+            parser.parse_metrics(test_data, host);
 
         assert_eq!(gpu_info.len(), 1);
         assert_eq!(cpu_info.len(), 1);
@@ -801,7 +1636,6 @@ all_smi_disk_total_bytes{instance="node-0001", mount_point="/", index="0"} 21990
     #[test]
     fn test_invalid_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -810,8 +1644,8 @@ all_smi_gpu_utilization{malformed labels} invalid_value
 all_smi_unknown_metric{instance="test"} 42.0
 "#;
 
-        let (gpu_info, cpu_info, memory_info, storage_info) =
-            parser.parse_metrics(test_data, host, &re);
+        let (gpu_info, cpu_info, memory_info, storage_info, _, _) =
+            parser.parse_metrics(test_data, host);
 
         assert!(gpu_info.is_empty());
         assert!(cpu_info.is_empty());
@@ -822,10 +1656,9 @@ all_smi_unknown_metric{instance="test"} 42.0
     #[test]
     fn test_empty_metrics() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
-        let (gpu_info, cpu_info, memory_info, storage_info) = parser.parse_metrics("", host, &re);
+        let (gpu_info, cpu_info, memory_info, storage_info, _, _) = parser.parse_metrics("", host);
 
         assert!(gpu_info.is_empty());
         assert!(cpu_info.is_empty());
@@ -836,7 +1669,6 @@ all_smi_unknown_metric{instance="test"} 42.0
     #[test]
     fn test_hostname_update() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -844,7 +1676,7 @@ all_smi_gpu_utilization{gpu="Tesla V100", instance="production-node-42", uuid="G
 all_smi_cpu_utilization{cpu_model="Intel Xeon", instance="production-node-42", hostname="node-0058", index="0"} 55.0
 "#;
 
-        let (gpu_info, cpu_info, _, _) = parser.parse_metrics(test_data, host, &re);
+        let (gpu_info, cpu_info, _, _, _, _) = parser.parse_metrics(test_data, host);
 
         assert_eq!(gpu_info[0].host_id, host);
         assert_eq!(gpu_info[0].hostname, "production-node-42");
@@ -857,7 +1689,6 @@ all_smi_cpu_utilization{cpu_model="Intel Xeon", instance="production-node-42", h
     #[test]
     fn test_cpu_platform_detection() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_cases = [
@@ -875,7 +1706,7 @@ all_smi_cpu_utilization{cpu_model="Intel Xeon", instance="production-node-42", h
                 r#"all_smi_cpu_utilization{{cpu_model="{cpu_model}", instance="test", hostname="test", index="0"}} 50.0"#
             );
 
-            let (_, cpu_info, _, _) = parser.parse_metrics(&test_data, host, &re);
+            let (_, cpu_info, _, _, _, _) = parser.parse_metrics(&test_data, host);
             assert_eq!(cpu_info.len(), 1);
 
             match (&cpu_info[0].platform_type, &expected_type) {
@@ -902,7 +1733,6 @@ all_smi_cpu_utilization{cpu_model="Intel Xeon", instance="production-node-42", h
     #[test]
     fn test_missing_required_fields() {
         let parser = create_test_parser();
-        let re = create_test_regex();
         let host = "127.0.0.1:10058";
 
         let test_data = r#"
@@ -910,9 +1740,210 @@ all_smi_gpu_utilization{instance="node-0058", index="0"} 25.5
 all_smi_disk_total_bytes{instance="node-0058", index="0"} 1000000000
 "#;
 
-        let (gpu_info, _, _, storage_info) = parser.parse_metrics(test_data, host, &re);
+        let (gpu_info, _, _, storage_info, _, _) = parser.parse_metrics(test_data, host);
 
         assert!(gpu_info.is_empty());
         assert!(storage_info.is_empty());
     }
+
+    #[test]
+    fn test_parse_exporter_cpu_payload() {
+        let parser = create_test_parser();
+        let host = "10.0.0.7:9090";
+
+        // Captured from a dual-socket Xeon node (trimmed to four cores)
+        let test_data = r#"
+# HELP all_smi_cpu_info CPU device information
+# TYPE all_smi_cpu_info gauge
+all_smi_cpu_info{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0", architecture="x86_64", platform_type="Intel"} 1
+all_smi_cpu_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 37.5
+all_smi_cpu_socket_count{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 2
+all_smi_cpu_core_count{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 4
+all_smi_cpu_thread_count{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 8
+all_smi_cpu_frequency_mhz{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 2000
+all_smi_cpu_temperature_celsius{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 61
+all_smi_cpu_power_consumption_watts{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", index="0"} 205.5
+all_smi_cpu_socket_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", cpu_index="0", socket_id="0"} 40
+all_smi_cpu_socket_frequency_mhz{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", cpu_index="0", socket_id="0"} 2100
+all_smi_cpu_socket_temperature_celsius{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", cpu_index="0", socket_id="0"} 63
+all_smi_cpu_socket_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", cpu_index="0", socket_id="1"} 35
+all_smi_cpu_socket_frequency_mhz{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", cpu_index="0", socket_id="1"} 1900
+all_smi_cpu_core_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", core_id="0", core_type="C"} 90
+all_smi_cpu_core_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", core_id="1", core_type="C"} 10
+all_smi_cpu_core_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", core_id="2", core_type="C"} 30
+all_smi_cpu_core_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", core_id="3", core_type="C"} 20
+all_smi_cpu_core_utilization{cpu_model="Intel Xeon Gold 6338", instance="gpu-07", hostname="gpu-07", core_id="4294967295", core_type="C"} 20
+all_smi_memory_total_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 540000000000
+all_smi_memory_used_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 135000000000
+all_smi_memory_available_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 405000000000
+all_smi_memory_free_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 300000000000
+all_smi_memory_utilization{instance="gpu-07", hostname="gpu-07", index="0"} 25
+all_smi_swap_total_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 8000000000
+all_smi_swap_used_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 1000000000
+all_smi_swap_free_bytes{instance="gpu-07", hostname="gpu-07", index="0"} 7000000000
+all_smi_chassis_power_watts{hostname="gpu-07", instance="gpu-07"} 1830.25
+all_smi_chassis_inlet_temperature_celsius{hostname="gpu-07", instance="gpu-07"} 24.5
+all_smi_chassis_fan_speed_rpm{hostname="gpu-07", instance="gpu-07", fan_id="1", fan_name="FAN1"} 7200
+all_smi_chassis_fan_speed_rpm{hostname="gpu-07", instance="gpu-07", fan_id="2", fan_name="FAN2"} 7100
+"#;
+
+        let (_, cpu_info, memory_info, _, _, chassis_info) = parser.parse_metrics(test_data, host);
+
+        assert_eq!(cpu_info.len(), 1, "socket samples belong to CPU 0");
+        let cpu = &cpu_info[0];
+        assert_eq!(cpu.host_id, host);
+        assert_eq!(cpu.hostname, "gpu-07");
+        assert_eq!(cpu.cpu_model, "Intel Xeon Gold 6338");
+        assert_eq!(cpu.architecture, "x86_64");
+        assert_eq!(cpu.platform_type, CpuPlatformType::Intel);
+        assert_eq!(cpu.utilization, 37.5);
+        assert_eq!(cpu.socket_count, 2);
+        assert_eq!(cpu.total_cores, 4);
+        assert_eq!(cpu.total_threads, 8);
+        assert_eq!(cpu.base_frequency_mhz, 2000);
+        assert_eq!(cpu.temperature, Some(61));
+        assert_eq!(cpu.power_consumption, Some(205.5));
+        assert!(cpu.apple_silicon_info.is_none());
+
+        assert_eq!(cpu.per_socket_info.len(), 2);
+        let socket0 = &cpu.per_socket_info[0];
+        assert_eq!(
+            (
+                socket0.socket_id,
+                socket0.utilization,
+                socket0.frequency_mhz
+            ),
+            (0, 40.0, 2100)
+        );
+        assert_eq!(socket0.temperature, Some(63));
+        assert_eq!(cpu.per_socket_info[1].temperature, None);
+
+        // The out-of-range core id is dropped rather than allocated
+        let cores: Vec<f64> = cpu
+            .per_core_utilization
+            .iter()
+            .map(|c| c.utilization)
+            .collect();
+        assert_eq!(cores, vec![90.0, 10.0, 30.0, 20.0]);
+
+        assert_eq!(memory_info.len(), 1);
+        let memory = &memory_info[0];
+        assert_eq!(memory.free_bytes, 300_000_000_000);
+        assert_eq!(memory.swap_total_bytes, 8_000_000_000);
+        assert_eq!(memory.swap_used_bytes, 1_000_000_000);
+        assert_eq!(memory.swap_free_bytes, 7_000_000_000);
+
+        assert_eq!(chassis_info.len(), 1);
+        let chassis = &chassis_info[0];
+        assert_eq!(chassis.host_id, host);
+        assert_eq!(chassis.total_power_watts, Some(1830.25));
+        assert_eq!(chassis.inlet_temperature, Some(24.5));
+        assert_eq!(chassis.outlet_temperature, None);
+        assert_eq!(chassis.fan_speeds.len(), 2);
+        assert_eq!(chassis.fan_speeds[1].name, "FAN2");
+        assert_eq!(chassis.fan_speeds[1].speed_rpm, 7100);
+    }
+
+    #[test]
+    fn test_parse_exporter_apple_silicon_payload() {
+        let parser = create_test_parser();
+        let host = "10.0.0.8:9090";
+
+        let test_data = r#"
+all_smi_cpu_info{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0", architecture="arm64", platform_type="AppleSilicon"} 1
+all_smi_cpu_utilization{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 22
+all_smi_cpu_p_core_count{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 16
+all_smi_cpu_e_core_count{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 8
+all_smi_cpu_gpu_core_count{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 76
+all_smi_cpu_p_core_utilization{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 30
+all_smi_cpu_e_core_utilization{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 12
+all_smi_cpu_ane_ops_per_second{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 1500
+all_smi_cpu_p_cluster_frequency_mhz{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 3204
+all_smi_cpu_e_cluster_frequency_mhz{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", index="0"} 2064
+all_smi_cpu_core_utilization{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", core_id="0", core_type="E"} 12
+all_smi_cpu_core_utilization{cpu_model="Apple M2 Ultra", instance="mac-01", hostname="mac-01", core_id="1", core_type="P"} 30
+all_smi_chassis_thermal_pressure_info{hostname="mac-01", instance="mac-01", level="Nominal"} 1
+all_smi_chassis_cpu_power_watts{hostname="mac-01", instance="mac-01"} 12.50
+"#;
+
+        let (_, cpu_info, _, _, _, chassis_info) = parser.parse_metrics(test_data, host);
+
+        let cpu = &cpu_info[0];
+        assert_eq!(cpu.platform_type, CpuPlatformType::AppleSilicon);
+        assert_eq!(cpu.architecture, "arm64");
+        let apple = cpu.apple_silicon_info.as_ref().unwrap();
+        assert_eq!((apple.p_core_count, apple.e_core_count), (16, 8));
+        assert_eq!(apple.gpu_core_count, 76);
+        assert_eq!(apple.ane_ops_per_second, Some(1500.0));
+        assert_eq!(apple.p_cluster_frequency_mhz, Some(3204));
+        assert_eq!(apple.e_cluster_frequency_mhz, Some(2064));
+        assert_eq!(
+            cpu.per_core_utilization[0].core_type,
+            crate::device::CoreType::Efficiency
+        );
+        assert_eq!(
+            cpu.per_core_utilization[1].core_type,
+            crate::device::CoreType::Performance
+        );
+
+        let chassis = &chassis_info[0];
+        assert_eq!(chassis.thermal_pressure.as_deref(), Some("Nominal"));
+        assert_eq!(
+            chassis.detail.get("cpu_power_watts").map(String::as_str),
+            Some("12.50")
+        );
+    }
+
+    #[test]
+    fn test_parse_host_system_metrics() {
+        let parser = create_test_parser();
+        let host = "10.0.0.9:9090";
+
+        let test_data = r#"
+# HELP all_smi_load_average System load average over the window
+# TYPE all_smi_load_average gauge
+all_smi_load_average{hostname="gpu-09", instance="gpu-09", window="1m"} 12.50
+all_smi_load_average{hostname="gpu-09", instance="gpu-09", window="5m"} 8.25
+all_smi_load_average{hostname="gpu-09", instance="gpu-09", window="15m"} 4.00
+all_smi_load_average{hostname="gpu-09", instance="gpu-09", window="1h"} 99
+all_smi_uptime_seconds{hostname="gpu-09", instance="gpu-09"} 1137600
+all_smi_node_info{hostname="gpu-09", instance="gpu-09", kernel="6.8.0-45-generic", os="Ubuntu 24.04.1 LTS"} 1
+"#;
+
+        let (_, _, _, _, _, chassis_info) = parser.parse_metrics(test_data, host);
+
+        assert_eq!(chassis_info.len(), 1);
+        let chassis = &chassis_info[0];
+        assert_eq!(chassis.host_id, host);
+        assert_eq!(chassis.hostname, "gpu-09");
+        assert_eq!(chassis.total_power_watts, None);
+        assert_eq!(
+            chassis.system,
+            Some(crate::device::HostSystemInfo {
+                load_average: [12.5, 8.25, 4.0],
+                uptime_seconds: 1_137_600,
+                kernel_version: "6.8.0-45-generic".to_string(),
+                os_name: "Ubuntu 24.04.1 LTS".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_node_draining() {
+        let parser = create_test_parser();
+
+        let in_service = r#"
+all_smi_node_draining{hostname="gpu-01",reason=""} 0
+"#;
+        let (_, _, _, _, _, chassis_info) = parser.parse_metrics(in_service, "gpu-01:9090");
+        assert!(chassis_info.is_empty());
+
+        let draining = r#"
+all_smi_uptime_seconds{hostname="gpu-02", instance="gpu-02"} 600
+all_smi_node_draining{hostname="gpu-02",reason="ALL_SMI_DRAINING is set"} 1
+"#;
+        let (_, _, _, _, _, chassis_info) = parser.parse_metrics(draining, "gpu-02:9090");
+        assert_eq!(chassis_info.len(), 1);
+        assert!(chassis_info[0].draining);
+    }
 }
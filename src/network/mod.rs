@@ -13,6 +13,11 @@
 // limitations under the License.
 
 pub mod client;
+pub mod infiniband;
+pub mod interfaces;
 pub mod metrics_parser;
+pub mod ssh;
 
 pub use client::NetworkClient;
+pub use infiniband::IbPortInfo;
+pub use interfaces::{NetworkInfo, NetworkReader};
@@ -0,0 +1,315 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scraping nodes over SSH, for hosts that do not run the API server.
+//!
+//! Each host is an SSH destination, `[user@]host[:port]`. The system `ssh`
+//! runs `all-smi api --once` there, or nvidia-smi where all-smi is missing,
+//! so authentication follows the user's agent, keys and ssh config. On Unix a
+//! control master per host keeps the connection open between refreshes.
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use tokio::process::Command;
+
+use super::client::RemoteData;
+use super::metrics_parser::MetricsParser;
+use crate::app_state::ConnectionStatus;
+use crate::device::readers::nvidia::{parse_nvidia_smi_gpus, NVIDIA_SMI_GPU_QUERY};
+
+/// Longest a single scrape may take, including the SSH handshake
+const SCRAPE_TIMEOUT: Duration = Duration::from_secs(15);
+/// Seconds an idle control master stays up after the last scrape
+#[cfg(unix)]
+const CONTROL_PERSIST_SECS: u32 = 60;
+
+/// How to reach and scrape SSH hosts.
+#[derive(Clone, Debug)]
+pub struct SshOptions {
+    /// Key passed with `-i`, on top of the agent and ssh config
+    pub identity: Option<PathBuf>,
+    /// all-smi on the remote hosts
+    pub remote_command: String,
+}
+
+impl Default for SshOptions {
+    fn default() -> Self {
+        Self {
+            identity: None,
+            remote_command: "all-smi".to_string(),
+        }
+    }
+}
+
+pub struct SshClient {
+    options: SshOptions,
+    /// Holds the control master sockets; `None` when it could not be created
+    #[cfg(unix)]
+    control_dir: Option<PathBuf>,
+}
+
+/// Split `[user@]host[:port]` into the destination and port. IPv6 literals,
+/// which contain several colons, are taken as they are.
+pub fn split_destination(host: &str) -> (&str, Option<&str>) {
+    match host.rsplit_once(':') {
+        Some((destination, port)) if !destination.contains(':') && port.parse::<u16>().is_ok() => {
+            (destination, Some(port))
+        }
+        _ => (host, None),
+    }
+}
+
+/// Host name of `[user@]host[:port]`, for nodes whose output carries none
+fn host_name(host: &str) -> &str {
+    let (destination, _) = split_destination(host);
+    destination
+        .rsplit_once('@')
+        .map_or(destination, |(_, name)| name)
+}
+
+impl SshClient {
+    pub fn new(options: SshOptions) -> Self {
+        Self {
+            options,
+            #[cfg(unix)]
+            control_dir: create_control_dir(),
+        }
+    }
+
+    /// The command run on every host: all-smi if it is installed, nvidia-smi
+    /// otherwise
+    fn remote_command(&self) -> String {
+        format!(
+            "{} api --once 2>/dev/null || nvidia-smi --query-gpu={NVIDIA_SMI_GPU_QUERY} --format=csv,noheader,nounits",
+            self.options.remote_command
+        )
+    }
+
+    fn command(&self, host: &str) -> Command {
+        let (destination, port) = split_destination(host);
+        let mut command = Command::new("ssh");
+        // Never prompt: a host that needs a password fails instead of
+        // stalling the refresh
+        command.args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=5"]);
+        #[cfg(unix)]
+        if let Some(dir) = &self.control_dir {
+            command
+                .args(["-o", "ControlMaster=auto"])
+                .arg("-o")
+                .arg(format!("ControlPath={}/%C", dir.display()))
+                .arg("-o")
+                .arg(format!("ControlPersist={CONTROL_PERSIST_SECS}"));
+        }
+        if let Some(identity) = &self.options.identity {
+            command.arg("-i").arg(identity);
+        }
+        if let Some(port) = port {
+            command.args(["-p", port]);
+        }
+        command
+            .arg("--")
+            .arg(destination)
+            .arg(self.remote_command())
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+        command
+    }
+
+    /// Run the scrape command on `host`, returning its standard output
+    async fn scrape(&self, host: &str) -> Result<String, String> {
+        if host.starts_with('-') {
+            return Err(format!("Invalid SSH destination: {host}"));
+        }
+        let output = tokio::time::timeout(SCRAPE_TIMEOUT, self.command(host).output())
+            .await
+            .map_err(|_| format!("Timed out after {}s", SCRAPE_TIMEOUT.as_secs()))?
+            .map_err(|e| format!("Cannot run ssh: {e}"))?;
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        if output.status.success() && !stdout.trim().is_empty() {
+            return Ok(stdout);
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(
+            match stderr.lines().rev().find(|line| !line.trim().is_empty()) {
+                Some(line) => line.trim().to_string(),
+                None => format!("ssh exited with {}", output.status),
+            },
+        )
+    }
+
+    /// Scrape every host, at most as many at once as `semaphore` allows,
+    /// keeping the `process_top` processes of each using the most GPU memory.
+    pub async fn fetch_remote_data(
+        self: &Arc<Self>,
+        hosts: &[String],
+        semaphore: &Arc<tokio::sync::Semaphore>,
+        process_top: usize,
+    ) -> RemoteData {
+        let mut fetches: FuturesUnordered<_> = hosts
+            .iter()
+            .map(|host| {
+                let client = Arc::clone(self);
+                let semaphore = semaphore.clone();
+                let host = host.clone();
+                tokio::spawn(async move {
+                    let _permit = semaphore.acquire().await;
+                    let result = client.scrape(&host).await;
+                    (host, result)
+                })
+            })
+            .collect();
+
+        let mut data = RemoteData::default();
+        while let Some(result) = fetches.next().await {
+            let Ok((host, result)) = result else {
+                continue;
+            };
+            let mut status = ConnectionStatus::new(host.clone(), host.clone());
+            match result {
+                Ok(output) => {
+                    status.mark_success();
+                    record_output(&mut data, &mut status, &host, &output, process_top);
+                }
+                Err(e) => status.mark_failure(e),
+            }
+            data.6.push(status);
+        }
+        data
+    }
+}
+
+/// Add the devices and processes in one host's scrape output to `data`
+fn record_output(
+    data: &mut RemoteData,
+    status: &mut ConnectionStatus,
+    host: &str,
+    output: &str,
+    process_top: usize,
+) {
+    if output.contains("all_smi_") {
+        let parser = MetricsParser::new();
+        let (gpu_info, cpu_info, memory_info, storage_info, network_info, chassis_info) =
+            parser.parse_metrics(output, host);
+        let processes = parser.parse_processes(output, host, process_top);
+        status.processes_omitted = processes.omitted;
+        status.actual_hostname = gpu_info
+            .first()
+            .map(|gpu| gpu.instance.clone())
+            .or_else(|| cpu_info.first().map(|cpu| cpu.instance.clone()))
+            .or_else(|| memory_info.first().map(|memory| memory.instance.clone()));
+        data.0.extend(gpu_info);
+        data.1.extend(cpu_info);
+        data.2.extend(memory_info);
+        data.3.extend(storage_info);
+        data.4.extend(network_info);
+        data.5.extend(chassis_info);
+        data.7.extend(processes.processes);
+    } else {
+        let name = host_name(host);
+        let mut gpu_info = parse_nvidia_smi_gpus(output, name);
+        for gpu in &mut gpu_info {
+            gpu.host_id = host.to_string();
+        }
+        if !gpu_info.is_empty() {
+            status.actual_hostname = Some(name.to_string());
+        }
+        data.0.extend(gpu_info);
+    }
+}
+
+#[cfg(unix)]
+impl Drop for SshClient {
+    fn drop(&mut self) {
+        if let Some(dir) = &self.control_dir {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+/// A private directory for the control master sockets
+#[cfg(unix)]
+fn create_control_dir() -> Option<PathBuf> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    let dir = std::env::temp_dir().join(format!("all-smi-ssh-{}", std::process::id()));
+    match std::fs::DirBuilder::new().mode(0o700).create(&dir) {
+        Ok(()) => Some(dir),
+        Err(e) => {
+            tracing::warn!(
+                "Cannot create {}: {e}; SSH connections will not be reused",
+                dir.display()
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_destination() {
+        assert_eq!(split_destination("gpu1"), ("gpu1", None));
+        assert_eq!(
+            split_destination("ops@gpu1:2222"),
+            ("ops@gpu1", Some("2222"))
+        );
+        assert_eq!(split_destination("ops@gpu1:ssh"), ("ops@gpu1:ssh", None));
+        assert_eq!(split_destination("fe80::1"), ("fe80::1", None));
+        assert_eq!(host_name("ops@gpu1:2222"), "gpu1");
+        assert_eq!(host_name("gpu1"), "gpu1");
+    }
+
+    #[test]
+    fn test_record_all_smi_output() {
+        let output = "\
+# HELP all_smi_gpu_utilization GPU utilization percentage
+all_smi_gpu_utilization{gpu=\"H100\", instance=\"node1\", uuid=\"GPU-a\", index=\"0\"} 42
+all_smi_cpu_utilization{cpu_model=\"Xeon\", instance=\"node1\", hostname=\"node1\", index=\"0\"} 10
+";
+        let mut data = RemoteData::default();
+        let mut status = ConnectionStatus::new("ops@10.0.0.1".into(), "ops@10.0.0.1".into());
+        record_output(&mut data, &mut status, "ops@10.0.0.1", output, 50);
+
+        assert_eq!(data.0.len(), 1);
+        assert_eq!(data.0[0].host_id, "ops@10.0.0.1");
+        assert_eq!(data.0[0].utilization, 42.0);
+        assert_eq!(data.1.len(), 1);
+        assert_eq!(status.actual_hostname.as_deref(), Some("node1"));
+    }
+
+    #[test]
+    fn test_record_nvidia_smi_output() {
+        let output = "0, GPU-a, NVIDIA A100, 87, 61, 30000, 81920, 1410, 250.5, 1410, 1593, 1275\n\
+                      1, GPU-b, NVIDIA A100, [N/A], 40, 0, 81920, 210, 60.1, 210, 1593, 795\n";
+        let mut data = RemoteData::default();
+        let mut status = ConnectionStatus::new("gpu2:2222".into(), "gpu2:2222".into());
+        record_output(&mut data, &mut status, "gpu2:2222", output, 50);
+
+        assert_eq!(data.0.len(), 2);
+        let gpu = &data.0[0];
+        assert_eq!(gpu.host_id, "gpu2:2222");
+        assert_eq!(gpu.instance, "gpu2");
+        assert_eq!(gpu.utilization, 87.0);
+        assert_eq!(gpu.total_memory, 81920 * 1024 * 1024);
+        assert_eq!(gpu.detail["clock_memory_current"], "1593");
+        assert_eq!(data.0[1].utilization, 0.0);
+        assert_eq!(status.actual_hostname.as_deref(), Some("gpu2"));
+    }
+}
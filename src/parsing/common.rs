@@ -48,21 +48,6 @@ pub fn to_bytes(value: f64, unit: &str) -> Option<u64> {
     }
 }
 
-/// Sanitize a quoted label/value by trimming whitespace and removing surrounding double quotes.
-pub fn sanitize_label_value(s: &str) -> String {
-    const MAX_LABEL_VALUE_LENGTH: usize = 1024;
-
-    let trimmed = s.trim();
-    let cleaned = trimmed.trim_matches('"');
-
-    // Truncate excessively long values to prevent memory exhaustion
-    if cleaned.len() > MAX_LABEL_VALUE_LENGTH {
-        cleaned[..MAX_LABEL_VALUE_LENGTH].to_string()
-    } else {
-        cleaned.to_string()
-    }
-}
-
 /// Sanitize a label name for Prometheus compatibility.
 /// Converts spaces to underscores and makes lowercase.
 /// Prometheus label names must match: [a-zA-Z_][a-zA-Z0-9_]*
@@ -99,12 +84,6 @@ mod tests {
         assert_eq!(to_bytes(1.0, "unknown"), None);
     }
 
-    #[test]
-    fn test_sanitize_label_value() {
-        assert_eq!(sanitize_label_value(r#" "hello" "#), "hello".to_string());
-        assert_eq!(sanitize_label_value("world"), "world".to_string());
-    }
-
     #[test]
     fn test_sanitize_label_name() {
         assert_eq!(sanitize_label_name("Driver Version"), "driver_version");
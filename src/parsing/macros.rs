@@ -90,7 +90,7 @@ macro_rules! parse_prometheus {
 macro_rules! extract_label_to_detail {
     ($labels:expr, $label_key:expr, $detail_map:expr, $detail_key:expr) => {
         if let Some(value) = $labels.get($label_key) {
-            $detail_map.insert($detail_key.to_string(), value.clone());
+            $detail_map.insert($detail_key.to_string(), value.to_string());
         }
     };
     // Variant that uses the same key for both label and detail
@@ -115,7 +115,7 @@ macro_rules! extract_labels_batch {
     ($labels:expr, $detail_map:expr, [$($key:expr),* $(,)?]) => {
         $(
             if let Some(value) = $labels.get($key) {
-                $detail_map.insert($key.to_string(), value.clone());
+                $detail_map.insert($key.to_string(), value.to_string());
             }
         )*
     };
@@ -169,11 +169,7 @@ macro_rules! update_metric_field {
 #[macro_export]
 macro_rules! get_label_or_default {
     ($labels:expr, $key:expr) => {
-        $labels
-            .get($key)
-            .map(|s| s.as_str())
-            .unwrap_or("")
-            .to_string()
+        $labels.get($key).map(|s| s.to_string()).unwrap_or_default()
     };
     ($labels:expr, $key:expr, $default:expr) => {
         $labels
@@ -17,3 +17,4 @@
 pub mod common;
 #[macro_use]
 pub mod macros;
+pub mod prometheus;
@@ -0,0 +1,289 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streaming parser for the Prometheus text exposition format.
+//!
+//! Lines are split into metric name, label pairs and value in a single pass
+//! without allocating: names, label keys and unescaped label values are
+//! borrowed from the input. Only label values that contain escape sequences
+//! are copied. Labels are written into a caller-owned [`LabelMap`] so the
+//! same map can be cleared and reused for every line of a scrape.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Scratch map of label key to label value, borrowed from the scrape text.
+pub type LabelMap<'a> = HashMap<&'a str, Cow<'a, str>>;
+
+/// Maximum metric name length accepted.
+const MAX_NAME_LENGTH: usize = 256;
+/// Maximum length of the label block between `{` and `}`.
+const MAX_LABELS_LENGTH: usize = 1024;
+/// Maximum number of labels kept per sample.
+const MAX_LABELS: usize = 100;
+/// Label values longer than this are truncated.
+const MAX_LABEL_VALUE_LENGTH: usize = 1024;
+
+/// A single sample line: metric name and value. Labels live in the
+/// [`LabelMap`] passed to [`parse_line`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample<'a> {
+    pub name: &'a str,
+    pub value: f64,
+}
+
+/// Parse one line of Prometheus text format.
+///
+/// `labels` is cleared and filled with the sample's labels. Returns `None` for
+/// blank lines, `#` comments, lines exceeding the size limits, and anything
+/// malformed (unterminated quotes, missing or non-finite value). An optional
+/// trailing timestamp is accepted and ignored.
+pub fn parse_line<'a>(line: &'a str, labels: &mut LabelMap<'a>) -> Option<Sample<'a>> {
+    labels.clear();
+
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let bytes = line.as_bytes();
+    let name_end = bytes
+        .iter()
+        .position(|&b| b == b'{' || b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let name = &line[..name_end];
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return None;
+    }
+
+    let mut rest = line[name_end..].trim_start();
+    if let Some(block) = rest.strip_prefix('{') {
+        let consumed = parse_label_block(block, labels)?;
+        rest = &block[consumed..];
+    }
+
+    let mut fields = rest.split_ascii_whitespace();
+    let value = parse_value(fields.next()?)?;
+    // Optional timestamp; anything after it is malformed.
+    if let Some(timestamp) = fields.next() {
+        timestamp.parse::<i64>().ok()?;
+    }
+    if fields.next().is_some() {
+        return None;
+    }
+
+    Some(Sample { name, value })
+}
+
+/// Parse the label block following `{` and return the number of bytes
+/// consumed, including the closing `}`.
+fn parse_label_block<'a>(block: &'a str, labels: &mut LabelMap<'a>) -> Option<usize> {
+    let bytes = block.as_bytes();
+    let mut pos = 0;
+
+    loop {
+        pos = skip_separators(bytes, pos);
+        if pos > MAX_LABELS_LENGTH {
+            return None;
+        }
+        if *bytes.get(pos)? == b'}' {
+            return Some(pos + 1);
+        }
+
+        let key_start = pos;
+        while pos < bytes.len() && bytes[pos] != b'=' && bytes[pos] != b'}' {
+            pos += 1;
+        }
+        if bytes.get(pos) != Some(&b'=') {
+            return None;
+        }
+        let key = block[key_start..pos].trim().trim_matches('"');
+        pos += 1;
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+
+        let value = if bytes.get(pos) == Some(&b'"') {
+            let (value, end) = parse_quoted(block, pos + 1)?;
+            pos = end;
+            value
+        } else {
+            // Tolerate unquoted values from non-conforming exporters.
+            let value_start = pos;
+            while pos < bytes.len() && bytes[pos] != b',' && bytes[pos] != b'}' {
+                pos += 1;
+            }
+            Cow::Borrowed(block[value_start..pos].trim())
+        };
+
+        if pos > MAX_LABELS_LENGTH {
+            return None;
+        }
+        if !key.is_empty() && labels.len() < MAX_LABELS {
+            labels.insert(key, truncate_value(value));
+        }
+    }
+}
+
+/// Parse a quoted label value starting just after the opening quote.
+/// Returns the unescaped value and the position after the closing quote.
+fn parse_quoted(block: &str, start: usize) -> Option<(Cow<'_, str>, usize)> {
+    let bytes = block.as_bytes();
+    let mut pos = start;
+
+    // Fast path: no escapes, borrow the slice directly.
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'"' => return Some((Cow::Borrowed(&block[start..pos]), pos + 1)),
+            b'\\' => break,
+            _ => pos += 1,
+        }
+    }
+
+    let mut owned = String::with_capacity(pos - start + 16);
+    owned.push_str(&block[start..pos]);
+    let mut chars = block[pos..].char_indices();
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            '"' => return Some((Cow::Owned(owned), pos + offset + 1)),
+            '\\' => match chars.next()?.1 {
+                'n' => owned.push('\n'),
+                't' => owned.push('\t'),
+                escaped => owned.push(escaped),
+            },
+            _ => owned.push(ch),
+        }
+    }
+    None
+}
+
+fn skip_separators(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && (bytes[pos] == b',' || bytes[pos].is_ascii_whitespace()) {
+        pos += 1;
+    }
+    pos
+}
+
+fn parse_value(token: &str) -> Option<f64> {
+    token.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn truncate_value(value: Cow<'_, str>) -> Cow<'_, str> {
+    if value.len() <= MAX_LABEL_VALUE_LENGTH {
+        return value;
+    }
+    let mut end = MAX_LABEL_VALUE_LENGTH;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    match value {
+        Cow::Borrowed(s) => Cow::Borrowed(&s[..end]),
+        Cow::Owned(mut s) => {
+            s.truncate(end);
+            Cow::Owned(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Option<(Sample<'_>, LabelMap<'_>)> {
+        let mut labels = LabelMap::new();
+        parse_line(line, &mut labels).map(|sample| (sample, labels))
+    }
+
+    #[test]
+    fn test_parse_basic_line() {
+        let (sample, labels) =
+            parse(r#"all_smi_gpu_utilization{gpu="RTX", uuid="GPU-1"} 25.5"#).unwrap();
+        assert_eq!(sample.name, "all_smi_gpu_utilization");
+        assert_eq!(sample.value, 25.5);
+        assert_eq!(labels.get("gpu").map(|v| v.as_ref()), Some("RTX"));
+        assert_eq!(labels.get("uuid").map(|v| v.as_ref()), Some("GPU-1"));
+        assert!(matches!(labels.get("gpu"), Some(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_parse_whitespace_variants() {
+        let (sample, labels) =
+            parse("  all_smi_x { a = \"1\" ,b=\"2\", }\t42   1700000000000 ").unwrap();
+        assert_eq!(sample.name, "all_smi_x");
+        assert_eq!(sample.value, 42.0);
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels.get("a").map(|v| v.as_ref()), Some("1"));
+        assert_eq!(labels.get("b").map(|v| v.as_ref()), Some("2"));
+
+        let (sample, labels) = parse("all_smi_y 1e3").unwrap();
+        assert_eq!(sample.name, "all_smi_y");
+        assert_eq!(sample.value, 1000.0);
+        assert!(labels.is_empty());
+    }
+
+    #[test]
+    fn test_parse_escaped_label_values() {
+        let (_, labels) =
+            parse(r#"m{model="Foo \"Bar\", Inc.", path="C:\\gpu", note="a\nb"} 1"#).unwrap();
+        assert_eq!(
+            labels.get("model").map(|v| v.as_ref()),
+            Some(r#"Foo "Bar", Inc."#)
+        );
+        assert_eq!(labels.get("path").map(|v| v.as_ref()), Some(r"C:\gpu"));
+        assert_eq!(labels.get("note").map(|v| v.as_ref()), Some("a\nb"));
+    }
+
+    #[test]
+    fn test_parse_brace_inside_quotes() {
+        let (sample, labels) = parse(r#"m{name="a}b"} 7"#).unwrap();
+        assert_eq!(sample.value, 7.0);
+        assert_eq!(labels.get("name").map(|v| v.as_ref()), Some("a}b"));
+    }
+
+    #[test]
+    fn test_skips_comments_and_malformed_lines() {
+        assert!(parse("").is_none());
+        assert!(parse("   ").is_none());
+        assert!(parse("# HELP all_smi_gpu_utilization GPU utilization").is_none());
+        assert!(parse("# TYPE all_smi_gpu_utilization gauge").is_none());
+        assert!(parse("bad format").is_none());
+        assert!(parse(r#"m{a="unterminated} 1"#).is_none());
+        assert!(parse(r#"m{a="1"} "#).is_none());
+        assert!(parse(r#"m{a="1"} NaN"#).is_none());
+        assert!(parse(r#"m{a="1"} 1 2 3"#).is_none());
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let long_name = "a".repeat(MAX_NAME_LENGTH + 1);
+        assert!(parse(&format!("{long_name} 1")).is_none());
+
+        let long_labels = format!(r#"m{{a="{}"}} 1"#, "x".repeat(MAX_LABELS_LENGTH + 1));
+        assert!(parse(&long_labels).is_none());
+    }
+
+    #[test]
+    fn test_label_map_is_reused() {
+        let text = "a{x=\"1\"} 1\nb{y=\"2\"} 2";
+        let mut labels = LabelMap::new();
+        let mut lines = text.lines();
+
+        parse_line(lines.next().unwrap(), &mut labels).unwrap();
+        assert!(labels.contains_key("x"));
+
+        parse_line(lines.next().unwrap(), &mut labels).unwrap();
+        assert!(!labels.contains_key("x"));
+        assert!(labels.contains_key("y"));
+    }
+}
@@ -0,0 +1,408 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Health of the drives behind mounted filesystems (Linux).
+//!
+//! A mount's source device is mapped to whole-disk block devices through
+//! sysfs, looking through one level of LVM (device-mapper) or md-raid.
+//! Temperatures come from the drive's hwmon node: NVMe controllers always
+//! have one, SATA disks with the `drivetemp` module. Wear, spare capacity,
+//! reallocated sectors and the overall SMART verdict are only in the SMART
+//! logs, read with `smartctl --json` when it is installed and permitted.
+//! Anything that cannot be read is left unknown.
+
+use serde::{Deserialize, Serialize};
+
+/// Health of the drives backing one mount. With several drives (RAID, or a
+/// volume group spanning disks) each field holds the worst of them.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct DiskHealth {
+    /// Whole-disk devices backing the mount, e.g. `nvme0n1`
+    pub devices: Vec<String>,
+    pub temperature_celsius: Option<u32>,
+    /// NVMe endurance used, in percent; may exceed 100
+    pub percentage_used: Option<u32>,
+    /// NVMe spare capacity left, in percent
+    pub available_spare_percent: Option<u32>,
+    /// SATA reallocated sector count (SMART attribute 5)
+    pub reallocated_sectors: Option<u64>,
+    /// Overall SMART self-assessment
+    pub health_ok: Option<bool>,
+}
+
+impl DiskHealth {
+    /// Fold another drive's health into this one, keeping the worst values.
+    fn merge(&mut self, other: DiskHealth) {
+        fn worst<T: Ord>(a: Option<T>, b: Option<T>, pick: fn(T, T) -> T) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(pick(a, b)),
+                (a, b) => a.or(b),
+            }
+        }
+        self.devices.extend(other.devices);
+        self.temperature_celsius = worst(
+            self.temperature_celsius,
+            other.temperature_celsius,
+            Ord::max,
+        );
+        self.percentage_used = worst(self.percentage_used, other.percentage_used, Ord::max);
+        self.available_spare_percent = worst(
+            self.available_spare_percent,
+            other.available_spare_percent,
+            Ord::min,
+        );
+        self.reallocated_sectors = worst(
+            self.reallocated_sectors,
+            other.reallocated_sectors,
+            Ord::max,
+        );
+        self.health_ok = worst(self.health_ok, other.health_ok, Ord::min);
+    }
+}
+
+/// Health of the drives behind the mount whose source device is `source`,
+/// e.g. `/dev/nvme0n1p2` or `/dev/mapper/vg-root`.
+#[cfg(target_os = "linux")]
+pub fn disk_health(source: &str) -> DiskHealth {
+    linux::disk_health(source)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn disk_health(_source: &str) -> DiskHealth {
+    DiskHealth::default()
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    use once_cell::sync::Lazy;
+    use serde_json::Value;
+
+    use super::DiskHealth;
+    use crate::device::common::execute_command_default;
+
+    const SYS_CLASS_BLOCK: &str = "/sys/class/block";
+
+    /// SMART data changes slowly and smartctl is slow, so each drive is
+    /// queried at most this often.
+    const SMART_REFRESH: Duration = Duration::from_secs(60);
+
+    /// Set once smartctl cannot be run at all
+    static SMARTCTL_MISSING: AtomicBool = AtomicBool::new(false);
+
+    type SmartCache = Mutex<HashMap<String, (Instant, DiskHealth)>>;
+    static SMART_CACHE: Lazy<SmartCache> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+    pub(super) fn disk_health(source: &str) -> DiskHealth {
+        let Some(name) = block_device_name(source) else {
+            return DiskHealth::default();
+        };
+        let class_block = Path::new(SYS_CLASS_BLOCK);
+        let mut health = DiskHealth::default();
+        for disk in physical_disks(class_block, &name) {
+            let mut drive = smart_health(&disk);
+            if let Some(temperature) = hwmon_temperature(class_block, &disk) {
+                drive.temperature_celsius = Some(temperature);
+            }
+            drive.devices = vec![disk];
+            health.merge(drive);
+        }
+        health
+    }
+
+    /// Kernel name of the block device behind `source`, following
+    /// `/dev/mapper` and `/dev/disk/by-*` links. `None` for sources that
+    /// are not device nodes (tmpfs, overlay, network filesystems).
+    fn block_device_name(source: &str) -> Option<String> {
+        let path = Path::new(source);
+        if !path.starts_with("/dev") {
+            return None;
+        }
+        let resolved = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        Some(resolved.file_name()?.to_string_lossy().into_owned())
+    }
+
+    /// Whole disks holding block device `name`. Partitions map to their
+    /// disk; device-mapper and md devices map to the disks of their
+    /// members. Members that are themselves virtual (LVM on md-raid) are
+    /// not followed further.
+    pub(super) fn physical_disks(class_block: &Path, name: &str) -> Vec<String> {
+        let members = slaves(class_block, name);
+        let mut disks: Vec<String> = if members.is_empty() {
+            whole_disk(class_block, name).into_iter().collect()
+        } else {
+            members
+                .iter()
+                .filter(|member| slaves(class_block, member).is_empty())
+                .filter_map(|member| whole_disk(class_block, member))
+                .collect()
+        };
+        disks.sort();
+        disks.dedup();
+        disks
+    }
+
+    fn slaves(class_block: &Path, name: &str) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(class_block.join(name).join("slaves")) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// The disk a partition belongs to, or `name` itself when it is not a
+    /// partition. Partitions sit in their disk's directory under
+    /// `/sys/devices`.
+    fn whole_disk(class_block: &Path, name: &str) -> Option<String> {
+        let dir = class_block.join(name);
+        if !dir.join("partition").exists() {
+            return dir.exists().then(|| name.to_string());
+        }
+        let device_dir = std::fs::canonicalize(&dir).ok()?;
+        Some(
+            device_dir
+                .parent()?
+                .file_name()?
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+
+    /// Drive temperature from hwmon: `device/hwmonN` for NVMe controllers,
+    /// `device/hwmon/hwmonN` for SATA disks with `drivetemp`.
+    pub(super) fn hwmon_temperature(class_block: &Path, disk: &str) -> Option<u32> {
+        let device = class_block.join(disk).join("device");
+        let candidates = [device.clone(), device.join("hwmon")];
+        candidates
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flat_map(|entries| entries.flatten())
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("hwmon"))
+            .map(|entry| entry.path().join("temp1_input"))
+            .find_map(|path| read_millidegrees(&path))
+    }
+
+    fn read_millidegrees(path: &PathBuf) -> Option<u32> {
+        let millidegrees: i64 = std::fs::read_to_string(path).ok()?.trim().parse().ok()?;
+        u32::try_from((millidegrees + 500) / 1000).ok()
+    }
+
+    /// SMART health of `disk` from smartctl, cached for [`SMART_REFRESH`].
+    fn smart_health(disk: &str) -> DiskHealth {
+        if SMARTCTL_MISSING.load(Ordering::Relaxed) {
+            return DiskHealth::default();
+        }
+        if let Ok(cache) = SMART_CACHE.lock() {
+            if let Some((read_at, health)) = cache.get(disk) {
+                if read_at.elapsed() < SMART_REFRESH {
+                    return health.clone();
+                }
+            }
+        }
+
+        let device = format!("/dev/{disk}");
+        let health = match execute_command_default("smartctl", &["--json", "-H", "-A", &device]) {
+            Ok(output) => parse_smartctl(&output.stdout),
+            Err(e) => {
+                tracing::debug!("smartctl unavailable, skipping SMART health: {e}");
+                SMARTCTL_MISSING.store(true, Ordering::Relaxed);
+                DiskHealth::default()
+            }
+        };
+        if let Ok(mut cache) = SMART_CACHE.lock() {
+            cache.insert(disk.to_string(), (Instant::now(), health.clone()));
+        }
+        health
+    }
+
+    /// Fields of `smartctl --json -H -A` output. Without permission to
+    /// open the device smartctl still prints JSON, just without these
+    /// sections, so everything stays unknown.
+    pub(super) fn parse_smartctl(json: &str) -> DiskHealth {
+        let Ok(root) = serde_json::from_str::<Value>(json) else {
+            return DiskHealth::default();
+        };
+        let u32_at = |pointer: &str| {
+            root.pointer(pointer)
+                .and_then(Value::as_u64)
+                .and_then(|v| u32::try_from(v).ok())
+        };
+        let reallocated_sectors = root
+            .pointer("/ata_smart_attributes/table")
+            .and_then(Value::as_array)
+            .and_then(|table| {
+                table
+                    .iter()
+                    .find(|attr| attr.get("id").and_then(Value::as_u64) == Some(5))
+            })
+            .and_then(|attr| attr.pointer("/raw/value"))
+            .and_then(Value::as_u64);
+
+        DiskHealth {
+            devices: Vec::new(),
+            temperature_celsius: u32_at("/temperature/current"),
+            percentage_used: u32_at("/nvme_smart_health_information_log/percentage_used"),
+            available_spare_percent: u32_at("/nvme_smart_health_information_log/available_spare"),
+            reallocated_sectors,
+            health_ok: root
+                .pointer("/smart_status/passed")
+                .and_then(Value::as_bool),
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::linux::{hwmon_temperature, parse_smartctl, physical_disks};
+    use super::*;
+    use std::fs;
+    use std::os::unix::fs::symlink;
+    use std::path::Path;
+
+    /// A sysfs-like tree: `devices/...` holds the device directories and
+    /// `class/block/<name>` links to them.
+    fn add_device(root: &Path, device_path: &str, slaves: &[&str], partition: bool) {
+        let dir = root.join("devices").join(device_path);
+        fs::create_dir_all(&dir).unwrap();
+        if partition {
+            fs::write(dir.join("partition"), "1\n").unwrap();
+        }
+        if !slaves.is_empty() {
+            fs::create_dir_all(dir.join("slaves")).unwrap();
+            for slave in slaves {
+                fs::write(dir.join("slaves").join(slave), "").unwrap();
+            }
+        }
+        let name = Path::new(device_path).file_name().unwrap();
+        fs::create_dir_all(root.join("class/block")).unwrap();
+        symlink(&dir, root.join("class/block").join(name)).unwrap();
+    }
+
+    #[test]
+    fn test_physical_disks() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        add_device(root, "pci/nvme0/nvme0n1", &[], false);
+        add_device(root, "pci/nvme0/nvme0n1/nvme0n1p2", &[], true);
+        add_device(root, "pci/nvme0/nvme0n1/nvme0n1p3", &[], true);
+        add_device(root, "ata1/sda", &[], false);
+        add_device(root, "ata1/sda/sda1", &[], true);
+        add_device(root, "ata2/sdb", &[], false);
+        add_device(root, "ata2/sdb/sdb1", &[], true);
+        add_device(root, "virtual/block/md0", &["sdb1", "sda1"], false);
+        add_device(root, "virtual/block/dm-0", &["nvme0n1p3"], false);
+        add_device(root, "virtual/block/dm-1", &["md0"], false);
+        let class_block = root.join("class/block");
+
+        let disks = |name: &str| physical_disks(&class_block, name);
+        assert_eq!(disks("nvme0n1p2"), vec!["nvme0n1"]);
+        assert_eq!(disks("nvme0n1"), vec!["nvme0n1"]);
+        // LVM volume on a partition
+        assert_eq!(disks("dm-0"), vec!["nvme0n1"]);
+        // md-raid1 across two disks
+        assert_eq!(disks("md0"), vec!["sda", "sdb"]);
+        // LVM on md-raid is two levels deep
+        assert!(disks("dm-1").is_empty());
+        assert!(disks("loop7").is_empty());
+    }
+
+    #[test]
+    fn test_hwmon_temperature() {
+        let root = tempfile::tempdir().unwrap();
+        let class_block = root.path();
+        // NVMe: hwmon directly under the controller
+        let nvme = class_block.join("nvme0n1/device/hwmon2");
+        fs::create_dir_all(&nvme).unwrap();
+        fs::write(nvme.join("temp1_input"), "38850\n").unwrap();
+        // SATA with drivetemp: hwmon/hwmonN
+        let sata = class_block.join("sda/device/hwmon/hwmon4");
+        fs::create_dir_all(&sata).unwrap();
+        fs::write(sata.join("temp1_input"), "31000\n").unwrap();
+
+        assert_eq!(hwmon_temperature(class_block, "nvme0n1"), Some(39));
+        assert_eq!(hwmon_temperature(class_block, "sda"), Some(31));
+        assert_eq!(hwmon_temperature(class_block, "sdb"), None);
+    }
+
+    #[test]
+    fn test_parse_smartctl() {
+        let nvme = r#"{
+            "smart_status": {"passed": true},
+            "temperature": {"current": 41},
+            "nvme_smart_health_information_log": {
+                "critical_warning": 0, "temperature": 41,
+                "available_spare": 100, "available_spare_threshold": 10,
+                "percentage_used": 3
+            }
+        }"#;
+        let health = parse_smartctl(nvme);
+        assert_eq!(health.health_ok, Some(true));
+        assert_eq!(health.temperature_celsius, Some(41));
+        assert_eq!(health.percentage_used, Some(3));
+        assert_eq!(health.available_spare_percent, Some(100));
+        assert_eq!(health.reallocated_sectors, None);
+
+        let sata = r#"{
+            "smart_status": {"passed": false},
+            "temperature": {"current": 35},
+            "ata_smart_attributes": {"table": [
+                {"id": 1, "name": "Raw_Read_Error_Rate", "raw": {"value": 0}},
+                {"id": 5, "name": "Reallocated_Sector_Ct", "raw": {"value": 24}}
+            ]}
+        }"#;
+        let health = parse_smartctl(sata);
+        assert_eq!(health.health_ok, Some(false));
+        assert_eq!(health.reallocated_sectors, Some(24));
+        assert_eq!(health.percentage_used, None);
+
+        // No permission: smartctl reports the error without SMART data
+        let denied = r#"{"smartctl": {"exit_status": 2, "messages": [
+            {"string": "Smartctl open device: /dev/sda failed: Permission denied", "severity": "error"}
+        ]}}"#;
+        assert_eq!(parse_smartctl(denied), DiskHealth::default());
+        assert_eq!(parse_smartctl("not json"), DiskHealth::default());
+    }
+
+    #[test]
+    fn test_merge_keeps_worst() {
+        let mut health = DiskHealth {
+            devices: vec!["sda".to_string()],
+            temperature_celsius: Some(35),
+            available_spare_percent: Some(90),
+            health_ok: Some(true),
+            ..Default::default()
+        };
+        health.merge(DiskHealth {
+            devices: vec!["sdb".to_string()],
+            temperature_celsius: Some(48),
+            available_spare_percent: Some(40),
+            reallocated_sectors: Some(8),
+            health_ok: Some(false),
+            ..Default::default()
+        });
+        assert_eq!(health.devices, vec!["sda", "sdb"]);
+        assert_eq!(health.temperature_celsius, Some(48));
+        assert_eq!(health.available_spare_percent, Some(40));
+        assert_eq!(health.reallocated_sectors, Some(8));
+        assert_eq!(health.health_ok, Some(false));
+    }
+}
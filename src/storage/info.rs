@@ -14,6 +14,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::health::DiskHealth;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StorageInfo {
     pub mount_point: String,
@@ -22,4 +24,50 @@ pub struct StorageInfo {
     pub host_id: String,  // Host identifier (e.g., "10.82.128.41:9090")
     pub hostname: String, // DNS hostname of the server
     pub index: u32,
+    /// Inode totals; `None` when the filesystem does not report inodes
+    #[serde(default)]
+    pub total_inodes: Option<u64>,
+    #[serde(default)]
+    pub free_inodes: Option<u64>,
+    /// Health of the drives backing the mount
+    #[serde(default)]
+    pub health: DiskHealth,
+}
+
+impl StorageInfo {
+    /// Percentage of inodes in use, if the filesystem reports them.
+    pub fn inode_usage_percent(&self) -> Option<f64> {
+        let total = self.total_inodes.filter(|&total| total > 0)?;
+        let free = self.free_inodes.unwrap_or(0).min(total);
+        Some((total - free) as f64 / total as f64 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage(total_inodes: Option<u64>, free_inodes: Option<u64>) -> StorageInfo {
+        StorageInfo {
+            mount_point: "/".to_string(),
+            total_bytes: 1000,
+            available_bytes: 900,
+            host_id: "host".to_string(),
+            hostname: "host".to_string(),
+            index: 0,
+            total_inodes,
+            free_inodes,
+            health: DiskHealth::default(),
+        }
+    }
+
+    #[test]
+    fn test_inode_usage_percent() {
+        assert_eq!(
+            storage(Some(200), Some(20)).inode_usage_percent(),
+            Some(90.0)
+        );
+        assert_eq!(storage(None, None).inode_usage_percent(), None);
+        assert_eq!(storage(Some(0), Some(0)).inode_usage_percent(), None);
+    }
 }
@@ -0,0 +1,91 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filesystem inode counts.
+//!
+//! `sysinfo` only reports bytes, so inode totals come from `statvfs(3)` on
+//! Unix. Filesystems without a fixed inode table (btrfs, some network and
+//! FUSE mounts) report zero inodes and are treated as unknown.
+
+use std::path::Path;
+
+/// Inode totals for one mounted filesystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InodeCounts {
+    pub total: u64,
+    pub free: u64,
+}
+
+/// Read inode counts for the filesystem mounted at `mount_point`. Returns
+/// `None` when the platform or filesystem does not report inodes.
+#[cfg(unix)]
+pub fn read_inode_counts(mount_point: &Path) -> Option<InodeCounts> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: `path` is a valid NUL-terminated string and `stat` points to
+    // writable memory of the right size; it is only read on success.
+    let stat = unsafe {
+        if libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) != 0 {
+            return None;
+        }
+        stat.assume_init()
+    };
+
+    // fsfilcnt_t is 32-bit on macOS and 64-bit on Linux
+    #[allow(clippy::unnecessary_cast)]
+    inode_counts(stat.f_files as u64, stat.f_favail as u64)
+}
+
+#[cfg(not(unix))]
+pub fn read_inode_counts(_mount_point: &Path) -> Option<InodeCounts> {
+    None
+}
+
+fn inode_counts(total: u64, free: u64) -> Option<InodeCounts> {
+    (total > 0).then_some(InodeCounts {
+        total,
+        free: free.min(total),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inode_counts() {
+        assert_eq!(inode_counts(0, 0), None);
+        assert_eq!(
+            inode_counts(1000, 250),
+            Some(InodeCounts {
+                total: 1000,
+                free: 250
+            })
+        );
+        assert_eq!(inode_counts(10, 20).map(|c| c.free), Some(10));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_inode_counts() {
+        let dir = tempfile::tempdir().unwrap();
+        if let Some(counts) = read_inode_counts(dir.path()) {
+            assert!(counts.free <= counts.total);
+        }
+        assert_eq!(read_inode_counts(Path::new("/nonexistent/mount")), None);
+    }
+}
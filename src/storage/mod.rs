@@ -17,7 +17,9 @@
 //! This module provides storage/disk information reading capabilities
 //! for local system monitoring.
 
+pub mod health;
 pub mod info;
+pub mod inodes;
 pub mod reader;
 
 // Re-export commonly used items for the public library API.
@@ -19,7 +19,9 @@
 
 use sysinfo::Disks;
 
+use crate::storage::health::disk_health;
 use crate::storage::info::StorageInfo;
+use crate::storage::inodes::read_inode_counts;
 use crate::utils::{filter_docker_aware_disks, get_hostname};
 
 /// Trait for reading storage/disk information.
@@ -107,13 +109,19 @@ impl StorageReader for LocalStorageReader {
         filtered_disks
             .iter()
             .enumerate()
-            .map(|(index, disk)| StorageInfo {
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                total_bytes: disk.total_space(),
-                available_bytes: disk.available_space(),
-                host_id: self.hostname.clone(),
-                hostname: self.hostname.clone(),
-                index: index as u32,
+            .map(|(index, disk)| {
+                let inodes = read_inode_counts(disk.mount_point());
+                StorageInfo {
+                    mount_point: disk.mount_point().to_string_lossy().to_string(),
+                    total_bytes: disk.total_space(),
+                    available_bytes: disk.available_space(),
+                    host_id: self.hostname.clone(),
+                    hostname: self.hostname.clone(),
+                    index: index as u32,
+                    total_inodes: inodes.map(|c| c.total),
+                    free_inodes: inodes.map(|c| c.free),
+                    health: disk_health(&disk.name().to_string_lossy()),
+                }
             })
             .collect()
     }
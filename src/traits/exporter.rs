@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use serde::Serialize;
+
 // Device info types will be defined by implementing modules
 
 /// Result type for export operations
@@ -67,17 +69,20 @@ pub enum ExportFormat {
 }
 
 /// Metric metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MetricMetadata {
     pub name: String,
     pub help: Option<String>,
     pub unit: Option<String>,
+    #[serde(rename = "type")]
     pub metric_type: MetricType,
+    /// Label names used by the metric, each mapped to an example value
     pub labels: HashMap<String, String>,
 }
 
 /// Metric types
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MetricType {
     Counter,
     Gauge,
@@ -86,6 +91,19 @@ pub enum MetricType {
     Untyped,
 }
 
+impl MetricType {
+    /// Parse the type name used on a Prometheus `# TYPE` line.
+    pub fn from_prometheus(name: &str) -> Self {
+        match name {
+            "counter" => Self::Counter,
+            "gauge" => Self::Gauge,
+            "histogram" => Self::Histogram,
+            "summary" => Self::Summary,
+            _ => Self::Untyped,
+        }
+    }
+}
+
 /// A single metric value
 #[derive(Debug, Clone)]
 pub struct MetricValue {
@@ -101,6 +119,134 @@ pub struct MetricCollection {
     pub metadata: HashMap<String, MetricMetadata>,
 }
 
+/// Derives per-second rates from counter metrics, for consumers that want
+/// rates precomputed instead of applying `rate()` themselves.
+///
+/// Each series (metric name plus labels) keeps its previous sample. A value
+/// lower than the previous one is taken as a counter reset, i.e. the counter
+/// restarted from zero.
+#[derive(Debug, Default)]
+pub struct RateTracker {
+    previous: HashMap<String, (f64, chrono::DateTime<chrono::Utc>)>,
+}
+
+impl RateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name of the rate gauge for `counter`: `_total` is dropped and
+    /// `_per_sec` appended.
+    pub fn rate_metric_name(counter: &str) -> String {
+        let base = counter.strip_suffix("_total").unwrap_or(counter);
+        format!("{base}_per_sec")
+    }
+
+    /// Record a counter sample and return its per-second rate since the
+    /// previous sample of the same series. `None` for the first sample, or
+    /// when the timestamp did not move forward.
+    pub fn observe(
+        &mut self,
+        name: &str,
+        labels: &HashMap<String, String>,
+        value: f64,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    ) -> Option<f64> {
+        let previous = self
+            .previous
+            .insert(series_key(name, labels), (value, timestamp))?;
+        let (previous_value, previous_timestamp) = previous;
+
+        let elapsed = (timestamp - previous_timestamp).num_milliseconds() as f64 / 1000.0;
+        if elapsed <= 0.0 {
+            return None;
+        }
+        let increase = if value < previous_value {
+            value
+        } else {
+            value - previous_value
+        };
+        Some(increase / elapsed)
+    }
+
+    /// Rate gauges for every counter in `collection`. Samples without a
+    /// timestamp are taken to be from `now`.
+    pub fn rates(
+        &mut self,
+        collection: &MetricCollection,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> MetricCollection {
+        let mut rates = MetricCollection::default();
+
+        for (name, values) in &collection.metrics {
+            let Some(metadata) = collection.metadata.get(name) else {
+                continue;
+            };
+            if metadata.metric_type != MetricType::Counter {
+                continue;
+            }
+
+            let rate_name = Self::rate_metric_name(name);
+            let rate_values: Vec<MetricValue> = values
+                .iter()
+                .filter_map(|sample| {
+                    let timestamp = sample.timestamp.unwrap_or(now);
+                    let rate = self.observe(name, &sample.labels, sample.value, timestamp)?;
+                    Some(MetricValue {
+                        value: rate,
+                        timestamp: Some(timestamp),
+                        labels: sample.labels.clone(),
+                    })
+                })
+                .collect();
+            if rate_values.is_empty() {
+                continue;
+            }
+
+            rates.metadata.insert(
+                rate_name.clone(),
+                MetricMetadata {
+                    name: rate_name.clone(),
+                    help: Some(format!("Per-second rate of {name}")),
+                    unit: metadata.unit.as_ref().map(|unit| format!("{unit}/s")),
+                    metric_type: MetricType::Gauge,
+                    labels: metadata.labels.clone(),
+                },
+            );
+            rates.metrics.insert(rate_name, rate_values);
+        }
+
+        rates
+    }
+
+    /// Forget series whose latest sample is older than `cutoff`, e.g. for
+    /// devices that disappeared.
+    pub fn prune(&mut self, cutoff: chrono::DateTime<chrono::Utc>) {
+        self.previous
+            .retain(|_, (_, timestamp)| *timestamp >= cutoff);
+    }
+
+    /// Number of series with a stored sample.
+    pub fn len(&self) -> usize {
+        self.previous.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty()
+    }
+}
+
+/// `name{a="1",b="2"}` with labels sorted, identifying one series.
+fn series_key(name: &str, labels: &HashMap<String, String>) -> String {
+    let mut pairs: Vec<(&String, &String)> = labels.iter().collect();
+    pairs.sort();
+    let labels: Vec<String> = pairs
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value:?}"))
+        .collect();
+    format!("{name}{{{}}}", labels.join(","))
+}
+
 /// Trait for exporting metrics in various formats
 pub trait MetricsExporter: Send + Sync {
     /// Export metrics in the specified format
@@ -271,3 +417,119 @@ pub trait ExporterFactory {
         BoxedCompositeExporter<Self::GpuInfo, Self::CpuInfo, Self::MemoryInfo, Self::StorageInfo>,
     >;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn labels(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_rate_tracker_computes_per_second_rate() {
+        let mut tracker = RateTracker::new();
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let gpu0 = labels(&[("gpu", "0")]);
+
+        // No prior sample
+        assert_eq!(
+            tracker.observe("energy_joules_total", &gpu0, 100.0, t0),
+            None
+        );
+        assert_eq!(
+            tracker.observe(
+                "energy_joules_total",
+                &gpu0,
+                400.0,
+                t0 + Duration::seconds(2)
+            ),
+            Some(150.0)
+        );
+        // Same timestamp again: no rate
+        assert_eq!(
+            tracker.observe(
+                "energy_joules_total",
+                &gpu0,
+                500.0,
+                t0 + Duration::seconds(2)
+            ),
+            None
+        );
+        // Other label values are separate series
+        let gpu1 = labels(&[("gpu", "1")]);
+        assert_eq!(tracker.observe("energy_joules_total", &gpu1, 5.0, t0), None);
+        assert_eq!(tracker.len(), 2);
+    }
+
+    #[test]
+    fn test_rate_tracker_handles_counter_reset() {
+        let mut tracker = RateTracker::new();
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let none = HashMap::new();
+
+        tracker.observe("ecc_errors_total", &none, 1000.0, t0);
+        // Counter restarted (driver reload): the increase is the new value
+        assert_eq!(
+            tracker.observe("ecc_errors_total", &none, 30.0, t0 + Duration::seconds(10)),
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn test_rates_from_collection() {
+        let mut tracker = RateTracker::new();
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let collection = |bytes: f64, temperature: f64| {
+            let sample = |value| MetricValue {
+                value,
+                timestamp: None,
+                labels: labels(&[("interface", "eth0")]),
+            };
+            let metadata = |name: &str, metric_type| MetricMetadata {
+                name: name.to_string(),
+                help: None,
+                unit: Some("bytes".to_string()),
+                metric_type,
+                labels: HashMap::new(),
+            };
+            MetricCollection {
+                metrics: HashMap::from([
+                    ("net_rx_bytes_total".to_string(), vec![sample(bytes)]),
+                    ("temperature".to_string(), vec![sample(temperature)]),
+                ]),
+                metadata: HashMap::from([
+                    (
+                        "net_rx_bytes_total".to_string(),
+                        metadata("net_rx_bytes_total", MetricType::Counter),
+                    ),
+                    (
+                        "temperature".to_string(),
+                        metadata("temperature", MetricType::Gauge),
+                    ),
+                ]),
+            }
+        };
+
+        assert!(tracker
+            .rates(&collection(1000.0, 40.0), t0)
+            .metrics
+            .is_empty());
+        let rates = tracker.rates(&collection(6000.0, 41.0), t0 + Duration::seconds(5));
+
+        assert_eq!(rates.metrics.len(), 1);
+        let rx = &rates.metrics["net_rx_bytes_per_sec"];
+        assert_eq!(rx[0].value, 1000.0);
+        assert_eq!(rx[0].labels["interface"], "eth0");
+        let metadata = &rates.metadata["net_rx_bytes_per_sec"];
+        assert_eq!(metadata.metric_type, MetricType::Gauge);
+        assert_eq!(metadata.unit.as_deref(), Some("bytes/s"));
+
+        tracker.prune(t0 + Duration::seconds(60));
+        assert!(tracker.is_empty());
+    }
+}
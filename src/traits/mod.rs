@@ -33,7 +33,7 @@ pub use collector::{
 pub use exporter::{
     BoxedCompositeExporter, CompositeExporter, CpuMetricsExporter, ExportFormat, ExporterBuilder,
     ExporterError, ExporterFactory, ExporterResult, GpuMetricsExporter, MemoryMetricsExporter,
-    MetricCollection, MetricMetadata, MetricType, MetricValue, MetricsExporter,
+    MetricCollection, MetricMetadata, MetricType, MetricValue, MetricsExporter, RateTracker,
     StorageMetricsExporter,
 };
 
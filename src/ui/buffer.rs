@@ -19,6 +19,9 @@ use crossterm::{
 };
 use std::io::{stdout, Write};
 
+/// One frame of terminal output. The UI loop keeps a single writer and
+/// clears it between frames, so the capacity grown for a large screen is
+/// reused instead of reallocated every frame.
 pub struct BufferWriter {
     buffer: String,
     line_count: usize,
@@ -32,14 +35,24 @@ impl Default for BufferWriter {
 
 impl BufferWriter {
     pub fn new() -> Self {
+        // Pre-allocate 64KB - sufficient for typical terminal content
+        // while avoiding excessive memory usage
+        Self::with_capacity(64 * 1024)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            // Pre-allocate 64KB - sufficient for typical terminal content
-            // while avoiding excessive memory usage
-            buffer: String::with_capacity(64 * 1024),
+            buffer: String::with_capacity(capacity),
             line_count: 0,
         }
     }
 
+    /// Empty the buffer for the next frame, keeping its capacity
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.line_count = 0;
+    }
+
     pub fn get_buffer(&self) -> &str {
         &self.buffer
     }
@@ -101,9 +114,34 @@ impl DifferentialRenderer {
 
     /// Render content with differential updates - only changed lines are updated
     pub fn render_differential(&mut self, content: &str) -> std::io::Result<()> {
+        let (width, height) = size().unwrap_or((80, 24));
+        let mut stdout = stdout();
+        self.render_to(&mut stdout, content, width as usize, height as usize)?;
+        // Flush all queued updates at once
+        stdout.flush()
+    }
+
+    /// Write the updates that turn the previous frame into `content` on a
+    /// `width` x `height` screen: a cursor move, a line clear and the new
+    /// text for each changed line, and a clear for each line no longer
+    /// used. Lines are compared byte for byte, so a line that differs only
+    /// in trailing whitespace is redrawn too; the padding may carry a
+    /// background color or overwrite older text. An unchanged frame
+    /// writes nothing.
+    pub fn render_to<W: Write>(
+        &mut self,
+        out: &mut W,
+        content: &str,
+        width: usize,
+        height: usize,
+    ) -> std::io::Result<()> {
         // Fast path: check if content is identical using hash
         let content_hash = Self::hash_content(content);
-        if content_hash == self.previous_content_hash && !self.previous_lines.is_empty() {
+        if content_hash == self.previous_content_hash
+            && !self.previous_lines.is_empty()
+            && width == self.screen_width
+            && height == self.screen_height
+        {
             // Content is unchanged, skip all rendering work
             return Ok(());
         }
@@ -112,10 +150,9 @@ impl DifferentialRenderer {
         self.previous_content_hash = content_hash;
 
         // Adjust buffer size if screen dimensions changed
-        let (width, height) = size().unwrap_or((80, 24));
-        if width as usize != self.screen_width || height as usize != self.screen_height {
-            self.screen_width = width as usize;
-            self.screen_height = height as usize;
+        if width != self.screen_width || height != self.screen_height {
+            self.screen_width = width;
+            self.screen_height = height;
             self.previous_lines
                 .resize(self.screen_height, String::new());
         }
@@ -125,7 +162,6 @@ impl DifferentialRenderer {
             self.previous_lines = vec![String::new(); self.screen_height];
         }
 
-        let mut stdout = stdout();
         let mut current_line_count = 0;
 
         // Process lines directly from iterator, updating previous_lines in-place
@@ -139,7 +175,7 @@ impl DifferentialRenderer {
             if self.previous_lines[line_num] != current_line {
                 // Update this line - clear it first to prevent artifacts from shorter lines
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(0, line_num as u16),
                     crossterm::terminal::Clear(ClearType::UntilNewLine),
                     Print(current_line)
@@ -155,7 +191,7 @@ impl DifferentialRenderer {
         for line_num in current_line_count..self.screen_height {
             if !self.previous_lines[line_num].is_empty() {
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(0, line_num as u16),
                     crossterm::terminal::Clear(ClearType::CurrentLine)
                 )?;
@@ -163,9 +199,6 @@ impl DifferentialRenderer {
             }
         }
 
-        // Flush all queued updates at once
-        stdout.flush()?;
-
         Ok(())
     }
 
@@ -184,3 +217,98 @@ impl DifferentialRenderer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn renderer() -> DifferentialRenderer {
+        DifferentialRenderer {
+            previous_lines: Vec::new(),
+            screen_height: 0,
+            screen_width: 0,
+            previous_content_hash: 0,
+        }
+    }
+
+    /// Render `content` on an 80x6 screen and return what was written
+    fn render(renderer: &mut DifferentialRenderer, content: &str) -> String {
+        let mut out = Vec::new();
+        renderer.render_to(&mut out, content, 80, 6).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// The cursor move `render_to` writes before redrawing `line`
+    fn move_to(line: u16) -> String {
+        let mut out = Vec::new();
+        queue!(out, cursor::MoveTo(0, line)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_unchanged_frame_writes_nothing() {
+        let mut renderer = renderer();
+        let frame = "GPU 0\r\nGPU 1\r\nGPU 2\r\n";
+        let first = render(&mut renderer, frame);
+        assert!(first.contains("GPU 0") && first.contains("GPU 2"));
+
+        assert_eq!(render(&mut renderer, frame), "");
+        // Equal content in a fresh buffer is still unchanged
+        let copy = String::from(frame);
+        assert_eq!(render(&mut renderer, &copy), "");
+    }
+
+    #[test]
+    fn test_only_changed_lines_are_redrawn() {
+        let mut renderer = renderer();
+        render(&mut renderer, "GPU 0 10%\r\nGPU 1 20%\r\nGPU 2 30%\r\n");
+
+        let out = render(&mut renderer, "GPU 0 10%\r\nGPU 1 25%\r\nGPU 2 30%\r\n");
+        assert!(out.contains(&move_to(1)));
+        assert!(out.contains("GPU 1 25%"));
+        assert!(!out.contains(&move_to(0)) && !out.contains(&move_to(2)));
+        assert!(!out.contains("GPU 0") && !out.contains("GPU 2"));
+    }
+
+    #[test]
+    fn test_trailing_whitespace_change_is_redrawn() {
+        let mut renderer = renderer();
+        render(&mut renderer, "Util: 10%   \r\nMem\r\n");
+
+        // Padding that shrinks or grows is part of the line
+        let out = render(&mut renderer, "Util: 10%\r\nMem\r\n");
+        assert!(out.contains(&move_to(0)));
+        assert!(!out.contains(&move_to(1)));
+        let out = render(&mut renderer, "Util: 10%\r\nMem \r\n");
+        assert!(out.contains(&move_to(1)));
+        assert!(!out.contains(&move_to(0)));
+    }
+
+    #[test]
+    fn test_shorter_frame_clears_leftover_lines() {
+        let mut renderer = renderer();
+        render(&mut renderer, "a\r\nb\r\nc\r\n");
+
+        let out = render(&mut renderer, "a\r\n");
+        assert!(out.contains(&move_to(1)) && out.contains(&move_to(2)));
+        assert!(!out.contains(&move_to(0)));
+        assert!(!out.contains(&move_to(3)));
+        // Lines past the screen height are never drawn
+        let tall = "x\r\n".repeat(10);
+        let out = render(&mut renderer, &tall);
+        assert!(!out.contains(&move_to(6)));
+    }
+
+    #[test]
+    fn test_cleared_buffer_keeps_capacity() {
+        let mut buffer = BufferWriter::with_capacity(16);
+        write!(buffer, "{}\r\n", "x".repeat(1000)).unwrap();
+        assert_eq!(buffer.line_count(), 1);
+        let capacity = buffer.buffer.capacity();
+
+        buffer.clear();
+        assert_eq!(buffer.get_buffer(), "");
+        assert_eq!(buffer.line_count(), 0);
+        assert_eq!(buffer.buffer.capacity(), capacity);
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Brief highlight of values that changed a lot since the previous refresh,
+//! toggled with `C`.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+
+use crate::device::{GpuInfo, ProcessInfo};
+use crate::ui::theme;
+
+/// Temperature rise or fall that flashes the value
+pub const TEMPERATURE_JUMP_CELSIUS: u32 = 5;
+/// Utilization change, in percentage points, that flashes the value
+pub const UTILIZATION_JUMP_PERCENT: f64 = 30.0;
+/// Memory change, as a share of the device's total, that flashes the value
+pub const MEMORY_JUMP_FRACTION: f64 = 0.1;
+
+/// How long a change stays highlighted; it fades after the first half
+const FLASH_DURATION: Duration = Duration::from_millis(2000);
+
+/// Highlight strength, decaying from `Strong` to `Fading` before it ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flash {
+    Strong,
+    Fading,
+}
+
+impl Flash {
+    fn at(started: Instant, now: Instant) -> Option<Self> {
+        let elapsed = now.saturating_duration_since(started);
+        if elapsed < FLASH_DURATION / 2 {
+            Some(Flash::Strong)
+        } else if elapsed < FLASH_DURATION {
+            Some(Flash::Fading)
+        } else {
+            None
+        }
+    }
+}
+
+/// Foreground and background of a value drawn in `fg` normally
+pub fn flash_colors(flash: Option<Flash>, fg: Color) -> (Color, Option<Color>) {
+    let theme = theme::current();
+    match flash {
+        Some(Flash::Strong) => (theme.badge_fg, Some(theme.warning)),
+        Some(Flash::Fading) => (theme.warning, None),
+        None => (fg, None),
+    }
+}
+
+/// Device values that can be highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangedField {
+    Utilization,
+    Memory,
+    Temperature,
+}
+
+/// Highlights of one device row
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GpuFlash {
+    pub utilization: Option<Flash>,
+    pub memory: Option<Flash>,
+    pub temperature: Option<Flash>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DeviceSample {
+    utilization: f64,
+    used_memory: u64,
+    total_memory: u64,
+    temperature: u32,
+}
+
+/// Readings of the previous refresh and the changes still highlighted
+#[derive(Debug, Clone, Default)]
+pub struct ChangeHighlight {
+    previous: HashMap<String, DeviceSample>,
+    /// Processes seen last refresh; `None` before the first one, so the
+    /// initial list does not flash
+    known_pids: Option<HashSet<u32>>,
+    flashes: HashMap<(String, ChangedField), Instant>,
+    new_processes: HashMap<u32, Instant>,
+}
+
+impl ChangeHighlight {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compare a refresh with the previous one and start highlighting what
+    /// changed significantly.
+    pub fn update(&mut self, gpus: &[GpuInfo], processes: &[ProcessInfo], now: Instant) {
+        self.flashes
+            .retain(|_, started| Flash::at(*started, now).is_some());
+        self.new_processes
+            .retain(|_, started| Flash::at(*started, now).is_some());
+
+        let mut current = HashMap::with_capacity(gpus.len());
+        for gpu in gpus {
+            let sample = DeviceSample {
+                utilization: gpu.utilization,
+                used_memory: gpu.used_memory,
+                total_memory: gpu.total_memory,
+                temperature: gpu.temperature,
+            };
+            if let Some(previous) = self.previous.get(&gpu.uuid) {
+                for field in changed_fields(previous, &sample) {
+                    self.flashes.insert((gpu.uuid.clone(), field), now);
+                }
+            }
+            current.insert(gpu.uuid.clone(), sample);
+        }
+        self.previous = current;
+
+        let pids: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+        if let Some(known) = &self.known_pids {
+            for &pid in pids.difference(known) {
+                self.new_processes.insert(pid, now);
+            }
+        }
+        self.known_pids = Some(pids);
+    }
+
+    pub fn gpu_flash(&self, uuid: &str, now: Instant) -> GpuFlash {
+        let flash = |field| {
+            self.flashes
+                .get(&(uuid.to_string(), field))
+                .and_then(|started| Flash::at(*started, now))
+        };
+        GpuFlash {
+            utilization: flash(ChangedField::Utilization),
+            memory: flash(ChangedField::Memory),
+            temperature: flash(ChangedField::Temperature),
+        }
+    }
+
+    /// Highlight of a process that appeared in a recent refresh
+    pub fn process_flash(&self, pid: u32, now: Instant) -> Option<Flash> {
+        self.new_processes
+            .get(&pid)
+            .and_then(|started| Flash::at(*started, now))
+    }
+}
+
+fn changed_fields(previous: &DeviceSample, current: &DeviceSample) -> Vec<ChangedField> {
+    let mut fields = Vec::new();
+    if previous.utilization >= 0.0
+        && current.utilization >= 0.0
+        && (current.utilization - previous.utilization).abs() >= UTILIZATION_JUMP_PERCENT
+    {
+        fields.push(ChangedField::Utilization);
+    }
+    let total = current.total_memory.max(previous.total_memory);
+    if total > 0
+        && current.used_memory.abs_diff(previous.used_memory) as f64
+            >= total as f64 * MEMORY_JUMP_FRACTION
+    {
+        fields.push(ChangedField::Memory);
+    }
+    if current.temperature.abs_diff(previous.temperature) >= TEMPERATURE_JUMP_CELSIUS {
+        fields.push(ChangedField::Temperature);
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(utilization: f64, used_gb: u64, temperature: u32) -> DeviceSample {
+        DeviceSample {
+            utilization,
+            used_memory: used_gb << 30,
+            total_memory: 80 << 30,
+            temperature,
+        }
+    }
+
+    #[test]
+    fn test_changed_fields_thresholds() {
+        let before = sample(10.0, 10, 50);
+        assert!(changed_fields(&before, &sample(35.0, 17, 54)).is_empty());
+        assert_eq!(
+            changed_fields(&before, &sample(40.0, 18, 45)),
+            [
+                ChangedField::Utilization,
+                ChangedField::Memory,
+                ChangedField::Temperature
+            ]
+        );
+        // Devices without a reading do not flash when it appears
+        assert!(changed_fields(&sample(-1.0, 10, 50), &sample(90.0, 10, 50)).is_empty());
+    }
+
+    #[test]
+    fn test_flash_decays() {
+        let start = Instant::now();
+        assert_eq!(Flash::at(start, start), Some(Flash::Strong));
+        assert_eq!(
+            Flash::at(start, start + FLASH_DURATION / 2),
+            Some(Flash::Fading)
+        );
+        assert_eq!(Flash::at(start, start + FLASH_DURATION), None);
+    }
+}
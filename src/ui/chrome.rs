@@ -16,13 +16,40 @@ use std::io::Write;
 
 use crossterm::{
     cursor, queue,
-    style::Color,
+    style::Print,
     terminal::{Clear, ClearType},
 };
 
-use crate::app_state::AppState;
+use crate::app_state::{AppState, KillPrompt};
+use crate::common::config::AppConfig;
 use crate::ui::constants::{ANIMATION_SPEED, BLOCK_SIZE_DIVISOR, BLOCK_SIZE_MAX, SCREEN_MARGIN};
+use crate::ui::process_renderer::format_gpu_memory;
 use crate::ui::text::{display_width, print_colored_text, truncate_to_width};
+use crate::ui::theme;
+
+/// True when the terminal is too small for the regular layout
+pub fn is_terminal_too_small(cols: u16, rows: u16) -> bool {
+    cols < AppConfig::MIN_TERMINAL_WIDTH || rows < AppConfig::MIN_TERMINAL_HEIGHT
+}
+
+/// Placeholder shown instead of the full layout on tiny terminals
+pub fn print_terminal_too_small<W: Write>(stdout: &mut W, cols: u16, rows: u16) {
+    let width = cols as usize;
+    let message = format!(
+        "terminal too small (need {}x{}, have {cols}x{rows})",
+        AppConfig::MIN_TERMINAL_WIDTH,
+        AppConfig::MIN_TERMINAL_HEIGHT
+    );
+    let message = truncate_to_width(&message, width);
+    let left = width.saturating_sub(display_width(&message)) / 2;
+
+    for _ in 0..rows.saturating_sub(1) / 2 {
+        queue!(stdout, Print("\r\n")).unwrap();
+    }
+    queue!(stdout, Print(" ".repeat(left))).unwrap();
+    print_colored_text(stdout, &message, theme::current().warning, None, None);
+    queue!(stdout, Print("\r\n")).unwrap();
+}
 
 pub fn print_loading_indicator<W: Write>(
     stdout: &mut W,
@@ -31,16 +58,17 @@ pub fn print_loading_indicator<W: Write>(
     frame_counter: u64,
     startup_status_lines: &[String],
 ) {
+    let theme = theme::current();
     // Center the loading message
     let message = "Loading...";
     let x = (cols.saturating_sub(message.len() as u16)) / 2;
     let y = rows / 2;
 
     queue!(stdout, cursor::MoveTo(x, y)).unwrap();
-    print_colored_text(stdout, message, Color::Yellow, None, None);
+    print_colored_text(stdout, message, theme.label, None, None);
 
     // Progress bar parameters
-    let bar_width = 40.min(cols as usize - SCREEN_MARGIN); // Ensure it fits on screen
+    let bar_width = 40.min((cols as usize).saturating_sub(SCREEN_MARGIN)); // Ensure it fits on screen
     let bar_x = (cols.saturating_sub(bar_width as u16)) / 2;
     let bar_y = y + 2; // 2 lines below "Loading..."
 
@@ -66,9 +94,9 @@ pub fn print_loading_indicator<W: Write>(
     // Draw the progress bar with thinner characters
     for i in 0..bar_width {
         if i >= block_start && i < block_end {
-            print_colored_text(stdout, "━", Color::Cyan, None, None);
+            print_colored_text(stdout, "━", theme.title, None, None);
         } else {
-            print_colored_text(stdout, "─", Color::DarkGrey, None, None);
+            print_colored_text(stdout, "─", theme.dim, None, None);
         }
     }
 
@@ -77,7 +105,10 @@ pub fn print_loading_indicator<W: Write>(
         let status_start_y = bar_y + 2; // 2 lines below the progress bar
 
         // Calculate starting position to show last N lines that fit on screen
-        let max_lines = ((rows - status_start_y) - 1).min(10) as usize; // Show max 10 lines
+        let max_lines = rows
+            .saturating_sub(status_start_y)
+            .saturating_sub(1)
+            .min(10) as usize; // Show max 10 lines
         let lines_to_show = startup_status_lines.len().min(max_lines);
         let start_idx = startup_status_lines.len().saturating_sub(lines_to_show);
 
@@ -90,9 +121,9 @@ pub fn print_loading_indicator<W: Write>(
 
             // Use different colors based on status
             let color = if status_line.contains("✓") {
-                Color::DarkGreen
+                theme.host
             } else {
-                Color::DarkGrey
+                theme.dim
             };
 
             print_colored_text(stdout, status_line, color, None, None);
@@ -109,12 +140,24 @@ pub fn print_function_keys<W: Write>(
     state: &AppState,
     is_remote: bool,
 ) {
+    let theme = theme::current();
     // Move to bottom of screen
     queue!(stdout, cursor::MoveTo(0, rows - 1)).unwrap();
 
+    // The host search input replaces the key hints while it is open
+    if let Some(search) = &state.host_search {
+        print_host_search_prompt(stdout, cols, state, &search.query);
+        return;
+    }
+    if let Some(prompt) = &state.kill_prompt {
+        print_kill_prompt(stdout, cols, prompt);
+        return;
+    }
+
     // Get current sorting indicator
     let sort_indicator = match state.sort_criteria {
         crate::app_state::SortCriteria::Default => "Sort:Default",
+        crate::app_state::SortCriteria::Uuid => "Sort:UUID",
         crate::app_state::SortCriteria::Pid => "Sort:PID",
         crate::app_state::SortCriteria::User => "Sort:User",
         crate::app_state::SortCriteria::Priority => "Sort:Priority",
@@ -141,18 +184,31 @@ pub fn print_function_keys<W: Write>(
         ""
     };
 
+    // Space pins the selected device instead of pausing
+    let space_key = if state.selected_gpu.is_some() {
+        "Space:Pin"
+    } else {
+        "Space:Pause"
+    };
+
     let function_keys = if is_remote {
         // Remote mode: only GPU sorting
         format!(
-            "h:Help q:Exit c:CPU Cores ←→:Tabs ↑↓:Scroll PgUp/PgDn:Page d:Default u:Util g:GPU-Mem [{sort_indicator}]"
+            "h:Help q:Exit c:CPU Cores {space_key} Tab:Select ←→:Tabs ↑↓:Scroll PgUp/PgDn:Page d:Default u:Util g:GPU-Mem [{sort_indicator}]"
         )
     } else {
         // Local mode: both process and GPU sorting
+        let mut keys = format!("h:Help q:Exit c:CPU Cores f:Filter t:Top i:Idle {space_key} ←→:Scroll ↑↓:Scroll p:PID m:Memory g:GPU-Mem [{sort_indicator}]");
         if state.gpu_filter_enabled {
-            format!("h:Help q:Exit c:CPU Cores f:Filter ←→:Scroll ↑↓:Scroll p:PID m:Memory g:GPU-Mem [{sort_indicator}] [{filter_indicator}]")
-        } else {
-            format!("h:Help q:Exit c:CPU Cores f:Filter ←→:Scroll ↑↓:Scroll p:PID m:Memory g:GPU-Mem [{sort_indicator}]")
+            keys.push_str(&format!(" [{filter_indicator}]"));
+        }
+        if let Some(top) = state.process_top.label() {
+            keys.push_str(&format!(" [{top}]"));
+        }
+        if state.min_process_activity > 0.0 {
+            keys.push_str(&format!(" [Min:{}%]", state.min_process_activity));
         }
+        keys
     };
 
     let truncated_keys = if display_width(&function_keys) > cols as usize {
@@ -180,21 +236,21 @@ pub fn print_function_keys<W: Write>(
     };
 
     // Print function keys
-    print_colored_text(stdout, &final_function_keys, Color::DarkGreen, None, None);
+    print_colored_text(stdout, &final_function_keys, theme.host, None, None);
 
     // Print notification if there is one
     if notification_len > 0 {
         // Add separator
-        print_colored_text(stdout, " ", Color::White, None, None);
+        print_colored_text(stdout, " ", theme.text, None, None);
 
         // Print notification with appropriate color
         let notification_color =
             if notification_msg.contains("Error") || notification_msg.contains("Failed") {
-                Color::Red
+                theme.critical
             } else if notification_msg.contains("Warning") {
-                Color::Yellow
+                theme.warning
             } else {
-                Color::Cyan
+                theme.title
             };
 
         print_colored_text(stdout, notification_msg, notification_color, None, None);
@@ -210,12 +266,77 @@ pub fn print_function_keys<W: Write>(
     let remaining_space = cols as usize - used_space.min(cols as usize);
 
     if remaining_space > 0 {
-        print_colored_text(
-            stdout,
-            &" ".repeat(remaining_space),
-            Color::White,
-            None,
-            None,
-        );
+        print_colored_text(stdout, &" ".repeat(remaining_space), theme.text, None, None);
+    }
+}
+
+fn print_kill_prompt<W: Write>(stdout: &mut W, cols: u16, prompt: &KillPrompt) {
+    let theme = theme::current();
+    let gpu_memory = format_gpu_memory(prompt.used_memory, prompt.used_memory_estimated);
+    let question = if prompt.foreign && prompt.confirmed_once {
+        format!(
+            "{} ({}) belongs to {}. Really send {}?",
+            prompt.process_name,
+            prompt.pid,
+            prompt.user,
+            prompt.signal.name()
+        )
+    } else {
+        format!(
+            "Send {} to {} ({}, GPU {gpu_memory})?",
+            prompt.signal.name(),
+            prompt.process_name,
+            prompt.pid
+        )
+    };
+    let hint = " y:Confirm n:Cancel";
+
+    let width = cols as usize;
+    let question = truncate_to_width(&question, width);
+    let mut used = display_width(&question);
+    print_colored_text(stdout, &question, theme.critical, None, None);
+
+    let hint_width = display_width(hint);
+    if used + hint_width <= width {
+        print_colored_text(stdout, hint, theme.host, None, None);
+        used += hint_width;
+    }
+
+    if used < width {
+        print_colored_text(stdout, &" ".repeat(width - used), theme.text, None, None);
+    }
+}
+
+fn print_host_search_prompt<W: Write>(stdout: &mut W, cols: u16, state: &AppState, query: &str) {
+    let theme = theme::current();
+    let prompt = format!("/{query}_");
+    let status = match state.matching_host_tabs(query).len() {
+        _ if query.is_empty() => String::new(),
+        0 => " (no match)".to_string(),
+        1 => " (1 host)".to_string(),
+        n => format!(" ({n} hosts)"),
+    };
+    let hint = " Enter:Go Esc:Cancel";
+
+    let width = cols as usize;
+    let prompt = truncate_to_width(&prompt, width);
+    let mut used = display_width(&prompt);
+    print_colored_text(stdout, &prompt, theme.label, None, None);
+
+    let status_color = if status == " (no match)" {
+        theme.critical
+    } else {
+        theme.title
+    };
+    for (text, color) in [(status.as_str(), status_color), (hint, theme.host)] {
+        let text_width = display_width(text);
+        if text_width > 0 && used + text_width <= width {
+            print_colored_text(stdout, text, color, None, None);
+            used += text_width;
+        }
+    }
+
+    if used < width {
+        print_colored_text(stdout, &" ".repeat(width - used), theme.text, None, None);
     }
 }
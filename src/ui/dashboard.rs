@@ -19,11 +19,43 @@ use crossterm::{queue, style::Color, style::Print};
 
 use crate::app_state::AppState;
 use crate::common::config::ThemeConfig;
-use crate::ui::text::{format_ram_value, print_colored_text};
+use crate::device::driver_versions::{VersionAudit, VersionField};
+use crate::device::power_headroom::cluster_headroom;
+use crate::ui::text::{format_throughput, print_colored_text, truncate_to_width};
+use crate::ui::theme;
+use crate::utils::{format_bytes, format_temperature, temperature_unit};
+
+/// Narrowest terminal that fits a seventh dashboard column
+const NETWORK_TILE_MIN_COLS: usize = 99;
+/// Columns each further dashboard column takes
+const TILE_COLS: usize = 14;
+/// Tile value shown before any host has reported the figure behind it
+const NO_DATA: &str = "no data";
+
+/// Label, value and label color of one dashboard tile
+type Tile = (&'static str, String, Color);
 
 pub fn draw_system_view<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
+    let theme = theme::current();
     let box_width = (cols as usize).min(80);
 
+    let (first_row, second_row) = dashboard_rows(state, cols);
+    print_dashboard_row(stdout, &first_row, box_width);
+    print_dashboard_row(stdout, &second_row, box_width);
+
+    if let Some((line, mixed)) = version_summary(state) {
+        let color = if mixed { theme.warning } else { theme.dim };
+        let line = truncate_to_width(&line, cols as usize);
+        print_colored_text(stdout, &line, color, None, None);
+        queue!(stdout, Print("\r\n")).unwrap();
+    }
+}
+
+/// The two rows of cluster tiles, with the network and power headroom
+/// columns when `cols` leaves room for them
+fn dashboard_rows(state: &AppState, cols: u16) -> (Vec<Tile>, Vec<Tile>) {
+    let theme = theme::current();
+
     // Calculate cluster statistics
     let is_local_mode = state.is_local_mode;
     let total_nodes = if is_local_mode {
@@ -35,9 +67,10 @@ pub fn draw_system_view<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
         1 // Local node is always considered live
     } else {
         state
-            .connection_status
-            .values()
-            .filter(|status| status.is_connected)
+            .tabs
+            .iter()
+            .skip(1)
+            .filter(|tab| state.is_host_connected(tab) == Some(true))
             .count()
     };
     let total_gpus = state.gpu_info.len();
@@ -69,22 +102,20 @@ pub fn draw_system_view<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
         total_gpus
     };
 
-    let total_memory_gb = if is_apple_silicon {
+    let total_memory_bytes = if is_apple_silicon {
         // Use system RAM for Apple Silicon
         state
             .memory_info
             .iter()
             .map(|memory| memory.total_bytes)
-            .sum::<u64>() as f64
-            / (1024.0 * 1024.0 * 1024.0)
+            .sum::<u64>()
     } else {
         // Use GPU memory for other platforms
         state
             .gpu_info
             .iter()
             .map(|gpu| gpu.total_memory)
-            .sum::<u64>() as f64
-            / (1024.0 * 1024.0 * 1024.0)
+            .sum::<u64>()
     };
 
     // Calculate total power
@@ -132,19 +163,17 @@ pub fn draw_system_view<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
         .sum::<u32>();
 
     // Calculate total system memory
-    let total_system_memory_gb = state
+    let total_system_memory_bytes = state
         .memory_info
         .iter()
         .map(|memory| memory.total_bytes)
-        .sum::<u64>() as f64
-        / (1024.0 * 1024.0 * 1024.0);
+        .sum::<u64>();
 
-    let used_system_memory_gb = state
+    let used_system_memory_bytes = state
         .memory_info
         .iter()
         .map(|memory| memory.used_bytes)
-        .sum::<u64>() as f64
-        / (1024.0 * 1024.0 * 1024.0);
+        .sum::<u64>();
 
     // Calculate averages
     let avg_utilization = if total_gpus > 0 {
@@ -197,9 +226,14 @@ pub fn draw_system_view<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
             0.0
         };
 
+        let unit = temperature_unit();
         (
-            format!("{avg_temperature:.0}°C"),
-            format!("±{temp_std_dev:.1}°C"),
+            format_temperature(avg_temperature),
+            format!(
+                "±{:.1}{}",
+                unit.convert_difference(temp_std_dev),
+                unit.symbol()
+            ),
         )
     };
 
@@ -209,78 +243,163 @@ pub fn draw_system_view<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
         0.0
     };
 
-    // Calculate used GPU memory in GB
-    let used_gpu_memory_gb = if is_apple_silicon {
+    // Calculate used GPU memory
+    let used_gpu_memory_bytes = if is_apple_silicon {
         // Use system RAM for Apple Silicon
         state
             .memory_info
             .iter()
             .map(|memory| memory.used_bytes)
-            .sum::<u64>() as f64
-            / (1024.0 * 1024.0 * 1024.0)
+            .sum::<u64>()
     } else {
         // Use GPU memory for other platforms
         state
             .gpu_info
             .iter()
             .map(|gpu| gpu.used_memory)
-            .sum::<u64>() as f64
-            / (1024.0 * 1024.0 * 1024.0)
+            .sum::<u64>()
     };
 
-    // First row: | Nodes | Total RAM | GPU Cores | Total GPU RAM | Avg. Temp | Total Power |
-    print_dashboard_row(
-        stdout,
-        &[
+    // Aggregate NIC throughput gets a seventh column when there is room for it
+    let network_tiles = (cols as usize >= NETWORK_TILE_MIN_COLS && !state.network_info.is_empty())
+        .then(|| {
+            let rx: f64 = state.network_info.iter().map(|n| n.rx_bytes_per_sec).sum();
+            let tx: f64 = state.network_info.iter().map(|n| n.tx_bytes_per_sec).sum();
             (
-                "Nodes",
-                format!("{live_nodes}/{total_nodes}"),
-                Color::Yellow,
-            ),
-            (
-                "Total RAM",
-                format_ram_value(total_system_memory_gb),
-                Color::Green,
-            ),
-            ("GPU Cores", format!("{gpu_cores_display}"), Color::Cyan),
-            ("Total VRAM", format_ram_value(total_memory_gb), Color::Blue),
-            ("Avg. Temp", avg_temperature_display, Color::Magenta),
+                ("Net RX", format_throughput(rx), theme.label_network),
+                ("Net TX", format_throughput(tx), theme.label_network),
+            )
+        });
+
+    // Power headroom gets the next column, flagged while any GPU is capped
+    let extra_columns = usize::from(network_tiles.is_some());
+    let headroom_tiles = cluster_headroom(&state.gpu_info)
+        .filter(|_| cols as usize >= NETWORK_TILE_MIN_COLS + extra_columns * TILE_COLS)
+        .map(|headroom| {
+            let color = if headroom.capped > 0 {
+                theme.critical
+            } else {
+                theme.label_power
+            };
             (
-                "Total Power",
-                format!("{:.1}kW", total_power_watts / 1000.0),
-                Color::Red,
-            ),
-        ],
-        box_width,
-    );
+                (
+                    "Pwr Capped",
+                    format!("{}/{}", headroom.capped, headroom.devices),
+                    color,
+                ),
+                (
+                    "Pwr Headroom",
+                    format!("{:+.1}W", headroom.average_watts),
+                    color,
+                ),
+            )
+        });
+
+    // Tiles backed by no reading yet (e.g. no host reachable) say so instead
+    // of showing 0°C or 0.0kW
+    let has_gpus = total_gpus > 0;
+    let has_cpus = !state.cpu_info.is_empty();
+    let has_memory = !state.memory_info.is_empty();
+    let tile = |available: bool, value: String| {
+        if available {
+            value
+        } else {
+            NO_DATA.to_string()
+        }
+    };
+
+    // First row: | Nodes | Total RAM | GPU Cores | Total GPU RAM | Avg. Temp | Total Power |
+    let mut first_row = vec![
+        ("Nodes", format!("{live_nodes}/{total_nodes}"), theme.label),
+        (
+            "Total RAM",
+            tile(has_memory, format_bytes(total_system_memory_bytes)),
+            theme.label_info,
+        ),
+        ("GPU Cores", format!("{gpu_cores_display}"), theme.title),
+        (
+            "Total VRAM",
+            tile(has_gpus, format_bytes(total_memory_bytes)),
+            theme.label_memory,
+        ),
+        (
+            "Avg. Temp",
+            tile(has_gpus, avg_temperature_display),
+            theme.label_thermal,
+        ),
+        (
+            "Total Power",
+            tile(has_gpus, format!("{:.1}kW", total_power_watts / 1000.0)),
+            theme.label_power,
+        ),
+    ];
 
     // Second row: | CPU Cores | Used RAM | Avg. GPU Util | Used GPU RAM | Temp. Stdev | Avg. Power |
-    print_dashboard_row(
-        stdout,
-        &[
-            ("CPU Cores", format!("{total_cpu_cores}"), Color::Cyan),
-            (
-                "Used RAM",
-                format_ram_value(used_system_memory_gb),
-                Color::Green,
-            ),
-            ("GPU Util", format!("{avg_utilization:.1}%"), Color::Blue),
-            (
-                "Used VRAM",
-                format_ram_value(used_gpu_memory_gb),
-                Color::Blue,
-            ),
-            ("Temp. Stdev", temp_std_dev_display, Color::Magenta),
-            ("Avg. Power", format!("{avg_power:.1}W"), Color::Red),
-        ],
-        box_width,
-    );
+    let mut second_row = vec![
+        (
+            "CPU Cores",
+            tile(has_cpus, format!("{total_cpu_cores}")),
+            theme.title,
+        ),
+        (
+            "Used RAM",
+            tile(has_memory, format_bytes(used_system_memory_bytes)),
+            theme.label_info,
+        ),
+        (
+            "GPU Util",
+            tile(has_gpus, format!("{avg_utilization:.1}%")),
+            theme.label_memory,
+        ),
+        (
+            "Used VRAM",
+            tile(has_gpus, format_bytes(used_gpu_memory_bytes)),
+            theme.label_memory,
+        ),
+        (
+            "Temp. Stdev",
+            tile(has_gpus, temp_std_dev_display),
+            theme.label_thermal,
+        ),
+        (
+            "Avg. Power",
+            tile(has_gpus, format!("{avg_power:.1}W")),
+            theme.label_power,
+        ),
+    ];
+
+    if let Some((rx_tile, tx_tile)) = network_tiles {
+        first_row.push(rx_tile);
+        second_row.push(tx_tile);
+    }
+    if let Some((capped_tile, headroom_tile)) = headroom_tiles {
+        first_row.push(capped_tile);
+        second_row.push(headroom_tile);
+    }
+    (first_row, second_row)
+}
+
+/// `drivers: 535.161 ×48, 550.54 ×2  cuda: 12.2 ×50` for the cluster, and
+/// whether any field has more than one version. Remote mode only.
+pub fn version_summary(state: &AppState) -> Option<(String, bool)> {
+    if state.is_local_mode {
+        return None;
+    }
+    let audit = VersionAudit::new(&state.gpu_info);
+    let fields: Vec<String> = [
+        (VersionField::Driver, "drivers"),
+        (VersionField::Cuda, "cuda"),
+    ]
+    .into_iter()
+    .filter_map(|(field, label)| Some(format!("{label}: {}", audit.summary(field)?)))
+    .collect();
+    (!fields.is_empty()).then(|| (format!(" {}", fields.join("  ")), audit.is_mixed()))
 }
 
 pub fn draw_dashboard_items<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
     // Print separator
     let separator = "─".repeat(cols as usize);
-    print_colored_text(stdout, &separator, Color::DarkGrey, None, None);
+    print_colored_text(stdout, &separator, theme::current().dim, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Node utilization history box
@@ -288,42 +407,31 @@ pub fn draw_dashboard_items<W: Write>(stdout: &mut W, state: &AppState, cols: u1
     queue!(stdout, Print("\r\n")).unwrap();
 }
 
-fn print_dashboard_row<W: Write>(
-    stdout: &mut W,
-    items: &[(&str, String, Color)],
-    _total_width: usize,
-) {
+fn print_dashboard_row<W: Write>(stdout: &mut W, items: &[Tile], _total_width: usize) {
+    let theme = theme::current();
     const ITEM_WIDTH: usize = 15; // Fixed width for each dashboard item
 
     // Print labels row
-    print_colored_text(stdout, "│", Color::DarkGrey, None, None);
+    print_colored_text(stdout, "│", theme.dim, None, None);
     for (label, _, color) in items {
         // Truncate label if too long, ensuring it fits in 15 characters minus padding and separator
         let max_label_len = ITEM_WIDTH.saturating_sub(3);
-        let truncated_label = if label.len() > max_label_len {
-            &label[..max_label_len]
-        } else {
-            label
-        };
+        let truncated_label = truncate_to_width(label, max_label_len);
         let formatted_label = format!(" {truncated_label:<max_label_len$}");
         print_colored_text(stdout, &formatted_label, *color, None, None);
-        print_colored_text(stdout, "│", Color::DarkGrey, None, None);
+        print_colored_text(stdout, "│", theme.dim, None, None);
     }
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Print values row
-    print_colored_text(stdout, "│", Color::DarkGrey, None, None);
+    print_colored_text(stdout, "│", theme.dim, None, None);
     for (_, value, _) in items {
         // Truncate value if too long, ensuring it fits in 15 characters minus padding and separator
         let max_value_len = ITEM_WIDTH.saturating_sub(3);
-        let truncated_value = if value.len() > max_value_len {
-            &value[..max_value_len]
-        } else {
-            value
-        };
+        let truncated_value = truncate_to_width(value, max_value_len);
         let formatted_value = format!(" {truncated_value:<max_value_len$}");
-        print_colored_text(stdout, &formatted_value, Color::White, None, None);
-        print_colored_text(stdout, "│", Color::DarkGrey, None, None);
+        print_colored_text(stdout, &formatted_value, theme.text, None, None);
+        print_colored_text(stdout, "│", theme.dim, None, None);
     }
     queue!(stdout, Print("\r\n")).unwrap();
 }
@@ -372,7 +480,13 @@ pub fn draw_utilization_history<W: Write>(stdout: &mut W, state: &AppState, cols
     };
 
     // Print header
-    print_colored_text(stdout, "Live Statistics", Color::Cyan, None, None);
+    print_colored_text(
+        stdout,
+        "Live Statistics",
+        theme::current().title,
+        None,
+        None,
+    );
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Layout: left half for node view, right half split between GPU and CPU stats
@@ -431,6 +545,7 @@ struct NodeViewParams {
 }
 
 fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, params: NodeViewParams) {
+    let theme = theme::current();
     // Get nodes (excluding "All" tab) - these are host addresses
     let nodes: Vec<&String> = state.tabs.iter().skip(1).collect();
 
@@ -479,7 +594,7 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
             print_colored_text(
                 stdout,
                 &" ".repeat(params.node_width),
-                Color::White,
+                theme.text,
                 None,
                 None,
             );
@@ -489,7 +604,7 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
         if params.has_gpu {
             match row {
                 0 => {
-                    print_colored_text(stdout, "GPU Util.", Color::Yellow, None, None);
+                    print_colored_text(stdout, "GPU Util.", theme.label, None, None);
                     print_history_bar_with_value(
                         stdout,
                         &state.utilization_history,
@@ -499,7 +614,7 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
                     );
                 }
                 1 => {
-                    print_colored_text(stdout, "GPU Mem. ", Color::Yellow, None, None);
+                    print_colored_text(stdout, "GPU Mem. ", theme.label, None, None);
                     print_history_bar_with_value(
                         stdout,
                         &state.memory_history,
@@ -509,13 +624,13 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
                     );
                 }
                 2 => {
-                    print_colored_text(stdout, "GPU Temp.", Color::Yellow, None, None);
+                    print_colored_text(stdout, "GPU Temp.", theme.label, None, None);
                     print_history_bar_with_value(
                         stdout,
                         &state.temperature_history,
                         params.gpu_history_width + 1, // +1 to fit the temperature value
                         100.0,
-                        format!("{:3.0}°C", params.avg_temp),
+                        format_history_temperature(params.avg_temp),
                     );
                 }
                 _ => {}
@@ -524,35 +639,35 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
             // No GPU, show labels with N/A values
             match row {
                 0 => {
-                    print_colored_text(stdout, "GPU Util.", Color::Yellow, None, None);
+                    print_colored_text(stdout, "GPU Util.", theme.label, None, None);
                     // Fill the history bar area with spaces, then show N/A
                     let padding = params.gpu_history_width.saturating_sub(5); // Reserve space for "  N/A" (5 chars like "xx.x%")
-                    print_colored_text(stdout, &" ".repeat(padding), Color::DarkGrey, None, None);
-                    print_colored_text(stdout, "  N/A", Color::DarkGrey, None, None);
+                    print_colored_text(stdout, &" ".repeat(padding), theme.dim, None, None);
+                    print_colored_text(stdout, "  N/A", theme.dim, None, None);
                 }
                 1 => {
-                    print_colored_text(stdout, "GPU Mem. ", Color::Yellow, None, None);
+                    print_colored_text(stdout, "GPU Mem. ", theme.label, None, None);
                     let padding = params.gpu_history_width.saturating_sub(5);
-                    print_colored_text(stdout, &" ".repeat(padding), Color::DarkGrey, None, None);
-                    print_colored_text(stdout, "  N/A", Color::DarkGrey, None, None);
+                    print_colored_text(stdout, &" ".repeat(padding), theme.dim, None, None);
+                    print_colored_text(stdout, "  N/A", theme.dim, None, None);
                 }
                 2 => {
-                    print_colored_text(stdout, "GPU Temp.", Color::Yellow, None, None);
+                    print_colored_text(stdout, "GPU Temp.", theme.label, None, None);
                     let padding = params.gpu_history_width.saturating_sub(5);
-                    print_colored_text(stdout, &" ".repeat(padding), Color::DarkGrey, None, None);
-                    print_colored_text(stdout, "  N/A", Color::DarkGrey, None, None);
+                    print_colored_text(stdout, &" ".repeat(padding), theme.dim, None, None);
+                    print_colored_text(stdout, "  N/A", theme.dim, None, None);
                 }
                 _ => {}
             }
         }
 
         // Add spacing between GPU and CPU stats
-        print_colored_text(stdout, "  ", Color::White, None, None);
+        print_colored_text(stdout, "  ", theme.text, None, None);
 
         // Print CPU statistics
         match row {
             0 => {
-                print_colored_text(stdout, "CPU Util.", Color::Cyan, None, None);
+                print_colored_text(stdout, "CPU Util.", theme.title, None, None);
                 print_history_bar_with_value(
                     stdout,
                     &state.cpu_utilization_history,
@@ -562,7 +677,7 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
                 );
             }
             1 => {
-                print_colored_text(stdout, "Host Mem.", Color::Cyan, None, None);
+                print_colored_text(stdout, "Host Mem.", theme.title, None, None);
                 print_history_bar_with_value(
                     stdout,
                     &state.system_memory_history,
@@ -572,13 +687,13 @@ fn print_node_view_and_history<W: Write>(stdout: &mut W, state: &AppState, param
                 );
             }
             2 => {
-                print_colored_text(stdout, "CPU Temp.", Color::Cyan, None, None);
+                print_colored_text(stdout, "CPU Temp.", theme.title, None, None);
                 print_history_bar_with_value(
                     stdout,
                     &state.cpu_temperature_history,
                     params.cpu_history_width + 1, // +1 to fit the temperature value
                     100.0,
-                    format!("{:3.0}°C", params.avg_cpu_temp),
+                    format_history_temperature(params.avg_cpu_temp),
                 );
             }
             _ => {}
@@ -640,7 +755,7 @@ fn print_node_view_row<W: Write>(stdout: &mut W, params: NodeViewRowParams) {
         print_colored_text(
             stdout,
             &" ".repeat(padding_needed),
-            Color::White,
+            theme::current().text,
             None,
             None,
         );
@@ -653,8 +768,8 @@ fn get_node_char_and_color(
     is_connected: bool,
 ) -> (char, Color) {
     if !is_connected {
-        // Disconnected node - show circle with X in gray
-        return ('⊗', Color::DarkGrey);
+        // Disconnected node - show a dimmed circle with X
+        return ('⊗', theme::current().dim);
     }
 
     let base_color = ThemeConfig::utilization_color(utilization);
@@ -662,6 +777,12 @@ fn get_node_char_and_color(
     (char, base_color)
 }
 
+/// Average temperature next to a history bar, three digits wide
+fn format_history_temperature(celsius: f64) -> String {
+    let unit = temperature_unit();
+    format!("{:3.0}{}", unit.convert(celsius), unit.symbol())
+}
+
 fn print_history_bar_with_value<W: Write>(
     stdout: &mut W,
     history: &std::collections::VecDeque<f64>,
@@ -669,6 +790,7 @@ fn print_history_bar_with_value<W: Write>(
     max_value: f64,
     value_text: String,
 ) {
+    let theme = theme::current();
     if history.is_empty() || width == 0 {
         return;
     }
@@ -723,10 +845,38 @@ fn print_history_bar_with_value<W: Write>(
     let remaining = available_width.saturating_sub(sampled_data.len());
     if remaining > 0 && remaining < 50 {
         // Limit to prevent long horizontal lines
-        print_colored_text(stdout, &"⠀".repeat(remaining), Color::DarkGrey, None, None);
+        print_colored_text(stdout, &"⠀".repeat(remaining), theme.dim, None, None);
     }
 
     // Print value
-    print_colored_text(stdout, " ", Color::White, None, None);
-    print_colored_text(stdout, &value_text, Color::White, None, None);
+    print_colored_text(stdout, " ", theme.text, None, None);
+    print_colored_text(stdout, &value_text, theme.text, None, None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_cluster_shows_no_data() {
+        let mut state = AppState::new();
+        state.is_local_mode = false;
+        state.tabs = vec!["All".to_string(), "10.0.0.1:9090".to_string()];
+
+        let mut out = Vec::new();
+        draw_system_view(&mut out, &state, 120);
+        draw_dashboard_items(&mut out, &state, 120);
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("0/1"));
+        // Only the node count and GPU core count need no reading
+        let (first_row, second_row) = dashboard_rows(&state, 120);
+        for (label, value, _) in first_row.iter().chain(&second_row) {
+            let counted = matches!(*label, "Nodes" | "GPU Cores");
+            assert_eq!(value == NO_DATA, !counted, "{label}: {value}");
+        }
+        for misleading in ["0.0kW", "0.0W", "0.0%", "°", "0B"] {
+            assert!(!text.contains(misleading), "{misleading} in {text:?}");
+        }
+    }
 }
@@ -0,0 +1,612 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Full-screen cluster heatmap for remote mode.
+//!
+//! Every host gets one cell colored by the selected [`HeatmapMetric`]. Cells
+//! are laid out automatically to fill the terminal, or at the positions given
+//! in a rack layout file so the grid mirrors the machine room.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crossterm::{queue, style::Color, style::Print};
+
+use crate::app_state::{AppState, HeatmapMetric};
+use crate::common::config::ThemeConfig;
+use crate::ui::text::{print_colored_text, truncate_to_width};
+use crate::ui::theme;
+use crate::utils::format_temperature;
+
+/// Title, cursor line and a blank line above the grid
+const HEADER_LINES: usize = 3;
+/// Legend below the grid
+const FOOTER_LINES: usize = 1;
+
+const MIN_CELL_WIDTH: usize = 4;
+const MAX_CELL_WIDTH: usize = 12;
+const MAX_CELL_HEIGHT: usize = 3;
+
+/// Largest row or column accepted from a rack layout file.
+const MAX_RACK_POSITION: usize = 1024;
+
+/// Errors raised while loading a rack layout file
+#[derive(Debug, thiserror::Error)]
+pub enum RackLayoutError {
+    #[error("Cannot read rack layout {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid rack layout, line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
+
+/// Physical positions of hosts, read from a file with one
+/// `<host> <row> <column>` entry per line (1-based, `#` starts a comment).
+/// Hosts are matched by address or by reported hostname.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RackLayout {
+    positions: HashMap<String, (usize, usize)>,
+    rows: usize,
+    columns: usize,
+}
+
+impl RackLayout {
+    pub fn load(path: &Path) -> Result<Self, RackLayoutError> {
+        let content = std::fs::read_to_string(path).map_err(|source| RackLayoutError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, RackLayoutError> {
+        let mut layout = Self::default();
+        let mut occupied: HashMap<(usize, usize), String> = HashMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line_no = index + 1;
+            let error = |message: String| RackLayoutError::Parse {
+                line: line_no,
+                message,
+            };
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [host, row, column] = fields[..] else {
+                return Err(error(format!(
+                    "expected `<host> <row> <column>`, got {} fields",
+                    fields.len()
+                )));
+            };
+            let position = |value: &str, what: &str| {
+                value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|v| (1..=MAX_RACK_POSITION).contains(v))
+                    .map(|v| v - 1)
+                    .ok_or_else(|| {
+                        error(format!(
+                            "{what} must be between 1 and {MAX_RACK_POSITION}, got '{value}'"
+                        ))
+                    })
+            };
+            let cell = (position(row, "row")?, position(column, "column")?);
+
+            if let Some(other) = occupied.get(&cell) {
+                return Err(error(format!(
+                    "{host} is placed on the same cell as {other}"
+                )));
+            }
+            if layout.positions.insert(host.to_string(), cell).is_some() {
+                return Err(error(format!("{host} is listed more than once")));
+            }
+            occupied.insert(cell, host.to_string());
+            layout.rows = layout.rows.max(cell.0 + 1);
+            layout.columns = layout.columns.max(cell.1 + 1);
+        }
+        Ok(layout)
+    }
+
+    /// Zero-based `(row, column)` of a host.
+    pub fn position(&self, host: &str) -> Option<(usize, usize)> {
+        self.positions.get(host).copied()
+    }
+}
+
+/// Shape of the heatmap grid and the size of each cell in characters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GridLayout {
+    pub columns: usize,
+    pub rows: usize,
+    pub cell_width: usize,
+    pub cell_height: usize,
+}
+
+/// Pick a grid for `host_count` cells in a `width` x `height` area, keeping
+/// cells as large and square as possible. Clusters too large to fit get
+/// one-line cells and the grid scrolls vertically.
+pub fn grid_layout(host_count: usize, width: usize, height: usize) -> GridLayout {
+    if host_count == 0 || width < MIN_CELL_WIDTH || height == 0 {
+        return GridLayout::default();
+    }
+
+    let max_columns = (width / MIN_CELL_WIDTH).min(host_count);
+    (1..=max_columns)
+        .map(|columns| sized_grid(columns, host_count.div_ceil(columns), width, height))
+        .filter(|grid| grid.rows * grid.cell_height <= height)
+        // Terminal characters are about twice as tall as they are wide
+        .max_by_key(|grid| {
+            let squareness = grid.cell_width.min(grid.cell_height * 2);
+            let area = grid.cell_width * grid.cell_height;
+            (squareness, area, grid.columns)
+        })
+        .unwrap_or_else(|| sized_grid(max_columns, host_count.div_ceil(max_columns), width, 1))
+}
+
+/// Cell size for a grid of fixed shape, as used for rack layouts.
+pub fn sized_grid(columns: usize, rows: usize, width: usize, height: usize) -> GridLayout {
+    if columns == 0 || rows == 0 {
+        return GridLayout::default();
+    }
+    GridLayout {
+        columns,
+        rows,
+        cell_width: (width / columns).clamp(1, MAX_CELL_WIDTH),
+        cell_height: (height / rows).clamp(1, MAX_CELL_HEIGHT),
+    }
+}
+
+/// Hosts placed on the grid, as indices into `AppState::tabs`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeatmapGrid {
+    pub layout: GridLayout,
+    cells: Vec<Option<usize>>,
+}
+
+impl HeatmapGrid {
+    /// Lay out the hosts of `state` for a terminal of `cols` x `rows`.
+    pub fn build(state: &AppState, cols: u16, rows: u16) -> Self {
+        let width = cols as usize;
+        let height = (rows as usize).saturating_sub(HEADER_LINES + FOOTER_LINES);
+        let host_tabs = 1..state.tabs.len().max(1);
+
+        let Some(rack) = &state.rack_layout else {
+            let layout = grid_layout(host_tabs.len(), width, height);
+            return Self {
+                layout,
+                cells: host_tabs.map(Some).collect(),
+            };
+        };
+
+        // Hosts missing from the rack layout fill the rows below it
+        let columns = rack.columns.max(1);
+        let mut placed: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut unplaced = Vec::new();
+        for tab in host_tabs {
+            let host = &state.tabs[tab];
+            let display_name = state
+                .connection_status
+                .get(host)
+                .and_then(|status| status.actual_hostname.as_deref());
+            let cell = rack
+                .position(host)
+                .or_else(|| display_name.and_then(|name| rack.position(name)));
+            match cell {
+                Some(cell) if !placed.contains_key(&cell) => {
+                    placed.insert(cell, tab);
+                }
+                _ => unplaced.push(tab),
+            }
+        }
+
+        let total_rows = rack.rows + unplaced.len().div_ceil(columns);
+        let mut cells = vec![None; total_rows * columns];
+        for ((row, column), tab) in placed {
+            cells[row * columns + column] = Some(tab);
+        }
+        for (offset, tab) in unplaced.into_iter().enumerate() {
+            cells[rack.rows * columns + offset] = Some(tab);
+        }
+
+        Self {
+            layout: sized_grid(columns, total_rows, width, height),
+            cells,
+        }
+    }
+
+    /// Tab index of the host in a cell, `None` for empty rack slots.
+    pub fn host_at(&self, row: usize, column: usize) -> Option<usize> {
+        if column >= self.layout.columns {
+            return None;
+        }
+        self.cells
+            .get(row * self.layout.columns + column)
+            .copied()
+            .flatten()
+    }
+}
+
+/// Aggregate of a host's accelerators for one metric, `None` without devices.
+pub fn host_metric_value(state: &AppState, host: &str, metric: HeatmapMetric) -> Option<f64> {
+    let gpus: Vec<_> = state
+        .gpu_info
+        .iter()
+        .filter(|g| g.host_id == host)
+        .collect();
+    if gpus.is_empty() {
+        return None;
+    }
+    let value = match metric {
+        HeatmapMetric::GpuUtilization => {
+            gpus.iter().map(|g| g.utilization).sum::<f64>() / gpus.len() as f64
+        }
+        HeatmapMetric::MemoryUtilization => {
+            let total: u64 = gpus.iter().map(|g| g.total_memory).sum();
+            let used: u64 = gpus.iter().map(|g| g.used_memory).sum();
+            if total == 0 {
+                return None;
+            }
+            used as f64 / total as f64 * 100.0
+        }
+        HeatmapMetric::MaxTemperature => gpus.iter().map(|g| g.temperature).max()? as f64,
+        HeatmapMetric::Power => gpus.iter().map(|g| g.power_consumption).sum(),
+    };
+    Some(value)
+}
+
+fn format_metric_value(value: f64, metric: HeatmapMetric) -> String {
+    match metric {
+        HeatmapMetric::GpuUtilization | HeatmapMetric::MemoryUtilization => {
+            format!("{value:.0}%")
+        }
+        HeatmapMetric::MaxTemperature => format_temperature(value),
+        HeatmapMetric::Power if value >= 1000.0 => format!("{:.1}kW", value / 1000.0),
+        HeatmapMetric::Power => format!("{value:.0}W"),
+    }
+}
+
+/// Cell color for a value. Power has no fixed scale, so it is colored
+/// relative to the busiest host.
+fn metric_color(value: f64, metric: HeatmapMetric, max_power: f64) -> Color {
+    let theme = theme::current();
+    match metric {
+        HeatmapMetric::GpuUtilization | HeatmapMetric::MemoryUtilization => {
+            ThemeConfig::utilization_color(value)
+        }
+        HeatmapMetric::MaxTemperature => {
+            if value > 85.0 {
+                theme.critical
+            } else if value > 75.0 {
+                theme.warning
+            } else if value > 50.0 {
+                theme.ok
+            } else {
+                theme.dim
+            }
+        }
+        HeatmapMetric::Power if max_power > 0.0 => {
+            ThemeConfig::utilization_color(value / max_power * 100.0)
+        }
+        HeatmapMetric::Power => theme.dim,
+    }
+}
+
+struct Cell {
+    text: String,
+    background: Option<Color>,
+}
+
+/// Draw the heatmap screen for the cursor in `state.heatmap`.
+pub fn draw_heatmap<W: Write>(stdout: &mut W, state: &AppState, cols: u16, rows: u16) {
+    let theme = theme::current();
+    let Some(view) = &state.heatmap else {
+        return;
+    };
+    let grid = HeatmapGrid::build(state, cols, rows);
+    let layout = grid.layout;
+    let cursor_row = view.row.min(layout.rows.saturating_sub(1));
+    let cursor_column = view.column.min(layout.columns.saturating_sub(1));
+
+    let values: HashMap<usize, f64> = (1..state.tabs.len())
+        .filter_map(|tab| {
+            host_metric_value(state, &state.tabs[tab], view.metric).map(|value| (tab, value))
+        })
+        .collect();
+    let max_power = values.values().copied().fold(0.0, f64::max);
+
+    // Header: title and the host under the cursor
+    print_colored_text(
+        stdout,
+        &format!("Cluster Heatmap: {}", view.metric.label()),
+        theme.title,
+        None,
+        None,
+    );
+    print_colored_text(
+        stdout,
+        "   m: metric  ←↑↓→: move  Enter: open host  v/Esc: close",
+        theme.dim,
+        None,
+        None,
+    );
+    queue!(stdout, Print("\r\n")).unwrap();
+
+    let cursor_text = match grid.host_at(cursor_row, cursor_column) {
+        Some(tab) => {
+            let host = &state.tabs[tab];
+            let status = state.connection_status.get(host);
+            let name = status
+                .and_then(|s| s.actual_hostname.as_deref())
+                .unwrap_or(host);
+            let value = match values.get(&tab) {
+                Some(value) => format_metric_value(*value, view.metric),
+                None if status.is_some_and(|s| !s.is_connected) => "disconnected".to_string(),
+                None => "no devices".to_string(),
+            };
+            format!("▶ {name} ({host})  {value}")
+        }
+        None if layout.columns == 0 => "▶ No hosts".to_string(),
+        None => format!(
+            "▶ Empty slot (row {}, column {})",
+            cursor_row + 1,
+            cursor_column + 1
+        ),
+    };
+    print_colored_text(
+        stdout,
+        &truncate_to_width(&cursor_text, cols as usize),
+        theme.text,
+        None,
+        None,
+    );
+    queue!(stdout, Print("\r\n\r\n")).unwrap();
+
+    // Grid, scrolled so the cursor row stays visible
+    let height = (rows as usize).saturating_sub(HEADER_LINES + FOOTER_LINES);
+    let visible_rows = (height / layout.cell_height.max(1)).max(1);
+    let first_row = (cursor_row + 1).saturating_sub(visible_rows);
+    let visible_columns = layout.columns.min(cols as usize / layout.cell_width.max(1));
+    let gap = usize::from(layout.cell_width > 1);
+    let fill_lines = if layout.cell_height > 1 {
+        layout.cell_height - 1
+    } else {
+        1
+    };
+    let mut lines_drawn = 0;
+
+    for row in first_row..layout.rows.min(first_row + visible_rows) {
+        let cells: Vec<Cell> = (0..visible_columns)
+            .map(|column| {
+                let Some(tab) = grid.host_at(row, column) else {
+                    return Cell {
+                        text: "·".to_string(),
+                        background: None,
+                    };
+                };
+                let host = &state.tabs[tab];
+                let connected = state
+                    .connection_status
+                    .get(host)
+                    .map(|status| status.is_connected)
+                    .unwrap_or(false);
+                let value = values.get(&tab).copied();
+                let text = match value {
+                    _ if !connected => "×".to_string(),
+                    Some(value) => format_metric_value(value, view.metric),
+                    None => "-".to_string(),
+                };
+                let background = if row == cursor_row && column == cursor_column {
+                    theme.selection_bg
+                } else {
+                    value
+                        .filter(|_| connected)
+                        .map(|value| metric_color(value, view.metric, max_power))
+                        .unwrap_or(theme.dim)
+                };
+                Cell {
+                    text,
+                    background: Some(background),
+                }
+            })
+            .collect();
+
+        for line in 0..layout.cell_height {
+            for cell in &cells {
+                let inner = layout.cell_width - gap;
+                if line >= fill_lines {
+                    print_colored_text(
+                        stdout,
+                        &" ".repeat(layout.cell_width),
+                        theme.text,
+                        None,
+                        None,
+                    );
+                    continue;
+                }
+                let text = if line == (fill_lines - 1) / 2 && cell.text.chars().count() <= inner {
+                    cell.text.as_str()
+                } else {
+                    ""
+                };
+                let content = format!("{text:^inner$}");
+                match cell.background {
+                    Some(background) => {
+                        print_colored_text(stdout, &content, theme.badge_fg, Some(background), None)
+                    }
+                    None => print_colored_text(stdout, &content, theme.dim, None, None),
+                }
+                print_colored_text(stdout, &" ".repeat(gap), theme.text, None, None);
+            }
+            queue!(stdout, Print("\r\n")).unwrap();
+            lines_drawn += 1;
+        }
+    }
+    for _ in lines_drawn..height {
+        queue!(stdout, Print("\r\n")).unwrap();
+    }
+
+    // Legend
+    print_colored_text(stdout, "Low ", theme.dim, None, None);
+    for color in [theme.dim, theme.ok, theme.warning, theme.critical] {
+        print_colored_text(stdout, "  ", theme.text, Some(color), None);
+    }
+    print_colored_text(
+        stdout,
+        " High   × disconnected   · empty slot",
+        theme.dim,
+        None,
+        None,
+    );
+    if layout.rows > visible_rows {
+        print_colored_text(
+            stdout,
+            &format!(
+                "   rows {}-{} of {}",
+                first_row + 1,
+                (first_row + visible_rows).min(layout.rows),
+                layout.rows
+            ),
+            theme.dim,
+            None,
+            None,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_fits(grid: GridLayout, host_count: usize, width: usize, height: usize) {
+        assert!(grid.columns * grid.rows >= host_count, "{grid:?}");
+        assert!(grid.columns * grid.cell_width <= width, "{grid:?}");
+        assert!(grid.rows * grid.cell_height <= height, "{grid:?}");
+        assert!(grid.cell_width >= MIN_CELL_WIDTH, "{grid:?}");
+    }
+
+    #[test]
+    fn test_grid_layout_small_cluster() {
+        let grid = grid_layout(4, 80, 20);
+        assert_eq!(
+            grid,
+            GridLayout {
+                columns: 4,
+                rows: 1,
+                cell_width: MAX_CELL_WIDTH,
+                cell_height: MAX_CELL_HEIGHT,
+            }
+        );
+        assert_eq!(grid_layout(1, 80, 20).columns, 1);
+    }
+
+    #[test]
+    fn test_grid_layout_fills_terminal() {
+        for (hosts, width, height) in [(16, 80, 20), (100, 80, 20), (256, 200, 50), (7, 30, 5)] {
+            assert_fits(grid_layout(hosts, width, height), hosts, width, height);
+        }
+        // Larger terminals never get smaller cells
+        let small = grid_layout(64, 80, 20);
+        let large = grid_layout(64, 160, 40);
+        assert!(large.cell_width * large.cell_height >= small.cell_width * small.cell_height);
+    }
+
+    #[test]
+    fn test_grid_layout_overflow_scrolls() {
+        let grid = grid_layout(1000, 80, 20);
+        assert_eq!(grid.columns, 80 / MIN_CELL_WIDTH);
+        assert_eq!(grid.rows, 1000usize.div_ceil(grid.columns));
+        assert_eq!(grid.cell_height, 1);
+    }
+
+    #[test]
+    fn test_grid_layout_degenerate() {
+        assert_eq!(grid_layout(0, 80, 20), GridLayout::default());
+        assert_eq!(grid_layout(10, 2, 20), GridLayout::default());
+        assert_eq!(grid_layout(10, 80, 0), GridLayout::default());
+    }
+
+    #[test]
+    fn test_sized_grid() {
+        assert_eq!(
+            sized_grid(10, 3, 80, 20),
+            GridLayout {
+                columns: 10,
+                rows: 3,
+                cell_width: 8,
+                cell_height: 3,
+            }
+        );
+        assert_eq!(sized_grid(200, 40, 80, 20).cell_width, 1);
+        assert_eq!(sized_grid(0, 3, 80, 20), GridLayout::default());
+    }
+
+    #[test]
+    fn test_parse_rack_layout() {
+        let layout = RackLayout::parse(
+            "# rack A\nnode-01 1 1\nnode-02 1 2  # top of rack\n\n10.0.0.9:9090 3 4\n",
+        )
+        .unwrap();
+        assert_eq!(layout.position("node-02"), Some((0, 1)));
+        assert_eq!(layout.position("10.0.0.9:9090"), Some((2, 3)));
+        assert_eq!((layout.rows, layout.columns), (3, 4));
+
+        for bad in [
+            "node 1",
+            "node 0 1",
+            "node 1 x",
+            "a 1 1\nb 1 1",
+            "a 1 1\na 2 2",
+        ] {
+            assert!(RackLayout::parse(bad).is_err(), "{bad}");
+        }
+        assert!(matches!(
+            RackLayout::parse("a 1 1\nb 1 1"),
+            Err(RackLayoutError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_heatmap_grid_with_rack_layout() {
+        let mut state = AppState::new();
+        state.tabs = vec![
+            "All".to_string(),
+            "10.0.0.1:9090".to_string(),
+            "10.0.0.2:9090".to_string(),
+            "10.0.0.3:9090".to_string(),
+        ];
+        state.rack_layout =
+            Some(RackLayout::parse("10.0.0.2:9090 1 2\n10.0.0.1:9090 2 1\n").unwrap());
+
+        let grid = HeatmapGrid::build(&state, 80, 24);
+        assert_eq!((grid.layout.columns, grid.layout.rows), (2, 3));
+        assert_eq!(grid.host_at(0, 0), None);
+        assert_eq!(grid.host_at(0, 1), Some(2));
+        assert_eq!(grid.host_at(1, 0), Some(1));
+        // Unmapped hosts go below the rack
+        assert_eq!(grid.host_at(2, 0), Some(3));
+        assert_eq!(grid.host_at(0, 5), None);
+
+        state.rack_layout = None;
+        let grid = HeatmapGrid::build(&state, 80, 24);
+        assert_eq!(grid.layout.columns * grid.layout.rows, 3);
+        assert_eq!(grid.host_at(0, 0), Some(1));
+    }
+}
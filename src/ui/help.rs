@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::app_state::AppState;
+use crate::ui::theme;
 use crossterm::style::{Color, Stylize};
 
 /// Generate a full-screen, colorful help interface with three sections:
@@ -126,6 +127,7 @@ fn get_row_content(
 }
 
 fn render_title_section(line_idx: usize, width: usize) -> String {
+    let theme = theme::current();
     let title_lines = [
         "",
         "    █████╗ ██╗     ██╗          ███████╗███╗   ███╗██╗",
@@ -142,12 +144,12 @@ fn render_title_section(line_idx: usize, width: usize) -> String {
     let description_lines = ["Developed and maintained as part of the Backend.AI project."];
 
     if line_idx < title_lines.len() {
-        center_text_colored(title_lines[line_idx], width, Color::Cyan)
+        center_text_colored(title_lines[line_idx], width, theme.title)
     } else if line_idx < title_lines.len() + description_lines.len() {
         center_text_colored(
             description_lines[line_idx - title_lines.len()],
             width,
-            Color::Green,
+            theme.label_info,
         )
     } else {
         " ".repeat(width)
@@ -160,6 +162,7 @@ fn render_shortcuts_section(
     state: &AppState,
     is_remote: bool,
 ) -> String {
+    let theme = theme::current();
     // Split content into left and right columns
     let mut left_column = vec![
         ("Navigation Keys:", "", "header"),
@@ -170,14 +173,40 @@ fn render_shortcuts_section(
         ),
         ("  ↑ ↓", "Scroll up/down in lists", "shortcut"),
         ("  PgUp PgDn", "Page up/down navigation", "shortcut"),
-        ("  Home End", "Jump to top/bottom", "shortcut"),
+        ("  Home End", "Jump to All/last tab (remote)", "shortcut"),
+        ("  1-9", "Jump to tab 1-9 (remote)", "shortcut"),
+        (
+            "  /",
+            "Filter tabs by host, Enter jumps to first (remote)",
+            "shortcut",
+        ),
+        (
+            "  V",
+            "Host heatmap; M: metric, Enter: open host (remote)",
+            "shortcut",
+        ),
         ("", "", ""),
         ("Display Control:", "", "header"),
         ("  H", "Toggle this help screen", "shortcut"),
+        ("  L", "Show recent log messages", "shortcut"),
         ("  C", "Toggle per-core CPU display", "shortcut"),
+        (
+            "  Shift+C",
+            "Flash values that changed a lot since last refresh",
+            "shortcut",
+        ),
         ("  F", "Toggle GPU process filter", "shortcut"),
+        ("  Space", "Pause/resume (no device selected)", "shortcut"),
+        ("  Shift+P", "Pause/resume the display", "shortcut"),
+        ("  R", "Refresh now instead of waiting", "shortcut"),
+        ("  Tab S-Tab", "Select next/previous device", "shortcut"),
+        (
+            "  Space",
+            "Pin/unpin selected device to the top",
+            "shortcut",
+        ),
         ("  Q", "Exit application", "shortcut"),
-        ("  ESC", "Close help or exit", "shortcut"),
+        ("  ESC", "Close help, clear selection or exit", "shortcut"),
         ("", "", ""),
         ("Data Sorting:", "", "header"),
         ("  D", "Sort by default (hostname+index)", "shortcut"),
@@ -190,7 +219,25 @@ fn render_shortcuts_section(
         left_column.extend(vec![
             ("  P", "Sort processes by PID", "shortcut"),
             ("  M", "Sort processes by memory", "shortcut"),
+            (
+                "  Shift+U",
+                "Group processes by user, Enter expands",
+                "shortcut",
+            ),
+            (
+                "  T",
+                "Top 10 processes by memory, utilization, or all",
+                "shortcut",
+            ),
+            ("  I", "Hide processes below 1%/5% GPU or CPU", "shortcut"),
         ]);
+        if !state.readonly {
+            left_column.extend(vec![
+                ("  K", "Send SIGTERM to selected process", "shortcut"),
+                ("  Shift+K", "Send SIGKILL to selected process", "shortcut"),
+            ]);
+        }
+        left_column.push(("  F2", "Show, hide and reorder process columns", "shortcut"));
     }
 
     left_column.extend(vec![
@@ -245,14 +292,30 @@ fn render_shortcuts_section(
     };
     right_column.push(("  Filter:", filter_status, "status"));
 
+    if let Some(top) = state.process_top.label() {
+        right_column.push(("  Top-N:", top, "status"));
+    }
+    let activity_status = format!("≥{}%", state.min_process_activity);
+    if state.min_process_activity > 0.0 {
+        right_column.push(("  Activity:", &activity_status, "status"));
+    }
+
+    // Display updates pause only freezes the view; collection continues
+    let update_status = if state.paused {
+        "Paused (collecting)"
+    } else {
+        "Live"
+    };
+    right_column.push(("  Updates:", update_status, "status"));
+
     // Handle special rows
     match line_idx {
-        0 => center_text_colored("KEYBOARD SHORTCUTS & NAVIGATION", width, Color::Yellow),
-        1 => "═".repeat(width).with(Color::DarkGrey).to_string(),
+        0 => center_text_colored("KEYBOARD SHORTCUTS & NAVIGATION", width, theme.label),
+        1 => "═".repeat(width).with(theme.dim).to_string(),
         2 => " ".repeat(width),
         _ => {
             let content_line = line_idx - 3; // Adjust for title and separator
-            let column_width = (width - 4) / 2; // Leave space for middle separator
+            let column_width = width.saturating_sub(4) / 2; // Leave space for middle separator
 
             // Get content from both columns
             let left_content = if content_line < left_column.len() {
@@ -282,11 +345,7 @@ fn render_terminal_section(line_idx: usize, width: usize) -> String {
         ("", "", ""),
         ("Local Monitoring:", "", "header"),
         ("  all-smi", "Monitor local GPUs (default mode)", "command"),
-        (
-            "  sudo all-smi local",
-            "Monitor local GPUs (requires sudo on macOS)",
-            "command",
-        ),
+        ("  all-smi local", "Monitor local GPUs", "command"),
         ("", "", ""),
         ("Remote Monitoring:", "", "header"),
         (
@@ -322,16 +381,17 @@ fn render_terminal_section(line_idx: usize, width: usize) -> String {
 }
 
 fn format_shortcut_line(key: &str, desc: &str, style: &str, width: usize) -> String {
+    let theme = theme::current();
     let content = match style {
-        "title" => center_text_colored(desc, width, Color::Yellow),
-        "separator" => "═".repeat(width).with(Color::DarkGrey).to_string(),
-        "header" => format!(" {}", key.green()),
+        "title" => center_text_colored(desc, width, theme.label),
+        "separator" => "═".repeat(width).with(theme.dim).to_string(),
+        "header" => format!(" {}", key.with(theme.label_info)),
         "shortcut" => {
             if key.is_empty() {
                 String::new()
             } else {
-                let key_str = key.white().bold().to_string();
-                let desc_str = desc.white().to_string();
+                let key_str = key.with(theme.text).bold().to_string();
+                let desc_str = desc.with(theme.text).to_string();
                 // Calculate available space for description
                 let key_display_width = calculate_display_width(&key_str) + 1; // +1 for leading space
                 let available_desc_width = width.saturating_sub(key_display_width + 2); // +2 for spaces
@@ -351,7 +411,7 @@ fn format_shortcut_line(key: &str, desc: &str, style: &str, width: usize) -> Str
                 } else {
                     desc.to_string()
                 };
-                format!(" {key_str:<10} {}", truncated_desc.white())
+                format!(" {key_str:<10} {}", truncated_desc.with(theme.text))
             }
         }
         "legend" => {
@@ -361,19 +421,19 @@ fn format_shortcut_line(key: &str, desc: &str, style: &str, width: usize) -> Str
             } else {
                 desc.to_string()
             };
-            format!(" {key:<10} {}", truncated_desc.white())
+            format!(" {key:<10} {}", truncated_desc.with(theme.text))
         }
         "status" => {
-            let key_str = key.cyan().to_string();
-            let desc_str = desc.yellow().to_string();
+            let key_str = key.with(theme.title).to_string();
+            let desc_str = desc.with(theme.label).to_string();
             format!(" {key_str:<10} {desc_str}")
         }
         "membar" => {
             // Format memory bar with colored segments
             let colored_desc = desc
-                .replace("used", &"used".green().to_string())
-                .replace("buffers", &"buffers".blue().to_string())
-                .replace("cache", &"cache".yellow().to_string());
+                .replace("used", &"used".with(theme.ok).to_string())
+                .replace("buffers", &"buffers".with(theme.cold).to_string())
+                .replace("cache", &"cache".with(theme.warning).to_string());
             format!(" {key:<10} {colored_desc}")
         }
         _ => String::new(),
@@ -384,17 +444,18 @@ fn format_shortcut_line(key: &str, desc: &str, style: &str, width: usize) -> Str
 }
 
 fn format_terminal_line(cmd: &str, desc: &str, style: &str, width: usize) -> String {
+    let theme = theme::current();
     let content = match style {
-        "title" => center_text_colored(desc, width, Color::Magenta),
-        "separator" => "═".repeat(width).with(Color::DarkGrey).to_string(),
-        "header" => format!(" {}", cmd.green()),
+        "title" => center_text_colored(desc, width, theme.label_thermal),
+        "separator" => "═".repeat(width).with(theme.dim).to_string(),
+        "header" => format!(" {}", cmd.with(theme.label_info)),
         "command" => {
             if cmd.is_empty() {
                 String::new()
             } else {
-                let formatted_cmd = format!(" {:<35}", cmd.white().bold().to_string());
-                let formatted_seperator = "#".with(Color::DarkGrey).to_string();
-                let formatted_desc = desc.blue().to_string();
+                let formatted_cmd = format!(" {:<35}", cmd.with(theme.text).bold().to_string());
+                let formatted_seperator = "#".with(theme.dim).to_string();
+                let formatted_desc = desc.with(theme.label_memory).to_string();
                 format!("{formatted_cmd} {formatted_seperator} {formatted_desc}")
             }
         }
@@ -451,6 +512,7 @@ fn strip_ansi_codes(text: &str) -> String {
 fn get_current_sort_status(sort_criteria: &crate::app_state::SortCriteria) -> String {
     match sort_criteria {
         crate::app_state::SortCriteria::Default => "Default (hostname+index)",
+        crate::app_state::SortCriteria::Uuid => "UUID",
         crate::app_state::SortCriteria::Pid => "Process PID",
         crate::app_state::SortCriteria::User => "User",
         crate::app_state::SortCriteria::Priority => "Priority",
@@ -29,6 +29,9 @@ impl LayoutCalculator {
 
         // System overview dashboard (2 rows)
         lines += 4;
+        if crate::ui::dashboard::version_summary(state).is_some() {
+            lines += 1; // Driver/CUDA versions across the cluster
+        }
 
         // Live statistics section
         if !state.utilization_history.is_empty() {
@@ -37,6 +40,9 @@ impl LayoutCalculator {
 
         // Tabs section
         lines += 2; // Tabs line + separator
+        if crate::ui::tabs::current_tab_addresses(state).is_some() {
+            lines += 1; // Addresses of a node listed more than once
+        }
 
         lines
     }
@@ -77,12 +83,19 @@ impl LayoutCalculator {
 
         // Calculate GPU display area
         let gpu_display_rows = if is_remote {
-            if state.current_tab < state.tabs.len() && state.tabs[state.current_tab] == "All" {
-                content_area.available_rows // Full space for "All" tab
+            let rows =
+                if state.current_tab < state.tabs.len() && state.tabs[state.current_tab] == "All" {
+                    content_area.available_rows // Full space for "All" tab
+                } else {
+                    content_area
+                        .available_rows
+                        .saturating_sub(storage_display_rows)
+                };
+            // Nodes that export their processes share the space with them
+            if state.remote_tab_has_processes() {
+                rows / 2
             } else {
-                content_area
-                    .available_rows
-                    .saturating_sub(storage_display_rows)
+                rows
             }
         } else if state.process_info.is_empty() {
             content_area
@@ -118,11 +131,9 @@ impl LayoutCalculator {
         let separators = if num_bars > 1 { (num_bars - 1) * 2 } else { 0 }; // 2 spaces between bars
 
         let available_width = width.saturating_sub(total_padding + separators);
-        let bar_width = if num_bars > 0 {
-            available_width / num_bars
-        } else {
-            available_width
-        };
+        let bar_width = available_width
+            .checked_div(num_bars)
+            .unwrap_or(available_width);
 
         ProgressBarLayout {
             bar_width,
@@ -140,7 +151,7 @@ impl LayoutCalculator {
         column_specs: &[ColumnSpec],
     ) -> Vec<usize> {
         let min_total: usize = column_specs.iter().map(|c| c.min_width).sum();
-        let separator_width = column_specs.len() - 1; // 1 space between columns
+        let separator_width = column_specs.len().saturating_sub(1); // 1 space between columns
 
         if available_width <= min_total + separator_width {
             // Use minimum widths if not enough space
@@ -211,6 +222,51 @@ pub struct GpuDisplayParams {
     pub storage_rows: usize,
 }
 
+/// Rows taken up by one rendered GPU entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuRowRegion {
+    pub first_row: u16,
+    pub last_row: u16,
+    pub host_id: String,
+    pub uuid: String,
+}
+
+/// Screen positions recorded while rendering the main view, used to map
+/// mouse clicks back to tabs, devices and processes
+#[derive(Debug, Clone, Default)]
+pub struct ScreenLayout {
+    /// Row of the "Tabs:" line
+    pub tabs_row: Option<u16>,
+    /// Rendered GPU entries
+    pub gpu_rows: Vec<GpuRowRegion>,
+    /// Row of the process table column header
+    pub process_header_row: Option<u16>,
+    /// Row of the first visible process
+    pub process_first_row: Option<u16>,
+    /// Number of process rows currently shown
+    pub process_visible_rows: usize,
+    /// GPU scroll offset after clamping to the visible capacity
+    pub gpu_scroll_offset: Option<usize>,
+    /// First visible process after clamping to keep the selection on screen
+    pub process_start_index: Option<usize>,
+}
+
+impl ScreenLayout {
+    /// GPU entry rendered at the given row, if any
+    pub fn gpu_at(&self, row: u16) -> Option<&GpuRowRegion> {
+        self.gpu_rows
+            .iter()
+            .find(|region| (region.first_row..=region.last_row).contains(&row))
+    }
+
+    /// Offset of the clicked process row from the first visible process
+    pub fn process_row_offset(&self, row: u16) -> Option<usize> {
+        let first = self.process_first_row?;
+        let offset = row.checked_sub(first)? as usize;
+        (offset < self.process_visible_rows).then_some(offset)
+    }
+}
+
 /// Progress bar layout configuration
 #[derive(Debug, Clone)]
 #[allow(dead_code)] // Future progress bar layout architecture
@@ -304,4 +360,44 @@ mod tests {
         assert_eq!(widths[1], 25); // 15 + 10
         assert_eq!(widths[2], 7); // 5 + 2
     }
+
+    #[test]
+    fn test_screen_layout_hit_testing() {
+        let layout = ScreenLayout {
+            tabs_row: Some(12),
+            gpu_rows: vec![
+                GpuRowRegion {
+                    first_row: 14,
+                    last_row: 15,
+                    host_id: "node1:9090".to_string(),
+                    uuid: "GPU-0".to_string(),
+                },
+                GpuRowRegion {
+                    first_row: 16,
+                    last_row: 17,
+                    host_id: "node2:9090".to_string(),
+                    uuid: "GPU-1".to_string(),
+                },
+            ],
+            process_header_row: Some(20),
+            process_first_row: Some(22),
+            process_visible_rows: 3,
+            ..Default::default()
+        };
+
+        let host_at = |row| layout.gpu_at(row).map(|region| region.host_id.as_str());
+        assert_eq!(host_at(15), Some("node1:9090"));
+        assert_eq!(host_at(16), Some("node2:9090"));
+        assert_eq!(host_at(18), None);
+        assert_eq!(
+            layout.gpu_at(14).map(|region| region.uuid.as_str()),
+            Some("GPU-0")
+        );
+
+        assert_eq!(layout.process_row_offset(21), None);
+        assert_eq!(layout.process_row_offset(22), Some(0));
+        assert_eq!(layout.process_row_offset(24), Some(2));
+        assert_eq!(layout.process_row_offset(25), None);
+        assert_eq!(ScreenLayout::default().process_row_offset(0), None);
+    }
 }
@@ -0,0 +1,176 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use crossterm::style::{Color, Stylize};
+
+use crate::ui::text::{display_width, truncate_to_width};
+use crate::ui::theme;
+use crate::utils::logging::{LogLevel, LogRecord};
+
+fn level_color(level: LogLevel) -> Color {
+    let theme = theme::current();
+    match level {
+        LogLevel::Error => theme.critical,
+        LogLevel::Warn => theme.warning,
+        LogLevel::Info => theme.text,
+        LogLevel::Debug | LogLevel::Trace => theme.dim,
+    }
+}
+
+/// One record as a single line of at most `width` columns. Line breaks in the
+/// message are flattened so a record never spills onto the next row.
+pub fn format_log_line(record: &LogRecord, width: usize) -> String {
+    let line = format!(
+        "{} {:<5} {}: {}",
+        record.timestamp.format("%H:%M:%S"),
+        record.level.as_str(),
+        record.source,
+        record.message.replace(['\n', '\r'], " "),
+    );
+    truncate_to_width(&line, width)
+}
+
+/// `"Last collection: GPU 312ms  CPU 4ms"`, or `None` before the first
+/// local collection.
+pub fn format_collection_timings(timings: &[(&str, Duration)], width: usize) -> Option<String> {
+    if timings.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = timings
+        .iter()
+        .map(|(category, duration)| format!("{category} {}ms", duration.as_millis()))
+        .collect();
+    Some(truncate_to_width(
+        &format!(" Last collection: {}", parts.join("  ")),
+        width,
+    ))
+}
+
+/// Full-screen list of the most recent log records, newest at the bottom,
+/// below the per-category collection timings of local mode.
+pub fn generate_log_overlay_content(
+    cols: u16,
+    rows: u16,
+    records: &[LogRecord],
+    timings: &[(&str, Duration)],
+) -> String {
+    let theme = theme::current();
+    let width = cols as usize;
+    let height = rows as usize;
+    let mut lines = Vec::with_capacity(height);
+
+    let title = truncate_to_width(
+        &format!(" Recent log messages ({}) - L/Esc to close", records.len()),
+        width,
+    );
+    let padding = " ".repeat(width.saturating_sub(display_width(&title)));
+    lines.push(
+        format!("{title}{padding}")
+            .with(theme.badge_fg)
+            .on(theme.title)
+            .to_string(),
+    );
+    if let Some(timings) = format_collection_timings(timings, width) {
+        let padding = " ".repeat(width.saturating_sub(display_width(&timings)));
+        lines.push(format!("{timings}{padding}").with(theme.title).to_string());
+    }
+
+    let visible = height.saturating_sub(lines.len());
+    if records.is_empty() {
+        lines.push(truncate_to_width(" No log messages recorded", width));
+    }
+    for record in records.iter().skip(records.len().saturating_sub(visible)) {
+        let line = format_log_line(record, width);
+        let padding = " ".repeat(width.saturating_sub(display_width(&line)));
+        lines.push(
+            format!("{line}{padding}")
+                .with(level_color(record.level))
+                .to_string(),
+        );
+    }
+    while lines.len() < height {
+        lines.push(" ".repeat(width));
+    }
+    lines.truncate(height);
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+
+    fn record(level: LogLevel, message: &str) -> LogRecord {
+        LogRecord {
+            timestamp: Local::now(),
+            level,
+            source: "all_smi::network::client".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_long_messages_are_truncated() {
+        let long = record(LogLevel::Error, &"x".repeat(500));
+        let line = format_log_line(&long, 80);
+        assert_eq!(display_width(&line), 80);
+        assert!(line.contains("ERROR all_smi::network::client: xxx"));
+
+        let short = format_log_line(&record(LogLevel::Info, "ok"), 200);
+        assert!(short.ends_with("INFO  all_smi::network::client: ok"));
+    }
+
+    #[test]
+    fn test_multiline_message_stays_on_one_row() {
+        let line = format_log_line(&record(LogLevel::Warn, "first\nsecond"), 120);
+        assert!(line.ends_with("first second"));
+    }
+
+    #[test]
+    fn test_overlay_shows_newest_records() {
+        let records: Vec<LogRecord> = (0..50)
+            .map(|i| record(LogLevel::Warn, &format!("message {i}")))
+            .collect();
+        let content = generate_log_overlay_content(100, 10, &records, &[]);
+        let lines: Vec<&str> = content.split('\n').collect();
+
+        assert_eq!(lines.len(), 10);
+        assert!(lines[0].contains("Recent log messages (50)"));
+        assert!(lines[1].contains("message 41"));
+        assert!(lines[9].contains("message 49"));
+        assert!(!content.contains("message 40"));
+    }
+
+    #[test]
+    fn test_overlay_shows_collection_timings() {
+        let records = vec![
+            record(LogLevel::Info, "first"),
+            record(LogLevel::Info, "last"),
+        ];
+        let timings = [
+            ("GPU", Duration::from_millis(312)),
+            ("CPU", Duration::from_millis(4)),
+        ];
+        let content = generate_log_overlay_content(100, 3, &records, &timings);
+        let lines: Vec<&str> = content.split('\n').collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("Last collection: GPU 312ms  CPU 4ms"));
+        assert!(lines[2].contains("last"));
+        assert!(!content.contains("first"));
+    }
+}
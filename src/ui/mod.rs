@@ -13,15 +13,22 @@
 // limitations under the License.
 
 pub mod buffer;
+pub mod change_highlight;
 pub mod chrome;
 pub mod constants;
 pub mod dashboard;
+pub mod heatmap;
 pub mod help;
 pub mod layout;
+pub mod log_overlay;
 pub mod notification;
+pub mod process_columns;
 pub mod process_renderer;
 pub mod renderer;
 pub mod renderers;
+pub mod replay_timeline;
+pub mod smoothing;
 pub mod tabs;
 pub mod text;
+pub mod theme;
 pub mod widgets;
@@ -130,7 +130,6 @@ impl NotificationManager {
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub fn show_with_duration(
         &mut self,
         message: String,
@@ -0,0 +1,563 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Column layout of the local process table and the F2 column chooser.
+
+use std::io::Write;
+
+use crossterm::{queue, style::Print};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::app_state::SortCriteria;
+use crate::ui::text::print_colored_text;
+use crate::ui::theme;
+
+/// Width the flexible column gets even when the terminal is too narrow; the
+/// rest of the row is reached with horizontal scrolling
+const FLEXIBLE_MIN_WIDTH: usize = 20;
+
+/// How a column's width is chosen from the terminal width
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnWidth {
+    /// Always exactly this wide
+    Fixed(usize),
+    /// At least this wide; grows when no flexible column is shown
+    Min(usize),
+    /// Takes whatever width the other columns leave
+    Flexible,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessColumn {
+    Pid,
+    User,
+    Container,
+    Priority,
+    Nice,
+    VirtualMemory,
+    ResidentMemory,
+    State,
+    CpuPercent,
+    MemoryPercent,
+    GpuPercent,
+    GpuMemory,
+    CpuTime,
+    Command,
+    /// Node a process runs on; only on the "All" tab of remote mode, so it
+    /// is not in [`ProcessColumn::ALL`] and not offered by the chooser
+    Host,
+}
+
+impl ProcessColumn {
+    /// Default column order
+    pub const ALL: [ProcessColumn; 14] = [
+        ProcessColumn::Pid,
+        ProcessColumn::User,
+        ProcessColumn::Container,
+        ProcessColumn::Priority,
+        ProcessColumn::Nice,
+        ProcessColumn::VirtualMemory,
+        ProcessColumn::ResidentMemory,
+        ProcessColumn::State,
+        ProcessColumn::CpuPercent,
+        ProcessColumn::MemoryPercent,
+        ProcessColumn::GpuPercent,
+        ProcessColumn::GpuMemory,
+        ProcessColumn::CpuTime,
+        ProcessColumn::Command,
+    ];
+
+    /// Name in the UI state file
+    pub fn name(self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "pid",
+            ProcessColumn::User => "user",
+            ProcessColumn::Container => "container",
+            ProcessColumn::Priority => "priority",
+            ProcessColumn::Nice => "nice",
+            ProcessColumn::VirtualMemory => "virt",
+            ProcessColumn::ResidentMemory => "rss",
+            ProcessColumn::State => "state",
+            ProcessColumn::CpuPercent => "cpu",
+            ProcessColumn::MemoryPercent => "mem",
+            ProcessColumn::GpuPercent => "gpu",
+            ProcessColumn::GpuMemory => "gpu_mem",
+            ProcessColumn::CpuTime => "time",
+            ProcessColumn::Command => "command",
+            ProcessColumn::Host => "host",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.name() == name)
+    }
+
+    /// Table header text
+    pub fn title(self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::User => "USER",
+            ProcessColumn::Container => "CONTAINER",
+            ProcessColumn::Priority => "PRI",
+            ProcessColumn::Nice => "NI",
+            ProcessColumn::VirtualMemory => "VIRT",
+            ProcessColumn::ResidentMemory => "RES",
+            ProcessColumn::State => "S",
+            ProcessColumn::CpuPercent => "CPU%",
+            ProcessColumn::MemoryPercent => "MEM%",
+            ProcessColumn::GpuPercent => "GPU%",
+            ProcessColumn::GpuMemory => "VRAM",
+            ProcessColumn::CpuTime => "TIME+",
+            ProcessColumn::Command => "Command",
+            ProcessColumn::Host => "HOST",
+        }
+    }
+
+    /// Description in the column chooser
+    pub fn label(self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "Process ID",
+            ProcessColumn::User => "User",
+            ProcessColumn::Container => "Container ID",
+            ProcessColumn::Priority => "Priority",
+            ProcessColumn::Nice => "Nice value",
+            ProcessColumn::VirtualMemory => "Virtual memory",
+            ProcessColumn::ResidentMemory => "Resident memory (RSS)",
+            ProcessColumn::State => "State",
+            ProcessColumn::CpuPercent => "CPU utilization",
+            ProcessColumn::MemoryPercent => "Memory utilization",
+            ProcessColumn::GpuPercent => "GPU utilization",
+            ProcessColumn::GpuMemory => "GPU memory",
+            ProcessColumn::CpuTime => "CPU time",
+            ProcessColumn::Command => "Command line",
+            ProcessColumn::Host => "Node",
+        }
+    }
+
+    pub fn width(self) -> ColumnWidth {
+        match self {
+            ProcessColumn::Pid => ColumnWidth::Fixed(7),
+            ProcessColumn::User => ColumnWidth::Fixed(12),
+            // Short container IDs are 12 hex digits
+            ProcessColumn::Container => ColumnWidth::Min(12),
+            ProcessColumn::Host => ColumnWidth::Fixed(16),
+            ProcessColumn::Priority | ProcessColumn::Nice => ColumnWidth::Fixed(3),
+            ProcessColumn::VirtualMemory | ProcessColumn::ResidentMemory => ColumnWidth::Fixed(6),
+            ProcessColumn::State => ColumnWidth::Fixed(1),
+            ProcessColumn::CpuPercent
+            | ProcessColumn::MemoryPercent
+            | ProcessColumn::GpuPercent => ColumnWidth::Fixed(5),
+            ProcessColumn::GpuMemory => ColumnWidth::Fixed(7),
+            ProcessColumn::CpuTime => ColumnWidth::Fixed(8),
+            ProcessColumn::Command => ColumnWidth::Flexible,
+        }
+    }
+
+    /// Text columns are left-aligned, numbers right-aligned
+    pub fn left_aligned(self) -> bool {
+        matches!(
+            self,
+            ProcessColumn::User
+                | ProcessColumn::Container
+                | ProcessColumn::Host
+                | ProcessColumn::State
+                | ProcessColumn::Command
+        )
+    }
+
+    /// Sort order selected by clicking the column header
+    pub fn sort_criteria(self) -> Option<SortCriteria> {
+        match self {
+            ProcessColumn::Pid => Some(SortCriteria::Pid),
+            ProcessColumn::User => Some(SortCriteria::User),
+            ProcessColumn::Container | ProcessColumn::Host => None,
+            ProcessColumn::Priority => Some(SortCriteria::Priority),
+            ProcessColumn::Nice => Some(SortCriteria::Nice),
+            ProcessColumn::VirtualMemory => Some(SortCriteria::VirtualMemory),
+            ProcessColumn::ResidentMemory => Some(SortCriteria::ResidentMemory),
+            ProcessColumn::State => Some(SortCriteria::State),
+            ProcessColumn::CpuPercent => Some(SortCriteria::CpuPercent),
+            ProcessColumn::MemoryPercent => Some(SortCriteria::MemoryPercent),
+            ProcessColumn::GpuPercent => Some(SortCriteria::GpuPercent),
+            ProcessColumn::GpuMemory => Some(SortCriteria::GpuMemoryUsage),
+            ProcessColumn::CpuTime => Some(SortCriteria::CpuTime),
+            ProcessColumn::Command => Some(SortCriteria::Command),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct ColumnSetting {
+    pub column: ProcessColumn,
+    pub visible: bool,
+}
+
+impl Serialize for ProcessColumn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Order and visibility of the process table columns, saved in the UI state
+/// file as a list of `{ "column": ..., "visible": ... }` entries
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessLayout {
+    columns: Vec<ColumnSetting>,
+}
+
+impl Default for ProcessLayout {
+    fn default() -> Self {
+        Self::from_settings(Vec::new())
+    }
+}
+
+impl ProcessLayout {
+    /// Layout from saved settings. Repeated columns are dropped and columns
+    /// missing from the settings are appended, shown only if they are shown
+    /// by default.
+    pub fn from_settings(settings: Vec<ColumnSetting>) -> Self {
+        let mut columns: Vec<ColumnSetting> = Vec::with_capacity(ProcessColumn::ALL.len());
+        for setting in settings {
+            if !columns.iter().any(|c| c.column == setting.column) {
+                columns.push(setting);
+            }
+        }
+        for column in ProcessColumn::ALL {
+            if !columns.iter().any(|c| c.column == column) {
+                columns.push(ColumnSetting {
+                    column,
+                    visible: column != ProcessColumn::Container,
+                });
+            }
+        }
+        if !columns.iter().any(|c| c.visible) {
+            columns.iter_mut().for_each(|c| c.visible = true);
+        }
+        Self { columns }
+    }
+
+    /// This layout with the node column shown first, for the process list
+    /// of several nodes
+    pub fn with_host_column(&self) -> Self {
+        let mut columns = self.columns.clone();
+        columns.insert(
+            0,
+            ColumnSetting {
+                column: ProcessColumn::Host,
+                visible: true,
+            },
+        );
+        Self { columns }
+    }
+
+    /// All columns in display order, shown or not
+    pub fn settings(&self) -> &[ColumnSetting] {
+        &self.columns
+    }
+
+    pub fn visible(&self) -> impl Iterator<Item = ProcessColumn> + '_ {
+        self.columns.iter().filter(|c| c.visible).map(|c| c.column)
+    }
+
+    /// Show or hide the column at `index`; the last shown column stays
+    pub fn toggle(&mut self, index: usize) {
+        let shown = self.columns.iter().filter(|c| c.visible).count();
+        if let Some(setting) = self.columns.get_mut(index) {
+            if !setting.visible || shown > 1 {
+                setting.visible = !setting.visible;
+            }
+        }
+    }
+
+    /// Move the column at `index` one place earlier (`delta < 0`) or later
+    /// and return its new index
+    pub fn move_column(&mut self, index: usize, delta: isize) -> usize {
+        if index >= self.columns.len() {
+            return index;
+        }
+        let target = index
+            .saturating_add_signed(delta)
+            .min(self.columns.len() - 1);
+        let setting = self.columns.remove(index);
+        self.columns.insert(target, setting);
+        target
+    }
+
+    /// Widths of the shown columns for a row `total` columns wide, with one
+    /// space between columns. Space left over by fixed and minimum widths goes
+    /// to the flexible columns, or to the minimum-width ones if none is shown.
+    pub fn allocate_widths(&self, total: usize) -> Vec<(ProcessColumn, usize)> {
+        let mut widths: Vec<(ProcessColumn, usize)> = self
+            .visible()
+            .map(|column| {
+                let width = match column.width() {
+                    ColumnWidth::Fixed(width) | ColumnWidth::Min(width) => width,
+                    ColumnWidth::Flexible => FLEXIBLE_MIN_WIDTH,
+                };
+                (column, width)
+            })
+            .collect();
+
+        let gaps = widths.len().saturating_sub(1);
+        let used = widths.iter().map(|(_, width)| width).sum::<usize>() + gaps;
+        let spare = total.saturating_sub(used);
+
+        let growable = |kind: fn(ColumnWidth) -> bool| -> Vec<usize> {
+            widths
+                .iter()
+                .enumerate()
+                .filter(|(_, (column, _))| kind(column.width()))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        let mut grow = growable(|w| w == ColumnWidth::Flexible);
+        if grow.is_empty() {
+            grow = growable(|w| matches!(w, ColumnWidth::Min(_)));
+        }
+        if !grow.is_empty() {
+            let share = spare / grow.len();
+            let extra = spare % grow.len();
+            for (n, &i) in grow.iter().enumerate() {
+                widths[i].1 += share + usize::from(n < extra);
+            }
+        }
+        widths
+    }
+
+    /// Column under position `x` of an unscrolled row `total` columns wide;
+    /// `None` on the gaps between columns and past the last one
+    pub fn column_at(&self, x: usize, total: usize) -> Option<ProcessColumn> {
+        let mut start = 0;
+        for (column, width) in self.allocate_widths(total) {
+            if x >= start && x < start + width {
+                return Some(column);
+            }
+            start += width + 1;
+        }
+        None
+    }
+}
+
+impl Serialize for ProcessLayout {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.columns.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProcessLayout {
+    /// Entries naming columns this version doesn't know are skipped, so a
+    /// state file written by a newer version still loads
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Entry {
+            column: String,
+            #[serde(default = "default_visible")]
+            visible: bool,
+        }
+        fn default_visible() -> bool {
+            true
+        }
+
+        let entries = Vec::<Entry>::deserialize(deserializer)?;
+        let settings = entries
+            .into_iter()
+            .filter_map(|entry| {
+                ProcessColumn::from_name(&entry.column).map(|column| ColumnSetting {
+                    column,
+                    visible: entry.visible,
+                })
+            })
+            .collect();
+        Ok(Self::from_settings(settings))
+    }
+}
+
+/// Cursor of the column chooser opened with F2 in local mode
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColumnChooser {
+    pub cursor: usize,
+}
+
+/// Full-screen list of the process columns with their visibility
+pub fn draw_column_chooser<W: Write>(
+    stdout: &mut W,
+    layout: &ProcessLayout,
+    chooser: &ColumnChooser,
+    cols: u16,
+    rows: u16,
+) {
+    let theme = theme::current();
+    let width = cols as usize;
+
+    print_colored_text(stdout, "Process Columns", theme.title, None, None);
+    print_colored_text(
+        stdout,
+        "   ↑↓: move  Space: show/hide  -/+: move earlier/later  F2/Esc: close",
+        theme.dim,
+        None,
+        None,
+    );
+    queue!(stdout, Print("\r\n\r\n")).unwrap();
+
+    let mut lines_used = 2;
+    for (i, setting) in layout.settings().iter().enumerate() {
+        if lines_used >= rows as usize {
+            break;
+        }
+        let check = if setting.visible { "[x]" } else { "[ ]" };
+        let line = format!(
+            " {check} {:<10} {}",
+            setting.column.title(),
+            setting.column.label()
+        );
+        let line = format!("{line:<width$}");
+        if i == chooser.cursor {
+            print_colored_text(
+                stdout,
+                &line,
+                theme.selection_fg,
+                Some(theme.selection_bg),
+                None,
+            );
+        } else if setting.visible {
+            print_colored_text(stdout, &line, theme.text, None, None);
+        } else {
+            print_colored_text(stdout, &line, theme.dim, None, None);
+        }
+        queue!(stdout, Print("\r\n")).unwrap();
+        lines_used += 1;
+    }
+
+    while lines_used < rows as usize {
+        queue!(stdout, Print(" ".repeat(width))).unwrap();
+        queue!(stdout, Print("\r\n")).unwrap();
+        lines_used += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout_of(columns: &[ProcessColumn]) -> ProcessLayout {
+        ProcessLayout::from_settings(
+            ProcessColumn::ALL
+                .iter()
+                .map(|&column| ColumnSetting {
+                    column,
+                    visible: columns.contains(&column),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_default_layout_matches_classic_table() {
+        let layout = ProcessLayout::default();
+        let widths = layout.allocate_widths(200);
+        assert!(!widths.iter().any(|(c, _)| *c == ProcessColumn::Container));
+        let fixed: Vec<usize> = widths[..12].iter().map(|(_, w)| *w).collect();
+        assert_eq!(fixed, [7, 12, 3, 3, 6, 6, 1, 5, 5, 5, 7, 8]);
+        // 68 columns of fixed widths and 12 gaps before the command
+        assert_eq!(widths[12], (ProcessColumn::Command, 200 - 80));
+    }
+
+    #[test]
+    fn test_allocate_widths_across_terminal_sizes() {
+        use ProcessColumn::*;
+        let layout = layout_of(&[Pid, User, Container, CpuPercent, GpuPercent, Command]);
+        // Fixed 7 + 12 + 5 + 5, min 12, flexible at least 20, 5 gaps = 66
+        for (total, command) in [(66, 20), (80, 34), (200, 154)] {
+            assert_eq!(
+                layout.allocate_widths(total),
+                vec![
+                    (Pid, 7),
+                    (User, 12),
+                    (Container, 12),
+                    (CpuPercent, 5),
+                    (GpuPercent, 5),
+                    (Command, command)
+                ]
+            );
+        }
+        // Narrower than the minimum keeps every width; the row scrolls
+        let widths = layout.allocate_widths(40);
+        assert_eq!(widths.iter().map(|(_, w)| w).sum::<usize>() + 5, 66);
+
+        // Without a flexible column the minimum-width ones grow instead
+        let layout = layout_of(&[Pid, Container]);
+        assert_eq!(layout.allocate_widths(50), vec![(Pid, 7), (Container, 42)]);
+        let layout = layout_of(&[Pid, GpuMemory]);
+        assert_eq!(layout.allocate_widths(50), vec![(Pid, 7), (GpuMemory, 7)]);
+    }
+
+    #[test]
+    fn test_column_at() {
+        let layout = ProcessLayout::default();
+        assert_eq!(layout.column_at(0, 120), Some(ProcessColumn::Pid));
+        assert_eq!(layout.column_at(7, 120), None);
+        assert_eq!(layout.column_at(8, 120), Some(ProcessColumn::User));
+        assert_eq!(layout.column_at(80, 120), Some(ProcessColumn::Command));
+        assert_eq!(layout.column_at(500, 120), None);
+    }
+
+    #[test]
+    fn test_toggle_and_move() {
+        let mut layout = ProcessLayout::default();
+        layout.toggle(2);
+        assert!(layout.settings()[2].visible);
+        assert_eq!(layout.move_column(2, -1), 1);
+        assert_eq!(layout.settings()[1].column, ProcessColumn::Container);
+        assert_eq!(layout.move_column(0, -1), 0);
+        assert_eq!(layout.move_column(13, 1), 13);
+
+        // The last shown column cannot be hidden
+        let mut layout = layout_of(&[ProcessColumn::Command]);
+        layout.toggle(13);
+        assert_eq!(
+            layout.visible().collect::<Vec<_>>(),
+            [ProcessColumn::Command]
+        );
+    }
+
+    #[test]
+    fn test_layout_serialization() {
+        let mut layout = ProcessLayout::default();
+        layout.toggle(2);
+        layout.move_column(13, -13);
+        let json = serde_json::to_value(&layout).unwrap();
+        assert_eq!(
+            json[0],
+            serde_json::json!({ "column": "command", "visible": true })
+        );
+        assert_eq!(
+            serde_json::from_value::<ProcessLayout>(json).unwrap(),
+            layout
+        );
+
+        // Unknown and repeated columns are dropped, missing ones appended
+        let layout: ProcessLayout = serde_json::from_str(
+            r#"[{"column": "gpu_mem"}, {"column": "future"}, {"column": "pid", "visible": false},
+                {"column": "gpu_mem", "visible": false}]"#,
+        )
+        .unwrap();
+        assert_eq!(layout.settings().len(), ProcessColumn::ALL.len());
+        assert_eq!(layout.settings()[0].column, ProcessColumn::GpuMemory);
+        assert!(layout.settings()[0].visible);
+        assert!(!layout.settings()[1].visible);
+        assert!(!layout
+            .visible()
+            .any(|column| column == ProcessColumn::Container));
+    }
+}
@@ -13,11 +13,49 @@
 // limitations under the License.
 
 use std::io::Write;
+use std::time::Instant;
 
-use crossterm::{queue, style::Color, style::Print};
+use crossterm::{queue, style::Print};
 
+use crate::app_state::{user_view_rows, ProcessRow};
+use crate::device::container_utils::process_container_id;
+use crate::device::process_list::UserProcessSummary;
 use crate::device::ProcessInfo;
-use crate::ui::text::{print_colored_text, truncate_to_width};
+use crate::ui::change_highlight::{flash_colors, ChangeHighlight};
+use crate::ui::process_columns::{ProcessColumn, ProcessLayout};
+use crate::ui::text::{display_width, print_colored_text, truncate_to_width};
+use crate::ui::theme;
+use crate::utils::{format_bytes, unit_system};
+
+/// Number of process rows that fit in the given number of screen rows
+pub fn process_rows_capacity(available_rows: u16) -> usize {
+    let footer_rows = 2usize; // "Showing..." line + "Active..." stats line
+
+    // Reserve rows for header section: 1 for "Processes:" title, 1 for header, 1 for separator, 1 for blank line
+    const RESERVED_HEADER_ROWS: usize = 4;
+    (available_rows as usize).saturating_sub(RESERVED_HEADER_ROWS + footer_rows)
+}
+
+/// First process row to show so that the selected row stays visible and no
+/// rows are left empty past the end of the list, e.g. after a resize
+pub fn clamp_start_index(selected: usize, start: usize, capacity: usize, len: usize) -> usize {
+    let capacity = capacity.max(1);
+    let start = start.min(len.saturating_sub(capacity));
+    if selected < start {
+        selected
+    } else if selected >= start + capacity {
+        selected + 1 - capacity
+    } else {
+        start
+    }
+}
+
+/// The part of `line` visible after scrolling `offset` columns to the right,
+/// padded or truncated to exactly `width` columns
+fn visible_columns(line: &str, offset: usize, width: usize) -> String {
+    let scrolled: String = line.chars().skip(offset).collect();
+    format!("{:<width$}", truncate_to_width(&scrolled, width))
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn print_process_info<W: Write>(
@@ -31,44 +69,21 @@ pub fn print_process_info<W: Write>(
     current_user: &str,
     sort_criteria: &crate::app_state::SortCriteria,
     sort_direction: &crate::app_state::SortDirection,
+    process_layout: &ProcessLayout,
+    change_highlight: Option<&ChangeHighlight>,
+    omitted: usize,
 ) {
+    let theme = theme::current();
+    let now = Instant::now();
     // Don't add extra newlines at the start - the caller should handle positioning
     queue!(stdout, Print("Processes:\r\n")).unwrap();
 
     let width = cols as usize;
-
-    // Fixed column widths based on actual data sizes
-    // PID: 7 (up to 9999999), USER: 12, PRI: 3, NI: 3, VIRT: 6, RES: 6, S: 1,
-    // CPU%: 5, MEM%: 5, GPU%: 5, VRAM: 7, TIME+: 8, Command: remaining
-    let fixed_widths = [7, 12, 3, 3, 6, 6, 1, 5, 5, 5, 7, 8];
-    let num_gaps = fixed_widths.len(); // Gaps between columns (not after last column)
-    let fixed_total: usize = fixed_widths.iter().sum::<usize>() + num_gaps;
-
-    // Give remaining space to command column, ensure at least 20 chars
-    let _command_w = if width > fixed_total + 20 {
-        width - fixed_total
-    } else {
-        20
-    };
-
-    let (pid_w, user_w, pri_w, ni_w, virt_w, res_w, s_w, cpu_w, mem_w, gpu_w, gpu_mem_w, time_w) = (
-        fixed_widths[0],  // PID: 7
-        fixed_widths[1],  // USER: 12
-        fixed_widths[2],  // PRI: 3
-        fixed_widths[3],  // NI: 3
-        fixed_widths[4],  // VIRT: 6
-        fixed_widths[5],  // RES: 6
-        fixed_widths[6],  // S: 1
-        fixed_widths[7],  // CPU%: 5
-        fixed_widths[8],  // MEM%: 5
-        fixed_widths[9],  // GPU%: 5
-        fixed_widths[10], // VRAM: 7
-        fixed_widths[11], // TIME+: 8
-    );
+    let columns = process_layout.allocate_widths(width);
 
     // Helper function to add sort arrow
-    let get_sort_arrow = |criteria: crate::app_state::SortCriteria| -> &'static str {
-        if sort_criteria == &criteria {
+    let get_sort_arrow = |criteria: Option<crate::app_state::SortCriteria>| -> &'static str {
+        if criteria.as_ref() == Some(sort_criteria) {
             match sort_direction {
                 crate::app_state::SortDirection::Ascending => "↑",
                 crate::app_state::SortDirection::Descending => "↓",
@@ -78,51 +93,35 @@ pub fn print_process_info<W: Write>(
         }
     };
 
-    // Build header format string with proper alignment and sort arrows
-    #[allow(clippy::format_in_format_args)]
-    let header_format = format!(
-        "{:>pid_w$} {:<user_w$} {:>pri_w$} {:>ni_w$} {:>virt_w$} {:>res_w$} {:<s_w$} {:>cpu_w$} {:>mem_w$} {:>gpu_w$} {:>gpu_mem_w$} {:>time_w$} {}",
-        format!("PID{}", get_sort_arrow(crate::app_state::SortCriteria::Pid)),
-        format!("USER{}", get_sort_arrow(crate::app_state::SortCriteria::User)),
-        format!("PRI{}", get_sort_arrow(crate::app_state::SortCriteria::Priority)),
-        format!("NI{}", get_sort_arrow(crate::app_state::SortCriteria::Nice)),
-        format!("VIRT{}", get_sort_arrow(crate::app_state::SortCriteria::VirtualMemory)),
-        format!("RES{}", get_sort_arrow(crate::app_state::SortCriteria::ResidentMemory)),
-        format!("S{}", get_sort_arrow(crate::app_state::SortCriteria::State)),
-        format!("CPU%{}", get_sort_arrow(crate::app_state::SortCriteria::CpuPercent)),
-        format!("MEM%{}", get_sort_arrow(crate::app_state::SortCriteria::MemoryPercent)),
-        format!("GPU%{}", get_sort_arrow(crate::app_state::SortCriteria::GpuPercent)),
-        format!("VRAM{}", get_sort_arrow(crate::app_state::SortCriteria::GpuMemoryUsage)),
-        format!("TIME+{}", get_sort_arrow(crate::app_state::SortCriteria::CpuTime)),
-        format!("Command{}", get_sort_arrow(crate::app_state::SortCriteria::Command)),
-    );
+    // Build the header with proper alignment and sort arrows
+    let header_cells: Vec<String> = columns
+        .iter()
+        .map(|&(column, col_width)| {
+            let title = format!(
+                "{}{}",
+                column.title(),
+                get_sort_arrow(column.sort_criteria())
+            );
+            align_cell(&title, column, col_width, false)
+        })
+        .collect();
+    let header_format = header_cells.join(" ");
 
-    // Apply horizontal scrolling
-    let visible_header = if horizontal_scroll_offset < header_format.len() {
-        let scrolled = &header_format[horizontal_scroll_offset..];
-        // Pad the header to full width to clear any previous content
-        format!("{:<width$}", truncate_to_width(scrolled, width))
-    } else {
-        // Clear the entire line when scrolled past the content
-        " ".repeat(width)
-    };
+    // Apply horizontal scrolling, padding to full width to clear previous content
+    let visible_header = visible_columns(&header_format, horizontal_scroll_offset, width);
 
-    print_colored_text(stdout, &visible_header, Color::White, None, None);
+    print_colored_text(stdout, &visible_header, theme.text, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Print separator line
     let separator = "─".repeat(width.min(120));
-    print_colored_text(stdout, &separator, Color::DarkGrey, None, None);
+    print_colored_text(stdout, &separator, theme.dim, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Calculate how many rows are reserved for footer information
     let footer_rows = 2usize; // "Showing..." line + "Active..." stats line
 
-    // Calculate how many processes we can display
-    // Reserve rows for header section: 1 for "Processes:" title, 1 for header, 1 for separator, 1 for blank line
-    const RESERVED_HEADER_ROWS: usize = 4;
-    let available_rows_for_processes =
-        (available_rows as usize).saturating_sub(RESERVED_HEADER_ROWS + footer_rows);
+    let available_rows_for_processes = process_rows_capacity(available_rows);
     let end_index = (start_index + available_rows_for_processes).min(processes.len());
 
     // Print process information
@@ -130,90 +129,42 @@ pub fn print_process_info<W: Write>(
         if let Some(process) = processes.get(i) {
             let is_selected = i == selected_index;
 
-            // Format process information with proper truncation
-            let pid = format!("{}", process.pid);
-            let user = truncate_to_width(&process.user, user_w);
-            let priority = format!("{}", process.priority);
-            let nice = format!("{:+}", process.nice_value); // Show + for positive values
-
-            // Format memory sizes
-            let virt = format_memory_size(process.memory_vms);
-            let res = format_memory_size(process.memory_rss);
-
-            let state = truncate_to_width(&process.state, s_w);
-            let cpu_percent = format!("{:.1}", process.cpu_percent);
-            let mem_percent = format!("{:.1}", process.memory_percent);
-
-            // Format GPU utilization
-            let gpu_percent = if process.uses_gpu && process.gpu_utilization > 0.0 {
-                format!("{:.1}", process.gpu_utilization)
-            } else if process.uses_gpu {
-                "-".to_string()
-            } else {
-                "".to_string()
-            };
-
-            // Format GPU memory usage
-            let gpu_mem = if process.used_memory > 0 {
-                let gpu_mem_mb = process.used_memory as f64 / (1024.0 * 1024.0);
-                if gpu_mem_mb >= 1024.0 {
-                    format!("{:.1}G", gpu_mem_mb / 1024.0)
-                } else {
-                    format!("{gpu_mem_mb:.0}M")
-                }
-            } else if process.uses_gpu {
-                "-".to_string()
+            // Only the shown columns are formatted (or looked up, for the container)
+            let last = columns.len().saturating_sub(1);
+            let cells: Vec<(ProcessColumn, String)> = columns
+                .iter()
+                .enumerate()
+                .map(|(idx, &(column, col_width))| {
+                    let value = format_process_cell(process, column);
+                    (column, align_cell(&value, column, col_width, idx == last))
+                })
+                .collect();
+
+            // Print with selection highlight, the highlight of a process that
+            // just appeared, or individual column colors
+            let row_colors = if is_selected {
+                Some((theme.selection_fg, Some(theme.selection_bg)))
             } else {
-                "".to_string()
+                change_highlight
+                    .and_then(|highlight| highlight.process_flash(process.pid, now))
+                    .map(|flash| flash_colors(Some(flash), theme.text))
             };
-
-            // Format CPU time
-            let time_plus = format_cpu_time(process.cpu_time);
-
-            let command = process.command.clone();
-
-            // Build the row with proper formatting and padding
-            let row_format = format!(
-                "{pid:>pid_w$} {:<user_w$} {priority:>pri_w$} {nice:>ni_w$} {virt:>virt_w$} {res:>res_w$} {state:<s_w$} {cpu_percent:>cpu_w$} {mem_percent:>mem_w$} {gpu_percent:>gpu_w$} {gpu_mem:>gpu_mem_w$} {time_plus:>time_w$} {command}",
-                truncate_to_width(&user, user_w),
-            );
-
-            // Apply horizontal scrolling
-            let visible_row = if horizontal_scroll_offset < row_format.len() {
-                let scrolled = &row_format[horizontal_scroll_offset..];
-                // Pad the row to full width to clear any previous content
-                format!("{:<width$}", truncate_to_width(scrolled, width))
+            if let Some((fg, bg)) = row_colors {
+                let row_format = cells
+                    .iter()
+                    .map(|(_, cell)| cell.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let visible_row = visible_columns(&row_format, horizontal_scroll_offset, width);
+                print_colored_text(stdout, &visible_row, fg, bg, None);
             } else {
-                // Clear the entire line when scrolled past the content
-                " ".repeat(width)
-            };
-
-            // Print with selection highlight or individual column colors
-            if is_selected {
-                print_colored_text(stdout, &visible_row, Color::Black, Some(Color::White), None);
-            } else {
-                // We need to print each column separately with its own color
-                // So we'll reconstruct the visible parts column by column
                 print_process_row_colored(
                     stdout,
                     process,
                     current_user,
-                    &pid,
-                    &user,
-                    &priority,
-                    &nice,
-                    &virt,
-                    &res,
-                    &state,
-                    &cpu_percent,
-                    &mem_percent,
-                    &gpu_percent,
-                    &gpu_mem,
-                    &time_plus,
-                    &command,
+                    &cells,
                     horizontal_scroll_offset,
                     width,
-                    &fixed_widths,
                 );
             }
 
@@ -234,23 +185,30 @@ pub fn print_process_info<W: Write>(
         lines_used += 1;
     }
 
+    // Processes the nodes exported beyond --process-top
+    let omitted_info = if omitted > 0 {
+        format!(" ({omitted} more not fetched, see --process-top)")
+    } else {
+        String::new()
+    };
+
     // Show navigation info if there are more processes
     if processes.len() > available_rows_for_processes {
         let nav_info = format!(
-            "Showing {}-{end_index} of {} processes (Use ↑↓ to navigate, PgUp/PgDn for pages)",
+            "Showing {}-{end_index} of {} processes{omitted_info} (Use ↑↓ to navigate, PgUp/PgDn for pages)",
             start_index + 1,
             processes.len()
         );
         // Pad the line to full width to clear any previous content
         let padded_nav_info = format!("{nav_info:<width$}");
-        print_colored_text(stdout, &padded_nav_info, Color::DarkGrey, None, None);
+        print_colored_text(stdout, &padded_nav_info, theme.dim, None, None);
         queue!(stdout, Print("\r\n")).unwrap();
         lines_used += 1;
     } else if !processes.is_empty() {
         // If all processes fit, still show a summary line
-        let nav_info = format!("Showing all {} processes", processes.len());
+        let nav_info = format!("Showing all {} processes{omitted_info}", processes.len());
         let padded_nav_info = format!("{nav_info:<width$}");
-        print_colored_text(stdout, &padded_nav_info, Color::DarkGrey, None, None);
+        print_colored_text(stdout, &padded_nav_info, theme.dim, None, None);
         queue!(stdout, Print("\r\n")).unwrap();
         lines_used += 1;
     }
@@ -258,17 +216,17 @@ pub fn print_process_info<W: Write>(
     // Show process statistics
     if !processes.is_empty() {
         let total_gpu_mem: u64 = processes.iter().map(|p| p.used_memory).sum();
-        let gpu_mem_gb = total_gpu_mem as f64 / (1024.0 * 1024.0 * 1024.0);
 
         let active_processes = processes.iter().filter(|p| p.cpu_percent > 0.1).count();
         let gpu_processes = processes.iter().filter(|p| p.used_memory > 0).count();
 
         let stats = format!(
-            "Active: {active_processes} | GPU: {gpu_processes} | Total GPU Memory: {gpu_mem_gb:.1}GB"
+            "Active: {active_processes} | GPU: {gpu_processes} | Total GPU Memory: {}",
+            format_bytes(total_gpu_mem)
         );
         // Pad the line to full width to clear any previous content
         let padded_stats = format!("{stats:<width$}");
-        print_colored_text(stdout, &padded_stats, Color::Cyan, None, None);
+        print_colored_text(stdout, &padded_stats, theme.title, None, None);
         queue!(stdout, Print("\r\n")).unwrap();
         lines_used += 1;
     }
@@ -282,19 +240,169 @@ pub fn print_process_info<W: Write>(
     }
 }
 
-/// Format memory size in human-readable format (e.g., 187T, 123G, 500M, 16K)
+/// Process list with one row per user, `U` in local mode. Rows come from
+/// [`user_view_rows`], so `selected_index` and `start_index` count users and
+/// the processes of the expanded user alike.
+#[allow(clippy::too_many_arguments)]
+pub fn print_user_process_info<W: Write>(
+    stdout: &mut W,
+    summaries: &[UserProcessSummary],
+    processes: &[ProcessInfo],
+    expanded_user: Option<&str>,
+    selected_index: usize,
+    start_index: usize,
+    available_rows: u16,
+    cols: u16,
+    horizontal_scroll_offset: usize,
+) {
+    let theme = theme::current();
+    queue!(
+        stdout,
+        Print("Processes by user (U to list processes, Enter to expand):\r\n")
+    )
+    .unwrap();
+
+    let width = cols as usize;
+    let header = format!(
+        "{:<16} {:>6} {:>8} {:>7}  {}",
+        "USER", "PROCS", "VRAM↓", "CPU%", "Command"
+    );
+    let visible_header = visible_columns(&header, horizontal_scroll_offset, width);
+    print_colored_text(stdout, &visible_header, theme.text, None, None);
+    queue!(stdout, Print("\r\n")).unwrap();
+
+    let separator = "─".repeat(width.min(120));
+    print_colored_text(stdout, &separator, theme.dim, None, None);
+    queue!(stdout, Print("\r\n")).unwrap();
+
+    let footer_rows = 2usize;
+    let rows = user_view_rows(summaries, processes, expanded_user);
+    let capacity = process_rows_capacity(available_rows);
+    let end_index = (start_index + capacity).min(rows.len());
+
+    for (i, row) in rows.iter().enumerate().take(end_index).skip(start_index) {
+        let (line, color) = match row {
+            ProcessRow::User(summary) => {
+                let marker = if expanded_user == Some(summary.user.as_str()) {
+                    "▾"
+                } else {
+                    "▸"
+                };
+                let user = truncate_to_width(&summary.user, 14);
+                let line = format!(
+                    "{marker} {user:<14} {:>6} {:>8} {:>7.1}",
+                    summary.process_count,
+                    format_gpu_memory(summary.gpu_memory, false),
+                    summary.cpu_percent,
+                );
+                let color = if summary.gpu_memory > 0 {
+                    theme.ok
+                } else {
+                    theme.text
+                };
+                (line, color)
+            }
+            ProcessRow::Process(process) => {
+                let line = format!(
+                    "  {:<14} {:>6} {:>8} {:>7.1}  {}",
+                    process.pid,
+                    "",
+                    format_gpu_memory(process.used_memory, process.used_memory_estimated),
+                    process.cpu_percent,
+                    process.command,
+                );
+                (line, theme.dim)
+            }
+        };
+
+        let visible_row = visible_columns(&line, horizontal_scroll_offset, width);
+        if i == selected_index {
+            print_colored_text(
+                stdout,
+                &visible_row,
+                theme.selection_fg,
+                Some(theme.selection_bg),
+                None,
+            );
+        } else {
+            print_colored_text(stdout, &visible_row, color, None, None);
+        }
+        queue!(stdout, Print("\r\n")).unwrap();
+    }
+
+    let mut lines_used = 3 + end_index.saturating_sub(start_index);
+    let total_lines_before_footer = (available_rows as usize).saturating_sub(footer_rows);
+    while lines_used < total_lines_before_footer {
+        queue!(stdout, Print(" ".repeat(width))).unwrap();
+        queue!(stdout, Print("\r\n")).unwrap();
+        lines_used += 1;
+    }
+
+    let nav_info = if rows.len() > capacity {
+        format!(
+            "Showing {}-{end_index} of {} rows (Use ↑↓ to navigate, PgUp/PgDn for pages)",
+            start_index + 1,
+            rows.len()
+        )
+    } else {
+        format!("Showing all {} users", summaries.len())
+    };
+    print_colored_text(
+        stdout,
+        &format!("{nav_info:<width$}"),
+        theme.dim,
+        None,
+        None,
+    );
+    queue!(stdout, Print("\r\n")).unwrap();
+    lines_used += 1;
+
+    let total_gpu_mem: u64 = summaries.iter().map(|s| s.gpu_memory).sum();
+    let gpu_users = summaries.iter().filter(|s| s.gpu_memory > 0).count();
+    let stats = format!(
+        "Users: {} | GPU: {gpu_users} | Total GPU Memory: {}",
+        summaries.len(),
+        format_bytes(total_gpu_mem)
+    );
+    print_colored_text(stdout, &format!("{stats:<width$}"), theme.title, None, None);
+    queue!(stdout, Print("\r\n")).unwrap();
+    lines_used += 1;
+
+    while lines_used < available_rows as usize {
+        queue!(stdout, Print(" ".repeat(width))).unwrap();
+        queue!(stdout, Print("\r\n")).unwrap();
+        lines_used += 1;
+    }
+}
+
+/// GPU memory as shown in the VRAM column, e.g. `512M` or `1.5G`, in steps
+/// of the selected unit system; a leading `~` marks an estimate
+pub(crate) fn format_gpu_memory(bytes: u64, estimated: bool) -> String {
+    let step = unit_system().step();
+    let gpu_mem_mb = bytes as f64 / (step * step);
+    let approx = if estimated { "~" } else { "" };
+    if gpu_mem_mb >= step {
+        format!("{approx}{:.1}G", gpu_mem_mb / step)
+    } else {
+        format!("{approx}{gpu_mem_mb:.0}M")
+    }
+}
+
+/// Format memory size in human-readable format (e.g., 187T, 123G, 500M, 16K),
+/// in steps of the selected unit system
 fn format_memory_size(bytes: u64) -> String {
     if bytes == 0 {
         return "0".to_string();
     }
 
-    let gb = bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-    let mb = bytes as f64 / (1024.0 * 1024.0);
-    let kb = bytes as f64 / 1024.0;
+    let step = unit_system().step();
+    let kb = bytes as f64 / step;
+    let mb = kb / step;
+    let gb = mb / step;
 
     if gb >= 1000.0 {
         // Only show TB if >= 1000GB
-        let tb = gb / 1024.0;
+        let tb = gb / step;
         format!("{tb:.0}T")
     } else if gb >= 1.0 {
         format!("{gb:.0}G")
@@ -307,226 +415,159 @@ fn format_memory_size(bytes: u64) -> String {
     }
 }
 
+/// Text of one process table cell before alignment
+fn format_process_cell(process: &ProcessInfo, column: ProcessColumn) -> String {
+    match column {
+        ProcessColumn::Pid => process.pid.to_string(),
+        ProcessColumn::User => process.user.clone(),
+        // The container lookup reads this machine's /proc
+        ProcessColumn::Container if !process.host_id.is_empty() => "-".to_string(),
+        ProcessColumn::Container => process_container_id(process.pid)
+            .map(|id| id.chars().take(12).collect())
+            .unwrap_or_else(|| "-".to_string()),
+        ProcessColumn::Priority => process.priority.to_string(),
+        ProcessColumn::Nice => format!("{:+}", process.nice_value), // Show + for positive values
+        ProcessColumn::VirtualMemory => format_memory_size(process.memory_vms),
+        ProcessColumn::ResidentMemory => format_memory_size(process.memory_rss),
+        ProcessColumn::State => process.state.clone(),
+        ProcessColumn::CpuPercent => format!("{:.1}", process.cpu_percent),
+        ProcessColumn::MemoryPercent => format!("{:.1}", process.memory_percent),
+        ProcessColumn::GpuPercent => {
+            if process.uses_gpu && process.gpu_utilization > 0.0 {
+                format!("{:.1}", process.gpu_utilization)
+            } else if process.uses_gpu {
+                "-".to_string()
+            } else {
+                "".to_string()
+            }
+        }
+        ProcessColumn::GpuMemory => {
+            if process.used_memory > 0 {
+                format_gpu_memory(process.used_memory, process.used_memory_estimated)
+            } else if process.uses_gpu {
+                "-".to_string()
+            } else {
+                "".to_string()
+            }
+        }
+        ProcessColumn::CpuTime => format_cpu_time(process.cpu_time),
+        ProcessColumn::Command => process.command.clone(),
+        ProcessColumn::Host => process.host_id.clone(),
+    }
+}
+
+/// Pad or truncate a cell to its column width. The last column is only
+/// padded so that horizontal scrolling reveals the rest of it.
+fn align_cell(value: &str, column: ProcessColumn, width: usize, last: bool) -> String {
+    let value = if last {
+        value.to_string()
+    } else {
+        truncate_to_width(value, width)
+    };
+    let padding = " ".repeat(width.saturating_sub(display_width(&value)));
+    if column.left_aligned() {
+        format!("{value}{padding}")
+    } else {
+        format!("{padding}{value}")
+    }
+}
+
 /// Print process row with individual column colors
-#[allow(clippy::too_many_arguments)]
 fn print_process_row_colored<W: Write>(
     stdout: &mut W,
     process: &ProcessInfo,
     current_user: &str,
-    pid: &str,
-    user: &str,
-    priority: &str,
-    nice: &str,
-    virt: &str,
-    res: &str,
-    state: &str,
-    cpu_percent: &str,
-    mem_percent: &str,
-    gpu_percent: &str,
-    gpu_mem: &str,
-    time_plus: &str,
-    command: &str,
+    cells: &[(ProcessColumn, String)],
     horizontal_scroll_offset: usize,
     width: usize,
-    fixed_widths: &[usize; 12],
 ) {
-    let values = vec![
-        pid,
-        user,
-        priority,
-        nice,
-        virt,
-        res,
-        state,
-        cpu_percent,
-        mem_percent,
-        gpu_percent,
-        gpu_mem,
-        time_plus,
-        command,
-    ];
-
-    let mut current_pos = 0;
-    let mut output_pos = 0;
+    let theme = theme::current();
 
     // Determine base colors
     let is_current_user = process.user == current_user;
 
     // Determine the default text color based on user and resource usage
     let default_color = if process.cpu_percent >= 90.0 || process.memory_percent >= 90.0 {
-        Color::Red
+        theme.critical
     } else if process.cpu_percent >= 80.0 || process.memory_percent >= 80.0 {
-        Color::Rgb {
-            r: 255,
-            g: 100,
-            b: 100,
-        }
+        theme.severe
     } else if process.cpu_percent >= 70.0 || process.memory_percent >= 70.0 {
-        Color::Yellow
+        theme.warning
     } else if process.cpu_percent >= 50.0 || process.memory_percent >= 50.0 {
-        Color::Rgb {
-            r: 255,
-            g: 200,
-            b: 0,
-        }
+        theme.elevated
     } else if process.uses_gpu && (process.cpu_percent >= 30.0 || process.memory_percent >= 30.0) {
-        Color::Cyan
+        theme.cool
     } else if process.uses_gpu {
-        Color::Green
+        theme.ok
     } else if is_current_user {
-        Color::White
+        theme.text
     } else {
         // Root, unknown, or other users' processes
-        Color::DarkGrey
+        theme.dim
     };
 
-    for (idx, value) in values.iter().enumerate() {
-        let col_width = if idx < fixed_widths.len() {
-            fixed_widths[idx]
+    // Columns whose value differs from the idle default are printed as plain text
+    let plain_if = |non_default: bool| {
+        if non_default {
+            theme.text
         } else {
-            // Command column takes remaining space
-            width
-                .saturating_sub(current_pos)
-                .saturating_sub(horizontal_scroll_offset)
-        };
-
-        // Check if this column is visible after scrolling
-        let col_start = current_pos;
-        let col_end = if idx < fixed_widths.len() {
-            current_pos + col_width + 1 // +1 for space
-        } else {
-            current_pos + value.len() // Command doesn't have fixed width
-        };
+            default_color
+        }
+    };
 
-        if col_end > horizontal_scroll_offset && output_pos < width {
-            // Determine color for this column
-            let color = match idx {
-                4 => {
-                    // VIRT column
-                    if process.memory_vms == 0 {
-                        Color::White
-                    } else {
-                        Color::Green
-                    }
-                }
-                0 => {
-                    // PID - white if non-zero
-                    if process.pid > 0 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                2 => {
-                    // Priority - white if not default (20)
-                    if process.priority != 20 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                3 => {
-                    // Nice - white if not 0
-                    if process.nice_value != 0 {
-                        Color::White
-                    } else {
-                        Color::DarkGrey
-                    }
-                }
-                5 => {
-                    // RES - white if non-zero
-                    if process.memory_rss > 0 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                7 => {
-                    // CPU% - white if non-zero
-                    if process.cpu_percent > 0.0 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                8 => {
-                    // MEM% - white if non-zero
-                    if process.memory_percent > 0.0 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                9 => {
-                    // GPU% - white if non-zero
-                    if process.gpu_utilization > 0.0 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                10 => {
-                    // GPUMEM - white if non-zero
-                    if process.used_memory > 0 {
-                        Color::White
-                    } else {
-                        default_color
-                    }
-                }
-                11 => {
-                    // TIME+ - white if not 0:00:00
-                    if time_plus != "0:00:00" {
-                        Color::White
-                    } else {
-                        default_color
-                    }
+    let mut skip = horizontal_scroll_offset;
+    let mut remaining = width;
+    for (idx, (column, cell)) in cells.iter().enumerate() {
+        let color = match column {
+            ProcessColumn::VirtualMemory => {
+                if process.memory_vms == 0 {
+                    theme.text
+                } else {
+                    theme.ok
                 }
-                _ => default_color, // USER, State, Command use default color
-            };
-
-            // Calculate what part of this column to display
-            let skip = horizontal_scroll_offset.saturating_sub(col_start);
-
-            // Format the value with proper alignment
-            let formatted = if idx < fixed_widths.len() {
-                match idx {
-                    0 => format!("{value:>col_width$}"), // PID - right align
-                    1 => format!("{:<col_width$}", truncate_to_width(value, col_width)), // USER - left align
-                    2..=11 => format!("{value:>col_width$}"), // Numbers - right align
-                    _ => value.to_string(),
+            }
+            ProcessColumn::Pid => plain_if(process.pid > 0),
+            // Priority - white if not default (20)
+            ProcessColumn::Priority => plain_if(process.priority != 20),
+            ProcessColumn::Nice => {
+                if process.nice_value != 0 {
+                    theme.text
+                } else {
+                    theme.dim
                 }
-            } else {
-                value.to_string() // Command - no padding
-            };
-
-            // Print the visible part
-            if skip < formatted.len() {
-                let visible_part = &formatted[skip..];
-                let remaining_width = width.saturating_sub(output_pos);
-                let to_print = truncate_to_width(visible_part, remaining_width);
-                print_colored_text(stdout, &to_print, color, None, None);
-                output_pos += to_print.len();
             }
+            ProcessColumn::ResidentMemory => plain_if(process.memory_rss > 0),
+            ProcessColumn::CpuPercent => plain_if(process.cpu_percent > 0.0),
+            ProcessColumn::MemoryPercent => plain_if(process.memory_percent > 0.0),
+            ProcessColumn::GpuPercent => plain_if(process.gpu_utilization > 0.0),
+            ProcessColumn::GpuMemory => plain_if(process.used_memory > 0),
+            // TIME+ - plain text if not 0:00:00
+            ProcessColumn::CpuTime => plain_if(cell.trim() != "0:00:00"),
+            _ => default_color, // USER, container, state and command use the default color
+        };
 
-            // Add space between columns (except after last column)
-            if idx < values.len() - 1 && output_pos < width && col_end > horizontal_scroll_offset {
-                print_colored_text(stdout, " ", default_color, None, None);
-                output_pos += 1;
+        let gap = if idx > 0 { " " } else { "" };
+        for (text, color) in [(gap, default_color), (cell.as_str(), color)] {
+            if remaining == 0 {
+                break;
+            }
+            // Drop the part scrolled off to the left
+            let len = text.chars().count();
+            if skip >= len {
+                skip -= len;
+                continue;
             }
+            let visible_part: String = text.chars().skip(skip).collect();
+            skip = 0;
+            let to_print = truncate_to_width(&visible_part, remaining);
+            remaining -= display_width(&to_print);
+            print_colored_text(stdout, &to_print, color, None, None);
         }
-
-        current_pos = col_end;
     }
 
     // Fill the rest of the line with spaces to clear any previous content
-    if output_pos < width {
-        print_colored_text(
-            stdout,
-            &" ".repeat(width - output_pos),
-            Color::Black,
-            None,
-            None,
-        );
+    if remaining > 0 {
+        print_colored_text(stdout, &" ".repeat(remaining), theme.text, None, None);
     }
 }
 
@@ -553,3 +594,103 @@ fn format_cpu_time(seconds: u64) -> String {
         format!("{}:{:02}:{secs:02}", minutes / 60, minutes % 60)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_columns_pathological_widths() {
+        let line = "    PID↓ USER         Command";
+        assert_eq!(visible_columns(line, 0, 0), "");
+        assert_eq!(visible_columns(line, 0, 1), " ");
+        assert_eq!(visible_columns(line, 7, 1), "↓");
+        assert_eq!(visible_columns(line, 0, 20), "    PID↓ USER       ");
+        // Scrolling past the end clears the line instead of slicing out of bounds
+        assert_eq!(visible_columns(line, 200, 20), " ".repeat(20));
+    }
+
+    #[test]
+    fn test_clamp_start_index() {
+        // Selection below the window after the terminal shrank
+        assert_eq!(clamp_start_index(30, 0, 10, 100), 21);
+        // Selection above the window
+        assert_eq!(clamp_start_index(5, 20, 10, 100), 5);
+        // Terminal grew: pull the window back so it ends at the last row
+        assert_eq!(clamp_start_index(95, 90, 20, 100), 80);
+        // No room at all still keeps the selected row first
+        assert_eq!(clamp_start_index(7, 0, 0, 100), 7);
+        assert_eq!(clamp_start_index(0, 3, 1, 0), 0);
+    }
+
+    fn remote_process(host_id: &str, pid: u32, used_memory: u64) -> ProcessInfo {
+        ProcessInfo {
+            device_id: 0,
+            device_uuid: "GPU-0".to_string(),
+            pid,
+            process_name: "python".to_string(),
+            used_memory,
+            used_memory_estimated: false,
+            cpu_percent: 0.0,
+            memory_percent: 0.0,
+            memory_rss: 0,
+            memory_vms: 0,
+            user: String::new(),
+            state: String::new(),
+            start_time: 0,
+            cpu_time: 0,
+            command: "python".to_string(),
+            ppid: 0,
+            threads: 0,
+            uses_gpu: true,
+            priority: 0,
+            nice_value: 0,
+            gpu_utilization: 0.0,
+            k8s_pod: None,
+            host_id: host_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_host_column_layout() {
+        let processes = vec![
+            remote_process("node-1:9090", 101, 4 << 30),
+            remote_process("gpu-node-with-long-name:9090", 202, 1 << 30),
+        ];
+        let layout = ProcessLayout::default().with_host_column();
+        let mut out = Vec::new();
+        print_process_info(
+            &mut out,
+            &processes,
+            usize::MAX,
+            0,
+            12,
+            120,
+            0,
+            "",
+            &crate::app_state::SortCriteria::Pid,
+            &crate::app_state::SortDirection::Ascending,
+            &layout,
+            None,
+            7,
+        );
+        let ansi = regex::Regex::new("\x1b\\[[0-9;]*m").unwrap();
+        let text = ansi.replace_all(std::str::from_utf8(&out).unwrap(), "");
+        let lines: Vec<&str> = text.split("\r\n").collect();
+
+        // The node comes first, cut to its 16 columns, then the usual table
+        assert!(lines[1].starts_with("HOST                PID↑ USER"));
+        assert!(lines[3].starts_with("node-1:9090          101 "));
+        assert!(lines[4].starts_with("gpu-node-with-lo     202 "));
+        assert!(lines[4].trim_end().ends_with(" 0:00:00 python"));
+        assert!(lines.iter().any(|line| line
+            .starts_with("Showing all 2 processes (7 more not fetched, see --process-top)")));
+    }
+
+    #[test]
+    fn test_process_rows_capacity_small_terminals() {
+        assert_eq!(process_rows_capacity(0), 0);
+        assert_eq!(process_rows_capacity(1), 0);
+        assert_eq!(process_rows_capacity(20), 14);
+    }
+}
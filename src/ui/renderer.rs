@@ -13,8 +13,11 @@
 // limitations under the License.
 
 // Re-export all the renderer functions from their respective modules
-pub use crate::ui::chrome::{print_function_keys, print_loading_indicator};
+pub use crate::ui::chrome::{
+    is_terminal_too_small, print_function_keys, print_loading_indicator, print_terminal_too_small,
+};
 pub use crate::ui::process_renderer::print_process_info;
 pub use crate::ui::renderers::{
     print_chassis_info, print_cpu_info, print_gpu_info, print_memory_info, print_storage_info,
+    GpuRowMarks,
 };
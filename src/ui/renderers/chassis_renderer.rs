@@ -14,11 +14,15 @@
 
 use std::io::Write;
 
-use crossterm::{queue, style::Color, style::Print};
+use crossterm::{queue, style::Print};
 
+use crate::device::thermal_pressure::thermal_level_value;
 use crate::device::ChassisInfo;
+use crate::ui::renderers::widgets::gauges::gauge_row_widths;
 use crate::ui::text::print_colored_text;
+use crate::ui::theme;
 use crate::ui::widgets::draw_bar;
+use crate::utils::temperature_unit;
 
 use super::gpu_renderer::format_hostname_with_scroll;
 
@@ -39,49 +43,71 @@ impl ChassisRenderer {
     }
 }
 
-/// Render chassis/node-level information including total power, thermal data
+/// Uptime as its two largest units, e.g. "13d 4h", "4h 12m" or "12m".
+pub fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = seconds % 86_400 / 3_600;
+    let minutes = seconds % 3_600 / 60;
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+/// Render chassis/node-level information including total power, thermal data,
+/// load and uptime. With `load_cores`, load averages are shown and colored
+/// per core.
 pub fn print_chassis_info<W: Write>(
     stdout: &mut W,
     _index: usize,
     info: &ChassisInfo,
     width: usize,
     hostname_scroll_offset: usize,
+    load_cores: Option<u32>,
 ) {
+    let theme = theme::current();
     // Format hostname with scrolling if needed
     let hostname_display = format_hostname_with_scroll(&info.hostname, hostname_scroll_offset);
 
     // Print chassis info line: NODE <hostname> Pwr:<power>W Thermal:<status> [CPU:<x>W GPU:<y>W ANE:<z>W]
-    print_colored_text(stdout, "NODE ", Color::Yellow, None, None);
-    print_colored_text(stdout, &hostname_display, Color::White, None, None);
+    print_colored_text(stdout, "NODE ", theme.label, None, None);
+    print_colored_text(stdout, &hostname_display, theme.text, None, None);
 
     // Total Power
-    print_colored_text(stdout, " Pwr:", Color::Red, None, None);
+    print_colored_text(stdout, " Pwr:", theme.label_power, None, None);
     let power_display = if let Some(power) = info.total_power_watts {
         format!("{power:>6.1}W")
     } else {
         format!("{:>7}", "N/A")
     };
-    print_colored_text(stdout, &power_display, Color::White, None, None);
+    print_colored_text(stdout, &power_display, theme.text, None, None);
 
     // Thermal pressure (Apple Silicon) or temperatures
     if let Some(ref pressure) = info.thermal_pressure {
-        print_colored_text(stdout, " Thermal:", Color::Magenta, None, None);
-        print_colored_text(stdout, &format!("{pressure:>8}"), Color::White, None, None);
+        print_colored_text(stdout, " Thermal:", theme.label_thermal, None, None);
+        let (fg, bg) = match thermal_level_value(pressure) {
+            Some(0) => (theme.ok, None),
+            Some(1) => (theme.warning, None),
+            Some(2) => (theme.critical, None),
+            Some(_) => (theme.text, Some(theme.critical)),
+            None => (theme.text, None),
+        };
+        print_colored_text(stdout, &format!("{pressure:>8}"), fg, bg, None);
     } else {
         // Show inlet/outlet temperatures if available
+        let unit = temperature_unit();
         if let Some(inlet) = info.inlet_temperature {
-            print_colored_text(stdout, " Inlet:", Color::Magenta, None, None);
-            print_colored_text(stdout, &format!("{inlet:>4.0}°C"), Color::White, None, None);
+            print_colored_text(stdout, " Inlet:", theme.label_thermal, None, None);
+            let text = format!("{:>4.0}{}", unit.convert(inlet), unit.symbol());
+            print_colored_text(stdout, &text, theme.text, None, None);
         }
         if let Some(outlet) = info.outlet_temperature {
-            print_colored_text(stdout, " Outlet:", Color::Magenta, None, None);
-            print_colored_text(
-                stdout,
-                &format!("{outlet:>4.0}°C"),
-                Color::White,
-                None,
-                None,
-            );
+            print_colored_text(stdout, " Outlet:", theme.label_thermal, None, None);
+            let text = format!("{:>4.0}{}", unit.convert(outlet), unit.symbol());
+            print_colored_text(stdout, &text, theme.text, None, None);
         }
     }
 
@@ -91,42 +117,36 @@ pub fn print_chassis_info<W: Write>(
         || info.detail.contains_key("ane_power_watts");
 
     if has_power_breakdown {
-        print_colored_text(stdout, " │", Color::DarkGrey, None, None);
+        print_colored_text(stdout, " │", theme.dim, None, None);
 
         if let Some(cpu_power) = info.detail.get("cpu_power_watts") {
             if let Ok(power) = cpu_power.parse::<f64>() {
-                print_colored_text(stdout, " CPU:", Color::Cyan, None, None);
-                print_colored_text(stdout, &format!("{power:>5.1}W"), Color::White, None, None);
+                print_colored_text(stdout, " CPU:", theme.title, None, None);
+                print_colored_text(stdout, &format!("{power:>5.1}W"), theme.text, None, None);
             }
         }
 
         if let Some(gpu_power) = info.detail.get("gpu_power_watts") {
             if let Ok(power) = gpu_power.parse::<f64>() {
-                print_colored_text(stdout, " GPU:", Color::Green, None, None);
-                print_colored_text(stdout, &format!("{power:>5.1}W"), Color::White, None, None);
+                print_colored_text(stdout, " GPU:", theme.label_info, None, None);
+                print_colored_text(stdout, &format!("{power:>5.1}W"), theme.text, None, None);
             }
         }
 
         if let Some(ane_power) = info.detail.get("ane_power_watts") {
             if let Ok(power) = ane_power.parse::<f64>() {
-                print_colored_text(stdout, " ANE:", Color::Blue, None, None);
-                print_colored_text(stdout, &format!("{power:>5.1}W"), Color::White, None, None);
+                print_colored_text(stdout, " ANE:", theme.label_memory, None, None);
+                print_colored_text(stdout, &format!("{power:>5.1}W"), theme.text, None, None);
             }
         }
     }
 
     // Fan speeds if available
     if !info.fan_speeds.is_empty() {
-        print_colored_text(stdout, " Fans:", Color::Cyan, None, None);
+        print_colored_text(stdout, " Fans:", theme.title, None, None);
         let avg_rpm: u32 =
             info.fan_speeds.iter().map(|f| f.speed_rpm).sum::<u32>() / info.fan_speeds.len() as u32;
-        print_colored_text(
-            stdout,
-            &format!("{avg_rpm:>5}RPM"),
-            Color::White,
-            None,
-            None,
-        );
+        print_colored_text(stdout, &format!("{avg_rpm:>5}RPM"), theme.text, None, None);
     }
 
     // PSU status if available
@@ -137,11 +157,11 @@ pub fn print_chassis_info<W: Write>(
             .filter(|p| p.status == crate::device::PsuStatus::Ok)
             .count();
         let total = info.psu_status.len();
-        print_colored_text(stdout, " PSU:", Color::Yellow, None, None);
+        print_colored_text(stdout, " PSU:", theme.label, None, None);
         let psu_color = if ok_count == total {
-            Color::Green
+            theme.ok
         } else {
-            Color::Red
+            theme.critical
         };
         print_colored_text(
             stdout,
@@ -152,13 +172,51 @@ pub fn print_chassis_info<W: Write>(
         );
     }
 
+    if let Some(system) = &info.system {
+        print_colored_text(stdout, " Load:", theme.label_thermal, None, None);
+        let cores = load_cores.filter(|&cores| cores > 0);
+        for load in system.load_average {
+            let (load, color) = match cores {
+                Some(cores) => {
+                    let per_core = load / f64::from(cores);
+                    let color = if per_core >= 1.0 {
+                        theme.critical
+                    } else if per_core >= 0.7 {
+                        theme.warning
+                    } else {
+                        theme.ok
+                    };
+                    (per_core, color)
+                }
+                None => (load, theme.text),
+            };
+            print_colored_text(stdout, &format!(" {load:.2}"), color, None, None);
+        }
+        print_colored_text(stdout, " Up:", theme.label_info, None, None);
+        print_colored_text(
+            stdout,
+            &format_uptime(system.uptime_seconds),
+            theme.text,
+            None,
+            None,
+        );
+    }
+
     queue!(stdout, Print("\r\n")).unwrap();
 
+    // Kernel and OS line
+    if let Some(system) = &info.system {
+        let os_line = format!("{} {}", system.os_name, system.kernel_version);
+        print_colored_text(stdout, "     OS:", theme.label_info, None, None);
+        let os_line: String = os_line.chars().take(width.saturating_sub(8)).collect();
+        print_colored_text(stdout, &format!(" {os_line}"), theme.text, None, None);
+        queue!(stdout, Print("\r\n")).unwrap();
+    }
+
     // Power gauge bar (if power data available)
     if let Some(power) = info.total_power_watts {
-        // Calculate gauge width with 5 char padding on each side
-        let available_width = width.saturating_sub(10);
-        let gauge_width = available_width;
+        // Single gauge with 5 char padding on each side
+        let (gauge_width, right_padding) = gauge_row_widths(width, 1);
 
         // Determine max power for gauge based on platform
         // Apple Silicon: ~150W max, Server: ~1000W max
@@ -167,10 +225,7 @@ pub fn print_chassis_info<W: Write>(
 
         let power_percent = (power / max_power * 100.0).min(100.0);
 
-        let left_padding = 5;
-        let right_padding = width - left_padding - gauge_width;
-
-        print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+        print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
 
         draw_bar(
             stdout,
@@ -178,10 +233,10 @@ pub fn print_chassis_info<W: Write>(
             power_percent,
             100.0,
             gauge_width,
-            Some(format!("{power:.1}W")),
+            Some(format_args!("{power:.1}W")),
         );
 
-        print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None);
+        print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None);
         queue!(stdout, Print("\r\n")).unwrap();
     }
 }
@@ -189,7 +244,7 @@ pub fn print_chassis_info<W: Write>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::device::ChassisInfo;
+    use crate::device::{ChassisInfo, HostSystemInfo};
 
     #[test]
     fn test_chassis_renderer_new() {
@@ -207,10 +262,46 @@ mod tests {
             ..Default::default()
         };
 
-        print_chassis_info(&mut buffer, 0, &chassis, 80, 0);
+        print_chassis_info(&mut buffer, 0, &chassis, 80, 0, None);
         let output = String::from_utf8(buffer).unwrap();
 
         assert!(output.contains("NODE"));
         assert!(output.contains("test-host"));
     }
+
+    #[test]
+    fn test_format_uptime() {
+        assert_eq!(format_uptime(13 * 86_400 + 4 * 3_600 + 59 * 60), "13d 4h");
+        assert_eq!(format_uptime(4 * 3_600 + 12 * 60 + 30), "4h 12m");
+        assert_eq!(format_uptime(12 * 60), "12m");
+        assert_eq!(format_uptime(0), "0m");
+    }
+
+    #[test]
+    fn test_print_chassis_info_system() {
+        let chassis = ChassisInfo {
+            hostname: "node-1".to_string(),
+            system: Some(HostSystemInfo {
+                load_average: [16.0, 8.0, 4.0],
+                uptime_seconds: 13 * 86_400 + 4 * 3_600,
+                kernel_version: "6.8.0-45-generic".to_string(),
+                os_name: "Ubuntu 24.04.1 LTS".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let mut buffer = Vec::new();
+        print_chassis_info(&mut buffer, 0, &chassis, 120, 0, None);
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains(" 16.00") && output.contains(" 4.00"));
+        assert!(output.contains("13d 4h"));
+        assert!(output.contains("Ubuntu 24.04.1 LTS 6.8.0-45-generic"));
+
+        // Per core on 16 cores: fully loaded, half loaded, barely loaded
+        let mut buffer = Vec::new();
+        print_chassis_info(&mut buffer, 0, &chassis, 120, 0, Some(16));
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains(" 1.00") && output.contains(" 0.50") && output.contains(" 0.25"));
+        assert!(!output.contains(" 16.00"));
+    }
 }
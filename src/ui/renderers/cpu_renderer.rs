@@ -14,13 +14,17 @@
 
 use std::io::Write;
 
-use crossterm::{queue, style::Color, style::Print};
+use crossterm::{queue, style::Print};
 
+use crate::device::numa::NumaTopology;
 use crate::device::{CoreUtilization, CpuInfo};
-use crate::ui::text::print_colored_text;
+use crate::ui::text::{print_colored_text, scroll_to_width};
+use crate::ui::theme;
 use crate::ui::widgets::draw_bar;
+use crate::utils::temperature_unit;
 
-use super::widgets::gauges::get_utilization_block;
+use super::gpu_renderer::format_hostname_with_scroll;
+use super::widgets::gauges::{gauge_row_widths, get_utilization_block};
 
 /// CPU renderer struct implementing the DeviceRenderer trait
 #[allow(dead_code)]
@@ -39,23 +43,6 @@ impl CpuRenderer {
     }
 }
 
-/// Helper function to format hostname with scrolling
-fn format_hostname_with_scroll(hostname: &str, scroll_offset: usize) -> String {
-    if hostname.len() > 9 {
-        let scroll_len = hostname.len() + 3;
-        let start_pos = scroll_offset % scroll_len;
-        let extended_hostname = format!("{hostname}   {hostname}");
-        extended_hostname
-            .chars()
-            .skip(start_pos)
-            .take(9)
-            .collect::<String>()
-    } else {
-        // Always return 9 characters, left-aligned with space padding
-        format!("{hostname:<9}")
-    }
-}
-
 /// Render fancy CPU visualization with utilization
 fn render_cpu_visualization<W: Write>(
     stdout: &mut W,
@@ -64,6 +51,7 @@ fn render_cpu_visualization<W: Write>(
     width: usize,
     is_container: bool,
 ) {
+    let theme = theme::current();
     if per_core_utilization.is_empty() {
         return;
     }
@@ -74,7 +62,7 @@ fn render_cpu_visualization<W: Write>(
     let box_width = width.saturating_sub(10);
 
     // Create a visual representation
-    print_colored_text(stdout, "     ", Color::White, None, None);
+    print_colored_text(stdout, "     ", theme.text, None, None);
 
     // Draw top border
     let title = if is_container {
@@ -86,22 +74,22 @@ fn render_cpu_visualization<W: Write>(
     // We want total length to be box_width + 2 (for the corners)
     let title_with_spaces_len = 1 + title.len() + 1; // " " + title + " "
 
-    print_colored_text(stdout, "╭─", Color::Cyan, None, None);
-    print_colored_text(stdout, " ", Color::White, None, None);
-    print_colored_text(stdout, title, Color::Cyan, None, None);
-    print_colored_text(stdout, " ", Color::White, None, None);
+    print_colored_text(stdout, "╭─", theme.title, None, None);
+    print_colored_text(stdout, " ", theme.text, None, None);
+    print_colored_text(stdout, title, theme.title, None, None);
+    print_colored_text(stdout, " ", theme.text, None, None);
 
     // Fill the rest with dashes, accounting for the closing corner
     let remaining_dashes = box_width.saturating_sub(title_with_spaces_len + 1); // +1 for "─" after "╭"
     for _ in 0..remaining_dashes {
-        print_colored_text(stdout, "─", Color::Cyan, None, None);
+        print_colored_text(stdout, "─", theme.title, None, None);
     }
-    print_colored_text(stdout, "╮", Color::Cyan, None, None);
+    print_colored_text(stdout, "╮", theme.title, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Draw CPU visualization line
-    print_colored_text(stdout, "     ", Color::White, None, None);
-    print_colored_text(stdout, "│ ", Color::Cyan, None, None);
+    print_colored_text(stdout, "     ", theme.text, None, None);
+    print_colored_text(stdout, "│ ", theme.title, None, None);
 
     // Calculate actual content for proper padding
     // For containers with cpuset, we show all monitored cores since they're already filtered
@@ -161,14 +149,14 @@ fn render_cpu_visualization<W: Write>(
         // Add grouping spaces for readability
         idx += 1;
         if (idx % group_size == 0) && (idx < display_count) {
-            print_colored_text(stdout, " ", Color::White, None, None);
+            print_colored_text(stdout, " ", theme.text, None, None);
         }
     }
 
     // Add summary
     if is_container && cpuset.is_some() {
         let summary = format!("  ({display_count} allocated)");
-        print_colored_text(stdout, &summary, Color::Yellow, None, None);
+        print_colored_text(stdout, &summary, theme.label, None, None);
     } else {
         let avg_util = per_core_utilization
             .iter()
@@ -176,7 +164,7 @@ fn render_cpu_visualization<W: Write>(
             .sum::<f64>()
             / total_cpus as f64;
         let summary = format!("  ({total_cpus} cores, {avg_util:.1}% avg)");
-        print_colored_text(stdout, &summary, Color::Yellow, None, None);
+        print_colored_text(stdout, &summary, theme.label, None, None);
     }
 
     // Add padding to align with box width
@@ -186,22 +174,36 @@ fn render_cpu_visualization<W: Write>(
     let padding_needed = inner_width.saturating_sub(content_display_len);
 
     for _ in 0..padding_needed {
-        print_colored_text(stdout, " ", Color::White, None, None);
+        print_colored_text(stdout, " ", theme.text, None, None);
     }
 
-    print_colored_text(stdout, " │", Color::Cyan, None, None);
+    print_colored_text(stdout, " │", theme.title, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Draw bottom border
-    print_colored_text(stdout, "     ", Color::White, None, None);
-    print_colored_text(stdout, "╰", Color::Cyan, None, None);
+    print_colored_text(stdout, "     ", theme.text, None, None);
+    print_colored_text(stdout, "╰", theme.title, None, None);
     for _ in 0..box_width {
-        print_colored_text(stdout, "─", Color::Cyan, None, None);
+        print_colored_text(stdout, "─", theme.title, None, None);
     }
-    print_colored_text(stdout, "╯", Color::Cyan, None, None);
+    print_colored_text(stdout, "╯", theme.title, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 }
 
+/// True when `per_socket_info` holds one entry per NUMA node of this machine
+/// and there is room for a gauge per node. Single-node systems, remote hosts
+/// and platforms without NUMA data keep a single gauge.
+fn shows_per_node_gauges(info: &CpuInfo, width: usize) -> bool {
+    const MIN_GAUGE_WIDTH: usize = 20;
+
+    let topology = NumaTopology::get();
+    let nodes = info.per_socket_info.len();
+    if !topology.is_multi_node() || nodes != topology.nodes().count() {
+        return false;
+    }
+    gauge_row_widths(width, nodes).0 >= MIN_GAUGE_WIDTH
+}
+
 /// Render CPU information including model, cores, frequency, and utilization
 pub fn print_cpu_info<W: Write>(
     stdout: &mut W,
@@ -212,69 +214,61 @@ pub fn print_cpu_info<W: Write>(
     cpu_name_scroll_offset: usize,
     hostname_scroll_offset: usize,
 ) {
+    let theme = theme::current();
     // Format CPU name with scrolling if needed (same as GPU: 15 chars)
-    let cpu_name = if info.cpu_model.len() > 15 {
-        let scroll_len = info.cpu_model.len() + 3;
-        let start_pos = cpu_name_scroll_offset % scroll_len;
-        let extended_name = format!("{0}   {0}", info.cpu_model);
-        let visible_name = extended_name
-            .chars()
-            .skip(start_pos)
-            .take(15)
-            .collect::<String>();
-        visible_name
-    } else {
-        format!("{:<15}", info.cpu_model)
-    };
+    let cpu_name = format!(
+        "{:<15}",
+        scroll_to_width(&info.cpu_model, 15, cpu_name_scroll_offset)
+    );
 
     // Format hostname with scrolling if needed (same as GPU: 9 chars)
     let hostname_display = format_hostname_with_scroll(&info.hostname, hostname_scroll_offset);
 
     // Print CPU info line
-    print_colored_text(stdout, "CPU  ", Color::Cyan, None, None);
-    print_colored_text(stdout, &cpu_name, Color::White, None, None);
-    print_colored_text(stdout, " @ ", Color::DarkGreen, None, None);
-    print_colored_text(stdout, &hostname_display, Color::White, None, None);
-    print_colored_text(stdout, " Arch:", Color::Yellow, None, None);
-    print_colored_text(stdout, &info.architecture, Color::White, None, None);
-    print_colored_text(stdout, " Sockets:", Color::Yellow, None, None);
+    print_colored_text(stdout, "CPU  ", theme.title, None, None);
+    print_colored_text(stdout, &cpu_name, theme.text, None, None);
+    print_colored_text(stdout, " @ ", theme.host, None, None);
+    print_colored_text(stdout, &hostname_display, theme.text, None, None);
+    print_colored_text(stdout, " Arch:", theme.label, None, None);
+    print_colored_text(stdout, &info.architecture, theme.text, None, None);
+    print_colored_text(stdout, " Sockets:", theme.label, None, None);
     print_colored_text(
         stdout,
         &format!("{:>2}", info.socket_count),
-        Color::White,
+        theme.text,
         None,
         None,
     );
     // Show P-Core/E-Core counts for Apple Silicon, regular core count for others
     if let Some(apple_info) = &info.apple_silicon_info {
-        print_colored_text(stdout, " Cores:", Color::Green, None, None);
+        print_colored_text(stdout, " Cores:", theme.label_info, None, None);
         print_colored_text(
             stdout,
             &format!("{:>2}P+", apple_info.p_core_count),
-            Color::White,
+            theme.text,
             None,
             None,
         );
         print_colored_text(
             stdout,
             &format!("{:>2}E", apple_info.e_core_count),
-            Color::White,
+            theme.text,
             None,
             None,
         );
     } else {
-        print_colored_text(stdout, " Cores:", Color::Green, None, None);
+        print_colored_text(stdout, " Cores:", theme.label_info, None, None);
         print_colored_text(
             stdout,
             &format!("{:>2}", info.total_cores),
-            Color::White,
+            theme.text,
             None,
             None,
         );
     }
 
     // Display frequency - P+E format for Apple Silicon, regular for others
-    print_colored_text(stdout, " Freq:", Color::Magenta, None, None);
+    print_colored_text(stdout, " Freq:", theme.label_thermal, None, None);
     if let Some(apple_info) = &info.apple_silicon_info {
         if let (Some(p_freq), Some(e_freq)) = (
             apple_info.p_cluster_frequency_mhz,
@@ -297,7 +291,7 @@ pub fn print_cpu_info<W: Write>(
             print_colored_text(
                 stdout,
                 &format!("{freq_display:>13}"),
-                Color::White,
+                theme.text,
                 None,
                 None,
             );
@@ -307,7 +301,7 @@ pub fn print_cpu_info<W: Write>(
             print_colored_text(
                 stdout,
                 &format!("{freq_ghz:>6.1}GHz"),
-                Color::White,
+                theme.text,
                 None,
                 None,
             );
@@ -318,15 +312,17 @@ pub fn print_cpu_info<W: Write>(
         print_colored_text(
             stdout,
             &format!("{freq_ghz:>6.1}GHz"),
-            Color::White,
+            theme.text,
             None,
             None,
         );
     }
     // Display CPU temperature if available (not on macOS)
     if let Some(temp) = info.temperature {
-        print_colored_text(stdout, " Temp:", Color::Magenta, None, None);
-        print_colored_text(stdout, &format!("{temp:>3}°C"), Color::White, None, None);
+        print_colored_text(stdout, " Temp:", theme.label_thermal, None, None);
+        let unit = temperature_unit();
+        let text = format!("{:>3.0}{}", unit.convert(f64::from(temp)), unit.symbol());
+        print_colored_text(stdout, &text, theme.text, None, None);
     }
 
     // Display cache based on platform type
@@ -335,32 +331,32 @@ pub fn print_cpu_info<W: Write>(
             (apple_info.p_core_l2_cache_mb, apple_info.e_core_l2_cache_mb)
         {
             // Apple Silicon: Display L2 cache as P+E format
-            print_colored_text(stdout, " L2 Cache:", Color::Red, None, None);
+            print_colored_text(stdout, " L2 Cache:", theme.label_power, None, None);
             print_colored_text(
                 stdout,
                 &format!("{p_cache}MB+{e_cache}MB"),
-                Color::White,
+                theme.text,
                 None,
                 None,
             );
         } else if info.cache_size_mb > 0 {
             // Fallback to total cache
-            print_colored_text(stdout, " L2 Cache:", Color::Red, None, None);
+            print_colored_text(stdout, " L2 Cache:", theme.label_power, None, None);
             print_colored_text(
                 stdout,
                 &format!("{:>5}MB", info.cache_size_mb),
-                Color::White,
+                theme.text,
                 None,
                 None,
             );
         }
     } else if info.cache_size_mb > 0 {
         // Non-Apple Silicon: display L3 cache (Intel Mac and Linux)
-        print_colored_text(stdout, " L3 Cache:", Color::Red, None, None);
+        print_colored_text(stdout, " L3 Cache:", theme.label_power, None, None);
         print_colored_text(
             stdout,
             &format!("{:>5}MB", info.cache_size_mb),
-            Color::White,
+            theme.text,
             None,
             None,
         );
@@ -368,26 +364,22 @@ pub fn print_cpu_info<W: Write>(
 
     // Display CPU power if available
     if let Some(power) = info.power_consumption {
-        print_colored_text(stdout, " Pwr:", Color::Red, None, None);
-        print_colored_text(stdout, &format!("{power:>4.0}W"), Color::White, None, None);
+        print_colored_text(stdout, " Pwr:", theme.label_power, None, None);
+        print_colored_text(stdout, &format!("{power:>4.0}W"), theme.text, None, None);
     }
 
-    queue!(stdout, Print("\r\n")).unwrap();
+    if info.stale {
+        print_colored_text(stdout, " STALE", theme.dim, None, None);
+    }
 
-    // Calculate gauge widths with 5 char padding on each side and 2 space separation
-    let available_width = width.saturating_sub(10); // 5 padding each side
+    queue!(stdout, Print("\r\n")).unwrap();
 
+    // Gauges get 5 char padding on each side and 2 space separation
     if let Some(apple_info) = &info.apple_silicon_info {
         // Apple Silicon: Two gauges for P-Core and E-Core
-        let num_gauges = 2;
-        let gauge_width = (available_width - 2) / 2; // 2 spaces between gauges
-
-        // Calculate actual space used and dynamic right padding
-        let total_gauge_width = gauge_width * num_gauges + (num_gauges - 1) * 2;
-        let left_padding = 5;
-        let right_padding = width - left_padding - total_gauge_width;
+        let (gauge_width, right_padding) = gauge_row_widths(width, 2);
 
-        print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+        print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
 
         // P-Core gauge
         draw_bar(
@@ -398,7 +390,7 @@ pub fn print_cpu_info<W: Write>(
             gauge_width,
             None,
         );
-        print_colored_text(stdout, "  ", Color::White, None, None); // 2 space separator
+        print_colored_text(stdout, "  ", theme.text, None, None); // 2 space separator
 
         // E-Core gauge
         draw_bar(
@@ -410,23 +402,39 @@ pub fn print_cpu_info<W: Write>(
             None,
         );
 
-        print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None);
+        print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None);
     // dynamic right padding
+    } else if shows_per_node_gauges(info, width) {
+        // Multi-node systems: one gauge per NUMA node
+        let (gauge_width, right_padding) = gauge_row_widths(width, info.per_socket_info.len());
+
+        print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
+
+        for (i, socket) in info.per_socket_info.iter().enumerate() {
+            if i > 0 {
+                print_colored_text(stdout, "  ", theme.text, None, None); // 2 space separator
+            }
+            draw_bar(
+                stdout,
+                &format!("Node{}", socket.socket_id),
+                socket.utilization,
+                100.0,
+                gauge_width,
+                None,
+            );
+        }
+
+        print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None);
     } else {
         // Other CPUs: Single CPU utilization gauge
-        let gauge_width = available_width;
-
-        // Calculate actual space used and dynamic right padding
-        let total_gauge_width = gauge_width;
-        let left_padding = 5;
-        let right_padding = width - left_padding - total_gauge_width;
+        let (gauge_width, right_padding) = gauge_row_widths(width, 1);
 
-        print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+        print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
 
         // CPU gauge
         draw_bar(stdout, "CPU", info.utilization, 100.0, gauge_width, None);
 
-        print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None);
+        print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None);
         // dynamic right padding
     }
 
@@ -488,8 +496,9 @@ pub fn print_cpu_info<W: Write>(
         // Calculate the width for each core bar
         let available_width = width.saturating_sub(10); // 5 padding each side
         let spacing_between_cores = 2;
-        let core_bar_width =
-            (available_width - (cores_per_line - 1) * spacing_between_cores) / cores_per_line;
+        let core_bar_width = available_width
+            .saturating_sub((cores_per_line - 1) * spacing_between_cores)
+            / cores_per_line;
 
         // Display E-cores first (matches Apple Silicon core ordering)
         let mut cores_displayed = 0;
@@ -499,7 +508,7 @@ pub fn print_cpu_info<W: Write>(
             }
 
             if cores_displayed % cores_per_line == 0 {
-                print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+                print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
             }
 
             let label = format!("E{}", i + 1);
@@ -514,7 +523,7 @@ pub fn print_cpu_info<W: Write>(
 
             cores_displayed += 1;
             if cores_displayed % cores_per_line != 0 && cores_displayed < total_cores {
-                print_colored_text(stdout, "  ", Color::White, None, None); // spacing between cores
+                print_colored_text(stdout, "  ", theme.text, None, None); // spacing between cores
             }
         }
 
@@ -525,7 +534,7 @@ pub fn print_cpu_info<W: Write>(
             }
 
             if cores_displayed % cores_per_line == 0 {
-                print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+                print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
             }
 
             let label = format!("P{}", i + 1);
@@ -540,7 +549,7 @@ pub fn print_cpu_info<W: Write>(
 
             cores_displayed += 1;
             if cores_displayed % cores_per_line != 0 && cores_displayed < total_cores {
-                print_colored_text(stdout, "  ", Color::White, None, None); // spacing between cores
+                print_colored_text(stdout, "  ", theme.text, None, None); // spacing between cores
             }
         }
 
@@ -551,7 +560,7 @@ pub fn print_cpu_info<W: Write>(
             }
 
             if cores_displayed % cores_per_line == 0 {
-                print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+                print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
             }
 
             let label = format!("C{}", i + 1);
@@ -566,7 +575,7 @@ pub fn print_cpu_info<W: Write>(
 
             cores_displayed += 1;
             if cores_displayed % cores_per_line != 0 && cores_displayed < total_cores {
-                print_colored_text(stdout, "  ", Color::White, None, None); // spacing between cores
+                print_colored_text(stdout, "  ", theme.text, None, None); // spacing between cores
             }
         }
 
@@ -578,7 +587,7 @@ pub fn print_cpu_info<W: Write>(
             print_colored_text(
                 stdout,
                 &" ".repeat(remaining_width + spacing_between_cores),
-                Color::White,
+                theme.text,
                 None,
                 None,
             );
@@ -587,8 +596,8 @@ pub fn print_cpu_info<W: Write>(
         // Add final right padding
         let total_line_width =
             cores_per_line * core_bar_width + (cores_per_line - 1) * spacing_between_cores;
-        let right_padding = width - 5 - total_line_width;
-        print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None);
+        let right_padding = width.saturating_sub(5 + total_line_width);
+        print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None);
 
         queue!(stdout, Print("\r\n")).unwrap();
     }
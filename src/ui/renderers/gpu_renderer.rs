@@ -12,13 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Write as _;
 use std::io::Write;
 
-use crossterm::{queue, style::Color, style::Print};
+use crossterm::{queue, style::Print};
 
+use crate::device::gpu_fan::fan_speed_summary;
+use crate::device::gpu_temperature::{hottest, TemperatureSensor};
+use crate::device::k8s_allocation::K8S_ALLOCATED_KEY;
+use crate::device::memory_headroom::{MemoryHeadroom, OomRisk};
+use crate::device::numa::PCIE_PATH_KEY;
+use crate::device::power_headroom::power_headroom;
+use crate::device::readers::nvidia::PEER_TOPOLOGY_KEY;
+use crate::device::readers::rebellions::neural_engine_utilization;
 use crate::device::GpuInfo;
-use crate::ui::text::print_colored_text;
+use crate::ui::change_highlight::{flash_colors, GpuFlash};
+use crate::ui::renderers::widgets::gauges::gauge_row_widths;
+use crate::ui::text::{
+    print_colored_fmt, print_colored_text, print_highlighted_fmt, scroll_to_width, InlineText,
+};
+use crate::ui::theme;
 use crate::ui::widgets::draw_bar;
+use crate::utils::{format_bytes, temperature_unit, unit_system, write_bytes_of};
 
 /// GPU renderer struct implementing the DeviceRenderer trait
 #[allow(dead_code)]
@@ -37,159 +52,233 @@ impl GpuRenderer {
     }
 }
 
-/// Helper function to format hostname with scrolling
+/// Hostname scrolled through 9 characters; shorter ones are padded to 9
 pub(crate) fn format_hostname_with_scroll(hostname: &str, scroll_offset: usize) -> String {
-    if hostname.len() > 9 {
-        let scroll_len = hostname.len() + 3;
-        let start_pos = scroll_offset % scroll_len;
-        let extended_hostname = format!("{hostname}   {hostname}");
-        extended_hostname
-            .chars()
-            .skip(start_pos)
-            .take(9)
-            .collect::<String>()
-    } else {
-        // Always return 9 characters, left-aligned with space padding
-        format!("{hostname:<9}")
-    }
+    format!("{:<9}", scroll_to_width(hostname, 9, scroll_offset))
+}
+
+/// How a device row is flagged in the GPU list
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GpuRowMarks {
+    /// Pinned to the top; shown with a `*` after the device type
+    pub pinned: bool,
+    /// Selected with Tab/Shift+Tab or a click; the name is highlighted
+    pub selected: bool,
+    /// The node stopped refreshing this device; values are greyed out
+    pub stale: bool,
+    /// The driver no longer reports this device; shown as `ERROR/lost`
+    /// without readings
+    pub lost: bool,
+    /// Utilization averaged over recent readings with `--smooth`; drawn in
+    /// the gauge while the `Util:` figure stays raw
+    pub smoothed_utilization: Option<f64>,
+    /// Values that changed significantly in a recent refresh, with `C`
+    pub flash: GpuFlash,
 }
 
 /// Render GPU information including utilization, memory, temperature, and power
 pub fn print_gpu_info<W: Write>(
     stdout: &mut W,
-    _index: usize,
+    marks: GpuRowMarks,
     info: &GpuInfo,
+    headroom: Option<MemoryHeadroom>,
     width: usize,
     device_name_scroll_offset: usize,
     hostname_scroll_offset: usize,
 ) {
-    // Format device name with scrolling if needed
-    let device_name = if info.name.len() > 15 {
-        let scroll_len = info.name.len() + 3;
-        let start_pos = device_name_scroll_offset % scroll_len;
-        let extended_name = format!("{}   {}", info.name, info.name);
-        let visible_name = extended_name
-            .chars()
-            .skip(start_pos)
-            .take(15)
-            .collect::<String>();
-        visible_name
-    } else {
-        format!("{:<15}", info.name)
-    };
+    let theme = theme::current();
+    // Format device name with scrolling if needed; short names are padded
+    // to 15 columns as they are printed
+    let device_name = scroll_to_width(&info.name, 15, device_name_scroll_offset);
 
     // Format hostname with scrolling if needed
-    let hostname_display = format_hostname_with_scroll(&info.hostname, hostname_scroll_offset);
+    let hostname_display = scroll_to_width(&info.hostname, 9, hostname_scroll_offset);
 
     // Calculate values
-    let memory_gb = info.used_memory as f64 / (1024.0 * 1024.0 * 1024.0);
-    let total_memory_gb = info.total_memory as f64 / (1024.0 * 1024.0 * 1024.0);
     let memory_percent = if info.total_memory > 0 {
         (info.used_memory as f64 / info.total_memory as f64) * 100.0
     } else {
         0.0
     };
 
-    // Print info line: <device_type> <name> @ <hostname> Util:4.0% Mem:25.2/128GB Temp:0°C Pwr:0.0W
-    print_colored_text(
-        stdout,
-        &format!("{:<5}", info.device_type),
-        Color::Cyan,
-        None,
-        None,
-    );
-    print_colored_text(stdout, &device_name, Color::White, None, None);
-    print_colored_text(stdout, " @ ", Color::DarkGreen, None, None);
-    print_colored_text(stdout, &hostname_display, Color::White, None, None);
-    print_colored_text(stdout, " Util:", Color::Yellow, None, None);
-    let util_display = if info.utilization < 0.0 {
-        format!("{:>6}", "N/A")
+    // Values of a device its node no longer refreshes are greyed out
+    let value_color = if marks.stale { theme.dim } else { theme.text };
+
+    // Print info line: <device_type> <name> @ <hostname> Util:4.0% VRAM:25.2/128.0GiB Temp:0°C Pwr:0.0W
+    print_colored_text(stdout, &info.device_type, theme.title, None, None);
+    let pin_marker = if marks.pinned { "*" } else { "" };
+    print_colored_text(stdout, pin_marker, theme.label, None, None);
+    let padding = 5usize.saturating_sub(info.device_type.len() + pin_marker.len());
+    print_colored_text(stdout, "", theme.title, None, Some(padding));
+    if marks.selected {
+        print_colored_text(
+            stdout,
+            &device_name,
+            theme.selection_fg,
+            Some(theme.selection_bg),
+            Some(15),
+        );
     } else {
-        format!("{:>5.1}%", info.utilization)
-    };
-    print_colored_text(stdout, &util_display, Color::White, None, None);
-    print_colored_text(stdout, " VRAM:", Color::Blue, None, None);
-    let vram_display = if info.detail.get("metrics_available") == Some(&"false".to_string()) {
-        format!("{:>11}", "N/A")
+        print_colored_text(stdout, &device_name, value_color, None, Some(15));
+    }
+    print_colored_text(stdout, " @ ", theme.host, None, None);
+    print_colored_text(stdout, &hostname_display, value_color, None, Some(9));
+
+    // A lost device keeps its two lines, with the failure in place of readings
+    if marks.lost {
+        print_colored_text(stdout, " ", theme.text, None, None);
+        print_colored_text(
+            stdout,
+            "ERROR/lost",
+            theme.badge_fg,
+            Some(theme.critical),
+            None,
+        );
+        queue!(stdout, Print("\r\n")).unwrap();
+        print_colored_text(
+            stdout,
+            "     No longer reported by its driver; it may have fallen off the bus",
+            theme.critical,
+            None,
+            None,
+        );
+        queue!(stdout, Print("\r\n")).unwrap();
+        return;
+    }
+
+    // Values are formatted straight into the frame; this runs for every
+    // device on every frame
+    let metrics_unavailable = info
+        .detail
+        .get("metrics_available")
+        .is_some_and(|v| v == "false");
+    print_colored_text(stdout, " Util:", theme.label, None, None);
+    if info.utilization < 0.0 {
+        print_colored_fmt(stdout, format_args!("{:>6}", "N/A"), value_color);
     } else {
-        // Format total memory with proper precision: 1 decimal for sub-GB, 0 decimal for GB+
-        let total_fmt = if total_memory_gb < 1.0 {
-            format!("{total_memory_gb:.1}")
-        } else {
-            format!("{total_memory_gb:.0}")
+        let (fg, bg) = flash_colors(marks.flash.utilization, value_color);
+        print_highlighted_fmt(stdout, format_args!("{:>5.1}%", info.utilization), fg, bg);
+    }
+    print_colored_text(stdout, " VRAM:", theme.label_memory, None, None);
+    if metrics_unavailable {
+        print_colored_fmt(stdout, format_args!("{:>13}", "N/A"), value_color);
+    } else {
+        let mut vram = InlineText::new();
+        let _ = write_bytes_of(
+            &mut vram,
+            info.used_memory,
+            info.total_memory,
+            unit_system(),
+        );
+        let (fg, bg) = flash_colors(marks.flash.memory, value_color);
+        print_highlighted_fmt(stdout, format_args!("{vram:>13}"), fg, bg);
+    }
+
+    // OOM badge when headroom is under --oom-warn-pct, with the largest
+    // single allocation so it is clear whether one process can be moved
+    if let Some(headroom) = headroom {
+        let badge_color = match headroom.risk {
+            OomRisk::None => None,
+            OomRisk::Warning => Some(theme.warning),
+            OomRisk::Critical => Some(theme.critical),
         };
-        format!("{:>11}", format!("{memory_gb:.1}/{total_fmt}GB"))
-    };
-    print_colored_text(stdout, &vram_display, Color::White, None, None);
-    print_colored_text(stdout, " Temp:", Color::Magenta, None, None);
+        if let Some(color) = badge_color {
+            print_colored_text(stdout, " OOM?", color, None, None);
+            if let Some(largest) = headroom.largest_process_bytes {
+                print_colored_fmt(
+                    stdout,
+                    format_args!(" top:{}", format_bytes(largest)),
+                    value_color,
+                );
+            }
+        }
+    }
+
+    print_colored_text(stdout, " Temp:", theme.label_thermal, None, None);
 
     // For Apple Silicon, display thermal pressure level instead of numeric temperature
-    let temp_display = if info.name.contains("Apple") || info.name.contains("Metal") {
-        if let Some(thermal_level) = info.detail.get("thermal_pressure") {
-            format!("{thermal_level:>7}")
-        } else {
-            format!("{:>7}", "Unknown")
-        }
-    } else if info.detail.get("metrics_available") == Some(&"false".to_string()) {
-        format!("{:>7}", "N/A")
+    let is_apple_silicon = info.name.contains("Apple") || info.name.contains("Metal");
+    if is_apple_silicon {
+        let thermal_level = info
+            .detail
+            .get("thermal_pressure")
+            .map_or("Unknown", String::as_str);
+        print_colored_fmt(stdout, format_args!("{thermal_level:>7}"), value_color);
+    } else if metrics_unavailable {
+        print_colored_fmt(stdout, format_args!("{:>7}", "N/A"), value_color);
     } else {
-        format!("{:>4}°C", info.temperature)
-    };
-
-    print_colored_text(stdout, &temp_display, Color::White, None, None);
+        // The hotter of the core and hotspot sensors, marked when it is the hotspot
+        let (temperature, sensor) = hottest(info);
+        let (fg, bg) = flash_colors(marks.flash.temperature, value_color);
+        let unit = temperature_unit();
+        print_highlighted_fmt(
+            stdout,
+            format_args!(
+                "{:>4.0}{}",
+                unit.convert(f64::from(temperature)),
+                unit.symbol()
+            ),
+            fg,
+            bg,
+        );
+        if sensor == TemperatureSensor::Hotspot {
+            print_colored_text(stdout, "(hot)", theme.label_thermal, None, None);
+        }
+    }
 
     // Display GPU frequency
     if info.frequency > 0 {
-        print_colored_text(stdout, " Freq:", Color::Magenta, None, None);
+        print_colored_text(stdout, " Freq:", theme.label_thermal, None, None);
         if info.frequency >= 1000 {
-            print_colored_text(
-                stdout,
-                &format!("{:.2}GHz", info.frequency as f64 / 1000.0),
-                Color::White,
-                None,
-                None,
-            );
+            let ghz = info.frequency as f64 / 1000.0;
+            print_colored_fmt(stdout, format_args!("{ghz:.2}GHz"), value_color);
         } else {
-            print_colored_text(
-                stdout,
-                &format!("{}MHz", info.frequency),
-                Color::White,
-                None,
-                None,
-            );
+            print_colored_fmt(stdout, format_args!("{}MHz", info.frequency), value_color);
         }
     }
 
-    print_colored_text(stdout, " Pwr:", Color::Red, None, None);
+    print_colored_text(stdout, " Pwr:", theme.label_power, None, None);
 
     // Check if power_limit_max is available and display as current/max
     // For Apple Silicon, info.power_consumption contains GPU power only
-    let is_apple_silicon = info.name.contains("Apple") || info.name.contains("Metal");
-    let power_display = if info.power_consumption < 0.0 {
-        "N/A".to_string()
+    let power_max = info
+        .detail
+        .get("power_limit_max")
+        .and_then(|max| max.parse::<f64>().ok());
+    let mut power = InlineText::new();
+    let _ = if info.power_consumption < 0.0 {
+        power.write_str("N/A")
     } else if is_apple_silicon {
         // Apple Silicon GPU uses very little power, show 2 decimal places
         // Use fixed width formatting to prevent trailing characters
-        format!("{:5.2}W", info.power_consumption)
-    } else if let Some(power_max_str) = info.detail.get("power_limit_max") {
-        if let Ok(power_max) = power_max_str.parse::<f64>() {
-            format!("{:.0}/{power_max:.0}W", info.power_consumption)
-        } else {
-            format!("{:.0}W", info.power_consumption)
-        }
+        write!(power, "{:5.2}W", info.power_consumption)
+    } else if let Some(power_max) = power_max {
+        write!(power, "{:.0}/{power_max:.0}W", info.power_consumption)
     } else {
-        format!("{:.0}W", info.power_consumption)
+        write!(power, "{:.0}W", info.power_consumption)
     };
 
-    // Dynamically adjust width based on content, with minimum of 8 chars
-    let display_width = power_display.len().max(8);
-    print_colored_text(
-        stdout,
-        &format!("{power_display:>display_width$}"),
-        Color::White,
-        None,
-        None,
-    );
+    // Right-aligned in at least 8 columns, wider when the content needs it
+    print_colored_fmt(stdout, format_args!("{power:>8}"), value_color);
+
+    // Drawing past the enforced limit: the driver is capping the device
+    if power_headroom(info).is_some_and(|headroom| headroom.is_capped()) {
+        print_colored_text(stdout, " CAPPED", theme.critical, None, None);
+    }
+
+    // Fan speeds, only on actively cooled cards
+    if let Some(fans) = fan_speed_summary(&info.detail) {
+        print_colored_text(stdout, " FAN:", theme.label_thermal, None, None);
+        print_colored_text(stdout, &fans, value_color, None, None);
+    }
+
+    // Video engines, only while one of them is busy
+    if let Some((encoder, decoder)) = video_engine_utilization(info) {
+        print_colored_text(stdout, " ENC:", theme.title, None, None);
+        print_colored_fmt(stdout, format_args!("{encoder:.0}%"), value_color);
+        print_colored_text(stdout, " DEC:", theme.title, None, None);
+        print_colored_fmt(stdout, format_args!("{decoder:.0}%"), value_color);
+    }
 
     // Display HLO Queue Size for TPU devices (show 0 if not available)
     if info.device_type == "TPU" {
@@ -198,71 +287,132 @@ pub fn print_gpu_info<W: Write>(
             .get("HLO Queue Size")
             .map(|s| s.as_str())
             .unwrap_or("0");
-        print_colored_text(stdout, " HLO Q:", Color::Cyan, None, None);
+        print_colored_text(stdout, " HLO Q:", theme.title, None, None);
         print_colored_text(
             stdout,
             &format!("{hlo_queue_size:>3}"),
-            Color::White,
+            theme.text,
             None,
             None,
         );
     }
 
+    // Per-neural-engine utilization (Rebellions drivers that report it)
+    let engines = neural_engine_utilization(&info.detail);
+    if !engines.is_empty() {
+        let breakdown = engines
+            .iter()
+            .map(|(_, util)| format!("{util:.0}"))
+            .collect::<Vec<_>>()
+            .join("/");
+        print_colored_text(stdout, " NE:", theme.title, None, None);
+        print_colored_text(stdout, &format!("{breakdown}%"), value_color, None, None);
+    }
+
+    // NUMA node is only reported on multi-node systems
+    if let Some(numa_node) = info.detail.get("numa_node") {
+        print_colored_text(stdout, " NUMA:", theme.title, None, None);
+        print_colored_text(stdout, numa_node, value_color, None, None);
+    }
+
+    // Root port the GPU hangs off; GPUs sharing one talk without the CPU
+    if let Some(root_port) = info
+        .detail
+        .get(PCIE_PATH_KEY)
+        .and_then(|path| pcie_root_port(path))
+    {
+        print_colored_text(stdout, " Root:", theme.title, None, None);
+        print_colored_text(stdout, root_port, value_color, None, None);
+    }
+
+    let nvlink_peers = info
+        .detail
+        .get(PEER_TOPOLOGY_KEY)
+        .map_or(0, |peers| nvlink_peer_count(peers));
+    if nvlink_peers > 0 {
+        print_colored_text(stdout, " NVLink:", theme.title, None, None);
+        print_colored_text(stdout, &nvlink_peers.to_string(), value_color, None, None);
+    }
+
+    // Kubernetes allocation is only known with --k8s
+    if let Some(allocated) = info.detail.get(K8S_ALLOCATED_KEY) {
+        let (label, color) = if allocated == "true" {
+            ("alloc", theme.warning)
+        } else {
+            ("free", theme.ok)
+        };
+        print_colored_text(stdout, " K8s:", theme.title, None, None);
+        print_colored_text(stdout, label, color, None, None);
+    }
+
     // Display driver version if available
     if let Some(driver_version) = info.detail.get("Driver Version") {
-        print_colored_text(stdout, " Drv:", Color::Green, None, None);
-        print_colored_text(stdout, driver_version, Color::White, None, None);
+        print_colored_text(stdout, " Drv:", theme.label_info, None, None);
+        print_colored_text(stdout, driver_version, value_color, None, None);
     }
 
     // Display AI library name and version using unified fields
     // Falls back to platform-specific fields for backward compatibility
     if let Some(lib_name) = info.detail.get("lib_name") {
         if let Some(lib_version) = info.detail.get("lib_version") {
-            print_colored_text(stdout, &format!(" {lib_name}:"), Color::Green, None, None);
-            print_colored_text(stdout, lib_version, Color::White, None, None);
+            print_colored_text(
+                stdout,
+                &format!(" {lib_name}:"),
+                theme.label_info,
+                None,
+                None,
+            );
+            print_colored_text(stdout, lib_version, value_color, None, None);
         }
     } else {
         // Backward compatibility: try platform-specific fields
         if let Some(cuda_version) = info.detail.get("CUDA Version") {
-            print_colored_text(stdout, " CUDA:", Color::Green, None, None);
-            print_colored_text(stdout, cuda_version, Color::White, None, None);
+            print_colored_text(stdout, " CUDA:", theme.label_info, None, None);
+            print_colored_text(stdout, cuda_version, value_color, None, None);
         } else if let Some(rocm_version) = info.detail.get("ROCm Version") {
-            print_colored_text(stdout, " ROCm:", Color::Green, None, None);
-            print_colored_text(stdout, rocm_version, Color::White, None, None);
+            print_colored_text(stdout, " ROCm:", theme.label_info, None, None);
+            print_colored_text(stdout, rocm_version, value_color, None, None);
         }
     }
 
+    if marks.stale {
+        print_colored_text(stdout, " STALE", theme.dim, None, None);
+    }
+
     queue!(stdout, Print("\r\n")).unwrap();
 
     // Calculate gauge widths with 5 char padding on each side and 2 space separation
-    let available_width = width.saturating_sub(10); // 5 padding each side
-    let is_apple_silicon = info.name.contains("Apple") || info.name.contains("Metal");
     let has_tensorcore = info.device_type == "TPU" && info.tensorcore_utilization.is_some();
     let num_gauges = if is_apple_silicon || has_tensorcore {
         3
     } else {
         2
     }; // Util, Mem, (ANE for Apple Silicon, TensorCore for TPU)
-    let gauge_width = (available_width - (num_gauges - 1) * 2) / num_gauges; // 2 spaces between gauges
-
-    // Calculate actual space used and dynamic right padding
-    let total_gauge_width = gauge_width * num_gauges + (num_gauges - 1) * 2;
-    let left_padding = 5;
-    let right_padding = width - left_padding - total_gauge_width;
+    let (gauge_width, right_padding) = gauge_row_widths(width, num_gauges);
 
     // Print gauges on one line with proper spacing
-    print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+    print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
 
-    // Util gauge
-    draw_bar(
-        stdout,
-        "Util",
-        info.utilization,
-        100.0,
-        gauge_width,
-        Some(format!("{:.1}%", info.utilization)),
-    );
-    print_colored_text(stdout, "  ", Color::White, None, None); // 2 space separator
+    // Util gauge, marked with `~` when smoothed
+    match marks.smoothed_utilization {
+        Some(smoothed) => draw_bar(
+            stdout,
+            "Util",
+            smoothed,
+            100.0,
+            gauge_width,
+            Some(format_args!("~{smoothed:.1}%")),
+        ),
+        None => draw_bar(
+            stdout,
+            "Util",
+            info.utilization,
+            100.0,
+            gauge_width,
+            Some(format_args!("{:.1}%", info.utilization)),
+        ),
+    }
+    print_colored_text(stdout, "  ", theme.text, None, None); // 2 space separator
 
     // Memory gauge
     draw_bar(
@@ -271,12 +421,12 @@ pub fn print_gpu_info<W: Write>(
         memory_percent,
         100.0,
         gauge_width,
-        Some(format!("{memory_gb:.1}GB")),
+        Some(format_args!("{}", format_bytes(info.used_memory))),
     );
 
     // ANE gauge only for Apple Silicon (in Watts)
     if is_apple_silicon {
-        print_colored_text(stdout, "  ", Color::White, None, None); // 2 space separator
+        print_colored_text(stdout, "  ", theme.text, None, None); // 2 space separator
 
         // Determine max ANE power based on die count (Ultra = 2 dies = 12W, others = 6W)
         let is_ultra = info.name.contains("Ultra");
@@ -292,13 +442,13 @@ pub fn print_gpu_info<W: Write>(
             ane_percent,
             100.0,
             gauge_width,
-            Some(format!("{ane_power_w:.1}W")),
+            Some(format_args!("{ane_power_w:.1}W")),
         );
     }
 
     // TensorCore gauge for TPU
     if has_tensorcore {
-        print_colored_text(stdout, "  ", Color::White, None, None); // 2 space separator
+        print_colored_text(stdout, "  ", theme.text, None, None); // 2 space separator
 
         let tc_util = info.tensorcore_utilization.unwrap_or(0.0);
         draw_bar(
@@ -307,14 +457,35 @@ pub fn print_gpu_info<W: Write>(
             tc_util,
             100.0,
             gauge_width,
-            Some(format!("{tc_util:.1}%")),
+            Some(format_args!("{tc_util:.1}%")),
         );
     }
 
-    print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None); // dynamic right padding
+    print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None); // dynamic right padding
     queue!(stdout, Print("\r\n")).unwrap();
 }
 
+/// Encoder and decoder utilization, `None` while both are idle or the
+/// card has no video engines
+fn video_engine_utilization(info: &GpuInfo) -> Option<(f64, f64)> {
+    let encoder = info.encoder_utilization.unwrap_or(0.0);
+    let decoder = info.decoder_utilization.unwrap_or(0.0);
+    (encoder > 0.0 || decoder > 0.0).then_some((encoder, decoder))
+}
+
+/// First port below the root complex in a `pcie_path`
+fn pcie_root_port(path: &str) -> Option<&str> {
+    path.split('/').nth(1)
+}
+
+/// Peers reached over NVLink in a `gpu_peer_topology` list
+fn nvlink_peer_count(peers: &str) -> usize {
+    peers
+        .split(',')
+        .filter(|peer| peer.ends_with(":NV"))
+        .count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,10 +514,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multibyte_hostname_scrolls_by_characters() {
+        // 8 characters in 20 bytes: padded, not scrolled
+        assert_eq!(
+            format_hostname_with_scroll("서버-노드-01", 0),
+            "서버-노드-01 "
+        );
+
+        let hostname = "データセンター-ラック-07";
+        let len = hostname.chars().count();
+        for offset in 0..2 * (len + 3) {
+            assert_eq!(
+                format_hostname_with_scroll(hostname, offset)
+                    .chars()
+                    .count(),
+                9
+            );
+        }
+        assert_eq!(
+            format_hostname_with_scroll(hostname, 0),
+            "データセンター-ラ"
+        );
+        assert_eq!(
+            format_hostname_with_scroll(hostname, len),
+            "   データセンタ"
+        );
+        assert_eq!(
+            format_hostname_with_scroll(hostname, len + 3),
+            format_hostname_with_scroll(hostname, 0)
+        );
+    }
+
+    #[test]
+    fn test_multibyte_device_and_host_names_render_at_every_offset() {
+        let info = GpuInfo {
+            uuid: "GPU-1".to_string(),
+            name: "Ñvidiá Tésla Ü100 – 80GB".to_string(),
+            device_type: "GPU".to_string(),
+            host_id: "10.0.0.1:9090".to_string(),
+            hostname: "гпу-узел-ноль-один".to_string(),
+            instance: "гпу-узел-ноль-один".to_string(),
+            utilization: 35.0,
+            temperature: 50,
+            used_memory: 1 << 30,
+            total_memory: 80 << 30,
+            frequency: 1500,
+            power_consumption: 300.0,
+            ..Default::default()
+        };
+        for offset in 0..40 {
+            let mut out = Vec::new();
+            print_gpu_info(
+                &mut out,
+                GpuRowMarks::default(),
+                &info,
+                None,
+                160,
+                offset,
+                offset,
+            );
+            let line = String::from_utf8(out).unwrap();
+            let visible: String = "гпу-узел-ноль-один   гпу-узел-ноль-один"
+                .chars()
+                .skip(offset % 21)
+                .take(9)
+                .collect();
+            assert!(line.contains(&visible), "offset {offset}: {line}");
+        }
+    }
+
     #[test]
     fn test_gpu_renderer_new() {
         let renderer = GpuRenderer::new();
         // Just verify it can be created
         let _ = renderer;
     }
+
+    #[test]
+    fn test_video_engines_shown_only_while_busy() {
+        let mut info = GpuInfo {
+            uuid: "GPU-1".to_string(),
+            name: "NVIDIA L4".to_string(),
+            device_type: "GPU".to_string(),
+            host_id: "node1".to_string(),
+            hostname: "node1".to_string(),
+            instance: "node1".to_string(),
+            utilization: 35.0,
+            encoder_utilization: Some(0.0),
+            decoder_utilization: Some(0.0),
+            temperature: 50,
+            used_memory: 1 << 30,
+            total_memory: 24 << 30,
+            frequency: 1500,
+            power_consumption: 40.0,
+            ..Default::default()
+        };
+        let render = |info: &GpuInfo| {
+            let mut out = Vec::new();
+            print_gpu_info(&mut out, GpuRowMarks::default(), info, None, 160, 0, 0);
+            String::from_utf8(out).unwrap()
+        };
+
+        assert!(!render(&info).contains("ENC:"));
+        info.encoder_utilization = None;
+        info.decoder_utilization = None;
+        assert!(!render(&info).contains("DEC:"));
+
+        info.decoder_utilization = Some(87.0);
+        let line = render(&info);
+        assert!(line.contains(" ENC:"));
+        assert!(line.contains(" DEC:"));
+        assert!(line.contains("87%"));
+    }
+
+    #[test]
+    fn test_topology_summary() {
+        assert_eq!(
+            pcie_root_port("pci0000:16/0000:16:01.0/0000:17:00.0/0000:18:00.0"),
+            Some("0000:16:01.0")
+        );
+        assert_eq!(pcie_root_port("pci0000:16"), None);
+        assert_eq!(nvlink_peer_count("GPU1:NV,GPU2:SYS,GPU3:NV"), 2);
+        assert_eq!(nvlink_peer_count("GPU1:PIX"), 0);
+    }
 }
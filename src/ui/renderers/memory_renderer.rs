@@ -14,11 +14,17 @@
 
 use std::io::Write;
 
-use crossterm::{queue, style::Color, style::Print};
+use crossterm::{queue, style::Print};
 
+use crate::device::memory_pressure;
 use crate::device::MemoryInfo;
+use crate::ui::renderers::widgets::gauges::gauge_row_widths;
 use crate::ui::text::print_colored_text;
+use crate::ui::theme;
 use crate::ui::widgets::{draw_bar_multi, BarSegment};
+use crate::utils::format_bytes;
+
+use super::gpu_renderer::format_hostname_with_scroll;
 
 /// Memory renderer struct implementing the DeviceRenderer trait
 #[allow(dead_code)]
@@ -37,23 +43,6 @@ impl MemoryRenderer {
     }
 }
 
-/// Helper function to format hostname with scrolling
-fn format_hostname_with_scroll(hostname: &str, scroll_offset: usize) -> String {
-    if hostname.len() > 9 {
-        let scroll_len = hostname.len() + 3;
-        let start_pos = scroll_offset % scroll_len;
-        let extended_hostname = format!("{hostname}   {hostname}");
-        extended_hostname
-            .chars()
-            .skip(start_pos)
-            .take(9)
-            .collect::<String>()
-    } else {
-        // Always return 9 characters, left-aligned with space padding
-        format!("{hostname:<9}")
-    }
-}
-
 /// Render memory information including total, used, available, and utilization
 pub fn print_memory_info<W: Write>(
     stdout: &mut W,
@@ -62,62 +51,55 @@ pub fn print_memory_info<W: Write>(
     width: usize,
     hostname_scroll_offset: usize,
 ) {
-    // Convert bytes to GB for display
-    let total_gb = info.total_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-    let used_gb = info.used_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-    let available_gb = info.available_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-
+    let theme = theme::current();
     // Format hostname with scrolling if needed (same as GPU/CPU: 9 chars)
     let hostname_display = format_hostname_with_scroll(&info.hostname, hostname_scroll_offset);
 
     // Print Memory info line
-    print_colored_text(stdout, "Host Memory         ", Color::Cyan, None, None);
-    print_colored_text(stdout, " @ ", Color::DarkGreen, None, None);
-    print_colored_text(stdout, &hostname_display, Color::White, None, None);
-    print_colored_text(stdout, " Total:", Color::Green, None, None);
+    print_colored_text(stdout, "Host Memory         ", theme.title, None, None);
+    print_colored_text(stdout, " @ ", theme.host, None, None);
+    print_colored_text(stdout, &hostname_display, theme.text, None, None);
+    print_colored_text(stdout, " Total:", theme.label_info, None, None);
     print_colored_text(
         stdout,
-        &format!("{total_gb:>6.0}GB"),
-        Color::White,
+        &format!("{:>9}", format_bytes(info.total_bytes)),
+        theme.text,
         None,
         None,
     );
-    print_colored_text(stdout, " Used:", Color::Red, None, None);
+    print_colored_text(stdout, " Used:", theme.label_power, None, None);
     print_colored_text(
         stdout,
-        &format!("{used_gb:>6.1}GB"),
-        Color::White,
+        &format!("{:>9}", format_bytes(info.used_bytes)),
+        theme.text,
         None,
         None,
     );
-    print_colored_text(stdout, " Avail:", Color::Green, None, None);
+    print_colored_text(stdout, " Avail:", theme.label_info, None, None);
     print_colored_text(
         stdout,
-        &format!("{available_gb:>6.1}GB"),
-        Color::White,
+        &format!("{:>9}", format_bytes(info.available_bytes)),
+        theme.text,
         None,
         None,
     );
-    print_colored_text(stdout, " Util:", Color::Magenta, None, None);
+    print_colored_text(stdout, " Util:", theme.label_thermal, None, None);
     print_colored_text(
         stdout,
         &format!("{:>5.1}%", info.utilization),
-        Color::White,
+        theme.text,
         None,
         None,
     );
+    if info.stale {
+        print_colored_text(stdout, " STALE", theme.dim, None, None);
+    }
     queue!(stdout, Print("\r\n")).unwrap();
 
-    // Calculate gauge widths with 5 char padding on each side
-    let available_width = width.saturating_sub(10); // 5 padding each side
-    let gauge_width = available_width;
+    // Single gauge with 5 char padding on each side
+    let (gauge_width, right_padding) = gauge_row_widths(width, 1);
 
-    // Calculate actual space used and dynamic right padding
-    let total_gauge_width = gauge_width;
-    let left_padding = 5;
-    let right_padding = width - left_padding - total_gauge_width;
-
-    print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+    print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
 
     // Create segments for multi-bar display
     let mut segments = Vec::new();
@@ -126,13 +108,18 @@ pub fn print_memory_info<W: Write>(
     let actual_used_bytes = info
         .used_bytes
         .saturating_sub(info.buffers_bytes + info.cached_bytes);
+    // Segments are drawn in proportion to the total, so any scale works
     let actual_used_gb = actual_used_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
     let buffers_gb = info.buffers_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
     let cached_gb = info.cached_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+    let total_gb = info.total_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
 
     // Add used memory segment (actual used without buffers/cache)
     if actual_used_bytes > 0 {
-        segments.push(BarSegment::memory_used(actual_used_gb));
+        segments.push(BarSegment::memory_used(
+            actual_used_gb,
+            memory_pressure::pressure_level(info),
+        ));
     }
 
     // Add buffers segment
@@ -146,8 +133,8 @@ pub fn print_memory_info<W: Write>(
     }
 
     // Calculate total used memory for display text
-    let total_used_gb = actual_used_gb + buffers_gb + cached_gb;
-    let display_text = format!("{total_used_gb:.1}GB");
+    let total_used_bytes = actual_used_bytes + info.buffers_bytes + info.cached_bytes;
+    let display_text = format_bytes(total_used_bytes);
 
     // Draw the multi-segment bar
     draw_bar_multi(
@@ -159,6 +146,6 @@ pub fn print_memory_info<W: Write>(
         Some(display_text),
     );
 
-    print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None);
+    print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 }
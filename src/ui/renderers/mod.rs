@@ -22,7 +22,7 @@ pub mod widgets;
 // Re-export the main rendering functions for backward compatibility
 pub use chassis_renderer::print_chassis_info;
 pub use cpu_renderer::print_cpu_info;
-pub use gpu_renderer::print_gpu_info;
+pub use gpu_renderer::{print_gpu_info, GpuRowMarks};
 pub use memory_renderer::print_memory_info;
 pub use storage_renderer::print_storage_info;
 
@@ -14,11 +14,22 @@
 
 use std::io::Write;
 
-use crossterm::{queue, style::Color, style::Print};
+use crossterm::{queue, style::Print};
 
 use crate::storage::info::StorageInfo;
+use crate::ui::renderers::widgets::gauges::gauge_row_widths;
 use crate::ui::text::{print_colored_text, truncate_to_width};
+use crate::ui::theme;
 use crate::ui::widgets::draw_bar;
+use crate::utils::{format_bytes, temperature_unit};
+
+use super::gpu_renderer::format_hostname_with_scroll;
+
+/// Inode usage at which a mount is flagged, regardless of byte usage.
+const INODE_WARNING_PERCENT: f64 = 90.0;
+
+/// Drive temperature above which it is shown in red.
+const DISK_TEMP_WARNING_CELSIUS: u32 = 60;
 
 /// Storage renderer struct implementing the DeviceRenderer trait
 #[allow(dead_code)]
@@ -37,23 +48,6 @@ impl StorageRenderer {
     }
 }
 
-/// Helper function to format hostname with scrolling
-fn format_hostname_with_scroll(hostname: &str, scroll_offset: usize) -> String {
-    if hostname.len() > 9 {
-        let scroll_len = hostname.len() + 3;
-        let start_pos = scroll_offset % scroll_len;
-        let extended_hostname = format!("{hostname}   {hostname}");
-        extended_hostname
-            .chars()
-            .skip(start_pos)
-            .take(9)
-            .collect::<String>()
-    } else {
-        // Always return 9 characters, left-aligned with space padding
-        format!("{hostname:<9}")
-    }
-}
-
 /// Render storage information including mount point, total space, used space, and utilization
 pub fn print_storage_info<W: Write>(
     stdout: &mut W,
@@ -62,76 +56,86 @@ pub fn print_storage_info<W: Write>(
     width: usize,
     hostname_scroll_offset: usize,
 ) {
-    // Convert bytes to appropriate units
-    let total_gb = info.total_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-    let available_gb = info.available_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-    let used_gb = total_gb - available_gb;
+    let theme = theme::current();
+    let used_bytes = info.total_bytes.saturating_sub(info.available_bytes);
 
     // Calculate usage percentage
-    let usage_percent = if total_gb > 0.0 {
-        (used_gb / total_gb) * 100.0
+    let usage_percent = if info.total_bytes > 0 {
+        (used_bytes as f64 / info.total_bytes as f64) * 100.0
     } else {
         0.0
     };
 
-    // Format size with appropriate units
-    let format_size = |gb: f64| -> String {
-        if gb >= 1024.0 {
-            format!("{:.1}TB", gb / 1024.0)
-        } else {
-            format!("{gb:.0}GB")
-        }
-    };
-
     // Print Disk info line
-    print_colored_text(stdout, "Disk ", Color::Cyan, None, None);
+    print_colored_text(stdout, "Disk ", theme.title, None, None);
     print_colored_text(
         stdout,
         &format!("{:<15}", truncate_to_width(&info.mount_point, 15)),
-        Color::White,
+        theme.text,
         None,
         None,
     );
-    print_colored_text(stdout, " @ ", Color::DarkGreen, None, None);
+    print_colored_text(stdout, " @ ", theme.host, None, None);
     let hostname_display = format_hostname_with_scroll(&info.hostname, hostname_scroll_offset);
-    print_colored_text(stdout, &hostname_display, Color::White, None, None);
-    print_colored_text(stdout, " Total:", Color::Green, None, None);
+    print_colored_text(stdout, &hostname_display, theme.text, None, None);
+    print_colored_text(stdout, " Total:", theme.label_info, None, None);
     print_colored_text(
         stdout,
-        &format!("{:>8}", format_size(total_gb)),
-        Color::White,
+        &format!("{:>9}", format_bytes(info.total_bytes)),
+        theme.text,
         None,
         None,
     );
-    print_colored_text(stdout, " Used:", Color::Red, None, None);
+    print_colored_text(stdout, " Used:", theme.label_power, None, None);
     print_colored_text(
         stdout,
-        &format!("{:>8}", format_size(used_gb)),
-        Color::White,
+        &format!("{:>9}", format_bytes(used_bytes)),
+        theme.text,
         None,
         None,
     );
-    print_colored_text(stdout, " Util:", Color::Magenta, None, None);
+    print_colored_text(stdout, " Util:", theme.label_thermal, None, None);
     print_colored_text(
         stdout,
         &format!("{usage_percent:>5.1}%"),
-        Color::White,
+        theme.text,
         None,
         None,
     );
+    if let Some(inode_percent) = info
+        .inode_usage_percent()
+        .filter(|&percent| percent >= INODE_WARNING_PERCENT)
+    {
+        print_colored_text(stdout, " Inodes:", theme.label, None, None);
+        print_colored_text(
+            stdout,
+            &format!("{inode_percent:>5.1}%"),
+            theme.critical,
+            None,
+            None,
+        );
+    }
+    if let Some(temperature) = info.health.temperature_celsius {
+        let color = if temperature > DISK_TEMP_WARNING_CELSIUS {
+            theme.critical
+        } else {
+            theme.text
+        };
+        print_colored_text(stdout, " Temp:", theme.label, None, None);
+        let unit = temperature_unit();
+        let temperature = unit.convert(f64::from(temperature));
+        let text = format!("{temperature:>3.0}{}", unit.symbol());
+        print_colored_text(stdout, &text, color, None, None);
+    }
+    if info.health.health_ok == Some(false) {
+        print_colored_text(stdout, " SMART:FAIL", theme.critical, None, None);
+    }
     queue!(stdout, Print("\r\n")).unwrap();
 
-    // Calculate gauge widths with 5 char padding on each side
-    let available_width = width.saturating_sub(10); // 5 padding each side
+    // Single gauge with 5 char padding on each side
+    let (gauge_width, right_padding) = gauge_row_widths(width, 1);
 
-    let gauge_width = available_width;
-
-    // Calculate actual space used and dynamic right padding
-    let total_gauge_width = gauge_width;
-    let left_padding = 5;
-    let right_padding = width - left_padding - total_gauge_width;
-
-    print_colored_text(stdout, "     ", Color::White, None, None); // 5 char left padding
+    print_colored_text(stdout, "     ", theme.text, None, None); // 5 char left padding
 
     // Just Used gauge (matching the other lists format)
     draw_bar(
@@ -140,9 +144,47 @@ pub fn print_storage_info<W: Write>(
         usage_percent,
         100.0,
         gauge_width,
-        Some(format_size(used_gb)),
+        Some(format_args!("{}", format_bytes(used_bytes))),
     );
 
-    print_colored_text(stdout, &" ".repeat(right_padding), Color::White, None, None); // dynamic right padding
+    print_colored_text(stdout, &" ".repeat(right_padding), theme.text, None, None); // dynamic right padding
     queue!(stdout, Print("\r\n")).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::health::DiskHealth;
+
+    fn render(mount_point: &str, hostname: &str, offset: usize) -> String {
+        let info = StorageInfo {
+            mount_point: mount_point.to_string(),
+            total_bytes: 1 << 40,
+            available_bytes: 1 << 39,
+            host_id: "10.0.0.1:9090".to_string(),
+            hostname: hostname.to_string(),
+            index: 0,
+            total_inodes: None,
+            free_inodes: None,
+            health: DiskHealth::default(),
+        };
+        let mut out = Vec::new();
+        print_storage_info(&mut out, 0, &info, 120, offset);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_multibyte_mount_point_and_hostname() {
+        // Cut after 15 characters, which is byte 17 here
+        let mount_point = "/mnt/données/équipe-α";
+        for offset in 0..30 {
+            let line = render(mount_point, "서버-노드-영-일-이", offset);
+            assert!(line.contains("/mnt/données/éq"), "{line}");
+            assert!(!line.contains("/mnt/données/équ"), "{line}");
+        }
+
+        let line = render("/데이터", "서버", 0);
+        assert!(line.contains(&format!("{:<15}", "/데이터")), "{line}");
+        assert!(line.contains(&format!("{:<9}", "서버")), "{line}");
+    }
+}
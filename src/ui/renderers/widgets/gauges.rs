@@ -17,24 +17,45 @@ use std::io::Write;
 use crossterm::style::Color;
 
 // Re-export the draw_bar function from the main widgets module
+use crate::ui::theme;
 pub use crate::ui::widgets::draw_bar;
 
 /// Get utilization block character and color based on usage percentage
 pub fn get_utilization_block(utilization: f64) -> (&'static str, Color) {
+    let theme = theme::current();
     match utilization {
-        u if u >= 90.0 => ("█", Color::Red), // Full block, red for high usage
-        u if u >= 80.0 => ("▇", Color::Magenta), // 7/8 block
-        u if u >= 70.0 => ("▆", Color::Yellow), // 6/8 block
-        u if u >= 60.0 => ("▅", Color::Yellow), // 5/8 block
-        u if u >= 50.0 => ("▄", Color::Green), // 4/8 block
-        u if u >= 40.0 => ("▃", Color::Green), // 3/8 block
-        u if u >= 30.0 => ("▂", Color::Cyan), // 2/8 block
-        u if u >= 20.0 => ("▁", Color::Cyan), // 1/8 block
-        u if u >= 10.0 => ("▁", Color::Blue), // Low usage
-        _ => ("▁", Color::DarkGrey),         // Minimal or no usage (still show lowest bar)
+        u if u >= 90.0 => ("█", theme.critical), // Full block for high usage
+        u if u >= 80.0 => ("▇", theme.hot),      // 7/8 block
+        u if u >= 70.0 => ("▆", theme.warning),  // 6/8 block
+        u if u >= 60.0 => ("▅", theme.warning),  // 5/8 block
+        u if u >= 50.0 => ("▄", theme.ok),       // 4/8 block
+        u if u >= 40.0 => ("▃", theme.ok),       // 3/8 block
+        u if u >= 30.0 => ("▂", theme.cool),     // 2/8 block
+        u if u >= 20.0 => ("▁", theme.cool),     // 1/8 block
+        u if u >= 10.0 => ("▁", theme.cold),     // Low usage
+        _ => ("▁", theme.dim),                   // Minimal or no usage (still show lowest bar)
     }
 }
 
+/// Left padding before a row of gauges; the same margin is kept on the right
+pub const GAUGE_ROW_PADDING: usize = 5;
+/// Spaces between adjacent gauges in a row
+pub const GAUGE_SPACING: usize = 2;
+
+/// Width of each gauge and the right padding needed to clear the line when
+/// `count` gauges share a row of `width` columns. Never underflows, so tiny or
+/// zero widths yield zero-width gauges instead of a panic.
+pub fn gauge_row_widths(width: usize, count: usize) -> (usize, usize) {
+    let count = count.max(1);
+    let spacing = (count - 1) * GAUGE_SPACING;
+    let gauge_width = width
+        .saturating_sub(GAUGE_ROW_PADDING * 2)
+        .saturating_sub(spacing)
+        / count;
+    let used = GAUGE_ROW_PADDING + gauge_width * count + spacing;
+    (gauge_width, width.saturating_sub(used))
+}
+
 /// Helper function to render a simple gauge bar
 #[allow(dead_code)]
 pub fn render_gauge<W: Write>(
@@ -44,21 +65,11 @@ pub fn render_gauge<W: Write>(
     max_value: f64,
     width: usize,
     _label_color: Color,
-    show_text: Option<String>,
+    show_text: Option<std::fmt::Arguments<'_>>,
 ) {
     draw_bar(stdout, label, value, max_value, width, show_text);
 }
 
-/// Gauge style constants
-#[allow(dead_code)]
-pub const GAUGE_HIGH_COLOR: Color = Color::Red;
-#[allow(dead_code)]
-pub const GAUGE_MEDIUM_COLOR: Color = Color::Yellow;
-#[allow(dead_code)]
-pub const GAUGE_LOW_COLOR: Color = Color::Green;
-#[allow(dead_code)]
-pub const GAUGE_MINIMAL_COLOR: Color = Color::Blue;
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,11 +125,27 @@ mod tests {
             100.0,
             20,
             Color::Blue,
-            Some("50%".to_string()),
+            Some(format_args!("50%")),
         );
 
         // Just verify it doesn't panic
         let output = String::from_utf8(buffer.into_inner()).unwrap();
         assert!(output.contains("Test"));
     }
+
+    #[test]
+    fn test_gauge_row_widths() {
+        // Regular widths fill the line exactly
+        assert_eq!(gauge_row_widths(120, 1), (110, 5));
+        assert_eq!(gauge_row_widths(120, 2), (54, 5));
+        assert_eq!(gauge_row_widths(121, 3), (35, 7));
+
+        // Pathological widths never underflow
+        assert_eq!(gauge_row_widths(0, 1), (0, 0));
+        assert_eq!(gauge_row_widths(0, 3), (0, 0));
+        assert_eq!(gauge_row_widths(1, 2), (0, 0));
+        assert_eq!(gauge_row_widths(20, 1), (10, 5));
+        assert_eq!(gauge_row_widths(20, 3), (2, 5));
+        assert_eq!(gauge_row_widths(20, 0), (10, 5));
+    }
 }
@@ -17,6 +17,7 @@ use std::io::Write;
 use crossterm::style::Color;
 
 use crate::ui::text::print_colored_text;
+use crate::ui::theme;
 
 /// A key-value pair for table rendering
 #[allow(dead_code)]
@@ -30,11 +31,12 @@ pub struct TableRow {
 #[allow(dead_code)]
 impl TableRow {
     pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        let theme = theme::current();
         Self {
             label: label.into(),
             value: value.into(),
-            label_color: Color::Yellow,
-            value_color: Color::White,
+            label_color: theme.label,
+            value_color: theme.text,
         }
     }
 
@@ -57,13 +59,14 @@ pub fn render_info_table<W: Write>(stdout: &mut W, rows: &[TableRow]) {
 /// Render a bordered box with title
 #[allow(dead_code)]
 pub fn render_bordered_box<W: Write>(stdout: &mut W, title: &str, width: usize, color: Color) {
+    let theme = theme::current();
     // Draw top border
     let title_with_spaces_len = 1 + title.len() + 1; // " " + title + " "
 
     print_colored_text(stdout, "╭─", color, None, None);
-    print_colored_text(stdout, " ", Color::White, None, None);
+    print_colored_text(stdout, " ", theme.text, None, None);
     print_colored_text(stdout, title, color, None, None);
-    print_colored_text(stdout, " ", Color::White, None, None);
+    print_colored_text(stdout, " ", theme.text, None, None);
 
     // Fill the rest with dashes
     let remaining_dashes = width.saturating_sub(title_with_spaces_len + 1);
@@ -83,14 +86,6 @@ pub fn close_bordered_box<W: Write>(stdout: &mut W, width: usize, color: Color)
     print_colored_text(stdout, "╯", color, None, None);
 }
 
-/// Table style constants
-#[allow(dead_code)]
-pub const TABLE_LABEL_COLOR: Color = Color::Yellow;
-#[allow(dead_code)]
-pub const TABLE_VALUE_COLOR: Color = Color::White;
-#[allow(dead_code)]
-pub const TABLE_BORDER_COLOR: Color = Color::Cyan;
-
 #[cfg(test)]
 mod tests {
     use super::*;
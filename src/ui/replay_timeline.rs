@@ -0,0 +1,153 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Playback position of a replayed recording, moved by the replay loop and
+//! by the playback keys.
+
+use std::time::Duration;
+
+/// Playback rates `[` and `]` step through
+const SPEEDS: [f64; 8] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayTimeline {
+    /// Index of the snapshot to show
+    pub position: usize,
+    /// Number of snapshots in the recording
+    pub len: usize,
+    pub paused: bool,
+    /// Playback rate; 2 plays twice as fast as recorded
+    pub speed: f64,
+    /// Recorded time of the snapshot on screen, seconds since the Unix epoch
+    pub shown_at: Option<f64>,
+}
+
+impl ReplayTimeline {
+    /// `len` must not be zero.
+    pub fn new(len: usize, speed: f64) -> Self {
+        Self {
+            position: 0,
+            len,
+            paused: false,
+            speed,
+            shown_at: None,
+        }
+    }
+
+    /// Move on to the next snapshot, starting over after the last one.
+    pub fn advance(&mut self) {
+        self.position = (self.position + 1) % self.len;
+    }
+
+    /// Step `delta` snapshots forward or back, stopping at either end, and
+    /// pause so the snapshot stays on screen.
+    pub fn step(&mut self, delta: isize) {
+        self.position = self.position.saturating_add_signed(delta).min(self.len - 1);
+        self.paused = true;
+    }
+
+    /// The next preset rate above the current one.
+    pub fn faster(&mut self) {
+        if let Some(&speed) = SPEEDS.iter().find(|&&speed| speed > self.speed) {
+            self.speed = speed;
+        }
+    }
+
+    /// The next preset rate below the current one.
+    pub fn slower(&mut self) {
+        if let Some(&speed) = SPEEDS.iter().rev().find(|&&speed| speed < self.speed) {
+            self.speed = speed;
+        }
+    }
+
+    /// How long a recorded gap lasts at the current rate.
+    pub fn scale(&self, recorded: Duration) -> Duration {
+        recorded.div_f64(self.speed)
+    }
+
+    /// Header text such as `REPLAY > 2x [######----] 45/340`, with a bar
+    /// `bar_width` cells wide.
+    pub fn label(&self, bar_width: usize) -> String {
+        let state = if self.paused { "||" } else { ">" };
+        let filled = if self.len > 1 {
+            (self.position * bar_width + (self.len - 1) / 2) / (self.len - 1)
+        } else {
+            bar_width
+        };
+        format!(
+            "REPLAY {state} {}x [{}{}] {}/{}",
+            self.speed,
+            "#".repeat(filled),
+            "-".repeat(bar_width - filled),
+            self.position + 1,
+            self.len,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_wraps_and_step_clamps() {
+        let mut timeline = ReplayTimeline::new(3, 1.0);
+        timeline.advance();
+        timeline.advance();
+        assert_eq!(timeline.position, 2);
+        timeline.advance();
+        assert_eq!(timeline.position, 0);
+
+        timeline.step(-1);
+        assert_eq!(timeline.position, 0);
+        assert!(timeline.paused);
+        timeline.step(10);
+        assert_eq!(timeline.position, 2);
+    }
+
+    #[test]
+    fn test_speed_presets_and_scaling() {
+        let mut timeline = ReplayTimeline::new(2, 1.5);
+        timeline.faster();
+        assert_eq!(timeline.speed, 2.0);
+        assert_eq!(
+            timeline.scale(Duration::from_secs(3)),
+            Duration::from_millis(1500)
+        );
+        timeline.slower();
+        timeline.slower();
+        assert_eq!(timeline.speed, 0.5);
+
+        timeline.speed = 32.0;
+        timeline.faster();
+        assert_eq!(timeline.speed, 32.0);
+        timeline.speed = 0.25;
+        timeline.slower();
+        assert_eq!(timeline.speed, 0.25);
+    }
+
+    #[test]
+    fn test_label_tracks_position() {
+        let mut timeline = ReplayTimeline::new(5, 2.0);
+        assert_eq!(timeline.label(8), "REPLAY > 2x [--------] 1/5");
+        timeline.step(2);
+        assert_eq!(timeline.label(8), "REPLAY || 2x [####----] 3/5");
+        timeline.step(2);
+        assert_eq!(timeline.label(8), "REPLAY || 2x [########] 5/5");
+        assert_eq!(
+            ReplayTimeline::new(1, 0.5).label(4),
+            "REPLAY > 0.5x [####] 1/1"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exponential moving average of GPU utilization for the `--smooth` gauges.
+
+use std::collections::HashMap;
+
+use crate::device::GpuInfo;
+
+/// Smoothed utilization per device UUID
+#[derive(Clone, Debug)]
+pub struct UtilizationEma {
+    /// Weight of the newest reading, in (0, 1]
+    alpha: f64,
+    values: HashMap<String, f64>,
+}
+
+impl UtilizationEma {
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha,
+            values: HashMap::new(),
+        }
+    }
+
+    /// Fold the latest readings in. A device's first reading is taken as is;
+    /// devices that disappear or report no utilization start over.
+    pub fn update(&mut self, gpus: &[GpuInfo]) {
+        let mut values = HashMap::with_capacity(gpus.len());
+        for gpu in gpus.iter().filter(|gpu| gpu.utilization >= 0.0) {
+            let smoothed = match self.values.get(&gpu.uuid) {
+                Some(previous) => previous + self.alpha * (gpu.utilization - previous),
+                None => gpu.utilization,
+            };
+            values.insert(gpu.uuid.clone(), smoothed);
+        }
+        self.values = values;
+    }
+
+    pub fn get(&self, uuid: &str) -> Option<f64> {
+        self.values.get(uuid).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ema_follows_bursts_slowly() {
+        let mut ema = UtilizationEma::new(0.5);
+        ema.update(&[
+            GpuInfo {
+                utilization: 100.0,
+                ..GpuInfo::fixture("GPU-a", "node1")
+            },
+            GpuInfo {
+                utilization: -1.0,
+                ..GpuInfo::fixture("GPU-b", "node1")
+            },
+        ]);
+        assert_eq!(ema.get("GPU-a"), Some(100.0));
+        assert_eq!(ema.get("GPU-b"), None);
+
+        ema.update(&[GpuInfo::fixture("GPU-a", "node1")]);
+        assert_eq!(ema.get("GPU-a"), Some(50.0));
+        ema.update(&[GpuInfo {
+            utilization: 100.0,
+            ..GpuInfo::fixture("GPU-a", "node1")
+        }]);
+        assert_eq!(ema.get("GPU-a"), Some(75.0));
+
+        // A device that went away starts over from its next reading
+        ema.update(&[]);
+        ema.update(&[GpuInfo {
+            utilization: 10.0,
+            ..GpuInfo::fixture("GPU-a", "node1")
+        }]);
+        assert_eq!(ema.get("GPU-a"), Some(10.0));
+    }
+}
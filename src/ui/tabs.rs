@@ -18,12 +18,62 @@ use crossterm::{
 };
 use std::io::Write;
 
-use crate::app_state::AppState;
+use crate::app_state::{AppState, HostInfo};
+use crate::device::driver_versions::VersionAudit;
 use crate::ui::text::print_colored_text;
+use crate::ui::theme;
 
-pub fn draw_tabs<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
-    // Print tabs
-    let mut labels: Vec<(String, Color)> = Vec::new();
+/// Width of the "Tabs: " prefix printed before the first tab label
+const TABS_PREFIX_WIDTH: u16 = 6;
+
+/// Screen region occupied by a rendered tab label, used for mouse hit-testing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabRegion {
+    pub start_col: u16,
+    pub end_col: u16,
+    pub tab_index: usize,
+}
+
+/// Appended to the tab of a host whose driver or CUDA version differs from
+/// the rest of the cluster
+const VERSION_MISMATCH_MARKER: &str = "*";
+
+/// Appended to the tab of a host marked for maintenance
+const DRAINING_MARKER: &str = " DRAINING";
+
+/// Whether the node on `tab` is marked for maintenance. A remote node says
+/// so through `all_smi_node_draining`; the local one is checked directly.
+fn is_draining(state: &AppState, tab: &str) -> bool {
+    if state.is_local_mode {
+        return state.runtime_environment.draining().is_some();
+    }
+    state
+        .chassis_info
+        .iter()
+        .any(|chassis| chassis.draining && chassis.host_id == tab)
+}
+
+/// Foreground and optional background of a tab label
+type TabStyle = (Color, Option<Color>);
+
+/// Name shown on a node's tab: the hostname it reports, or for a hostfile
+/// bond its name and endpoint count, such as `nodeA[2]`
+fn tab_display_name(state: &AppState, tab: &str) -> String {
+    if let Some(info) = state.host_info.get(tab).filter(|info| info.bonded) {
+        return format!("{tab}[{}]", info.addresses.len());
+    }
+    state
+        .connection_status
+        .get(tab)
+        .and_then(|status| status.actual_hostname.clone())
+        .unwrap_or_else(|| tab.to_string())
+}
+
+/// Compute the tabs that fit on screen as (tab index, label, style)
+fn visible_tabs(state: &AppState, cols: u16) -> Vec<(usize, String, TabStyle)> {
+    let theme = theme::current();
+    let selected = (theme.tab_fg, Some(theme.tab_bg));
+    let mut labels: Vec<(usize, String, TabStyle)> = Vec::new();
 
     // Calculate available width for tabs
     // Reserve space for "Tabs: " prefix (6 chars) plus some padding
@@ -36,36 +86,51 @@ pub fn draw_tabs<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
 
         if available_width >= tab_width {
             if state.current_tab == 0 {
-                labels.push((format!(" {all_tab} "), Color::Black));
+                labels.push((0, format!(" {all_tab} "), selected));
             } else {
-                labels.push((format!(" {all_tab} "), Color::White));
+                labels.push((0, format!(" {all_tab} "), (theme.text, None)));
             }
             available_width -= tab_width;
         }
     }
 
-    // Show node tabs starting from scroll offset (skip "All" tab at index 0)
-    let node_tabs: Vec<_> = state
-        .tabs
-        .iter()
-        .enumerate()
-        .skip(1) // Skip "All" tab
-        .skip(state.tab_scroll_offset)
-        .collect();
+    // Show node tabs starting from scroll offset (skip "All" tab at index 0).
+    // While a host search is being typed only the matching tabs are shown.
+    let node_tabs: Vec<_> = match state.active_host_filter() {
+        Some(query) => state
+            .tabs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, tab)| state.host_matches(tab, query))
+            .collect(),
+        None => state
+            .tabs
+            .iter()
+            .enumerate()
+            .skip(1) // Skip "All" tab
+            .skip(state.tab_scroll_offset)
+            .collect(),
+    };
+
+    // Hosts left behind on another driver or CUDA version get a marker
+    let audit = VersionAudit::new(&state.gpu_info);
+    let deviating = audit.deviating_hosts();
 
     for (i, tab) in node_tabs {
         // Get display name (instance name) while keeping tab as the key
-        let display_name = if tab == "All" {
+        let mut display_name = if tab == "All" {
             tab.to_string()
-        } else if let Some(connection_status) = state.connection_status.get(tab) {
-            connection_status
-                .actual_hostname
-                .as_ref()
-                .unwrap_or(tab)
-                .clone()
         } else {
-            tab.to_string()
+            tab_display_name(state, tab)
         };
+        if deviating.contains(tab.as_str()) {
+            display_name.push_str(VERSION_MISMATCH_MARKER);
+        }
+        let draining = tab != "All" && is_draining(state, tab);
+        if draining {
+            display_name.push_str(DRAINING_MARKER);
+        }
 
         let tab_width = display_name.len() as u16 + 2; // Display name + 2 spaces padding
         if available_width < tab_width {
@@ -73,46 +138,118 @@ pub fn draw_tabs<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
         }
 
         // Determine color based on connection status and selection
-        let color = if state.current_tab == i {
-            Color::Black // Selected tab (will get blue background)
+        let style = if state.current_tab == i {
+            selected
         } else {
             // Check if this tab represents a disconnected node
             let is_connected = if tab != "All" {
-                state
-                    .connection_status
-                    .get(tab)
-                    .map(|status| status.is_connected)
-                    .unwrap_or(true) // Default to connected for local mode
+                state.is_host_connected(tab).unwrap_or(true) // Default to connected for local mode
             } else {
                 true // "All" tab is always "connected"
             };
 
-            if is_connected {
-                Color::White // Connected: normal white text
+            if !is_connected {
+                (theme.dim, None) // Disconnected: dimmed text
+            } else if draining {
+                (theme.warning, None) // Draining: stands out until back in service
             } else {
-                Color::DarkGrey // Disconnected: dimmed grey text
+                (theme.text, None) // Connected: normal text
             }
         };
 
-        labels.push((format!(" {display_name} "), color));
+        labels.push((i, format!(" {display_name} "), style));
 
         available_width -= tab_width;
     }
 
+    labels
+}
+
+pub fn draw_tabs<W: Write>(stdout: &mut W, state: &AppState, cols: u16) {
+    let labels = visible_tabs(state, cols)
+        .into_iter()
+        .map(|(_, text, style)| (text, style))
+        .collect();
+
     // Render tabs
     render_tab_labels(stdout, labels);
+    if let Some(info) = current_tab_addresses(state) {
+        if info.bonded {
+            render_bond_endpoints(stdout, state, info, cols);
+        } else {
+            render_tab_addresses(stdout, &info.addresses, cols);
+        }
+    }
     render_tab_separator(stdout, cols);
 }
 
-fn render_tab_labels<W: Write>(stdout: &mut W, labels: Vec<(String, Color)>) {
-    queue!(stdout, Print("Tabs: ")).unwrap();
-    for (text, color) in labels {
-        if color == Color::Black {
-            // Selected tab: white text on blue background for good visibility
-            print_colored_text(stdout, &text, Color::White, Some(Color::Blue), None);
-        } else {
-            print_colored_text(stdout, &text, color, None, None);
+/// Addresses of the node on the selected tab, when more than one reaches it
+pub fn current_tab_addresses(state: &AppState) -> Option<&HostInfo> {
+    match state.current_tab {
+        0 => None,
+        tab => state.host_info.get(state.tabs.get(tab)?),
+    }
+}
+
+fn render_tab_addresses<W: Write>(stdout: &mut W, addresses: &[String], cols: u16) {
+    let line = format!("      via {}", addresses.join(", "));
+    let line = crate::ui::text::truncate_to_width(&line, cols as usize);
+    print_colored_text(stdout, &line, theme::current().dim, None, None);
+    queue!(stdout, Print("\r\n")).unwrap();
+}
+
+/// `(text, color)` pieces listing a bond's endpoints with their health
+fn bond_endpoint_parts(state: &AppState, info: &HostInfo) -> Vec<(String, Color)> {
+    let theme = theme::current();
+    let mut parts = vec![("      endpoints ".to_string(), theme.dim)];
+    for (i, address) in info.addresses.iter().enumerate() {
+        if i > 0 {
+            parts.push((", ".to_string(), theme.dim));
         }
+        let (health, color) = match state.connection_status.get(address) {
+            Some(status) if status.is_connected => ("up", theme.ok),
+            Some(_) => ("down", theme.critical),
+            None => ("pending", theme.dim),
+        };
+        parts.push((format!("{address} {health}"), color));
+    }
+    parts
+}
+
+fn render_bond_endpoints<W: Write>(stdout: &mut W, state: &AppState, info: &HostInfo, cols: u16) {
+    let mut remaining = cols as usize;
+    for (text, color) in bond_endpoint_parts(state, info) {
+        let text = crate::ui::text::truncate_to_width(&text, remaining);
+        if text.is_empty() {
+            break;
+        }
+        remaining -= crate::ui::text::display_width(&text);
+        print_colored_text(stdout, &text, color, None, None);
+    }
+    queue!(stdout, Print("\r\n")).unwrap();
+}
+
+/// Column ranges of the currently visible tab labels
+pub fn tab_hit_regions(state: &AppState, cols: u16) -> Vec<TabRegion> {
+    let mut col = TABS_PREFIX_WIDTH;
+    visible_tabs(state, cols)
+        .into_iter()
+        .map(|(tab_index, text, _)| {
+            let start_col = col;
+            col += crate::ui::text::display_width(&text) as u16;
+            TabRegion {
+                start_col,
+                end_col: col,
+                tab_index,
+            }
+        })
+        .collect()
+}
+
+fn render_tab_labels<W: Write>(stdout: &mut W, labels: Vec<(String, TabStyle)>) {
+    queue!(stdout, Print("Tabs: ")).unwrap();
+    for (text, (fg, bg)) in labels {
+        print_colored_text(stdout, &text, fg, bg, None);
     }
     queue!(stdout, Print("\r\n")).unwrap();
 }
@@ -120,7 +257,7 @@ fn render_tab_labels<W: Write>(stdout: &mut W, labels: Vec<(String, Color)>) {
 fn render_tab_separator<W: Write>(stdout: &mut W, cols: u16) {
     // Print separator
     let separator = "─".repeat(cols as usize);
-    print_colored_text(stdout, &separator, Color::DarkGrey, None, None);
+    print_colored_text(stdout, &separator, theme::current().dim, None, None);
     queue!(stdout, Print("\r\n")).unwrap();
 }
 
@@ -145,15 +282,7 @@ pub fn calculate_tab_visibility(state: &AppState, cols: u16) -> TabVisibility {
         .skip(state.tab_scroll_offset)
     {
         // Get display name for width calculation
-        let display_name = if let Some(connection_status) = state.connection_status.get(tab) {
-            connection_status
-                .actual_hostname
-                .as_ref()
-                .unwrap_or(tab)
-                .clone()
-        } else {
-            tab.to_string()
-        };
+        let display_name = tab_display_name(state, tab);
         let tab_width = display_name.len() as u16 + 2;
         if available_width < tab_width {
             break;
@@ -189,6 +318,7 @@ mod tests {
             cpu_info: Vec::new(),
             memory_info: Vec::new(),
             process_info: Vec::new(),
+            user_summaries: Vec::new(),
             chassis_info: Vec::new(),
             selected_process_index: 0,
             start_index: 0,
@@ -212,8 +342,13 @@ mod tests {
             cpu_name_scroll_offsets: HashMap::new(),
             frame_counter: 0,
             storage_info: Vec::new(),
+            network_info: Vec::new(),
+            infiniband_info: Vec::new(),
             show_help: false,
+            collection_timings: Vec::new(),
+            show_logs: false,
             show_per_core_cpu: false,
+            normalize_load: false,
             utilization_history: VecDeque::new(),
             memory_history: VecDeque::new(),
             temperature_history: VecDeque::new(),
@@ -229,10 +364,34 @@ mod tests {
             connection_status: HashMap::new(),
             known_hosts: Vec::new(),
             hostname_to_host_id: HashMap::new(),
+            host_info: HashMap::new(),
             is_local_mode: false, // Test state assumes remote mode
+            readonly: false,
             runtime_environment: crate::utils::RuntimeEnvironment::detect(),
             data_version: 0,
             gpu_filter_enabled: false,
+            group_by_user: false,
+            process_top: crate::app_state::ProcessTop::Off,
+            min_process_activity: 0.0,
+            expanded_user: None,
+            paused: false,
+            paused_at: None,
+            refresh_requested: None,
+            refresh_now: Default::default(),
+            host_search: None,
+            heatmap: None,
+            rack_layout: None,
+            pinned_gpus: std::collections::BTreeSet::new(),
+            selected_gpu: None,
+            collection_stamps: std::collections::BTreeMap::new(),
+            processes_collected_at: None,
+            kill_prompt: None,
+            refresh_interval: None,
+            process_layout: Default::default(),
+            column_chooser: None,
+            utilization_ema: None,
+            change_highlight: None,
+            replay: None,
         }
     }
 
@@ -255,4 +414,116 @@ mod tests {
         assert_eq!(visibility.first_visible, 1);
         assert!(visibility.has_more_left);
     }
+
+    #[test]
+    fn test_host_search_filters_tabs() {
+        let mut state = create_test_state();
+        state.tabs = vec![
+            "All".to_string(),
+            "gpu-a:9090".to_string(),
+            "cpu-b:9090".to_string(),
+            "gpu-c:9090".to_string(),
+        ];
+        state.tab_scroll_offset = 2;
+        state.begin_host_search();
+        state.host_search_push('g');
+        state.host_search_push('P');
+
+        // Matches ignore the scroll offset and keep "All" first
+        let indices: Vec<usize> = tab_hit_regions(&state, 80)
+            .iter()
+            .map(|region| region.tab_index)
+            .collect();
+        assert_eq!(indices, vec![0, 1, 3]);
+
+        state.cancel_host_search();
+        assert_eq!(tab_hit_regions(&state, 80).len(), 2);
+    }
+
+    #[test]
+    fn test_tab_hit_regions() {
+        let mut state = create_test_state();
+        state.tabs = vec!["All".to_string(), "node1".to_string(), "node2".to_string()];
+
+        let regions = tab_hit_regions(&state, 80);
+        assert_eq!(regions.len(), 3);
+        // "Tabs: " prefix, then " All " (5 columns)
+        assert_eq!(regions[0].start_col, 6);
+        assert_eq!(regions[0].end_col, 11);
+        assert_eq!(regions[0].tab_index, 0);
+        // " node1 " follows immediately
+        assert_eq!(regions[1].start_col, 11);
+        assert_eq!(regions[1].end_col, 18);
+        assert_eq!(regions[1].tab_index, 1);
+
+        // Labels take as many columns as they draw, not bytes
+        state.tabs[1] = "nœud-é".to_string();
+        let regions = tab_hit_regions(&state, 80);
+        assert_eq!((regions[1].start_col, regions[1].end_col), (11, 19));
+        assert_eq!(regions[2].start_col, 19);
+
+        // Scrolled tabs keep their real tab index
+        state.tab_scroll_offset = 1;
+        let regions = tab_hit_regions(&state, 80);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[1].tab_index, 2);
+
+        // Nothing fits on a tiny terminal
+        assert!(tab_hit_regions(&state, 8).is_empty());
+    }
+
+    #[test]
+    fn test_bond_tab_shows_endpoint_count_and_health() {
+        let mut state = create_test_state();
+        state.tabs = vec!["All".to_string(), "nodeA".to_string()];
+        state.current_tab = 1;
+        state.host_info.insert(
+            "nodeA".to_string(),
+            HostInfo {
+                instance: "nodeA".to_string(),
+                addresses: vec!["nodeA:9090".to_string(), "nodeA:9091".to_string()],
+                bonded: true,
+            },
+        );
+        let mut up = crate::app_state::ConnectionStatus::new(
+            "nodeA:9090".to_string(),
+            "nodeA:9090".to_string(),
+        );
+        up.mark_success();
+        let mut down = crate::app_state::ConnectionStatus::new(
+            "nodeA:9091".to_string(),
+            "nodeA:9091".to_string(),
+        );
+        down.mark_failure("timeout".to_string());
+        state.connection_status.insert(up.host_id.clone(), up);
+        state.connection_status.insert(down.host_id.clone(), down);
+
+        assert_eq!(tab_display_name(&state, "nodeA"), "nodeA[2]");
+        let info = current_tab_addresses(&state).unwrap();
+        let text: Vec<String> = bond_endpoint_parts(&state, info)
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect();
+        assert_eq!(
+            text.concat().trim(),
+            "endpoints nodeA:9090 up, nodeA:9091 down"
+        );
+    }
+
+    #[test]
+    fn test_draining_host_tab_is_marked() {
+        let mut state = create_test_state();
+        state.tabs = vec!["All".to_string(), "node1".to_string(), "node2".to_string()];
+        state.chassis_info.push(crate::device::ChassisInfo {
+            host_id: "node2".to_string(),
+            draining: true,
+            ..Default::default()
+        });
+
+        let labels: Vec<String> = visible_tabs(&state, 80)
+            .into_iter()
+            .map(|(_, text, _)| text)
+            .collect();
+        assert_eq!(labels, [" All ", " node1 ", " node2 DRAINING "]);
+    }
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+use std::fmt;
 use std::io::Write;
 
 use crossterm::{
@@ -19,6 +21,8 @@ use crossterm::{
     style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
 };
 
+use crate::utils::format_bytes;
+
 // Helper function to get display width of a single character
 pub fn char_display_width(c: char) -> usize {
     match c {
@@ -52,15 +56,75 @@ pub fn truncate_to_width(s: &str, max_width: usize) -> String {
     result
 }
 
-// Helper function to format RAM values with appropriate units
-pub fn format_ram_value(gb_value: f64) -> String {
-    if gb_value >= 1024.0 {
-        format!("{:.2}TB", gb_value / 1024.0)
-    } else if gb_value < 1.0 {
-        // For sub-GB values (like 512MB = 0.5GB), show with 1 decimal place
-        format!("{gb_value:.1}GB")
-    } else {
-        format!("{gb_value:.0}GB")
+/// `text` as is when it has at most `width` characters, otherwise the
+/// `width` characters starting `offset` characters in, wrapping around after
+/// a three-space gap so that a growing offset scrolls it like a ticker.
+/// Counts characters rather than bytes, so non-ASCII hostnames scroll too.
+pub fn scroll_to_width(text: &str, width: usize, offset: usize) -> Cow<'_, str> {
+    let len = text.chars().count();
+    if len <= width {
+        return Cow::Borrowed(text);
+    }
+    let cycle = len + 3;
+    Cow::Owned(
+        text.chars()
+            .chain("   ".chars())
+            .cycle()
+            .skip(offset % cycle)
+            .take(width)
+            .collect(),
+    )
+}
+
+/// Network throughput in the selected unit system, e.g. `12.5MiB/s`
+pub fn format_throughput(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_bytes(bytes_per_sec.max(0.0) as u64))
+}
+
+/// Short text formatted on the stack, so a composite value can be padded
+/// as a whole without a heap allocation. Anything past 32 bytes is dropped
+/// at a character boundary.
+pub struct InlineText {
+    buf: [u8; 32],
+    len: usize,
+}
+
+impl Default for InlineText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InlineText {
+    pub fn new() -> Self {
+        Self {
+            buf: [0; 32],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for InlineText {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let end = self.len + c.len_utf8();
+            if end > self.buf.len() {
+                break;
+            }
+            c.encode_utf8(&mut self.buf[self.len..end]);
+            self.len = end;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for InlineText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
     }
 }
 
@@ -71,22 +135,46 @@ pub fn print_colored_text<W: Write>(
     bg_color: Option<Color>,
     width: Option<usize>,
 ) {
-    let adjusted_text = if let Some(w) = width {
-        if text.len() > w {
-            text.chars().take(w).collect::<String>()
-        } else {
-            format!("{text:<w$}")
+    match width {
+        Some(w) if text.chars().count() > w => {
+            let end = text.char_indices().nth(w).map_or(text.len(), |(i, _)| i);
+            queue_colored(stdout, &text[..end], fg_color, bg_color);
         }
-    } else {
-        text.to_string()
-    };
+        Some(w) => queue_colored(stdout, format_args!("{text:<w$}"), fg_color, bg_color),
+        None => queue_colored(stdout, text, fg_color, bg_color),
+    }
+}
 
+/// Like [`print_colored_text`] for formatted values, written straight into
+/// `stdout` without an intermediate `String`:
+/// `print_colored_fmt(stdout, format_args!("{util:>5.1}%"), theme.text)`.
+pub fn print_colored_fmt<W: Write>(stdout: &mut W, args: fmt::Arguments<'_>, fg_color: Color) {
+    queue_colored(stdout, args, fg_color, None);
+}
+
+/// [`print_colored_fmt`] with an optional background, for values that are
+/// briefly highlighted.
+pub fn print_highlighted_fmt<W: Write>(
+    stdout: &mut W,
+    args: fmt::Arguments<'_>,
+    fg_color: Color,
+    bg_color: Option<Color>,
+) {
+    queue_colored(stdout, args, fg_color, bg_color);
+}
+
+fn queue_colored<W: Write, T: fmt::Display>(
+    stdout: &mut W,
+    text: T,
+    fg_color: Color,
+    bg_color: Option<Color>,
+) {
     if let Some(bg) = bg_color {
         queue!(
             stdout,
             SetForegroundColor(fg_color),
             SetBackgroundColor(bg),
-            Print(adjusted_text),
+            Print(text),
             ResetColor
         )
         .unwrap();
@@ -94,7 +182,7 @@ pub fn print_colored_text<W: Write>(
         queue!(
             stdout,
             SetForegroundColor(fg_color),
-            Print(adjusted_text),
+            Print(text),
             ResetColor
         )
         .unwrap();
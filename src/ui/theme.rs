@@ -0,0 +1,344 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Color themes for the terminal UI.
+//!
+//! Renderers never name colors directly; they ask [`current`] for the role a
+//! piece of text plays. The theme is chosen once at startup from `--theme`,
+//! `ui.theme` in the config file, or the terminal background advertised in
+//! `COLORFGBG`.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use crossterm::style::Color;
+
+/// Built-in themes
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeName {
+    /// Bright text for dark terminal backgrounds
+    #[default]
+    Dark,
+    /// Dark text for light terminal backgrounds
+    Light,
+    /// Bold colors that never contrast red with green, for colorblind users
+    HighContrast,
+}
+
+impl ThemeName {
+    /// Parse a `ui.theme` config value
+    pub fn parse(name: &str) -> Option<Self> {
+        <Self as ValueEnum>::from_str(&name.replace('_', "-"), true).ok()
+    }
+
+    /// Pick a theme from the terminal background in `COLORFGBG`, dark when
+    /// the terminal does not say.
+    pub fn detect() -> Self {
+        Self::from_colorfgbg(std::env::var("COLORFGBG").ok().as_deref())
+    }
+
+    /// `COLORFGBG` is `fg;bg` or `fg;default;bg` with ANSI color numbers;
+    /// backgrounds 7 and 9-15 are light.
+    pub fn from_colorfgbg(value: Option<&str>) -> Self {
+        match value
+            .and_then(|value| value.rsplit(';').next())
+            .and_then(|bg| bg.trim().parse::<u8>().ok())
+        {
+            Some(7 | 9..=15) => Self::Light,
+            _ => Self::Dark,
+        }
+    }
+
+    pub fn theme(self) -> &'static Theme {
+        match self {
+            Self::Dark => &DARK,
+            Self::Light => &LIGHT,
+            Self::HighContrast => &HIGH_CONTRAST,
+        }
+    }
+}
+
+impl fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_possible_value() {
+            Some(value) => f.write_str(value.get_name()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Every color the UI draws with, by role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Regular text, values and padding
+    pub text: Color,
+    /// Text drawn inside bars
+    pub muted: Color,
+    /// Separators, hints, idle and disconnected items
+    pub dim: Color,
+    /// Headings, device kinds and box borders
+    pub title: Color,
+    /// The " @ " before host names and the key hints
+    pub host: Color,
+
+    /// Field labels without a more specific role
+    pub label: Color,
+    /// Memory and accelerator labels
+    pub label_memory: Color,
+    /// Temperature, frequency and utilization labels
+    pub label_thermal: Color,
+    /// Power, used-space and cache labels
+    pub label_power: Color,
+    /// Capacity, core count and software version labels
+    pub label_info: Color,
+    /// Network throughput labels
+    pub label_network: Color,
+
+    /// Selected row
+    pub selection_fg: Color,
+    pub selection_bg: Color,
+    /// Selected tab
+    pub tab_fg: Color,
+    pub tab_bg: Color,
+    /// Text on colored badges and heatmap cells
+    pub badge_fg: Color,
+
+    /// Levels, from lightly loaded to critical, for bars, gauges and alerts
+    pub low: Color,
+    pub ok: Color,
+    pub elevated: Color,
+    pub warning: Color,
+    pub severe: Color,
+    pub critical: Color,
+    /// Extra steps of the per-core utilization ramp
+    pub cold: Color,
+    pub cool: Color,
+    pub hot: Color,
+    /// CPU time stolen by or given to guests
+    pub virtualized: Color,
+}
+
+pub const DARK: Theme = Theme {
+    text: Color::White,
+    muted: Color::Grey,
+    dim: Color::DarkGrey,
+    title: Color::Cyan,
+    host: Color::DarkGreen,
+
+    label: Color::Yellow,
+    label_memory: Color::Blue,
+    label_thermal: Color::Magenta,
+    label_power: Color::Red,
+    label_info: Color::Green,
+    label_network: Color::DarkCyan,
+
+    selection_fg: Color::Black,
+    selection_bg: Color::White,
+    tab_fg: Color::White,
+    tab_bg: Color::Blue,
+    badge_fg: Color::Black,
+
+    low: Color::DarkGreen,
+    ok: Color::Green,
+    elevated: Color::Rgb {
+        r: 255,
+        g: 200,
+        b: 0,
+    },
+    warning: Color::Yellow,
+    severe: Color::Rgb {
+        r: 255,
+        g: 100,
+        b: 100,
+    },
+    critical: Color::Red,
+    cold: Color::Blue,
+    cool: Color::Cyan,
+    hot: Color::Magenta,
+    virtualized: Color::DarkBlue,
+};
+
+pub const LIGHT: Theme = Theme {
+    text: Color::Black,
+    muted: Color::DarkGrey,
+    dim: Color::DarkGrey,
+    title: Color::DarkBlue,
+    host: Color::DarkGreen,
+
+    label: Color::DarkYellow,
+    label_memory: Color::DarkBlue,
+    label_thermal: Color::DarkMagenta,
+    label_power: Color::DarkRed,
+    label_info: Color::DarkGreen,
+    label_network: Color::DarkCyan,
+
+    selection_fg: Color::White,
+    selection_bg: Color::Black,
+    tab_fg: Color::White,
+    tab_bg: Color::DarkBlue,
+    badge_fg: Color::Black,
+
+    low: Color::DarkCyan,
+    ok: Color::DarkGreen,
+    elevated: Color::Rgb {
+        r: 190,
+        g: 130,
+        b: 0,
+    },
+    warning: Color::DarkYellow,
+    severe: Color::Rgb {
+        r: 200,
+        g: 60,
+        b: 40,
+    },
+    critical: Color::DarkRed,
+    cold: Color::Blue,
+    cool: Color::DarkCyan,
+    hot: Color::DarkMagenta,
+    virtualized: Color::DarkBlue,
+};
+
+/// Orange from the Okabe-Ito palette, which reads the same to all common
+/// kinds of color vision
+const ORANGE: Color = Color::Rgb {
+    r: 230,
+    g: 159,
+    b: 0,
+};
+
+pub const HIGH_CONTRAST: Theme = Theme {
+    text: Color::White,
+    muted: Color::White,
+    dim: Color::Grey,
+    title: Color::Cyan,
+    host: Color::Grey,
+
+    label: Color::Yellow,
+    label_memory: Color::Cyan,
+    label_thermal: Color::Magenta,
+    label_power: ORANGE,
+    label_info: Color::Blue,
+    label_network: Color::Cyan,
+
+    selection_fg: Color::Black,
+    selection_bg: Color::Yellow,
+    tab_fg: Color::Black,
+    tab_bg: Color::Cyan,
+    badge_fg: Color::Black,
+
+    low: Color::Blue,
+    ok: Color::Cyan,
+    elevated: Color::Yellow,
+    warning: Color::Yellow,
+    severe: ORANGE,
+    critical: Color::Magenta,
+    cold: Color::DarkCyan,
+    cool: Color::Blue,
+    hot: ORANGE,
+    virtualized: Color::DarkCyan,
+};
+
+static SELECTED: OnceLock<ThemeName> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    static OVERRIDE: std::cell::Cell<Option<ThemeName>> = const { std::cell::Cell::new(None) };
+}
+
+/// Select the theme for the rest of the run. Only the first call has an
+/// effect.
+pub fn set_theme(name: ThemeName) {
+    let _ = SELECTED.set(name);
+}
+
+/// The theme renderers draw with; dark until one is selected.
+pub fn current() -> &'static Theme {
+    #[cfg(test)]
+    if let Some(name) = OVERRIDE.with(|name| name.get()) {
+        return name.theme();
+    }
+    SELECTED.get().copied().unwrap_or_default().theme()
+}
+
+/// Render with `name` on this thread only, so tests can compare themes
+/// without touching the process-wide choice.
+#[cfg(test)]
+pub fn with_theme<R>(name: ThemeName, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<ThemeName>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|name| name.set(self.0));
+        }
+    }
+
+    let _restore = Restore(OVERRIDE.with(|current| current.replace(Some(name))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::widgets::draw_bar;
+
+    fn gauge(name: ThemeName) -> String {
+        with_theme(name, || {
+            let mut out = Vec::new();
+            draw_bar(&mut out, "GPU", 85.0, 100.0, 40, None);
+            String::from_utf8(out).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_gauge_escape_sequences_differ_per_theme() {
+        let dark = gauge(ThemeName::Dark);
+        let light = gauge(ThemeName::Light);
+        let high_contrast = gauge(ThemeName::HighContrast);
+
+        // 85% fills the bar with the critical color, and the label is text
+        assert!(dark.contains("\x1b[38;5;9m") && dark.contains("\x1b[38;5;15m"));
+        assert!(light.contains("\x1b[38;5;1m") && light.contains("\x1b[38;5;0m"));
+        assert!(high_contrast.contains("\x1b[38;5;13m"));
+        assert!(!high_contrast.contains("\x1b[38;5;9m"));
+
+        assert_ne!(dark, light);
+        assert_ne!(dark, high_contrast);
+        assert_ne!(light, high_contrast);
+        // The override does not leak out of with_theme
+        assert_eq!(gauge(ThemeName::Dark), dark);
+        assert_eq!(current(), &DARK);
+    }
+
+    #[test]
+    fn test_from_colorfgbg() {
+        assert_eq!(ThemeName::from_colorfgbg(None), ThemeName::Dark);
+        assert_eq!(ThemeName::from_colorfgbg(Some("15;0")), ThemeName::Dark);
+        assert_eq!(ThemeName::from_colorfgbg(Some("0;15")), ThemeName::Light);
+        assert_eq!(
+            ThemeName::from_colorfgbg(Some("0;default;7")),
+            ThemeName::Light
+        );
+        assert_eq!(ThemeName::from_colorfgbg(Some("garbage")), ThemeName::Dark);
+    }
+
+    #[test]
+    fn test_parse_theme_name() {
+        assert_eq!(ThemeName::parse("light"), Some(ThemeName::Light));
+        assert_eq!(
+            ThemeName::parse("High_Contrast"),
+            Some(ThemeName::HighContrast)
+        );
+        assert_eq!(ThemeName::parse("solarized"), None);
+    }
+}
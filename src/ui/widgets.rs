@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::{self, Write as _};
 use std::io::Write;
 
-use crossterm::style::Color;
+use crossterm::queue;
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
 
 use crate::common::config::ThemeConfig;
-use crate::ui::text::print_colored_text;
+use crate::device::memory_pressure::PressureLevel;
+use crate::ui::text::{print_colored_text, truncate_to_width, InlineText};
+use crate::ui::theme;
 
 pub struct BarSegment {
     pub value: f64,
@@ -40,22 +44,29 @@ impl BarSegment {
     }
 }
 
+/// Write `cell` `count` times in `color`, with one color change per run
+fn print_run<W: Write>(stdout: &mut W, cell: &str, count: usize, color: Color) {
+    if count == 0 {
+        return;
+    }
+    queue!(stdout, SetForegroundColor(color)).unwrap();
+    for _ in 0..count {
+        queue!(stdout, Print(cell)).unwrap();
+    }
+    queue!(stdout, ResetColor).unwrap();
+}
+
 pub fn draw_bar<W: Write>(
     stdout: &mut W,
     label: &str,
     value: f64,
     max_value: f64,
     width: usize,
-    show_text: Option<String>,
+    show_text: Option<fmt::Arguments<'_>>,
 ) {
-    // Format label to exactly 5 characters for consistent alignment
-    let formatted_label = if label.len() > 5 {
-        // Trim to 5 characters if too long
-        label[..5].to_string()
-    } else {
-        // Pad with spaces if too short
-        format!("{label:<5}")
-    };
+    let theme = theme::current();
+    // Label padded or trimmed to exactly 5 characters for consistent alignment
+    let label = label.get(..5).unwrap_or(label);
     let available_bar_width = width.saturating_sub(9); // 9 for "LABEL: [" and "] " (5 + 4)
 
     // Calculate the filled portion
@@ -65,45 +76,49 @@ pub fn draw_bar<W: Write>(
     // Choose color based on usage using ThemeConfig
     let color = ThemeConfig::progress_bar_color(fill_ratio);
 
-    // Prepare text to display inside the bar with fixed width
-    let display_text = if let Some(text) = show_text {
-        // Ensure consistent width for value text (8 characters)
-        if text.len() > 8 {
-            text[..8].to_string()
-        } else {
-            format!("{text:>8}") // Right-align in 8-character field
+    // Text inside the bar, right-aligned in an 8-character field
+    let mut value_text = InlineText::new();
+    match show_text {
+        Some(text) => {
+            let _ = value_text.write_fmt(text);
         }
-    } else {
-        format!("{:>7.1}%", fill_ratio * 100.0) // Right-align percentage in 8-character field
-    };
-
-    // Print label
-    print_colored_text(stdout, &formatted_label, Color::White, None, None);
-    print_colored_text(stdout, ": [", Color::White, None, None);
-
-    // Calculate positioning for right-aligned text
-    let text_len = display_text.len();
-    let text_pos = available_bar_width.saturating_sub(text_len);
-
-    // Print the bar with embedded text using filled vertical lines
-    for i in 0..available_bar_width {
-        if i >= text_pos && i < text_pos + text_len {
-            // Print text character
-            let char_index = i - text_pos;
-            if let Some(ch) = display_text.chars().nth(char_index) {
-                // Always use white for text to ensure readability
-                print_colored_text(stdout, &ch.to_string(), Color::Grey, None, None);
-            }
-        } else if i < filled_width {
-            // Print filled area with shorter vertical lines in load color
-            print_colored_text(stdout, "▬", color, None, None);
-        } else {
-            // Print empty line segments
-            print_colored_text(stdout, "─", Color::DarkGrey, None, None);
+        None => {
+            let _ = write!(value_text, "{:.1}%", fill_ratio * 100.0);
         }
     }
+    let value_text = value_text.as_str();
+    let value_text = value_text
+        .char_indices()
+        .nth(8)
+        .map_or(value_text, |(end, _)| &value_text[..end]);
+    let mut display_text = InlineText::new();
+    let _ = write!(display_text, "{value_text:>8}");
+    let display_text = display_text.as_str();
 
-    print_colored_text(stdout, "]", Color::White, None, None);
+    // Print label
+    queue!(
+        stdout,
+        SetForegroundColor(theme.text),
+        Print(format_args!("{label:<5}: [")),
+        ResetColor
+    )
+    .unwrap();
+
+    // The text is right-aligned and drawn over the bar, cut off if the bar
+    // is narrower than it. Like the padding above, it is measured in chars.
+    let text_len = display_text.chars().count();
+    let text_pos = available_bar_width.saturating_sub(text_len);
+    let filled_before_text = filled_width.min(text_pos);
+    print_run(stdout, "▬", filled_before_text, color);
+    print_run(stdout, "─", text_pos - filled_before_text, theme.dim);
+    let visible_text = display_text
+        .char_indices()
+        .nth(available_bar_width - text_pos)
+        .map_or(display_text, |(end, _)| &display_text[..end]);
+    // Bar text stays muted so it reads over any fill
+    print_colored_text(stdout, visible_text, theme.muted, None, None);
+
+    print_colored_text(stdout, "]", theme.text, None, None);
 }
 
 pub fn draw_bar_multi<W: Write>(
@@ -114,12 +129,9 @@ pub fn draw_bar_multi<W: Write>(
     width: usize,
     show_text: Option<String>,
 ) {
+    let theme = theme::current();
     // Format label to exactly 5 characters for consistent alignment
-    let formatted_label = if label.len() > 5 {
-        label[..5].to_string()
-    } else {
-        format!("{label:<5}")
-    };
+    let formatted_label = format!("{:<5}", truncate_to_width(label, 5));
     let available_bar_width = width.saturating_sub(9); // 9 for "LABEL: [" and "] " (5 + 4)
 
     // Calculate total value
@@ -129,21 +141,17 @@ pub fn draw_bar_multi<W: Write>(
     // Prepare text to display inside the bar
     let display_text = if let Some(text) = show_text {
         // Ensure consistent width for value text (8 characters)
-        if text.len() > 8 {
-            text[..8].to_string()
-        } else {
-            format!("{text:>8}")
-        }
+        format!("{:>8}", truncate_to_width(&text, 8))
     } else {
         format!("{:>7.1}%", total_ratio * 100.0)
     };
 
     // Print label
-    print_colored_text(stdout, &formatted_label, Color::White, None, None);
-    print_colored_text(stdout, ": [", Color::White, None, None);
+    print_colored_text(stdout, &formatted_label, theme.text, None, None);
+    print_colored_text(stdout, ": [", theme.text, None, None);
 
-    // Calculate positioning for right-aligned text
-    let text_len = display_text.len();
+    // Calculate positioning for right-aligned text, in chars as drawn below
+    let text_len = display_text.chars().count();
     let text_pos = available_bar_width.saturating_sub(text_len);
 
     // Calculate segment positions
@@ -172,7 +180,7 @@ pub fn draw_bar_multi<W: Write>(
             // Print text character
             let char_index = i - text_pos;
             if let Some(ch) = display_text.chars().nth(char_index) {
-                print_colored_text(stdout, &ch.to_string(), Color::Grey, None, None);
+                print_colored_text(stdout, &ch.to_string(), theme.muted, None, None);
             }
         } else {
             // Find which segment this position belongs to
@@ -187,12 +195,12 @@ pub fn draw_bar_multi<W: Write>(
 
             if !printed {
                 // Print empty line segments
-                print_colored_text(stdout, "─", Color::DarkGrey, None, None);
+                print_colored_text(stdout, "─", theme.dim, None, None);
             }
         }
     }
 
-    print_colored_text(stdout, "]", Color::White, None, None);
+    print_colored_text(stdout, "]", theme.text, None, None);
 }
 
 // Helper functions for common use cases
@@ -201,37 +209,95 @@ impl BarSegment {
     #[allow(dead_code)]
     pub fn cpu_low_priority(value: f64) -> Self {
         // nice
-        Self::new(value, Color::Blue).with_label("low")
+        Self::new(value, theme::current().cold).with_label("low")
     }
 
     #[allow(dead_code)]
     pub fn cpu_normal(value: f64) -> Self {
         // user
-        Self::new(value, Color::Green).with_label("normal")
+        Self::new(value, theme::current().ok).with_label("normal")
     }
 
     #[allow(dead_code)]
     pub fn cpu_kernel(value: f64) -> Self {
         // system
-        Self::new(value, Color::Red).with_label("kernel")
+        Self::new(value, theme::current().critical).with_label("kernel")
     }
 
     #[allow(dead_code)]
     pub fn cpu_virtualized(value: f64) -> Self {
         // steal + guest
-        Self::new(value, Color::DarkBlue).with_label("virtual")
+        Self::new(value, theme::current().virtualized).with_label("virtual")
     }
 
     // Memory usage helpers
-    pub fn memory_used(value: f64) -> Self {
-        Self::new(value, Color::Green).with_label("used")
+    /// Used memory, colored by the memory pressure when the host reports it
+    pub fn memory_used(value: f64, pressure: Option<PressureLevel>) -> Self {
+        let theme = theme::current();
+        let color = match pressure {
+            None | Some(PressureLevel::Normal) => theme.ok,
+            Some(PressureLevel::Warning) => theme.severe,
+            Some(PressureLevel::Critical) => theme.critical,
+        };
+        Self::new(value, color).with_label("used")
     }
 
     pub fn memory_buffers(value: f64) -> Self {
-        Self::new(value, Color::Blue).with_label("buffers")
+        Self::new(value, theme::current().cold).with_label("buffers")
     }
 
     pub fn memory_cache(value: f64) -> Self {
-        Self::new(value, Color::Yellow).with_label("cache")
+        Self::new(value, theme::current().warning).with_label("cache")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The bar between its brackets, without color escapes
+    fn bar_cells(out: Vec<u8>) -> String {
+        let text = String::from_utf8(out).unwrap();
+        let mut plain = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            } else {
+                plain.push(c);
+            }
+        }
+        let start = plain.find('[').unwrap() + 1;
+        plain[start..plain.rfind(']').unwrap()].to_string()
+    }
+
+    #[test]
+    fn test_bar_text_with_multibyte_chars_fills_the_bar() {
+        let mut out = Vec::new();
+        draw_bar(
+            &mut out,
+            "Temp",
+            65.0,
+            100.0,
+            29,
+            Some(format_args!("65°C")),
+        );
+        let cells = bar_cells(out);
+        assert_eq!(cells.chars().count(), 20);
+        assert!(cells.ends_with("    65°C"));
+
+        let mut out = Vec::new();
+        let segments = [BarSegment::new(40.0, Color::Green)];
+        draw_bar_multi(
+            &mut out,
+            "Mem",
+            &segments,
+            100.0,
+            29,
+            Some("1.5 GiB·".to_string()),
+        );
+        let cells = bar_cells(out);
+        assert_eq!(cells.chars().count(), 20);
+        assert!(cells.ends_with("1.5 GiB·"));
     }
 }
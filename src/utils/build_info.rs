@@ -0,0 +1,83 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! What this binary was built with, for `all-smi --version --json` and
+//! `all_smi_build_info`. Everything is read from `cfg!`, so it describes
+//! the running build rather than the manifest.
+
+use serde::Serialize;
+
+/// Crate version
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Commit the binary was built from, `unknown` outside a git checkout
+pub const GIT_SHA: &str = env!("ALL_SMI_GIT_SHA");
+
+/// Version, commit and compiled-in capabilities of this build
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_sha: &'static str,
+    /// Cargo features enabled at build time
+    pub features: Vec<&'static str>,
+    /// Device readers compiled in, named as in `all-smi doctor`
+    pub platforms: Vec<&'static str>,
+    /// OS and architecture, e.g. `linux-x86_64`
+    pub target: String,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        Self {
+            version: VERSION,
+            git_sha: GIT_SHA,
+            features: enabled(&[
+                ("furiosa-smi-rs", cfg!(feature = "furiosa-smi-rs")),
+                ("grpc", cfg!(feature = "grpc")),
+                ("mock", cfg!(feature = "mock")),
+                ("remote-write", cfg!(feature = "remote-write")),
+                ("systemd", cfg!(feature = "systemd")),
+                ("web", cfg!(feature = "web")),
+            ]),
+            platforms: enabled(&[
+                (
+                    "nvidia",
+                    cfg!(any(target_os = "linux", target_os = "windows")),
+                ),
+                ("nvidia_jetson", cfg!(target_os = "linux")),
+                (
+                    "amd",
+                    cfg!(all(target_os = "linux", not(target_env = "musl"))),
+                ),
+                ("furiosa", cfg!(target_os = "linux")),
+                ("tenstorrent", cfg!(target_os = "linux")),
+                ("rebellions", cfg!(target_os = "linux")),
+                ("gaudi", cfg!(target_os = "linux")),
+                ("google_tpu", cfg!(target_os = "linux")),
+                ("apple_silicon", cfg!(target_os = "macos")),
+                ("windows_gpu", cfg!(target_os = "windows")),
+                ("amd_windows", cfg!(target_os = "windows")),
+            ]),
+            target: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+        }
+    }
+}
+
+fn enabled(flags: &[(&'static str, bool)]) -> Vec<&'static str> {
+    flags
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| *name)
+        .collect()
+}
@@ -162,14 +162,92 @@ impl Default for DiskFilter {
     }
 }
 
+/// Mount point globs supplied by the user with `--disk-include` and
+/// `--disk-exclude` or the `[disk]` config section.
+///
+/// Exclude patterns always win. When include patterns are given, only
+/// matching mounts are reported and they bypass the built-in exclusions,
+/// so e.g. a mount under `/run` can be monitored. With no patterns the
+/// built-in filtering applies unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MountPatterns {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl MountPatterns {
+    /// `Some(true)` to keep the mount, `Some(false)` to drop it, or `None`
+    /// to leave the decision to the built-in filter.
+    pub fn decide(&self, mount_point: &str) -> Option<bool> {
+        if self
+            .exclude
+            .iter()
+            .any(|pattern| glob_match(pattern, mount_point))
+        {
+            return Some(false);
+        }
+        if self.include.is_empty() {
+            return None;
+        }
+        Some(
+            self.include
+                .iter()
+                .any(|pattern| glob_match(pattern, mount_point)),
+        )
+    }
+}
+
+/// Match a mount point against a glob. `*` matches within one path
+/// component, `**` matches across components and `?` matches a single
+/// character other than `/`. Everything else matches literally.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    fn matches(pattern: &[char], text: &[char]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some(('*', rest)) => {
+                let (rest, cross_separators) = match rest.split_first() {
+                    Some(('*', rest)) => (rest, true),
+                    _ => (rest, false),
+                };
+                (0..=text.len())
+                    .take_while(|&i| cross_separators || i == 0 || text[i - 1] != '/')
+                    .any(|i| matches(rest, &text[i..]))
+            }
+            Some(('?', rest)) => text
+                .split_first()
+                .is_some_and(|(c, text)| *c != '/' && matches(rest, text)),
+            Some((p, rest)) => text
+                .split_first()
+                .is_some_and(|(c, text)| c == p && matches(rest, text)),
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    matches(&pattern, &text)
+}
+
 // Thread-safe singleton for global use
 use std::sync::OnceLock;
 
 static DISK_FILTER: OnceLock<DiskFilter> = OnceLock::new();
+static MOUNT_PATTERNS: OnceLock<MountPatterns> = OnceLock::new();
+
+/// Install the user's mount patterns. Must be called before the first disk
+/// collection; later calls are ignored.
+pub fn set_mount_patterns(patterns: MountPatterns) {
+    let _ = MOUNT_PATTERNS.set(patterns);
+}
 
 /// Docker-aware disk filtering that handles bind mounts
 pub fn filter_docker_aware_disks(disks: &Disks) -> Vec<&Disk> {
     let filter = DISK_FILTER.get_or_init(DiskFilter::new);
+    let patterns = MOUNT_PATTERNS.get();
+    let user_decision =
+        |mount_point: &str| patterns.and_then(|patterns| patterns.decide(mount_point));
+
+    // Mounts selected by user patterns skip the built-in heuristics
+    let mut filtered_disks = Vec::new();
 
     // First pass: count mounts per device
     let mut device_mount_count: HashMap<String, usize> = HashMap::new();
@@ -179,6 +257,15 @@ pub fn filter_docker_aware_disks(disks: &Disks) -> Vec<&Disk> {
         let device_name = disk.name().to_string_lossy().to_string();
         let mount_point = disk.mount_point().to_string_lossy().to_string();
 
+        match user_decision(&mount_point) {
+            Some(true) => {
+                filtered_disks.push(disk);
+                continue;
+            }
+            Some(false) => continue,
+            None => {}
+        }
+
         // Skip if mount point should be excluded by basic filter
         if !filter.should_include(&mount_point) {
             continue;
@@ -189,8 +276,6 @@ pub fn filter_docker_aware_disks(disks: &Disks) -> Vec<&Disk> {
     }
 
     // Second pass: apply Docker-aware filtering
-    let mut filtered_disks = Vec::new();
-
     for (device_name, disks_for_device) in device_to_disks {
         let mount_count = device_mount_count.get(&device_name).unwrap_or(&0);
 
@@ -217,6 +302,7 @@ pub fn filter_docker_aware_disks(disks: &Disks) -> Vec<&Disk> {
     for disk in disks.list() {
         let mount_point = disk.mount_point().to_string_lossy().to_string();
         if (disk.file_system() == "overlay" || disk.file_system() == "overlay2")
+            && user_decision(&mount_point).is_none()
             && filter.should_include(&mount_point)
             && !filtered_disks
                 .iter()
@@ -377,6 +463,43 @@ mod tests {
         assert!(!is_primary_mount_point("/etc/hosts"));
     }
 
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("/", "/"));
+        assert!(glob_match("/mnt/nfs*", "/mnt/nfs"));
+        assert!(glob_match("/mnt/nfs*", "/mnt/nfs-home"));
+        assert!(!glob_match("/mnt/nfs*", "/mnt/nfs/sub"));
+        assert!(glob_match("/lustre/**", "/lustre/scratch/user"));
+        assert!(!glob_match("/lustre/**", "/lustre"));
+        assert!(glob_match("/data?", "/data1"));
+        assert!(!glob_match("/data?", "/data/"));
+        assert!(glob_match("*", "C:\\"));
+        assert!(!glob_match("/home", "/home/user"));
+    }
+
+    #[test]
+    fn test_mount_patterns() {
+        // No patterns defers to the built-in filter
+        assert_eq!(MountPatterns::default().decide("/run/nfs"), None);
+
+        let patterns = MountPatterns {
+            include: vec!["/".to_string(), "/run/nfs*".to_string()],
+            exclude: vec!["/run/nfs-old".to_string()],
+        };
+        assert_eq!(patterns.decide("/"), Some(true));
+        assert_eq!(patterns.decide("/run/nfs1"), Some(true));
+        assert_eq!(patterns.decide("/home"), Some(false));
+        // Exclude wins over include
+        assert_eq!(patterns.decide("/run/nfs-old"), Some(false));
+
+        let exclude_only = MountPatterns {
+            include: Vec::new(),
+            exclude: vec!["/scratch/**".to_string()],
+        };
+        assert_eq!(exclude_only.decide("/scratch/tmp"), Some(false));
+        assert_eq!(exclude_only.decide("/home"), None);
+    }
+
     #[test]
     fn test_performance() {
         let filter = DiskFilter::new();
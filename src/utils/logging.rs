@@ -0,0 +1,359 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recent log records kept in memory.
+//!
+//! Code logs through the `tracing` macros (records from the `log` crate are
+//! bridged by the subscriber). Every event is appended to a bounded ring
+//! buffer, which the TUI shows in the log overlay and API mode serves at
+//! `/debug/logs`. API mode additionally writes to stderr; the TUI does not,
+//! since stderr output would corrupt the alternate screen. With `--log-file`
+//! records go to a daily rotated file instead of stderr.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Local};
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::rolling::{InitError, RollingFileAppender, Rotation};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+/// Records kept by the process-wide buffer.
+pub const LOG_BUFFER_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+}
+
+impl From<&Level> for LogLevel {
+    fn from(level: &Level) -> Self {
+        match *level {
+            Level::ERROR => LogLevel::Error,
+            Level::WARN => LogLevel::Warn,
+            Level::INFO => LogLevel::Info,
+            Level::DEBUG => LogLevel::Debug,
+            Level::TRACE => LogLevel::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub timestamp: DateTime<Local>,
+    pub level: LogLevel,
+    /// Module path of the code that logged, e.g. `all_smi::network::client`
+    pub source: String,
+    pub message: String,
+}
+
+fn serialize_timestamp<S: Serializer>(
+    timestamp: &DateTime<Local>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&timestamp.to_rfc3339_opts(chrono::SecondsFormat::Millis, false))
+}
+
+/// Fixed-capacity buffer that drops the oldest record when full.
+pub struct LogBuffer {
+    capacity: usize,
+    records: Mutex<VecDeque<LogRecord>>,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub fn push(&self, record: LogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        // A panic while holding the lock leaves the deque intact, so keep using it
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        while records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Copy of the buffered records, oldest first.
+    pub fn records(&self) -> Vec<LogRecord> {
+        let records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        records.iter().cloned().collect()
+    }
+}
+
+static LOG_BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+
+/// The process-wide buffer fed by the subscriber installed in [`init_logging`].
+pub fn log_buffer() -> &'static LogBuffer {
+    LOG_BUFFER.get_or_init(|| LogBuffer::new(LOG_BUFFER_CAPACITY))
+}
+
+/// Collects the `message` field and appends any other fields as `key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+    log_target: Option<String>,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message.push_str(value),
+            // Records bridged from the `log` crate carry their real target here
+            "log.target" => self.log_target = Some(value.to_string()),
+            name if name.starts_with("log.") => {}
+            name => self.fields.push_str(&format!(" {name}={value}")),
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message.push_str(&format!("{value:?}")),
+            name if name.starts_with("log.") => {}
+            name => self.fields.push_str(&format!(" {name}={value:?}")),
+        }
+    }
+}
+
+/// `tracing` layer that appends every event to a [`LogBuffer`].
+pub struct LogBufferLayer {
+    buffer: &'static LogBuffer,
+}
+
+impl LogBufferLayer {
+    pub fn new(buffer: &'static LogBuffer) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        self.buffer.push(LogRecord {
+            timestamp: Local::now(),
+            level: metadata.level().into(),
+            source: visitor
+                .log_target
+                .unwrap_or_else(|| metadata.target().to_string()),
+            message: visitor.message + &visitor.fields,
+        });
+    }
+}
+
+/// Where log records go besides the in-memory buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogOutput {
+    /// Buffer only; used while the TUI owns the terminal
+    Buffer,
+    /// Buffer and stderr
+    Stderr,
+    /// Buffer and a file rotated daily, see [`open_log_file`]
+    File(PathBuf),
+}
+
+#[derive(Debug, Error)]
+pub enum LogFileError {
+    #[error("Log file path {0} has no file name")]
+    NoFileName(PathBuf),
+
+    #[error("Cannot open log file {path}: {source}")]
+    Open { path: PathBuf, source: InitError },
+}
+
+/// Appender for `--log-file PATH`. A new file is started every day and named
+/// after `PATH` with the date appended, e.g. `all-smi.log.2025-07-01`.
+/// Missing parent directories are created.
+pub fn open_log_file(path: &Path) -> Result<RollingFileAppender, LogFileError> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| LogFileError::NoFileName(path.to_path_buf()))?;
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(file_name.to_string_lossy())
+        .build(directory)
+        .map_err(|source| LogFileError::Open {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+/// `base` raised by one level per `-v`, capped at TRACE.
+pub fn raise_level(base: Level, verbose: u8) -> Level {
+    const LEVELS: [Level; 5] = [
+        Level::ERROR,
+        Level::WARN,
+        Level::INFO,
+        Level::DEBUG,
+        Level::TRACE,
+    ];
+    let index = LEVELS.iter().position(|level| *level == base).unwrap_or(2);
+    LEVELS[(index + verbose as usize).min(LEVELS.len() - 1)]
+}
+
+/// Install the global subscriber. `RUST_LOG` overrides `default_filter`.
+/// Calling it again is a no-op.
+pub fn init_logging(output: LogOutput, default_filter: &str) -> Result<(), LogFileError> {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_filter));
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(LogBufferLayer::new(log_buffer()));
+
+    let _ = match output {
+        LogOutput::Buffer => registry.try_init(),
+        LogOutput::Stderr => registry
+            .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+            .try_init(),
+        LogOutput::File(path) => registry
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(open_log_file(&path)?),
+            )
+            .try_init(),
+    };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message: &str) -> LogRecord {
+        LogRecord {
+            timestamp: Local::now(),
+            level: LogLevel::Warn,
+            source: "all_smi::test".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_buffer_drops_oldest_records() {
+        let buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(record(&format!("message {i}")));
+        }
+
+        let messages: Vec<String> = buffer.records().into_iter().map(|r| r.message).collect();
+        assert_eq!(messages, vec!["message 2", "message 3", "message 4"]);
+    }
+
+    #[test]
+    fn test_zero_capacity_keeps_nothing() {
+        let buffer = LogBuffer::new(0);
+        buffer.push(record("dropped"));
+        assert!(buffer.records().is_empty());
+    }
+
+    #[test]
+    fn test_raise_level() {
+        assert_eq!(raise_level(Level::INFO, 0), Level::INFO);
+        assert_eq!(raise_level(Level::INFO, 1), Level::DEBUG);
+        assert_eq!(raise_level(Level::INFO, 2), Level::TRACE);
+        assert_eq!(raise_level(Level::DEBUG, 5), Level::TRACE);
+        // The level is rendered into an EnvFilter directive
+        assert!(EnvFilter::try_new(format!("all_smi={}", raise_level(Level::INFO, 1))).is_ok());
+    }
+
+    #[test]
+    fn test_log_file_is_rotated_daily() {
+        let dir = std::env::temp_dir().join(format!("all-smi-log-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let appender = open_log_file(&dir.join("nested").join("all-smi.log")).unwrap();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(appender),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("written to file");
+        });
+
+        let today = chrono::Utc::now().format("%Y-%m-%d");
+        let content =
+            std::fs::read_to_string(dir.join("nested").join(format!("all-smi.log.{today}")))
+                .unwrap();
+        assert!(content.contains("written to file"));
+        assert!(!content.contains('\x1b'));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(matches!(
+            open_log_file(Path::new("/")),
+            Err(LogFileError::NoFileName(_))
+        ));
+    }
+
+    #[test]
+    fn test_layer_captures_events() {
+        static BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+        let buffer = BUFFER.get_or_init(|| LogBuffer::new(8));
+        let subscriber = tracing_subscriber::registry().with(LogBufferLayer::new(buffer));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::error!(host = "node1", "fetch failed: {}", "timeout");
+        });
+
+        let records = buffer.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, LogLevel::Error);
+        assert_eq!(records[0].message, "fetch failed: timeout host=node1");
+        assert!(records[0].source.ends_with("utils::logging::tests"));
+
+        let json = serde_json::to_value(&records[0]).unwrap();
+        assert_eq!(json["level"], "ERROR");
+        assert!(json["timestamp"].as_str().unwrap().contains('T'));
+    }
+}
@@ -12,19 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod build_info;
 pub mod command_timeout;
 pub mod disk_filter;
+pub mod logging;
+pub mod process_signal;
 pub mod profiling;
 pub mod runtime_environment;
 pub mod system;
+#[cfg(test)]
 pub mod test_helpers;
 pub mod units;
 
 pub use command_timeout::run_command_fast_fail;
-pub use disk_filter::filter_docker_aware_disks;
+pub use disk_filter::{filter_docker_aware_disks, set_mount_patterns, MountPatterns};
 pub use profiling::StartupProfiler;
 pub use runtime_environment::{ContainerRuntime, RuntimeEnvironment};
 pub use system::*;
 #[cfg(target_os = "linux")]
 pub use units::khz_to_mhz;
-pub use units::{hz_to_mhz, millicelsius_to_celsius};
+pub use units::{
+    format_bytes, format_temperature, hz_to_mhz, millicelsius_to_celsius, set_temperature_unit,
+    set_unit_system, temperature_unit, unit_system, write_bytes_of, TemperatureUnit, UnitSystem,
+};
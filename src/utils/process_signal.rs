@@ -0,0 +1,142 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Terminating processes picked in the process pane (`k`/`K`).
+
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+/// Signal sent to a process
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Signal {
+    /// Ask the process to exit (`k`)
+    Terminate,
+    /// Kill the process outright (`K`)
+    Kill,
+}
+
+impl Signal {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Terminate => "SIGTERM",
+            Self::Kill => "SIGKILL",
+        }
+    }
+}
+
+/// Delivers signals to processes. The UI goes through this trait so the
+/// guard rails can be tested without touching real processes.
+pub trait SignalSender {
+    fn send(&self, pid: u32, signal: Signal) -> io::Result<()>;
+}
+
+/// Sends signals with `kill(2)` on Unix and `TerminateProcess` on Windows,
+/// where both signals end the process immediately.
+pub struct SystemSignalSender;
+
+impl SignalSender for SystemSignalSender {
+    #[cfg(unix)]
+    fn send(&self, pid: u32, signal: Signal) -> io::Result<()> {
+        let pid =
+            libc::pid_t::try_from(pid).map_err(|_| io::Error::from_raw_os_error(libc::ESRCH))?;
+        let signal = match signal {
+            Signal::Terminate => libc::SIGTERM,
+            Signal::Kill => libc::SIGKILL,
+        };
+        // SAFETY: kill(2) only reads its arguments; the guard rails keep pid
+        // positive so it never addresses a process group
+        if unsafe { libc::kill(pid, signal) } == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    #[cfg(windows)]
+    fn send(&self, pid: u32, _signal: Signal) -> io::Result<()> {
+        use windows::Win32::Foundation::CloseHandle;
+        use windows::Win32::System::Threading::{OpenProcess, TerminateProcess, PROCESS_TERMINATE};
+
+        // SAFETY: the handle is checked by OpenProcess and closed right after use
+        unsafe {
+            let handle = OpenProcess(PROCESS_TERMINATE, false, pid).map_err(io::Error::from)?;
+            let result = TerminateProcess(handle, 1).map_err(io::Error::from);
+            let _ = CloseHandle(handle);
+            result
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn send(&self, _pid: u32, _signal: Signal) -> io::Result<()> {
+        Err(io::Error::from(io::ErrorKind::Unsupported))
+    }
+}
+
+/// Why a signal is not sent at all
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignalRefusal {
+    /// PID 0 or 1, which would hit a process group or init
+    Init,
+    /// all-smi itself
+    OwnProcess,
+    /// The process list is older than two refresh cycles, or was never
+    /// collected live, so the PID may belong to another process by now
+    StaleList,
+}
+
+impl fmt::Display for SignalRefusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Init => write!(f, "refusing to signal PID 1"),
+            Self::OwnProcess => write!(f, "refusing to signal all-smi itself"),
+            Self::StaleList => write!(f, "process list is stale; wait for a refresh"),
+        }
+    }
+}
+
+/// Check the guard rails for signaling `pid`. `list_age` is how long ago the
+/// process list was collected, `None` when it was not collected live.
+pub fn check_target(
+    pid: u32,
+    own_pid: u32,
+    list_age: Option<Duration>,
+    max_age: Duration,
+) -> Result<(), SignalRefusal> {
+    if pid <= 1 {
+        return Err(SignalRefusal::Init);
+    }
+    if pid == own_pid {
+        return Err(SignalRefusal::OwnProcess);
+    }
+    match list_age {
+        Some(age) if age <= max_age => Ok(()),
+        _ => Err(SignalRefusal::StaleList),
+    }
+}
+
+/// Status line text for a failed signal.
+pub fn describe_error(error: &io::Error, owner: &str) -> String {
+    match error.kind() {
+        io::ErrorKind::PermissionDenied => {
+            format!("permission denied: process belongs to {owner}; run all-smi as root")
+        }
+        io::ErrorKind::NotFound => "no such process".to_string(),
+        _ => match error.raw_os_error() {
+            #[cfg(unix)]
+            Some(libc::ESRCH) => "no such process".to_string(),
+            _ => error.to_string(),
+        },
+    }
+}
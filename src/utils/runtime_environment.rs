@@ -16,8 +16,24 @@ use crate::common::config::AppConfig;
 use crossterm::style::Color;
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::OnceLock;
+
+/// File whose existence marks this node for maintenance (`--drain-file`)
+static DRAIN_FILE: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Environment variable that marks this node for maintenance when truthy
+pub const DRAIN_ENV_VAR: &str = "ALL_SMI_DRAINING";
+
+/// Backend.AI agent label; `false` means the scheduler no longer places
+/// sessions on the node
+pub const BACKEND_AI_SCHEDULABLE_VAR: &str = "BACKENDAI_SCHEDULABLE";
+
+/// Watch `path` for the rest of the run; while it exists the node is draining
+pub fn set_drain_file(path: Option<PathBuf>) {
+    let _ = DRAIN_FILE.set(path);
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ContainerRuntime {
@@ -616,6 +632,97 @@ fn check_aws_metadata() -> bool {
     }
 }
 
+/// Backend.AI compute session all-smi runs in, from the variables the agent
+/// sets in each kernel container
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackendAiSession {
+    pub session_id: Option<String>,
+    pub kernel_id: Option<String>,
+    /// `main` or `sub` in multi-container sessions
+    pub cluster_role: Option<String>,
+}
+
+impl BackendAiSession {
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let read = |name: &str| {
+            var(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Self {
+            session_id: read("BACKENDAI_SESSION_ID"),
+            kernel_id: read("BACKENDAI_KERNEL_ID").or_else(|| read("BACKEND_AI_KERNEL_ID")),
+            cluster_role: read("BACKENDAI_CLUSTER_ROLE"),
+        }
+    }
+
+    /// `session_id`, `kernel_id` and `cluster_role` labels for the
+    /// variables that are set; empty outside Backend.AI
+    pub fn labels(&self) -> Vec<(&'static str, String)> {
+        [
+            ("session_id", &self.session_id),
+            ("kernel_id", &self.kernel_id),
+            ("cluster_role", &self.cluster_role),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| Some((label, value.clone()?)))
+        .collect()
+    }
+}
+
+/// What marked a node for maintenance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrainSource {
+    /// The `--drain-file` exists
+    File(PathBuf),
+    /// `ALL_SMI_DRAINING` is set
+    Env,
+    /// Backend.AI marked the agent unschedulable
+    BackendAI,
+}
+
+impl DrainSource {
+    pub fn describe(&self) -> String {
+        match self {
+            DrainSource::File(path) => format!("drain file {}", path.display()),
+            DrainSource::Env => format!("{DRAIN_ENV_VAR} is set"),
+            DrainSource::BackendAI => "Backend.AI agent is not schedulable".to_string(),
+        }
+    }
+}
+
+/// Whether an environment value switches a flag on: anything but empty,
+/// `0`, `false`, `no` or `off`
+fn is_truthy(value: &str) -> bool {
+    !matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "" | "0" | "false" | "no" | "off"
+    )
+}
+
+/// First drain marker found, checking the file, then the environment, then
+/// the Backend.AI label (only inside Backend.AI)
+fn find_drain_marker(
+    drain_file: Option<&Path>,
+    env_value: Option<&str>,
+    schedulable: Option<&str>,
+) -> Option<DrainSource> {
+    if let Some(path) = drain_file.filter(|path| path.exists()) {
+        return Some(DrainSource::File(path.to_path_buf()));
+    }
+    if env_value.is_some_and(is_truthy) {
+        return Some(DrainSource::Env);
+    }
+    if schedulable.is_some_and(|value| !is_truthy(value)) {
+        return Some(DrainSource::BackendAI);
+    }
+    None
+}
+
 #[derive(Debug, Clone)]
 pub struct RuntimeEnvironment {
     pub container: ContainerInfo,
@@ -660,6 +767,22 @@ impl RuntimeEnvironment {
         self.container.runtime == ContainerRuntime::BackendAI
     }
 
+    /// Why this node is marked for maintenance, `None` while in service.
+    /// Checked on every call so that creating or removing the drain file
+    /// takes effect on the next refresh.
+    pub fn draining(&self) -> Option<DrainSource> {
+        let schedulable = if self.is_backend_ai() {
+            env::var(BACKEND_AI_SCHEDULABLE_VAR).ok()
+        } else {
+            None
+        };
+        find_drain_marker(
+            DRAIN_FILE.get().and_then(Option::as_deref),
+            env::var(DRAIN_ENV_VAR).ok().as_deref(),
+            schedulable.as_deref(),
+        )
+    }
+
     /// Get Backend.AI cluster hosts from environment variable
     /// Returns a list of host URLs constructed from BACKENDAI_CLUSTER_HOSTS
     pub fn get_backend_ai_hosts(&self) -> Option<Vec<String>> {
@@ -722,4 +845,67 @@ mod tests {
         };
         assert!(!info.is_containerized());
     }
+
+    #[test]
+    fn test_find_drain_marker() {
+        assert_eq!(find_drain_marker(None, None, None), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("drain");
+        // A drain file that does not exist yet leaves the node in service
+        assert_eq!(find_drain_marker(Some(&marker), None, None), None);
+        fs::write(&marker, "").unwrap();
+        assert_eq!(
+            find_drain_marker(Some(&marker), Some("1"), None),
+            Some(DrainSource::File(marker.clone()))
+        );
+
+        assert_eq!(
+            find_drain_marker(None, Some("yes"), None),
+            Some(DrainSource::Env)
+        );
+        assert_eq!(find_drain_marker(None, Some("0"), None), None);
+        assert_eq!(find_drain_marker(None, Some("false"), None), None);
+
+        assert_eq!(
+            find_drain_marker(None, None, Some("false")),
+            Some(DrainSource::BackendAI)
+        );
+        assert_eq!(find_drain_marker(None, None, Some("true")), None);
+    }
+
+    fn vars(pairs: &'static [(&'static str, &'static str)]) -> impl Fn(&str) -> Option<String> {
+        move |name| {
+            pairs
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_backend_ai_session_labels() {
+        let session = BackendAiSession::from_vars(vars(&[
+            ("BACKENDAI_SESSION_ID", "3f2a"),
+            ("BACKENDAI_KERNEL_ID", "9c1d"),
+            ("BACKENDAI_CLUSTER_ROLE", "main"),
+        ]));
+        assert_eq!(
+            session.labels(),
+            [
+                ("session_id", "3f2a".to_string()),
+                ("kernel_id", "9c1d".to_string()),
+                ("cluster_role", "main".to_string()),
+            ]
+        );
+
+        // Unset and empty variables leave their label out
+        let session = BackendAiSession::from_vars(vars(&[
+            ("BACKEND_AI_KERNEL_ID", "9c1d"),
+            ("BACKENDAI_CLUSTER_ROLE", " "),
+        ]));
+        assert_eq!(session.labels(), [("kernel_id", "9c1d".to_string())]);
+
+        assert!(BackendAiSession::from_vars(|_| None).labels().is_empty());
+    }
 }
@@ -12,11 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::process::Command;
+
+/// Check if the current process already has sudo privileges
+pub fn has_sudo_privileges() -> bool {
+    Command::new("sudo")
+        .arg("-n") // Non-interactive mode
+        .arg("-v") // Validate sudo timestamp
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
 /// Helper macro to skip tests that require sudo privileges
 #[macro_export]
 macro_rules! skip_without_sudo {
     () => {
-        if !$crate::utils::system::has_sudo_privileges() {
+        if !$crate::utils::test_helpers::has_sudo_privileges() {
             eprintln!("Test requires sudo privileges, skipping...");
             eprintln!("Run with: sudo cargo test -- --test-threads=1");
             return;
@@ -51,7 +63,7 @@ pub fn should_skip_sudo_tests() -> bool {
     // Skip if we don't have sudo privileges
     #[cfg(target_os = "macos")]
     {
-        !super::system::has_sudo_privileges()
+        !has_sudo_privileges()
     }
 
     #[cfg(not(target_os = "macos"))]
@@ -69,4 +81,21 @@ mod tests {
         // This should always run without requiring sudo
         let _result = should_skip_sudo_tests();
     }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn test_has_sudo_privileges_on_macos() {
+        // This test just checks if the function runs without error
+        // It doesn't require sudo itself
+        let _result = has_sudo_privileges();
+        // Function should execute without panicking and return a boolean
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_has_sudo_privileges_on_non_macos() {
+        let result = has_sudo_privileges();
+        // Result is always a boolean, so just verify it completes
+        let _ = result;
+    }
 }
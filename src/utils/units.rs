@@ -14,6 +14,201 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+
+/// How byte sizes are shown: in powers of 1024 or of 1000
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// KiB, MiB, GiB, TiB: powers of 1024, as the sizes are counted
+    #[default]
+    Binary,
+    /// KB, MB, GB, TB: powers of 1000, as disks and network links are sold
+    Decimal,
+}
+
+impl UnitSystem {
+    /// Parse a `ui.units` config value
+    pub fn parse(name: &str) -> Option<Self> {
+        <Self as ValueEnum>::from_str(name, true).ok()
+    }
+
+    /// Bytes per step from one unit to the next
+    pub fn step(self) -> f64 {
+        match self {
+            Self::Binary => 1024.0,
+            Self::Decimal => 1000.0,
+        }
+    }
+
+    fn units(self) -> &'static [&'static str] {
+        match self {
+            Self::Binary => &["B", "KiB", "MiB", "GiB", "TiB", "PiB"],
+            Self::Decimal => &["B", "KB", "MB", "GB", "TB", "PB"],
+        }
+    }
+
+    /// `bytes` in the largest unit that keeps it at 1.0 or more once
+    /// rounded to one decimal
+    pub fn scale(self, bytes: u64) -> (f64, &'static str) {
+        let units = self.units();
+        let step = self.step();
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while unit < units.len() - 1 {
+            // Past bytes, a value that would round to a full step moves up
+            let threshold = if unit == 0 { step } else { step - 0.05 };
+            if value < threshold {
+                break;
+            }
+            value /= step;
+            unit += 1;
+        }
+        (value, units[unit])
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_possible_value() {
+            Some(value) => f.write_str(value.get_name()),
+            None => Ok(()),
+        }
+    }
+}
+
+static SELECTED_UNIT_SYSTEM: OnceLock<UnitSystem> = OnceLock::new();
+
+/// Select the unit system for the rest of the run. Only the first call has
+/// an effect.
+pub fn set_unit_system(system: UnitSystem) {
+    let _ = SELECTED_UNIT_SYSTEM.set(system);
+}
+
+/// The unit system sizes are shown in; binary until one is selected.
+pub fn unit_system() -> UnitSystem {
+    SELECTED_UNIT_SYSTEM.get().copied().unwrap_or_default()
+}
+
+/// `bytes` in the selected unit system, e.g. `1.5GiB` or `512B`
+pub fn format_bytes(bytes: u64) -> String {
+    format_bytes_in(bytes, unit_system())
+}
+
+/// `bytes` in `system`: whole bytes below one kilo-unit, one decimal above
+pub fn format_bytes_in(bytes: u64, system: UnitSystem) -> String {
+    let mut out = String::new();
+    let _ = write_bytes(&mut out, bytes, system);
+    out
+}
+
+/// [`format_bytes_in`] without allocating
+pub fn write_bytes(out: &mut impl fmt::Write, bytes: u64, system: UnitSystem) -> fmt::Result {
+    match system.scale(bytes) {
+        (value, unit @ "B") => write!(out, "{value:.0}{unit}"),
+        (value, unit) => write!(out, "{value:.1}{unit}"),
+    }
+}
+
+/// `used/total` in `system` and the unit of `total`, e.g. `25.2/80.0GiB`
+pub fn write_bytes_of(
+    out: &mut impl fmt::Write,
+    used: u64,
+    total: u64,
+    system: UnitSystem,
+) -> fmt::Result {
+    let (total_value, unit) = system.scale(total);
+    // The step power `total` was divided by
+    let divisor = if total_value > 0.0 {
+        total as f64 / total_value
+    } else {
+        1.0
+    };
+    let used_value = used as f64 / divisor;
+    if unit == "B" {
+        write!(out, "{used_value:.0}/{total_value:.0}{unit}")
+    } else {
+        write!(out, "{used_value:.1}/{total_value:.1}{unit}")
+    }
+}
+
+/// How temperatures are shown. Readers and metrics always use Celsius.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    /// Degrees Celsius
+    #[default]
+    #[value(name = "c", alias = "celsius")]
+    Celsius,
+    /// Degrees Fahrenheit
+    #[value(name = "f", alias = "fahrenheit")]
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Parse a `ui.temp_unit` config value
+    pub fn parse(name: &str) -> Option<Self> {
+        <Self as ValueEnum>::from_str(name, true).ok()
+    }
+
+    /// A temperature in degrees Celsius, in this unit
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// A temperature difference in degrees Celsius, in this unit
+    pub fn convert_difference(self, celsius: f64) -> f64 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_possible_value() {
+            Some(value) => f.write_str(value.get_name()),
+            None => Ok(()),
+        }
+    }
+}
+
+static SELECTED_TEMPERATURE_UNIT: OnceLock<TemperatureUnit> = OnceLock::new();
+
+/// Select the temperature unit for the rest of the run. Only the first call
+/// has an effect.
+pub fn set_temperature_unit(unit: TemperatureUnit) {
+    let _ = SELECTED_TEMPERATURE_UNIT.set(unit);
+}
+
+/// The unit temperatures are shown in; Celsius until one is selected.
+pub fn temperature_unit() -> TemperatureUnit {
+    SELECTED_TEMPERATURE_UNIT.get().copied().unwrap_or_default()
+}
+
+/// `celsius` in the selected unit, rounded to whole degrees, e.g. `72°C`
+/// or `162°F`
+pub fn format_temperature(celsius: f64) -> String {
+    format_temperature_in(celsius, temperature_unit())
+}
+
+/// [`format_temperature`] in `unit`
+pub fn format_temperature_in(celsius: f64, unit: TemperatureUnit) -> String {
+    format!("{:.0}{}", unit.convert(celsius), unit.symbol())
+}
+
 /// Convert kilohertz to megahertz
 #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
 #[inline]
@@ -53,6 +248,70 @@ mod tests {
         assert_eq!(hz_to_mhz(0), 0);
     }
 
+    const KIB: u64 = 1 << 10;
+    const MIB: u64 = 1 << 20;
+    const GIB: u64 = 1 << 30;
+    const TIB: u64 = 1 << 40;
+
+    #[test]
+    fn test_format_bytes_binary() {
+        let format = |bytes| format_bytes_in(bytes, UnitSystem::Binary);
+        assert_eq!(format(0), "0B");
+        assert_eq!(format(1023), "1023B");
+        assert_eq!(format(KIB), "1.0KiB");
+        assert_eq!(format(1023 * MIB), "1023.0MiB");
+        assert_eq!(format(GIB), "1.0GiB");
+        assert_eq!(format(GIB + GIB / 2), "1.5GiB");
+        assert_eq!(format(TIB), "1.0TiB");
+        // Rounding up to 1024.0 moves to the next unit
+        assert_eq!(format(TIB - MIB), "1.0TiB");
+        assert_eq!(format(80 * GIB), "80.0GiB");
+    }
+
+    #[test]
+    fn test_format_bytes_decimal() {
+        let format = |bytes| format_bytes_in(bytes, UnitSystem::Decimal);
+        assert_eq!(format(0), "0B");
+        assert_eq!(format(999), "999B");
+        assert_eq!(format(1000), "1.0KB");
+        assert_eq!(format(1023 * MIB), "1.1GB");
+        assert_eq!(format(999_000_000), "999.0MB");
+        assert_eq!(format(999_990_000), "1.0GB");
+        assert_eq!(format(1_000_000_000_000), "1.0TB");
+        assert_eq!(format(TIB), "1.1TB");
+        assert_eq!(format(80 * GIB), "85.9GB");
+    }
+
+    #[test]
+    fn test_write_bytes_of() {
+        let format = |used, total, system| {
+            let mut out = String::new();
+            write_bytes_of(&mut out, used, total, system).unwrap();
+            out
+        };
+        assert_eq!(
+            format(25 * GIB + GIB / 5, 80 * GIB, UnitSystem::Binary),
+            "25.2/80.0GiB"
+        );
+        assert_eq!(
+            format(512 * MIB, 80 * GIB, UnitSystem::Binary),
+            "0.5/80.0GiB"
+        );
+        assert_eq!(
+            format(40_000_000_000, 80_000_000_000, UnitSystem::Decimal),
+            "40.0/80.0GB"
+        );
+        assert_eq!(format(0, 0, UnitSystem::Binary), "0/0B");
+    }
+
+    #[test]
+    fn test_parse_unit_system() {
+        assert_eq!(UnitSystem::parse("decimal"), Some(UnitSystem::Decimal));
+        assert_eq!(UnitSystem::parse("Binary"), Some(UnitSystem::Binary));
+        assert_eq!(UnitSystem::parse("si"), None);
+        assert_eq!(UnitSystem::Decimal.to_string(), "decimal");
+    }
+
     #[test]
     fn test_millicelsius_to_celsius() {
         assert_eq!(millicelsius_to_celsius(1000), 1);
@@ -60,4 +319,27 @@ mod tests {
         assert_eq!(millicelsius_to_celsius(100500), 100);
         assert_eq!(millicelsius_to_celsius(0), 0);
     }
+
+    #[test]
+    fn test_temperature_units() {
+        let fahrenheit = TemperatureUnit::Fahrenheit;
+        assert_eq!(fahrenheit.convert(0.0), 32.0);
+        assert_eq!(fahrenheit.convert(100.0), 212.0);
+        assert_eq!(fahrenheit.convert(-40.0), -40.0);
+        // A spread of degrees has no offset
+        assert_eq!(fahrenheit.convert_difference(5.0), 9.0);
+        assert_eq!(format_temperature_in(72.0, fahrenheit), "162°F");
+        assert_eq!(
+            format_temperature_in(72.4, TemperatureUnit::Celsius),
+            "72°C"
+        );
+
+        assert_eq!(TemperatureUnit::parse("F"), Some(fahrenheit));
+        assert_eq!(
+            TemperatureUnit::parse("celsius"),
+            Some(TemperatureUnit::Celsius)
+        );
+        assert_eq!(TemperatureUnit::parse("kelvin"), None);
+        assert_eq!(fahrenheit.to_string(), "f");
+    }
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Instant;
+
 use crate::app_state::AppState;
 use crate::common::config::AppConfig;
 
@@ -30,6 +32,13 @@ impl DataAggregator {
 
         // Update GPU history if we have GPU data OR if we're on Apple Silicon
         self.update_gpu_history(state);
+
+        if let Some(ema) = state.utilization_ema.as_mut() {
+            ema.update(&state.gpu_info);
+        }
+        if let Some(highlight) = state.change_highlight.as_mut() {
+            highlight.update(&state.gpu_info, &state.process_info, Instant::now());
+        }
     }
 
     fn update_cpu_history(&self, state: &mut AppState) {
@@ -0,0 +1,75 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How long recent remote fetch rounds took, for the adaptive interval.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Fetch rounds the p95 is taken over
+const LATENCY_WINDOW: usize = 10;
+
+/// Durations of the most recent fetch rounds
+#[derive(Debug, Default)]
+pub struct FetchLatencies {
+    samples: VecDeque<Duration>,
+}
+
+impl FetchLatencies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a round, forgetting the oldest once the window is full.
+    pub fn record(&mut self, latency: Duration) {
+        if self.samples.len() == LATENCY_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency);
+    }
+
+    /// Nearest-rank 95th percentile of the recorded rounds, `None` before
+    /// the first one.
+    pub fn p95(&self) -> Option<Duration> {
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort_unstable();
+        let rank = (sorted.len() as f64 * 0.95).ceil() as usize;
+        sorted.get(rank.checked_sub(1)?).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p95_over_window() {
+        let mut latencies = FetchLatencies::new();
+        assert_eq!(latencies.p95(), None);
+
+        for ms in [100, 200, 300] {
+            latencies.record(Duration::from_millis(ms));
+        }
+        assert_eq!(latencies.p95(), Some(Duration::from_millis(300)));
+
+        // One slow round among ten is the p95; once it leaves the window
+        // the fast rounds take over again
+        latencies.record(Duration::from_secs(7));
+        assert_eq!(latencies.p95(), Some(Duration::from_secs(7)));
+        for _ in 0..LATENCY_WINDOW {
+            latencies.record(Duration::from_millis(500));
+        }
+        assert_eq!(latencies.p95(), Some(Duration::from_millis(500)));
+    }
+}
@@ -0,0 +1,439 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Folding endpoints that reach the same node into one.
+//!
+//! A node listed twice, say by IP and by DNS name, answers on both addresses
+//! with the same `instance` label and GPU UUIDs. Such addresses share one tab,
+//! named after whichever comes first in the host list, and its data comes
+//! from whichever answered last.
+//!
+//! Endpoints bonded in the hostfile report different parts of one node, so
+//! the node shows the devices and mounts of all of them. A device or mount
+//! reported twice is taken from the endpoint listed later in the bond; the
+//! rest of the node's data comes from the last listed endpoint that answered.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::app_state::{ConnectionStatus, HostInfo};
+use crate::device::GpuInfo;
+
+use super::hostfile::HostBond;
+
+/// Group the addresses in `hosts` by the instance they last reported. Only
+/// nodes reached through more than one address are returned, keyed by the
+/// first of them.
+pub fn group_by_instance(
+    statuses: &HashMap<String, ConnectionStatus>,
+    hosts: &[String],
+) -> HashMap<String, HostInfo> {
+    let mut groups: Vec<HostInfo> = Vec::new();
+    let mut group_of: HashMap<&str, usize> = HashMap::new();
+    for host in hosts {
+        let Some(instance) = statuses
+            .get(host)
+            .and_then(|status| status.actual_hostname.as_deref())
+        else {
+            continue;
+        };
+        match group_of.get(instance) {
+            Some(&index) => groups[index].addresses.push(host.clone()),
+            None => {
+                group_of.insert(instance, groups.len());
+                groups.push(HostInfo {
+                    instance: instance.to_string(),
+                    addresses: vec![host.clone()],
+                    bonded: false,
+                });
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|info| info.addresses.len() > 1)
+        .map(|info| (info.addresses[0].clone(), info))
+        .collect()
+}
+
+/// One merged node per bond, keyed by the bond's name
+pub fn bond_info(bonds: &[HostBond]) -> HashMap<String, HostInfo> {
+    bonds
+        .iter()
+        .map(|bond| {
+            let info = HostInfo {
+                instance: bond.name.clone(),
+                addresses: bond.endpoints.clone(),
+                bonded: true,
+            };
+            (bond.name.clone(), info)
+        })
+        .collect()
+}
+
+/// The bond and position within it of every bonded endpoint
+pub struct BondOrder {
+    of: HashMap<String, (String, usize)>,
+}
+
+impl BondOrder {
+    pub fn new(bonds: &[HostBond]) -> Self {
+        let of = bonds
+            .iter()
+            .flat_map(|bond| {
+                bond.endpoints
+                    .iter()
+                    .enumerate()
+                    .map(|(position, endpoint)| (endpoint.clone(), (bond.name.clone(), position)))
+            })
+            .collect();
+        Self { of }
+    }
+
+    /// Name of the bond `host` is an endpoint of
+    pub fn bond_of(&self, host: &str) -> Option<&str> {
+        self.of.get(host).map(|(name, _)| name.as_str())
+    }
+
+    /// Move records of a bond's later endpoints after those of its earlier
+    /// ones, so that keeping the last copy of a duplicate keeps theirs.
+    /// Records of other hosts keep their relative order.
+    pub fn sort<T>(&self, records: &mut [T], host_id: impl Fn(&T) -> &str) {
+        if self.of.is_empty() {
+            return;
+        }
+        records.sort_by_key(|record| self.of.get(host_id(record)).map_or(0, |(_, pos)| *pos));
+    }
+}
+
+/// Which address speaks for each merged node in one collection cycle.
+pub struct EndpointMerge {
+    /// Address of a merged node -> the tab showing that node
+    tab_of: HashMap<String, String>,
+    /// Tab -> the node's address that answered last, or for a bond the last
+    /// listed endpoint that answered
+    source: HashMap<String, String>,
+    /// Tabs of bonds
+    bonded: HashSet<String>,
+}
+
+impl EndpointMerge {
+    /// `responders` are the addresses that answered this cycle, in the order
+    /// their responses arrived.
+    pub fn new(host_info: &HashMap<String, HostInfo>, responders: &[String]) -> Self {
+        let tab_of: HashMap<String, String> = host_info
+            .iter()
+            .flat_map(|(tab, info)| {
+                info.addresses
+                    .iter()
+                    .map(move |address| (address.clone(), tab.clone()))
+            })
+            .collect();
+        let mut source = HashMap::new();
+        for responder in responders {
+            if let Some(tab) = tab_of.get(responder) {
+                source.insert(tab.clone(), responder.clone());
+            }
+        }
+        let mut bonded = HashSet::new();
+        for (tab, info) in host_info.iter().filter(|(_, info)| info.bonded) {
+            bonded.insert(tab.clone());
+            match info
+                .addresses
+                .iter()
+                .rfind(|address| responders.contains(address))
+            {
+                Some(address) => source.insert(tab.clone(), address.clone()),
+                None => source.remove(tab),
+            };
+        }
+        Self {
+            tab_of,
+            source,
+            bonded,
+        }
+    }
+
+    /// Drop records that another address of the same node answered for
+    /// later, and file the rest under the node's tab.
+    pub fn apply<T>(&self, records: Vec<T>, host_id: impl Fn(&mut T) -> &mut String) -> Vec<T> {
+        self.merge(records, host_id, false)
+    }
+
+    /// Like `apply`, but a bond keeps the records of all its endpoints; for
+    /// devices and mounts, which each endpoint reports a share of.
+    pub fn union<T>(&self, records: Vec<T>, host_id: impl Fn(&mut T) -> &mut String) -> Vec<T> {
+        self.merge(records, host_id, true)
+    }
+
+    fn merge<T>(
+        &self,
+        records: Vec<T>,
+        host_id: impl Fn(&mut T) -> &mut String,
+        union: bool,
+    ) -> Vec<T> {
+        if self.tab_of.is_empty() {
+            return records;
+        }
+        records
+            .into_iter()
+            .filter_map(|mut record| {
+                let address = host_id(&mut record);
+                if let Some(tab) = self.tab_of.get(address.as_str()) {
+                    let keep_all = union && self.bonded.contains(tab);
+                    if !keep_all && self.source.get(tab) != Some(address) {
+                        return None;
+                    }
+                    *address = tab.clone();
+                }
+                Some(record)
+            })
+            .collect()
+    }
+}
+
+/// Whether `uuid` is a stand-in that distinct devices may share, such as an
+/// empty string, "N/A" or all zeros
+fn is_placeholder_uuid(uuid: &str) -> bool {
+    let uuid = uuid.trim();
+    matches!(
+        uuid.to_ascii_lowercase().as_str(),
+        "n/a" | "na" | "none" | "null" | "unknown"
+    ) || uuid.chars().all(|c| !c.is_ascii_alphanumeric() || c == '0')
+}
+
+/// Drop GPUs that more than one endpoint reported, keeping the copy from the
+/// endpoint that answered last; `gpus` is in response order. A placeholder
+/// UUID, or one that repeats within one endpoint's payload, cannot tell
+/// devices apart, so those devices are keyed by endpoint and position
+/// instead.
+pub fn dedup_gpus(gpus: Vec<GpuInfo>) -> Vec<GpuInfo> {
+    let mut uuid_counts: HashMap<(&str, &str), usize> = HashMap::new();
+    for gpu in &gpus {
+        *uuid_counts
+            .entry((gpu.host_id.as_str(), gpu.uuid.trim()))
+            .or_default() += 1;
+    }
+
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    let keys: Vec<String> = gpus
+        .iter()
+        .map(|gpu| {
+            let position = positions.entry(gpu.host_id.as_str()).or_default();
+            *position += 1;
+            let uuid = gpu.uuid.trim();
+            if !is_placeholder_uuid(uuid) && uuid_counts[&(gpu.host_id.as_str(), uuid)] == 1 {
+                format!("uuid:{uuid}")
+            } else {
+                format!("host:{}#{position}", gpu.host_id)
+            }
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut kept: Vec<GpuInfo> = gpus
+        .into_iter()
+        .zip(keys)
+        .rev()
+        .filter(|(_, key)| seen.insert(key.clone()))
+        .map(|(gpu, _)| gpu)
+        .collect();
+    kept.reverse();
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::metrics_parser::MetricsParser;
+
+    fn payload(instance: &str, uuids: &[&str], utilization: f64) -> String {
+        uuids
+            .iter()
+            .enumerate()
+            .map(|(i, uuid)| {
+                format!(
+                    "all_smi_gpu_utilization{{gpu=\"H100\", instance=\"{instance}\", uuid=\"{uuid}\", index=\"{i}\"}} {utilization}\n\
+                     all_smi_gpu_memory_total_bytes{{gpu=\"H100\", instance=\"{instance}\", uuid=\"{uuid}\", index=\"{i}\"}} 85899345920\n"
+                )
+            })
+            .collect()
+    }
+
+    fn parse(text: &str, host: &str) -> Vec<GpuInfo> {
+        let mut gpus = MetricsParser::new().parse_metrics(text, host).0;
+        gpus.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+        gpus
+    }
+
+    fn status(host: &str, instance: &str) -> (String, ConnectionStatus) {
+        let mut status = ConnectionStatus::new(host.to_string(), host.to_string());
+        status.mark_success();
+        status.actual_hostname = Some(instance.to_string());
+        (host.to_string(), status)
+    }
+
+    fn hosts(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_same_uuid_from_two_addresses_is_one_device() {
+        let mut gpus = parse(&payload("node1", &["GPU-a"], 10.0), "10.0.0.1:9090");
+        gpus.extend(parse(&payload("node1", &["GPU-a"], 90.0), "node1:9090"));
+
+        let gpus = dedup_gpus(gpus);
+        assert_eq!(gpus.len(), 1);
+        // The later response wins
+        assert_eq!(gpus[0].host_id, "node1:9090");
+        assert_eq!(gpus[0].utilization, 90.0);
+    }
+
+    #[test]
+    fn test_bogus_uuids_fall_back_to_host_and_index() {
+        // Placeholder UUIDs repeated by different nodes are distinct devices
+        let zeros = "00000000-0000-0000-0000-000000000000";
+        let mut gpus = parse(&payload("node1", &["N/A", zeros], 10.0), "node1:9090");
+        gpus.extend(parse(
+            &payload("node2", &["N/A", zeros], 20.0),
+            "node2:9090",
+        ));
+        gpus.extend(parse(&payload("node3", &["GPU-c"], 30.0), "node3:9090"));
+        assert_eq!(dedup_gpus(gpus.clone()).len(), 5);
+
+        // So are devices sharing a UUID within one payload
+        let mut twins = gpus[4].clone();
+        twins.utilization = 40.0;
+        gpus.push(twins);
+        assert_eq!(dedup_gpus(gpus).len(), 6);
+    }
+
+    #[test]
+    fn test_group_by_instance() {
+        let statuses: HashMap<_, _> = [
+            status("node1:9090", "node1"),
+            status("10.0.0.2:9090", "node2"),
+            status("10.0.0.1:9090", "node1"),
+        ]
+        .into_iter()
+        .collect();
+        let groups = group_by_instance(
+            &statuses,
+            &hosts(&["10.0.0.1:9090", "10.0.0.2:9090", "node1:9090", "node4:9090"]),
+        );
+
+        assert_eq!(groups.len(), 1);
+        let info = &groups["10.0.0.1:9090"];
+        assert_eq!(info.instance, "node1");
+        assert_eq!(info.addresses, hosts(&["10.0.0.1:9090", "node1:9090"]));
+    }
+
+    #[test]
+    fn test_merge_keeps_latest_responder_under_first_address() {
+        let statuses: HashMap<_, _> = [
+            status("10.0.0.1:9090", "node1"),
+            status("node1:9090", "node1"),
+        ]
+        .into_iter()
+        .collect();
+        let groups = group_by_instance(
+            &statuses,
+            &hosts(&["10.0.0.1:9090", "node1:9090", "node2:9090"]),
+        );
+
+        let mut gpus = parse(&payload("node1", &["GPU-a"], 90.0), "node1:9090");
+        gpus.extend(parse(&payload("node1", &["GPU-a"], 10.0), "10.0.0.1:9090"));
+        gpus.extend(parse(&payload("node2", &["GPU-b"], 50.0), "node2:9090"));
+
+        // node1:9090 answered after 10.0.0.1:9090
+        let merge = EndpointMerge::new(
+            &groups,
+            &hosts(&["node2:9090", "10.0.0.1:9090", "node1:9090"]),
+        );
+        let gpus = merge.apply(gpus, |gpu| &mut gpu.host_id);
+        assert_eq!(gpus.len(), 2);
+        assert_eq!(gpus[0].host_id, "10.0.0.1:9090");
+        assert_eq!(gpus[0].utilization, 90.0);
+        assert_eq!(gpus[1].host_id, "node2:9090");
+    }
+
+    fn bond(name: &str, endpoints: &[&str]) -> HostBond {
+        HostBond {
+            name: name.to_string(),
+            endpoints: hosts(endpoints),
+        }
+    }
+
+    #[test]
+    fn test_bond_unions_devices_of_its_endpoints() {
+        let bonds = [bond("nodeA", &["nodeA:9090", "nodeA:9091"])];
+        // The container's exporter sees one of the host's GPUs, with another
+        // reading of it, and a GPU of its own
+        let mut gpus = parse(
+            &payload("nodeA-ctr", &["GPU-b", "GPU-c"], 70.0),
+            "nodeA:9091",
+        );
+        gpus.extend(parse(
+            &payload("nodeA", &["GPU-a", "GPU-b"], 10.0),
+            "nodeA:9090",
+        ));
+        gpus.extend(parse(&payload("node2", &["GPU-d"], 50.0), "node2:9090"));
+
+        // The container answered first, yet its copy of GPU-b wins as the
+        // later endpoint of the bond
+        BondOrder::new(&bonds).sort(&mut gpus, |gpu| &gpu.host_id);
+        let gpus = dedup_gpus(gpus);
+        let merge = EndpointMerge::new(
+            &bond_info(&bonds),
+            &hosts(&["nodeA:9091", "nodeA:9090", "node2:9090"]),
+        );
+        let mut gpus = merge.union(gpus, |gpu| &mut gpu.host_id);
+        gpus.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+
+        let summary: Vec<_> = gpus
+            .iter()
+            .map(|gpu| (gpu.uuid.as_str(), gpu.host_id.as_str(), gpu.utilization))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("GPU-a", "nodeA", 10.0),
+                ("GPU-b", "nodeA", 70.0),
+                ("GPU-c", "nodeA", 70.0),
+                ("GPU-d", "node2:9090", 50.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bond_takes_host_data_from_last_listed_endpoint() {
+        let bonds = [bond("nodeA", &["nodeA:9090", "nodeA:9091"])];
+        let info = bond_info(&bonds);
+        let records = hosts(&["nodeA:9090", "nodeA:9091"]);
+
+        // Either order of answers picks the later endpoint
+        let merge = EndpointMerge::new(&info, &hosts(&["nodeA:9091", "nodeA:9090"]));
+        let kept = merge.apply(records.clone(), |host| host);
+        assert_eq!(kept, hosts(&["nodeA"]));
+        assert_eq!(merge.source["nodeA"], "nodeA:9091");
+
+        // Without it, the endpoint that did answer speaks for the node
+        let merge = EndpointMerge::new(&info, &hosts(&["nodeA:9090"]));
+        assert_eq!(merge.source["nodeA"], "nodeA:9090");
+        assert_eq!(
+            merge.union(records, |host| host),
+            hosts(&["nodeA", "nodeA"])
+        );
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading `--hostfile` lists and noticing when they change.
+//!
+//! Besides one address per line, a hostfile can bond several endpoints of
+//! one node under a name, for example a host exporter and one inside a
+//! container:
+//!
+//! ```text
+//! nodeA = http://nodeA:9090, http://nodeA:9091
+//! ```
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::SystemTime;
+
+const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10MB max
+/// Limit number of hosts to prevent memory exhaustion
+const MAX_HOSTS: usize = 1000;
+
+/// Drop a leading `http://` or `https://`.
+pub fn strip_scheme(host: &str) -> &str {
+    host.strip_prefix("http://")
+        .or_else(|| host.strip_prefix("https://"))
+        .unwrap_or(host)
+}
+
+/// A node served by several endpoints, shown as one tab named `name`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostBond {
+    pub name: String,
+    /// In hostfile order; later endpoints win when they report the same device
+    pub endpoints: Vec<String>,
+}
+
+/// One line of a hostfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostEntry {
+    Host(String),
+    Bond(HostBond),
+}
+
+/// The endpoints to scrape and the bonds among them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostList {
+    /// Every endpoint, bonded or not, without duplicates
+    pub hosts: Vec<String>,
+    pub bonds: Vec<HostBond>,
+}
+
+impl HostList {
+    /// The hosts as nodes: each bond is listed once by name, where its first
+    /// endpoint appears
+    pub fn nodes(&self) -> Vec<String> {
+        let mut nodes = Vec::new();
+        for host in &self.hosts {
+            let node = match self.bonds.iter().find(|bond| bond.endpoints.contains(host)) {
+                Some(bond) => &bond.name,
+                None => host,
+            };
+            if !nodes.contains(node) {
+                nodes.push(node.clone());
+            }
+        }
+        nodes
+    }
+}
+
+/// Whether `host` only has characters that can appear in a host address
+fn is_valid_host(host: &str) -> bool {
+    !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii() && (c.is_alphanumeric() || ".-:_@".contains(c)))
+}
+
+/// Parse a hostfile line: an address, or `name = endpoint, endpoint, ...`
+pub fn parse_entry(line: &str) -> Option<HostEntry> {
+    let Some((name, endpoints)) = line.split_once('=') else {
+        let host = strip_scheme(line.trim());
+        return is_valid_host(host).then(|| HostEntry::Host(host.to_string()));
+    };
+    let name = name.trim();
+    let endpoints: Vec<String> = endpoints
+        .split(',')
+        .map(|endpoint| strip_scheme(endpoint.trim()).to_string())
+        .collect();
+    if !is_valid_host(name) || !endpoints.iter().all(|e| is_valid_host(e)) {
+        return None;
+    }
+    Some(HostEntry::Bond(HostBond {
+        name: name.to_string(),
+        endpoints,
+    }))
+}
+
+/// Entries of a hostfile, one per line. Blank lines and lines starting with
+/// `#` are skipped, as are entries with characters that cannot appear in a
+/// host address.
+pub fn read_hostfile(file_path: &str) -> Result<Vec<HostEntry>, std::io::Error> {
+    // Resolve to absolute path and check it exists
+    let canonical_path = Path::new(file_path).canonicalize().map_err(|e| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("Invalid hostfile path: {e}"),
+        )
+    })?;
+
+    // Ensure it's a file, not a directory
+    if !canonical_path.is_file() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Hostfile path is not a regular file",
+        ));
+    }
+
+    // Check file size to prevent loading huge files
+    let metadata = std::fs::metadata(&canonical_path)?;
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "Hostfile too large: {} bytes (max: {MAX_FILE_SIZE} bytes)",
+                metadata.len()
+            ),
+        ));
+    }
+
+    let content = std::fs::read_to_string(&canonical_path)?;
+    let entries: Vec<&str> = content
+        .lines()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .filter(|s| !s.starts_with('#'))
+        .collect();
+    if entries.len() > MAX_HOSTS {
+        tracing::warn!("Hostfile contains more than {MAX_HOSTS} hosts, truncating");
+    }
+
+    Ok(entries
+        .into_iter()
+        .take(MAX_HOSTS)
+        .filter_map(|s| {
+            let entry = parse_entry(s);
+            if entry.is_none() {
+                tracing::warn!("Invalid host format skipped: {s}");
+            }
+            entry
+        })
+        .collect())
+}
+
+/// `--hosts` followed by the entries of every hostfile in order, without
+/// duplicates. The first occurrence of an endpoint decides its position and
+/// whether it belongs to a bond; a bond name used twice keeps the first.
+pub fn load_hosts(hosts: &[String], hostfiles: &[String]) -> Result<HostList, std::io::Error> {
+    let mut entries: Vec<HostEntry> = hosts
+        .iter()
+        .map(|h| HostEntry::Host(strip_scheme(h).to_string()))
+        .collect();
+    for file_path in hostfiles {
+        let file_entries = read_hostfile(file_path).map_err(|e| {
+            std::io::Error::new(
+                e.kind(),
+                format!("Error loading hosts from {file_path}: {e}"),
+            )
+        })?;
+        entries.extend(file_entries);
+    }
+
+    let mut list = HostList::default();
+    let mut seen = HashSet::new();
+    for entry in entries {
+        match entry {
+            HostEntry::Host(host) => {
+                if seen.insert(host.clone()) {
+                    list.hosts.push(host);
+                }
+            }
+            HostEntry::Bond(mut bond) => {
+                if list.bonds.iter().any(|other| other.name == bond.name) {
+                    tracing::warn!("Duplicate host bond skipped: {}", bond.name);
+                    continue;
+                }
+                bond.endpoints
+                    .retain(|endpoint| seen.insert(endpoint.clone()));
+                if bond.endpoints.is_empty() {
+                    continue;
+                }
+                list.hosts.extend(bond.endpoints.iter().cloned());
+                list.bonds.push(bond);
+            }
+        }
+    }
+    Ok(list)
+}
+
+/// Hosts added and removed between two host lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostChanges {
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl HostChanges {
+    pub fn between(old: &[String], new: &[String]) -> Self {
+        let old_set: HashSet<&String> = old.iter().collect();
+        let new_set: HashSet<&String> = new.iter().collect();
+        Self {
+            added: new_set.difference(&old_set).count(),
+            removed: old_set.difference(&new_set).count(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added == 0 && self.removed == 0
+    }
+}
+
+/// Polls the modification time and size of the hostfiles.
+pub struct HostfileWatcher {
+    paths: Vec<String>,
+    stamps: Vec<Option<(SystemTime, u64)>>,
+}
+
+impl HostfileWatcher {
+    /// Start watching from the files' current state.
+    pub fn new(paths: &[String]) -> Self {
+        Self {
+            paths: paths.to_vec(),
+            stamps: paths.iter().map(|p| Self::stamp(p)).collect(),
+        }
+    }
+
+    fn stamp(path: &str) -> Option<(SystemTime, u64)> {
+        let metadata = std::fs::metadata(path).ok()?;
+        Some((metadata.modified().ok()?, metadata.len()))
+    }
+
+    /// Whether any hostfile was modified, created or removed since the last call.
+    pub fn changed(&mut self) -> bool {
+        let stamps: Vec<_> = self.paths.iter().map(|p| Self::stamp(p)).collect();
+        let changed = stamps != self.stamps;
+        self.stamps = stamps;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_hostfile(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "all-smi-hostfile-{}-{name}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_hostfile_skips_comments_and_blank_lines() {
+        let path = temp_hostfile(
+            "read",
+            "# rack 1\n  node1:9090  \n\nhttp://node2:9090\n#node3:9090\nbad host!\n",
+        );
+        let hosts = read_hostfile(path.to_str().unwrap()).unwrap();
+        assert_eq!(
+            hosts,
+            vec![
+                HostEntry::Host("node1:9090".to_string()),
+                HostEntry::Host("node2:9090".to_string())
+            ]
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_hosts_merges_and_dedups() {
+        let first = temp_hostfile("merge-a", "node1:9090\nnode2:9090\n");
+        let second = temp_hostfile("merge-b", "https://node2:9090\nnode3:9090\nnode1:9090\n");
+        let hostfiles = vec![
+            first.to_string_lossy().to_string(),
+            second.to_string_lossy().to_string(),
+        ];
+
+        let hosts = load_hosts(&["http://node3:9090".to_string()], &hostfiles).unwrap();
+        assert_eq!(hosts.hosts, vec!["node3:9090", "node1:9090", "node2:9090"]);
+
+        assert!(load_hosts(&[], &["/nonexistent/hosts.txt".to_string()]).is_err());
+        std::fs::remove_file(first).unwrap();
+        std::fs::remove_file(second).unwrap();
+    }
+
+    #[test]
+    fn test_reload_after_edit() {
+        let path = temp_hostfile("reload", "node1:9090\nnode2:9090\nnode3:9090\n");
+        let hostfiles = vec![path.to_string_lossy().to_string()];
+        let before = load_hosts(&[], &hostfiles).unwrap().hosts;
+        let mut watcher = HostfileWatcher::new(&hostfiles);
+        assert!(!watcher.changed());
+
+        // The size changes too, so the edit is seen even within one mtime tick
+        std::fs::write(
+            &path,
+            "node1:9090\n# node2 drained\n\nnode3:9090\nnode4:9090\nnode5:9090\n",
+        )
+        .unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        let after = load_hosts(&[], &hostfiles).unwrap().hosts;
+        assert_eq!(
+            after,
+            vec!["node1:9090", "node3:9090", "node4:9090", "node5:9090"]
+        );
+        assert_eq!(
+            HostChanges::between(&before, &after),
+            HostChanges {
+                added: 2,
+                removed: 1
+            }
+        );
+        assert!(HostChanges::between(&after, &after).is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+
+    #[test]
+    fn test_parse_bond_entries() {
+        assert_eq!(
+            parse_entry("nodeA = http://nodeA:9090, http://nodeA:9091"),
+            Some(HostEntry::Bond(HostBond {
+                name: "nodeA".to_string(),
+                endpoints: vec!["nodeA:9090".to_string(), "nodeA:9091".to_string()],
+            }))
+        );
+        assert_eq!(
+            parse_entry("https://node1:9090"),
+            Some(HostEntry::Host("node1:9090".to_string()))
+        );
+        assert_eq!(parse_entry("nodeA = nodeA:9090, "), None);
+        assert_eq!(parse_entry(" = nodeA:9090"), None);
+        assert_eq!(parse_entry("node A = nodeA:9090"), None);
+    }
+
+    #[test]
+    fn test_load_hosts_with_bonds() {
+        let path = temp_hostfile(
+            "bonds",
+            "node1:9090\n\
+             nodeA = http://nodeA:9090, http://nodeA:9091\n\
+             nodeB = node1:9090, nodeB:9090\n\
+             nodeA = nodeC:9090\n",
+        );
+        let list = load_hosts(&[], &[path.to_string_lossy().to_string()]).unwrap();
+        assert_eq!(
+            list.hosts,
+            vec!["node1:9090", "nodeA:9090", "nodeA:9091", "nodeB:9090"]
+        );
+        // node1:9090 was listed on its own first, and nodeA is taken
+        assert_eq!(list.bonds.len(), 2);
+        assert_eq!(list.bonds[1].endpoints, vec!["nodeB:9090"]);
+        assert_eq!(list.nodes(), vec!["node1:9090", "nodeA", "nodeB"]);
+        std::fs::remove_file(path).unwrap();
+    }
+}
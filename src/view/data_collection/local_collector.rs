@@ -16,24 +16,30 @@ use async_trait::async_trait;
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use sysinfo::Disks;
-use tokio::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::time::timeout;
 
 /// Type alias for the process cache using std::sync::RwLock for synchronous access
 type ProcessCache = std::sync::RwLock<HashMap<u32, ProcessInfo>>;
 
-use crate::app_state::AppState;
+/// Startup status updates: (index of the subsystem line, new text)
+type StatusSender = mpsc::Sender<(usize, String)>;
+
+use crate::app_state::{AppState, STALE_KEY};
+use crate::device::device_order::stamp_indices;
 #[cfg(target_os = "linux")]
 use crate::device::platform_detection::has_tenstorrent;
 use crate::device::{
-    create_chassis_reader, get_cpu_readers, get_gpu_readers, get_memory_readers,
-    get_nvml_status_message,
+    create_chassis_reader, get_memory_readers, get_nvml_status_message,
+    gpu_presence::GpuPresence,
+    k8s_allocation::annotate_gpu_allocation,
+    numa::annotate_gpu_topology,
     platform_detection::has_nvidia,
     process_list::{merge_gpu_processes, update_process_cache},
-    ChassisInfo, ChassisReader, CpuInfo, CpuReader, GpuInfo, GpuReader, MemoryInfo, MemoryReader,
-    ProcessInfo,
+    reader_supervisor::{Collected, ReaderSupervisor},
+    ChassisInfo, ChassisReader, CollectionFilter, CpuInfo, CpuReader, GpuInfo, GpuReader,
+    MemoryInfo, MemoryReader, ProcessInfo,
 };
 
 #[cfg(target_os = "linux")]
@@ -42,12 +48,14 @@ use crate::device::get_tenstorrent_status_message;
 use crate::device::get_tpu_status_message;
 #[cfg(target_os = "linux")]
 use crate::device::platform_detection::has_google_tpu;
-use crate::storage::info::StorageInfo;
-use crate::utils::{filter_docker_aware_disks, get_hostname, with_global_system};
+use crate::network::NetworkReader;
+use crate::storage::{create_storage_reader, StorageReader};
+use crate::utils::{get_hostname, with_global_system};
 
 use super::aggregator::DataAggregator;
 use super::strategy::{
-    CollectionConfig, CollectionData, CollectionError, CollectionResult, DataCollectionStrategy,
+    CategoryTiming, CollectionCategory, CollectionConfig, CollectionData, CollectionError,
+    CollectionResult, DataCollectionStrategy,
 };
 
 /// Maximum number of processes to keep after collection.
@@ -60,10 +68,19 @@ const MAX_DISPLAY_PROCESSES: usize = 500;
 const FULL_REFRESH_INTERVAL: u32 = 5;
 
 pub struct LocalCollector {
-    gpu_readers: Arc<RwLock<Vec<Box<dyn GpuReader>>>>,
-    cpu_readers: Arc<RwLock<Vec<Box<dyn CpuReader>>>>,
-    memory_readers: Arc<RwLock<Vec<Box<dyn MemoryReader>>>>,
-    chassis_reader: Arc<RwLock<Option<Box<dyn ChassisReader>>>>,
+    /// Subsystems enabled on the command line
+    filter: CollectionFilter,
+    gpu_readers: RwLock<ReaderSupervisor<dyn GpuReader>>,
+    cpu_readers: RwLock<ReaderSupervisor<dyn CpuReader>>,
+    memory_readers: RwLock<ReaderSupervisor<dyn MemoryReader>>,
+    /// Readers quarantined since the state was last updated, shown as
+    /// warnings
+    quarantined: std::sync::Mutex<Vec<String>>,
+    chassis_readers: RwLock<ReaderSupervisor<dyn ChassisReader>>,
+    /// Mounted filesystems, none with --disk off
+    storage_readers: ReaderSupervisor<dyn StorageReader>,
+    /// Devices seen earlier in the run, shown as lost once they vanish
+    gpu_presence: Arc<std::sync::Mutex<GpuPresence>>,
     aggregator: DataAggregator,
     initialized: Arc<Mutex<bool>>,
     /// PIDs of processes from the previous collection cycle (top N by CPU usage).
@@ -75,15 +92,27 @@ pub struct LocalCollector {
     /// On each collection, existing objects are updated in place rather than reallocated.
     /// Uses std::sync::RwLock for synchronous access within with_global_system closure.
     process_cache: Arc<ProcessCache>,
+    /// Keeps the previous interface counters between cycles to derive rates.
+    network_readers: ReaderSupervisor<NetworkReader>,
 }
 
 impl LocalCollector {
-    pub fn new() -> Self {
+    pub fn new(filter: CollectionFilter) -> Self {
+        let timeout = filter.reader_timeout;
+        let storage_readers = filter
+            .disk
+            .then(create_storage_reader)
+            .into_iter()
+            .collect();
+        let network_readers = filter.network_reader().map(Box::new).into_iter().collect();
         Self {
-            gpu_readers: Arc::new(RwLock::new(Vec::new())),
-            cpu_readers: Arc::new(RwLock::new(Vec::new())),
-            memory_readers: Arc::new(RwLock::new(Vec::new())),
-            chassis_reader: Arc::new(RwLock::new(None)),
+            gpu_readers: RwLock::new(ReaderSupervisor::new("GPU", Vec::new(), timeout)),
+            cpu_readers: RwLock::new(ReaderSupervisor::new("CPU", Vec::new(), timeout)),
+            memory_readers: RwLock::new(ReaderSupervisor::new("memory", Vec::new(), timeout)),
+            quarantined: std::sync::Mutex::new(Vec::new()),
+            chassis_readers: RwLock::new(ReaderSupervisor::new("chassis", Vec::new(), timeout)),
+            storage_readers: ReaderSupervisor::new("storage", storage_readers, timeout),
+            gpu_presence: Arc::new(std::sync::Mutex::new(GpuPresence::new())),
             aggregator: DataAggregator::new(),
             initialized: Arc::new(Mutex::new(false)),
             tracked_pids: Arc::new(RwLock::new(Vec::new())),
@@ -91,6 +120,8 @@ impl LocalCollector {
             process_cache: Arc::new(std::sync::RwLock::new(HashMap::with_capacity(
                 MAX_DISPLAY_PROCESSES,
             ))),
+            network_readers: ReaderSupervisor::new("network", network_readers, timeout),
+            filter,
         }
     }
 
@@ -101,7 +132,7 @@ impl LocalCollector {
         let mut initialized = match initialized_result {
             Ok(lock) => lock,
             Err(_) => {
-                eprintln!("Warning: Timeout acquiring initialized lock");
+                tracing::warn!("Timeout acquiring initialized lock");
                 return;
             }
         };
@@ -121,7 +152,8 @@ impl LocalCollector {
             }
         }
 
-        let gpu_readers = get_gpu_readers();
+        let timeout = self.filter.reader_timeout;
+        let gpu_readers = ReaderSupervisor::new("GPU", self.filter.gpu_readers(), timeout);
 
         // Add startup status
         {
@@ -131,7 +163,7 @@ impl LocalCollector {
                 .push("✓ Initializing CPU readers...".to_string());
         }
 
-        let cpu_readers = get_cpu_readers();
+        let cpu_readers = ReaderSupervisor::new("CPU", self.filter.cpu_readers(), timeout);
 
         // Add startup status
         {
@@ -141,10 +173,10 @@ impl LocalCollector {
                 .push("✓ Initializing memory readers...".to_string());
         }
 
-        let memory_readers = get_memory_readers();
+        let memory_readers = ReaderSupervisor::new("memory", get_memory_readers(), timeout);
 
-        // Create chassis reader
-        let chassis_reader = create_chassis_reader();
+        let chassis_readers =
+            ReaderSupervisor::new("chassis", vec![create_chassis_reader()], timeout);
 
         // Store the readers in self using RwLock with timeout
         {
@@ -153,7 +185,7 @@ impl LocalCollector {
             {
                 *gpu_lock = gpu_readers;
             } else {
-                eprintln!("Warning: Timeout acquiring GPU readers lock");
+                tracing::warn!("Timeout acquiring GPU readers lock");
             }
         }
         {
@@ -162,7 +194,7 @@ impl LocalCollector {
             {
                 *cpu_lock = cpu_readers;
             } else {
-                eprintln!("Warning: Timeout acquiring CPU readers lock");
+                tracing::warn!("Timeout acquiring CPU readers lock");
             }
         }
         {
@@ -171,252 +203,222 @@ impl LocalCollector {
             {
                 *mem_lock = memory_readers;
             } else {
-                eprintln!("Warning: Timeout acquiring memory readers lock");
+                tracing::warn!("Timeout acquiring memory readers lock");
             }
         }
         {
             if let Ok(mut chassis_lock) =
-                timeout(Duration::from_secs(2), self.chassis_reader.write()).await
+                timeout(Duration::from_secs(2), self.chassis_readers.write()).await
             {
-                *chassis_lock = Some(chassis_reader);
+                *chassis_lock = chassis_readers;
             } else {
-                eprintln!("Warning: Timeout acquiring chassis reader lock");
+                tracing::warn!("Timeout acquiring chassis reader lock");
             }
         }
 
         *initialized = true;
     }
 
-    async fn collect_parallel_first_iteration(
-        &self,
-        app_state: Arc<Mutex<AppState>>,
-    ) -> CollectionData {
-        use tokio::sync::mpsc;
-        use tokio::task;
-
-        // Add initial startup status
+    /// Startup status lines for the first collection, updated as each
+    /// category finishes.
+    async fn collect_first_iteration(&self, app_state: Arc<Mutex<AppState>>) -> CollectionData {
         {
             let mut state = app_state.lock().await;
-            state
-                .startup_status_lines
-                .push("○ Collecting GPU information...".to_string());
-            state
-                .startup_status_lines
-                .push("○ Collecting CPU information...".to_string());
-            state
-                .startup_status_lines
-                .push("○ Collecting memory information...".to_string());
-            state
-                .startup_status_lines
-                .push("○ Collecting process information...".to_string());
-            state
-                .startup_status_lines
-                .push("○ Collecting storage information...".to_string());
+            for subsystem in ["GPU", "CPU", "memory", "process", "storage"] {
+                state
+                    .startup_status_lines
+                    .push(format!("○ Collecting {subsystem} information..."));
+            }
         }
 
-        // Create channel for status updates
         let (status_tx, mut status_rx) = mpsc::channel(10);
-        let app_state_clone = Arc::clone(&app_state);
-
-        // Spawn task to handle status updates
-        let status_handler = task::spawn(async move {
+        let status_handler = tokio::spawn(async move {
             while let Some((index, message)) = status_rx.recv().await {
-                let mut state = app_state_clone.lock().await;
-                if index < state.startup_status_lines.len() {
-                    state.startup_status_lines[3 + index] = message;
+                let mut state = app_state.lock().await;
+                // The first three lines belong to reader initialization
+                if let Some(line) = state.startup_status_lines.get_mut(3 + index) {
+                    *line = message;
                 }
             }
         });
 
-        // Run all collections in parallel with status updates
-        // Use Arc references instead of cloning the entire Arc<RwLock<_>>
-        let gpu_readers_1 = Arc::clone(&self.gpu_readers);
-        let gpu_readers_2 = Arc::clone(&self.gpu_readers);
-        let cpu_readers = Arc::clone(&self.cpu_readers);
-        let memory_readers = Arc::clone(&self.memory_readers);
-        let chassis_reader = Arc::clone(&self.chassis_reader);
-        let process_cache = Arc::clone(&self.process_cache);
+        let data = self.collect_concurrent(Some(status_tx)).await;
+        let _ = status_handler.await;
+        data
+    }
 
-        let (
-            all_gpu_info,
-            all_cpu_info,
-            all_memory_info,
-            gpu_processes,
-            all_processes,
-            all_storage_info,
-            all_chassis_info,
-        ) = {
-            let status_tx_gpu = status_tx.clone();
-            let status_tx_cpu = status_tx.clone();
-            let status_tx_mem = status_tx.clone();
-            let status_tx_proc = status_tx.clone();
-            let status_tx_storage = status_tx.clone();
-
-            tokio::join!(
-                // GPU info collection
-                async move {
-                    let readers = gpu_readers_1.read().await;
-                    let info: Vec<GpuInfo> = readers
-                        .iter()
-                        .flat_map(|reader| reader.get_gpu_info())
-                        .collect();
-                    let _ = status_tx_gpu
-                        .send((0, "✓ GPU information collected".to_string()))
-                        .await;
-                    info
-                },
-                // CPU info collection
-                async move {
-                    let readers = cpu_readers.read().await;
-                    let info: Vec<CpuInfo> = readers
-                        .iter()
-                        .flat_map(|reader| reader.get_cpu_info())
-                        .collect();
-                    let _ = status_tx_cpu
-                        .send((1, "✓ CPU information collected".to_string()))
-                        .await;
-                    info
-                },
-                // Memory info collection
-                async move {
-                    let readers = memory_readers.read().await;
-                    let info: Vec<MemoryInfo> = readers
-                        .iter()
-                        .flat_map(|reader| reader.get_memory_info())
-                        .collect();
-                    let _ = status_tx_mem
-                        .send((2, "✓ Memory information collected".to_string()))
-                        .await;
-                    info
-                },
-                // GPU process collection (lightweight)
-                async move {
-                    let readers = gpu_readers_2.read().await;
-                    let processes = readers
-                        .iter()
-                        .flat_map(|reader| reader.get_process_info())
-                        .collect::<Vec<ProcessInfo>>();
-                    processes
-                },
-                // Full process collection - use spawn_blocking to avoid blocking tokio runtime
-                async move {
-                    let all_processes = tokio::task::spawn_blocking(move || {
-                        with_global_system(|system| {
-                            use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, UpdateKind};
-                            // OPTIMIZATION: Only refresh fields we actually need
-                            // - CPU usage for cpu_percent
-                            // - Memory for memory_percent/memory_rss/memory_vms
-                            // - User only if not already set (avoid repeated lookups)
-                            // This is much cheaper than everything() which includes disk I/O, etc.
-                            let refresh_kind = ProcessRefreshKind::nothing()
-                                .with_cpu()
-                                .with_memory()
-                                .with_user(UpdateKind::OnlyIfNotSet);
-                            system.refresh_processes_specifics(
-                                ProcessesToUpdate::All,
-                                true,
-                                refresh_kind,
-                            );
-                            system.refresh_memory();
-
-                            // OPTIMIZATION: Initialize process cache on first iteration
-                            // This populates the cache with all current processes
-                            let gpu_pids: HashSet<u32> = HashSet::new();
-                            let mut cache = process_cache.write().unwrap();
-                            update_process_cache(system, &gpu_pids, &mut cache)
-                        })
-                    })
-                    .await
-                    .unwrap_or_default();
-                    let _ = status_tx_proc
-                        .send((3, "✓ Process information collected".to_string()))
-                        .await;
-                    all_processes
+    /// Collect every category at once, each on the blocking pool, so a slow
+    /// reader delays neither the others nor the async runtime the UI runs on.
+    async fn collect_concurrent(&self, status_tx: Option<StatusSender>) -> CollectionData {
+        let filter = &self.filter;
+        let k8s = filter.k8s;
+        let report = |index: usize, message: String| {
+            let status_tx = status_tx.clone();
+            async move {
+                if let Some(tx) = status_tx {
+                    let _ = tx.send((index, message)).await;
+                }
+            }
+        };
+
+        let gpu_presence = Arc::clone(&self.gpu_presence);
+        let gpu = async {
+            let started = Instant::now();
+            let collected = self
+                .gpu_readers
+                .read()
+                .await
+                .collect("gpu_info", |reader| reader.get_gpu_info())
+                .await;
+            let mut info = self.supervised(collected, mark_stale);
+            let mut result = run_timed(CollectionCategory::Gpu, move || {
+                stamp_indices(&mut info);
+                if let Ok(mut presence) = gpu_presence.lock() {
+                    presence.track(&mut info);
+                }
+                annotate_gpu_topology(&mut info);
+                if k8s {
+                    annotate_gpu_allocation(&mut info);
+                }
+                info
+            })
+            .await;
+            result.1.duration = started.elapsed();
+            report(0, status_message(filter.gpu, "GPU information")).await;
+            result
+        };
+
+        let cpu = async {
+            let started = Instant::now();
+            let collected = self
+                .cpu_readers
+                .read()
+                .await
+                .collect("cpu_info", |reader| reader.get_cpu_info())
+                .await;
+            let result: (Vec<CpuInfo>, _) = (
+                self.supervised(collected, |info| info.stale = true),
+                CategoryTiming {
+                    category: CollectionCategory::Cpu,
+                    duration: started.elapsed(),
                 },
-                // Storage collection
-                async move {
-                    let storage_info = Self::collect_storage_info();
-                    let _ = status_tx_storage
-                        .send((4, "✓ Storage information collected".to_string()))
-                        .await;
-                    storage_info
+            );
+            report(1, status_message(filter.cpu, "CPU information")).await;
+            result
+        };
+
+        let memory = async {
+            let started = Instant::now();
+            let collected = self
+                .memory_readers
+                .read()
+                .await
+                .collect("memory_info", |reader| reader.get_memory_info())
+                .await;
+            let result: (Vec<MemoryInfo>, _) = (
+                self.supervised(collected, |info| info.stale = true),
+                CategoryTiming {
+                    category: CollectionCategory::Memory,
+                    duration: started.elapsed(),
                 },
-                // Chassis info collection
-                async move {
-                    let reader = chassis_reader.read().await;
-                    let info: Vec<ChassisInfo> = reader
-                        .as_ref()
-                        .and_then(|r| r.get_chassis_info())
-                        .into_iter()
-                        .collect();
-                    info
-                }
-            )
+            );
+            report(2, "✓ Memory information collected".to_string()).await;
+            result
         };
 
-        // Close the channel and wait for status handler to finish
-        drop(status_tx);
-        let _ = status_handler.await;
+        let processes = async {
+            let result = self.collect_processes().await;
+            report(3, "✓ Process information collected".to_string()).await;
+            result
+        };
 
-        // Merge GPU processes into main process list
-        let mut all_processes_merged = all_processes;
-        merge_gpu_processes(&mut all_processes_merged, gpu_processes);
+        // Filesystems and interfaces have no stale mark; a reader stuck
+        // for good still shows up as a quarantine warning
+        let storage = async {
+            let started = Instant::now();
+            let collected = self
+                .storage_readers
+                .collect("storage_info", |reader| reader.get_storage_info())
+                .await;
+            let result = (
+                self.supervised(collected, |_| {}),
+                CategoryTiming {
+                    category: CollectionCategory::Storage,
+                    duration: started.elapsed(),
+                },
+            );
+            report(4, status_message(filter.disk, "Storage information")).await;
+            result
+        };
 
-        // Sort by CPU usage descending and limit to top MAX_DISPLAY_PROCESSES
-        all_processes_merged.sort_by(|a, b| {
-            b.cpu_percent
-                .partial_cmp(&a.cpu_percent)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        if all_processes_merged.len() > MAX_DISPLAY_PROCESSES {
-            all_processes_merged.truncate(MAX_DISPLAY_PROCESSES);
-        }
+        let network = async {
+            let started = Instant::now();
+            let collected = self
+                .network_readers
+                .collect("network_info", NetworkReader::get_network_info)
+                .await;
+            (
+                self.supervised(collected, |_| {}),
+                CategoryTiming {
+                    category: CollectionCategory::Network,
+                    duration: started.elapsed(),
+                },
+            )
+        };
 
-        // Initialize tracked PIDs for selective refresh in subsequent cycles
-        let new_tracked_pids: Vec<sysinfo::Pid> = all_processes_merged
-            .iter()
-            .map(|p| sysinfo::Pid::from_u32(p.pid))
-            .collect();
-        *self.tracked_pids.write().await = new_tracked_pids;
+        let chassis = async {
+            let started = Instant::now();
+            let collected = self
+                .chassis_readers
+                .read()
+                .await
+                .collect("chassis_info", |reader| {
+                    reader.get_chassis_info().into_iter().collect()
+                })
+                .await;
+            (
+                self.supervised(collected, mark_chassis_stale),
+                CategoryTiming {
+                    category: CollectionCategory::Chassis,
+                    duration: started.elapsed(),
+                },
+            )
+        };
 
-        // Reset refresh cycle counter (first iteration counts as cycle 0)
-        self.refresh_cycle.store(1, Ordering::Relaxed);
+        let (
+            (gpu_info, gpu_timing),
+            (cpu_info, cpu_timing),
+            (memory_info, memory_timing),
+            (process_info, process_timing),
+            (storage_info, storage_timing),
+            (network_info, network_timing),
+            (chassis_info, chassis_timing),
+        ) = tokio::join!(gpu, cpu, memory, processes, storage, network, chassis);
 
         CollectionData {
-            gpu_info: all_gpu_info,
-            cpu_info: all_cpu_info,
-            memory_info: all_memory_info,
-            process_info: all_processes_merged,
-            storage_info: all_storage_info,
-            chassis_info: all_chassis_info,
+            gpu_info,
+            cpu_info,
+            memory_info,
+            process_info,
+            storage_info,
+            network_info,
+            chassis_info,
             connection_statuses: Vec::new(),
+            timings: vec![
+                gpu_timing,
+                cpu_timing,
+                memory_timing,
+                process_timing,
+                storage_timing,
+                network_timing,
+                chassis_timing,
+            ],
         }
     }
 
-    async fn collect_sequential(&self) -> CollectionData {
-        let gpu_readers = self.gpu_readers.read().await;
-        let all_gpu_info: Vec<GpuInfo> = gpu_readers
-            .iter()
-            .flat_map(|reader| reader.get_gpu_info())
-            .collect();
-
-        let cpu_readers = self.cpu_readers.read().await;
-        let all_cpu_info: Vec<CpuInfo> = cpu_readers
-            .iter()
-            .flat_map(|reader| reader.get_cpu_info())
-            .collect();
-
-        let memory_readers = self.memory_readers.read().await;
-        let all_memory_info: Vec<MemoryInfo> = memory_readers
-            .iter()
-            .flat_map(|reader| reader.get_memory_info())
-            .collect();
-
-        let gpu_processes: Vec<ProcessInfo> = gpu_readers
-            .iter()
-            .flat_map(|reader| reader.get_process_info())
-            .collect();
-
+    /// System processes merged with the accelerator processes, top
+    /// MAX_DISPLAY_PROCESSES by CPU usage.
+    async fn collect_processes(&self) -> (Vec<ProcessInfo>, CategoryTiming) {
         // Determine if we should do a full refresh or selective refresh
         let cycle = self.refresh_cycle.fetch_add(1, Ordering::Relaxed);
         let do_full_refresh = cycle.is_multiple_of(FULL_REFRESH_INTERVAL);
@@ -428,116 +430,93 @@ impl LocalCollector {
             self.tracked_pids.read().await.clone()
         };
 
-        let gpu_pids: HashSet<u32> = gpu_processes.iter().map(|p| p.pid).collect();
+        let started = Instant::now();
+        let collected = self
+            .gpu_readers
+            .read()
+            .await
+            .collect("process_info", |reader| reader.get_process_info())
+            .await;
+        let gpu_processes = self.supervised(collected, |_| {});
         let process_cache = Arc::clone(&self.process_cache);
-        let mut all_processes = with_global_system(|system| {
-            use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, UpdateKind};
-            // OPTIMIZATION: Only refresh fields we actually need
-            // - CPU usage for cpu_percent
-            // - Memory for memory_percent/memory_rss/memory_vms
-            // - User only if not already set (avoid repeated lookups)
-            let refresh_kind = ProcessRefreshKind::nothing()
-                .with_cpu()
-                .with_memory()
-                .with_user(UpdateKind::OnlyIfNotSet);
-
-            // OPTIMIZATION: Selective process refresh
-            // Full refresh every N cycles to discover new high-CPU processes;
-            // otherwise only refresh tracked PIDs to significantly reduce CPU usage.
-            if do_full_refresh || tracked_pids_for_refresh.is_empty() {
-                system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
-            } else {
-                system.refresh_processes_specifics(
-                    ProcessesToUpdate::Some(&tracked_pids_for_refresh),
-                    true,
-                    refresh_kind,
-                );
-            }
-            system.refresh_memory();
+        let (all_processes, mut timing) = run_timed(CollectionCategory::Processes, move || {
+            let gpu_pids: HashSet<u32> = gpu_processes.iter().map(|p| p.pid).collect();
+
+            let mut all_processes = with_global_system(|system| {
+                use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, UpdateKind};
+                // OPTIMIZATION: Only refresh fields we actually need
+                // - CPU usage for cpu_percent
+                // - Memory for memory_percent/memory_rss/memory_vms
+                // - User only if not already set (avoid repeated lookups)
+                let refresh_kind = ProcessRefreshKind::nothing()
+                    .with_cpu()
+                    .with_memory()
+                    .with_user(UpdateKind::OnlyIfNotSet);
+
+                // OPTIMIZATION: Selective process refresh
+                // Full refresh every N cycles to discover new high-CPU processes;
+                // otherwise only refresh tracked PIDs to significantly reduce CPU usage.
+                if do_full_refresh || tracked_pids_for_refresh.is_empty() {
+                    system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+                } else {
+                    system.refresh_processes_specifics(
+                        ProcessesToUpdate::Some(&tracked_pids_for_refresh),
+                        true,
+                        refresh_kind,
+                    );
+                }
+                system.refresh_memory();
 
-            // OPTIMIZATION: Use process cache to reduce memory allocation overhead
-            // Instead of creating new ProcessInfo objects every cycle, we update
-            // existing cached objects and only allocate for new processes.
-            let mut cache = process_cache.write().unwrap();
-            update_process_cache(system, &gpu_pids, &mut cache)
-        });
-        merge_gpu_processes(&mut all_processes, gpu_processes);
+                // OPTIMIZATION: Use process cache to reduce memory allocation overhead
+                // Instead of creating new ProcessInfo objects every cycle, we update
+                // existing cached objects and only allocate for new processes.
+                let mut cache = process_cache.write().unwrap();
+                update_process_cache(system, &gpu_pids, &mut cache)
+            });
+            merge_gpu_processes(&mut all_processes, gpu_processes);
 
-        // Sort by CPU usage descending and limit to top MAX_DISPLAY_PROCESSES
-        all_processes.sort_by(|a, b| {
-            b.cpu_percent
-                .partial_cmp(&a.cpu_percent)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        if all_processes.len() > MAX_DISPLAY_PROCESSES {
+            // Sort by CPU usage descending and limit to top MAX_DISPLAY_PROCESSES
+            all_processes.sort_by(|a, b| {
+                b.cpu_percent
+                    .partial_cmp(&a.cpu_percent)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
             all_processes.truncate(MAX_DISPLAY_PROCESSES);
-        }
+            all_processes
+        })
+        .await;
+        timing.duration = started.elapsed();
 
         // Update tracked PIDs for next cycle (after truncation to top N)
-        let new_tracked_pids: Vec<sysinfo::Pid> = all_processes
+        *self.tracked_pids.write().await = all_processes
             .iter()
             .map(|p| sysinfo::Pid::from_u32(p.pid))
             .collect();
-        *self.tracked_pids.write().await = new_tracked_pids;
-
-        let all_storage_info = Self::collect_storage_info();
-
-        // Collect chassis info
-        let chassis_reader = self.chassis_reader.read().await;
-        let all_chassis_info: Vec<ChassisInfo> = chassis_reader
-            .as_ref()
-            .and_then(|r| r.get_chassis_info())
-            .into_iter()
-            .collect();
 
-        CollectionData {
-            gpu_info: all_gpu_info,
-            cpu_info: all_cpu_info,
-            memory_info: all_memory_info,
-            process_info: all_processes,
-            storage_info: all_storage_info,
-            chassis_info: all_chassis_info,
-            connection_statuses: Vec::new(),
-        }
+        (all_processes, timing)
     }
 
-    fn collect_storage_info() -> Vec<StorageInfo> {
-        let mut all_storage_info = Vec::new();
-        let disks = Disks::new_with_refreshed_list();
-        let hostname = get_hostname();
-
-        let mut filtered_disks = filter_docker_aware_disks(&disks);
-        filtered_disks.sort_by(|a, b| {
-            a.mount_point()
-                .to_string_lossy()
-                .cmp(&b.mount_point().to_string_lossy())
-        });
-
-        for (index, disk) in filtered_disks.iter().enumerate() {
-            let mount_point_str = disk.mount_point().to_string_lossy();
-            all_storage_info.push(StorageInfo {
-                mount_point: mount_point_str.to_string(),
-                total_bytes: disk.total_space(),
-                available_bytes: disk.available_space(),
-                host_id: hostname.clone(),
-                hostname: hostname.clone(),
-                index: index as u32,
-            });
+    /// Readings of a supervised call, keeping any new quarantines for the
+    /// next state update to warn about.
+    fn supervised<T>(&self, collected: Collected<T>, mark: impl Fn(&mut T)) -> Vec<T> {
+        let quarantined = collected
+            .faults
+            .iter()
+            .filter(|fault| fault.is_new_quarantine())
+            .map(ToString::to_string);
+        if let Ok(mut warnings) = self.quarantined.lock() {
+            warnings.extend(quarantined);
         }
-
-        all_storage_info
+        collected.into_values_marked(mark)
     }
 
     fn update_notifications(state: &mut AppState) {
-        // Update notifications (remove expired ones)
-        state.notifications.update();
-
         // Only check NVML status if we're trying to monitor NVIDIA devices
         if has_nvidia() {
             if let Some(nvml_message) = get_nvml_status_message() {
                 if !state.nvml_notification_shown {
                     if let Err(e) = state.notifications.warning(nvml_message) {
-                        eprintln!("Failed to show NVML notification: {e}");
+                        tracing::warn!("Failed to show NVML notification: {e}");
                     }
                     state.nvml_notification_shown = true;
                 }
@@ -550,7 +529,7 @@ impl LocalCollector {
             if let Some(tt_message) = get_tenstorrent_status_message() {
                 if !state.tenstorrent_notification_shown {
                     if let Err(e) = state.notifications.warning(tt_message) {
-                        eprintln!("Failed to show Tenstorrent notification: {e}");
+                        tracing::warn!("Failed to show Tenstorrent notification: {e}");
                     }
                     state.tenstorrent_notification_shown = true;
                 }
@@ -599,6 +578,50 @@ impl LocalCollector {
     }
 }
 
+/// Run `collect` on the blocking pool and time it. A reader that panics
+/// yields an empty result for this cycle instead of ending collection.
+async fn run_timed<T, F>(category: CollectionCategory, collect: F) -> (T, CategoryTiming)
+where
+    T: Default + Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (value, duration) = tokio::task::spawn_blocking(move || {
+        let _span = tracing::debug_span!("collect", category = category.label()).entered();
+        let started = Instant::now();
+        let value = collect();
+        let elapsed = started.elapsed();
+        tracing::trace!(
+            elapsed_ms = elapsed.as_millis() as u64,
+            "Category collected"
+        );
+        (value, elapsed)
+    })
+    .await
+    .unwrap_or_default();
+    (value, CategoryTiming { category, duration })
+}
+
+/// Flag a device whose reader timed out, so it renders as stale
+fn mark_stale(info: &mut GpuInfo) {
+    info.detail
+        .insert(STALE_KEY.to_string(), "true".to_string());
+}
+
+/// Flag chassis readings kept from before their reader timed out
+fn mark_chassis_stale(info: &mut ChassisInfo) {
+    info.detail
+        .insert(STALE_KEY.to_string(), "true".to_string());
+}
+
+/// Startup status line for a subsystem that was collected or disabled
+fn status_message(enabled: bool, subsystem: &str) -> String {
+    if enabled {
+        format!("✓ {subsystem} collected")
+    } else {
+        format!("- {subsystem} disabled")
+    }
+}
+
 #[async_trait]
 impl DataCollectionStrategy for LocalCollector {
     async fn collect(&self, config: &CollectionConfig) -> CollectionResult {
@@ -611,7 +634,7 @@ impl DataCollectionStrategy for LocalCollector {
             ));
         }
 
-        Ok(self.collect_sequential().await)
+        Ok(self.collect_concurrent(None).await)
     }
 
     async fn update_state(
@@ -626,7 +649,34 @@ impl DataCollectionStrategy for LocalCollector {
         }
 
         let mut state = app_state.lock().await;
+        Self::apply_to_state(&mut state, data, &self.aggregator);
+        state.processes_collected_at = Some(Instant::now());
+        Self::update_notifications(&mut state);
+        let quarantined = self
+            .quarantined
+            .lock()
+            .map(|mut warnings| std::mem::take(&mut *warnings))
+            .unwrap_or_default();
+        for warning in quarantined {
+            if let Err(e) = state.notifications.warning(warning) {
+                tracing::warn!("Failed to show reader notification: {e}");
+            }
+        }
+    }
+
+    fn strategy_type(&self) -> &str {
+        "local"
+    }
+
+    async fn is_ready(&self) -> bool {
+        *self.initialized.lock().await
+    }
+}
 
+impl LocalCollector {
+    /// Apply one round of collected data to the state. Replay goes through
+    /// here too, so recorded snapshots render exactly like live ones.
+    pub fn apply_to_state(state: &mut AppState, data: CollectionData, aggregator: &DataAggregator) {
         // Update GPU info with UUID matching
         if state.gpu_info.is_empty() {
             state.gpu_info = data.gpu_info;
@@ -655,34 +705,30 @@ impl DataCollectionStrategy for LocalCollector {
         state.process_info = sorted_processes;
 
         state.storage_info = data.storage_info;
+        state.network_info = data.network_info;
         state.chassis_info = data.chassis_info;
+        state.collection_timings = data
+            .timings
+            .iter()
+            .map(|timing| (timing.category.label(), timing.duration))
+            .collect();
 
         // Mark data as changed to trigger UI update
         state.mark_data_changed();
 
-        // Update notifications
-        Self::update_notifications(&mut state);
+        // Drop expired notifications
+        state.notifications.update();
 
         // Update utilization history
-        self.aggregator.update_utilization_history(&mut state);
+        aggregator.update_utilization_history(state);
 
         // Update tabs
-        Self::update_tabs(&mut state);
+        Self::update_tabs(state);
 
         // Always clear loading state in local mode after first iteration
         state.loading = false;
     }
 
-    fn strategy_type(&self) -> &str {
-        "local"
-    }
-
-    async fn is_ready(&self) -> bool {
-        *self.initialized.lock().await
-    }
-}
-
-impl LocalCollector {
     pub async fn collect_with_app_state(
         &self,
         app_state: Arc<Mutex<AppState>>,
@@ -693,9 +739,204 @@ impl LocalCollector {
         }
 
         if config.first_iteration {
-            Ok(self.collect_parallel_first_iteration(app_state).await)
+            Ok(self.collect_first_iteration(app_state).await)
         } else {
-            Ok(self.collect_sequential().await)
+            Ok(self.collect_concurrent(None).await)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const READER_DELAY: Duration = Duration::from_millis(300);
+
+    /// Reader that takes `READER_DELAY` per call, like a busy NVML or
+    /// powermetrics query.
+    struct SlowReader;
+
+    impl GpuReader for SlowReader {
+        fn get_gpu_info(&self) -> Vec<GpuInfo> {
+            std::thread::sleep(READER_DELAY);
+            Vec::new()
+        }
+
+        fn get_process_info(&self) -> Vec<ProcessInfo> {
+            Vec::new()
+        }
+    }
+
+    impl CpuReader for SlowReader {
+        fn get_cpu_info(&self) -> Vec<CpuInfo> {
+            std::thread::sleep(READER_DELAY);
+            Vec::new()
+        }
+    }
+
+    struct FastReader;
+
+    impl MemoryReader for FastReader {
+        fn get_memory_info(&self) -> Vec<MemoryInfo> {
+            vec![MemoryInfo {
+                host_id: "node1".to_string(),
+                hostname: "node1".to_string(),
+                instance: "node1".to_string(),
+                total_bytes: 64 << 30,
+                used_bytes: 16 << 30,
+                available_bytes: 48 << 30,
+                free_bytes: 48 << 30,
+                buffers_bytes: 0,
+                cached_bytes: 0,
+                swap_total_bytes: 0,
+                swap_used_bytes: 0,
+                swap_free_bytes: 0,
+                utilization: 25.0,
+                time: String::new(),
+                psi: None,
+                pressure_level: None,
+                compressed_bytes: None,
+                swap_in_bytes_total: None,
+                swap_out_bytes_total: None,
+                stale: false,
+            }]
+        }
+    }
+
+    /// CPU reader stuck in its driver far beyond any reader timeout
+    struct HungReader;
+
+    impl CpuReader for HungReader {
+        fn get_cpu_info(&self) -> Vec<CpuInfo> {
+            std::thread::sleep(READER_DELAY * 5);
+            Vec::new()
         }
     }
+
+    /// Memory reader that gets stuck once `hang` is set
+    struct StallingReader {
+        hang: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl MemoryReader for StallingReader {
+        fn get_memory_info(&self) -> Vec<MemoryInfo> {
+            if self.hang.load(Ordering::Relaxed) {
+                std::thread::sleep(READER_DELAY * 5);
+            }
+            FastReader.get_memory_info()
+        }
+    }
+
+    async fn collector_with(
+        reader_timeout: Duration,
+        cpu_reader: Box<dyn CpuReader>,
+    ) -> LocalCollector {
+        let collector = LocalCollector::new(CollectionFilter {
+            disk: false,
+            net: false,
+            reader_timeout,
+            ..CollectionFilter::default()
+        });
+        *collector.gpu_readers.write().await =
+            ReaderSupervisor::new("GPU", vec![Box::new(SlowReader)], reader_timeout);
+        *collector.cpu_readers.write().await =
+            ReaderSupervisor::new("CPU", vec![cpu_reader], reader_timeout);
+        *collector.memory_readers.write().await =
+            ReaderSupervisor::new("memory", vec![Box::new(FastReader)], reader_timeout);
+        collector
+    }
+
+    async fn collector_with_slow_readers() -> LocalCollector {
+        collector_with(Duration::from_secs(5), Box::new(SlowReader)).await
+    }
+
+    fn timing(data: &CollectionData, category: CollectionCategory) -> Duration {
+        data.timings
+            .iter()
+            .find(|t| t.category == category)
+            .map(|t| t.duration)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_slow_readers_do_not_delay_each_other() {
+        let collector = collector_with_slow_readers().await;
+
+        let started = Instant::now();
+        let data = collector.collect_concurrent(None).await;
+        let elapsed = started.elapsed();
+
+        // Two slow readers run side by side rather than one after the other
+        assert!(elapsed < READER_DELAY * 2, "collection took {elapsed:?}");
+        assert_eq!(data.timings.len(), 7);
+        assert!(timing(&data, CollectionCategory::Gpu) >= READER_DELAY);
+        assert!(timing(&data, CollectionCategory::Cpu) >= READER_DELAY);
+        assert!(timing(&data, CollectionCategory::Memory) < READER_DELAY);
+    }
+
+    #[tokio::test]
+    async fn test_slow_readers_do_not_block_the_runtime() {
+        // The default test runtime has a single thread, so a reader running
+        // on it would starve this ticker just as it would stall the UI loop
+        let collector = collector_with_slow_readers().await;
+        let ticks = Arc::new(AtomicU32::new(0));
+        let ticker = tokio::spawn({
+            let ticks = Arc::clone(&ticks);
+            async move {
+                let mut interval = tokio::time::interval(Duration::from_millis(10));
+                loop {
+                    interval.tick().await;
+                    ticks.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        });
+
+        collector.collect_concurrent(None).await;
+        ticker.abort();
+
+        // ~30 ticks fit in READER_DELAY; allow for a loaded test machine
+        let ticks = ticks.load(Ordering::Relaxed);
+        assert!(ticks >= 10, "runtime only ticked {ticks} times");
+    }
+
+    #[tokio::test]
+    async fn test_hung_reader_times_out_without_holding_up_the_cycle() {
+        let reader_timeout = READER_DELAY * 2;
+        let collector = collector_with(reader_timeout, Box::new(HungReader)).await;
+
+        let started = Instant::now();
+        let data = collector.collect_concurrent(None).await;
+        let elapsed = started.elapsed();
+
+        // The slow GPU reader still makes it; the hung CPU reader is cut off
+        assert!(elapsed < READER_DELAY * 3, "collection took {elapsed:?}");
+        assert!(timing(&data, CollectionCategory::Gpu) >= READER_DELAY);
+        assert!(data.cpu_info.is_empty());
+        assert_eq!(data.memory_info.len(), 1);
+        assert_eq!(data.memory_info[0].total_bytes, 64 << 30);
+        assert!(timing(&data, CollectionCategory::Cpu) < READER_DELAY * 3);
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_memory_reader_serves_stale_readings() {
+        let reader_timeout = READER_DELAY * 2;
+        let collector = collector_with(reader_timeout, Box::new(SlowReader)).await;
+        let hang = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let reader = StallingReader {
+            hang: Arc::clone(&hang),
+        };
+        *collector.memory_readers.write().await =
+            ReaderSupervisor::new("memory", vec![Box::new(reader)], reader_timeout);
+
+        let data = collector.collect_concurrent(None).await;
+        assert_eq!(data.memory_info.len(), 1);
+        assert!(!data.memory_info[0].stale);
+
+        hang.store(true, Ordering::Relaxed);
+        let started = Instant::now();
+        let data = collector.collect_concurrent(None).await;
+        assert!(started.elapsed() < READER_DELAY * 3);
+        assert_eq!(data.memory_info.len(), 1);
+        assert!(data.memory_info[0].stale);
+    }
 }
@@ -13,10 +13,15 @@
 // limitations under the License.
 
 pub mod aggregator;
+pub mod fetch_latency;
+pub mod host_merge;
+pub mod hostfile;
 pub mod local_collector;
 pub mod remote_collector;
+pub mod replay;
 pub mod strategy;
 
 pub use local_collector::LocalCollector;
 pub use remote_collector::RemoteCollectorBuilder;
+pub use replay::{Recorder, ReplayCollector};
 pub use strategy::{CollectionConfig, DataCollectionStrategy};
@@ -13,17 +13,20 @@
 // limitations under the License.
 
 use async_trait::async_trait;
-use regex::{Regex, RegexBuilder};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use crate::app_state::{AppState, ConnectionStatus};
+use crate::cli::DEFAULT_REMOTE_PROCESS_TOP;
 use crate::common::config::EnvConfig;
+use crate::network::ssh::{SshClient, SshOptions};
 use crate::network::NetworkClient;
 use crate::storage::info::StorageInfo;
 
 use super::aggregator::DataAggregator;
+use super::host_merge::{bond_info, dedup_gpus, group_by_instance, BondOrder, EndpointMerge};
+use super::hostfile::{HostBond, HostList};
 use super::strategy::{
     CollectionConfig, CollectionData, CollectionError, CollectionResult, DataCollectionStrategy,
 };
@@ -53,28 +56,45 @@ fn extract_host_identifier(url: &str) -> String {
     extract_hostname_from_url(url)
 }
 
+/// Make `known` list the same hosts as `hosts`: hosts in both keep their
+/// order in `known`, the rest of `known` is dropped and new hosts are
+/// appended in the order of `hosts`.
+fn reconcile_known_hosts(known: &mut Vec<String>, hosts: &[String]) {
+    known.retain(|host| hosts.contains(host));
+    for host in hosts {
+        if !known.contains(host) {
+            known.push(host.clone());
+        }
+    }
+}
+
+/// How hosts are scraped
+enum Transport {
+    /// GET /metrics from the API server
+    Http(NetworkClient),
+    /// Run all-smi or nvidia-smi over SSH
+    Ssh(Arc<SshClient>),
+}
+
 pub struct RemoteCollector {
-    network_client: NetworkClient,
+    transport: Transport,
     semaphore: Arc<tokio::sync::Semaphore>,
-    regex: Regex,
     aggregator: DataAggregator,
+    /// Processes kept per node, those using the most GPU memory
+    process_top: usize,
 }
 
 impl RemoteCollector {
     pub fn new(max_connections: usize) -> Self {
-        // Use simpler quantifiers to avoid DFA explosion
-        // The + quantifier is much more efficient than bounded quantifiers
-        let regex = RegexBuilder::new(r"^all_smi_([^\{]+)\{([^}]+)\} ([\d\.]+)$")
-            .size_limit(10_485_760) // 10MB size limit for DFA (increased for safety)
-            .dfa_size_limit(10_485_760) // 10MB DFA limit
-            .build()
-            .expect("Failed to compile metrics regex");
+        Self::with_transport(Transport::Http(NetworkClient::new()), max_connections)
+    }
 
+    fn with_transport(transport: Transport, max_connections: usize) -> Self {
         Self {
-            network_client: NetworkClient::new(),
+            transport,
             semaphore: Arc::new(tokio::sync::Semaphore::new(max_connections)),
-            regex,
             aggregator: DataAggregator::new(),
+            process_top: DEFAULT_REMOTE_PROCESS_TOP,
         }
     }
 
@@ -84,10 +104,18 @@ impl RemoteCollector {
         Self::new(max_connections)
     }
 
-    fn deduplicate_storage_info(storage_info: Vec<StorageInfo>) -> Vec<StorageInfo> {
+    fn deduplicate_storage_info(
+        storage_info: Vec<StorageInfo>,
+        bonds: &BondOrder,
+    ) -> Vec<StorageInfo> {
         let mut deduplicated_storage: HashMap<String, StorageInfo> = HashMap::new();
         for storage in storage_info {
-            let dedup_key = format!("{}:{}", storage.hostname, storage.mount_point);
+            // `hostname` is the instance label, so a node listed under two
+            // addresses keeps one entry per mount: the last response's. A
+            // bond's endpoints may report different instances, so their
+            // mounts are matched under the bond's name.
+            let node = bonds.bond_of(&storage.host_id).unwrap_or(&storage.hostname);
+            let dedup_key = format!("{node}:{}", storage.mount_point);
             deduplicated_storage.insert(dedup_key, storage);
         }
         let mut final_storage_info: Vec<StorageInfo> = deduplicated_storage.into_values().collect();
@@ -105,11 +133,21 @@ impl RemoteCollector {
         state: &mut AppState,
         connection_statuses: Vec<ConnectionStatus>,
         hosts: &[String],
+        bonds: &[HostBond],
     ) {
-        // Initialize known hosts if not already set
-        if state.known_hosts.is_empty() {
-            state.known_hosts = hosts.iter().map(|h| extract_host_identifier(h)).collect();
+        // Follow hostfile reloads; hosts that stay keep their tab position,
+        // and each bond has one tab
+        let host_ids: Vec<String> = hosts.iter().map(|h| extract_host_identifier(h)).collect();
+        let nodes = HostList {
+            hosts: host_ids.clone(),
+            bonds: bonds.to_vec(),
         }
+        .nodes();
+        reconcile_known_hosts(&mut state.known_hosts, &nodes);
+        let bond_order = BondOrder::new(bonds);
+        state
+            .connection_status
+            .retain(|host_id, _| host_ids.contains(host_id));
 
         // Clear the reverse lookup map before rebuilding it
         state.hostname_to_host_id.clear();
@@ -125,8 +163,13 @@ impl RemoteCollector {
                 }
             }
 
-            // Update the reverse lookup map if we have an actual hostname
-            if let Some(actual_hostname) = &status.actual_hostname {
+            // Update the reverse lookup map if we have an actual hostname;
+            // bonded endpoints are reached through the bond's tab instead
+            if let Some(actual_hostname) = status
+                .actual_hostname
+                .as_ref()
+                .filter(|_| bond_order.bond_of(&status.host_id).is_none())
+            {
                 state
                     .hostname_to_host_id
                     .insert(actual_hostname.clone(), status.host_id.clone());
@@ -150,13 +193,40 @@ impl RemoteCollector {
                     status
                 });
         }
+
+        let unbonded: Vec<String> = host_ids
+            .into_iter()
+            .filter(|host_id| bond_order.bond_of(host_id).is_none())
+            .collect();
+        state.host_info = group_by_instance(&state.connection_status, &unbonded);
+        state.host_info.extend(bond_info(bonds));
     }
 
     fn update_remote_tabs(state: &mut AppState) {
         // Always create "All" tab for consistent UI behavior
+        // A node listed under several addresses gets one tab, named after
+        // the first of them
+        let aliases: Vec<&String> = state
+            .host_info
+            .values()
+            .filter(|info| !info.bonded)
+            .flat_map(|info| info.addresses.iter().skip(1))
+            .collect();
         let mut tabs = vec!["All".to_string()];
-        tabs.extend(state.known_hosts.clone());
-
+        tabs.extend(
+            state
+                .known_hosts
+                .iter()
+                .filter(|host| !aliases.contains(host))
+                .cloned(),
+        );
+
+        // Keep the selected host selected when hosts before it come or go
+        let selected = state.tabs.get(state.current_tab).cloned();
+        state.current_tab = selected
+            .and_then(|name| tabs.iter().position(|tab| *tab == name))
+            .unwrap_or_else(|| state.current_tab.min(tabs.len() - 1));
+        state.tab_scroll_offset = state.tab_scroll_offset.min(state.current_tab);
         state.tabs = tabs;
     }
 }
@@ -168,21 +238,45 @@ impl DataCollectionStrategy for RemoteCollector {
             return Err(CollectionError::Other("No hosts configured".to_string()));
         }
 
-        let (gpu_info, cpu_info, memory_info, storage_info, connection_statuses) = self
-            .network_client
-            .fetch_remote_data(&config.hosts, &self.semaphore, &self.regex)
-            .await;
+        let (
+            gpu_info,
+            cpu_info,
+            memory_info,
+            storage_info,
+            network_info,
+            chassis_info,
+            connection_statuses,
+            process_info,
+        ) = match &self.transport {
+            Transport::Http(client) => {
+                client
+                    .fetch_remote_data(&config.hosts, &self.semaphore, self.process_top)
+                    .await
+            }
+            Transport::Ssh(client) => {
+                client
+                    .fetch_remote_data(&config.hosts, &self.semaphore, self.process_top)
+                    .await
+            }
+        };
 
-        let deduplicated_storage = Self::deduplicate_storage_info(storage_info);
+        // Within a bond, the later endpoint's copy of a device or mount wins
+        let bonds = BondOrder::new(&config.bonds);
+        let (mut gpu_info, mut storage_info) = (gpu_info, storage_info);
+        bonds.sort(&mut gpu_info, |gpu| &gpu.host_id);
+        bonds.sort(&mut storage_info, |storage| &storage.host_id);
+        let deduplicated_storage = Self::deduplicate_storage_info(storage_info, &bonds);
 
         Ok(CollectionData {
-            gpu_info,
+            gpu_info: dedup_gpus(gpu_info),
             cpu_info,
             memory_info,
-            process_info: Vec::new(), // No process info in remote mode
+            process_info,
             storage_info: deduplicated_storage,
-            chassis_info: Vec::new(), // TODO: Parse chassis info from remote metrics
+            network_info,
+            chassis_info,
             connection_statuses,
+            timings: Vec::new(),
         })
     }
 
@@ -194,6 +288,34 @@ impl DataCollectionStrategy for RemoteCollector {
     ) {
         let mut state = app_state.lock().await;
 
+        // Update connection status and maintain known hosts; responses are in
+        // arrival order, so the last one of a node's addresses is the freshest
+        let responders: Vec<String> = data
+            .connection_statuses
+            .iter()
+            .filter(|status| status.is_connected)
+            .map(|status| status.host_id.clone())
+            .collect();
+        Self::update_connection_status(
+            &mut state,
+            data.connection_statuses,
+            &config.hosts,
+            &config.bonds,
+        );
+
+        // Show each node once even when it is listed under several addresses
+        let merge = EndpointMerge::new(&state.host_info, &responders);
+        let data = CollectionData {
+            gpu_info: merge.union(data.gpu_info, |gpu| &mut gpu.host_id),
+            cpu_info: merge.apply(data.cpu_info, |cpu| &mut cpu.host_id),
+            memory_info: merge.apply(data.memory_info, |memory| &mut memory.host_id),
+            storage_info: merge.union(data.storage_info, |storage| &mut storage.host_id),
+            network_info: merge.apply(data.network_info, |network| &mut network.host_id),
+            chassis_info: merge.apply(data.chassis_info, |chassis| &mut chassis.host_id),
+            process_info: merge.union(data.process_info, |process| &mut process.host_id),
+            ..CollectionData::new()
+        };
+
         // Only update GPU info if we have valid data (not empty and has memory info)
         if !data.gpu_info.is_empty() && data.gpu_info.iter().any(|gpu| gpu.total_memory > 0) {
             state.gpu_info = data.gpu_info;
@@ -206,9 +328,8 @@ impl DataCollectionStrategy for RemoteCollector {
         state.cpu_info = data.cpu_info;
         state.memory_info = data.memory_info;
         state.storage_info = data.storage_info;
-
-        // Update connection status and maintain known hosts
-        Self::update_connection_status(&mut state, data.connection_statuses, &config.hosts);
+        state.network_info = data.network_info;
+        state.chassis_info = data.chassis_info;
 
         // Update utilization history
         self.aggregator.update_utilization_history(&mut state);
@@ -216,7 +337,14 @@ impl DataCollectionStrategy for RemoteCollector {
         // Update tabs from all device hostnames (including disconnected ones)
         Self::update_remote_tabs(&mut state);
 
-        state.process_info = Vec::new(); // No process info in remote mode
+        // One list for all nodes, in the order the sort keys ask for
+        let mut process_info = data.process_info;
+        process_info.sort_by(|a, b| {
+            state
+                .sort_criteria
+                .sort_processes(a, b, state.sort_direction)
+        });
+        state.process_info = process_info;
         state.loading = false;
 
         // Mark data as changed to trigger UI update
@@ -231,6 +359,8 @@ impl DataCollectionStrategy for RemoteCollector {
 pub struct RemoteCollectorBuilder {
     hosts: Vec<String>,
     max_connections: Option<usize>,
+    ssh: Option<SshOptions>,
+    process_top: usize,
 }
 
 impl RemoteCollectorBuilder {
@@ -238,9 +368,23 @@ impl RemoteCollectorBuilder {
         Self {
             hosts: Vec::new(),
             max_connections: None,
+            ssh: None,
+            process_top: DEFAULT_REMOTE_PROCESS_TOP,
         }
     }
 
+    /// Scrape the hosts over SSH instead of HTTP
+    pub fn with_ssh(mut self, options: SshOptions) -> Self {
+        self.ssh = Some(options);
+        self
+    }
+
+    /// Keep the `limit` processes of each node using the most GPU memory
+    pub fn with_process_top(mut self, limit: usize) -> Self {
+        self.process_top = limit;
+        self
+    }
+
     pub fn with_hosts(mut self, hosts: Vec<String>) -> Self {
         self.hosts = hosts;
         self
@@ -252,92 +396,18 @@ impl RemoteCollectorBuilder {
         self
     }
 
-    pub fn load_hosts_from_file(mut self, file_path: &str) -> Result<Self, std::io::Error> {
-        use std::path::Path;
-
-        // Sanitize and validate file path
-        let path = Path::new(file_path);
-
-        // Resolve to absolute path and check it exists
-        let canonical_path = path.canonicalize().map_err(|e| {
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("Invalid hostfile path: {e}"),
-            )
-        })?;
-
-        // Ensure it's a file, not a directory
-        if !canonical_path.is_file() {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Hostfile path is not a regular file",
-            ));
-        }
-
-        // Check file size to prevent loading huge files
-        let metadata = std::fs::metadata(&canonical_path)?;
-        const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10MB max
-        if metadata.len() > MAX_FILE_SIZE {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!(
-                    "Hostfile too large: {} bytes (max: {MAX_FILE_SIZE} bytes)",
-                    metadata.len()
-                ),
-            ));
-        }
-
-        let content = std::fs::read_to_string(&canonical_path)?;
-
-        // Limit number of hosts to prevent memory exhaustion
-        const MAX_HOSTS: usize = 1000;
-        let mut host_count = 0;
-
-        let file_hosts: Vec<String> = content
-            .lines()
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .filter(|s| !s.starts_with('#'))
-            .take(MAX_HOSTS)
-            .filter_map(|s| {
-                host_count += 1;
-                if host_count > MAX_HOSTS {
-                    eprintln!("Warning: Hostfile contains more than {MAX_HOSTS} hosts, truncating");
-                    return None;
-                }
-
-                // Validate host format (basic validation)
-                let host = if let Some(stripped) = s.strip_prefix("http://") {
-                    stripped.to_string()
-                } else if let Some(stripped) = s.strip_prefix("https://") {
-                    stripped.to_string()
-                } else {
-                    s.to_string()
-                };
-
-                // Basic validation: must contain valid characters
-                if host
-                    .chars()
-                    .all(|c| c.is_ascii() && (c.is_alphanumeric() || ".-:_".contains(c)))
-                {
-                    Some(host)
-                } else {
-                    eprintln!("Warning: Invalid host format skipped: {s}");
-                    None
-                }
-            })
-            .collect();
-
-        self.hosts.extend(file_hosts);
-        Ok(self)
-    }
-
     pub fn build(self) -> RemoteCollector {
         let max_connections = self
             .max_connections
             .unwrap_or_else(|| EnvConfig::max_concurrent_connections(self.hosts.len()));
 
-        RemoteCollector::new(max_connections)
+        let transport = match self.ssh {
+            Some(options) => Transport::Ssh(Arc::new(SshClient::new(options))),
+            None => Transport::Http(NetworkClient::new()),
+        };
+        let mut collector = RemoteCollector::with_transport(transport, max_connections);
+        collector.process_top = self.process_top;
+        collector
     }
 }
 
@@ -346,3 +416,110 @@ impl Default for RemoteCollectorBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hosts(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reconcile_known_hosts_keeps_order() {
+        let mut known = hosts(&["node1:9090", "node2:9090", "node3:9090"]);
+        reconcile_known_hosts(
+            &mut known,
+            &hosts(&["node4:9090", "node3:9090", "node1:9090"]),
+        );
+        assert_eq!(known, hosts(&["node1:9090", "node3:9090", "node4:9090"]));
+    }
+
+    #[test]
+    fn test_reload_keeps_selected_tab() {
+        let mut state = AppState::new();
+        let initial = hosts(&["node1:9090", "node2:9090", "node3:9090"]);
+        RemoteCollector::update_connection_status(&mut state, Vec::new(), &initial, &[]);
+        RemoteCollector::update_remote_tabs(&mut state);
+        state.current_tab = 3; // node3
+
+        // node1 is drained and node4 added
+        let reloaded = hosts(&["node2:9090", "node3:9090", "node4:9090"]);
+        RemoteCollector::update_connection_status(&mut state, Vec::new(), &reloaded, &[]);
+        RemoteCollector::update_remote_tabs(&mut state);
+        assert_eq!(
+            state.tabs,
+            hosts(&["All", "node2:9090", "node3:9090", "node4:9090"])
+        );
+        assert_eq!(state.tabs[state.current_tab], "node3:9090");
+        assert!(!state.connection_status.contains_key("node1:9090"));
+
+        // Removing the selected host keeps the selection in range
+        RemoteCollector::update_connection_status(
+            &mut state,
+            Vec::new(),
+            &hosts(&["node2:9090"]),
+            &[],
+        );
+        RemoteCollector::update_remote_tabs(&mut state);
+        assert_eq!(state.tabs, hosts(&["All", "node2:9090"]));
+        assert_eq!(state.current_tab, 1);
+    }
+
+    #[test]
+    fn test_addresses_of_one_node_share_a_tab() {
+        let mut state = AppState::new();
+        let listed = hosts(&["10.0.0.1:9090", "node2:9090", "node1:9090"]);
+        let statuses = [
+            ("node1:9090", "node1"),
+            ("node2:9090", "node2"),
+            ("10.0.0.1:9090", "node1"),
+        ]
+        .into_iter()
+        .map(|(host, instance)| {
+            let mut status = ConnectionStatus::new(host.to_string(), host.to_string());
+            status.mark_success();
+            status.actual_hostname = Some(instance.to_string());
+            status
+        })
+        .collect();
+        RemoteCollector::update_connection_status(&mut state, statuses, &listed, &[]);
+        RemoteCollector::update_remote_tabs(&mut state);
+
+        assert_eq!(state.tabs, hosts(&["All", "10.0.0.1:9090", "node2:9090"]));
+        assert_eq!(
+            state.merged_addresses("10.0.0.1:9090"),
+            Some(hosts(&["10.0.0.1:9090", "node1:9090"]).as_slice())
+        );
+
+        // The node stays up while either address answers
+        state
+            .connection_status
+            .get_mut("10.0.0.1:9090")
+            .unwrap()
+            .mark_failure("timeout".to_string());
+        assert_eq!(state.is_host_connected("10.0.0.1:9090"), Some(true));
+    }
+
+    #[test]
+    fn test_bond_has_one_tab_named_after_it() {
+        let mut state = AppState::new();
+        let listed = hosts(&["node1:9090", "nodeA:9090", "nodeA:9091"]);
+        let bonds = [HostBond {
+            name: "nodeA".to_string(),
+            endpoints: hosts(&["nodeA:9090", "nodeA:9091"]),
+        }];
+        let mut status = ConnectionStatus::new("nodeA:9091".to_string(), "nodeA:9091".to_string());
+        status.mark_success();
+        status.actual_hostname = Some("nodeA".to_string());
+        RemoteCollector::update_connection_status(&mut state, vec![status], &listed, &bonds);
+        RemoteCollector::update_remote_tabs(&mut state);
+
+        assert_eq!(state.tabs, hosts(&["All", "node1:9090", "nodeA"]));
+        assert!(state.host_info["nodeA"].bonded);
+        assert!(!state.hostname_to_host_id.contains_key("nodeA"));
+        // Up while one endpoint answers; the other has no response yet
+        assert_eq!(state.is_host_connected("nodeA"), Some(true));
+        assert!(!state.connection_status["nodeA:9090"].is_connected);
+    }
+}
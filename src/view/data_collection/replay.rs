@@ -0,0 +1,683 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recording local-mode snapshots with `--record` and playing them back with
+//! `all-smi replay` or `--replay`.
+//!
+//! A recording is JSON Lines: one [`Snapshot`] per collection cycle, each
+//! stamped with the wall-clock time it was taken. A directory of Prometheus
+//! text scrapes, one file per scrape named by its time, replays the same way.
+//! Playback waits out the recorded gaps, divided by the replay speed, and then
+//! starts over. Recordings can be large, so only the offsets and times of the
+//! snapshots are kept in memory and a few around the playback position are
+//! decoded at a time.
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+use crate::app_state::AppState;
+use crate::device::{ChassisInfo, CpuInfo, GpuInfo, MemoryInfo, ProcessInfo};
+use crate::network::metrics_parser::MetricsParser;
+use crate::network::NetworkInfo;
+use crate::storage::info::StorageInfo;
+
+use super::aggregator::DataAggregator;
+use super::local_collector::LocalCollector;
+use super::strategy::{CollectionConfig, CollectionData, CollectionResult, DataCollectionStrategy};
+
+/// Pause after the last snapshot of a single-snapshot recording, before
+/// `--replay-speed` is applied
+const DEFAULT_GAP: Duration = Duration::from_secs(1);
+
+/// Snapshots kept decoded on either side of the one last read
+const WINDOW_RADIUS: usize = 16;
+
+/// One recorded collection cycle.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Seconds since the Unix epoch
+    pub timestamp: f64,
+    #[serde(default)]
+    pub gpu_info: Vec<GpuInfo>,
+    #[serde(default)]
+    pub cpu_info: Vec<CpuInfo>,
+    #[serde(default)]
+    pub memory_info: Vec<MemoryInfo>,
+    #[serde(default)]
+    pub process_info: Vec<ProcessInfo>,
+    #[serde(default)]
+    pub storage_info: Vec<StorageInfo>,
+    #[serde(default)]
+    pub network_info: Vec<NetworkInfo>,
+    #[serde(default)]
+    pub chassis_info: Vec<ChassisInfo>,
+}
+
+impl Snapshot {
+    pub fn capture(data: &CollectionData, at: SystemTime) -> Self {
+        Self {
+            timestamp: at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64(),
+            gpu_info: data.gpu_info.clone(),
+            cpu_info: data.cpu_info.clone(),
+            memory_info: data.memory_info.clone(),
+            process_info: data.process_info.clone(),
+            storage_info: data.storage_info.clone(),
+            network_info: data.network_info.clone(),
+            chassis_info: data.chassis_info.clone(),
+        }
+    }
+
+    /// Snapshot of the data shown in `state`.
+    pub fn from_state(state: &AppState, at: SystemTime) -> Self {
+        Self {
+            timestamp: at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64(),
+            gpu_info: state.gpu_info.clone(),
+            cpu_info: state.cpu_info.clone(),
+            memory_info: state.memory_info.clone(),
+            process_info: state.process_info.clone(),
+            storage_info: state.storage_info.clone(),
+            network_info: state.network_info.clone(),
+            chassis_info: state.chassis_info.clone(),
+        }
+    }
+
+    pub fn to_data(&self) -> CollectionData {
+        CollectionData {
+            gpu_info: self.gpu_info.clone(),
+            cpu_info: self.cpu_info.clone(),
+            memory_info: self.memory_info.clone(),
+            process_info: self.process_info.clone(),
+            storage_info: self.storage_info.clone(),
+            network_info: self.network_info.clone(),
+            chassis_info: self.chassis_info.clone(),
+            ..CollectionData::new()
+        }
+    }
+}
+
+/// Appends snapshots to a `--record` file.
+pub struct Recorder {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    /// Bytes written to the current file
+    written: u64,
+    /// Size at which the file is rotated to `<path>.1`, from `--record-max-mb`
+    max_bytes: Option<u64>,
+}
+
+impl Recorder {
+    /// Create `path`, replacing an earlier recording.
+    pub fn create(path: &Path, max_bytes: Option<u64>) -> io::Result<Self> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(File::create(path)?),
+            written: 0,
+            max_bytes,
+        })
+    }
+
+    /// Write one line and flush it, so an interrupted session still leaves a
+    /// file that replays. A line that would push the file past the size cap
+    /// starts a new file instead, keeping the previous one as `<path>.1`.
+    pub fn record(&mut self, data: &CollectionData) -> io::Result<()> {
+        let mut line = serde_json::to_vec(&Snapshot::capture(data, SystemTime::now()))?;
+        line.push(b'\n');
+        let len = line.len() as u64;
+        if self
+            .max_bytes
+            .is_some_and(|max| self.written > 0 && self.written + len > max)
+        {
+            self.rotate()?;
+        }
+        self.writer.write_all(&line)?;
+        self.writer.flush()?;
+        self.written += len;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        std::fs::rename(&self.path, rotated_path(&self.path))?;
+        self.writer = BufWriter::new(File::create(&self.path)?);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+/// `session.jsonl` rotates to `session.jsonl.1`.
+fn rotated_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".1");
+    PathBuf::from(name)
+}
+
+/// Read a recording. Blank lines are skipped; anything else that does not
+/// parse is an error naming the line.
+pub fn load_snapshots(path: &Path) -> io::Result<Vec<Snapshot>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut snapshots = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let snapshot = serde_json::from_str(&line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {e}", index + 1),
+            )
+        })?;
+        snapshots.push(snapshot);
+    }
+    if snapshots.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "recording has no snapshots",
+        ));
+    }
+    Ok(snapshots)
+}
+
+/// Where a snapshot of a recording is stored.
+enum Location {
+    /// A line of a JSON Lines recording
+    Line { offset: u64, len: usize },
+    /// A Prometheus text scrape
+    Scrape(PathBuf),
+}
+
+struct Entry {
+    /// Seconds since the Unix epoch
+    timestamp: f64,
+    location: Location,
+}
+
+/// Only the timestamp of a recorded line, read while indexing
+#[derive(Deserialize)]
+struct Stamp {
+    timestamp: f64,
+}
+
+/// Reads the snapshots of a recording on demand.
+pub struct RecordingReader {
+    /// The JSON Lines file; `None` for a directory of scrapes
+    file: Option<File>,
+    /// Host the devices of scrapes are attributed to
+    host: String,
+    entries: Vec<Entry>,
+    /// Snapshots decoded around the last one read
+    window: BTreeMap<usize, Snapshot>,
+}
+
+impl RecordingReader {
+    /// Index a `--record` file, or a directory of Prometheus text scrapes.
+    /// Lines of a recording that do not parse are reported with their
+    /// number.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let reader = if path.is_dir() {
+            Self {
+                file: None,
+                host: path.file_name().map_or_else(
+                    || "replay".to_string(),
+                    |name| name.to_string_lossy().into(),
+                ),
+                entries: index_scrapes(path)?,
+                window: BTreeMap::new(),
+            }
+        } else {
+            let mut file = File::open(path)?;
+            let entries = index_lines(&mut file)?;
+            Self {
+                file: Some(file),
+                host: String::new(),
+                entries,
+                window: BTreeMap::new(),
+            }
+        };
+        if reader.entries.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "recording has no snapshots",
+            ));
+        }
+        Ok(reader)
+    }
+
+    /// Number of snapshots; never zero.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The snapshot at `index`, decoded unless it is in the window around
+    /// the previous read. Snapshots far from `index` are dropped.
+    pub fn snapshot(&mut self, index: usize) -> io::Result<&Snapshot> {
+        if !self.window.contains_key(&index) {
+            let snapshot = self.decode(index)?;
+            self.window
+                .retain(|&kept, _| kept.abs_diff(index) <= WINDOW_RADIUS);
+            self.window.insert(index, snapshot);
+        }
+        Ok(&self.window[&index])
+    }
+
+    fn decode(&mut self, index: usize) -> io::Result<Snapshot> {
+        let entry = self
+            .entries
+            .get(index)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "snapshot out of range"))?;
+        match &entry.location {
+            Location::Line { offset, len } => {
+                let file = self.file.as_mut().ok_or(io::ErrorKind::NotFound)?;
+                file.seek(SeekFrom::Start(*offset))?;
+                let mut line = vec![0; *len];
+                file.read_exact(&mut line)?;
+                Ok(serde_json::from_slice(&line)?)
+            }
+            Location::Scrape(path) => {
+                let text = std::fs::read_to_string(path)?;
+                Ok(scrape_snapshot(&text, &self.host, entry.timestamp))
+            }
+        }
+    }
+
+    /// The recorded gap after the snapshot at `index`. The last snapshot
+    /// reuses the first gap before the recording starts over.
+    pub fn delay_after(&self, index: usize) -> Duration {
+        let gap = |from: &Entry, to: &Entry| {
+            Duration::try_from_secs_f64(to.timestamp - from.timestamp).unwrap_or_default()
+        };
+        match (self.entries.get(index), self.entries.get(index + 1)) {
+            (Some(from), Some(to)) => gap(from, to),
+            _ if self.entries.len() > 1 => gap(&self.entries[0], &self.entries[1]),
+            _ => DEFAULT_GAP,
+        }
+    }
+}
+
+/// Offsets and times of the lines of a recording.
+fn index_lines(file: &mut File) -> io::Result<Vec<Entry>> {
+    let mut reader = BufReader::new(file);
+    let mut entries = Vec::new();
+    let mut line = String::new();
+    let mut offset = 0u64;
+    for number in 1.. {
+        line.clear();
+        let read = reader.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        let content = line.trim_end();
+        if !content.trim_start().is_empty() {
+            let stamp: Stamp = serde_json::from_str(content).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("line {number}: {e}"))
+            })?;
+            entries.push(Entry {
+                timestamp: stamp.timestamp,
+                location: Location::Line {
+                    offset,
+                    len: content.len(),
+                },
+            });
+        }
+        offset += read as u64;
+    }
+    Ok(entries)
+}
+
+/// Scrapes in a directory in time order. Hidden files are skipped.
+fn index_scrapes(dir: &Path) -> io::Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for dir_entry in std::fs::read_dir(dir)? {
+        let dir_entry = dir_entry?;
+        let path = dir_entry.path();
+        let metadata = dir_entry.metadata()?;
+        let hidden = dir_entry.file_name().to_string_lossy().starts_with('.');
+        if !metadata.is_file() || hidden {
+            continue;
+        }
+        let timestamp = path
+            .file_stem()
+            .and_then(|stem| scrape_time(&stem.to_string_lossy()))
+            .or_else(|| {
+                let modified = metadata.modified().ok()?;
+                Some(modified.duration_since(UNIX_EPOCH).ok()?.as_secs_f64())
+            })
+            .unwrap_or_default();
+        entries.push(Entry {
+            timestamp,
+            location: Location::Scrape(path),
+        });
+    }
+    entries.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+    Ok(entries)
+}
+
+/// Time in a scrape's file name: Unix seconds (`1735689600.5`) or RFC 3339
+/// (`2025-01-01T00:00:00Z`).
+fn scrape_time(name: &str) -> Option<f64> {
+    if let Ok(seconds) = name.parse::<f64>() {
+        return seconds.is_finite().then_some(seconds);
+    }
+    let time = DateTime::parse_from_rfc3339(name).ok()?;
+    Some(time.timestamp_millis() as f64 / 1000.0)
+}
+
+/// The devices of a Prometheus text scrape. Scrapes carry no process list.
+fn scrape_snapshot(text: &str, host: &str, timestamp: f64) -> Snapshot {
+    let (mut gpu_info, cpu_info, memory_info, storage_info, network_info, chassis_info) =
+        MetricsParser::new().parse_metrics(text, host);
+    gpu_info.sort_by(|a, b| a.hostname.cmp(&b.hostname).then(a.uuid.cmp(&b.uuid)));
+    Snapshot {
+        timestamp,
+        gpu_info,
+        cpu_info,
+        memory_info,
+        process_info: Vec::new(),
+        storage_info,
+        network_info,
+        chassis_info,
+    }
+}
+
+/// Plays a recording back in a loop.
+pub struct ReplayCollector {
+    reader: std::sync::Mutex<RecordingReader>,
+    /// Index of the snapshot the next `collect` returns
+    position: AtomicUsize,
+    aggregator: DataAggregator,
+}
+
+impl ReplayCollector {
+    pub fn new(reader: RecordingReader) -> Self {
+        Self {
+            reader: std::sync::Mutex::new(reader),
+            position: AtomicUsize::new(0),
+            aggregator: DataAggregator::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.reader().len()
+    }
+
+    /// See [`RecordingReader::delay_after`].
+    pub fn delay_after(&self, index: usize) -> Duration {
+        self.reader().delay_after(index)
+    }
+
+    /// The data of the snapshot at `index` and its recorded time.
+    pub fn snapshot_data(&self, index: usize) -> io::Result<(CollectionData, f64)> {
+        let mut reader = self.reader();
+        let snapshot = reader.snapshot(index)?;
+        Ok((snapshot.to_data(), snapshot.timestamp))
+    }
+
+    fn reader(&self) -> std::sync::MutexGuard<'_, RecordingReader> {
+        self.reader.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl DataCollectionStrategy for ReplayCollector {
+    /// The next snapshot, wrapping at the end of the recording.
+    async fn collect(&self, _config: &CollectionConfig) -> CollectionResult {
+        let len = self.len();
+        let index = self
+            .position
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |position| {
+                Some((position + 1) % len)
+            })
+            .unwrap_or_default();
+        Ok(self.snapshot_data(index)?.0)
+    }
+
+    async fn update_state(
+        &self,
+        app_state: Arc<Mutex<AppState>>,
+        data: CollectionData,
+        _config: &CollectionConfig,
+    ) {
+        let mut state = app_state.lock().await;
+        LocalCollector::apply_to_state(&mut state, data, &self.aggregator);
+    }
+
+    fn strategy_type(&self) -> &str {
+        "replay"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(timestamp: f64, utilization: f64) -> Snapshot {
+        Snapshot {
+            timestamp,
+            gpu_info: vec![GpuInfo {
+                uuid: "GPU-0".to_string(),
+                name: "Test GPU".to_string(),
+                device_type: "GPU".to_string(),
+                host_id: "node1".to_string(),
+                hostname: "node1".to_string(),
+                instance: "node1".to_string(),
+                utilization,
+                temperature: 50,
+                used_memory: 1 << 30,
+                total_memory: 8 << 30,
+                frequency: 1500,
+                power_consumption: 100.0,
+                ..Default::default()
+            }],
+            cpu_info: Vec::new(),
+            memory_info: Vec::new(),
+            process_info: Vec::new(),
+            storage_info: Vec::new(),
+            network_info: Vec::new(),
+            chassis_info: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_record_then_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let mut recorder = Recorder::create(&path, None).unwrap();
+        recorder.record(&snapshot(0.0, 10.0).to_data()).unwrap();
+        recorder.record(&snapshot(0.0, 20.0).to_data()).unwrap();
+        drop(recorder);
+
+        let snapshots = load_snapshots(&path).unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].gpu_info[0].utilization, 20.0);
+        assert!(snapshots[0].timestamp > 0.0);
+        assert!(snapshots[1].timestamp >= snapshots[0].timestamp);
+    }
+
+    #[test]
+    fn test_record_rotates_at_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capped.jsonl");
+        let data = snapshot(0.0, 10.0).to_data();
+        let mut line = serde_json::to_vec(&Snapshot::capture(&data, SystemTime::now())).unwrap();
+        line.push(b'\n');
+        // Room for two lines, with some slack for timestamp width
+        let cap = line.len() as u64 * 2 + 8;
+
+        let mut recorder = Recorder::create(&path, Some(cap)).unwrap();
+        for _ in 0..5 {
+            recorder.record(&data).unwrap();
+        }
+        drop(recorder);
+
+        let current = std::fs::metadata(&path).unwrap().len();
+        let rotated = std::fs::metadata(rotated_path(&path)).unwrap().len();
+        assert!(current <= cap && rotated <= cap);
+        assert_eq!(load_snapshots(&path).unwrap().len(), 1);
+        assert_eq!(load_snapshots(&rotated_path(&path)).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_load_accepts_partial_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("partial.jsonl");
+        std::fs::write(&path, "{\"timestamp\": 1.5}\n\n{\"timestamp\": 2.5}\n").unwrap();
+        let snapshots = load_snapshots(&path).unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots[0].gpu_info.is_empty());
+    }
+
+    #[test]
+    fn test_load_reports_bad_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bad.jsonl");
+        std::fs::write(&path, "{\"timestamp\": 1.0}\nnot json\n").unwrap();
+        let err = load_snapshots(&path).err().unwrap();
+        assert!(err.to_string().starts_with("line 2:"));
+
+        std::fs::write(&path, "\n").unwrap();
+        assert!(load_snapshots(&path).is_err());
+    }
+
+    /// Write `snapshots` as a recording and open it.
+    fn reader_for(dir: &Path, snapshots: &[Snapshot]) -> RecordingReader {
+        let path = dir.join("session.jsonl");
+        let mut text = String::new();
+        for snapshot in snapshots {
+            text.push_str(&serde_json::to_string(snapshot).unwrap());
+            text.push('\n');
+        }
+        std::fs::write(&path, text).unwrap();
+        RecordingReader::open(&path).unwrap()
+    }
+
+    #[test]
+    fn test_delay_follows_recorded_gaps() {
+        let dir = tempfile::tempdir().unwrap();
+        let reader = reader_for(
+            dir.path(),
+            &[
+                snapshot(10.0, 0.0),
+                snapshot(12.0, 0.0),
+                snapshot(13.0, 0.0),
+            ],
+        );
+        assert_eq!(reader.delay_after(0), Duration::from_secs(2));
+        assert_eq!(reader.delay_after(1), Duration::from_secs(1));
+        // Wrapping around reuses the first gap
+        assert_eq!(reader.delay_after(2), Duration::from_secs(2));
+
+        // Out-of-order timestamps do not stall playback
+        let reader = reader_for(dir.path(), &[snapshot(5.0, 0.0), snapshot(3.0, 0.0)]);
+        assert_eq!(reader.delay_after(0), Duration::ZERO);
+
+        let reader = reader_for(dir.path(), &[snapshot(5.0, 0.0)]);
+        assert_eq!(reader.delay_after(0), DEFAULT_GAP);
+    }
+
+    #[test]
+    fn test_reader_decodes_a_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshots: Vec<Snapshot> = (0..100).map(|i| snapshot(i as f64, i as f64)).collect();
+        let path = dir.path().join("long.jsonl");
+        let mut text = String::from("\n");
+        for snapshot in &snapshots {
+            text.push_str(&serde_json::to_string(snapshot).unwrap());
+            text.push_str("\r\n");
+        }
+        std::fs::write(&path, text).unwrap();
+
+        let mut reader = RecordingReader::open(&path).unwrap();
+        assert_eq!(reader.len(), 100);
+        assert!(reader.window.is_empty());
+        for index in [0, 1, 2, 50, 99, 60, 49] {
+            let shown = reader.snapshot(index).unwrap();
+            assert_eq!(shown.gpu_info[0].utilization, index as f64);
+        }
+        // Only snapshots near the last read stay decoded
+        assert_eq!(reader.window.keys().copied().collect::<Vec<_>>(), [49, 60]);
+        assert!(reader.snapshot(100).is_err());
+    }
+
+    #[test]
+    fn test_open_reports_bad_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bad.jsonl");
+        std::fs::write(&path, "{\"timestamp\": 1.0}\n\n{\"gpu_info\": []}\n").unwrap();
+        let err = RecordingReader::open(&path).err().unwrap();
+        assert!(err.to_string().starts_with("line 3:"));
+
+        std::fs::write(&path, "\n").unwrap();
+        assert!(RecordingReader::open(&path).is_err());
+    }
+
+    #[test]
+    fn test_scrape_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let scrapes = dir.path().join("node1");
+        std::fs::create_dir(&scrapes).unwrap();
+        let scrape = |utilization: f64| {
+            format!(
+                "all_smi_gpu_utilization{{gpu=\"Test GPU\",instance=\"node1\",uuid=\"GPU-0\",index=\"0\"}} {utilization}\n"
+            )
+        };
+        std::fs::write(scrapes.join("2025-01-01T00:00:10Z.prom"), scrape(70.0)).unwrap();
+        std::fs::write(scrapes.join("1735689600.prom"), scrape(30.0)).unwrap();
+        std::fs::write(scrapes.join(".partial"), "").unwrap();
+
+        let mut reader = RecordingReader::open(&scrapes).unwrap();
+        assert_eq!(reader.len(), 2);
+        assert_eq!(reader.delay_after(0), Duration::from_secs(10));
+        let first = reader.snapshot(0).unwrap();
+        assert_eq!(first.timestamp, 1_735_689_600.0);
+        assert_eq!(first.gpu_info[0].utilization, 30.0);
+        assert_eq!(reader.snapshot(1).unwrap().gpu_info[0].utilization, 70.0);
+
+        assert_eq!(scrape_time("1735689600.25"), Some(1_735_689_600.25));
+        assert_eq!(scrape_time("scrape"), None);
+    }
+
+    #[tokio::test]
+    async fn test_replay_updates_state_and_wraps() {
+        let dir = tempfile::tempdir().unwrap();
+        let reader = reader_for(dir.path(), &[snapshot(0.0, 10.0), snapshot(1.0, 90.0)]);
+        let collector = ReplayCollector::new(reader);
+        let app_state = Arc::new(Mutex::new(AppState::new()));
+        let config = CollectionConfig::default();
+
+        for expected in [10.0, 90.0, 10.0] {
+            let data = collector.collect(&config).await.unwrap();
+            collector
+                .update_state(app_state.clone(), data, &config)
+                .await;
+            let state = app_state.lock().await;
+            assert_eq!(state.gpu_info[0].utilization, expected);
+            assert!(!state.loading);
+        }
+    }
+}
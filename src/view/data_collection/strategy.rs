@@ -14,12 +14,16 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
 use crate::app_state::{AppState, ConnectionStatus};
 use crate::device::{ChassisInfo, CpuInfo, GpuInfo, MemoryInfo, ProcessInfo};
+use crate::network::NetworkInfo;
 use crate::storage::info::StorageInfo;
 
+use super::hostfile::HostBond;
+
 /// Result type for data collection operations
 pub type CollectionResult = Result<CollectionData, CollectionError>;
 
@@ -31,8 +35,43 @@ pub struct CollectionData {
     pub memory_info: Vec<MemoryInfo>,
     pub process_info: Vec<ProcessInfo>,
     pub storage_info: Vec<StorageInfo>,
+    pub network_info: Vec<NetworkInfo>,
     pub chassis_info: Vec<ChassisInfo>,
     pub connection_statuses: Vec<ConnectionStatus>,
+    /// Time each category took to collect; local mode only
+    pub timings: Vec<CategoryTiming>,
+}
+
+/// Reader categories that local mode collects independently of each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionCategory {
+    Gpu,
+    Cpu,
+    Memory,
+    Processes,
+    Storage,
+    Network,
+    Chassis,
+}
+
+impl CollectionCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CollectionCategory::Gpu => "GPU",
+            CollectionCategory::Cpu => "CPU",
+            CollectionCategory::Memory => "Memory",
+            CollectionCategory::Processes => "Processes",
+            CollectionCategory::Storage => "Storage",
+            CollectionCategory::Network => "Network",
+            CollectionCategory::Chassis => "Chassis",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryTiming {
+    pub category: CollectionCategory,
+    pub duration: Duration,
 }
 
 impl CollectionData {
@@ -43,8 +82,10 @@ impl CollectionData {
             memory_info: Vec::new(),
             process_info: Vec::new(),
             storage_info: Vec::new(),
+            network_info: Vec::new(),
             chassis_info: Vec::new(),
             connection_statuses: Vec::new(),
+            timings: Vec::new(),
         }
     }
 }
@@ -78,6 +119,8 @@ pub struct CollectionConfig {
     pub interval: u64,
     pub first_iteration: bool,
     pub hosts: Vec<String>,
+    /// Endpoints of `hosts` that report for one node together
+    pub bonds: Vec<HostBond>,
 }
 
 impl Default for CollectionConfig {
@@ -86,6 +129,7 @@ impl Default for CollectionConfig {
             interval: 2,
             first_iteration: true,
             hosts: Vec::new(),
+            bonds: Vec::new(),
         }
     }
 }
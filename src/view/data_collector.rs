@@ -13,16 +13,23 @@
 // limitations under the License.
 
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Notify};
 
-use crate::app_state::AppState;
+use crate::api::textfile::{local_metrics, TextfileWriter};
+use crate::app_state::{AppState, RefreshInterval};
 use crate::cli::ViewArgs;
 use crate::common::config::EnvConfig;
+use crate::device::CollectionFilter;
+use crate::network::ssh::SshOptions;
+
+use super::data_collection::fetch_latency::FetchLatencies;
+use super::data_collection::hostfile::{load_hosts, HostChanges, HostfileWatcher};
 
 // Re-export for backward compatibility
 pub use super::data_collection::{
-    CollectionConfig, DataCollectionStrategy, LocalCollector, RemoteCollectorBuilder,
+    CollectionConfig, DataCollectionStrategy, LocalCollector, Recorder, RemoteCollectorBuilder,
+    ReplayCollector,
 };
 
 pub struct DataCollector {
@@ -34,12 +41,19 @@ impl DataCollector {
         Self { app_state }
     }
 
-    pub async fn run_local_mode(&self, args: ViewArgs) {
+    pub async fn run_local_mode(
+        &self,
+        args: ViewArgs,
+        filter: CollectionFilter,
+        mut recorder: Option<Recorder>,
+        textfile: Option<TextfileWriter>,
+    ) {
         let mut profiler = crate::utils::StartupProfiler::new();
         profiler.checkpoint("Starting local mode data collection");
 
-        let collector = LocalCollector::new();
+        let collector = LocalCollector::new(filter);
         let mut first_iteration = true;
+        let refresh = self.app_state.lock().await.refresh_now.clone();
 
         loop {
             let mut config = CollectionConfig {
@@ -48,6 +62,7 @@ impl DataCollector {
                     .unwrap_or_else(|| EnvConfig::adaptive_interval(1)),
                 first_iteration,
                 hosts: Vec::new(),
+                bonds: Vec::new(),
             };
 
             // Special handling for first iteration with app_state
@@ -63,7 +78,7 @@ impl DataCollector {
                         data
                     }
                     Err(e) => {
-                        eprintln!("Error collecting data: {e}");
+                        tracing::error!("Error collecting data: {e}");
                         tokio::time::sleep(Duration::from_secs(config.interval)).await;
                         continue;
                     }
@@ -72,17 +87,28 @@ impl DataCollector {
                 match collector.collect(&config).await {
                     Ok(data) => data,
                     Err(e) => {
-                        eprintln!("Error collecting data: {e}");
+                        tracing::error!("Error collecting data: {e}");
                         tokio::time::sleep(Duration::from_secs(config.interval)).await;
                         continue;
                     }
                 }
             };
 
+            if let Some(active) = recorder.as_mut() {
+                if let Err(e) = active.record(&data) {
+                    tracing::error!("Stopped recording: {e}");
+                    recorder = None;
+                }
+            }
+
             // Update state with collected data
             collector
                 .update_state(self.app_state.clone(), data, &config)
                 .await;
+            if let Some(writer) = &textfile {
+                let metrics = local_metrics(&*self.app_state.lock().await);
+                writer.publish(&metrics);
+            }
 
             if first_iteration {
                 first_iteration = false;
@@ -93,123 +119,166 @@ impl DataCollector {
             let interval = args
                 .interval
                 .unwrap_or_else(|| EnvConfig::adaptive_interval(1));
-            tokio::time::sleep(Duration::from_secs(interval)).await;
+            self.app_state.lock().await.refresh_interval = Some(RefreshInterval {
+                secs: interval,
+                slowed_by: None,
+            });
+            sleep_or_refresh(&refresh, Duration::from_secs(interval)).await;
         }
     }
 
-    pub async fn run_remote_mode(
-        &self,
-        args: ViewArgs,
-        mut hosts: Vec<String>,
-        hostfile: Option<String>,
-    ) {
-        // Strip protocol prefix from command line hosts
-        hosts = hosts
-            .into_iter()
-            .map(|host| {
-                if let Some(stripped) = host.strip_prefix("http://") {
-                    stripped.to_string()
-                } else if let Some(stripped) = host.strip_prefix("https://") {
-                    stripped.to_string()
-                } else {
-                    host
-                }
-            })
-            .collect();
-
-        // Load hosts from file if specified
-        let mut builder = RemoteCollectorBuilder::new().with_hosts(hosts.clone());
+    /// Feed recorded snapshots through the local update path, keeping the
+    /// recorded pacing. The playback keys move `state.replay`; this loop
+    /// shows whichever snapshot it points at and advances it while playing.
+    pub async fn run_replay_mode(&self, collector: ReplayCollector) {
+        /// How often playback controls are picked up
+        const TICK: Duration = Duration::from_millis(50);
 
-        if let Some(ref file_path) = hostfile {
-            match builder.load_hosts_from_file(file_path) {
-                Ok(b) => builder = b,
-                Err(e) => {
-                    eprintln!("Error loading hosts from file {file_path}: {e}");
+        let config = CollectionConfig {
+            first_iteration: false,
+            ..CollectionConfig::default()
+        };
+        let mut shown: Option<(usize, Instant)> = None;
+        loop {
+            let position = {
+                let mut state = self.app_state.lock().await;
+                let Some(timeline) = state.replay.as_mut() else {
                     return;
+                };
+                if let Some((index, since)) = shown {
+                    let due = since.elapsed() >= timeline.scale(collector.delay_after(index));
+                    if index == timeline.position && !timeline.paused && due {
+                        timeline.advance();
+                    }
                 }
+                timeline.position
+            };
+
+            if shown.map(|(index, _)| index) != Some(position) {
+                match collector.snapshot_data(position) {
+                    Ok((data, timestamp)) => {
+                        collector
+                            .update_state(self.app_state.clone(), data, &config)
+                            .await;
+                        if let Some(timeline) = self.app_state.lock().await.replay.as_mut() {
+                            timeline.shown_at = Some(timestamp);
+                        }
+                    }
+                    Err(e) => tracing::error!("Skipping snapshot {}: {e}", position + 1),
+                }
+                shown = Some((position, Instant::now()));
             }
+            tokio::time::sleep(TICK).await;
         }
+    }
 
+    pub async fn run_remote_mode(
+        &self,
+        args: ViewArgs,
+        hosts: Vec<String>,
+        hostfiles: Vec<String>,
+    ) {
+        let mut hosts_list = match load_hosts(&hosts, &hostfiles) {
+            Ok(hosts_list) => hosts_list,
+            Err(e) => {
+                tracing::error!("{e}");
+                return;
+            }
+        };
+        let mut builder = RemoteCollectorBuilder::new()
+            .with_hosts(hosts_list.hosts.clone())
+            .with_process_top(args.process_top);
+        if args.ssh.ssh {
+            builder = builder.with_ssh(SshOptions {
+                identity: args.ssh.ssh_identity.clone(),
+                remote_command: args.ssh.ssh_command.clone(),
+            });
+        }
         let collector = builder.build();
+        let mut watcher = HostfileWatcher::new(&hostfiles);
+        let mut latencies = FetchLatencies::new();
+        let refresh = self.app_state.lock().await.refresh_now.clone();
 
         loop {
-            // Get the current hosts from builder with validation
-            let hosts_list = if let Some(file_path) = &hostfile {
-                let mut hosts_vec = hosts.clone();
-
-                // Validate file path
-                match std::fs::metadata(file_path) {
-                    Ok(metadata) => {
-                        const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10MB
-                        if metadata.len() > MAX_FILE_SIZE {
-                            eprintln!("Warning: Hostfile too large, skipping reload");
-                            hosts_vec
-                        } else if let Ok(content) = std::fs::read_to_string(file_path) {
-                            const MAX_HOSTS: usize = 1000;
-                            let file_hosts: Vec<String> = content
-                                .lines()
-                                .map(|s| s.trim())
-                                .filter(|s| !s.is_empty())
-                                .filter(|s| !s.starts_with('#'))
-                                .take(MAX_HOSTS)
-                                .filter_map(|s| {
-                                    let host = if let Some(stripped) = s.strip_prefix("http://") {
-                                        stripped.to_string()
-                                    } else if let Some(stripped) = s.strip_prefix("https://") {
-                                        stripped.to_string()
-                                    } else {
-                                        s.to_string()
-                                    };
-
-                                    // Basic host validation
-                                    if host.chars().all(|c| {
-                                        c.is_ascii() && (c.is_alphanumeric() || ".-:_".contains(c))
-                                    }) {
-                                        Some(host)
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect();
-                            hosts_vec.extend(file_hosts);
-                            hosts_vec
-                        } else {
-                            hosts_vec
+            // Pick up hostfile edits; the previous scrape has finished by now,
+            // so removed hosts simply drop out of the next one
+            if watcher.changed() {
+                match load_hosts(&hosts, &hostfiles) {
+                    Ok(reloaded) => {
+                        let changes = HostChanges::between(&hosts_list.hosts, &reloaded.hosts);
+                        hosts_list = reloaded;
+                        if !changes.is_empty() {
+                            tracing::info!(
+                                added = changes.added,
+                                removed = changes.removed,
+                                "Hosts reloaded"
+                            );
+                            let mut state = self.app_state.lock().await;
+                            let _ = state.notifications.status(format!(
+                                "Hosts reloaded: +{} / -{}",
+                                changes.added, changes.removed
+                            ));
                         }
                     }
-                    Err(e) => {
-                        eprintln!("Warning: Cannot access hostfile: {e}");
-                        hosts_vec
-                    }
+                    Err(e) => tracing::warn!("Keeping current hosts: {e}"),
                 }
-            } else {
-                hosts.clone()
-            };
+            }
 
             let config = CollectionConfig {
-                interval: args
-                    .interval
-                    .unwrap_or_else(|| EnvConfig::adaptive_interval(hosts_list.len())),
+                interval: remote_interval(args.interval, hosts_list.hosts.len(), latencies.p95())
+                    .secs,
                 first_iteration: false,
-                hosts: hosts_list.clone(),
+                hosts: hosts_list.hosts.clone(),
+                bonds: hosts_list.bonds.clone(),
             };
 
-            match collector.collect(&config).await {
+            let started = Instant::now();
+            let collected = collector.collect(&config).await;
+            latencies.record(started.elapsed());
+            match collected {
                 Ok(data) => {
                     collector
                         .update_state(self.app_state.clone(), data, &config)
                         .await;
                 }
                 Err(e) => {
-                    eprintln!("Error collecting remote data: {e}");
+                    tracing::error!("Error collecting remote data: {e}");
                 }
             }
 
-            // Use adaptive interval for remote mode based on node count
-            let interval = args
-                .interval
-                .unwrap_or_else(|| EnvConfig::adaptive_interval(hosts_list.len()));
-            tokio::time::sleep(Duration::from_secs(interval)).await;
+            // Use adaptive interval for remote mode based on node count,
+            // backing off while fetches take longer than that
+            let interval = remote_interval(args.interval, hosts_list.hosts.len(), latencies.p95());
+            self.app_state.lock().await.refresh_interval = Some(interval);
+            sleep_or_refresh(&refresh, Duration::from_secs(interval.secs)).await;
         }
     }
 }
+
+/// Wait out the collection interval, or less if `r` asks for a refresh.
+async fn sleep_or_refresh(refresh: &Notify, interval: Duration) {
+    tokio::select! {
+        _ = tokio::time::sleep(interval) => {}
+        _ = refresh.notified() => {}
+    }
+}
+
+/// `--interval` if given, else the node-count interval stretched to the p95
+/// latency of recent fetch rounds.
+fn remote_interval(
+    fixed: Option<u64>,
+    node_count: usize,
+    p95_latency: Option<Duration>,
+) -> RefreshInterval {
+    if let Some(secs) = fixed {
+        return RefreshInterval {
+            secs,
+            slowed_by: None,
+        };
+    }
+    let secs = EnvConfig::latency_adaptive_interval(node_count, p95_latency);
+    RefreshInterval {
+        secs,
+        slowed_by: p95_latency.filter(|_| secs > EnvConfig::adaptive_interval(node_count)),
+    }
+}
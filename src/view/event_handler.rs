@@ -13,28 +13,82 @@
 // limitations under the License.
 
 use crossterm::{
-    event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
+    event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     terminal::size,
 };
 
+use std::time::Duration;
+
 use crate::app_state::{AppState, SortCriteria};
 use crate::cli::ViewArgs;
+use crate::common::config::EnvConfig;
+use crate::ui::change_highlight::ChangeHighlight;
+use crate::ui::heatmap::HeatmapGrid;
+use crate::ui::layout::{GpuRowRegion, ScreenLayout};
+use crate::ui::process_columns::ColumnChooser;
+use crate::ui::tabs::tab_hit_regions;
+use crate::utils::process_signal::{Signal, SystemSignalSender};
 
 pub async fn handle_key_event(key_event: KeyEvent, state: &mut AppState, args: &ViewArgs) -> bool {
+    // The host search input captures every key until it is confirmed or cancelled
+    if state.host_search.is_some() {
+        handle_host_search_key(key_event, state);
+        return false;
+    }
+    // So does the confirmation prompt for signaling a process
+    if state.kill_prompt.is_some() {
+        handle_kill_prompt_key(key_event.code, state, args);
+        return false;
+    }
+    if state.show_logs && !state.show_help {
+        match key_event.code {
+            KeyCode::Char('q') => return true,
+            KeyCode::Esc | KeyCode::Char('l') | KeyCode::Char('L') => state.show_logs = false,
+            KeyCode::Char('h') => state.show_help = true,
+            _ => {}
+        }
+        return false;
+    }
+    if state.heatmap.is_some() && !state.show_help {
+        return handle_heatmap_key(key_event.code, state);
+    }
+    if state.column_chooser.is_some() && !state.show_help {
+        return handle_column_chooser_key(key_event.code, state);
+    }
+    if state.replay.is_some() && !state.show_help && handle_replay_key(key_event.code, state) {
+        return false;
+    }
+
     match key_event.code {
         KeyCode::Esc => {
             if state.show_help {
                 state.show_help = false;
                 false
+            } else if state.selected_gpu.take().is_some() {
+                false // Clear the device selection first
             } else {
                 true // Exit
             }
         }
         KeyCode::Char('q') => true, // Exit
+        // In remote mode digits jump straight to the first nine host tabs
+        KeyCode::Char(c @ '1'..='9')
+            if !state.is_local_mode && !state.loading && !state.show_help =>
+        {
+            let tab_index = c as usize - '0' as usize;
+            if let Ok((cols, _)) = size() {
+                select_tab(state, tab_index, cols);
+            }
+            false
+        }
         KeyCode::Char('1') | KeyCode::Char('h') => {
             state.show_help = !state.show_help;
             false
         }
+        KeyCode::Char('l') | KeyCode::Char('L') if !state.show_help => {
+            state.show_logs = true;
+            false
+        }
         KeyCode::Left => {
             if !state.show_help {
                 handle_left_arrow(state);
@@ -55,6 +109,25 @@ pub async fn handle_key_event(key_event: KeyEvent, state: &mut AppState, args: &
     }
 }
 
+/// Playback keys of a replay: Space pauses (unless a device is selected, so
+/// it still pins), Left/Right step and `[`/`]` change the speed. Returns
+/// whether the key was used.
+fn handle_replay_key(key_code: KeyCode, state: &mut AppState) -> bool {
+    let device_selected = state.selected_gpu.is_some();
+    let Some(timeline) = state.replay.as_mut() else {
+        return false;
+    };
+    match key_code {
+        KeyCode::Char(' ') if !device_selected => timeline.paused = !timeline.paused,
+        KeyCode::Left => timeline.step(-1),
+        KeyCode::Right => timeline.step(1),
+        KeyCode::Char('[') => timeline.slower(),
+        KeyCode::Char(']') => timeline.faster(),
+        _ => return false,
+    }
+    true
+}
+
 fn handle_left_arrow(state: &mut AppState) {
     // Check if we're in local mode ("All" tab + local hostname)
     if state.is_local_mode {
@@ -145,8 +218,47 @@ fn handle_navigation_keys(key_code: KeyCode, state: &mut AppState, args: &ViewAr
         KeyCode::Char('m') => state.sort_criteria = SortCriteria::MemoryPercent,
         KeyCode::Char('u') => state.sort_criteria = SortCriteria::Utilization,
         KeyCode::Char('g') => state.sort_criteria = SortCriteria::GpuMemory,
+        KeyCode::Char('U') if state.is_local_mode => state.toggle_group_by_user(),
+        KeyCode::Char('t') if state.is_local_mode => state.cycle_process_top(),
+        KeyCode::Char('i') if state.is_local_mode => state.cycle_min_process_activity(),
+        KeyCode::Enter if state.is_local_mode => state.toggle_selected_user(),
+        KeyCode::Char('k') if state.is_local_mode && !state.readonly => {
+            begin_kill(state, Signal::Terminate, args);
+        }
+        KeyCode::Char('K') if state.is_local_mode && !state.readonly => {
+            begin_kill(state, Signal::Kill, args);
+        }
+        KeyCode::F(2) if state.is_local_mode => {
+            state.column_chooser = Some(ColumnChooser::default());
+        }
         KeyCode::Char('d') => state.sort_criteria = SortCriteria::Default,
         KeyCode::Char('c') => state.show_per_core_cpu = !state.show_per_core_cpu,
+        KeyCode::Char('C') => {
+            state.change_highlight = match state.change_highlight {
+                Some(_) => None,
+                None => Some(ChangeHighlight::new()),
+            };
+        }
+        KeyCode::Tab => state.move_gpu_selection(true),
+        KeyCode::BackTab => state.move_gpu_selection(false),
+        // Space pins the selected device, or pauses when none is selected
+        KeyCode::Char(' ') if state.selected_gpu.is_some() => {
+            state.toggle_selected_gpu_pin();
+        }
+        KeyCode::Char(' ') | KeyCode::Char('P') => state.toggle_pause(),
+        KeyCode::Char('r') => state.request_refresh(),
+        KeyCode::Char('/') if !state.is_local_mode => state.begin_host_search(),
+        KeyCode::Char('v') if !state.is_local_mode => state.toggle_heatmap(),
+        KeyCode::Home if !state.is_local_mode => {
+            if let Ok((cols, _)) = size() {
+                select_tab(state, 0, cols);
+            }
+        }
+        KeyCode::End if !state.is_local_mode => {
+            if let Ok((cols, _)) = size() {
+                select_tab(state, state.tabs.len().saturating_sub(1), cols);
+            }
+        }
         KeyCode::Char('f') => {
             let was_enabled = state.gpu_filter_enabled;
             state.gpu_filter_enabled = !state.gpu_filter_enabled;
@@ -161,6 +273,121 @@ fn handle_navigation_keys(key_code: KeyCode, state: &mut AppState, args: &ViewAr
     }
 }
 
+fn handle_host_search_key(key_event: KeyEvent, state: &mut AppState) {
+    match key_event.code {
+        KeyCode::Esc => state.cancel_host_search(),
+        KeyCode::Enter => {
+            if let Some(tab_index) = state.accept_host_search() {
+                if let Ok((cols, _)) = size() {
+                    select_tab(state, tab_index, cols);
+                }
+            }
+        }
+        KeyCode::Backspace => state.host_search_pop(),
+        KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.host_search_push(c)
+        }
+        _ => {}
+    }
+}
+
+fn begin_kill(state: &mut AppState, signal: Signal, args: &ViewArgs) {
+    let current_user = whoami::username().unwrap_or_default();
+    state.begin_kill(signal, &current_user, kill_max_age(state, args));
+}
+
+fn handle_kill_prompt_key(key_code: KeyCode, state: &mut AppState, args: &ViewArgs) {
+    match key_code {
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+            let max_age = kill_max_age(state, args);
+            state.confirm_kill(&SystemSignalSender, max_age);
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => state.cancel_kill(),
+        _ => {}
+    }
+}
+
+/// Oldest process list that may be acted on: two refresh cycles, each the
+/// sleep between collections plus the time the slowest reader took.
+fn kill_max_age(state: &AppState, args: &ViewArgs) -> Duration {
+    let interval = args
+        .interval
+        .unwrap_or_else(|| EnvConfig::adaptive_interval(1));
+    let collection = state
+        .collection_timings
+        .iter()
+        .map(|(_, duration)| *duration)
+        .max()
+        .unwrap_or_default();
+    2 * (Duration::from_secs(interval) + collection)
+}
+
+/// Keys while the heatmap is open. Returns true to exit.
+fn handle_heatmap_key(key_code: KeyCode, state: &mut AppState) -> bool {
+    let Ok((cols, rows)) = size() else {
+        return false;
+    };
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Esc | KeyCode::Char('v') => state.heatmap = None,
+        KeyCode::Char('h') => state.show_help = true,
+        KeyCode::Char('l') | KeyCode::Char('L') => state.show_logs = true,
+        KeyCode::Char('m') => state.cycle_heatmap_metric(),
+        KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => {
+            let grid = HeatmapGrid::build(state, cols, rows).layout;
+            if let Some(heatmap) = state.heatmap.as_mut() {
+                let (row, column) = (&mut heatmap.row, &mut heatmap.column);
+                match key_code {
+                    KeyCode::Up => *row = row.saturating_sub(1),
+                    KeyCode::Down => *row += 1,
+                    KeyCode::Left => *column = column.saturating_sub(1),
+                    _ => *column += 1,
+                }
+                *row = (*row).min(grid.rows.saturating_sub(1));
+                *column = (*column).min(grid.columns.saturating_sub(1));
+            }
+        }
+        KeyCode::Enter => {
+            let grid = HeatmapGrid::build(state, cols, rows);
+            let tab = state
+                .heatmap
+                .as_ref()
+                .and_then(|heatmap| grid.host_at(heatmap.row, heatmap.column));
+            if let Some(tab) = tab {
+                state.heatmap = None;
+                select_tab(state, tab, cols);
+            }
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Keys while the column chooser is open. Changes apply immediately.
+/// Returns true to exit.
+fn handle_column_chooser_key(key_code: KeyCode, state: &mut AppState) -> bool {
+    let Some(chooser) = state.column_chooser.as_mut() else {
+        return false;
+    };
+    let last = state.process_layout.settings().len().saturating_sub(1);
+    match key_code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Esc | KeyCode::F(2) => state.column_chooser = None,
+        KeyCode::Char('h') => state.show_help = true,
+        KeyCode::Up => chooser.cursor = chooser.cursor.saturating_sub(1),
+        KeyCode::Down => chooser.cursor = (chooser.cursor + 1).min(last),
+        KeyCode::Char(' ') | KeyCode::Enter => state.process_layout.toggle(chooser.cursor),
+        KeyCode::Char('-') => {
+            chooser.cursor = state.process_layout.move_column(chooser.cursor, -1);
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            chooser.cursor = state.process_layout.move_column(chooser.cursor, 1);
+        }
+        _ => {}
+    }
+    false
+}
+
 fn handle_up_arrow(state: &mut AppState, args: &ViewArgs) {
     let is_remote = args.hosts.is_some() || args.hostfile.is_some();
     if is_remote {
@@ -215,9 +442,7 @@ fn handle_down_arrow(state: &mut AppState, args: &ViewArgs) {
         }
     } else {
         // Local mode - process list scrolling
-        if !state.process_info.is_empty()
-            && state.selected_process_index < state.process_info.len() - 1
-        {
+        if state.selected_process_index + 1 < state.process_row_count() {
             state.selected_process_index += 1;
         }
         let (_cols, rows) = size().unwrap();
@@ -321,12 +546,13 @@ fn handle_page_down(state: &mut AppState, args: &ViewArgs) {
         }
     } else {
         // Local mode - page down through process list
-        if !state.process_info.is_empty() {
+        let row_count = state.process_row_count();
+        if row_count > 0 {
             let (_cols, rows) = size().unwrap();
             let half_rows = rows / 2;
             let page_size = half_rows.saturating_sub(1) as usize;
             state.selected_process_index =
-                (state.selected_process_index + page_size).min(state.process_info.len() - 1);
+                (state.selected_process_index + page_size).min(row_count - 1);
             let visible_process_rows = half_rows.saturating_sub(1) as usize;
             if state.selected_process_index >= state.start_index + visible_process_rows {
                 state.start_index = state.selected_process_index - visible_process_rows + 1;
@@ -338,83 +564,114 @@ fn handle_page_down(state: &mut AppState, args: &ViewArgs) {
 pub async fn handle_mouse_event(
     mouse_event: MouseEvent,
     state: &mut AppState,
-    _args: &ViewArgs,
+    args: &ViewArgs,
+    layout: &ScreenLayout,
 ) -> bool {
+    // Only handle mouse input when not in help mode, loading, typing a search,
+    // confirming a signal, reading the log or on the heatmap
+    if state.show_help
+        || state.show_logs
+        || state.loading
+        || state.host_search.is_some()
+        || state.kill_prompt.is_some()
+        || state.heatmap.is_some()
+        || state.column_chooser.is_some()
+    {
+        return false;
+    }
+
     match mouse_event.kind {
         MouseEventKind::Down(MouseButton::Left) => {
-            // Only handle clicks when not in help mode and not loading
-            if !state.show_help && !state.loading {
-                handle_process_header_click(mouse_event.column, mouse_event.row, state);
+            let (x, y) = (mouse_event.column, mouse_event.row);
+            if layout.tabs_row == Some(y) {
+                handle_tab_click(x, state);
+            } else if layout.process_header_row == Some(y) {
+                handle_process_header_click(x, state);
+            } else if let Some(offset) = layout.process_row_offset(y) {
+                if state.is_local_mode {
+                    state.selected_process_index = state.start_index + offset;
+                }
+            } else if let Some(region) = layout.gpu_at(y) {
+                handle_device_click(region, state);
             }
             false
         }
+        MouseEventKind::ScrollUp => {
+            handle_up_arrow(state, args);
+            false
+        }
+        MouseEventKind::ScrollDown => {
+            handle_down_arrow(state, args);
+            false
+        }
         _ => false,
     }
 }
 
-fn handle_process_header_click(x: u16, y: u16, state: &mut AppState) {
-    // Check if we're in local mode with process list visible
-    if !state.is_local_mode {
+fn handle_tab_click(x: u16, state: &mut AppState) {
+    if state.is_local_mode {
         return;
     }
 
-    // Get terminal size to calculate process list position
-    let (_cols, rows) = match size() {
-        Ok((c, r)) => (c, r),
-        Err(_) => return,
+    let Ok((cols, _)) = size() else {
+        return;
     };
+    if let Some(region) = tab_hit_regions(state, cols)
+        .into_iter()
+        .find(|region| x >= region.start_col && x < region.end_col)
+    {
+        select_tab(state, region.tab_index, cols);
+    }
+}
 
-    // Calculate where the process header should be
-    // The header is at half_rows - 1 based on testing
-    let half_rows = rows / 2;
-    let process_header_row = half_rows - 1;
-
-    // Check if click is on the process header row
-    if y != process_header_row {
+/// Clicking a device selects it; on the "All" tab it also jumps to that
+/// node's tab
+fn handle_device_click(region: &GpuRowRegion, state: &mut AppState) {
+    state.selected_gpu = Some(region.uuid.clone());
+    if state.is_local_mode || state.current_tab != 0 {
         return;
     }
 
-    // Calculate column positions based on fixed widths
-    let fixed_widths = [7, 12, 3, 3, 6, 6, 1, 5, 5, 5, 7, 8];
-    let mut column_start: usize = 0;
-    let mut column_index = None;
-
-    // Account for horizontal scrolling
-    let scroll_offset = state.process_horizontal_scroll_offset;
+    let Ok((cols, _)) = size() else {
+        return;
+    };
+    if let Some(tab_index) = state.tabs.iter().position(|tab| *tab == region.host_id) {
+        select_tab(state, tab_index, cols);
+    }
+}
 
-    // Find which column was clicked
-    for (i, &width) in fixed_widths.iter().enumerate() {
-        let column_end = column_start + width;
+fn select_tab(state: &mut AppState, tab_index: usize, cols: u16) {
+    if tab_index >= state.tabs.len() || tab_index == state.current_tab {
+        return;
+    }
 
-        // Adjust for scroll offset
-        let visible_start = column_start.saturating_sub(scroll_offset) as u16;
-        let visible_end = column_end.saturating_sub(scroll_offset) as u16;
+    state.current_tab = tab_index;
+    state.gpu_scroll_offset = 0;
+    state.storage_scroll_offset = 0;
+
+    // Scroll the tab bar so the selected node tab is visible
+    if tab_index > 0
+        && !tab_hit_regions(state, cols)
+            .iter()
+            .any(|region| region.tab_index == tab_index)
+    {
+        state.tab_scroll_offset = tab_index - 1;
+    }
+}
 
-        if x >= visible_start && x < visible_end {
-            column_index = Some(i);
-            break;
-        }
-        column_start = column_end + 1; // +1 for space between columns
+fn handle_process_header_click(x: u16, state: &mut AppState) {
+    // Check if we're in local mode with process list visible
+    if !state.is_local_mode {
+        return;
     }
 
-    // Map column index to sort criteria
-    if let Some(idx) = column_index {
-        let new_criteria = match idx {
-            0 => SortCriteria::Pid,
-            1 => SortCriteria::User,
-            2 => SortCriteria::Priority,
-            3 => SortCriteria::Nice,
-            4 => SortCriteria::VirtualMemory,
-            5 => SortCriteria::ResidentMemory,
-            6 => SortCriteria::State,
-            7 => SortCriteria::CpuPercent,
-            8 => SortCriteria::MemoryPercent,
-            9 => SortCriteria::GpuPercent,
-            10 => SortCriteria::GpuMemoryUsage,
-            11 => SortCriteria::CpuTime,
-            _ => return, // Command column or beyond
-        };
+    let Ok((cols, _)) = size() else {
+        return;
+    };
+    let x = x as usize + state.process_horizontal_scroll_offset;
+    let column = state.process_layout.column_at(x, cols as usize);
 
+    if let Some(new_criteria) = column.and_then(|column| column.sort_criteria()) {
         // Toggle sort direction if clicking the same column
         if state.sort_criteria == new_criteria {
             state.sort_direction = match state.sort_direction {
@@ -12,28 +12,93 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
 use std::sync::Arc;
 
 use tokio::sync::Mutex;
 
+use crate::api::textfile::TextfileWriter;
 use crate::app_state::AppState;
-use crate::cli::{LocalArgs, ViewArgs};
+use crate::cli::{LocalArgs, SshArgs, ViewArgs, DEFAULT_REMOTE_PROCESS_TOP};
+use crate::common::config::UiSettings;
+use crate::device::privileges::{missing_features_summary, unavailable_features};
+use crate::ui::heatmap::RackLayout;
+use crate::ui::notification::NotificationType;
+use crate::ui::replay_timeline::ReplayTimeline;
+use crate::ui::smoothing::UtilizationEma;
+use crate::view::data_collection::replay::{Recorder, RecordingReader, ReplayCollector};
 use crate::view::{
     data_collector::DataCollector, terminal_manager::TerminalManager, ui_loop::UiLoop,
 };
 
-pub async fn run_local_mode(args: &LocalArgs) {
+/// How long the startup notice about missing features stays up
+const MISSING_FEATURES_NOTICE_SECS: u64 = 10;
+
+/// `readonly` (`--readonly`) unbinds the kill keys
+pub async fn run_local_mode(args: &LocalArgs, ui_settings: &UiSettings, readonly: bool) {
     let mut startup_profiler = crate::utils::StartupProfiler::new();
     startup_profiler.checkpoint("Starting run_local_mode");
 
     // Initialize application state for local mode
     let mut initial_state = AppState::new();
     initial_state.is_local_mode = true;
+    initial_state.readonly = readonly;
+    initial_state.sort_criteria = ui_settings.default_sort;
+    initial_state.normalize_load = args.normalize_load;
+    initial_state.utilization_ema = args.smooth.map(UtilizationEma::new);
+
+    // Open recordings before the terminal switches to the alternate screen,
+    // so errors stay visible
+    let recording = &args.recording;
+    let replay = recording
+        .replay
+        .as_deref()
+        .map(|path| match RecordingReader::open(path) {
+            Ok(reader) => ReplayCollector::new(reader),
+            Err(e) => {
+                eprintln!("Error: failed to read {}: {e}", path.display());
+                std::process::exit(1);
+            }
+        });
+    initial_state.replay = replay
+        .as_ref()
+        .map(|collector| ReplayTimeline::new(collector.len(), recording.replay_speed));
+    // Name what the current user cannot read; the reasons are in the log
+    if let Some(summary) = missing_features_summary(unavailable_features()) {
+        let _ = initial_state.notifications.show_with_duration(
+            summary,
+            NotificationType::Warning,
+            MISSING_FEATURES_NOTICE_SECS,
+        );
+    }
     let app_state = Arc::new(Mutex::new(initial_state));
     startup_profiler.checkpoint("AppState initialized");
+    let recorder = recording.record.as_deref().map(|path| {
+        let max_bytes = recording
+            .record_max_mb
+            .map(|mb| mb.saturating_mul(1024 * 1024));
+        match Recorder::create(path, max_bytes) {
+            Ok(recorder) => recorder,
+            Err(e) => {
+                eprintln!("Error: failed to create {}: {e}", path.display());
+                std::process::exit(1);
+            }
+        }
+    });
+
+    let textfile = args
+        .textfile_dir
+        .as_deref()
+        .map(|dir| match TextfileWriter::new(dir) {
+            Ok(writer) => writer,
+            Err(e) => {
+                eprintln!("Error: cannot write metrics to {}: {e}", dir.display());
+                std::process::exit(1);
+            }
+        });
 
     // Initialize terminal
-    let _terminal_manager = match TerminalManager::new() {
+    let _terminal_manager = match TerminalManager::new(args.mouse) {
         Ok(manager) => manager,
         Err(e) => {
             eprintln!("Failed to initialize terminal: {e}");
@@ -48,14 +113,31 @@ pub async fn run_local_mode(args: &LocalArgs) {
         hosts: None,
         hostfile: None,
         interval: args.interval,
+        mouse: args.mouse,
+        theme: args.theme,
+        sort_by: args.sort_by,
+        normalize_load: args.normalize_load,
+        smooth: args.smooth,
+        rack_layout: None,
+        process_top: DEFAULT_REMOTE_PROCESS_TOP,
+        ssh: SshArgs::default(),
+        oom: args.oom.clone(),
     };
+    let filter = args.subsystems.collection_filter();
     tokio::spawn(async move {
-        data_collector.run_local_mode(view_args).await;
+        match replay {
+            Some(collector) => data_collector.run_replay_mode(collector).await,
+            None => {
+                data_collector
+                    .run_local_mode(view_args, filter, recorder, textfile)
+                    .await
+            }
+        }
     });
     startup_profiler.checkpoint("Data collector spawned");
 
     // Run UI loop
-    let mut ui_loop = match UiLoop::new(app_state) {
+    let mut ui_loop = match UiLoop::new(app_state, ui_settings) {
         Ok(ui_loop) => ui_loop,
         Err(e) => {
             eprintln!("Failed to initialize UI: {e}");
@@ -70,6 +152,15 @@ pub async fn run_local_mode(args: &LocalArgs) {
         hosts: None,
         hostfile: None,
         interval: args.interval,
+        mouse: args.mouse,
+        theme: args.theme,
+        sort_by: args.sort_by,
+        normalize_load: args.normalize_load,
+        smooth: args.smooth,
+        rack_layout: None,
+        process_top: DEFAULT_REMOTE_PROCESS_TOP,
+        ssh: SshArgs::default(),
+        oom: args.oom.clone(),
     };
     if let Err(e) = ui_loop.run(&view_args).await {
         eprintln!("UI loop error: {e}");
@@ -78,14 +169,26 @@ pub async fn run_local_mode(args: &LocalArgs) {
     // Terminal cleanup is handled by TerminalManager's Drop trait
 }
 
-pub async fn run_view_mode(args: &ViewArgs) {
+pub async fn run_view_mode(args: &ViewArgs, ui_settings: &UiSettings) {
     // Initialize application state for remote mode
     let mut initial_state = AppState::new();
     initial_state.is_local_mode = false;
+    initial_state.sort_criteria = ui_settings.default_sort;
+    initial_state.normalize_load = args.normalize_load;
+    initial_state.utilization_ema = args.smooth.map(UtilizationEma::new);
+    if let Some(path) = &args.rack_layout {
+        match RackLayout::load(Path::new(path)) {
+            Ok(layout) => initial_state.rack_layout = Some(layout),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
     let app_state = Arc::new(Mutex::new(initial_state));
 
     // Initialize terminal
-    let _terminal_manager = match TerminalManager::new() {
+    let _terminal_manager = match TerminalManager::new(args.mouse) {
         Ok(manager) => manager,
         Err(e) => {
             eprintln!("Failed to initialize terminal: {e}");
@@ -98,16 +201,16 @@ pub async fn run_view_mode(args: &ViewArgs) {
     let args_clone = args.clone();
     tokio::spawn(async move {
         let hosts = args_clone.hosts.clone().unwrap_or_default();
-        let hostfile = args_clone.hostfile.clone();
+        let hostfiles = args_clone.hostfile.clone().unwrap_or_default();
 
         // Remote mode
         data_collector
-            .run_remote_mode(args_clone, hosts, hostfile)
+            .run_remote_mode(args_clone, hosts, hostfiles)
             .await;
     });
 
     // Run UI loop
-    let mut ui_loop = match UiLoop::new(app_state) {
+    let mut ui_loop = match UiLoop::new(app_state, ui_settings) {
         Ok(ui_loop) => ui_loop,
         Err(e) => {
             eprintln!("Failed to initialize UI: {e}");
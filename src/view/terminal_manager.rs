@@ -24,11 +24,17 @@ use crossterm::{
 
 pub struct TerminalManager {
     initialized: bool,
+    mouse_capture: bool,
 }
 
 impl TerminalManager {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let mut manager = Self { initialized: false };
+    /// Set up the terminal. Mouse capture is opt-in because it prevents the
+    /// terminal's own text selection (copy/paste).
+    pub fn new(mouse_capture: bool) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut manager = Self {
+            initialized: false,
+            mouse_capture,
+        };
         manager.initialize()?;
         Ok(manager)
     }
@@ -42,10 +48,10 @@ impl TerminalManager {
         if execute!(
             stdout,
             EnterAlternateScreen,
-            EnableMouseCapture,
             crossterm::terminal::Clear(ClearType::All)
         )
         .is_err()
+            || (self.mouse_capture && execute!(stdout, EnableMouseCapture).is_err())
         {
             let _ = disable_raw_mode();
             return Err("Failed to initialize terminal display".into());
@@ -66,7 +72,10 @@ impl Drop for TerminalManager {
         if self.initialized {
             let mut stdout = stdout();
             // Leave alternate screen and restore terminal state
-            let _ = execute!(stdout, LeaveAlternateScreen, DisableMouseCapture);
+            if self.mouse_capture {
+                let _ = execute!(stdout, DisableMouseCapture);
+            }
+            let _ = execute!(stdout, LeaveAlternateScreen);
             let _ = disable_raw_mode();
             // No "Terminating..." message needed - native APIs don't require cleanup
         }
@@ -75,6 +84,9 @@ impl Drop for TerminalManager {
 
 impl Default for TerminalManager {
     fn default() -> Self {
-        Self::new().unwrap_or_else(|_| Self { initialized: false })
+        Self::new(false).unwrap_or_else(|_| Self {
+            initialized: false,
+            mouse_capture: false,
+        })
     }
 }
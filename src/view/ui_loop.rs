@@ -12,45 +12,65 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::borrow::Cow;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::io::{stdout, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use chrono::Local;
+use chrono::{DateTime, Local};
 use crossterm::{
     cursor,
     event::{self, Event},
     queue,
-    style::{Color, Print},
+    style::Print,
     terminal::size,
 };
 use tokio::sync::Mutex;
 
-use crate::app_state::AppState;
+use crate::app_state::{
+    user_view_rows, AppState, DisplayedSnapshot, HeatmapState, HostSearch, ProcessTop, STALE_KEY,
+};
 use crate::cli::ViewArgs;
-use crate::common::config::AppConfig;
-use crate::device::ProcessInfo;
+use crate::common::config::{AppConfig, UiSettings};
+use crate::common::ui_state::{default_ui_state_path, UiStateFile};
+use crate::device::gpu_presence::is_lost;
+use crate::device::memory_headroom::{memory_headroom, oom_thresholds};
+use crate::device::process_list::summarize_by_user;
 use crate::ui::buffer::{BufferWriter, DifferentialRenderer};
 use crate::ui::dashboard::{draw_dashboard_items, draw_system_view};
-use crate::ui::layout::LayoutCalculator;
+use crate::ui::heatmap::draw_heatmap;
+use crate::ui::layout::{GpuRowRegion, LayoutCalculator, ScreenLayout};
+use crate::ui::log_overlay::generate_log_overlay_content;
+use crate::ui::process_columns::{draw_column_chooser, ColumnChooser, ProcessLayout};
+use crate::ui::process_renderer::{
+    clamp_start_index, print_user_process_info, process_rows_capacity,
+};
 use crate::ui::renderer::{
-    print_chassis_info, print_cpu_info, print_function_keys, print_gpu_info,
+    is_terminal_too_small, print_chassis_info, print_cpu_info, print_function_keys, print_gpu_info,
     print_loading_indicator, print_memory_info, print_process_info, print_storage_info,
+    print_terminal_too_small, GpuRowMarks,
 };
+use crate::ui::replay_timeline::ReplayTimeline;
 use crate::ui::tabs::draw_tabs;
 use crate::ui::text::print_colored_text;
+use crate::ui::theme;
+use crate::utils::logging::log_buffer;
 use crate::view::event_handler::handle_key_event;
 
 pub struct UiLoop {
     app_state: Arc<Mutex<AppState>>,
     differential_renderer: DifferentialRenderer,
+    /// Output of the last frame, cleared and reused for the next one
+    frame: BufferWriter,
     previous_show_help: bool,
+    previous_show_logs: bool,
     previous_loading: bool,
     previous_tab: usize,
     previous_show_per_core_cpu: bool,
     last_render_time: std::time::Instant,
+    /// Minimum time between renders (`ui.refresh_rate_ms`)
+    render_interval_ms: u64,
     resize_occurred: bool,
     /// Track the last rendered data version to skip re-rendering unchanged data
     last_rendered_data_version: u64,
@@ -61,6 +81,26 @@ pub struct UiLoop {
     previous_process_horizontal_scroll_offset: usize,
     previous_tab_scroll_offset: usize,
     previous_gpu_filter_enabled: bool,
+    previous_group_by_user: bool,
+    previous_process_top: ProcessTop,
+    previous_min_process_activity: f64,
+    previous_expanded_user: Option<String>,
+    previous_paused: bool,
+    previous_paused_at: Option<DateTime<Local>>,
+    previous_host_search: Option<HostSearch>,
+    previous_heatmap: Option<HeatmapState>,
+    previous_selected_gpu: Option<String>,
+    previous_pinned_gpus: BTreeSet<String>,
+    previous_process_layout: ProcessLayout,
+    previous_column_chooser: Option<ColumnChooser>,
+    previous_change_highlight: bool,
+    previous_replay: Option<ReplayTimeline>,
+    /// Where pins are saved; `None` if no state directory is known
+    ui_state_path: Option<PathBuf>,
+    /// Snapshot rendered while updates are paused (collection keeps running)
+    displayed: DisplayedSnapshot,
+    /// Positions of clickable elements from the last rendered frame
+    screen_layout: ScreenLayout,
     #[cfg(target_os = "linux")]
     hlsmi_notified: bool,
     #[cfg(target_os = "linux")]
@@ -70,18 +110,25 @@ pub struct UiLoop {
 }
 
 impl UiLoop {
-    pub fn new(app_state: Arc<Mutex<AppState>>) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn new(
+        app_state: Arc<Mutex<AppState>>,
+        ui_settings: &UiSettings,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let differential_renderer =
             DifferentialRenderer::new().map_err(|_| "Failed to create differential renderer")?;
+        let ui_state_path = default_ui_state_path();
 
         Ok(Self {
             app_state,
             differential_renderer,
+            frame: BufferWriter::new(),
             previous_show_help: false,
+            previous_show_logs: false,
             previous_loading: false,
             previous_tab: 0,
             previous_show_per_core_cpu: false,
             last_render_time: std::time::Instant::now(),
+            render_interval_ms: ui_settings.refresh_rate_ms,
             resize_occurred: false,
             last_rendered_data_version: 0,
             previous_gpu_scroll_offset: 0,
@@ -90,6 +137,23 @@ impl UiLoop {
             previous_process_horizontal_scroll_offset: 0,
             previous_tab_scroll_offset: 0,
             previous_gpu_filter_enabled: false,
+            previous_group_by_user: false,
+            previous_process_top: ProcessTop::Off,
+            previous_min_process_activity: 0.0,
+            previous_expanded_user: None,
+            previous_paused: false,
+            previous_paused_at: None,
+            previous_host_search: None,
+            previous_heatmap: None,
+            previous_selected_gpu: None,
+            previous_pinned_gpus: BTreeSet::new(),
+            previous_process_layout: ProcessLayout::default(),
+            previous_column_chooser: None,
+            previous_change_highlight: false,
+            previous_replay: None,
+            ui_state_path,
+            displayed: DisplayedSnapshot::default(),
+            screen_layout: ScreenLayout::default(),
             #[cfg(target_os = "linux")]
             hlsmi_notified: false,
             #[cfg(target_os = "linux")]
@@ -100,6 +164,16 @@ impl UiLoop {
     }
 
     pub async fn run(&mut self, args: &ViewArgs) -> Result<(), Box<dyn std::error::Error>> {
+        // Restore pins and the process table layout from the last run
+        if let Some(path) = &self.ui_state_path {
+            let ui_state = UiStateFile::load(path);
+            self.previous_pinned_gpus = ui_state.pinned_gpus.clone();
+            self.previous_process_layout = ui_state.process_columns.clone();
+            let mut state = self.app_state.lock().await;
+            state.pinned_gpus = ui_state.pinned_gpus;
+            state.process_layout = ui_state.process_columns;
+        }
+
         loop {
             // Check hl-smi initialization on Linux (periodic check for performance)
             #[cfg(target_os = "linux")]
@@ -156,6 +230,7 @@ impl UiLoop {
                                 mouse_event,
                                 &mut state,
                                 args,
+                                &self.screen_layout,
                             )
                             .await;
                             if should_break {
@@ -180,14 +255,24 @@ impl UiLoop {
 
             // Check if we need to force clear due to mode change or tab change
             let force_clear = state.show_help != self.previous_show_help
+                || state.show_logs != self.previous_show_logs
                 || state.loading != self.previous_loading
                 || state.current_tab != self.previous_tab
                 || state.show_per_core_cpu != self.previous_show_per_core_cpu
                 || state.gpu_filter_enabled != self.previous_gpu_filter_enabled
+                || state.group_by_user != self.previous_group_by_user
+                || state.process_top != self.previous_process_top
+                || state.min_process_activity != self.previous_min_process_activity
+                || state.paused != self.previous_paused
+                || state.heatmap.is_some() != self.previous_heatmap.is_some()
+                || state.column_chooser.is_some() != self.previous_column_chooser.is_some()
                 || self.resize_occurred;
 
-            // Check if data has changed (used for skipping expensive rendering when idle)
-            let data_changed = state.data_version != self.last_rendered_data_version;
+            // Check if data has changed (used for skipping expensive rendering when idle).
+            // New data is ignored while paused; unpausing forces a clear and shows
+            // the latest collected data immediately.
+            let data_changed =
+                !state.paused && state.data_version != self.last_rendered_data_version;
 
             // Check if scroll/selection state has changed (requires re-render)
             let scroll_changed = state.gpu_scroll_offset != self.previous_gpu_scroll_offset
@@ -195,12 +280,22 @@ impl UiLoop {
                 || state.selected_process_index != self.previous_selected_process_index
                 || state.process_horizontal_scroll_offset
                     != self.previous_process_horizontal_scroll_offset
-                || state.tab_scroll_offset != self.previous_tab_scroll_offset;
+                || state.tab_scroll_offset != self.previous_tab_scroll_offset
+                || state.host_search != self.previous_host_search
+                || state.expanded_user != self.previous_expanded_user
+                || state.heatmap != self.previous_heatmap
+                || state.selected_gpu != self.previous_selected_gpu
+                || state.pinned_gpus != self.previous_pinned_gpus
+                || state.process_layout != self.previous_process_layout
+                || state.column_chooser != self.previous_column_chooser
+                || state.change_highlight.is_some() != self.previous_change_highlight
+                || state.replay != self.previous_replay
+                || state.paused_at != self.previous_paused_at;
 
             // Check if enough time has passed for rendering (throttle to prevent visual artifacts)
             let now = std::time::Instant::now();
             let time_to_render = now.duration_since(self.last_render_time).as_millis()
-                >= AppConfig::MIN_RENDER_INTERVAL_MS as u128;
+                >= self.render_interval_ms as u128;
 
             // Only render if there's something worth rendering
             // Note: We always render when time_to_render is true to ensure smooth
@@ -208,7 +303,7 @@ impl UiLoop {
             // skip actual rendering if content is unchanged.
             let should_render = force_clear
                 || self.resize_occurred
-                || (time_to_render && (data_changed || scroll_changed));
+                || (time_to_render && (data_changed || scroll_changed || state.show_logs));
 
             // Update scroll offsets for long text (controlled by SCROLL_UPDATE_FREQUENCY)
             // This runs independently of should_render to keep animations smooth
@@ -241,37 +336,96 @@ impl UiLoop {
                 break;
             }
 
-            // Create content using buffer, then render differentially
-            let content = if state.show_help {
-                self.render_help_popup_content(&state, args, cols, rows)
+            // Keep a frozen copy of the data while paused, following the live
+            // navigation state so the user can still scroll and switch tabs
+            self.displayed.update(&mut state);
+
+            // Render into the buffer kept from the previous frame so its
+            // capacity is reused, then update only the lines that changed
+            let mut buffer = std::mem::replace(&mut self.frame, BufferWriter::with_capacity(0));
+            buffer.clear();
+            let mut layout = ScreenLayout::default();
+            if is_terminal_too_small(cols, rows) {
+                print_terminal_too_small(&mut buffer, cols, rows);
+            } else if state.show_help {
+                let content = self.render_help_popup_content(&state, args, cols, rows);
+                let _ = buffer.write_all(content.as_bytes());
+            } else if state.show_logs {
+                let content = generate_log_overlay_content(
+                    cols,
+                    rows,
+                    &log_buffer().records(),
+                    &state.collection_timings,
+                );
+                let _ = buffer.write_all(content.as_bytes());
             } else if state.loading {
                 let is_remote = args.hosts.is_some() || args.hostfile.is_some();
-                self.render_loading_content(&state, is_remote, cols, rows)
+                self.render_loading_content(&mut buffer, &state, is_remote, cols, rows);
+            } else if state.heatmap.is_some() {
+                draw_heatmap(
+                    &mut buffer,
+                    self.displayed.frozen().unwrap_or(&state),
+                    cols,
+                    rows,
+                );
+            } else if let Some(chooser) = &state.column_chooser {
+                draw_column_chooser(&mut buffer, &state.process_layout, chooser, cols, rows);
+            } else if let Some(frozen) = self.displayed.frozen() {
+                self.render_main_content(&mut buffer, frozen, args, cols, rows, &mut layout);
             } else {
-                self.render_main_content(&state, args, cols, rows)
-            };
+                self.render_main_content(&mut buffer, &state, args, cols, rows, &mut layout);
+            }
+            // Keep scroll positions valid for the current terminal size so the
+            // selection stays visible after a resize
+            if let Some(offset) = layout.gpu_scroll_offset {
+                state.gpu_scroll_offset = offset;
+            }
+            if let Some(start) = layout.process_start_index {
+                state.start_index = start;
+            }
+            self.screen_layout = layout;
 
-            // Use differential rendering to update only changed lines
-            if self
+            let rendered = self
                 .differential_renderer
-                .render_differential(&content)
-                .is_err()
-            {
+                .render_differential(buffer.get_buffer());
+            self.frame = buffer;
+            if rendered.is_err() {
                 break;
             }
 
             // Update previous state
             self.previous_show_help = state.show_help;
+            self.previous_show_logs = state.show_logs;
             self.previous_loading = state.loading;
             self.previous_tab = state.current_tab;
             self.previous_show_per_core_cpu = state.show_per_core_cpu;
             self.previous_gpu_filter_enabled = state.gpu_filter_enabled;
+            self.previous_group_by_user = state.group_by_user;
+            self.previous_process_top = state.process_top;
+            self.previous_min_process_activity = state.min_process_activity;
+            self.previous_expanded_user = state.expanded_user.clone();
+            self.previous_paused = state.paused;
+            self.previous_paused_at = state.paused_at;
             self.last_rendered_data_version = state.data_version;
             self.previous_gpu_scroll_offset = state.gpu_scroll_offset;
             self.previous_storage_scroll_offset = state.storage_scroll_offset;
             self.previous_selected_process_index = state.selected_process_index;
             self.previous_process_horizontal_scroll_offset = state.process_horizontal_scroll_offset;
             self.previous_tab_scroll_offset = state.tab_scroll_offset;
+            self.previous_host_search = state.host_search.clone();
+            self.previous_heatmap = state.heatmap.clone();
+            self.previous_selected_gpu = state.selected_gpu.clone();
+            if state.pinned_gpus != self.previous_pinned_gpus {
+                self.save_pins(&state.pinned_gpus);
+                self.previous_pinned_gpus = state.pinned_gpus.clone();
+            }
+            self.previous_column_chooser = state.column_chooser;
+            self.previous_change_highlight = state.change_highlight.is_some();
+            self.previous_replay = state.replay;
+            if state.process_layout != self.previous_process_layout {
+                self.save_process_layout(&state.process_layout);
+                self.previous_process_layout = state.process_layout.clone();
+            }
             self.resize_occurred = false;
 
             if queue!(stdout, cursor::Show).is_err() {
@@ -371,37 +525,56 @@ impl UiLoop {
 
     fn render_loading_content(
         &self,
+        buffer: &mut BufferWriter,
         state: &AppState,
         is_remote: bool,
         cols: u16,
         rows: u16,
-    ) -> String {
-        let mut buffer = BufferWriter::new();
-        print_function_keys(&mut buffer, cols, rows, state, is_remote);
+    ) {
+        print_function_keys(buffer, cols, rows, state, is_remote);
         print_loading_indicator(
-            &mut buffer,
+            buffer,
             cols,
             rows,
             state.frame_counter,
             &state.startup_status_lines,
         );
-        buffer.get_buffer().to_string()
     }
 
     fn render_main_content(
         &self,
+        buffer: &mut BufferWriter,
         state: &AppState,
         args: &ViewArgs,
         cols: u16,
         rows: u16,
-    ) -> String {
+        layout: &mut ScreenLayout,
+    ) {
+        let theme = theme::current();
         let width = cols as usize;
-        let mut buffer = BufferWriter::new();
 
         // Write time/date header to buffer first
         let current_time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let version = env!("CARGO_PKG_VERSION");
-        let header_text = format!("all-smi - {current_time}");
+        let header_text = if let Some(timeline) = &state.replay {
+            // A replay shows when the snapshot was recorded and where it is
+            // in the recording
+            let recorded_time = timeline
+                .shown_at
+                .and_then(|secs| DateTime::from_timestamp_millis((secs * 1000.0) as i64))
+                .map(|time| {
+                    time.with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            format!("all-smi - {recorded_time} | {}", timeline.label(20))
+        } else {
+            match state.refresh_interval {
+                Some(interval) => format!("all-smi - {current_time} | {}", interval.label()),
+                None => format!("all-smi - {current_time}"),
+            }
+        };
         let version_text = format!("v{version}");
 
         // Get runtime environment info
@@ -420,7 +593,18 @@ impl UiLoop {
             .as_ref()
             .map(|(_, _, len)| len + 1)
             .unwrap_or(0); // +1 for space before shield
-        let content_length = header_text.len() + runtime_shield_len + version_text.len();
+                           // Paused at the time shown, e.g. ` PAUSED 14:03:22 `
+        let paused_badge = match state.paused_at {
+            Some(at) => format!(" PAUSED {} ", at.format("%H:%M:%S")),
+            None => " PAUSED ".to_string(),
+        };
+        let paused_len = if state.paused {
+            paused_badge.len() + 1
+        } else {
+            0
+        };
+        let content_length =
+            header_text.len() + runtime_shield_len + paused_len + version_text.len();
         let spacing = if total_width > content_length {
             " ".repeat(total_width - content_length)
         } else {
@@ -428,53 +612,87 @@ impl UiLoop {
         };
 
         // Print header with runtime environment shield
-        print_colored_text(&mut buffer, &header_text, Color::White, None, None);
+        print_colored_text(buffer, &header_text, theme.text, None, None);
 
         if let Some((shield_content, shield_color, _)) = runtime_shield {
-            print_colored_text(&mut buffer, " ", Color::White, None, None);
+            print_colored_text(buffer, " ", theme.text, None, None);
             print_colored_text(
-                &mut buffer,
+                buffer,
                 &shield_content,
-                Color::Black,
+                theme.badge_fg,
                 Some(shield_color),
                 None,
             );
         }
 
+        if state.paused {
+            print_colored_text(buffer, " ", theme.text, None, None);
+            print_colored_text(
+                buffer,
+                &paused_badge,
+                theme.badge_fg,
+                Some(theme.warning),
+                None,
+            );
+        }
+
         print_colored_text(
-            &mut buffer,
+            buffer,
             &format!("{spacing}{version_text}\r\n"),
-            Color::White,
+            theme.text,
             None,
             None,
         );
 
         // Write remaining header content to buffer
-        print_colored_text(&mut buffer, "Cluster Overview\r\n", Color::Cyan, None, None);
-        draw_system_view(&mut buffer, state, cols);
+        print_colored_text(buffer, "Cluster Overview\r\n", theme.title, None, None);
+        draw_system_view(buffer, state, cols);
 
-        draw_dashboard_items(&mut buffer, state, cols);
-        draw_tabs(&mut buffer, state, cols);
+        draw_dashboard_items(buffer, state, cols);
+        layout.tabs_row = Some(buffer.line_count() as u16);
+        draw_tabs(buffer, state, cols);
 
         let is_remote = args.hosts.is_some() || args.hostfile.is_some();
 
         // Render chassis information (node-level metrics)
-        self.render_chassis_section(&mut buffer, state, width);
+        self.render_chassis_section(buffer, state, width);
 
         // Render GPU information
-        self.render_gpu_section(&mut buffer, state, args, cols, rows);
+        self.render_gpu_section(buffer, state, args, cols, rows, layout);
 
         // Render other device information based on mode
         if is_remote {
-            self.render_remote_devices(&mut buffer, state, width);
+            self.render_remote_devices(buffer, state, width);
+            self.render_remote_processes(buffer, state, cols, rows);
         } else {
-            self.render_local_devices(&mut buffer, state, width);
+            self.render_local_devices(buffer, state, width, layout);
         }
 
         // Add function keys to main content view
-        print_function_keys(&mut buffer, cols, rows, state, is_remote);
+        print_function_keys(buffer, cols, rows, state, is_remote);
+    }
 
-        buffer.get_buffer().to_string()
+    /// Save pins to the UI state file, keeping its other entries
+    fn save_pins(&self, pinned_gpus: &BTreeSet<String>) {
+        let Some(path) = &self.ui_state_path else {
+            return;
+        };
+        let mut ui_state = UiStateFile::load(path);
+        ui_state.pinned_gpus = pinned_gpus.clone();
+        if let Err(e) = ui_state.save(path) {
+            tracing::warn!("Failed to save UI state to {}: {e}", path.display());
+        }
+    }
+
+    fn save_process_layout(&self, process_layout: &ProcessLayout) {
+        let Some(path) = &self.ui_state_path else {
+            return;
+        };
+        let mut ui_state = UiStateFile::load(path);
+        ui_state.process_columns = process_layout.clone();
+        if let Err(e) = ui_state.save(path) {
+            tracing::warn!("Failed to save UI state to {}: {e}", path.display());
+        }
     }
 
     fn render_gpu_section(
@@ -484,20 +702,10 @@ impl UiLoop {
         args: &ViewArgs,
         cols: u16,
         rows: u16,
+        layout: &mut ScreenLayout,
     ) {
-        let mut gpu_info_to_display: Vec<_> =
-            if state.current_tab < state.tabs.len() && state.tabs[state.current_tab] == "All" {
-                state.gpu_info.iter().collect()
-            } else {
-                state
-                    .gpu_info
-                    .iter()
-                    .filter(|info| info.host_id == state.tabs[state.current_tab])
-                    .collect()
-            };
-
-        // Sort GPUs based on current sort criteria
-        gpu_info_to_display.sort_by(|a, b| state.sort_criteria.sort_gpus(a, b));
+        // Pinned devices first, then the current sort criteria
+        let gpu_info_to_display = state.displayed_gpus();
 
         // Calculate available space and render GPUs
         let header_lines = LayoutCalculator::calculate_header_lines(state);
@@ -510,13 +718,28 @@ impl UiLoop {
             LayoutCalculator::calculate_gpu_display_params(state, args, &content_area);
         let max_gpu_items = gpu_display_params.max_items;
 
-        // Display GPUs with scrolling
-        let start_gpu_index = state.gpu_scroll_offset;
+        // Display GPUs with scrolling; a larger terminal may fit more GPUs
+        // than the stored offset leaves, so pull it back
+        let mut start_gpu_index = state
+            .gpu_scroll_offset
+            .min(gpu_info_to_display.len().saturating_sub(max_gpu_items));
+
+        // After the selection moves or pinning reorders the list, scroll to
+        // the selected device's new row
+        let selection_moved = state.selected_gpu != self.previous_selected_gpu
+            || state.pinned_gpus != self.previous_pinned_gpus;
+        if let Some(row) = state.selected_gpu_row().filter(|_| selection_moved) {
+            if row < start_gpu_index {
+                start_gpu_index = row;
+            } else if row >= start_gpu_index + max_gpu_items {
+                start_gpu_index = (row + 1).saturating_sub(max_gpu_items);
+            }
+        }
+        layout.gpu_scroll_offset = Some(start_gpu_index);
         let end_gpu_index = (start_gpu_index + max_gpu_items).min(gpu_info_to_display.len());
 
-        for (i, gpu_info) in gpu_info_to_display
+        for gpu_info in gpu_info_to_display
             .iter()
-            .enumerate()
             .skip(start_gpu_index)
             .take(end_gpu_index - start_gpu_index)
         {
@@ -531,14 +754,45 @@ impl UiLoop {
                 .copied()
                 .unwrap_or(0);
 
+            let first_row = buffer.line_count() as u16;
+            let headroom = memory_headroom(gpu_info, &state.process_info, oom_thresholds());
+            // Flagged stale by its node, or kept from before the node dropped off
+            let stale = gpu_info.detail.get(STALE_KEY).is_some_and(|v| v == "true")
+                || state
+                    .connection_status
+                    .get(&gpu_info.host_id)
+                    .is_some_and(|status| !status.is_connected);
+            let marks = GpuRowMarks {
+                pinned: state.pinned_gpus.contains(&gpu_info.uuid),
+                selected: state.selected_gpu.as_ref() == Some(&gpu_info.uuid),
+                stale,
+                lost: is_lost(gpu_info),
+                smoothed_utilization: state
+                    .utilization_ema
+                    .as_ref()
+                    .and_then(|ema| ema.get(&gpu_info.uuid)),
+                flash: state
+                    .change_highlight
+                    .as_ref()
+                    .map(|highlight| highlight.gpu_flash(&gpu_info.uuid, Instant::now()))
+                    .unwrap_or_default(),
+            };
             print_gpu_info(
                 buffer,
-                i,
+                marks,
                 gpu_info,
+                headroom,
                 cols as usize,
                 device_name_scroll_offset,
                 hostname_scroll_offset,
             );
+            let last_row = (buffer.line_count() as u16).saturating_sub(1);
+            layout.gpu_rows.push(GpuRowRegion {
+                first_row,
+                last_row,
+                host_id: gpu_info.host_id.clone(),
+                uuid: gpu_info.uuid.clone(),
+            });
         }
     }
 
@@ -574,7 +828,26 @@ impl UiLoop {
                 .copied()
                 .unwrap_or(0);
 
-            print_chassis_info(buffer, i, chassis, width, hostname_scroll_offset);
+            // Logical CPUs of the same host, to compare load against
+            let load_cores = state
+                .normalize_load
+                .then(|| {
+                    state
+                        .cpu_info
+                        .iter()
+                        .find(|cpu| cpu.host_id == chassis.host_id)
+                        .map(|cpu| cpu.total_threads.max(cpu.total_cores))
+                })
+                .flatten();
+
+            print_chassis_info(
+                buffer,
+                i,
+                chassis,
+                width,
+                hostname_scroll_offset,
+                load_cores,
+            );
         }
     }
 
@@ -587,18 +860,10 @@ impl UiLoop {
             let is_connected =
                 if let Some(host_id) = state.hostname_to_host_id.get(current_hostname) {
                     // Found in reverse lookup, get the connection status
-                    state
-                        .connection_status
-                        .get(host_id)
-                        .map(|status| status.is_connected)
-                        .unwrap_or(false)
+                    state.is_host_connected(host_id).unwrap_or(false)
                 } else {
                     // Direct lookup by host_id
-                    state
-                        .connection_status
-                        .get(current_hostname)
-                        .map(|status| status.is_connected)
-                        .unwrap_or(true) // Default to connected for local mode
+                    state.is_host_connected(current_hostname).unwrap_or(true) // Default to connected for local mode
                 };
 
             if !is_connected {
@@ -676,116 +941,186 @@ impl UiLoop {
         }
     }
 
+    /// Processes of the nodes that export them: those of the tab's node, or
+    /// of every node with a column naming the node on the "All" tab
+    fn render_remote_processes(
+        &self,
+        buffer: &mut BufferWriter,
+        state: &AppState,
+        cols: u16,
+        rows: u16,
+    ) {
+        let processes = state.remote_tab_processes();
+        if processes.is_empty() {
+            return;
+        }
+
+        // Add a blank line before process list, and keep the function keys
+        queue!(buffer, Print("\r\n")).unwrap();
+        let function_key_rows = 1;
+        let available_rows =
+            rows.saturating_sub(buffer.line_count() as u16 + 1 + function_key_rows);
+
+        let process_layout = if state.current_tab == 0 {
+            state.process_layout.with_host_column()
+        } else {
+            state.process_layout.clone()
+        };
+        let current_user = whoami::username().unwrap_or_default();
+        // Nothing to select: the arrow keys scroll the devices in remote mode
+        print_process_info(
+            buffer,
+            &processes,
+            usize::MAX,
+            0,
+            available_rows,
+            cols,
+            0,
+            &current_user,
+            &state.sort_criteria,
+            &state.sort_direction,
+            &process_layout,
+            state.change_highlight.as_ref(),
+            state.remote_processes_omitted(),
+        );
+    }
+
     fn render_disconnection_notification(
         &self,
         buffer: &mut BufferWriter,
         hostname: &str,
         width: usize,
     ) {
-        use crate::ui::text::print_colored_text;
-        use crossterm::style::Color;
+        use crate::ui::text::{print_colored_text, truncate_to_width};
+
+        let theme = theme::current();
 
         // Add some spacing
         writeln!(buffer).unwrap();
         writeln!(buffer).unwrap();
 
         // Create a centered notification box
-        let box_width = (width - 4).min(60); // Leave margin and max width
+        let box_width = width.saturating_sub(4).min(60); // Leave margin and max width
         let margin = (width - box_width) / 2;
+        let inner_width = box_width.saturating_sub(4);
 
         // Top border
         write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "┌", Color::Red, None, None);
-        print_colored_text(buffer, &"─".repeat(box_width - 2), Color::Red, None, None);
-        print_colored_text(buffer, "┐", Color::Red, None, None);
+        print_colored_text(buffer, "┌", theme.critical, None, None);
+        print_colored_text(
+            buffer,
+            &"─".repeat(box_width.saturating_sub(2)),
+            theme.critical,
+            None,
+            None,
+        );
+        print_colored_text(buffer, "┐", theme.critical, None, None);
         writeln!(buffer).unwrap();
 
         // Title line
         let title = "CONNECTION LOST";
-        let title_padding = (box_width - 4 - title.len()) / 2;
+        let title_padding = inner_width.saturating_sub(title.len()) / 2;
         write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "│ ", Color::Red, None, None);
-        print_colored_text(buffer, &" ".repeat(title_padding), Color::White, None, None);
-        print_colored_text(buffer, title, Color::Red, None, None);
+        print_colored_text(buffer, "│ ", theme.critical, None, None);
+        print_colored_text(buffer, &" ".repeat(title_padding), theme.text, None, None);
+        print_colored_text(buffer, title, theme.critical, None, None);
         print_colored_text(
             buffer,
-            &" ".repeat(box_width - 4 - title_padding - title.len()),
-            Color::White,
+            &" ".repeat(inner_width.saturating_sub(title_padding + title.len())),
+            theme.text,
             None,
             None,
         );
-        print_colored_text(buffer, " │", Color::Red, None, None);
+        print_colored_text(buffer, " │", theme.critical, None, None);
         writeln!(buffer).unwrap();
 
         // Empty line
         write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "│", Color::Red, None, None);
-        print_colored_text(buffer, &" ".repeat(box_width - 2), Color::White, None, None);
-        print_colored_text(buffer, "│", Color::Red, None, None);
+        print_colored_text(buffer, "│", theme.critical, None, None);
+        print_colored_text(
+            buffer,
+            &" ".repeat(box_width.saturating_sub(2)),
+            theme.text,
+            None,
+            None,
+        );
+        print_colored_text(buffer, "│", theme.critical, None, None);
         writeln!(buffer).unwrap();
 
         // Hostname line
-        let hostname_text = format!("Node: {hostname}");
-        let hostname_padding = (box_width - 4 - hostname_text.len()) / 2;
+        let hostname_text = truncate_to_width(&format!("Node: {hostname}"), inner_width);
+        let hostname_padding = inner_width.saturating_sub(hostname_text.len()) / 2;
         write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "│ ", Color::Red, None, None);
+        print_colored_text(buffer, "│ ", theme.critical, None, None);
         print_colored_text(
             buffer,
             &" ".repeat(hostname_padding),
-            Color::White,
+            theme.text,
             None,
             None,
         );
-        print_colored_text(buffer, &hostname_text, Color::Yellow, None, None);
+        print_colored_text(buffer, &hostname_text, theme.label, None, None);
         print_colored_text(
             buffer,
-            &" ".repeat(box_width - 4 - hostname_padding - hostname_text.len()),
-            Color::White,
+            &" ".repeat(inner_width.saturating_sub(hostname_padding + hostname_text.len())),
+            theme.text,
             None,
             None,
         );
-        print_colored_text(buffer, " │", Color::Red, None, None);
+        print_colored_text(buffer, " │", theme.critical, None, None);
         writeln!(buffer).unwrap();
 
         // Status line
-        let status_text = "Unable to retrieve node information";
-        let status_padding = (box_width - 4 - status_text.len()) / 2;
+        let status_text = truncate_to_width("Unable to retrieve node information", inner_width);
+        let status_padding = inner_width.saturating_sub(status_text.len()) / 2;
         write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "│ ", Color::Red, None, None);
+        print_colored_text(buffer, "│ ", theme.critical, None, None);
+        print_colored_text(buffer, &" ".repeat(status_padding), theme.text, None, None);
+        print_colored_text(buffer, &status_text, theme.dim, None, None);
         print_colored_text(
             buffer,
-            &" ".repeat(status_padding),
-            Color::White,
+            &" ".repeat(inner_width.saturating_sub(status_padding + status_text.len())),
+            theme.text,
             None,
             None,
         );
-        print_colored_text(buffer, status_text, Color::DarkGrey, None, None);
+        print_colored_text(buffer, " │", theme.critical, None, None);
+        writeln!(buffer).unwrap();
+
+        // Empty line
+        write!(buffer, "{}", " ".repeat(margin)).unwrap();
+        print_colored_text(buffer, "│", theme.critical, None, None);
         print_colored_text(
             buffer,
-            &" ".repeat(box_width - 4 - status_padding - status_text.len()),
-            Color::White,
+            &" ".repeat(box_width.saturating_sub(2)),
+            theme.text,
             None,
             None,
         );
-        print_colored_text(buffer, " │", Color::Red, None, None);
-        writeln!(buffer).unwrap();
-
-        // Empty line
-        write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "│", Color::Red, None, None);
-        print_colored_text(buffer, &" ".repeat(box_width - 2), Color::White, None, None);
-        print_colored_text(buffer, "│", Color::Red, None, None);
+        print_colored_text(buffer, "│", theme.critical, None, None);
         writeln!(buffer).unwrap();
 
         // Bottom border
         write!(buffer, "{}", " ".repeat(margin)).unwrap();
-        print_colored_text(buffer, "└", Color::Red, None, None);
-        print_colored_text(buffer, &"─".repeat(box_width - 2), Color::Red, None, None);
-        print_colored_text(buffer, "┘", Color::Red, None, None);
+        print_colored_text(buffer, "└", theme.critical, None, None);
+        print_colored_text(
+            buffer,
+            &"─".repeat(box_width.saturating_sub(2)),
+            theme.critical,
+            None,
+            None,
+        );
+        print_colored_text(buffer, "┘", theme.critical, None, None);
         writeln!(buffer).unwrap();
     }
 
-    fn render_local_devices(&self, buffer: &mut BufferWriter, state: &AppState, width: usize) {
+    fn render_local_devices(
+        &self,
+        buffer: &mut BufferWriter,
+        state: &AppState,
+        width: usize,
+        layout: &mut ScreenLayout,
+    ) {
         // CPU information for local mode
         for (i, cpu_info) in state.cpu_info.iter().enumerate() {
             // Get scroll offsets for CPU name and hostname
@@ -860,31 +1195,68 @@ impl UiLoop {
             let current_user = whoami::username().unwrap_or_default();
 
             // Apply GPU filter if enabled
-            let processes_to_display: Cow<'_, [ProcessInfo]> = if state.gpu_filter_enabled {
-                Cow::Owned(
-                    state
-                        .process_info
-                        .iter()
-                        .filter(|p| p.used_memory > 0)
-                        .cloned()
-                        .collect(),
+            let processes_to_display = state.displayed_processes();
+            let summaries = if state.group_by_user {
+                summarize_by_user(&processes_to_display)
+            } else {
+                Vec::new()
+            };
+            let row_count = if state.group_by_user {
+                user_view_rows(
+                    &summaries,
+                    &processes_to_display,
+                    state.expanded_user.as_deref(),
                 )
+                .len()
             } else {
-                Cow::Borrowed(&state.process_info)
+                processes_to_display.len()
             };
 
-            print_process_info(
-                buffer,
-                &processes_to_display,
+            let capacity = process_rows_capacity(available_rows);
+            let start_index = clamp_start_index(
                 state.selected_process_index,
                 state.start_index,
-                available_rows,
-                cols,
-                state.process_horizontal_scroll_offset,
-                &current_user,
-                &state.sort_criteria,
-                &state.sort_direction,
+                capacity,
+                row_count,
             );
+            layout.process_start_index = Some(start_index);
+
+            // "Processes:" title, column header and separator precede the rows.
+            // The per-user header has no sortable columns.
+            let title_row = buffer.line_count() as u16;
+            layout.process_header_row = (!state.group_by_user).then_some(title_row + 1);
+            layout.process_first_row = Some(title_row + 3);
+            layout.process_visible_rows = row_count.saturating_sub(start_index).min(capacity);
+
+            if state.group_by_user {
+                print_user_process_info(
+                    buffer,
+                    &summaries,
+                    &processes_to_display,
+                    state.expanded_user.as_deref(),
+                    state.selected_process_index,
+                    start_index,
+                    available_rows,
+                    cols,
+                    state.process_horizontal_scroll_offset,
+                );
+            } else {
+                print_process_info(
+                    buffer,
+                    &processes_to_display,
+                    state.selected_process_index,
+                    start_index,
+                    available_rows,
+                    cols,
+                    state.process_horizontal_scroll_offset,
+                    &current_user,
+                    &state.sort_criteria,
+                    &state.sort_direction,
+                    &state.process_layout,
+                    state.change_highlight.as_ref(),
+                    0,
+                );
+            }
         }
     }
 }
@@ -0,0 +1,73 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Completion scripts and man pages generated from the clap definitions.
+
+use all_smi::cli::{write_completions, write_manpage, write_manpages, Cli};
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+fn subcommands() -> Vec<String> {
+    Cli::command()
+        .get_subcommands()
+        .map(|command| command.get_name().to_string())
+        .collect()
+}
+
+fn completions(shell: Shell) -> String {
+    let mut out = Vec::new();
+    write_completions(shell, &mut out);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_completions_cover_every_subcommand() {
+    let subcommands = subcommands();
+    assert!(subcommands.iter().any(|name| name == "completions"));
+
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        let script = completions(shell);
+        for name in &subcommands {
+            assert!(
+                script.contains(name.as_str()),
+                "{shell} script lacks {name}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_zsh_completion_is_a_compdef() {
+    let script = completions(Shell::Zsh);
+    assert!(script.starts_with("#compdef all-smi"));
+    // Value hints: --hostfile completes files, --dir completes directories
+    assert!(script.contains("--hostfile=[") && script.contains(":FILE:_files"));
+    assert!(script.contains(":DIR:_files -/"));
+}
+
+#[test]
+fn test_manpages() {
+    let mut page = Vec::new();
+    write_manpage(&mut page).unwrap();
+    let page = String::from_utf8(page).unwrap();
+    assert!(page.contains(".TH all-smi 1"));
+
+    let dir = tempfile::tempdir().unwrap();
+    write_manpages(dir.path()).unwrap();
+    assert!(dir.path().join("all-smi.1").exists());
+    for name in subcommands() {
+        let path = dir.path().join(format!("all-smi-{name}.1"));
+        assert!(path.exists(), "missing {}", path.display());
+    }
+}
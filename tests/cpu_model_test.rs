@@ -1,11 +1,9 @@
 use all_smi::device::CpuPlatformType;
 use all_smi::network::metrics_parser::MetricsParser;
-use regex::Regex;
 
 #[test]
 fn test_cpu_model_metric_parsing() {
     let parser = MetricsParser::new();
-    let re = Regex::new(r"^all_smi_([^\{]+)\{([^}]+)\} ([\d\.]+)$").unwrap();
     let host = "127.0.0.1:10001";
 
     let test_data = r#"
@@ -17,7 +15,7 @@ all_smi_cpu_core_count{cpu_model="", instance="node-0001", index="0"} 128
 all_smi_cpu_frequency_mhz{instance="node-0001"} 2450
 "#;
 
-    let (_, cpu_info, _, _) = parser.parse_metrics(test_data, host, &re);
+    let (_, cpu_info, _, _, _, _) = parser.parse_metrics(test_data, host);
 
     assert_eq!(cpu_info.len(), 1);
     let cpu = &cpu_info[0];
@@ -0,0 +1,95 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streams snapshots from the gRPC service through a tonic client.
+
+#![cfg(feature = "grpc")]
+
+use std::time::Duration;
+
+use all_smi::app_state::AppState;
+use all_smi::grpc::proto::metrics_service_client::MetricsServiceClient;
+use all_smi::grpc::proto::SnapshotRequest;
+use all_smi::grpc::{serve_grpc, snapshot_channel};
+use all_smi::storage::info::StorageInfo;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+#[tokio::test]
+async fn test_stream_pushes_consecutive_snapshots() {
+    let (mut publisher, snapshots) = snapshot_channel("node1".to_string());
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    let server = tokio::spawn(serve_grpc(listener, snapshots, async {
+        let _ = stop_rx.await;
+    }));
+
+    let mut client = MetricsServiceClient::connect(format!("http://{addr}"))
+        .await
+        .unwrap();
+    let error = client.get_snapshot(SnapshotRequest {}).await.unwrap_err();
+    assert_eq!(error.code(), tonic::Code::Unavailable);
+
+    let mut state = AppState::new();
+    state.storage_info.push(StorageInfo {
+        mount_point: "/".to_string(),
+        total_bytes: 1000,
+        available_bytes: 400,
+        host_id: "node1".to_string(),
+        hostname: "node1".to_string(),
+        index: 0,
+        total_inodes: None,
+        free_inodes: None,
+        health: Default::default(),
+    });
+    publisher.publish(&state);
+
+    let mut stream = client
+        .stream_metrics(SnapshotRequest {})
+        .await
+        .unwrap()
+        .into_inner();
+    let first = tokio::time::timeout(Duration::from_secs(5), stream.message())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.hostname, "node1");
+    assert_eq!(first.disks.len(), 1);
+    assert_eq!(first.disks[0].available_bytes, 400);
+
+    state.storage_info[0].available_bytes = 300;
+    publisher.publish(&state);
+    let second = tokio::time::timeout(Duration::from_secs(5), stream.message())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert!(second.timestamp_ms > first.timestamp_ms);
+    assert_eq!(second.disks[0].available_bytes, 300);
+
+    let latest = client
+        .get_snapshot(SnapshotRequest {})
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(latest.timestamp_ms, second.timestamp_ms);
+
+    // Dropping the publisher ends open streams, letting the server stop
+    drop(publisher);
+    assert!(stream.message().await.unwrap().is_none());
+    stop_tx.send(()).unwrap();
+    server.await.unwrap().unwrap();
+}
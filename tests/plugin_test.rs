@@ -0,0 +1,67 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builds the sample plugin in tests/plugins/sample and reads devices
+//! through it.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+use all_smi::device::readers::plugin::{Plugin, PluginGpuReader};
+use all_smi::device::GpuReader;
+
+/// Build the sample plugin into this test's scratch directory and return
+/// the library path.
+fn build_sample_plugin() -> PathBuf {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/plugins/sample/Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("sample-plugin");
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--offline", "--manifest-path"])
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "building the sample plugin failed");
+
+    let file_name = format!(
+        "{}all_smi_sample_plugin{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    );
+    target_dir.join("debug").join(file_name)
+}
+
+#[test]
+fn test_sample_plugin() {
+    let plugin = Plugin::load(&build_sample_plugin()).expect("failed to load sample plugin");
+    assert_eq!(plugin.name(), "acme");
+
+    let reader = PluginGpuReader::new(Arc::new(plugin));
+    let gpus = reader.get_gpu_info();
+    assert_eq!(gpus.len(), 2);
+    assert_eq!(gpus[0].uuid, "acme-0000");
+    assert_eq!(gpus[1].uuid, "acme-0001");
+    for gpu in &gpus {
+        assert_eq!(gpu.name, "Acme X1");
+        assert_eq!(gpu.device_type, "NPU");
+        assert_eq!(gpu.total_memory, 16 << 30);
+        assert_eq!(gpu.detail["plugin"], "acme");
+        assert_eq!(gpu.detail["driver_version"], "1.0.0");
+    }
+    assert_eq!(gpus[1].utilization, 50.0);
+    assert_eq!(gpus[1].temperature, 41);
+    assert!(reader.get_process_info().is_empty());
+}
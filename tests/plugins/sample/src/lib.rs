@@ -0,0 +1,79 @@
+// Copyright 2025 Lablup Inc. and Jeongkyu Shin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A plugin reporting two fixed "Acme X1" accelerators.
+//!
+//! Vendors can start from this file: the struct mirrors `AllSmiDevice` in
+//! all-smi's `device::plugin_abi` and must keep the same layout.
+
+use std::ffi::c_char;
+
+#[repr(C)]
+pub struct AllSmiDevice {
+    pub uuid: [c_char; 64],
+    pub name: [c_char; 128],
+    pub device_type: [c_char; 16],
+    pub driver_version: [c_char; 64],
+    pub utilization: f64,
+    pub temperature: u32,
+    pub frequency: u32,
+    pub used_memory: u64,
+    pub total_memory: u64,
+    pub power_consumption: f64,
+}
+
+const DEVICE_COUNT: usize = 2;
+
+/// Copy `value` into a fixed-size field, truncating and NUL-terminating it.
+fn set(field: &mut [c_char], value: &str) {
+    let len = value.len().min(field.len() - 1);
+    for (slot, byte) in field.iter_mut().zip(&value.as_bytes()[..len]) {
+        *slot = *byte as c_char;
+    }
+    field[len] = 0;
+}
+
+#[no_mangle]
+pub extern "C" fn all_smi_plugin_abi_version() -> u32 {
+    1
+}
+
+#[no_mangle]
+pub extern "C" fn all_smi_plugin_name() -> *const c_char {
+    c"acme".as_ptr()
+}
+
+/// # Safety
+///
+/// `out` must point to `capacity` writable devices.
+#[no_mangle]
+pub unsafe extern "C" fn all_smi_plugin_devices(out: *mut AllSmiDevice, capacity: usize) -> i32 {
+    if out.is_null() {
+        return -1;
+    }
+    let devices = unsafe { std::slice::from_raw_parts_mut(out, capacity.min(DEVICE_COUNT)) };
+    for (index, device) in devices.iter_mut().enumerate() {
+        set(&mut device.uuid, &format!("acme-{index:04}"));
+        set(&mut device.name, "Acme X1");
+        set(&mut device.device_type, "NPU");
+        set(&mut device.driver_version, "1.0.0");
+        device.utilization = 25.0 * (index + 1) as f64;
+        device.temperature = 40 + index as u32;
+        device.frequency = 1200;
+        device.used_memory = (index as u64 + 1) << 30;
+        device.total_memory = 16 << 30;
+        device.power_consumption = 75.5;
+    }
+    DEVICE_COUNT as i32
+}